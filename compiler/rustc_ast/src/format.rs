@@ -211,6 +211,10 @@ pub enum FormatTrait {
     LowerHex,
     /// `{:X}`
     UpperHex,
+    /// `{:a}`
+    LowerHexFloat,
+    /// `{:A}`
+    UpperHexFloat,
 }
 
 #[derive(Clone, Encodable, Decodable, Default, Debug, PartialEq, Eq)]
@@ -255,6 +255,8 @@ fn make_argument<'hir>(
             Format(Binary) => sym::new_binary,
             Format(LowerHex) => sym::new_lower_hex,
             Format(UpperHex) => sym::new_upper_hex,
+            Format(LowerHexFloat) => sym::new_lower_hex_float,
+            Format(UpperHexFloat) => sym::new_upper_hex_float,
             Usize => sym::from_usize,
         },
     ));
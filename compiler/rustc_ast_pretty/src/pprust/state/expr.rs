@@ -894,6 +894,8 @@ fn reconstruct_format_args_template_string(pieces: &[FormatArgsPiece]) -> String
                     FormatTrait::Binary => "b",
                     FormatTrait::LowerHex => "x",
                     FormatTrait::UpperHex => "X",
+                    FormatTrait::LowerHexFloat => "a",
+                    FormatTrait::UpperHexFloat => "A",
                 });
                 template.push('}');
             }
@@ -12,7 +12,8 @@
 use rustc_lint_defs::builtin::NAMED_ARGUMENTS_USED_POSITIONALLY;
 use rustc_lint_defs::{BufferedEarlyLint, BuiltinLintDiag, LintId};
 use rustc_parse_format as parse;
-use rustc_span::symbol::{Ident, Symbol};
+use rustc_session::parse::feature_err;
+use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::{BytePos, ErrorGuaranteed, InnerSpan, Span};
 
 use crate::errors;
@@ -430,6 +431,15 @@ enum ArgRef<'a> {
                     "b" => FormatTrait::Binary,
                     "x" => FormatTrait::LowerHex,
                     "X" => FormatTrait::UpperHex,
+                    "a" | "A" => {
+                        let ty_span = format.ty_span.and_then(to_span).unwrap_or(fmt_span);
+                        maybe_emit_hex_float_feature(ecx, ty_span);
+                        if format.ty == "a" {
+                            FormatTrait::LowerHexFloat
+                        } else {
+                            FormatTrait::UpperHexFloat
+                        }
+                    }
                     _ => {
                         invalid_placeholder_type_error(ecx, format.ty, format.ty_span, fmt_span);
                         FormatTrait::Display
@@ -572,6 +582,14 @@ enum ArgRef<'a> {
     ExpandResult::Ready(Ok(FormatArgs { span: fmt_span, template, arguments: args }))
 }
 
+/// Asks for the `hex_float` feature if it is not already declared.
+fn maybe_emit_hex_float_feature(ecx: &ExtCtxt<'_>, span: Span) {
+    if !ecx.ecfg.features.hex_float && !span.allows_unstable(sym::hex_float) {
+        let msg = "formatting floats in hexadecimal is experimental";
+        feature_err(ecx.sess, sym::hex_float, span, msg).emit();
+    }
+}
+
 fn invalid_placeholder_type_error(
     ecx: &ExtCtxt<'_>,
     ty: &str,
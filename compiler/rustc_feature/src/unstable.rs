@@ -495,6 +495,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, global_registration, "1.80.0", Some(125119)),
     /// Allows using `..=X` as a patterns in slices.
     (unstable, half_open_range_patterns_in_slices, "1.66.0", Some(67264)),
+    /// Allows formatting floats in hexadecimal with `{:a}` and `{:A}`.
+    (unstable, hex_float, "CURRENT_RUSTC_VERSION", None),
     /// Allows `if let` guard in match arms.
     (unstable, if_let_guard, "1.47.0", Some(51114)),
     /// Allows `impl Trait` to be used inside associated types (RFC 2515).
@@ -309,6 +309,7 @@
         RwLockReadGuard,
         RwLockWriteGuard,
         Saturating,
+        SeekFrom,
         Send,
         SeqCst,
         Sized,
@@ -972,6 +973,7 @@
         half_open_range_patterns,
         half_open_range_patterns_in_slices,
         hash,
+        hex_float,
         hexagon_target_feature,
         hidden,
         homogeneous_aggregate,
@@ -1292,11 +1294,13 @@
         new_display,
         new_lower_exp,
         new_lower_hex,
+        new_lower_hex_float,
         new_octal,
         new_pointer,
         new_unchecked,
         new_upper_exp,
         new_upper_hex,
+        new_upper_hex_float,
         new_v1,
         new_v1_formatted,
         next,
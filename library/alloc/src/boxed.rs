@@ -2513,6 +2513,92 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+#[unstable(feature = "core_io", issue = "none")]
+impl<T: core::io::ErrorType + ?Sized, A: Allocator> core::io::ErrorType for Box<T, A> {
+    type Error = T::Error;
+}
+
+#[unstable(feature = "core_io", issue = "none")]
+impl<R: core::io::Read + ?Sized, A: Allocator> core::io::Read for Box<R, A> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        core::io::Read::read(&mut **self, buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        core::io::Read::read_exact(&mut **self, buf)
+    }
+
+    #[inline]
+    fn read_buf(&mut self, cursor: core::io::BorrowedCursor<'_>) -> Result<(), Self::Error> {
+        core::io::Read::read_buf(&mut **self, cursor)
+    }
+}
+
+#[unstable(feature = "core_io", issue = "none")]
+impl<B: core::io::BufRead + ?Sized, A: Allocator> core::io::BufRead for Box<B, A> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error> {
+        core::io::BufRead::fill_buf(&mut **self)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        core::io::BufRead::consume(&mut **self, amt)
+    }
+}
+
+#[unstable(feature = "core_io", issue = "none")]
+impl<W: core::io::Write + ?Sized, A: Allocator> core::io::Write for Box<W, A> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        core::io::Write::write(&mut **self, buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        core::io::Write::flush(&mut **self)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), Self::Error> {
+        core::io::Write::write_all(&mut **self, buf)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        core::io::Write::write_fmt(&mut **self, fmt)
+    }
+}
+
+#[unstable(feature = "core_io", issue = "none")]
+impl<S: core::io::Seek + ?Sized, A: Allocator> core::io::Seek for Box<S, A> {
+    #[inline]
+    fn seek(&mut self, pos: core::io::SeekFrom) -> Result<u64, Self::Error> {
+        core::io::Seek::seek(&mut **self, pos)
+    }
+
+    #[inline]
+    fn rewind(&mut self) -> Result<(), Self::Error> {
+        core::io::Seek::rewind(&mut **self)
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> Result<u64, Self::Error> {
+        core::io::Seek::stream_position(&mut **self)
+    }
+}
+
+#[unstable(feature = "core_io_internals", issue = "none")]
+impl<A: Allocator> core::io::CursorBuffer for Box<[u8], A> {
+    #[inline]
+    fn write_at(&mut self, pos: &mut u64, buf: &[u8]) -> Result<usize, core::io::ErrorKind> {
+        let mut slice: &mut [u8] = self;
+        core::io::CursorBuffer::write_at(&mut slice, pos, buf)
+    }
+}
+
 impl dyn Error {
     #[inline]
     #[stable(feature = "error_downcast", since = "1.3.0")]
@@ -0,0 +1,620 @@
+//! A binary heap whose elements can be found again after they were pushed.
+//!
+//! [`AddressableHeap::push`] returns a [`Handle`] that stays valid while the
+//! element is in the heap, no matter how it moves around. The handle can be
+//! used to look the element up, to change its priority, or to remove it,
+//! which is what decrease-key algorithms such as Dijkstra's, or timer queues
+//! that cancel pending timers, need.
+//!
+//! The heap keeps a table of slots next to its elements. Every element
+//! records its slot, and every slot records the element's current position,
+//! so both directions are kept up to date as the sifting code moves elements.
+
+use core::alloc::Allocator;
+use core::fmt;
+
+use super::Hole;
+use crate::alloc::Global;
+use crate::vec::Vec;
+
+/// A max-heap that hands out a [`Handle`] for every pushed element.
+///
+/// Unlike [`BinaryHeap`], an `AddressableHeap` can change the priority of, or
+/// remove, any element in it, not just the greatest one, in *O*(log(*n*))
+/// time.
+///
+/// It is a logic error for an item to be modified in such a way that the
+/// item's ordering relative to any other item, as determined by the [`Ord`]
+/// trait, changes while it is in the heap; use [`change_priority`] instead.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(binary_heap_addressable)]
+/// use std::cmp::Reverse;
+/// use std::collections::binary_heap::AddressableHeap;
+///
+/// // A min-heap of tentative distances.
+/// let mut heap = AddressableHeap::new();
+/// let a = heap.push(Reverse(7));
+/// let b = heap.push(Reverse(3));
+/// let c = heap.push(Reverse(9));
+///
+/// // A shorter path to `c` was found.
+/// heap.change_priority(c, Reverse(1));
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+///
+/// // `a` is no longer needed.
+/// assert_eq!(heap.remove(a), Some(Reverse(7)));
+/// assert!(!heap.contains(a));
+/// assert!(heap.contains(b));
+/// assert_eq!(heap.pop(), Some(Reverse(3)));
+/// assert!(heap.is_empty());
+/// ```
+///
+/// [`BinaryHeap`]: super::BinaryHeap
+/// [`change_priority`]: AddressableHeap::change_priority
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+pub struct AddressableHeap<
+    T,
+    #[unstable(feature = "allocator_api", issue = "32838")] A: Allocator = Global,
+> {
+    data: Vec<Node<T>, A>,
+    slots: Vec<Slot, A>,
+    // The first vacant slot, the others are linked through `SlotState::Vacant`.
+    free: Option<usize>,
+}
+
+/// Identifies an element pushed onto an [`AddressableHeap`].
+///
+/// A handle is only meaningful for the heap that returned it (or a clone of
+/// that heap). Once its element has been popped or removed, the handle is no
+/// longer [contained] in the heap, even if the heap reuses its slot for a
+/// later element.
+///
+/// [contained]: AddressableHeap::contains
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Handle {
+    slot: usize,
+    generation: usize,
+}
+
+#[derive(Clone)]
+struct Node<T> {
+    value: T,
+    slot: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    // Bumped whenever the slot is vacated, which invalidates old handles.
+    generation: usize,
+    state: SlotState,
+}
+
+#[derive(Clone, Copy)]
+enum SlotState {
+    /// The position of the slot's element in `data`.
+    Occupied(usize),
+    /// The next vacant slot.
+    Vacant(Option<usize>),
+}
+
+/// Moves `hole` to `index` and records the new positions of both the element
+/// that filled the old position and the element in the hole.
+///
+/// Keeping the hole's element up to date on every step means `slots` is
+/// still correct if a comparison panics and the hole is filled early.
+///
+/// # Safety
+///
+/// Same as [`Hole::move_to`].
+unsafe fn move_hole<T>(hole: &mut Hole<'_, Node<T>>, slots: &mut [Slot], index: usize) {
+    let pos = hole.pos();
+    // SAFETY: the caller upholds the requirements of `move_to`, and after the
+    //  move `pos` is a valid index that is no longer the hole.
+    let moved = unsafe {
+        hole.move_to(index);
+        hole.get(pos).slot
+    };
+    slots[moved].state = SlotState::Occupied(pos);
+    slots[hole.element().slot].state = SlotState::Occupied(index);
+}
+
+impl<T: Ord> AddressableHeap<T> {
+    /// Creates an empty `AddressableHeap` as a max-heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// heap.push(4);
+    /// ```
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    #[must_use]
+    pub const fn new() -> AddressableHeap<T> {
+        AddressableHeap { data: Vec::new(), slots: Vec::new(), free: None }
+    }
+
+    /// Creates an empty `AddressableHeap` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::with_capacity(10);
+    /// heap.push(4);
+    /// ```
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> AddressableHeap<T> {
+        AddressableHeap::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> AddressableHeap<T, A> {
+    /// Creates an empty `AddressableHeap` as a max-heap, using `A` as allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, binary_heap_addressable)]
+    ///
+    /// use std::alloc::System;
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new_in(System);
+    /// heap.push(4);
+    /// ```
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    #[must_use]
+    pub fn new_in(alloc: A) -> AddressableHeap<T, A> {
+        AddressableHeap { data: Vec::new_in(alloc.clone()), slots: Vec::new_in(alloc), free: None }
+    }
+
+    /// Creates an empty `AddressableHeap` with at least the specified capacity,
+    /// using `A` as allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api, binary_heap_addressable)]
+    ///
+    /// use std::alloc::System;
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::with_capacity_in(10, System);
+    /// heap.push(4);
+    /// ```
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> AddressableHeap<T, A> {
+        AddressableHeap {
+            data: Vec::with_capacity_in(capacity, alloc.clone()),
+            slots: Vec::with_capacity_in(capacity, alloc),
+            free: None,
+        }
+    }
+
+    /// Pushes an item onto the heap and returns a handle to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let three = heap.push(3);
+    /// heap.push(5);
+    ///
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// assert_eq!(heap.get(three), Some(&3));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Like [`BinaryHeap::push`], the expected cost is *O*(1) and the worst
+    /// case cost is *O*(log(*n*)), not counting reallocation.
+    ///
+    /// [`BinaryHeap::push`]: super::BinaryHeap::push
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn push(&mut self, item: T) -> Handle {
+        let pos = self.data.len();
+        // Reserve first, so that the slot is never occupied without its node.
+        self.data.reserve(1);
+        let slot = match self.free {
+            Some(slot) => {
+                let SlotState::Vacant(next) = self.slots[slot].state else {
+                    unreachable!("occupied slot in the free list")
+                };
+                self.free = next;
+                self.slots[slot].state = SlotState::Occupied(pos);
+                slot
+            }
+            None => {
+                self.slots.push(Slot { generation: 0, state: SlotState::Occupied(pos) });
+                self.slots.len() - 1
+            }
+        };
+        self.data.push(Node { value: item, slot });
+        // SAFETY: pos is the index of the node that was just pushed.
+        unsafe { self.sift_up(pos) };
+        self.handle(slot)
+    }
+
+    /// Removes the greatest item from the heap and returns it, or `None` if it
+    /// is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let one = heap.push(1);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(1));
+    /// assert_eq!(heap.pop(), None);
+    /// assert!(!heap.contains(one));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// The worst case cost of `pop` on a heap containing *n* elements is *O*(log(*n*)).
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() { None } else { Some(self.remove_at(0)) }
+    }
+
+    /// Removes the item identified by `handle` from the heap and returns it,
+    /// or `None` if it is no longer in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// heap.push(1);
+    /// let five = heap.push(5);
+    /// heap.push(3);
+    ///
+    /// assert_eq!(heap.remove(five), Some(5));
+    /// assert_eq!(heap.remove(five), None);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// The worst case cost is *O*(log(*n*)).
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let pos = self.position(handle)?;
+        Some(self.remove_at(pos))
+    }
+
+    /// Replaces the item identified by `handle` with `item`, moving it to its
+    /// new place in the heap, and returns the old item.
+    ///
+    /// The handle stays valid. This can be used both to increase and to
+    /// decrease an item's priority.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap does not [contain] `handle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let two = heap.push(2);
+    /// heap.push(5);
+    ///
+    /// assert_eq!(heap.change_priority(two, 8), 2);
+    /// assert_eq!(heap.peek(), Some(&8));
+    /// assert_eq!(heap.get(two), Some(&8));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// The worst case cost is *O*(log(*n*)).
+    ///
+    /// [contain]: AddressableHeap::contains
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn change_priority(&mut self, handle: Handle, item: T) -> T {
+        let Some(pos) = self.position(handle) else {
+            panic!("handle is not in the heap");
+        };
+        let increased = item > self.data[pos].value;
+        let old = core::mem::replace(&mut self.data[pos].value, item);
+        // SAFETY: pos is the position of an element in the heap.
+        unsafe {
+            if increased {
+                self.sift_up(pos);
+            } else {
+                self.sift_down(pos);
+            }
+        }
+        old
+    }
+
+    /// Removes the node at `pos`, which must be in bounds, and restores the
+    /// heap property around the node that takes its place.
+    fn remove_at(&mut self, pos: usize) -> T {
+        let node = self.data.swap_remove(pos);
+        let slot = &mut self.slots[node.slot];
+        slot.generation = slot.generation.wrapping_add(1);
+        slot.state = SlotState::Vacant(self.free);
+        self.free = Some(node.slot);
+
+        if pos < self.data.len() {
+            // The last node took the place of the removed one.
+            self.slots[self.data[pos].slot].state = SlotState::Occupied(pos);
+            // SAFETY: pos < self.len() was just checked.
+            unsafe {
+                if self.sift_up(pos) == pos {
+                    self.sift_down(pos);
+                }
+            }
+        }
+        node.value
+    }
+
+    // Like the sifting in `BinaryHeap`, these move the element out into a
+    // `Hole`, but also record every move in `slots`.
+
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_up(&mut self, pos: usize) -> usize {
+        // SAFETY: The caller guarantees that pos < self.len()
+        let mut hole = unsafe { Hole::new(&mut self.data, pos) };
+
+        while hole.pos() > 0 {
+            let parent = (hole.pos() - 1) / 2;
+
+            // SAFETY: hole.pos() > 0, so parent < hole.pos() is a valid index
+            //  that is not the hole.
+            if hole.element().value <= unsafe { hole.get(parent) }.value {
+                break;
+            }
+
+            // SAFETY: Same as above
+            unsafe { move_hole(&mut hole, &mut self.slots, parent) };
+        }
+
+        hole.pos()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_down(&mut self, pos: usize) {
+        let end = self.data.len();
+        // SAFETY: The caller guarantees that pos < self.len().
+        let mut hole = unsafe { Hole::new(&mut self.data, pos) };
+        let mut child = 2 * hole.pos() + 1;
+
+        // Loop invariant: child == 2 * hole.pos() + 1.
+        while child <= end.saturating_sub(2) {
+            // compare with the greater of the two children
+            // SAFETY: child < end - 1 and child + 1 < end are valid indexes,
+            //  and both differ from hole.pos().
+            child += unsafe { hole.get(child).value <= hole.get(child + 1).value } as usize;
+
+            // if we are already in order, stop.
+            // SAFETY: child is either the old child or the old child + 1.
+            if hole.element().value >= unsafe { hole.get(child) }.value {
+                return;
+            }
+
+            // SAFETY: same as above.
+            unsafe { move_hole(&mut hole, &mut self.slots, child) };
+            child = 2 * hole.pos() + 1;
+        }
+
+        // SAFETY: && short circuit, which means that in the
+        //  second condition it's already true that child == end - 1 < self.len().
+        if child == end - 1 && hole.element().value < unsafe { hole.get(child) }.value {
+            // SAFETY: child is a valid index and child != hole.pos().
+            unsafe { move_hole(&mut hole, &mut self.slots, child) };
+        }
+    }
+}
+
+impl<T, A: Allocator> AddressableHeap<T, A> {
+    /// Returns the greatest item in the heap, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// assert_eq!(heap.peek(), None);
+    ///
+    /// heap.push(1);
+    /// heap.push(5);
+    /// heap.push(2);
+    /// assert_eq!(heap.peek(), Some(&5));
+    /// ```
+    ///
+    /// # Time complexity
+    ///
+    /// Cost is *O*(1) in the worst case.
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first().map(|node| &node.value)
+    }
+
+    /// Returns the handle of the greatest item in the heap, or `None` if it is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// heap.push(1);
+    /// let five = heap.push(5);
+    /// assert_eq!(heap.peek_handle(), Some(five));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn peek_handle(&self) -> Option<Handle> {
+        self.data.first().map(|node| self.handle(node.slot))
+    }
+
+    /// Returns a reference to the item identified by `handle`, or `None` if it
+    /// is no longer in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let one = heap.push(1);
+    /// assert_eq!(heap.get(one), Some(&1));
+    /// heap.pop();
+    /// assert_eq!(heap.get(one), None);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.position(handle).map(|pos| &self.data[pos].value)
+    }
+
+    /// Returns `true` if the item identified by `handle` is still in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let one = heap.push(1);
+    /// assert!(heap.contains(one));
+    /// heap.remove(one);
+    /// assert!(!heap.contains(one));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    /// Returns the number of elements the heap can hold without reallocating.
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` elements more than the
+    /// current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Returns the length of the heap.
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks if the heap is empty.
+    #[must_use]
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops all items from the heap. None of the handles returned so far are
+    /// contained in the heap afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(binary_heap_addressable)]
+    /// use std::collections::binary_heap::AddressableHeap;
+    /// let mut heap = AddressableHeap::new();
+    /// let one = heap.push(1);
+    ///
+    /// heap.clear();
+    /// assert!(heap.is_empty());
+    /// assert!(!heap.contains(one));
+    /// ```
+    #[unstable(feature = "binary_heap_addressable", issue = "none")]
+    pub fn clear(&mut self) {
+        // Vacate the slots before dropping the items, in case a drop panics.
+        for node in &self.data {
+            let slot = &mut self.slots[node.slot];
+            slot.generation = slot.generation.wrapping_add(1);
+            slot.state = SlotState::Vacant(self.free);
+            self.free = Some(node.slot);
+        }
+        self.data.clear();
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
+    fn handle(&self, slot: usize) -> Handle {
+        Handle { slot, generation: self.slots[slot].generation }
+    }
+
+    fn position(&self, handle: Handle) -> Option<usize> {
+        match self.slots.get(handle.slot)? {
+            Slot { generation, state: SlotState::Occupied(pos) }
+                if *generation == handle.generation =>
+            {
+                Some(*pos)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+impl<T: Clone, A: Allocator + Clone> Clone for AddressableHeap<T, A> {
+    /// Clones the heap. The handles of `self` identify the same items in the
+    /// clone.
+    fn clone(&self) -> Self {
+        AddressableHeap { data: self.data.clone(), slots: self.slots.clone(), free: self.free }
+    }
+}
+
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+impl<T: Ord> Default for AddressableHeap<T> {
+    /// Creates an empty `AddressableHeap<T>`.
+    #[inline]
+    fn default() -> AddressableHeap<T> {
+        AddressableHeap::new()
+    }
+}
+
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for AddressableHeap<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter().map(|node| &node.value)).finish()
+    }
+}
@@ -155,9 +155,13 @@
 use crate::slice;
 use crate::vec::{self, AsVecIntoIter, Vec};
 
+mod addressable;
 #[cfg(test)]
 mod tests;
 
+#[unstable(feature = "binary_heap_addressable", issue = "none")]
+pub use addressable::{AddressableHeap, Handle};
+
 /// A priority queue implemented with a binary heap.
 ///
 /// This will be a max-heap.
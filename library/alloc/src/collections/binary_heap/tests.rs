@@ -578,3 +578,74 @@ fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         }
     }
 }
+
+#[test]
+fn test_addressable_change_and_remove() {
+    use crate::testing::rng::DeterministicRng;
+
+    let mut rng = DeterministicRng::new();
+    let mut heap = AddressableHeap::new();
+    let mut live: Vec<(Handle, u32)> = Vec::new();
+    let mut gone = Vec::new();
+    for i in 0..500 {
+        match rng.next() % 4 {
+            0 if !live.is_empty() => {
+                let (handle, old) = live.swap_remove(i % live.len());
+                assert_eq!(heap.remove(handle), Some(old));
+                gone.push(handle);
+            }
+            1 if !live.is_empty() => {
+                let new = rng.next();
+                let (handle, old) = &mut live[i % live.len()];
+                assert_eq!(heap.change_priority(*handle, new), *old);
+                *old = new;
+            }
+            _ => {
+                let value = rng.next();
+                live.push((heap.push(value), value));
+            }
+        }
+    }
+
+    assert_eq!(heap.len(), live.len());
+    for &(handle, value) in &live {
+        assert_eq!(heap.get(handle), Some(&value));
+    }
+    for &handle in &gone {
+        assert!(!heap.contains(handle));
+        assert_eq!(heap.remove(handle), None);
+    }
+
+    live.sort_by_key(|&(_, value)| value);
+    while let Some((handle, value)) = live.pop() {
+        assert_eq!(heap.peek_handle(), Some(handle));
+        assert_eq!(heap.pop(), Some(value));
+        assert!(!heap.contains(handle));
+    }
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_addressable_reused_slot() {
+    let mut heap = AddressableHeap::new();
+    let a = heap.push(1);
+    assert_eq!(heap.pop(), Some(1));
+    let b = heap.push(2);
+    assert_ne!(a, b);
+    assert!(!heap.contains(a));
+    assert_eq!(heap.get(b), Some(&2));
+
+    heap.clear();
+    assert!(!heap.contains(b));
+    let c = heap.push(3);
+    assert_eq!(heap.clone().get(c), Some(&3));
+}
+
+#[test]
+#[should_panic(expected = "handle is not in the heap")]
+fn test_addressable_change_removed() {
+    let mut heap = AddressableHeap::new();
+    let a = heap.push(1);
+    heap.remove(a);
+    heap.change_priority(a, 2);
+}
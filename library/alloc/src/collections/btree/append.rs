@@ -1,8 +1,38 @@
 use core::alloc::Allocator;
 use core::iter::FusedIterator;
+use core::mem;
 
+use super::borrow::DormantMutRef;
+use super::map::MIN_LEN;
 use super::merge_iter::MergeIterInner;
+use super::node::ForceResult::*;
 use super::node::{self, Root};
+use crate::alloc::Global;
+
+/// A trait to tell whether an allocator type is [`Global`].
+/// Returns `true` only for `Global`, `false` for all other types (blanket implementation).
+///
+/// Moving nodes from one tree into another is only sound if the allocator of the
+/// receiving tree can free nodes allocated by the allocator of the other tree. Two
+/// instances of an arbitrary allocator, such as two arenas, need not be able to do so,
+/// but every instance of `Global` can.
+pub trait IsGlobal {
+    fn is_global() -> bool;
+}
+
+// Blanket implementation
+impl<A: Allocator> IsGlobal for A {
+    default fn is_global() -> bool {
+        false
+    }
+}
+
+// Specialization
+impl IsGlobal for Global {
+    fn is_global() -> bool {
+        true
+    }
+}
 
 impl<K, V> Root<K, V> {
     /// Appends all key-value pairs from the union of two ascending iterators,
@@ -40,7 +70,11 @@ pub fn bulk_push<I, A: Allocator + Clone>(&mut self, iter: I, length: &mut usize
     where
         I: Iterator<Item = (K, V)>,
     {
-        let mut cur_node = self.borrow_mut().last_leaf_edge().into_node();
+        // Nodes on the right border remain open to new pairs until we're done,
+        // so we only count the pairs in their subtrees at the end, or when the
+        // iterator panics.
+        let border = CorrectRightBorderOnDrop(self);
+        let mut cur_node = border.0.borrow_mut().last_leaf_edge().into_node();
         // Iterate through all key-value pairs, pushing them into nodes at the right level.
         for (key, value) in iter {
             // Try to push key-value pair into the current leaf node.
@@ -53,19 +87,20 @@ pub fn bulk_push<I, A: Allocator + Clone>(&mut self, iter: I, length: &mut usize
                 loop {
                     match test_node.ascend() {
                         Ok(parent) => {
-                            let parent = parent.into_node();
+                            let mut parent = parent.into_node();
                             if parent.len() < node::CAPACITY {
                                 // Found a node with space left, push here.
                                 open_node = parent;
                                 break;
                             } else {
-                                // Go up again.
+                                // Go up again, leaving a node that is now closed.
+                                parent.correct_subtree_len();
                                 test_node = parent.forget_type();
                             }
                         }
                         Err(_) => {
                             // We are at the top, create a new root node and push there.
-                            open_node = self.push_internal_level(alloc.clone());
+                            open_node = border.0.push_internal_level(alloc.clone());
                             break;
                         }
                     }
@@ -87,8 +122,135 @@ pub fn bulk_push<I, A: Allocator + Clone>(&mut self, iter: I, length: &mut usize
             // the appended elements even if advancing the iterator panicks.
             *length += 1;
         }
+        drop(border);
         self.fix_right_border_of_plentiful();
     }
+
+    /// Appends all key-value pairs of another tree, whose keys must all be
+    /// greater than the keys in this tree, in time logarithmic in the size of
+    /// both trees.
+    ///
+    /// If `self` and `right` respect all `BTreeMap` tree invariants, then
+    /// `self` will respect those invariants too.
+    pub fn append_disjoint<A: Allocator + Clone>(&mut self, mut right: Self, alloc: A) {
+        // SAFETY: An empty tree is a single, empty leaf, which we drop here.
+        if right.len() == 0 {
+            unsafe { right.into_dying().deallocate_and_ascend(alloc) };
+            return;
+        }
+        if self.len() == 0 {
+            let left = mem::replace(self, right);
+            unsafe { left.into_dying().deallocate_and_ascend(alloc) };
+            return;
+        }
+
+        // Take out the greatest pair, to separate both trees in a common parent.
+        let (sep_key, sep_val) = {
+            let mut emptied_internal_root = false;
+            let last_kv = self.borrow_mut().last_leaf_edge().left_kv().ok().unwrap();
+            let (kv, _) = last_kv
+                .forget_node_type()
+                .remove_kv_tracking(|| emptied_internal_root = true, alloc.clone());
+            if emptied_internal_root {
+                self.pop_internal_level(alloc.clone());
+            }
+            kv
+        };
+
+        let (left_height, right_height) = (self.height(), right.height());
+        if left_height == right_height {
+            let mut root = self.push_internal_level(alloc.clone());
+            root.push(sep_key, sep_val, right);
+            let mut parent_kv = root.first_kv().consider_for_balancing();
+            let left_len = parent_kv.left_child_len();
+            let right_len = parent_kv.right_child_len();
+            if parent_kv.can_merge() {
+                parent_kv.merge_tracking_parent(alloc.clone());
+                self.pop_internal_level(alloc);
+            } else if left_len < MIN_LEN {
+                parent_kv.bulk_steal_right(MIN_LEN - left_len);
+            } else if right_len < MIN_LEN {
+                parent_kv.bulk_steal_left(MIN_LEN - right_len);
+            }
+            return;
+        }
+
+        // Hang the lower tree below the border of the higher tree, next to the
+        // separator, and stock up its root node if needed.
+        let lower;
+        let (root, mut dormant_root) = DormantMutRef::new(self);
+        if left_height > right_height {
+            lower = right.borrow_mut().dormant();
+            let mut node = root.borrow_mut();
+            while node.height() > right_height + 1 {
+                node = match node.force() {
+                    Internal(internal) => internal.last_edge().descend(),
+                    Leaf(_) => unreachable!(),
+                };
+            }
+            let parent = match node.force() {
+                Internal(internal) => internal,
+                Leaf(_) => unreachable!(),
+            };
+            parent.last_edge().insert_subtree_recursing(
+                sep_key,
+                sep_val,
+                right,
+                alloc.clone(),
+                |ins| {
+                    drop(ins.left);
+                    // SAFETY: Pushing a new root node doesn't invalidate
+                    // handles to existing nodes.
+                    let root = unsafe { dormant_root.reborrow() };
+                    root.push_internal_level(alloc.clone()).push(ins.kv.0, ins.kv.1, ins.right)
+                },
+            );
+        } else {
+            let mut left = mem::replace(root, right);
+            lower = left.borrow_mut().dormant();
+            let mut node = root.borrow_mut();
+            while node.height() > left_height + 1 {
+                node = match node.force() {
+                    Internal(internal) => internal.first_edge().descend(),
+                    Leaf(_) => unreachable!(),
+                };
+            }
+            let mut first_edge = match node.force() {
+                Internal(internal) => internal.first_edge(),
+                Leaf(_) => unreachable!(),
+            };
+            let old_first = first_edge.replace_subtree(left);
+            first_edge.insert_subtree_recursing(
+                sep_key,
+                sep_val,
+                old_first,
+                alloc.clone(),
+                |ins| {
+                    drop(ins.left);
+                    // SAFETY: Pushing a new root node doesn't invalidate
+                    // handles to existing nodes.
+                    let root = unsafe { dormant_root.reborrow() };
+                    root.push_internal_level(alloc.clone()).push(ins.kv.0, ins.kv.1, ins.right)
+                },
+            );
+        }
+        // SAFETY: The lower tree's root node is part of the tree now, and
+        // we're done with all other references into the tree.
+        unsafe { lower.awaken() }.fix_node_and_affected_ancestors(alloc.clone());
+        let root = unsafe { dormant_root.awaken() };
+        root.fix_top(alloc);
+    }
+}
+
+/// Counts the pairs in the subtrees of the right border, once `bulk_push`
+/// stops pushing pairs into them.
+struct CorrectRightBorderOnDrop<'a, K, V>(&'a mut Root<K, V>);
+
+impl<K, V> Drop for CorrectRightBorderOnDrop<'_, K, V> {
+    fn drop(&mut self) {
+        let last_leaf = self.0.borrow_mut().last_leaf_edge().into_node();
+        last_leaf.forget_type().correct_ancestors_subtree_len();
+    }
 }
 
 // An iterator for merging two sorted sequences into one
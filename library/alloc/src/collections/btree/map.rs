@@ -9,15 +9,17 @@
 use core::ops::{Bound, Index, RangeBounds};
 use core::ptr;
 
+use super::append::IsGlobal;
 use super::borrow::DormantMutRef;
 use super::dedup_sorted_iter::DedupSortedIter;
 use super::navigate::{LazyLeafRange, LeafRange};
 use super::node::ForceResult::*;
 use super::node::{self, marker, Handle, NodeRef, Root};
-use super::search::SearchBound;
+use super::search::{check_range_bounds, SearchBound};
 use super::search::SearchResult::*;
 use super::set_val::SetValZST;
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 use crate::vec::Vec;
 
 mod entry;
@@ -994,6 +996,40 @@ pub fn insert(&mut self, key: K, value: V) -> Option<V>
         }
     }
 
+    /// Inserts a key-value pair into the map, or returns an error if
+    /// allocating the nodes the insertion needs failed.
+    ///
+    /// This is the fallible counterpart of [`insert`](BTreeMap::insert). The
+    /// map is left unchanged, and the key and value are dropped, if an error
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the allocator reports a failure, then an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// assert_eq!(map.try_insert_alloc(37, "a")?, None);
+    /// assert_eq!(map.try_insert_alloc(37, "b")?, Some("a"));
+    /// assert_eq!(map[&37], "b");
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert_alloc(&mut self, key: K, value: V) -> Result<Option<V>, TryReserveError>
+    where
+        K: Ord,
+    {
+        match self.entry(key) {
+            Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Vacant(entry) => entry.try_insert_alloc(value).map(|_| None),
+        }
+    }
+
     /// Tries to insert a key-value pair into the map, and returns
     /// a mutable reference to the value in the entry.
     ///
@@ -1163,6 +1199,28 @@ pub fn append(&mut self, other: &mut Self)
             return;
         }
 
+        // If all keys of one map are less than all keys of the other map,
+        // we can join both trees without visiting every element. This moves
+        // nodes between the maps, so it requires that each map's allocator
+        // can free the nodes of the other.
+        if <A as IsGlobal>::is_global() {
+            let alloc = (*self.alloc).clone();
+            if self.last_key_value().unwrap().0 < other.first_key_value().unwrap().0 {
+                let right_root = other.root.take().unwrap();
+                self.root.as_mut().unwrap().append_disjoint(right_root, alloc);
+                self.length += mem::take(&mut other.length);
+                return;
+            }
+            if other.last_key_value().unwrap().0 < self.first_key_value().unwrap().0 {
+                let right_root = self.root.take().unwrap();
+                let mut root = other.root.take().unwrap();
+                root.append_disjoint(right_root, alloc);
+                self.root = Some(root);
+                self.length += mem::take(&mut other.length);
+                return;
+            }
+        }
+
         let self_iter = mem::replace(self, Self::new_in((*self.alloc).clone())).into_iter();
         let other_iter = mem::replace(other, Self::new_in((*self.alloc).clone())).into_iter();
         let root = self.root.get_or_insert_with(|| Root::new((*self.alloc).clone()));
@@ -1345,10 +1403,11 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
         let total_num = self.len();
         let left_root = self.root.as_mut().unwrap(); // unwrap succeeds because not empty
 
-        let right_root = left_root.split_off(key, (*self.alloc).clone());
+        let right_root = left_root.split_off(SearchBound::Included(key), (*self.alloc).clone());
 
-        let (new_left_len, right_len) = Root::calc_split_length(total_num, &left_root, &right_root);
-        self.length = new_left_len;
+        let right_len = right_root.subtree_len();
+        debug_assert_eq!(right_len, right_root.reborrow().calc_length());
+        self.length = total_num - right_len;
 
         BTreeMap {
             root: Some(right_root),
@@ -1358,6 +1417,126 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, key: &Q) -> Self
         }
     }
 
+    /// Returns the key-value pair at position `n` in the ascending order of
+    /// the keys, i.e., the pair with `n` smaller keys in the map, or `None`
+    /// if the map holds no more than `n` elements.
+    ///
+    /// This takes time logarithmic in the size of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(3, "c"), (1, "a"), (2, "b")]);
+    /// assert_eq!(map.nth(0), Some((&1, &"a")));
+    /// assert_eq!(map.nth(2), Some((&3, &"c")));
+    /// assert_eq!(map.nth(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn nth(&self, n: usize) -> Option<(&K, &V)> {
+        let root_node = self.root.as_ref()?.reborrow();
+        root_node.nth_kv(n).map(Handle::into_kv)
+    }
+
+    /// Returns the number of keys in the map that are less than the given key:
+    /// in an `Ok` if the map contains the key, in which case the number is the
+    /// position of the key in ascending order, and in an `Err` otherwise, in
+    /// which case the number is the position the key would get if inserted.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// This takes time logarithmic in the size of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::from([(10, "a"), (20, "b"), (30, "c")]);
+    /// assert_eq!(map.rank(&20), Ok(1));
+    /// assert_eq!(map.rank(&25), Err(2));
+    /// assert_eq!(map.rank(&5), Err(0));
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        match &self.root {
+            Some(root) => root.reborrow().rank(key),
+            None => Err(0),
+        }
+    }
+
+    /// Removes all elements with keys in the given range from the map, and
+    /// returns them in a new map. The range may be given in any of the forms
+    /// accepted by [`range`].
+    ///
+    /// This takes time logarithmic in the size of the map, regardless of the
+    /// number of elements removed.
+    ///
+    /// [`range`]: BTreeMap::range
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut a: BTreeMap<i32, char> = (1..=6).zip('a'..).collect();
+    /// let b = a.remove_range(2..5);
+    ///
+    /// assert_eq!(a.into_iter().collect::<Vec<_>>(), [(1, 'a'), (5, 'e'), (6, 'f')]);
+    /// assert_eq!(b.into_iter().collect::<Vec<_>>(), [(2, 'b'), (3, 'c'), (4, 'd')]);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn remove_range<T: ?Sized, R>(&mut self, range: R) -> Self
+    where
+        T: Ord,
+        K: Borrow<T> + Ord,
+        R: RangeBounds<T>,
+        A: Clone,
+    {
+        // Inlining these variables should be avoided, like in `search_tree_for_bifurcation`.
+        let (start, end) = (range.start_bound(), range.end_bound());
+        check_range_bounds::<T, V>(start, end);
+
+        let alloc = (*self.alloc).clone();
+        let left_root = match self.root.as_mut() {
+            Some(root) => root,
+            None => return Self::new_in(alloc),
+        };
+
+        // Cut out the range, then splice the part after the range back on.
+        let mut mid_root = left_root.split_off(SearchBound::from_range(start), alloc.clone());
+        let right_bound = match end {
+            Bound::Included(key) => SearchBound::Excluded(key),
+            Bound::Excluded(key) => SearchBound::Included(key),
+            Bound::Unbounded => SearchBound::AllExcluded,
+        };
+        let right_root = mid_root.split_off(right_bound, alloc.clone());
+        left_root.append_disjoint(right_root, alloc);
+
+        let mid_len = mid_root.subtree_len();
+        self.length -= mid_len;
+        BTreeMap {
+            root: Some(mid_root),
+            length: mid_len,
+            alloc: self.alloc.clone(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Creates an iterator that visits all elements (key-value pairs) in
     /// ascending key order and uses a closure to determine if an element should
     /// be removed. If the closure returns `true`, the element is removed from
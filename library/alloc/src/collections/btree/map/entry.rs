@@ -5,9 +5,10 @@
 use Entry::*;
 
 use super::super::borrow::DormantMutRef;
-use super::super::node::{marker, Handle, NodeRef};
+use super::super::node::{marker, Handle, NodeRef, NodeReserve};
 use super::BTreeMap;
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 
 /// A view into a single entry in a map, which may either be vacant or occupied.
 ///
@@ -348,12 +349,55 @@ pub fn into_key(self) -> K {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_confusables("push", "put")]
-    pub fn insert(mut self, value: V) -> &'a mut V {
+    pub fn insert(self, value: V) -> &'a mut V {
+        let alloc = self.alloc.clone();
+        self.insert_in(value, alloc)
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key, and returns a
+    /// mutable reference to it, or returns an error if allocating the nodes the
+    /// insertion needs failed.
+    ///
+    /// This is the fallible counterpart of [`insert`](VacantEntry::insert).
+    /// The map is left unchanged, and the key and value are dropped, if an
+    /// error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the allocator reports a failure, then an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeMap;
+    /// use std::collections::btree_map::Entry;
+    ///
+    /// let mut map: BTreeMap<&str, u32> = BTreeMap::new();
+    ///
+    /// if let Entry::Vacant(o) = map.entry("poneyland") {
+    ///     o.try_insert_alloc(37)?;
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert_alloc(self, value: V) -> Result<&'a mut V, TryReserveError> {
+        let (leaves, internals) = match &self.handle {
+            None => (1, 0),
+            Some(handle) => handle.reborrow().nodes_needed_for_insert(),
+        };
+        let reserve = NodeReserve::<K, V, A>::try_new(leaves, internals, self.alloc.clone())?;
+        Ok(self.insert_in(value, &reserve))
+    }
+
+    /// Body of `insert`, allocating any new nodes with `node_alloc`.
+    fn insert_in<B: Allocator + Clone>(mut self, value: V, node_alloc: B) -> &'a mut V {
         let out_ptr = match self.handle {
             None => {
                 // SAFETY: There is no tree yet so no reference to it exists.
                 let map = unsafe { self.dormant_map.awaken() };
-                let mut root = NodeRef::new_leaf(self.alloc.clone());
+                let mut root = NodeRef::new_leaf(node_alloc);
                 let val_ptr = root.borrow_mut().push(self.key, value);
                 map.root = Some(root.forget_type());
                 map.length = 1;
@@ -361,13 +405,13 @@ pub fn insert(mut self, value: V) -> &'a mut V {
             }
             Some(handle) => {
                 let new_handle =
-                    handle.insert_recursing(self.key, value, self.alloc.clone(), |ins| {
+                    handle.insert_recursing(self.key, value, node_alloc.clone(), |ins| {
                         drop(ins.left);
                         // SAFETY: Pushing a new root node doesn't invalidate
                         // handles to existing nodes.
                         let map = unsafe { self.dormant_map.reborrow() };
                         let root = map.root.as_mut().unwrap(); // same as ins.left
-                        root.push_internal_level(self.alloc).push(ins.kv.0, ins.kv.1, ins.right)
+                        root.push_internal_level(node_alloc).push(ins.kv.0, ins.kv.1, ins.right)
                     });
 
                 // Get the pointer to the value
@@ -51,6 +51,7 @@ fn check_invariants(&self) {
 
             // Check consistency of `length` with what navigation code encounters.
             assert_eq!(self.length, root_node.calc_length());
+            assert_eq!(self.length, root_node.assert_subtree_lens());
 
             // Lastly, check the invariant causing the least harm.
             root_node.assert_min_len(if root_node.height() > 0 { 1 } else { 0 });
@@ -2048,6 +2049,63 @@ fn test_try_insert() {
     assert_eq!(err.value, 200);
 }
 
+#[test]
+fn test_try_insert_alloc() {
+    use core::alloc::{AllocError, Layout};
+    use core::cell::Cell;
+    use core::ptr::NonNull;
+
+    // Hands out at most `spare` nodes at a time.
+    #[derive(Clone, Copy)]
+    struct Budget<'a> {
+        spare: &'a Cell<usize>,
+    }
+
+    unsafe impl Allocator for Budget<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            if self.spare.get() == 0 {
+                return Err(AllocError);
+            }
+            self.spare.set(self.spare.get() - 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.spare.set(self.spare.get() + 1);
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    let spare = Cell::new(0);
+    let mut map = BTreeMap::new_in(Budget { spare: &spare });
+    let mut failures = 0;
+    for i in 0..MIN_INSERTS_HEIGHT_2 {
+        loop {
+            match map.try_insert_alloc(i, i) {
+                Ok(old) => {
+                    assert_eq!(old, None);
+                    break;
+                }
+                Err(_) => {
+                    // A failed insertion leaves the map untouched.
+                    assert_eq!(map.len(), i);
+                    assert!(map.keys().copied().eq(0..i));
+                    failures += 1;
+                    spare.set(spare.get() + 1);
+                }
+            }
+        }
+    }
+    assert!(failures > 0);
+    assert_eq!(map.try_insert_alloc(0, 100), Ok(Some(0)));
+    assert_eq!(map[&0], 100);
+    assert_eq!(map.len(), MIN_INSERTS_HEIGHT_2);
+
+    // Every node that was allocated is given back.
+    drop(map);
+    assert_eq!(spare.get(), failures);
+}
+
 macro_rules! create_append_test {
     ($name:ident, $len:expr) => {
         #[test]
@@ -2229,6 +2287,199 @@ fn test_split_off_halfway() {
     }
 }
 
+#[test]
+fn test_nth_and_rank() {
+    let sizes = [0, 1, node::CAPACITY, MIN_INSERTS_HEIGHT_1, MIN_INSERTS_HEIGHT_2, 500];
+    for &len in &sizes {
+        let mut map = BTreeMap::from_iter((0..len).map(|i| (i * 2, i)));
+        map.check();
+        for i in 0..len {
+            assert_eq!(map.nth(i), Some((&(i * 2), &i)));
+            assert_eq!(map.rank(&(i * 2)), Ok(i));
+            assert_eq!(map.rank(&(i * 2 + 1)), Err(i + 1));
+        }
+        assert_eq!(map.nth(len), None);
+        assert_eq!(map.nth(usize::MAX), None);
+
+        // Removing elements keeps the subtree lengths up to date.
+        map.retain(|k, _| k % 3 != 0);
+        map.check();
+        for (i, (k, v)) in map.iter().enumerate() {
+            assert_eq!(map.nth(i), Some((k, v)));
+            assert_eq!(map.rank(k), Ok(i));
+        }
+        assert_eq!(map.nth(map.len()), None);
+    }
+}
+
+#[test]
+fn test_nth_and_rank_random() {
+    let mut data = rand_data(if cfg!(miri) { 200 } else { 1000 });
+    let mut map = BTreeMap::new();
+    for &(k, v) in &data {
+        map.insert(k, v);
+    }
+    map.check();
+    data.sort();
+    data.dedup_by_key(|kv| kv.0);
+    for (i, (k, v)) in data.iter().enumerate() {
+        assert_eq!(map.nth(i), Some((k, v)));
+        assert_eq!(map.rank(k), Ok(i));
+    }
+}
+
+#[test]
+fn test_remove_range() {
+    let len = MIN_INSERTS_HEIGHT_2 * 2;
+    let ranges = [(0, 0), (0, 1), (0, len), (1, len - 1), (10, 20), (30, 150), (len - 1, len)];
+    for &(start, end) in &ranges {
+        let mut map = BTreeMap::from_iter((0..len).map(|i| (i, i)));
+        let removed = map.remove_range(start..end);
+        map.check();
+        removed.check();
+        assert!(map.keys().copied().eq((0..start).chain(end..len)));
+        assert!(removed.keys().copied().eq(start..end));
+    }
+
+    let mut map = BTreeMap::from_iter((0..len).map(|i| (i, i)));
+    let removed = map.remove_range((Excluded(10), Included(100)));
+    map.check();
+    removed.check();
+    assert!(map.keys().copied().eq((0..=10).chain(101..len)));
+    assert!(removed.keys().copied().eq(11..=100));
+
+    let removed = map.remove_range(..);
+    map.check();
+    removed.check();
+    assert!(map.is_empty());
+    assert_eq!(removed.len(), len - 90);
+}
+
+#[test]
+fn test_remove_range_random() {
+    let data = rand_data(if cfg!(miri) { 200 } else { 1000 });
+    let mut rng = DeterministicRng::new();
+    let mut map = BTreeMap::from_iter(data.iter().copied());
+    while !map.is_empty() {
+        let (a, b) = (rng.next(), rng.next());
+        let (start, end) = if a <= b { (a, b) } else { (b, a) };
+        let expected: Vec<_> = map.range(start..end).map(|(&k, &v)| (k, v)).collect();
+        let len = map.len();
+        let removed = map.remove_range(start..end);
+        map.check();
+        removed.check();
+        assert!(removed.into_iter().eq(expected.iter().copied()));
+        assert_eq!(map.len(), len - expected.len());
+        assert_eq!(map.range(start..end).next(), None);
+        // Cut a random prefix to make sure the loop ends.
+        let first = *map.keys().next().unwrap_or(&0);
+        map.remove_range(..=first);
+        map.check();
+    }
+}
+
+#[test]
+#[should_panic(expected = "range start is greater than range end in BTreeMap")]
+fn test_remove_range_backwards() {
+    let mut map = BTreeMap::from([(1, ()), (2, ())]);
+    map.remove_range(2..1);
+}
+
+#[test]
+fn test_append_disjoint() {
+    let sizes = [0, 1, 5, node::CAPACITY, MIN_INSERTS_HEIGHT_1, 40, MIN_INSERTS_HEIGHT_2, 300];
+    for &left_len in &sizes {
+        for &right_len in &sizes {
+            let left = BTreeMap::from_iter((0..left_len).map(|i| (i, i)));
+            let right = BTreeMap::from_iter((left_len..left_len + right_len).map(|i| (i, i)));
+
+            // The keys of `b` come after the keys of `a`.
+            let (mut a, mut b) = (left.clone(), right.clone());
+            a.append(&mut b);
+            a.check();
+            b.check();
+            assert!(a.keys().copied().eq(0..left_len + right_len));
+            assert!(b.is_empty());
+
+            // The keys of `b` come before the keys of `a`.
+            let (mut a, mut b) = (right, left);
+            a.append(&mut b);
+            a.check();
+            b.check();
+            assert!(a.keys().copied().eq(0..left_len + right_len));
+            assert!(b.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_append_disjoint_alloc() {
+    use core::alloc::{AllocError, Layout};
+    use core::cell::Cell;
+    use core::ptr::NonNull;
+
+    // Counts the nodes it has handed out and not yet taken back.
+    #[derive(Clone, Copy)]
+    struct Counting<'a> {
+        live: &'a Cell<isize>,
+    }
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.live.set(self.live.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.live.set(self.live.get() - 1);
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    for (left_len, right_len) in [(MIN_INSERTS_HEIGHT_2, 5), (5, MIN_INSERTS_HEIGHT_2)] {
+        let (a_live, b_live) = (Cell::new(0), Cell::new(0));
+        let mut a = BTreeMap::new_in(Counting { live: &a_live });
+        let mut b = BTreeMap::new_in(Counting { live: &b_live });
+        a.extend((0..left_len).map(|i| (i, i)));
+        b.extend((left_len..left_len + right_len).map(|i| (i, i)));
+
+        // Nodes of `b` must not end up in `a`, whose allocator did not allocate them.
+        a.append(&mut b);
+        assert!(a.keys().copied().eq(0..left_len + right_len));
+        drop(b);
+        assert_eq!(b_live.get(), 0);
+        drop(a);
+        assert_eq!(a_live.get(), 0);
+    }
+}
+
+#[test]
+fn test_append_disjoint_ragged() {
+    // Insertion in random order and removal create trees with nodes of all lengths.
+    let mut rng = DeterministicRng::new();
+    let rounds = if cfg!(miri) { 5 } else { 50 };
+    for _ in 0..rounds {
+        let left_len = rng.next() % 400;
+        let right_len = rng.next() % 400;
+        let mut left = BTreeMap::new();
+        let mut right = BTreeMap::new();
+        for _ in 0..left_len {
+            left.insert(rng.next() % 1000, ());
+        }
+        for _ in 0..right_len {
+            right.insert(1000 + rng.next() % 1000, ());
+        }
+        left.retain(|_, _| rng.next() % 4 != 0);
+        let expected: Vec<_> = left.keys().chain(right.keys()).copied().collect();
+        left.append(&mut right);
+        left.check();
+        assert!(left.keys().eq(expected.iter()));
+        for (i, k) in expected.iter().enumerate() {
+            assert_eq!(left.rank(k), Ok(i));
+        }
+    }
+}
+
 #[test]
 fn test_split_off_large_random_sorted() {
     // Miri is too slow
@@ -7,6 +7,7 @@
 mod merge_iter;
 mod navigate;
 mod node;
+mod rank;
 mod remove;
 mod search;
 pub mod set;
@@ -31,13 +31,15 @@
 //   since leaf edges are empty and need no data representation. In an internal node,
 //   an edge both identifies a position and contains a pointer to a child node.
 
+use core::cell::Cell;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
 use core::ptr::{self, NonNull};
 use core::slice::SliceIndex;
 
-use crate::alloc::{Allocator, Layout};
+use crate::alloc::{AllocError, Allocator, Layout};
 use crate::boxed::Box;
+use crate::collections::{TryReserveError, TryReserveErrorKind};
 
 const B: usize = 6;
 pub const CAPACITY: usize = 2 * B - 1;
@@ -101,6 +103,10 @@ struct InternalNode<K, V> {
     /// initialized and valid, except that near the end, while the tree is held
     /// through borrow type `Dying`, some of these pointers are dangling.
     edges: [MaybeUninit<BoxedNode<K, V>>; 2 * B],
+
+    /// The number of key-value pairs in the subtree headed by this node, i.e.,
+    /// in this node and in all of its descendants.
+    subtree_len: usize,
 }
 
 impl<K, V> InternalNode<K, V> {
@@ -113,8 +119,10 @@ impl<K, V> InternalNode<K, V> {
     unsafe fn new<A: Allocator + Clone>(alloc: A) -> Box<Self, A> {
         unsafe {
             let mut node = Box::<Self, _>::new_uninit_in(alloc);
-            // We only need to initialize the data; the edges are MaybeUninit.
+            // We only need to initialize the data and the subtree length;
+            // the edges are MaybeUninit.
             LeafNode::init(ptr::addr_of_mut!((*node.as_mut_ptr()).data));
+            ptr::addr_of_mut!((*node.as_mut_ptr()).subtree_len).write(0);
             node.assume_init()
         }
     }
@@ -226,6 +234,7 @@ impl<K, V> NodeRef<marker::Owned, K, V, marker::Internal> {
     fn new_internal<A: Allocator + Clone>(child: Root<K, V>, alloc: A) -> Self {
         let mut new_node = unsafe { InternalNode::new(alloc) };
         new_node.edges[0].write(child.node);
+        new_node.subtree_len = child.subtree_len();
         unsafe { NodeRef::from_new_internal(new_node, child.height + 1) }
     }
 
@@ -280,6 +289,18 @@ pub fn len(&self) -> usize {
         unsafe { usize::from((*Self::as_leaf_ptr(self)).len) }
     }
 
+    /// Finds the number of key-value pairs in the subtree headed by this node.
+    /// For a leaf, that is simply the length of the node.
+    pub fn subtree_len(&self) -> usize {
+        if self.height == 0 {
+            self.len()
+        } else {
+            // SAFETY: a node of non-zero height is internal. Like `len`, we only
+            // access the one field, so as not to invalidate references to values.
+            unsafe { (*(self.node.as_ptr() as *const InternalNode<K, V>)).subtree_len }
+        }
+    }
+
     /// Returns the number of levels that the node and leaves are apart. Zero
     /// height means the node is a leaf itself. If you picture trees with the
     /// root on top, the number says at which elevation the node appears.
@@ -557,6 +578,48 @@ fn correct_all_childrens_parent_links(&mut self) {
         let len = self.len();
         unsafe { self.correct_childrens_parent_links(0..=len) };
     }
+
+    /// Recomputes the subtree length from the length of the node and the
+    /// subtree lengths of its children, after edges moved in or out of the node.
+    pub fn correct_subtree_len(&mut self) {
+        let len = self.len();
+        let mut subtree_len = len;
+        for i in 0..=len {
+            subtree_len += unsafe { Handle::new_edge(self.reborrow(), i) }.descend().subtree_len();
+        }
+        self.as_internal_mut().subtree_len = subtree_len;
+    }
+
+    /// Adjusts the subtree length for pairs added to or removed from the subtree.
+    fn add_to_subtree_len(&mut self, delta: isize) {
+        let subtree_len = &mut self.as_internal_mut().subtree_len;
+        *subtree_len = subtree_len.wrapping_add_signed(delta);
+    }
+}
+
+impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
+    /// Adjusts the subtree length of every ancestor of the node for pairs added
+    /// to or removed from the subtree headed by the node.
+    pub fn add_to_ancestors_subtree_len(self, delta: isize) {
+        let mut node = self;
+        while let Ok(parent) = node.ascend() {
+            let mut parent = parent.into_node();
+            parent.add_to_subtree_len(delta);
+            node = parent.forget_type();
+        }
+    }
+
+    /// Recomputes the subtree length of every ancestor of the node, bottom up,
+    /// after pairs and edges moved around in the subtree headed by the node
+    /// and in its ancestors.
+    pub fn correct_ancestors_subtree_len(self) {
+        let mut node = self;
+        while let Ok(parent) = node.ascend() {
+            let mut parent = parent.into_node();
+            parent.correct_subtree_len();
+            node = parent.forget_type();
+        }
+    }
 }
 
 impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal> {
@@ -648,7 +711,8 @@ pub fn into_dying(self) -> NodeRef<marker::Dying, K, V, Type> {
 
 impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::Leaf> {
     /// Adds a key-value pair to the end of the node, and returns
-    /// a handle to the inserted value.
+    /// a handle to the inserted value. Leaves the subtree lengths of
+    /// any ancestors as they are.
     ///
     /// # Safety
     ///
@@ -686,6 +750,7 @@ impl<'a, K: 'a, V: 'a> NodeRef<marker::Mut<'a>, K, V, marker::Internal> {
     pub fn push(&mut self, key: K, val: V, edge: Root<K, V>) {
         assert!(edge.height == self.height - 1);
 
+        self.as_internal_mut().subtree_len += 1 + edge.subtree_len();
         let len = self.len_mut();
         let idx = usize::from(*len);
         assert!(idx < CAPACITY);
@@ -1022,6 +1087,9 @@ fn insert<A: Allocator + Clone>(
                     Handle::new_edge(result.right.borrow_mut(), insert_idx)
                 },
             };
+            // The halves of the split node counted their own children, but
+            // not the pair and the edge we are about to insert.
+            insertion_edge.node.add_to_subtree_len(1 + edge.subtree_len() as isize);
             insertion_edge.insert_fit(key, val, edge);
             Some(result)
         }
@@ -1037,40 +1105,213 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     /// The returned pointer points to the inserted value, which in the case of `SplitResult`
     /// is in the `left` or `right` tree.
     pub fn insert_recursing<A: Allocator + Clone>(
-        self,
+        mut self,
         key: K,
         value: V,
         alloc: A,
         split_root: impl FnOnce(SplitResult<'a, K, V, marker::LeafOrInternal>),
     ) -> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV> {
-        let (mut split, handle) = match self.insert(key, value, alloc.clone()) {
+        // Count the new pair in all ancestors up front; any ancestor that
+        // splits on the way up recounts its halves.
+        unsafe { self.reborrow_mut() }.into_node().forget_type().add_to_ancestors_subtree_len(1);
+
+        let (split, handle) = match self.insert(key, value, alloc.clone()) {
             // SAFETY: we have finished splitting and can now re-awaken the
             // handle to the inserted element.
             (None, handle) => return unsafe { handle.awaken() },
             (Some(split), handle) => (split.forget_node_type(), handle),
         };
+        split.insert_into_parent(alloc, split_root);
+        // SAFETY: we have finished splitting and can now re-awaken the
+        // handle to the inserted element.
+        unsafe { handle.awaken() }
+    }
+}
+
+impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Internal>, marker::Edge> {
+    /// Inserts a new key-value pair and a subtree that will go to the right of that new pair
+    /// between this edge and the key-value pair to the right of this edge. Like
+    /// `insert_recursing`, this splits nodes up to the root as needed, and hands a split
+    /// of the root node to `split_root`.
+    ///
+    /// The subtree must be exactly one level below this node and must not be
+    /// underfull, unless it is the only level below this node.
+    pub fn insert_subtree_recursing<A: Allocator + Clone>(
+        mut self,
+        key: K,
+        val: V,
+        subtree: Root<K, V>,
+        alloc: A,
+        split_root: impl FnOnce(SplitResult<'a, K, V, marker::LeafOrInternal>),
+    ) {
+        let delta = 1 + subtree.subtree_len() as isize;
+        self.node.add_to_subtree_len(delta);
+        unsafe { self.node.reborrow_mut() }.forget_type().add_to_ancestors_subtree_len(delta);
 
+        if let Some(split) = self.insert(key, val, subtree, alloc.clone()) {
+            split.forget_node_type().insert_into_parent(alloc, split_root);
+        }
+    }
+
+    /// Replaces the subtree this edge points to, and returns the old subtree,
+    /// detached from the tree.
+    pub fn replace_subtree(&mut self, subtree: Root<K, V>) -> Root<K, V> {
+        assert!(subtree.height == self.node.height - 1);
+
+        let old = unsafe { self.reborrow_mut() }.descend();
+        let delta = subtree.subtree_len() as isize - old.subtree_len() as isize;
+        let mut old: Root<K, V> =
+            NodeRef { height: old.height, node: old.node, _marker: PhantomData };
+        old.clear_parent_link();
+
+        unsafe {
+            self.node.edge_area_mut(self.idx).write(subtree.node);
+            self.reborrow_mut().correct_parent_link();
+        }
+        self.node.add_to_subtree_len(delta);
+        unsafe { self.node.reborrow_mut() }.forget_type().add_to_ancestors_subtree_len(delta);
+        old
+    }
+}
+
+impl<'a, K: 'a, V: 'a> SplitResult<'a, K, V, marker::LeafOrInternal> {
+    /// Inserts the split off key-value pair and right node into the parent of the
+    /// left node, splitting the parent and further ancestors as needed, until the
+    /// root is reached and handed to `split_root`.
+    fn insert_into_parent<A: Allocator + Clone>(
+        mut self,
+        alloc: A,
+        split_root: impl FnOnce(SplitResult<'a, K, V, marker::LeafOrInternal>),
+    ) {
         loop {
-            split = match split.left.ascend() {
+            self = match self.left.ascend() {
                 Ok(parent) => {
-                    match parent.insert(split.kv.0, split.kv.1, split.right, alloc.clone()) {
-                        // SAFETY: we have finished splitting and can now re-awaken the
-                        // handle to the inserted element.
-                        None => return unsafe { handle.awaken() },
+                    match parent.insert(self.kv.0, self.kv.1, self.right, alloc.clone()) {
+                        None => return,
                         Some(split) => split.forget_node_type(),
                     }
                 }
-                Err(root) => {
-                    split_root(SplitResult { left: root, ..split });
-                    // SAFETY: we have finished splitting and can now re-awaken the
-                    // handle to the inserted element.
-                    return unsafe { handle.awaken() };
-                }
+                Err(root) => return split_root(SplitResult { left: root, ..self }),
             };
         }
     }
 }
 
+impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Immut<'a>, K, V, marker::Leaf>, marker::Edge> {
+    /// Returns how many leaf nodes and internal nodes `insert_recursing` allocates
+    /// when inserting at this edge: one leaf if the leaf is full, one internal node
+    /// for every full ancestor above it, and one more if the root is split.
+    pub fn nodes_needed_for_insert(self) -> (usize, usize) {
+        let leaf = self.into_node();
+        if leaf.len() < CAPACITY {
+            return (0, 0);
+        }
+        let mut internals = 0;
+        let mut node = leaf.forget_type();
+        loop {
+            match node.ascend() {
+                Ok(parent) => {
+                    let parent = parent.into_node();
+                    if parent.len() < CAPACITY {
+                        return (1, internals);
+                    }
+                    internals += 1;
+                    node = parent.forget_type();
+                }
+                Err(_) => return (1, internals + 1),
+            }
+        }
+    }
+}
+
+/// Nodes allocated ahead of an insertion, so that a fallible insertion can fail
+/// before it starts modifying the tree.
+///
+/// The reserve hands its nodes out through the `Allocator` interface, which lets
+/// the insertion code run unchanged with `&NodeReserve` as its allocator. The nodes
+/// come from the tree's own allocator, which also frees them once they are part
+/// of the tree.
+pub struct NodeReserve<K, V, A: Allocator> {
+    alloc: A,
+    leaf: Cell<Option<NonNull<u8>>>,
+    /// Internal nodes, each storing the pointer to the next in its first word.
+    internals: Cell<Option<NonNull<u8>>>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K, V, A: Allocator> NodeReserve<K, V, A> {
+    const LEAF: Layout = Layout::new::<LeafNode<K, V>>();
+    const INTERNAL: Layout = Layout::new::<InternalNode<K, V>>();
+
+    /// Allocates `leaves` (at most one) leaf nodes and `internals` internal nodes.
+    pub fn try_new(leaves: usize, internals: usize, alloc: A) -> Result<Self, TryReserveError> {
+        debug_assert!(leaves <= 1);
+        let reserve = NodeReserve {
+            alloc,
+            leaf: Cell::new(None),
+            internals: Cell::new(None),
+            _marker: PhantomData,
+        };
+        // On failure, dropping `reserve` frees the nodes allocated so far.
+        if leaves > 0 {
+            reserve.leaf.set(Some(reserve.allocate_node(Self::LEAF)?));
+        }
+        for _ in 0..internals {
+            let node = reserve.allocate_node(Self::INTERNAL)?;
+            // SAFETY: an internal node is large and aligned enough to hold a pointer.
+            unsafe { node.cast::<Option<NonNull<u8>>>().write(reserve.internals.get()) };
+            reserve.internals.set(Some(node));
+        }
+        Ok(reserve)
+    }
+
+    fn allocate_node(&self, layout: Layout) -> Result<NonNull<u8>, TryReserveError> {
+        match self.alloc.allocate(layout) {
+            Ok(ptr) => Ok(ptr.cast()),
+            Err(AllocError) => {
+                Err(TryReserveErrorKind::AllocError { layout, non_exhaustive: () }.into())
+            }
+        }
+    }
+
+    fn pop_internal(&self) -> Option<NonNull<u8>> {
+        let node = self.internals.get()?;
+        // SAFETY: every node in the list stores the pointer to the next one.
+        self.internals.set(unsafe { node.cast::<Option<NonNull<u8>>>().read() });
+        Some(node)
+    }
+}
+
+unsafe impl<K, V, A: Allocator> Allocator for NodeReserve<K, V, A> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let node = if layout == Self::LEAF {
+            self.leaf.take()
+        } else if layout == Self::INTERNAL {
+            self.pop_internal()
+        } else {
+            None
+        };
+        node.map(|node| NonNull::slice_from_raw_parts(node, layout.size())).ok_or(AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { self.alloc.deallocate(ptr, layout) }
+    }
+}
+
+impl<K, V, A: Allocator> Drop for NodeReserve<K, V, A> {
+    fn drop(&mut self) {
+        unsafe {
+            if let Some(leaf) = self.leaf.take() {
+                self.alloc.deallocate(leaf, Self::LEAF);
+            }
+            while let Some(node) = self.pop_internal() {
+                self.alloc.deallocate(node, Self::INTERNAL);
+            }
+        }
+    }
+}
+
 impl<BorrowType: marker::BorrowType, K, V>
     Handle<NodeRef<BorrowType, K, V, marker::Internal>, marker::Edge>
 {
@@ -1264,7 +1505,9 @@ pub fn split<A: Allocator + Clone>(
             );
 
             let height = self.node.height;
-            let right = NodeRef::from_new_internal(new_node, height);
+            let mut right = NodeRef::from_new_internal(new_node, height);
+            self.node.correct_subtree_len();
+            right.borrow_mut().correct_subtree_len();
 
             SplitResult { left: self.node, kv, right }
         }
@@ -1408,6 +1651,7 @@ fn do_merge<
                 );
 
                 left_node.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
+                left_node.as_internal_mut().subtree_len += 1 + right_node.subtree_len();
 
                 alloc.deallocate(right_node.node.cast(), Layout::new::<InternalNode<K, V>>());
             } else {
@@ -1543,6 +1787,8 @@ pub fn bulk_steal_left(&mut self, count: usize) {
                     );
 
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+                    left.correct_subtree_len();
+                    right.correct_subtree_len();
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1607,6 +1853,8 @@ pub fn bulk_steal_right(&mut self, count: usize) {
 
                     left.correct_childrens_parent_links(old_left_len + 1..new_left_len + 1);
                     right.correct_childrens_parent_links(0..new_right_len + 1);
+                    left.correct_subtree_len();
+                    right.correct_subtree_len();
                 }
                 (ForceResult::Leaf(_), ForceResult::Leaf(_)) => {}
                 _ => unreachable!(),
@@ -1631,6 +1879,14 @@ pub fn forget_node_type(
     }
 }
 
+impl<BorrowType, K, V> Handle<NodeRef<BorrowType, K, V, marker::Internal>, marker::KV> {
+    pub fn forget_node_type(
+        self,
+    ) -> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV> {
+        unsafe { Handle::new_kv(self.node.forget_type(), self.idx) }
+    }
+}
+
 impl<BorrowType, K, V> Handle<NodeRef<BorrowType, K, V, marker::Leaf>, marker::KV> {
     pub fn forget_node_type(
         self,
@@ -1670,7 +1926,8 @@ pub unsafe fn cast_to_leaf_unchecked(
 
 impl<'a, K, V> Handle<NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>, marker::Edge> {
     /// Move the suffix after `self` from one node to another one. `right` must be empty.
-    /// The first edge of `right` remains unchanged.
+    /// The first edge of `right` remains unchanged. The subtree lengths of both nodes
+    /// are left for the caller to correct.
     pub fn move_suffix(
         &mut self,
         right: &mut NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>,
@@ -0,0 +1,94 @@
+use core::borrow::Borrow;
+
+use super::node::ForceResult::*;
+use super::node::{marker, Handle, NodeRef};
+use super::search::SearchResult::*;
+
+impl<BorrowType: marker::BorrowType, K, V> NodeRef<BorrowType, K, V, marker::LeafOrInternal> {
+    /// Looks up the key-value pair with `n` pairs before it, in the ascending
+    /// order of the (sub)tree headed by the node. Returns `None` if the subtree
+    /// holds no more than `n` pairs.
+    pub fn nth_kv(
+        self,
+        mut n: usize,
+    ) -> Option<Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV>> {
+        if n >= self.subtree_len() {
+            return None;
+        }
+        let mut node = self;
+        loop {
+            let internal = match node.force() {
+                Leaf(leaf) if n < leaf.len() => {
+                    return Some(unsafe { Handle::new_kv(leaf, n) }.forget_node_type());
+                }
+                Leaf(_) => return None,
+                Internal(internal) => internal,
+            };
+            // Skip over the children and key-value pairs before the pair we
+            // look for, until it is either in a child or in the node itself.
+            let len = internal.len();
+            let mut idx = 0;
+            loop {
+                let edge = unsafe { Handle::new_edge(internal.reborrow(), idx) };
+                let child_len = edge.descend().subtree_len();
+                if n < child_len {
+                    node = unsafe { Handle::new_edge(internal, idx) }.descend();
+                    break;
+                }
+                n -= child_len;
+                if idx == len {
+                    // Only if the subtree lengths are off.
+                    return None;
+                }
+                if n == 0 {
+                    return Some(unsafe { Handle::new_kv(internal, idx) }.forget_node_type());
+                }
+                n -= 1;
+                idx += 1;
+            }
+        }
+    }
+
+    /// Counts the key-value pairs in the (sub)tree headed by the node with a
+    /// key less than the given key. Returns that count in an `Ok` if the tree
+    /// contains the key, and in an `Err` otherwise.
+    ///
+    /// The result is meaningful only if the tree is ordered by key, like the tree
+    /// in a `BTreeMap` is.
+    pub fn rank<Q: ?Sized>(self, key: &Q) -> Result<usize, usize>
+    where
+        Q: Ord,
+        K: Borrow<Q>,
+    {
+        let mut node = self;
+        let mut rank = 0;
+        loop {
+            node = match node.search_node(key) {
+                // Everything left of the edge after the pair precedes the key,
+                // except for the pair itself.
+                Found(kv) => return Ok(rank + kv.right_edge().count_left() - 1),
+                GoDown(edge) => {
+                    rank += edge.count_left();
+                    match edge.force() {
+                        Leaf(_) => return Err(rank),
+                        Internal(edge) => edge.descend(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<BorrowType, K, V> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::Edge> {
+    /// Counts the key-value pairs in the node, and in the subtrees of its
+    /// children, that are to the left of this edge.
+    fn count_left(&self) -> usize {
+        let mut count = self.idx();
+        if let Internal(node) = self.reborrow().into_node().force() {
+            for idx in 0..self.idx() {
+                count += unsafe { Handle::new_edge(node, idx) }.descend().subtree_len();
+            }
+        }
+        count
+    }
+}
@@ -24,10 +24,13 @@ pub fn remove_kv_tracking<F: FnOnce(), A: Allocator + Clone>(
 
 impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::KV> {
     fn remove_leaf_kv<F: FnOnce(), A: Allocator + Clone>(
-        self,
+        mut self,
         handle_emptied_internal_root: F,
         alloc: A,
     ) -> ((K, V), Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge>) {
+        // Discount the pair in all ancestors up front; rebalancing below
+        // recounts any nodes whose children change.
+        unsafe { self.reborrow_mut() }.into_node().forget_type().add_to_ancestors_subtree_len(-1);
         let (old_kv, mut pos) = self.remove();
         let len = pos.reborrow().into_node().len();
         if len < MIN_LEN {
@@ -29,6 +29,33 @@ pub fn from_range(range_bound: Bound<T>) -> Self {
     }
 }
 
+/// As a diagnostic service, panics if the bounds of a range are impossible,
+/// with a message naming a map or a set depending on the value type `V`.
+pub fn check_range_bounds<Q: ?Sized + Ord, V>(start: Bound<&Q>, end: Bound<&Q>) {
+    // Determine if map or set is being searched
+    let is_set = <V as super::set_val::IsSetVal>::is_set_val();
+
+    match (start, end) {
+        (Bound::Excluded(s), Bound::Excluded(e)) if s == e => {
+            if is_set {
+                panic!("range start and end are equal and excluded in BTreeSet")
+            } else {
+                panic!("range start and end are equal and excluded in BTreeMap")
+            }
+        }
+        (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e))
+            if s > e =>
+        {
+            if is_set {
+                panic!("range start is greater than range end in BTreeSet")
+            } else {
+                panic!("range start is greater than range end in BTreeMap")
+            }
+        }
+        _ => {}
+    }
+}
+
 pub enum SearchResult<BorrowType, K, V, FoundType, GoDownType> {
     Found(Handle<NodeRef<BorrowType, K, V, FoundType>, marker::KV>),
     GoDown(Handle<NodeRef<BorrowType, K, V, GoDownType>, marker::Edge>),
@@ -98,31 +125,10 @@ pub fn search_tree_for_bifurcation<'r, Q: ?Sized, R>(
         K: Borrow<Q>,
         R: RangeBounds<Q>,
     {
-        // Determine if map or set is being searched
-        let is_set = <V as super::set_val::IsSetVal>::is_set_val();
-
         // Inlining these variables should be avoided. We assume the bounds reported by `range`
         // remain the same, but an adversarial implementation could change between calls (#81138).
         let (start, end) = (range.start_bound(), range.end_bound());
-        match (start, end) {
-            (Bound::Excluded(s), Bound::Excluded(e)) if s == e => {
-                if is_set {
-                    panic!("range start and end are equal and excluded in BTreeSet")
-                } else {
-                    panic!("range start and end are equal and excluded in BTreeMap")
-                }
-            }
-            (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e))
-                if s > e =>
-            {
-                if is_set {
-                    panic!("range start is greater than range end in BTreeSet")
-                } else {
-                    panic!("range start is greater than range end in BTreeMap")
-                }
-            }
-            _ => {}
-        }
+        check_range_bounds::<Q, V>(start, end);
         let mut lower_bound = SearchBound::from_range(start);
         let mut upper_bound = SearchBound::from_range(end);
         loop {
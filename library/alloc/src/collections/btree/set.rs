@@ -12,6 +12,7 @@
 use super::set_val::SetValZST;
 use super::Recover;
 use crate::alloc::{Allocator, Global};
+use crate::collections::TryReserveError;
 use crate::vec::Vec;
 
 /// An ordered set based on a B-Tree.
@@ -905,6 +906,37 @@ pub fn insert(&mut self, value: T) -> bool
         self.map.insert(value, SetValZST::default()).is_none()
     }
 
+    /// Adds a value to the set, or returns an error if allocating the nodes
+    /// the insertion needs failed.
+    ///
+    /// This is the fallible counterpart of [`insert`](BTreeSet::insert). The
+    /// set is left unchanged, and the value is dropped, if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the allocator reports a failure, then an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::new();
+    ///
+    /// assert_eq!(set.try_insert_alloc(2)?, true);
+    /// assert_eq!(set.try_insert_alloc(2)?, false);
+    /// assert_eq!(set.len(), 1);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert_alloc(&mut self, value: T) -> Result<bool, TryReserveError>
+    where
+        T: Ord,
+    {
+        Ok(self.map.try_insert_alloc(value, SetValZST::default())?.is_none())
+    }
+
     /// Adds a value to the set, replacing the existing element, if any, that is
     /// equal to the value. Returns the replaced element.
     ///
@@ -1080,6 +1112,86 @@ pub fn split_off<Q: ?Sized + Ord>(&mut self, value: &Q) -> Self
         BTreeSet { map: self.map.split_off(value) }
     }
 
+    /// Returns the element at position `n` in ascending order, i.e., the
+    /// element with `n` smaller elements in the set, or `None` if the set
+    /// holds no more than `n` elements.
+    ///
+    /// This takes time logarithmic in the size of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([30, 10, 20]);
+    /// assert_eq!(set.nth(1), Some(&20));
+    /// assert_eq!(set.nth(3), None);
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        self.map.nth(n).map(|(k, _)| k)
+    }
+
+    /// Returns the number of elements in the set that are less than the given
+    /// value: in an `Ok` if the set contains the value, and in an `Err`
+    /// otherwise. See [`BTreeMap::rank`] for details.
+    ///
+    /// This takes time logarithmic in the size of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let set = BTreeSet::from([10, 20, 30]);
+    /// assert_eq!(set.rank(&20), Ok(1));
+    /// assert_eq!(set.rank(&25), Err(2));
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn rank<Q: ?Sized>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord,
+    {
+        self.map.rank(value)
+    }
+
+    /// Removes all elements in the given range from the set, and returns them
+    /// in a new set.
+    ///
+    /// This takes time logarithmic in the size of the set, regardless of the
+    /// number of elements removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if range `start == end` and both bounds are `Excluded`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_order_statistics)]
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut a: BTreeSet<i32> = (1..=6).collect();
+    /// let b = a.remove_range(2..5);
+    ///
+    /// assert!(a.into_iter().eq([1, 5, 6]));
+    /// assert!(b.into_iter().eq([2, 3, 4]));
+    /// ```
+    #[unstable(feature = "btree_order_statistics", issue = "none")]
+    pub fn remove_range<K: ?Sized, R>(&mut self, range: R) -> Self
+    where
+        K: Ord,
+        T: Borrow<K> + Ord,
+        R: RangeBounds<K>,
+        A: Clone,
+    {
+        BTreeSet { map: self.map.remove_range(range) }
+    }
+
     /// Creates an iterator that visits all elements in ascending order and
     /// uses a closure to determine if an element should be removed.
     ///
@@ -3,35 +3,20 @@
 
 use super::node::ForceResult::*;
 use super::node::Root;
-use super::search::SearchResult::*;
+use super::search::SearchBound;
 
 impl<K, V> Root<K, V> {
-    /// Calculates the length of both trees that result from splitting up
-    /// a given number of distinct key-value pairs.
-    pub fn calc_split_length(
-        total_num: usize,
-        root_a: &Root<K, V>,
-        root_b: &Root<K, V>,
-    ) -> (usize, usize) {
-        let (length_a, length_b);
-        if root_a.height() < root_b.height() {
-            length_a = root_a.reborrow().calc_length();
-            length_b = total_num - length_a;
-            debug_assert_eq!(length_b, root_b.reborrow().calc_length());
-        } else {
-            length_b = root_b.reborrow().calc_length();
-            length_a = total_num - length_b;
-            debug_assert_eq!(length_a, root_a.reborrow().calc_length());
-        }
-        (length_a, length_b)
-    }
-
-    /// Split off a tree with key-value pairs at and after the given key.
+    /// Split off a tree with the key-value pairs above the given lower bound,
+    /// e.g. with the pairs at and after a key for `SearchBound::Included(key)`.
     /// The result is meaningful only if the tree is ordered by key,
     /// and if the ordering of `Q` corresponds to that of `K`.
     /// If `self` respects all `BTreeMap` tree invariants, then both
     /// `self` and the returned tree will respect those invariants.
-    pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(&mut self, key: &Q, alloc: A) -> Self
+    pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(
+        &mut self,
+        bound: SearchBound<&Q>,
+        alloc: A,
+    ) -> Self
     where
         K: Borrow<Q>,
     {
@@ -39,13 +24,10 @@ pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(&mut self, key: &Q, allo
         let mut right_root = Root::new_pillar(left_root.height(), alloc.clone());
         let mut left_node = left_root.borrow_mut();
         let mut right_node = right_root.borrow_mut();
+        let mut bound = bound;
 
         loop {
-            let mut split_edge = match left_node.search_node(key) {
-                // key is going to the right tree
-                Found(kv) => kv.left_edge(),
-                GoDown(edge) => edge,
-            };
+            let (mut split_edge, child_bound) = left_node.find_lower_bound_edge(bound);
 
             split_edge.move_suffix(&mut right_node);
 
@@ -53,8 +35,15 @@ pub fn split_off<Q: ?Sized + Ord, A: Allocator + Clone>(&mut self, key: &Q, allo
                 (Internal(edge), Internal(node)) => {
                     left_node = edge.descend();
                     right_node = node.first_edge().descend();
+                    bound = child_bound;
+                }
+                (Leaf(left_edge), Leaf(right_leaf)) => {
+                    // Moving suffixes left the subtree lengths on both borders
+                    // stale; recount them bottom up before fixing the borders.
+                    left_edge.into_node().forget_type().correct_ancestors_subtree_len();
+                    right_leaf.forget_type().correct_ancestors_subtree_len();
+                    break;
                 }
-                (Leaf(_), Leaf(_)) => break,
                 _ => unreachable!(),
             }
         }
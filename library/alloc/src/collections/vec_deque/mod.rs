@@ -3054,3 +3054,60 @@ fn from(mut other: VecDeque<T, A>) -> Self {
         deq
     }
 }
+
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::ErrorType for VecDeque<u8, A> {
+    type Error = core::io::ErrorKind;
+}
+
+/// Read is implemented for `VecDeque<u8>` by consuming bytes from the front of the `VecDeque`.
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::Read for VecDeque<u8, A> {
+    /// Fill `buf` with the contents of the "front" slice as returned by
+    /// [`as_slices`][`VecDeque::as_slices`]. If the contained byte slices of the `VecDeque` are
+    /// discontiguous, multiple calls to `read` will be needed to read the entire content.
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, core::io::ErrorKind> {
+        let (ref mut front, _) = self.as_slices();
+        let n = core::io::Read::read(front, buf)?;
+        self.drain(..n);
+        Ok(n)
+    }
+}
+
+/// BufRead is implemented for `VecDeque<u8>` by reading bytes from the front of the `VecDeque`.
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::BufRead for VecDeque<u8, A> {
+    #[inline]
+    fn fill_buf(&mut self) -> Result<&[u8], core::io::ErrorKind> {
+        let (front, _) = self.as_slices();
+        Ok(front)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.drain(..amt);
+    }
+}
+
+/// Write is implemented for `VecDeque<u8>` by appending to the `VecDeque`, growing it as needed.
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::Write for VecDeque<u8, A> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, core::io::ErrorKind> {
+        self.extend(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), core::io::ErrorKind> {
+        self.extend(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), core::io::ErrorKind> {
+        Ok(())
+    }
+}
@@ -582,6 +582,8 @@
 pub use core::fmt::Error;
 #[unstable(feature = "debug_closure_helpers", issue = "117729")]
 pub use core::fmt::{from_fn, FromFn};
+#[unstable(feature = "fmt_display_width", issue = "none")]
+pub use core::fmt::DisplayWidth;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{write, Arguments};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -594,6 +596,8 @@
 pub use core::fmt::{Formatter, Result, Write};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerExp, UpperExp};
+#[unstable(feature = "hex_float", issue = "none")]
+pub use core::fmt::{LowerHexFloat, UpperHexFloat};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerHex, Pointer, UpperHex};
 
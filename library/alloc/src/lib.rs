@@ -117,6 +117,8 @@
 #![feature(const_refs_to_cell)]
 #![feature(const_size_of_val)]
 #![feature(core_intrinsics)]
+#![feature(core_io)]
+#![feature(core_io_internals)]
 #![feature(deprecated_suggestion)]
 #![feature(deref_pure_trait)]
 #![feature(dispatch_from_dyn)]
@@ -157,6 +159,8 @@
 #![feature(try_with_capacity)]
 #![feature(tuple_trait)]
 #![feature(unicode_internals)]
+#![feature(unicode_normalization)]
+#![feature(unicode_segmentation)]
 #![feature(unsize)]
 #![feature(unwrap_infallible)]
 #![feature(vec_pop_if)]
@@ -166,6 +170,7 @@
 // tidy-alphabetical-start
 #![cfg_attr(not(test), feature(coroutine_trait))]
 #![cfg_attr(test, feature(panic_update_hook))]
+#![cfg_attr(test, feature(sort_floats))]
 #![cfg_attr(test, feature(test))]
 #![feature(allocator_internals)]
 #![feature(allow_internal_unstable)]
@@ -210,6 +210,9 @@ impl<T> [T] {
     /// handled without allocation, medium sized slices allocate `self.len()` and beyond that it
     /// clamps at `self.len() / 2`.
     ///
+    /// Long slices of primitive integers are sorted with an LSD radix sort instead, which takes
+    /// *O*(*n*) time and allocates `self.len()`.
+    ///
     /// # Panics
     ///
     /// May panic if the implementation of [`Ord`] for `T` does not implement a [total order].
@@ -233,7 +236,7 @@ pub fn sort(&mut self)
     where
         T: Ord,
     {
-        stable_sort(self, T::lt);
+        sort::stable::sort_ord::<T, Vec<T>>(self);
     }
 
     /// Sorts the slice with a comparison function, preserving initial order of equal elements.
@@ -311,6 +314,10 @@ pub fn sort_by<F>(&mut self, mut compare: F)
     /// handled without allocation, medium sized slices allocate `self.len()` and beyond that it
     /// clamps at `self.len() / 2`.
     ///
+    /// If `K` is a primitive integer, long slices are sorted with an LSD radix sort instead, which
+    /// takes *O*(*m* \* *n*) time. It calls the key function exactly once per element, and
+    /// allocates temporary storage in a `Vec<(K, u32)>` twice the length of the slice.
+    ///
     /// # Panics
     ///
     /// May panic if the implementation of [`Ord`] for `K` does not implement a [total order].
@@ -330,12 +337,12 @@ pub fn sort_by<F>(&mut self, mut compare: F)
     #[rustc_allow_incoherent_impl]
     #[stable(feature = "slice_sort_by_key", since = "1.7.0")]
     #[inline]
-    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    pub fn sort_by_key<K, F>(&mut self, f: F)
     where
         F: FnMut(&T) -> K,
         K: Ord,
     {
-        stable_sort(self, |a, b| f(a).lt(&f(b)));
+        sort::stable::sort_by_key::<T, K, F, Vec<T>, Vec<(K, u32)>>(self, f);
     }
 
     /// Sorts the slice with a key extraction function, preserving initial order of equal elements.
@@ -684,6 +691,72 @@ pub fn to_ascii_lowercase(&self) -> Vec<u8> {
     }
 }
 
+#[cfg(not(test))]
+impl [f32] {
+    /// Sorts the slice of floats, allocating a buffer to sort long slices faster.
+    ///
+    /// This sort is *O*(*n* \* log(*n*)) worst-case and uses the ordering defined by
+    /// [`f32::total_cmp`]. That ordering only considers identical floats equal, so the result is
+    /// the same as with [`sort_floats`](slice::sort_floats).
+    ///
+    /// # Current implementation
+    ///
+    /// Long slices are sorted with an LSD radix sort, which takes *O*(*n*) time and allocates
+    /// `self.len()`. Shorter slices are sorted like with [`sort`](slice::sort).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sort_floats)]
+    /// let mut v = [2.6, -5e-8, f32::NAN, 8.29, f32::INFINITY, -1.0, 0.0, -f32::INFINITY, -0.0];
+    ///
+    /// v.sort_floats_buffered();
+    /// let sorted = [-f32::INFINITY, -1.0, -5e-8, -0.0, 0.0, 2.6, 8.29, f32::INFINITY, f32::NAN];
+    /// assert_eq!(&v[..8], &sorted[..8]);
+    /// assert!(v[8].is_nan());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "sort_floats", issue = "93396")]
+    #[inline]
+    pub fn sort_floats_buffered(&mut self) {
+        sort::stable::sort_f32::<Vec<f32>>(self);
+    }
+}
+
+#[cfg(not(test))]
+impl [f64] {
+    /// Sorts the slice of floats, allocating a buffer to sort long slices faster.
+    ///
+    /// This sort is *O*(*n* \* log(*n*)) worst-case and uses the ordering defined by
+    /// [`f64::total_cmp`]. That ordering only considers identical floats equal, so the result is
+    /// the same as with [`sort_floats`](slice::sort_floats).
+    ///
+    /// # Current implementation
+    ///
+    /// Long slices are sorted with an LSD radix sort, which takes *O*(*n*) time and allocates
+    /// `self.len()`. Shorter slices are sorted like with [`sort`](slice::sort).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sort_floats)]
+    /// let mut v = [2.6, -5e-8, f64::NAN, 8.29, f64::INFINITY, -1.0, 0.0, -f64::INFINITY, -0.0];
+    ///
+    /// v.sort_floats_buffered();
+    /// let sorted = [-f64::INFINITY, -1.0, -5e-8, -0.0, 0.0, 2.6, 8.29, f64::INFINITY, f64::NAN];
+    /// assert_eq!(&v[..8], &sorted[..8]);
+    /// assert!(v[8].is_nan());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "sort_floats", issue = "93396")]
+    #[inline]
+    pub fn sort_floats_buffered(&mut self) {
+        sort::stable::sort_f64::<Vec<f64>>(self);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Extension traits for slices over specific kinds of data
 ////////////////////////////////////////////////////////////////////////////////
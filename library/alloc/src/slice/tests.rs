@@ -12,7 +12,7 @@
 
 use crate::borrow::ToOwned;
 use crate::rc::Rc;
-use crate::string::ToString;
+use crate::string::{String, ToString};
 use crate::test_helpers::test_rng;
 use crate::vec::Vec;
 
@@ -367,3 +367,162 @@ fn test_sort_stability() {
         }
     }
 }
+
+#[test]
+fn test_sort_radix() {
+    // Radix sort is used from a few hundred elements on, depending on the size of the key.
+    let lens: &[usize] = if cfg!(miri) { &[600] } else { &[600, 2_000, 20_000] };
+
+    macro_rules! check {
+        ($rng:expr, $($t:ty),*) => {$(
+            for &len in lens {
+                for shift in [0, 4, <$t>::BITS - 4] {
+                    let orig: Vec<$t> =
+                        (0..len).map(|_| $rng.gen::<$t>() >> shift).collect();
+
+                    let mut expected = orig.clone();
+                    expected.sort_by(|a, b| a.cmp(b));
+
+                    let mut v = orig.clone();
+                    v.sort();
+                    assert_eq!(v, expected);
+
+                    let mut v = orig.clone();
+                    v.sort_by_key(|&x| x);
+                    assert_eq!(v, expected);
+                }
+            }
+        )*};
+    }
+
+    let mut rng = test_rng();
+    check!(rng, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+}
+
+#[test]
+fn test_sort_by_key_radix() {
+    let len = if cfg!(miri) { 600 } else { 10_000 };
+    let mut rng = test_rng();
+
+    // Sort by a key that has many duplicates, and check stability with the original index.
+    let orig: Vec<(i32, usize)> = (0..len).map(|i| (rng.gen::<i32>() % 100, i)).collect();
+    let calls = Cell::new(0);
+    let mut v = orig.clone();
+    v.sort_by_key(|&(x, _)| {
+        calls.set(calls.get() + 1);
+        x
+    });
+    assert!(v.windows(2).all(|w| w[0] <= w[1]));
+    // The key function is called exactly once per element.
+    assert_eq!(calls.get(), len);
+
+    // Elements that own resources are moved, not duplicated.
+    let orig: Vec<(u16, String)> =
+        (0..len).map(|_| rng.gen::<u16>()).map(|x| (x, x.to_string())).collect();
+    let mut v = orig.clone();
+    v.sort_by_key(|(x, _)| *x);
+    let mut expected = orig.clone();
+    expected.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(v, expected);
+
+    // Zero-sized types are not reordered.
+    let mut v = vec![(); len];
+    v.sort_by_key(|_| rng.gen::<u32>());
+}
+
+#[test]
+fn test_sort_by_key_radix_key_types() {
+    // Above the radix sort threshold for every key type, including 8-byte keys.
+    let len = if cfg!(miri) { 600 } else { 5_000 };
+    let mut rng = test_rng();
+
+    macro_rules! check {
+        ($($t:ty),*) => {$(
+            // Few distinct keys, so that stability is observable through the index.
+            let orig: Vec<(u64, usize)> = (0..len).map(|i| (rng.gen::<u64>(), i)).collect();
+            let key = |&(x, _): &(u64, usize)| ((x % 37) as $t).wrapping_sub(18);
+
+            let mut expected = orig.clone();
+            expected.sort_by(|a, b| key(a).cmp(&key(b)));
+
+            let mut v = orig.clone();
+            v.sort_by_key(key);
+            assert_eq!(v, expected);
+        )*};
+    }
+
+    check!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+}
+
+#[test]
+fn test_sort_floats_buffered() {
+    // Above the radix sort threshold for both float types.
+    let len = if cfg!(miri) { 600 } else { 5_000 };
+    let mut rng = test_rng();
+
+    macro_rules! check {
+        ($($t:ident),*) => {$(
+            // In the order of `total_cmp`, which puts NaNs at the ends by their sign.
+            let special = [
+                -$t::NAN,
+                $t::NEG_INFINITY,
+                $t::MIN,
+                -$t::MIN_POSITIVE,
+                -0.0,
+                0.0,
+                $t::from_bits(1),
+                $t::MIN_POSITIVE,
+                $t::MAX,
+                $t::INFINITY,
+                $t::NAN,
+            ];
+            let bits = |v: &[$t]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+
+            let mut v = special.to_vec();
+            v.reverse();
+            v.sort_floats_buffered();
+            assert_eq!(bits(&v), bits(&special));
+
+            let orig: Vec<$t> = (0..len)
+                .map(|i| {
+                    if i % 4 == 0 {
+                        special[rng.gen_range(0..special.len())]
+                    } else {
+                        rng.gen::<$t>() * 2.0 - 1.0
+                    }
+                })
+                .collect();
+            let mut expected = orig.clone();
+            expected.sort_by($t::total_cmp);
+
+            let mut v = orig.clone();
+            v.sort_floats_buffered();
+            assert_eq!(bits(&v), bits(&expected));
+        )*};
+    }
+
+    check!(f32, f64);
+}
+
+#[test]
+#[cfg_attr(not(panic = "unwind"), ignore = "test requires unwinding support")]
+fn test_sort_by_key_radix_panic() {
+    let len = 1_000;
+    let orig: Vec<Rc<u32>> = (0..len).rev().map(Rc::new).collect();
+    let mut v = orig.clone();
+    let calls = Cell::new(0);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        v.sort_by_key(|x| {
+            calls.set(calls.get() + 1);
+            if calls.get() == len / 2 {
+                panic!("key function panicked");
+            }
+            **x
+        })
+    }));
+    assert!(result.is_err());
+    // A panic in the key function leaves the slice untouched.
+    assert_eq!(v, orig);
+    drop(v);
+    assert!(orig.iter().all(|x| Rc::strong_count(x) == 1));
+}
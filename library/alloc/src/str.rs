@@ -23,13 +23,17 @@
 #[unstable(feature = "str_from_raw_parts", issue = "119206")]
 pub use core::str::{from_raw_parts, from_raw_parts_mut};
 #[stable(feature = "rust1", since = "1.0.0")]
-pub use core::str::{from_utf8, from_utf8_mut, Bytes, CharIndices, Chars};
+pub use core::str::{Bytes, CharIndices, Chars, from_utf8, from_utf8_mut};
 #[stable(feature = "rust1", since = "1.0.0")]
-pub use core::str::{from_utf8_unchecked, from_utf8_unchecked_mut, ParseBoolError};
+pub use core::str::{ParseBoolError, from_utf8_unchecked, from_utf8_unchecked_mut};
 #[stable(feature = "str_escape", since = "1.34.0")]
 pub use core::str::{EscapeDebug, EscapeDefault, EscapeUnicode};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{FromStr, Utf8Error};
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub use core::str::IsNormalized;
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+pub use core::str::{Graphemes, WordBounds};
 #[allow(deprecated)]
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{Lines, LinesAny};
@@ -48,12 +52,20 @@
 use core::unicode::conversions;
 use core::{mem, ptr};
 
+use crate::alloc::Allocator;
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::slice::{Concat, Join, SliceIndex};
 use crate::string::String;
 use crate::vec::Vec;
 
+#[cfg(not(no_global_oom_handling))]
+mod normalize;
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub use self::normalize::{Decompositions, Recompositions};
+
 /// Note: `str` in `Concat<str>` is not meaningful here.
 /// This type parameter of the trait only exists to enable another impl.
 #[cfg(not(no_global_oom_handling))]
@@ -185,7 +197,7 @@ fn join_generic_copy<B, T, S>(slice: &[S], sep: &[T]) -> Vec<T>
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl Borrow<str> for String {
+impl<A: Allocator> Borrow<str> for String<A> {
     #[inline]
     fn borrow(&self) -> &str {
         &self[..]
@@ -193,7 +205,7 @@ fn borrow(&self) -> &str {
 }
 
 #[stable(feature = "string_borrow_mut", since = "1.36.0")]
-impl BorrowMut<str> for String {
+impl<A: Allocator> BorrowMut<str> for String<A> {
     #[inline]
     fn borrow_mut(&mut self) -> &mut str {
         &mut self[..]
@@ -594,6 +606,195 @@ pub fn to_ascii_lowercase(&self) -> String {
         s.make_ascii_lowercase();
         s
     }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode
+    /// Normalization Form D, the canonical decomposition.
+    ///
+    /// See [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+    /// for the definition of the normalization forms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert_eq!("caf\u{e9}".nfd().to_string(), "cafe\u{301}");
+    /// assert_eq!("\u{fb01}".nfd().to_string(), "\u{fb01}");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    #[inline]
+    pub fn nfd(&self) -> Decompositions<'_> {
+        Decompositions::new(self, false)
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode
+    /// Normalization Form KD, the compatibility decomposition.
+    ///
+    /// See [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/)
+    /// for the definition of the normalization forms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert_eq!("caf\u{e9}".nfkd().to_string(), "cafe\u{301}");
+    /// assert_eq!("\u{fb01}".nfkd().to_string(), "fi");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    #[inline]
+    pub fn nfkd(&self) -> Decompositions<'_> {
+        Decompositions::new(self, true)
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode
+    /// Normalization Form C, the canonical decomposition followed by
+    /// canonical composition.
+    ///
+    /// This is the form that most text is already in, and the one to compare
+    /// user-entered text in when compatibility characters should stay
+    /// distinct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert_eq!("cafe\u{301}".nfc().to_string(), "caf\u{e9}");
+    /// assert_eq!("\u{1100}\u{1161}".nfc().to_string(), "\u{ac00}");
+    /// assert!("cafe\u{301}".nfc().eq("caf\u{e9}".nfc()));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    #[inline]
+    pub fn nfc(&self) -> Recompositions<'_> {
+        Recompositions::new(self, false)
+    }
+
+    /// Returns an iterator over the [`char`]s of this string slice in Unicode
+    /// Normalization Form KC, the compatibility decomposition followed by
+    /// canonical composition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert_eq!("\u{fb01}n\u{e9}".nfkc().to_string(), "fin\u{e9}");
+    /// assert_eq!("\u{2460}".nfkc().to_string(), "1");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    #[inline]
+    pub fn nfkc(&self) -> Recompositions<'_> {
+        Recompositions::new(self, true)
+    }
+
+    /// Returns `true` if this string slice is in Unicode Normalization Form D.
+    ///
+    /// This answers most strings with the [quick check][str::is_nfd_quick],
+    /// and only normalizes the string if that is inconclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert!("cafe\u{301}".is_nfd());
+    /// assert!(!"caf\u{e9}".is_nfd());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfd(&self) -> bool {
+        match self.is_nfd_quick() {
+            IsNormalized::Yes => true,
+            IsNormalized::No => false,
+            IsNormalized::Maybe => self.chars().eq(self.nfd()),
+        }
+    }
+
+    /// Returns `true` if this string slice is in Unicode Normalization Form KD.
+    ///
+    /// This answers most strings with the [quick check][str::is_nfkd_quick],
+    /// and only normalizes the string if that is inconclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert!("fi".is_nfkd());
+    /// assert!(!"\u{fb01}".is_nfkd());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfkd(&self) -> bool {
+        match self.is_nfkd_quick() {
+            IsNormalized::Yes => true,
+            IsNormalized::No => false,
+            IsNormalized::Maybe => self.chars().eq(self.nfkd()),
+        }
+    }
+
+    /// Returns `true` if this string slice is in Unicode Normalization Form C.
+    ///
+    /// This answers most strings with the [quick check][str::is_nfc_quick],
+    /// and only normalizes the string if that is inconclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert!("caf\u{e9}".is_nfc());
+    /// assert!(!"cafe\u{301}".is_nfc());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfc(&self) -> bool {
+        match self.is_nfc_quick() {
+            IsNormalized::Yes => true,
+            IsNormalized::No => false,
+            IsNormalized::Maybe => self.chars().eq(self.nfc()),
+        }
+    }
+
+    /// Returns `true` if this string slice is in Unicode Normalization Form KC.
+    ///
+    /// This answers most strings with the [quick check][str::is_nfkc_quick],
+    /// and only normalizes the string if that is inconclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    ///
+    /// assert!("fin\u{e9}".is_nfkc());
+    /// assert!(!"\u{fb01}n\u{e9}".is_nfkc());
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfkc(&self) -> bool {
+        match self.is_nfkc_quick() {
+            IsNormalized::Yes => true,
+            IsNormalized::No => false,
+            IsNormalized::Maybe => self.chars().eq(self.nfkc()),
+        }
+    }
 }
 
 /// Converts a boxed slice of bytes to a boxed string slice without checking
@@ -0,0 +1,223 @@
+//! Iterators over the Unicode normalization forms of string slices, see
+//! [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/).
+
+use core::fmt::{self, Write};
+use core::iter::FusedIterator;
+use core::str::Chars;
+use core::unicode::normalization::{combining_class, compose, decompose};
+
+use crate::vec::Vec;
+
+/// An iterator over the [`char`]s of a string slice in Normalization Form D
+/// or KD.
+///
+/// This struct is created by the [`nfd`] and [`nfkd`] methods on [`str`].
+/// See their documentation for more.
+///
+/// [`nfd`]: str::nfd
+/// [`nfkd`]: str::nfkd
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub struct Decompositions<'a> {
+    chars: Chars<'a>,
+    compatibility: bool,
+    /// The decomposed characters along with their combining classes. The
+    /// characters before `ready` are in canonical order, the ones after it
+    /// are non-starters that may still have to be reordered.
+    buffer: Vec<(u8, char)>,
+    ready: usize,
+    /// The number of characters at the front of the buffer that were already
+    /// returned.
+    emitted: usize,
+}
+
+impl<'a> Decompositions<'a> {
+    pub(super) fn new(s: &'a str, compatibility: bool) -> Decompositions<'a> {
+        Decompositions { chars: s.chars(), compatibility, buffer: Vec::new(), ready: 0, emitted: 0 }
+    }
+
+    fn push(&mut self, c: char) {
+        let class = combining_class(c);
+        if class == 0 {
+            // Nothing reorders across a starter, so all before it is final.
+            self.sort_pending();
+            self.buffer.push((0, c));
+            self.ready = self.buffer.len();
+        } else {
+            self.buffer.push((class, c));
+        }
+    }
+
+    fn sort_pending(&mut self) {
+        // The canonical ordering algorithm is a stable sort by combining class.
+        self.buffer[self.ready..].sort_by_key(|&(class, _)| class);
+        self.ready = self.buffer.len();
+    }
+}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl Iterator for Decompositions<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if self.emitted < self.ready {
+                let (_, c) = self.buffer[self.emitted];
+                self.emitted += 1;
+                return Some(c);
+            }
+
+            self.buffer.drain(..self.emitted);
+            self.ready -= self.emitted;
+            self.emitted = 0;
+
+            match self.chars.next() {
+                Some(c) => {
+                    let compatibility = self.compatibility;
+                    decompose(c, compatibility, |c| self.push(c));
+                }
+                None if self.ready < self.buffer.len() => self.sort_pending(),
+                None => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, _) = self.chars.size_hint();
+        (lower.saturating_add(self.ready - self.emitted), None)
+    }
+}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl FusedIterator for Decompositions<'_> {}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl fmt::Display for Decompositions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|c| f.write_char(c))
+    }
+}
+
+/// An iterator over the [`char`]s of a string slice in Normalization Form C
+/// or KC.
+///
+/// This struct is created by the [`nfc`] and [`nfkc`] methods on [`str`].
+/// See their documentation for more.
+///
+/// [`nfc`]: str::nfc
+/// [`nfkc`]: str::nfkc
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub struct Recompositions<'a> {
+    decompositions: Decompositions<'a>,
+    state: RecompositionState,
+    /// The starter that the following characters are composed with.
+    starter: Option<char>,
+    /// The characters after the starter that did not compose with it.
+    buffer: Vec<char>,
+    /// The combining class of the last character in `buffer`, which blocks
+    /// the composition of characters with a class that is not higher.
+    last_class: Option<u8>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum RecompositionState {
+    Composing,
+    /// The starter was returned, what remains is to return the buffer from
+    /// the given index before composing the next starter.
+    Purging(usize),
+    /// The input is exhausted, what remains is to return the buffer from the
+    /// given index.
+    Finished(usize),
+}
+
+impl<'a> Recompositions<'a> {
+    pub(super) fn new(s: &'a str, compatibility: bool) -> Recompositions<'a> {
+        Recompositions {
+            decompositions: Decompositions::new(s, compatibility),
+            state: RecompositionState::Composing,
+            starter: None,
+            buffer: Vec::new(),
+            last_class: None,
+        }
+    }
+}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl Iterator for Recompositions<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        use RecompositionState::*;
+
+        loop {
+            match self.state {
+                Composing => {
+                    while let Some(c) = self.decompositions.next() {
+                        let class = combining_class(c);
+                        let Some(starter) = self.starter else {
+                            // Non-starters at the very beginning have nothing
+                            // to compose with.
+                            if class != 0 {
+                                return Some(c);
+                            }
+                            self.starter = Some(c);
+                            continue;
+                        };
+
+                        let blocked = self.last_class.is_some_and(|last| last >= class);
+                        if !blocked {
+                            if let Some(composed) = compose(starter, c) {
+                                self.starter = Some(composed);
+                                continue;
+                            }
+                        }
+
+                        if class == 0 {
+                            // A new starter, nothing after it can compose with
+                            // the current one anymore.
+                            self.starter = Some(c);
+                            self.last_class = None;
+                            self.state = Purging(0);
+                            return Some(starter);
+                        }
+                        self.buffer.push(c);
+                        self.last_class = Some(class);
+                    }
+
+                    self.state = Finished(0);
+                    if let Some(starter) = self.starter.take() {
+                        return Some(starter);
+                    }
+                }
+                Purging(next) => match self.buffer.get(next) {
+                    Some(&c) => {
+                        self.state = Purging(next + 1);
+                        return Some(c);
+                    }
+                    None => {
+                        self.buffer.clear();
+                        self.state = Composing;
+                    }
+                },
+                Finished(next) => {
+                    let c = self.buffer.get(next).copied();
+                    self.state = Finished(next + 1);
+                    return c;
+                }
+            }
+        }
+    }
+}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl FusedIterator for Recompositions<'_> {}
+
+#[unstable(feature = "unicode_normalization", issue = "none")]
+impl fmt::Display for Recompositions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|c| f.write_char(c))
+    }
+}
@@ -54,17 +54,16 @@
 use core::ops::Bound::{Excluded, Included, Unbounded};
 use core::ops::{self, Range, RangeBounds};
 use core::str::pattern::Pattern;
-use core::{fmt, hash, ptr, slice};
+use core::{cmp, fmt, hash, ptr, slice};
 
-#[cfg(not(no_global_oom_handling))]
-use crate::alloc::Allocator;
+use crate::alloc::{Allocator, Global};
 #[cfg(not(no_global_oom_handling))]
 use crate::borrow::{Cow, ToOwned};
 use crate::boxed::Box;
 use crate::collections::TryReserveError;
 use crate::str::{self, from_utf8_unchecked_mut, Chars, Utf8Error};
 #[cfg(not(no_global_oom_handling))]
-use crate::str::{from_boxed_utf8_unchecked, FromStr};
+use crate::str::FromStr;
 use crate::vec::Vec;
 
 /// A UTF-8–encoded, growable string.
@@ -356,11 +355,37 @@
 /// [Deref]: core::ops::Deref "ops::Deref"
 /// [`Deref`]: core::ops::Deref "ops::Deref"
 /// [`as_str()`]: String::as_str
-#[derive(PartialEq, PartialOrd, Eq, Ord)]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg_attr(not(test), lang = "String")]
-pub struct String {
-    vec: Vec<u8>,
+pub struct String<#[unstable(feature = "allocator_api", issue = "32838")] A: Allocator = Global> {
+    vec: Vec<u8, A>,
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<A: Allocator> PartialEq for String<A> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.vec == other.vec
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<A: Allocator> Eq for String<A> {}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<A: Allocator> PartialOrd for String<A> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<A: Allocator> Ord for String<A> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.vec.cmp(&other.vec)
+    }
 }
 
 /// A possible error value when converting a `String` from a UTF-8 byte vector.
@@ -991,6 +1016,121 @@ pub unsafe fn from_raw_parts(buf: *mut u8, length: usize, capacity: usize) -> St
     pub unsafe fn from_utf8_unchecked(bytes: Vec<u8>) -> String {
         String { vec: bytes }
     }
+}
+
+impl<A: Allocator> String<A> {
+    /// Creates a new empty `String` in the provided allocator.
+    ///
+    /// The `String` will not allocate until bytes are pushed onto it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    ///
+    /// let mut s = String::new_in(System);
+    /// s.push_str("hello");
+    /// assert_eq!(s.as_str(), "hello");
+    /// ```
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        String { vec: Vec::new_in(alloc) }
+    }
+
+    /// Creates a new empty `String` with at least the specified capacity in
+    /// the provided allocator.
+    ///
+    /// See [`String::with_capacity`] for how the capacity behaves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(allocator_api)]
+    ///
+    /// use std::alloc::System;
+    ///
+    /// let s = String::with_capacity_in(10, System);
+    /// assert!(s.capacity() >= 10);
+    /// assert_eq!(s.len(), 0);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        String { vec: Vec::with_capacity_in(capacity, alloc) }
+    }
+
+    /// Creates a new empty `String` with at least the specified capacity in
+    /// the provided allocator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the capacity exceeds `isize::MAX` bytes,
+    /// or if the memory allocator reports failure.
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "try_with_capacity", issue = "91913")]
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Ok(String { vec: Vec::try_with_capacity_in(capacity, alloc)? })
+    }
+
+    /// Creates a new `String` from a pointer, a length, a capacity and an
+    /// allocator.
+    ///
+    /// # Safety
+    ///
+    /// This has the same requirements as [`String::from_raw_parts`], with
+    /// the memory having been allocated by `alloc` rather than the global
+    /// allocator. See also [`Vec::from_raw_parts_in`].
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub unsafe fn from_raw_parts_in(
+        buf: *mut u8,
+        length: usize,
+        capacity: usize,
+        alloc: A,
+    ) -> Self {
+        unsafe { String { vec: Vec::from_raw_parts_in(buf, length, capacity, alloc) } }
+    }
+
+    /// Decomposes a `String` into its raw components:
+    /// `(pointer, length, capacity, allocator)`.
+    ///
+    /// After calling this function, the caller is responsible for the memory
+    /// previously managed by the `String`. The only way to do this is to
+    /// convert the raw parts back into a `String` with
+    /// [`from_raw_parts_in`](String::from_raw_parts_in).
+    #[must_use = "losing the pointer will leak memory"]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    // #[unstable(feature = "vec_into_raw_parts", reason = "new API", issue = "65816")]
+    pub fn into_raw_parts_with_alloc(self) -> (*mut u8, usize, usize, A) {
+        self.vec.into_raw_parts_with_alloc()
+    }
+
+    /// Converts a vector of bytes in any allocator to a `String` without
+    /// checking that the string contains valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// See [`String::from_utf8_unchecked`].
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub unsafe fn from_utf8_unchecked_in(bytes: Vec<u8, A>) -> Self {
+        String { vec: bytes }
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    #[unstable(feature = "allocator_api", issue = "32838")]
+    pub fn allocator(&self) -> &A {
+        self.vec.allocator()
+    }
 
     /// Converts a `String` into a byte vector.
     ///
@@ -1007,7 +1147,7 @@ pub unsafe fn from_utf8_unchecked(bytes: Vec<u8>) -> String {
     #[inline]
     #[must_use = "`self` will be dropped if the result is not used"]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub fn into_bytes(self) -> Vec<u8> {
+    pub fn into_bytes(self) -> Vec<u8, A> {
         self.vec
     }
 
@@ -1065,6 +1205,33 @@ pub fn push_str(&mut self, string: &str) {
         self.vec.extend_from_slice(string.as_bytes())
     }
 
+    /// Appends a given string slice onto the end of this `String`, or returns
+    /// an error if the string needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`push_str`](String::push_str). The
+    /// string is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut s = String::from("foo");
+    /// s.try_push_str("bar")?;
+    /// assert_eq!("foobar", s);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), TryReserveError> {
+        self.vec.try_extend_from_slice(string.as_bytes())
+    }
+
     /// Copies elements from `src` range to the end of the string.
     ///
     /// # Panics
@@ -1360,6 +1527,63 @@ pub fn push(&mut self, ch: char) {
         }
     }
 
+    /// Appends the given [`char`] to the end of this `String`, or returns an
+    /// error if the string needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`push`](String::push). The string
+    /// is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut s = String::from("abc");
+    /// s.try_push('1')?;
+    /// s.try_push('é')?;
+    /// assert_eq!("abc1é", s);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push(&mut self, ch: char) -> Result<(), TryReserveError> {
+        match ch.len_utf8() {
+            1 => self.vec.try_push(ch as u8),
+            _ => self.vec.try_extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+
+    /// Clones the string, or returns an error if the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`Clone::clone`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let s = String::from("hello");
+    /// assert_eq!(s.try_clone()?, "hello");
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_clone(&self) -> Result<Self, TryReserveError>
+    where
+        A: Clone,
+    {
+        Ok(String { vec: self.vec.try_clone()? })
+    }
+
     /// Returns a byte slice of this `String`'s contents.
     ///
     /// The inverse of this method is [`from_utf8`].
@@ -1576,13 +1800,13 @@ pub fn retain<F>(&mut self, mut f: F)
     where
         F: FnMut(char) -> bool,
     {
-        struct SetLenOnDrop<'a> {
-            s: &'a mut String,
+        struct SetLenOnDrop<'a, A: Allocator> {
+            s: &'a mut String<A>,
             idx: usize,
             del_bytes: usize,
         }
 
-        impl<'a> Drop for SetLenOnDrop<'a> {
+        impl<'a, A: Allocator> Drop for SetLenOnDrop<'a, A> {
             fn drop(&mut self) {
                 let new_len = self.idx - self.del_bytes;
                 debug_assert!(new_len <= self.s.len());
@@ -1728,7 +1952,7 @@ pub fn insert_str(&mut self, idx: usize, string: &str) {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
-    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8> {
+    pub unsafe fn as_mut_vec(&mut self) -> &mut Vec<u8, A> {
         &mut self.vec
     }
 
@@ -1799,10 +2023,13 @@ pub fn is_empty(&self) -> bool {
     #[inline]
     #[stable(feature = "string_split_off", since = "1.16.0")]
     #[must_use = "use `.truncate()` if you don't need the other half"]
-    pub fn split_off(&mut self, at: usize) -> String {
+    pub fn split_off(&mut self, at: usize) -> String<A>
+    where
+        A: Clone,
+    {
         assert!(self.is_char_boundary(at));
         let other = self.vec.split_off(at);
-        unsafe { String::from_utf8_unchecked(other) }
+        unsafe { String::from_utf8_unchecked_in(other) }
     }
 
     /// Truncates this `String`, removing all contents.
@@ -1861,7 +2088,7 @@ pub fn clear(&mut self) {
     /// assert_eq!(s, "");
     /// ```
     #[stable(feature = "drain", since = "1.6.0")]
-    pub fn drain<R>(&mut self, range: R) -> Drain<'_>
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, A>
     where
         R: RangeBounds<usize>,
     {
@@ -1954,9 +2181,10 @@ pub fn replace_range<R>(&mut self, range: R, replace_with: &str)
     #[stable(feature = "box_str", since = "1.4.0")]
     #[must_use = "`self` will be dropped if the result is not used"]
     #[inline]
-    pub fn into_boxed_str(self) -> Box<str> {
-        let slice = self.vec.into_boxed_slice();
-        unsafe { from_boxed_utf8_unchecked(slice) }
+    pub fn into_boxed_str(self) -> Box<str, A> {
+        let (slice, alloc) = Box::into_raw_with_allocator(self.vec.into_boxed_slice());
+        // SAFETY: the bytes came from a `String`, so they are valid UTF-8.
+        unsafe { Box::from_raw_in(slice as *mut str, alloc) }
     }
 
     /// Consumes and leaks the `String`, returning a mutable reference to the contents,
@@ -1985,7 +2213,10 @@ pub fn into_boxed_str(self) -> Box<str> {
     /// ```
     #[stable(feature = "string_leak", since = "1.72.0")]
     #[inline]
-    pub fn leak<'a>(self) -> &'a mut str {
+    pub fn leak<'a>(self) -> &'a mut str
+    where
+        A: 'a,
+    {
         let slice = self.vec.leak();
         unsafe { from_utf8_unchecked_mut(slice) }
     }
@@ -2092,7 +2323,7 @@ fn description(&self) -> &str {
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
-impl Clone for String {
+impl<A: Allocator + Clone> Clone for String<A> {
     fn clone(&self) -> Self {
         String { vec: self.vec.clone() }
     }
@@ -2187,7 +2418,7 @@ fn from_iter<I: IntoIterator<Item = Cow<'a, str>>>(iter: I) -> String {
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
-impl Extend<char> for String {
+impl<A: Allocator> Extend<char> for String<A> {
     fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
         let iterator = iter.into_iter();
         let (lower_bound, _) = iterator.size_hint();
@@ -2208,7 +2439,7 @@ fn extend_reserve(&mut self, additional: usize) {
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "extend_ref", since = "1.2.0")]
-impl<'a> Extend<&'a char> for String {
+impl<'a, A: Allocator> Extend<&'a char> for String<A> {
     fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
         self.extend(iter.into_iter().cloned());
     }
@@ -2226,7 +2457,7 @@ fn extend_reserve(&mut self, additional: usize) {
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<'a> Extend<&'a str> for String {
+impl<'a, A: Allocator> Extend<&'a str> for String<A> {
     fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
         iter.into_iter().for_each(move |s| self.push_str(s));
     }
@@ -2371,7 +2602,7 @@ fn default() -> String {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl fmt::Display for String {
+impl<A: Allocator> fmt::Display for String<A> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(&**self, f)
@@ -2379,7 +2610,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl fmt::Debug for String {
+impl<A: Allocator> fmt::Debug for String<A> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&**self, f)
@@ -2387,7 +2618,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl hash::Hash for String {
+impl<A: Allocator> hash::Hash for String<A> {
     #[inline]
     fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
         (**self).hash(hasher)
@@ -2456,7 +2687,7 @@ fn add_assign(&mut self, other: &str) {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<I> ops::Index<I> for String
+impl<I, A: Allocator> ops::Index<I> for String<A>
 where
     I: slice::SliceIndex<str>,
 {
@@ -2469,7 +2700,7 @@ fn index(&self, index: I) -> &I::Output {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl<I> ops::IndexMut<I> for String
+impl<I, A: Allocator> ops::IndexMut<I> for String<A>
 where
     I: slice::SliceIndex<str>,
 {
@@ -2480,7 +2711,7 @@ fn index_mut(&mut self, index: I) -> &mut I::Output {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl ops::Deref for String {
+impl<A: Allocator> ops::Deref for String<A> {
     type Target = str;
 
     #[inline]
@@ -2490,10 +2721,10 @@ fn deref(&self) -> &str {
 }
 
 #[unstable(feature = "deref_pure_trait", issue = "87121")]
-unsafe impl ops::DerefPure for String {}
+unsafe impl<A: Allocator> ops::DerefPure for String<A> {}
 
 #[stable(feature = "derefmut_for_string", since = "1.3.0")]
-impl ops::DerefMut for String {
+impl<A: Allocator> ops::DerefMut for String<A> {
     #[inline]
     fn deref_mut(&mut self) -> &mut str {
         unsafe { str::from_utf8_unchecked_mut(&mut *self.vec) }
@@ -2725,7 +2956,7 @@ fn to_string(&self) -> String {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl AsRef<str> for String {
+impl<A: Allocator> AsRef<str> for String<A> {
     #[inline]
     fn as_ref(&self) -> &str {
         self
@@ -2733,7 +2964,7 @@ fn as_ref(&self) -> &str {
 }
 
 #[stable(feature = "string_as_mut", since = "1.43.0")]
-impl AsMut<str> for String {
+impl<A: Allocator> AsMut<str> for String<A> {
     #[inline]
     fn as_mut(&mut self) -> &mut str {
         self
@@ -2741,7 +2972,7 @@ fn as_mut(&mut self) -> &mut str {
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
-impl AsRef<[u8]> for String {
+impl<A: Allocator> AsRef<[u8]> for String<A> {
     #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -2939,7 +3170,7 @@ fn from_iter<I: IntoIterator<Item = String>>(it: I) -> Cow<'a, str> {
 }
 
 #[stable(feature = "from_string_for_vec_u8", since = "1.14.0")]
-impl From<String> for Vec<u8> {
+impl<A: Allocator> From<String<A>> for Vec<u8, A> {
     /// Converts the given [`String`] to a vector [`Vec`] that holds values of type [`u8`].
     ///
     /// # Examples
@@ -2952,14 +3183,14 @@ impl From<String> for Vec<u8> {
     ///     println!("{b}");
     /// }
     /// ```
-    fn from(string: String) -> Vec<u8> {
+    fn from(string: String<A>) -> Vec<u8, A> {
         string.into_bytes()
     }
 }
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "rust1", since = "1.0.0")]
-impl fmt::Write for String {
+impl<A: Allocator> fmt::Write for String<A> {
     #[inline]
     fn write_str(&mut self, s: &str) -> fmt::Result {
         self.push_str(s);
@@ -2980,9 +3211,10 @@ fn write_char(&mut self, c: char) -> fmt::Result {
 ///
 /// [`drain`]: String::drain
 #[stable(feature = "drain", since = "1.6.0")]
-pub struct Drain<'a> {
+pub struct Drain<'a, #[unstable(feature = "allocator_api", issue = "32838")] A: Allocator = Global>
+{
     /// Will be used as &'a mut String in the destructor
-    string: *mut String,
+    string: *mut String<A>,
     /// Start of part to remove
     start: usize,
     /// End of part to remove
@@ -2992,19 +3224,19 @@ pub struct Drain<'a> {
 }
 
 #[stable(feature = "collection_debug", since = "1.17.0")]
-impl fmt::Debug for Drain<'_> {
+impl<A: Allocator> fmt::Debug for Drain<'_, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Drain").field(&self.as_str()).finish()
     }
 }
 
 #[stable(feature = "drain", since = "1.6.0")]
-unsafe impl Sync for Drain<'_> {}
+unsafe impl<A: Allocator + Sync> Sync for Drain<'_, A> {}
 #[stable(feature = "drain", since = "1.6.0")]
-unsafe impl Send for Drain<'_> {}
+unsafe impl<A: Allocator + Send> Send for Drain<'_, A> {}
 
 #[stable(feature = "drain", since = "1.6.0")]
-impl Drop for Drain<'_> {
+impl<A: Allocator> Drop for Drain<'_, A> {
     fn drop(&mut self) {
         unsafe {
             // Use Vec::drain. "Reaffirm" the bounds checks to avoid
@@ -3017,7 +3249,7 @@ fn drop(&mut self) {
     }
 }
 
-impl<'a> Drain<'a> {
+impl<'a, A: Allocator> Drain<'a, A> {
     /// Returns the remaining (sub)string of this iterator as a slice.
     ///
     /// # Examples
@@ -3037,21 +3269,21 @@ pub fn as_str(&self) -> &str {
 }
 
 #[stable(feature = "string_drain_as_str", since = "1.55.0")]
-impl<'a> AsRef<str> for Drain<'a> {
+impl<'a, A: Allocator> AsRef<str> for Drain<'a, A> {
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
 #[stable(feature = "string_drain_as_str", since = "1.55.0")]
-impl<'a> AsRef<[u8]> for Drain<'a> {
+impl<'a, A: Allocator> AsRef<[u8]> for Drain<'a, A> {
     fn as_ref(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
 }
 
 #[stable(feature = "drain", since = "1.6.0")]
-impl Iterator for Drain<'_> {
+impl<A: Allocator> Iterator for Drain<'_, A> {
     type Item = char;
 
     #[inline]
@@ -3070,7 +3302,7 @@ fn last(mut self) -> Option<char> {
 }
 
 #[stable(feature = "drain", since = "1.6.0")]
-impl DoubleEndedIterator for Drain<'_> {
+impl<A: Allocator> DoubleEndedIterator for Drain<'_, A> {
     #[inline]
     fn next_back(&mut self) -> Option<char> {
         self.iter.next_back()
@@ -3078,7 +3310,7 @@ fn next_back(&mut self) -> Option<char> {
 }
 
 #[stable(feature = "fused", since = "1.26.0")]
-impl FusedIterator for Drain<'_> {}
+impl<A: Allocator> FusedIterator for Drain<'_, A> {}
 
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "from_char_for_string", since = "1.46.0")]
@@ -1583,6 +1583,66 @@ fn assert_failed(index: usize, len: usize) -> ! {
             self.buf.grow_one();
         }
 
+        // SAFETY: `index <= len` and there is room for one more element.
+        unsafe { self.insert_within_capacity(index, element) };
+    }
+
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right, or returns an error if the vector
+    /// needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`insert`](Vec::insert). The vector
+    /// is left unchanged, and `element` is dropped, if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// vec.try_insert(1, 4)?;
+    /// assert_eq!(vec, [1, 4, 2, 3]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), TryReserveError> {
+        #[cold]
+        #[cfg_attr(not(feature = "panic_immediate_abort"), inline(never))]
+        #[track_caller]
+        #[optimize(size)]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        let len = self.len();
+        if index > len {
+            assert_failed(index, len);
+        }
+
+        self.try_reserve(1)?;
+
+        // SAFETY: `index <= len` and there is room for one more element.
+        unsafe { self.insert_within_capacity(index, element) };
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be at most `self.len()`, and the capacity must be larger
+    /// than the length.
+    unsafe fn insert_within_capacity(&mut self, index: usize, element: T) {
+        let len = self.len();
+        debug_assert!(index <= len && len < self.capacity());
+
         unsafe {
             // infallible
             // The spot to put the new value
@@ -2067,6 +2127,76 @@ pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
         Ok(())
     }
 
+    /// Appends an element to the back of a collection, or returns an error if
+    /// the vector needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`push`](Vec::push). The vector is
+    /// left unchanged, and `value` is dropped, if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1, 2];
+    /// vec.try_push(3)?;
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.buf.capacity() {
+            self.buf.try_reserve(self.len, 1)?;
+        }
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Appends the elements of an iterator to the vector, or returns an error
+    /// if the vector needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`Extend::extend`]. If an error is
+    /// returned, the elements appended so far are kept and the rest of the
+    /// iterator is dropped.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1];
+    /// vec.try_extend((2..5).map(|x| x * 10))?;
+    /// assert_eq!(vec, [1, 20, 30, 40]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), TryReserveError> {
+        let iter = iter.into_iter();
+        self.try_reserve(iter.size_hint().0)?;
+        for value in iter {
+            self.try_push(value)?;
+        }
+        Ok(())
+    }
+
     /// Removes the last element from a vector and returns it, or [`None`] if it
     /// is empty.
     ///
@@ -2610,6 +2740,68 @@ pub fn extend_from_slice(&mut self, other: &[T]) {
         self.spec_extend(other.iter())
     }
 
+    /// Clones and appends all elements in a slice to the `Vec`, or returns an
+    /// error if the vector needed to grow and the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`extend_from_slice`]. The vector
+    /// is left unchanged if an error is returned.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let mut vec = vec![1];
+    /// vec.try_extend_from_slice(&[2, 3, 4])?;
+    /// assert_eq!(vec, [1, 2, 3, 4]);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    ///
+    /// [`extend_from_slice`]: Vec::extend_from_slice
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(other.len())?;
+        for value in other {
+            // Cannot fail, the capacity was reserved above.
+            let _ = self.push_within_capacity(value.clone());
+        }
+        Ok(())
+    }
+
+    /// Clones the vector, or returns an error if the allocation failed.
+    ///
+    /// This is the fallible counterpart of [`Clone::clone`].
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fallible_alloc)]
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// let copy = vec.try_clone()?;
+    /// assert_eq!(vec, copy);
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[unstable(feature = "fallible_alloc", issue = "none")]
+    pub fn try_clone(&self) -> Result<Self, TryReserveError>
+    where
+        A: Clone,
+    {
+        let mut vec = Vec::try_with_capacity_in(self.len(), self.allocator().clone())?;
+        vec.try_extend_from_slice(self)?;
+        Ok(vec)
+    }
+
     /// Copies elements from `src` range to the end of the vector.
     ///
     /// # Panics
@@ -3546,6 +3738,64 @@ fn from(s: &str) -> Vec<u8> {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::ErrorType for Vec<u8, A> {
+    type Error = core::io::ErrorKind;
+}
+
+/// Write is implemented for `Vec<u8>` by appending to the vector.
+/// The vector will grow as needed.
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io", issue = "none")]
+impl<A: Allocator> core::io::Write for Vec<u8, A> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, core::io::ErrorKind> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), core::io::ErrorKind> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), core::io::ErrorKind> {
+        Ok(())
+    }
+}
+
+/// Writing through a `Cursor<Vec<u8>>` overwrites the bytes at the cursor's
+/// position and grows the vector as needed, padding it with zeroes if the
+/// position is past its end.
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io_internals", issue = "none")]
+impl<A: Allocator> core::io::CursorBuffer for Vec<u8, A> {
+    fn write_at(&mut self, pos: &mut u64, buf: &[u8]) -> Result<usize, core::io::ErrorKind> {
+        let start = usize::try_from(*pos).map_err(|_| core::io::ErrorKind::InvalidInput)?;
+        let end = start.checked_add(buf.len()).ok_or(core::io::ErrorKind::InvalidInput)?;
+        if start > self.len() {
+            self.resize(start, 0);
+        }
+        let overwritten = cmp::min(self.len() - start, buf.len());
+        self[start..start + overwritten].copy_from_slice(&buf[..overwritten]);
+        self.extend_from_slice(&buf[overwritten..]);
+        *pos = end as u64;
+        Ok(buf.len())
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "core_io_internals", issue = "none")]
+impl<A: Allocator> core::io::CursorBuffer for &mut Vec<u8, A> {
+    #[inline]
+    fn write_at(&mut self, pos: &mut u64, buf: &[u8]) -> Result<usize, core::io::ErrorKind> {
+        core::io::CursorBuffer::write_at(&mut **self, pos, buf)
+    }
+}
+
 #[stable(feature = "array_try_from_vec", since = "1.48.0")]
 impl<T, A: Allocator, const N: usize> TryFrom<Vec<T, A>> for [T; N] {
     type Error = Vec<T, A>;
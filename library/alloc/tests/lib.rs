@@ -12,6 +12,7 @@
 #![feature(const_try)]
 #![feature(core_intrinsics)]
 #![feature(extract_if)]
+#![feature(fallible_alloc)]
 #![feature(exact_size_is_empty)]
 #![feature(linked_list_cursors)]
 #![feature(map_try_insert)]
@@ -40,6 +41,8 @@
 #![feature(local_waker)]
 #![feature(vec_pop_if)]
 #![feature(unique_rc_arc)]
+#![feature(unicode_normalization)]
+#![feature(unicode_segmentation)]
 #![allow(internal_features)]
 #![deny(fuzzy_provenance_casts)]
 #![deny(unsafe_op_in_unsafe_fn)]
@@ -1906,6 +1906,30 @@ fn test_repeat() {
     assert_eq!("α".repeat(3), "ααα");
 }
 
+#[test]
+fn test_multi_str_pattern() {
+    use std::str::pattern::MultiStrPattern;
+
+    let needles = MultiStrPattern::new(&["&", "<", ">", "&amp;"]);
+    let s = "a<b && c>d &amp;";
+    assert_eq!(s.find(&needles), Some(1));
+    assert_eq!(s.matches(&needles).collect::<Vec<_>>(), ["<", "&", "&", ">", "&"]);
+    assert_eq!(s.replace(&needles, "_"), "a_b __ c_d _amp;");
+    assert_eq!(s.split(&needles).collect::<Vec<_>>(), ["a", "b ", "", " c", "d ", "amp;"]);
+    assert!(s.contains(&MultiStrPattern::new(&["&amp;"])));
+    assert!(!s.contains(&MultiStrPattern::new(&["&lt;", "&gt;"])));
+    assert!(s.starts_with(&MultiStrPattern::new(&["b", "a"])));
+    assert_eq!(s.strip_prefix(&MultiStrPattern::new(&["a<", "a"])), Some("b && c>d &amp;"));
+
+    let entities = MultiStrPattern::new(&["&amp;", "&"]);
+    assert_eq!(s.match_indices(&entities).collect::<Vec<_>>(), [(4, "&"), (5, "&"), (11, "&amp;")]);
+    assert_eq!("&amp;amp;".replacen(&entities, "&", 1), "&amp;");
+
+    let empty = MultiStrPattern::new(&["", "b"]);
+    assert_eq!("abc".split(&empty).collect::<Vec<_>>(), ["", "a", "b", "c", ""]);
+    assert_eq!("abc".find(&MultiStrPattern::new(&[])), None);
+}
+
 mod pattern {
     use std::str::pattern::SearchStep::{self, Done, Match, Reject};
     use std::str::pattern::{Pattern, ReverseSearcher, Searcher};
@@ -2455,3 +2479,161 @@ fn check_many(s: &str, arg: impl IntoIterator<Item = usize>, ret: usize) {
     // above len
     check_many("hello", 5..=10, 5);
 }
+
+#[test]
+fn normalization_forms() {
+    fn check(s: &str, nfc: &str, nfd: &str, nfkc: &str, nfkd: &str) {
+        assert_eq!(s.nfc().to_string(), nfc, "nfc of {s:?}");
+        assert_eq!(s.nfd().to_string(), nfd, "nfd of {s:?}");
+        assert_eq!(s.nfkc().to_string(), nfkc, "nfkc of {s:?}");
+        assert_eq!(s.nfkd().to_string(), nfkd, "nfkd of {s:?}");
+    }
+
+    check("", "", "", "", "");
+    check("abc", "abc", "abc", "abc", "abc");
+    check("e\u{301}", "\u{e9}", "e\u{301}", "\u{e9}", "e\u{301}");
+    check("\u{e9}", "\u{e9}", "e\u{301}", "\u{e9}", "e\u{301}");
+    check("\u{fb01}", "\u{fb01}", "\u{fb01}", "fi", "fi");
+    // Example from UAX #15, long s with dot above and dot below.
+    check(
+        "\u{1e9b}\u{323}",
+        "\u{1e9b}\u{323}",
+        "\u{17f}\u{323}\u{307}",
+        "\u{1e69}",
+        "s\u{323}\u{307}",
+    );
+    // Canonical reordering by combining class.
+    check(
+        "a\u{301}\u{323}",
+        "\u{1ea1}\u{301}",
+        "a\u{323}\u{301}",
+        "\u{1ea1}\u{301}",
+        "a\u{323}\u{301}",
+    );
+    // Leading non-starters have nothing to compose with.
+    check("\u{301}e", "\u{301}e", "\u{301}e", "\u{301}e", "\u{301}e");
+    // A blocked combining mark stays after the one of the same class.
+    check(
+        "e\u{302}\u{302}",
+        "\u{ea}\u{302}",
+        "e\u{302}\u{302}",
+        "\u{ea}\u{302}",
+        "e\u{302}\u{302}",
+    );
+    // Singleton decompositions are never recomposed.
+    check("\u{212b}", "\u{c5}", "A\u{30a}", "\u{c5}", "A\u{30a}");
+    // Composition exclusions.
+    check("\u{958}", "\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}");
+}
+
+#[test]
+fn normalization_hangul() {
+    assert_eq!("\u{ac00}".nfd().to_string(), "\u{1100}\u{1161}");
+    assert_eq!("\u{1100}\u{1161}".nfc().to_string(), "\u{ac00}");
+    assert_eq!("\u{d4db}".nfd().to_string(), "\u{1111}\u{1171}\u{11b6}");
+    assert_eq!("\u{1111}\u{1171}\u{11b6}".nfc().to_string(), "\u{d4db}");
+    assert_eq!("\u{1100}\u{ac00}\u{11a8}".nfc().to_string(), "\u{1100}\u{ac01}");
+    // The first trailing consonant index, U+11A7, is not a jongseong.
+    assert_eq!("\u{ac00}\u{11a7}".nfc().to_string(), "\u{ac00}\u{11a7}");
+}
+
+#[test]
+fn is_normalized() {
+    use std::str::IsNormalized;
+
+    assert_eq!("abc".is_nfc_quick(), IsNormalized::Yes);
+    assert_eq!("caf\u{e9}".is_nfc_quick(), IsNormalized::Yes);
+    assert_eq!("cafe\u{301}".is_nfc_quick(), IsNormalized::Maybe);
+    assert_eq!("\u{212b}".is_nfc_quick(), IsNormalized::No);
+    assert_eq!("caf\u{e9}".is_nfd_quick(), IsNormalized::No);
+    assert_eq!("cafe\u{301}".is_nfd_quick(), IsNormalized::Yes);
+    assert_eq!("\u{fb01}".is_nfkc_quick(), IsNormalized::No);
+    assert_eq!("\u{fb01}".is_nfkd_quick(), IsNormalized::No);
+    assert_eq!("a\u{301}\u{323}".is_nfd_quick(), IsNormalized::No);
+
+    assert!("caf\u{e9}".is_nfc());
+    assert!(!"cafe\u{301}".is_nfc());
+    assert!("\u{301}".is_nfc());
+    assert!("cafe\u{301}".is_nfd());
+    assert!(!"a\u{301}\u{323}".is_nfd());
+    assert!("fi".is_nfkc());
+    assert!(!"\u{fb01}".is_nfkc());
+
+    for s in ["", "abc", "e\u{301}", "\u{1e9b}\u{323}", "\u{d4db}", "\u{fb01}n\u{e9}"] {
+        assert!(s.nfc().collect::<String>().is_nfc());
+        assert!(s.nfd().collect::<String>().is_nfd());
+        assert!(s.nfkc().collect::<String>().is_nfkc());
+        assert!(s.nfkd().collect::<String>().is_nfkd());
+    }
+}
+
+#[test]
+fn graphemes() {
+    fn check(s: &str, expected: &[&str]) {
+        assert_eq!(s.graphemes().collect::<Vec<_>>(), expected, "graphemes of {s:?}");
+    }
+
+    check("", &[]);
+    check("abc", &["a", "b", "c"]);
+    check("a\r\nb\n\r", &["a", "\r\n", "b", "\n", "\r"]);
+    // Combining marks and spacing marks.
+    check("e\u{301}\u{323}x", &["e\u{301}\u{323}", "x"]);
+    check("\u{915}\u{93f}", &["\u{915}\u{93f}"]);
+    // A lone combining mark.
+    check("\u{301}a", &["\u{301}", "a"]);
+    // Hangul syllables made of jamo.
+    check("\u{1100}\u{1161}\u{11a8}\u{ac00}", &["\u{1100}\u{1161}\u{11a8}", "\u{ac00}"]);
+    // Prepend.
+    check("\u{600}1", &["\u{600}1"]);
+    // Flags are pairs of regional indicators.
+    check(
+        "\u{1f1e9}\u{1f1ea}\u{1f1eb}\u{1f1f7}\u{1f1ee}",
+        &["\u{1f1e9}\u{1f1ea}", "\u{1f1eb}\u{1f1f7}", "\u{1f1ee}"],
+    );
+    // Emoji with modifiers and ZWJ sequences.
+    check("\u{1f44d}\u{1f3fd}!", &["\u{1f44d}\u{1f3fd}", "!"]);
+    check(
+        "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+        &["\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"],
+    );
+    check("a\u{200d}\u{1f469}", &["a\u{200d}", "\u{1f469}"]);
+    // Indic conjuncts.
+    check("\u{915}\u{94d}\u{937}", &["\u{915}\u{94d}\u{937}"]);
+    check("\u{915}\u{94d}a", &["\u{915}\u{94d}", "a"]);
+
+    let mut iter = "e\u{301}x".graphemes();
+    assert_eq!(iter.as_str(), "e\u{301}x");
+    assert_eq!(iter.next(), Some("e\u{301}"));
+    assert_eq!(iter.as_str(), "x");
+    assert_eq!(iter.next(), Some("x"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn split_word_bounds() {
+    fn check(s: &str, expected: &[&str]) {
+        assert_eq!(s.split_word_bounds().collect::<Vec<_>>(), expected, "word bounds of {s:?}");
+    }
+
+    check("", &[]);
+    check("Hello, world!", &["Hello", ",", " ", "world", "!"]);
+    check("a  b\r\n\nc", &["a", "  ", "b", "\r\n", "\n", "c"]);
+    // Apostrophes and periods within words and numbers.
+    check("can't e.g. 3.14 1,000", &["can't", " ", "e.g", ".", " ", "3.14", " ", "1,000"]);
+    check("end. 'quoted'", &["end", ".", " ", "'", "quoted", "'"]);
+    // Letters and digits, and connector punctuation.
+    check("abc123 snake_case", &["abc123", " ", "snake_case"]);
+    // Combining marks stay with the word.
+    check("cafe\u{301} au", &["cafe\u{301}", " ", "au"]);
+    // Every ideograph is a word of its own, while katakana are kept together.
+    check("\u{65e5}\u{672c}\u{30ab}\u{30bf}", &["\u{65e5}", "\u{672c}", "\u{30ab}\u{30bf}"]);
+    // Hebrew letters with quotes.
+    check("\u{5d0}\"\u{5d1}", &["\u{5d0}\"\u{5d1}"]);
+    // Flags and emoji ZWJ sequences.
+    check("\u{1f1e9}\u{1f1ea}\u{1f1eb}", &["\u{1f1e9}\u{1f1ea}", "\u{1f1eb}"]);
+    check("\u{1f468}\u{200d}\u{1f469} ", &["\u{1f468}\u{200d}\u{1f469}", " "]);
+
+    let s = "The (quick) brown fox's 2.5 jumps\u{2026}";
+    assert_eq!(s.split_word_bounds().collect::<String>(), s);
+}
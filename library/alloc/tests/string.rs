@@ -223,6 +223,19 @@ fn test_push_str() {
     assert_eq!(&s[0..], "abcประเทศไทย中华Việt Nam");
 }
 
+#[test]
+fn test_try_push_str() {
+    let mut s = String::new();
+    s.try_push_str("").unwrap();
+    assert_eq!(s, "");
+    s.try_push_str("abc").unwrap();
+    s.try_push('ä').unwrap();
+    s.try_push('!').unwrap();
+    s.try_push('中').unwrap();
+    assert_eq!(s, "abcä!中");
+    assert_eq!(s.try_clone().unwrap(), s);
+}
+
 #[test]
 fn test_add_assign() {
     let mut s = String::new();
@@ -875,3 +888,29 @@ fn test_str_concat() {
     let s: String = format!("{a}{b}");
     assert_eq!(s.as_bytes()[9], 'd' as u8);
 }
+
+#[test]
+fn test_string_in_allocator() {
+    use std::alloc::System;
+
+    let mut s = String::with_capacity_in(4, System);
+    s.push_str("hello");
+    s.push(' ');
+    s.extend(["wide", " world"]);
+    assert_eq!(s.as_str(), "hello wide world");
+    assert_eq!(s.pop(), Some('d'));
+
+    let mut rest = s.split_off(6);
+    assert_eq!(&*rest, "wide worl");
+    rest.insert_str(0, "a ");
+    assert_eq!(format!("{s}|{rest}"), "hello |a wide worl");
+
+    let drained: std::string::String = rest.drain(..2).collect();
+    assert_eq!(drained, "a ");
+    assert_eq!(rest.clone(), rest);
+
+    let boxed = rest.into_boxed_str();
+    assert_eq!(&*boxed, "wide worl");
+    let bytes = s.into_bytes();
+    assert_eq!(bytes, b"hello ");
+}
@@ -1888,6 +1888,58 @@ fn test_try_reserve_exact() {
     }
 }
 
+#[test]
+fn test_try_push_insert_extend() {
+    let mut vec = Vec::new();
+    vec.try_push(1).unwrap();
+    vec.try_insert(0, 0).unwrap();
+    vec.try_extend(2..4).unwrap();
+    vec.try_extend_from_slice(&[4, 5]).unwrap();
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+    assert_eq!(vec.try_clone().unwrap(), vec);
+
+    let mut zsts = Vec::new();
+    zsts.try_extend(std::iter::repeat(()).take(100)).unwrap();
+    assert_eq!(zsts.len(), 100);
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+fn test_try_insert_out_of_bounds() {
+    let mut vec = vec![1];
+    let _ = vec.try_insert(2, 2);
+}
+
+#[test]
+fn test_try_push_alloc_failure() {
+    // An allocator that refuses every allocation.
+    #[derive(Clone)]
+    struct Refuse;
+
+    unsafe impl Allocator for Refuse {
+        fn allocate(&self, _: Layout) -> Result<NonNull<[u8]>, core::alloc::AllocError> {
+            Err(core::alloc::AllocError)
+        }
+
+        unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {
+            unreachable!("nothing was allocated")
+        }
+    }
+
+    let mut vec: Vec<u32, Refuse> = Vec::new_in(Refuse);
+    assert_matches!(vec.try_push(1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(vec.try_insert(0, 1).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(vec.try_extend([1, 2]).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert_matches!(vec.try_extend_from_slice(&[1]).map_err(|e| e.kind()), Err(AllocError { .. }));
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 0);
+
+    // Zero-sized elements never allocate.
+    let mut zsts: Vec<(), Refuse> = Vec::new_in(Refuse);
+    zsts.try_push(()).unwrap();
+    assert_eq!(zsts.try_clone().unwrap().len(), 1);
+}
+
 #[test]
 fn test_stable_pointers() {
     /// Pull an element from the iterator, then drop it.
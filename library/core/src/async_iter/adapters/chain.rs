@@ -0,0 +1,59 @@
+use crate::async_iter::AsyncIterator;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// An async iterator that links two async iterators together, in a chain.
+///
+/// This `struct` is created by the [`chain`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`chain`]: AsyncIterator::chain
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Chain<A, B> {
+    // `a` is dropped as soon as it is exhausted, so that it is never polled
+    // again. `b` is polled after that, which may be after it has finished.
+    a: Option<A>,
+    b: B,
+}
+
+impl<A, B> Chain<A, B> {
+    pub(in crate::async_iter) fn new(a: A, b: B) -> Chain<A, B> {
+        Chain { a: Some(a), b }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<A, B> AsyncIterator for Chain<A, B>
+where
+    A: AsyncIterator,
+    B: AsyncIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<A::Item>> {
+        // SAFETY: `a` and `b` are structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut a = unsafe { Pin::new_unchecked(&mut this.a) };
+        if let Some(iter) = a.as_mut().as_pin_mut() {
+            match ready!(iter.poll_next(cx)) {
+                Some(item) => return Poll::Ready(Some(item)),
+                None => a.set(None),
+            }
+        }
+        unsafe { Pin::new_unchecked(&mut this.b) }.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (b_lower, b_upper) = self.b.size_hint();
+        let Some(a) = &self.a else { return (b_lower, b_upper) };
+        let (a_lower, a_upper) = a.size_hint();
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
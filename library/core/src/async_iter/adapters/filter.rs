@@ -0,0 +1,57 @@
+use crate::async_iter::AsyncIterator;
+use crate::fmt;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// An async iterator that filters the values of `iter` with `predicate`.
+///
+/// This `struct` is created by the [`filter`] method on [`AsyncIterator`].
+/// See its documentation for more.
+///
+/// [`filter`]: AsyncIterator::filter
+#[derive(Clone)]
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> Filter<I, P> {
+    pub(in crate::async_iter) fn new(iter: I, predicate: P) -> Filter<I, P> {
+        Filter { iter, predicate }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: fmt::Debug, P> fmt::Debug for Filter<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").field("iter", &self.iter).finish()
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator, P> AsyncIterator for Filter<I, P>
+where
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // SAFETY: `iter` is structurally pinned and `predicate` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        loop {
+            match ready!(iter.as_mut().poll_next(cx)) {
+                Some(item) if (this.predicate)(&item) => return Poll::Ready(Some(item)),
+                Some(_) => {}
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
@@ -0,0 +1,54 @@
+use crate::async_iter::AsyncIterator;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// An async iterator that yields `None` forever after `iter` has finished.
+///
+/// This `struct` is created by the [`fuse`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`fuse`]: AsyncIterator::fuse
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Fuse<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I> Fuse<I> {
+    pub(in crate::async_iter) fn new(iter: I) -> Fuse<I> {
+        Fuse { iter, done: false }
+    }
+
+    /// Returns `true` if the underlying async iterator has finished, so that
+    /// this one will only yield `None` from now on.
+    #[must_use]
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator> AsyncIterator for Fuse<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // SAFETY: `iter` is structurally pinned and `done` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        let next = iter.poll_next(cx);
+        if let Poll::Ready(None) = next {
+            this.done = true;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { (0, Some(0)) } else { self.iter.size_hint() }
+    }
+}
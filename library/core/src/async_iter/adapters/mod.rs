@@ -0,0 +1,23 @@
+//! Adapters returned by the provided methods of [`AsyncIterator`].
+//!
+//! These mirror the adapters in [`core::iter`](crate::iter). Every adapter
+//! structurally pins the async iterators and futures it contains, but never the
+//! closures it was given.
+//!
+//! [`AsyncIterator`]: super::AsyncIterator
+
+mod chain;
+mod filter;
+mod fuse;
+mod map;
+mod take;
+mod then;
+mod zip;
+
+pub use self::chain::Chain;
+pub use self::filter::Filter;
+pub use self::fuse::Fuse;
+pub use self::map::Map;
+pub use self::take::Take;
+pub use self::then::Then;
+pub use self::zip::Zip;
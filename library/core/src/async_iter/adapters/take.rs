@@ -0,0 +1,59 @@
+use crate::async_iter::AsyncIterator;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// An async iterator that only yields the first `n` values of `iter`.
+///
+/// This `struct` is created by the [`take`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`take`]: AsyncIterator::take
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> Take<I> {
+    pub(in crate::async_iter) fn new(iter: I, n: usize) -> Take<I> {
+        Take { iter, n }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator> AsyncIterator for Take<I> {
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<I::Item>> {
+        // SAFETY: `iter` is structurally pinned and `n` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.n == 0 {
+            return Poll::Ready(None);
+        }
+        let iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        let next = iter.poll_next(cx);
+        match next {
+            Poll::Ready(Some(_)) => this.n -= 1,
+            // Do not poll `iter` after it is exhausted.
+            Poll::Ready(None) => this.n = 0,
+            Poll::Pending => {}
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.size_hint();
+        let lower = lower.min(self.n);
+        let upper = match upper {
+            Some(x) if x < self.n => Some(x),
+            _ => Some(self.n),
+        };
+        (lower, upper)
+    }
+}
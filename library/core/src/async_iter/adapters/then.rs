@@ -0,0 +1,67 @@
+use crate::async_iter::AsyncIterator;
+use crate::fmt;
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// An async iterator that maps the values of `iter` to futures with `f`, and
+/// yields their outputs.
+///
+/// This `struct` is created by the [`then`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`then`]: AsyncIterator::then
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Then<I, F, Fut> {
+    iter: I,
+    f: F,
+    /// The future of the item that is being processed.
+    future: Option<Fut>,
+}
+
+impl<I, F, Fut> Then<I, F, Fut> {
+    pub(in crate::async_iter) fn new(iter: I, f: F) -> Then<I, F, Fut> {
+        Then { iter, f, future: None }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: fmt::Debug, F, Fut: fmt::Debug> fmt::Debug for Then<I, F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Then").field("iter", &self.iter).field("future", &self.future).finish()
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: AsyncIterator, F, Fut> AsyncIterator for Then<I, F, Fut>
+where
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Fut::Output>> {
+        // SAFETY: `iter` and `future` are structurally pinned and `f` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut iter = unsafe { Pin::new_unchecked(&mut this.iter) };
+        let mut future = unsafe { Pin::new_unchecked(&mut this.future) };
+        loop {
+            if let Some(fut) = future.as_mut().as_pin_mut() {
+                let output = ready!(fut.poll(cx));
+                future.set(None);
+                return Poll::Ready(Some(output));
+            }
+            match ready!(iter.as_mut().poll_next(cx)) {
+                Some(item) => future.set(Some((this.f)(item))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.future.is_some() as usize;
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_add(pending), upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::async_iter::AsyncIterator;
+use crate::pin::Pin;
+use crate::task::{ready, Context, Poll};
+
+/// An async iterator that iterates two other async iterators simultaneously.
+///
+/// This `struct` is created by the [`zip`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`zip`]: AsyncIterator::zip
+#[derive(Clone, Debug)]
+#[must_use = "async iterators do nothing unless polled"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Zip<A: AsyncIterator, B> {
+    a: A,
+    b: B,
+    /// The item of `a` that waits for the next item of `b`.
+    a_item: Option<A::Item>,
+}
+
+impl<A: AsyncIterator, B> Zip<A, B> {
+    pub(in crate::async_iter) fn new(a: A, b: B) -> Zip<A, B> {
+        Zip { a, b, a_item: None }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<A, B> AsyncIterator for Zip<A, B>
+where
+    A: AsyncIterator,
+    B: AsyncIterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: `a` and `b` are structurally pinned and `a_item` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.a_item.is_none() {
+            let a = unsafe { Pin::new_unchecked(&mut this.a) };
+            match ready!(a.poll_next(cx)) {
+                Some(item) => this.a_item = Some(item),
+                None => return Poll::Ready(None),
+            }
+        }
+        let b = unsafe { Pin::new_unchecked(&mut this.b) };
+        let b_item = ready!(b.poll_next(cx));
+        // Like `Iterator::zip`, the item of `a` is dropped if `b` is exhausted.
+        let a_item = this.a_item.take();
+        Poll::Ready(a_item.zip(b_item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.a_item.is_some() as usize;
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let a_lower = a_lower.saturating_add(pending);
+        let a_upper = a_upper.and_then(|upper| upper.checked_add(pending));
+
+        let lower = a_lower.min(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
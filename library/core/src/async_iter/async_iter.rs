@@ -1,3 +1,6 @@
+use super::adapters::{Chain, Filter, Fuse, Map, Take, Then, Zip};
+use super::next::Next;
+use crate::future::Future;
 use crate::ops::DerefMut;
 use crate::pin::Pin;
 use crate::task::{Context, Poll};
@@ -80,6 +83,262 @@ pub trait AsyncIterator {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Returns a future that resolves to the next item of the async iterator,
+    /// or to `None` once it is exhausted.
+    ///
+    /// This is the async counterpart of [`Iterator::next`]. It requires the
+    /// async iterator to be [`Unpin`]; other async iterators can be pinned
+    /// first, for example with [`pin!`](crate::pin::pin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter([1, 2]);
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(2)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Next::new(self)
+    }
+
+    /// Takes a closure and creates an async iterator which calls that closure
+    /// on each item, like [`Iterator::map`].
+    ///
+    /// The closure is synchronous. To call an async function or closure on each
+    /// item, use [`then`](AsyncIterator::then) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter([1, 2]).map(|x| x * 10);
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(10)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(20)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        Map::new(self, f)
+    }
+
+    /// Creates an async iterator which uses a closure to determine if an item
+    /// should be yielded, like [`Iterator::filter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter(0..5).filter(|x| x % 2 == 1);
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(3)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Takes a closure returning a future and creates an async iterator which
+    /// yields the outputs of the futures it returns for each item.
+    ///
+    /// Each future is awaited before the next item is pulled from the
+    /// underlying async iterator, so at most one future runs at a time.
+    ///
+    /// This is the adapter to use with async closures, whose futures must not
+    /// borrow from the closure itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let iter = async_iter::from_iter([1, 2]).then(|x| async move { x * 10 });
+    /// let mut iter = pin!(iter);
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(10)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(20)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn then<F, Fut>(self, f: F) -> Then<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future,
+    {
+        Then::new(self, f)
+    }
+
+    /// Creates an async iterator that yields the first `n` items, or fewer if
+    /// the underlying async iterator ends sooner, like [`Iterator::take`].
+    ///
+    /// The underlying async iterator is not polled again once `n` items have
+    /// been yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter(1..).take(2);
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(2)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Creates an async iterator which ends after the first `None`, like
+    /// [`Iterator::fuse`].
+    ///
+    /// After an async iterator returns `Poll::Ready(None)`, future calls may or
+    /// may not yield more items, or may panic. `fuse()` makes sure that the
+    /// underlying async iterator is not polled again after it has finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter([1]).fuse();
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// assert!(iter.is_done());
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        Fuse::new(self)
+    }
+
+    /// 'Zips up' two async iterators into a single async iterator of pairs,
+    /// like [`Iterator::zip`].
+    ///
+    /// The new async iterator ends as soon as either of the two ends. An item
+    /// of the first one that is ready before the second one is kept until the
+    /// second one yields an item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter([1, 2, 3]).zip(async_iter::from_iter("ab".chars()));
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some((1, 'a'))));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some((2, 'b'))));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn zip<U>(self, other: U) -> Zip<Self, U::IntoAsyncIter>
+    where
+        Self: Sized,
+        U: IntoAsyncIterator,
+    {
+        Zip::new(self, other.into_async_iter())
+    }
+
+    /// Takes two async iterators and creates a new async iterator over both in
+    /// sequence, like [`Iterator::chain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(async_iterator, async_iter_from_iter, async_iter_adapters, noop_waker)]
+    ///
+    /// use std::async_iter::{self, AsyncIterator};
+    /// use std::future::Future;
+    /// use std::pin::pin;
+    /// use std::task::{Context, Poll, Waker};
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// let mut iter = async_iter::from_iter([1]).chain(async_iter::from_iter([2]));
+    ///
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(2)));
+    /// assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+    /// ```
+    #[unstable(feature = "async_iter_adapters", issue = "none")]
+    fn chain<U>(self, other: U) -> Chain<Self, U::IntoAsyncIter>
+    where
+        Self: Sized,
+        U: IntoAsyncIterator<Item = Self::Item>,
+    {
+        Chain::new(self, other.into_async_iter())
+    }
 }
 
 #[unstable(feature = "async_iterator", issue = "79024")]
@@ -121,8 +121,12 @@
 //! warning: unused result that must be used: async iterators do nothing unless polled
 //! ```
 
+mod adapters;
 mod async_iter;
 mod from_iter;
+mod next;
 
+pub use adapters::{Chain, Filter, Fuse, Map, Take, Then, Zip};
 pub use async_iter::{AsyncIterator, IntoAsyncIterator};
 pub use from_iter::{from_iter, FromIter};
+pub use next::Next;
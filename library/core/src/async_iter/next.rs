@@ -0,0 +1,32 @@
+use crate::async_iter::AsyncIterator;
+use crate::future::Future;
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// A future that resolves to the next item of an async iterator.
+///
+/// This `struct` is created by the [`next`] method on [`AsyncIterator`]. See
+/// its documentation for more.
+///
+/// [`next`]: AsyncIterator::next
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+pub struct Next<'a, I: ?Sized> {
+    iter: &'a mut I,
+}
+
+impl<'a, I: ?Sized> Next<'a, I> {
+    pub(super) fn new(iter: &'a mut I) -> Next<'a, I> {
+        Next { iter }
+    }
+}
+
+#[unstable(feature = "async_iter_adapters", issue = "none")]
+impl<I: ?Sized + AsyncIterator + Unpin> Future for Next<'_, I> {
+    type Output = Option<I::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.iter).poll_next(cx)
+    }
+}
@@ -1071,6 +1071,9 @@ fn description(&self) -> &str {
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 impl Error for crate::time::TryFromFloatSecsError {}
 
+#[unstable(feature = "duration_human", issue = "none")]
+impl Error for crate::time::ParseDurationError {}
+
 #[stable(feature = "cstr_from_bytes_until_nul", since = "1.69.0")]
 impl Error for crate::ffi::FromBytesUntilNulError {}
 
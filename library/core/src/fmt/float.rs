@@ -1,4 +1,6 @@
-use crate::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use crate::fmt::{
+    Debug, Display, Formatter, LowerExp, LowerHexFloat, Result, UpperExp, UpperHexFloat,
+};
 use crate::mem::MaybeUninit;
 use crate::num::{flt2dec, fmt as numfmt};
 
@@ -195,6 +197,139 @@ fn float_to_general_debug<T>(fmt: &mut Formatter<'_>, num: &T) -> Result
     }
 }
 
+// Common code of floating point LowerHexFloat and UpperHexFloat, working on the
+// bits of a number with the given number of explicit mantissa and of exponent
+// bits.
+fn float_to_hexadecimal_common(
+    fmt: &mut Formatter<'_>,
+    bits: u128,
+    mantissa_bits: u32,
+    exponent_bits: u32,
+    upper: bool,
+) -> Result {
+    let negative = (bits >> (mantissa_bits + exponent_bits)) & 1 != 0;
+    let biased_exponent = (bits >> mantissa_bits) & ((1 << exponent_bits) - 1);
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+    let bias = (1 << (exponent_bits - 1)) - 1;
+
+    if biased_exponent == (1 << exponent_bits) - 1 {
+        // Infinities and NaNs are written like with `Display`.
+        let (sign, special): (_, &[u8]) = match (mantissa != 0, negative, fmt.sign_plus()) {
+            (true, _, _) => ("", b"NaN"),
+            (false, true, _) => ("-", b"inf"),
+            (false, false, true) => ("+", b"inf"),
+            (false, false, false) => ("", b"inf"),
+        };
+        let parts = [numfmt::Part::Copy(special)];
+        // SAFETY: All parts are ASCII.
+        return unsafe { fmt.pad_formatted_parts(&numfmt::Formatted { sign, parts: &parts }) };
+    }
+
+    // Like C's `%a`, subnormal numbers are written with a leading zero and the
+    // exponent of the smallest normal number.
+    let (mut leading, exponent) = match biased_exponent {
+        0 if mantissa == 0 => (0, 0),
+        0 => (0, 1 - bias),
+        _ => (1, biased_exponent as i32 - bias),
+    };
+
+    // The mantissa is padded with zero bits to whole hexadecimal digits.
+    let digits = mantissa_bits.div_ceil(4) as usize;
+    let mut fraction = mantissa << (digits * 4 - mantissa_bits as usize);
+    let (shown, extra_zeros) = match fmt.precision {
+        Some(precision) if precision < digits => {
+            // Rounds half to even, which may carry into the leading digit.
+            let dropped = (digits - precision) * 4;
+            let rest = fraction & ((1 << dropped) - 1);
+            let half = 1 << (dropped - 1);
+            fraction >>= dropped;
+            let odd = if precision == 0 { leading & 1 == 1 } else { fraction & 1 == 1 };
+            if rest > half || rest == half && odd {
+                fraction += 1;
+                if fraction >> (precision * 4) != 0 {
+                    fraction = 0;
+                    leading += 1;
+                }
+            }
+            (precision, 0)
+        }
+        Some(precision) => (digits, precision - digits),
+        None => {
+            // Without a precision, the trailing zeros are left out.
+            let mut shown = digits;
+            while shown > 0 && fraction & 0xf == 0 {
+                fraction >>= 4;
+                shown -= 1;
+            }
+            (shown, 0)
+        }
+    };
+
+    let hex_digits = if upper { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+    // The leading digit, the point, and at most 28 digits for `f128`.
+    let mut buf = [0; 30];
+    buf[0] = hex_digits[leading];
+    let mut len = 1;
+    if shown + extra_zeros > 0 {
+        buf[1] = b'.';
+        len += 1;
+        for i in (0..shown).rev() {
+            buf[len] = hex_digits[(fraction >> (i * 4)) as usize & 0xf];
+            len += 1;
+        }
+    }
+
+    // The `0x` is part of the sign, so that sign-aware zero padding goes after it.
+    let sign = match (negative, fmt.sign_plus()) {
+        (true, _) => "-0x",
+        (false, true) => "+0x",
+        (false, false) => "0x",
+    };
+    let exponent_sign: &[u8] = match (upper, exponent < 0) {
+        (false, false) => b"p",
+        (false, true) => b"p-",
+        (true, false) => b"P",
+        (true, true) => b"P-",
+    };
+    let parts = [
+        numfmt::Part::Copy(&buf[..len]),
+        numfmt::Part::Zero(extra_zeros),
+        numfmt::Part::Copy(exponent_sign),
+        numfmt::Part::Num(exponent.unsigned_abs() as u16),
+    ];
+    // SAFETY: All parts are ASCII.
+    unsafe { fmt.pad_formatted_parts(&numfmt::Formatted { sign, parts: &parts }) }
+}
+
+macro_rules! floating_hex {
+    ($($ty:ident: $mantissa_bits:literal, $exponent_bits:literal;)*) => {
+        $(
+        #[unstable(feature = "hex_float", issue = "none")]
+        impl LowerHexFloat for $ty {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                let bits = self.to_bits() as u128;
+                float_to_hexadecimal_common(fmt, bits, $mantissa_bits, $exponent_bits, false)
+            }
+        }
+
+        #[unstable(feature = "hex_float", issue = "none")]
+        impl UpperHexFloat for $ty {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                let bits = self.to_bits() as u128;
+                float_to_hexadecimal_common(fmt, bits, $mantissa_bits, $exponent_bits, true)
+            }
+        }
+        )*
+    };
+}
+
+floating_hex! {
+    f16: 10, 5;
+    f32: 23, 8;
+    f64: 52, 11;
+    f128: 112, 15;
+}
+
 macro_rules! floating {
     ($ty:ident) => {
         #[stable(feature = "rust1", since = "1.0.0")]
@@ -10,12 +10,14 @@
 use crate::{iter, mem, result, str};
 
 mod builders;
+pub(crate) mod display_buffer;
 #[cfg(not(no_fp_fmt_parse))]
 mod float;
 #[cfg(no_fp_fmt_parse)]
 mod nofloat;
 mod num;
 mod rt;
+mod width;
 
 #[stable(feature = "fmt_flags_align", since = "1.28.0")]
 #[cfg_attr(not(test), rustc_diagnostic_item = "Alignment")]
@@ -37,6 +39,8 @@ pub enum Alignment {
 pub use self::builders::{from_fn, FromFn};
 #[stable(feature = "debug_builders", since = "1.2.0")]
 pub use self::builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
+#[unstable(feature = "fmt_display_width", issue = "none")]
+pub use self::width::DisplayWidth;
 
 /// The type returned by formatter methods.
 ///
@@ -1125,6 +1129,70 @@ pub trait UpperExp {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result;
 }
 
+/// `a` formatting.
+///
+/// The `LowerHexFloat` trait should format its output as a hexadecimal
+/// floating point number, with lower-case digits and a lower-case `p`
+/// introducing the binary exponent, like C's `%a`.
+///
+/// The formatting of the primitive floating point types is exact, so the
+/// output can be parsed back with their `from_str_hex` methods. The leading
+/// digit is `1`, or `0` for zero and subnormal numbers, which use the exponent
+/// of the smallest normal number. A precision gives the number of hexadecimal
+/// digits after the point, rounding the value half to even if it does not fit.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(hex_float)]
+///
+/// let x = 12.0; // 12.0 is 1.5 * 2^3
+///
+/// assert_eq!(format!("{x:a}"), "0x1.8p3");
+/// assert_eq!(format!("{:a}", -0.1f32), "-0x1.99999ap-4");
+/// assert_eq!(format!("{:.1a}", 0.1f64), "0x1.ap-4");
+/// assert_eq!(format!("{:a}", f64::MIN_POSITIVE / 2.0), "0x0.8p-1022");
+/// ```
+#[unstable(feature = "hex_float", issue = "none")]
+pub trait LowerHexFloat {
+    #[doc = include_str!("fmt_trait_method_doc.md")]
+    #[unstable(feature = "hex_float", issue = "none")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+/// `A` formatting.
+///
+/// The `UpperHexFloat` trait should format its output like [`LowerHexFloat`],
+/// but with upper-case digits and an upper-case `P`.
+///
+/// For more information on formatters, see [the module-level documentation][module].
+///
+/// [module]: ../../std/fmt/index.html
+///
+/// # Examples
+///
+/// Basic usage with `f64`:
+///
+/// ```
+/// #![feature(hex_float)]
+///
+/// let x = 1000.0;
+///
+/// assert_eq!(format!("{x:A}"), "0x1.F4P9");
+/// ```
+#[unstable(feature = "hex_float", issue = "none")]
+pub trait UpperHexFloat {
+    #[doc = include_str!("fmt_trait_method_doc.md")]
+    #[unstable(feature = "hex_float", issue = "none")]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
 /// Takes an output stream and an `Arguments` struct that can be precompiled with
 /// the `format_args!` macro.
 ///
@@ -1405,7 +1473,9 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
     /// * precision - the maximum length to emit, the string is truncated if it
     ///               is longer than this length
     ///
-    /// Notably this function ignores the `flag` parameters.
+    /// Notably this function ignores the `flag` parameters. Both lengths are
+    /// counted in [`char`]s, or in columns if the value is wrapped in
+    /// [`DisplayWidth`].
     ///
     /// # Examples
     ///
@@ -1435,7 +1505,12 @@ pub fn pad(&mut self, s: &str) -> Result {
             // If our string is longer that the precision, then we must have
             // truncation. However other flags like `fill`, `width` and `align`
             // must act as always.
-            if let Some((i, _)) = s.char_indices().nth(max) {
+            let end = if self.pads_by_display_width() {
+                width::truncate(s, max)
+            } else {
+                s.char_indices().nth(max).map(|(i, _)| i)
+            };
+            if let Some(i) = end {
                 // LLVM here can't prove that `..i` won't panic `&s[..i]`, but
                 // we know that it can't panic. Use `get` + `unwrap_or` to avoid
                 // `unsafe` and otherwise don't emit any panic-related code
@@ -1453,7 +1528,11 @@ pub fn pad(&mut self, s: &str) -> Result {
             // requirements, then we can just emit the string
             None => self.buf.write_str(s),
             Some(width) => {
-                let chars_count = s.chars().count();
+                let chars_count = if self.pads_by_display_width() {
+                    width::str_width(s)
+                } else {
+                    s.chars().count()
+                };
                 // If we're under the maximum width, check if we're over the minimum
                 // width, if so it's as easy as just emitting the string.
                 if chars_count >= width {
@@ -1920,6 +1999,37 @@ pub fn sign_aware_zero_pad(&self) -> bool {
         self.flags & (1 << rt::Flag::SignAwareZeroPad as u32) != 0
     }
 
+    /// Determines if the width and precision are measured in the columns that
+    /// the output takes up when displayed, see [`DisplayWidth`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_display_width)]
+    ///
+    /// use std::fmt::{self, DisplayWidth};
+    ///
+    /// struct Foo;
+    ///
+    /// impl fmt::Display for Foo {
+    ///     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         if formatter.pads_by_display_width() {
+    ///             formatter.write_str("columns")
+    ///         } else {
+    ///             formatter.write_str("chars")
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(format!("{}", DisplayWidth(Foo)), "columns");
+    /// assert_eq!(format!("{}", Foo), "chars");
+    /// ```
+    #[must_use]
+    #[unstable(feature = "fmt_display_width", issue = "none")]
+    pub fn pads_by_display_width(&self) -> bool {
+        self.flags & (1 << rt::Flag::DisplayWidth as u32) != 0
+    }
+
     // FIXME: Decide what public API we want for these two flags.
     // https://github.com/rust-lang/rust/issues/48584
     fn debug_lower_hex(&self) -> bool {
@@ -2391,6 +2501,34 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result { $tr::fmt(&**self, f) }
 
 fmt_refs! { Debug, Display, Octal, Binary, LowerHex, UpperHex, LowerExp, UpperExp }
 
+#[unstable(feature = "hex_float", issue = "none")]
+impl<T: ?Sized + LowerHexFloat> LowerHexFloat for &T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerHexFloat::fmt(&**self, f)
+    }
+}
+
+#[unstable(feature = "hex_float", issue = "none")]
+impl<T: ?Sized + LowerHexFloat> LowerHexFloat for &mut T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerHexFloat::fmt(&**self, f)
+    }
+}
+
+#[unstable(feature = "hex_float", issue = "none")]
+impl<T: ?Sized + UpperHexFloat> UpperHexFloat for &T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperHexFloat::fmt(&**self, f)
+    }
+}
+
+#[unstable(feature = "hex_float", issue = "none")]
+impl<T: ?Sized + UpperHexFloat> UpperHexFloat for &mut T {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperHexFloat::fmt(&**self, f)
+    }
+}
+
 #[unstable(feature = "never_type", issue = "35121")]
 impl Debug for ! {
     #[inline]
@@ -63,6 +63,8 @@ pub(super) enum Flag {
     SignAwareZeroPad,
     DebugLowerHex,
     DebugUpperHex,
+    /// Never set by `format_args!`, only by the `DisplayWidth` wrapper.
+    DisplayWidth,
 }
 
 #[derive(Copy, Clone)]
@@ -150,6 +152,14 @@ pub fn new_upper_exp<'b, T: UpperExp>(x: &'b T) -> Argument<'b> {
         Self::new(x, UpperExp::fmt)
     }
     #[inline(always)]
+    pub fn new_lower_hex_float<'b, T: LowerHexFloat>(x: &'b T) -> Argument<'b> {
+        Self::new(x, LowerHexFloat::fmt)
+    }
+    #[inline(always)]
+    pub fn new_upper_hex_float<'b, T: UpperHexFloat>(x: &'b T) -> Argument<'b> {
+        Self::new(x, UpperHexFloat::fmt)
+    }
+    #[inline(always)]
     pub fn from_usize(x: &usize) -> Argument<'_> {
         Argument { ty: ArgumentType::Count(*x) }
     }
@@ -0,0 +1,75 @@
+//! Padding by the number of columns that a string takes up when displayed.
+
+use crate::fmt::{self, Display, Formatter};
+use crate::unicode::{Wide, Zero_Width};
+
+/// Formats the wrapped value with the width and precision measured in the
+/// columns that the output takes up in a terminal, instead of in [`char`]s.
+///
+/// Characters with an East Asian Width of Wide or Fullwidth, like CJK
+/// ideographs and most emoji, take up two columns. Control characters,
+/// format characters and combining marks take up none. Everything else takes
+/// up one column.
+///
+/// This only changes the result for types that are padded with
+/// [`Formatter::pad`], like [`str`] and [`char`]. The widths of nested
+/// arguments in the format string of a [`Display`] implementation are not
+/// affected either.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_display_width)]
+///
+/// use std::fmt::DisplayWidth;
+///
+/// assert_eq!(format!("[{:<6}]", "日本"), "[日本    ]");
+/// assert_eq!(format!("[{:<6}]", DisplayWidth("日本")), "[日本  ]");
+/// assert_eq!(format!("[{:>4}]", DisplayWidth("e\u{301}")), "[   e\u{301}]");
+/// assert_eq!(format!("[{:.3}]", DisplayWidth("日本語")), "[日]");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[unstable(feature = "fmt_display_width", issue = "none")]
+pub struct DisplayWidth<T>(#[unstable(feature = "fmt_display_width", issue = "none")] pub T);
+
+#[unstable(feature = "fmt_display_width", issue = "none")]
+impl<T: Display> Display for DisplayWidth<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let flags = f.flags;
+        f.flags |= 1 << super::rt::Flag::DisplayWidth as u32;
+        let result = self.0.fmt(f);
+        f.flags = flags;
+        result
+    }
+}
+
+/// Returns the number of columns that `c` takes up when displayed.
+fn char_width(c: char) -> usize {
+    if c.is_ascii() {
+        !c.is_ascii_control() as usize
+    } else if Zero_Width(c) {
+        0
+    } else if Wide(c) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the number of columns that `s` takes up when displayed.
+pub(super) fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Returns the length in bytes of the longest prefix of `s` that takes up at
+/// most `max` columns, or `None` if all of `s` does.
+pub(super) fn truncate(s: &str, max: usize) -> Option<usize> {
+    let mut width = 0;
+    for (i, c) in s.char_indices() {
+        width += char_width(c);
+        if width > max {
+            return Some(i);
+        }
+    }
+    None
+}
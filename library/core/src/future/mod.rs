@@ -18,7 +18,9 @@
 mod join;
 mod pending;
 mod poll_fn;
+mod race;
 mod ready;
+mod select;
 
 #[unstable(feature = "async_drop", issue = "126482")]
 pub use async_drop::{async_drop, async_drop_in_place, AsyncDrop, AsyncDropInPlace};
@@ -28,6 +30,8 @@
 pub use pending::{pending, Pending};
 #[stable(feature = "future_poll_fn", since = "1.64.0")]
 pub use poll_fn::{poll_fn, PollFn};
+#[unstable(feature = "future_race", issue = "none")]
+pub use race::{race, Race};
 #[stable(feature = "future_readiness_fns", since = "1.48.0")]
 pub use ready::{ready, Ready};
 
@@ -35,6 +39,8 @@
 pub use self::future::Future;
 #[unstable(feature = "future_join", issue = "91642")]
 pub use self::join::join;
+#[unstable(feature = "future_select", issue = "none")]
+pub use self::select::select;
 
 /// This type is needed because:
 ///
@@ -0,0 +1,90 @@
+use crate::fmt;
+use crate::future::{Future, IntoFuture};
+use crate::pin::Pin;
+use crate::task::{Context, Poll};
+
+/// Waits for the first of two futures to complete, and returns its output.
+///
+/// The other future is dropped as soon as one of them completes, before the
+/// output is returned. This is the building block of timeouts and
+/// cancellation: race the work against a timer or a cancellation signal.
+///
+/// `race` is biased: each time the returned future is polled, `a` is polled
+/// before `b`, so `a` wins if both are ready. Use [`select!`] for more than two
+/// futures or for futures with different output types.
+///
+/// [`select!`]: crate::future::select
+///
+/// # Examples
+///
+/// ```
+/// #![feature(future_race)]
+///
+/// use std::future::{self, race};
+///
+/// # let _ = async {
+/// let output = race(future::pending(), async { 1 }).await;
+/// assert_eq!(output, 1);
+/// # };
+/// ```
+#[unstable(feature = "future_race", issue = "none")]
+pub fn race<A, B>(a: A, b: B) -> Race<A::IntoFuture, B::IntoFuture>
+where
+    A: IntoFuture,
+    B: IntoFuture<Output = A::Output>,
+{
+    Race { futures: Some((a.into_future(), b.into_future())) }
+}
+
+/// A future that waits for the first of two futures to complete.
+///
+/// This `struct` is created by the [`race()`] function. See its
+/// documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[unstable(feature = "future_race", issue = "none")]
+pub struct Race<A, B> {
+    /// Both futures, until one of them has completed.
+    futures: Option<(A, B)>,
+}
+
+#[unstable(feature = "future_race", issue = "none")]
+impl<A: fmt::Debug, B: fmt::Debug> fmt::Debug for Race<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Race").field("futures", &self.futures).finish()
+    }
+}
+
+#[unstable(feature = "future_race", issue = "none")]
+impl<A, B> Future for Race<A, B>
+where
+    A: Future,
+    B: Future<Output = A::Output>,
+{
+    type Output = A::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<A::Output> {
+        // SAFETY: the futures are structurally pinned, they are only ever
+        // dropped in place.
+        let mut futures = unsafe { self.map_unchecked_mut(|race| &mut race.futures) };
+        let Some((a, b)) = futures.as_mut().as_pin_mut().map(|futures| {
+            // SAFETY: pinning projection.
+            unsafe {
+                let (a, b) = futures.get_unchecked_mut();
+                (Pin::new_unchecked(a), Pin::new_unchecked(b))
+            }
+        }) else {
+            panic!("`Race` polled after completion")
+        };
+
+        let output = match a.poll(cx) {
+            Poll::Ready(output) => output,
+            Poll::Pending => match b.poll(cx) {
+                Poll::Ready(output) => output,
+                Poll::Pending => return Poll::Pending,
+            },
+        };
+        // Drop the loser, and the winner along with it.
+        futures.set(None);
+        Poll::Ready(output)
+    }
+}
@@ -0,0 +1,181 @@
+#![allow(unused_imports, unused_macros)] // items are used by the macro
+
+use crate::future::{poll_fn, Future, IntoFuture};
+use crate::pin::{pin, Pin};
+use crate::task::Poll;
+
+/// Waits on multiple futures simultaneously, running the branch of the first
+/// future that completes with an output matching its pattern.
+///
+/// Each branch has the form `pattern = future => handler`, and the last branch
+/// may be an `else => handler` branch. `select!` must be used in an async
+/// context, where it evaluates all futures, and then polls them until one
+/// completes:
+///
+/// * If its output matches the pattern of its branch, all other futures are
+///   dropped, and the handler is evaluated with the bindings of the pattern.
+///   The value of the handler is the value of the `select!` expression.
+/// * Otherwise, the branch is disabled and the remaining futures are polled.
+///   If all branches are disabled, the `else` handler is evaluated instead, or
+///   `select!` panics if there is none.
+///
+/// Handlers are evaluated in the enclosing function, so `return`, `break`,
+/// `?` and `.await` in them act on that function as usual. Patterns are
+/// also matched against a reference to the output, to find out whether a
+/// branch is disabled, so `&` patterns and `mut` bindings of values that are
+/// not `Copy` are not supported.
+///
+/// `select!` is biased: the futures are polled in the order of the branches
+/// each time, so an earlier branch wins if several futures are ready. A
+/// future that is always ready can starve the branches after it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(future_select)]
+///
+/// use std::future::{self, select};
+///
+/// # let _ = async {
+/// let output = select! {
+///     x = future::pending::<i32>() => x,
+///     y = async { 2 } => y * 10,
+/// };
+/// assert_eq!(output, 20);
+/// # };
+/// ```
+///
+/// Branches whose output does not match are skipped, and the `else` branch
+/// runs once no branch can match anymore:
+///
+/// ```
+/// #![feature(future_select)]
+///
+/// use std::future::{self, select};
+///
+/// async fn lookup(found: bool) -> Option<&'static str> {
+///     found.then_some("found")
+/// }
+///
+/// # let _ = async {
+/// let output = select! {
+///     Some(value) = lookup(false) => value,
+///     Some(value) = lookup(true) => value,
+///     else => "missing",
+/// };
+/// assert_eq!(output, "found");
+///
+/// let output = select! {
+///     Some(value) = lookup(false) => value,
+///     else => "missing",
+/// };
+/// assert_eq!(output, "missing");
+/// # };
+/// ```
+#[unstable(feature = "future_select", issue = "none")]
+pub macro select( $($branches:tt)+ ) {
+    // Funnel through an internal macro not to leak implementation details.
+    select_internal! {
+        current_position: []
+        branches: []
+        else_branch: [
+            panic!("all branches of `select!` are disabled and there is no `else` branch")
+        ]
+        munching: [ $($branches)+ ]
+    }
+}
+
+// FIXME: a private macro should need no stability guarantee.
+#[unstable(feature = "future_select", issue = "none")]
+/// Like `join_internal!`, this numbers the branches with a repetition of `_`,
+/// which is used to name the i-th future and output in a tuple.
+macro select_internal {
+    // Munch the `else` branch, which must be the last one.
+    (
+        current_position: $position:tt
+        branches: $branches:tt
+        else_branch: $_:tt
+        munching: [ else => $else:expr $(,)? ]
+    ) => (
+        select_internal! {
+            current_position: $position
+            branches: $branches
+            else_branch: [ $else ]
+            munching: []
+        }
+    ),
+
+    // Munch one branch, and record it with its position.
+    (
+        current_position: [ $($underscores:tt)* ]
+        branches: [ $($acc:tt)* ]
+        else_branch: $else:tt
+        munching: [ $pat:pat = $fut:expr => $handler:expr $(, $($rest:tt)*)? ]
+    ) => (
+        select_internal! {
+            current_position: [ $($underscores)* _ ]
+            branches: [
+                $($acc)*
+                { ( $($underscores)* ) None ( $pat ) ( $fut ) ( $handler ) }
+            ]
+            else_branch: $else
+            munching: [ $($($rest)*)? ]
+        }
+    ),
+
+    // Nothing left to munch: generate the output expression.
+    (
+        current_position: $_:tt
+        branches: [
+            $( { ( $($pos:tt)* ) $none:ident ( $pat:pat ) ( $fut:expr ) ( $handler:expr ) } )+
+        ]
+        else_branch: [ $else:expr ]
+        munching: []
+    ) => ({
+        // The output of the winning branch, at its position in the tuple. All
+        // outputs are `None` if every branch is disabled.
+        let outputs = {
+            // A future is replaced by `None` once it has completed.
+            let mut futures = pin!(( $( Some(IntoFuture::into_future($fut)), )+ ));
+            let outputs = poll_fn(|cx| {
+                let mut outputs = ( $( $none, )+ );
+                let mut pending = false;
+                $(
+                    // SAFETY: pinning projection
+                    let mut fut = unsafe {
+                        futures.as_mut().map_unchecked_mut(|it| {
+                            let ( $($pos,)* fut, .. ) = it;
+                            fut
+                        })
+                    };
+                    if let Some(f) = fut.as_mut().as_pin_mut() {
+                        match f.poll(cx) {
+                            Poll::Ready(output) => {
+                                fut.set(None);
+                                #[allow(unreachable_patterns, unused_variables)]
+                                let matches = match &output {
+                                    $pat => true,
+                                    _ => false,
+                                };
+                                if matches {
+                                    let ( $($pos,)* slot, .. ) = &mut outputs;
+                                    *slot = Some(output);
+                                    return Poll::Ready(outputs);
+                                }
+                            }
+                            Poll::Pending => pending = true,
+                        }
+                    }
+                )+
+                if pending { Poll::Pending } else { Poll::Ready(outputs) }
+            })
+            .await;
+            outputs
+        };
+        // The futures have been dropped, so the handler runs without them.
+        match outputs {
+            $( ( $($pos,)* Some($pat), .. ) => $handler, )+
+            _ => $else,
+        }
+    }),
+}
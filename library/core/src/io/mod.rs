@@ -1,6 +1,291 @@
 //! Traits, helpers, and type definitions for core I/O functionality.
+//!
+//! The [`Read`], [`Write`], [`BufRead`] and [`Seek`] traits of this module are
+//! the `no_std` counterparts of the traits of the same names in `std::io`.
+//! Instead of the allocating `std::io::Error`, every implementor picks its own
+//! error type through [`ErrorType`], so that drivers for embedded devices and
+//! code using the standard library can share the same traits. The standard
+//! library implements them for its files, sockets and standard streams, with
+//! `std::io::Error` as the error type.
 
 mod borrowed_buf;
+mod cursor;
+mod error;
+mod impls;
+mod seek_from;
 
 #[unstable(feature = "core_io_borrowed_buf", issue = "117693")]
 pub use self::borrowed_buf::{BorrowedBuf, BorrowedCursor};
+#[unstable(feature = "core_io", issue = "none")]
+pub use self::cursor::Cursor;
+#[doc(hidden)]
+#[unstable(feature = "core_io_internals", issue = "none")]
+pub use self::cursor::CursorBuffer;
+#[unstable(feature = "core_io", issue = "none")]
+pub use self::error::{Error, ErrorKind, ErrorType};
+#[unstable(feature = "core_io", issue = "none")]
+pub use self::seek_from::SeekFrom;
+use crate::fmt;
+
+/// The `Read` trait allows for reading bytes from a source.
+///
+/// This is the `no_std` counterpart of `std::io::Read`, see there for the
+/// contract of [`read`]. Errors are of the type chosen through [`ErrorType`].
+///
+/// [`read`]: Read::read
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_io)]
+/// use core::io::Read;
+///
+/// let mut bytes: &[u8] = b"hello";
+/// let mut buf = [0; 3];
+///
+/// bytes.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"hel");
+/// assert_eq!(bytes, b"lo");
+/// ```
+#[unstable(feature = "core_io", issue = "none")]
+pub trait Read: ErrorType {
+    /// Pulls some bytes from this source into the specified buffer, returning
+    /// how many bytes were read.
+    ///
+    /// A return value of `Ok(0)` means that the source reached its end, or
+    /// that `buf` was 0 bytes in length.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+
+    /// Reads the exact number of bytes required to fill `buf`.
+    ///
+    /// Errors of the kind [`ErrorKind::Interrupted`] are ignored, and the read
+    /// is retried.
+    ///
+    /// # Errors
+    ///
+    /// If the source reaches its end before `buf` is filled, an error made
+    /// from [`ErrorKind::UnexpectedEof`] is returned. The contents of `buf` are
+    /// unspecified in this case, as is the number of bytes consumed from the
+    /// source.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), Self::Error> {
+        while !buf.is_empty() {
+            match self.read(buf) {
+                Ok(0) => break,
+                Ok(n) => buf = &mut buf[n..],
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if !buf.is_empty() { Err(ErrorKind::UnexpectedEof.into()) } else { Ok(()) }
+    }
+
+    /// Pulls some bytes from this source into the specified buffer.
+    ///
+    /// This is equivalent to [`read`](Read::read), except that it is passed a
+    /// [`BorrowedCursor`] rather than `[u8]` to allow use with uninitialized
+    /// buffers. The new data will be appended to any existing contents of
+    /// `buf`.
+    ///
+    /// The default implementation initializes the unfilled part of the buffer
+    /// and delegates to [`read`](Read::read).
+    #[unstable(feature = "core_io", issue = "none")]
+    fn read_buf(&mut self, mut buf: BorrowedCursor<'_>) -> Result<(), Self::Error> {
+        let n = self.read(buf.ensure_init().init_mut())?;
+        buf.advance(n);
+        Ok(())
+    }
+}
+
+/// A `BufRead` is a type of [`Read`]er which has an internal buffer.
+///
+/// This is the `no_std` counterpart of `std::io::BufRead`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_io)]
+/// use core::io::BufRead;
+///
+/// let mut bytes: &[u8] = b"hello";
+///
+/// assert_eq!(bytes.fill_buf().unwrap(), b"hello");
+/// bytes.consume(2);
+/// assert_eq!(bytes.fill_buf().unwrap(), b"llo");
+/// ```
+#[unstable(feature = "core_io", issue = "none")]
+pub trait BufRead: Read {
+    /// Returns the contents of the internal buffer, filling it with more data
+    /// from the inner reader if it is empty.
+    ///
+    /// An empty buffer returned indicates that the stream has reached EOF.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn fill_buf(&mut self) -> Result<&[u8], Self::Error>;
+
+    /// Tells this buffer that `amt` bytes have been consumed from the buffer,
+    /// so they should no longer be returned in calls to [`fill_buf`].
+    ///
+    /// The `amt` must be `<=` the number of bytes in the buffer returned by
+    /// [`fill_buf`].
+    ///
+    /// [`fill_buf`]: BufRead::fill_buf
+    #[unstable(feature = "core_io", issue = "none")]
+    fn consume(&mut self, amt: usize);
+}
+
+/// A trait for objects which are byte-oriented sinks.
+///
+/// This is the `no_std` counterpart of `std::io::Write`, see there for the
+/// contract of [`write`]. Errors are of the type chosen through [`ErrorType`].
+///
+/// [`write`]: Write::write
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_io)]
+/// use core::io::Write;
+///
+/// let mut buf = [0; 8];
+/// let mut out = &mut buf[..];
+///
+/// write!(out, "{}-{}", 4, 2).unwrap();
+/// assert_eq!(out.len(), 5);
+/// assert_eq!(&buf[..3], b"4-2");
+/// ```
+#[unstable(feature = "core_io", issue = "none")]
+pub trait Write: ErrorType {
+    /// Writes a buffer into this writer, returning how many bytes were
+    /// written.
+    ///
+    /// A return value of `Ok(0)` typically means that the underlying object is
+    /// no longer able to accept bytes, or that `buf` was 0 bytes in length.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error>;
+
+    /// Flushes this output stream, ensuring that all intermediately buffered
+    /// contents reach their destination.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn flush(&mut self) -> Result<(), Self::Error>;
+
+    /// Attempts to write an entire buffer into this writer.
+    ///
+    /// Errors of the kind [`ErrorKind::Interrupted`] are ignored, and the
+    /// write is retried.
+    ///
+    /// # Errors
+    ///
+    /// If a call to [`write`](Write::write) returns `Ok(0)` before the whole
+    /// buffer is written, an error made from [`ErrorKind::WriteZero`] is
+    /// returned.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn write_all(&mut self, mut buf: &[u8]) -> Result<(), Self::Error> {
+        while !buf.is_empty() {
+            match self.write(buf) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => buf = &buf[n..],
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a formatted string into this writer, returning any error
+    /// encountered.
+    ///
+    /// This method is primarily used to interface with the
+    /// [`format_args!()`] macro, and it is rare that this should explicitly
+    /// be called. The [`write!()`] macro should be favored to invoke this
+    /// method instead.
+    ///
+    /// # Errors
+    ///
+    /// This function will return any error returned by the underlying
+    /// [`write_all`](Write::write_all).
+    #[unstable(feature = "core_io", issue = "none")]
+    fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> Result<(), Self::Error> {
+        // Create a shim which translates a Write to a fmt::Write and saves
+        // off I/O errors. instead of discarding them
+        struct Adapter<'a, T: ?Sized + Write + 'a> {
+            inner: &'a mut T,
+            error: Result<(), T::Error>,
+        }
+
+        impl<T: Write + ?Sized> fmt::Write for Adapter<'_, T> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self.inner.write_all(s.as_bytes()) {
+                    Ok(()) => Ok(()),
+                    Err(e) => {
+                        self.error = Err(e);
+                        Err(fmt::Error)
+                    }
+                }
+            }
+        }
+
+        let mut output = Adapter { inner: self, error: Ok(()) };
+        match fmt::write(&mut output, fmt) {
+            Ok(()) => Ok(()),
+            Err(..) => {
+                // check if the error came from the underlying `Write` or not
+                if output.error.is_err() {
+                    output.error
+                } else {
+                    // This shouldn't happen: the underlying stream did not error, but somehow
+                    // the formatter still errored?
+                    panic!(
+                        "a formatting trait implementation returned an error when the underlying stream did not"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The `Seek` trait provides a cursor which can be moved within a stream of
+/// bytes.
+///
+/// This is the `no_std` counterpart of `std::io::Seek`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(core_io)]
+/// use core::io::{Cursor, Seek, SeekFrom};
+///
+/// let mut cursor = Cursor::new([1u8, 2, 3, 4, 5]);
+///
+/// assert_eq!(cursor.seek(SeekFrom::End(-2)).unwrap(), 3);
+/// assert_eq!(cursor.stream_position().unwrap(), 3);
+/// cursor.rewind().unwrap();
+/// assert_eq!(cursor.position(), 0);
+/// ```
+#[unstable(feature = "core_io", issue = "none")]
+pub trait Seek: ErrorType {
+    /// Seeks to an offset, in bytes, in a stream, and returns the new position
+    /// from the start of the stream.
+    ///
+    /// A seek beyond the end of a stream is allowed, but behavior is defined
+    /// by the implementation.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error>;
+
+    /// Rewinds to the beginning of a stream.
+    ///
+    /// This is a convenience method, equivalent to `seek(SeekFrom::Start(0))`.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn rewind(&mut self) -> Result<(), Self::Error> {
+        self.seek(SeekFrom::Start(0))?;
+        Ok(())
+    }
+
+    /// Returns the current seek position from the start of the stream.
+    ///
+    /// This is equivalent to `self.seek(SeekFrom::Current(0))`.
+    #[unstable(feature = "core_io", issue = "none")]
+    fn stream_position(&mut self) -> Result<u64, Self::Error> {
+        self.seek(SeekFrom::Current(0))
+    }
+}
@@ -1,5 +1,5 @@
-use super::display_buffer::DisplayBuffer;
 use crate::cmp::Ordering;
+use crate::fmt::display_buffer::DisplayBuffer;
 use crate::fmt::{self, Write};
 use crate::hash::{Hash, Hasher};
 use crate::iter;
@@ -0,0 +1,940 @@
+use crate::fmt::display_buffer::DisplayBuffer;
+use crate::fmt::{self, Write};
+use crate::iter::FusedIterator;
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// An IP network, either IPv4 or IPv6.
+///
+/// This enum can contain either an [`Ipv4Net`] or an [`Ipv6Net`], see their
+/// respective documentation for more details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_net)]
+/// use std::net::{IpAddr, IpNet, Ipv4Addr, Ipv6Addr};
+///
+/// let net: IpNet = "10.0.0.0/8".parse().unwrap();
+/// assert!(net.contains(IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3))));
+/// assert!(!net.contains(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+/// assert_eq!(net.to_string(), "10.0.0.0/8");
+/// ```
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum IpNet {
+    /// An IPv4 network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    V4(#[unstable(feature = "ip_net", issue = "none")] Ipv4Net),
+    /// An IPv6 network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    V6(#[unstable(feature = "ip_net", issue = "none")] Ipv6Net),
+}
+
+/// An IPv4 network, given by an address and a prefix length in CIDR notation.
+///
+/// The network consists of all addresses whose leading `prefix_len` bits are
+/// equal to those of the address. The address may have host bits set, as in
+/// `192.168.1.17/24` describing an interface, in which case [`network`] and
+/// [`trunc`] give the network address and the network itself.
+///
+/// [`network`]: Ipv4Net::network
+/// [`trunc`]: Ipv4Net::trunc
+///
+/// # Textual representation
+///
+/// `Ipv4Net` provides a [`FromStr`] implementation. The address is written as
+/// for [`Ipv4Addr`], followed by a `/` and the prefix length in decimal notation,
+/// without leading zeros.
+///
+/// [`FromStr`]: crate::str::FromStr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_net)]
+/// use std::net::{Ipv4Addr, Ipv4Net};
+///
+/// let net = Ipv4Net::new(Ipv4Addr::new(192, 168, 1, 17), 24).unwrap();
+/// assert_eq!("192.168.1.17/24".parse(), Ok(net));
+/// assert_eq!(net.network(), Ipv4Addr::new(192, 168, 1, 0));
+/// assert_eq!(net.broadcast(), Ipv4Addr::new(192, 168, 1, 255));
+/// assert!(net.contains(Ipv4Addr::new(192, 168, 1, 200)));
+/// assert!("192.168.1.0/33".parse::<Ipv4Net>().is_err());
+/// ```
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv4Net {
+    addr: Ipv4Addr,
+    prefix_len: u8,
+}
+
+/// An IPv6 network, given by an address and a prefix length in CIDR notation.
+///
+/// See [`Ipv4Net`] for the meaning of the address and the prefix length.
+///
+/// # Textual representation
+///
+/// `Ipv6Net` provides a [`FromStr`] implementation. The address is written as
+/// for [`Ipv6Addr`], followed by a `/` and the prefix length in decimal notation,
+/// without leading zeros.
+///
+/// [`FromStr`]: crate::str::FromStr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ip_net)]
+/// use std::net::{Ipv6Addr, Ipv6Net};
+///
+/// let net: Ipv6Net = "2001:db8::/32".parse().unwrap();
+/// assert_eq!(net.prefix_len(), 32);
+/// assert!(net.contains(Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6)));
+/// assert!(!net.contains(Ipv6Addr::LOCALHOST));
+/// ```
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Ipv6Net {
+    addr: Ipv6Addr,
+    prefix_len: u8,
+}
+
+impl IpNet {
+    /// Creates a new IP network from an address and a prefix length, or returns
+    /// `None` if the prefix length exceeds the number of bits in the address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::{IpAddr, IpNet, Ipv4Addr};
+    ///
+    /// let addr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0));
+    /// assert!(IpNet::new(addr, 8).is_some());
+    /// assert!(IpNet::new(addr, 33).is_none());
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn new(addr: IpAddr, prefix_len: u8) -> Option<IpNet> {
+        match addr {
+            IpAddr::V4(addr) => match Ipv4Net::new(addr, prefix_len) {
+                Some(net) => Some(IpNet::V4(net)),
+                None => None,
+            },
+            IpAddr::V6(addr) => match Ipv6Net::new(addr, prefix_len) {
+                Some(net) => Some(IpNet::V6(net)),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns the address the network was created with.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn addr(&self) -> IpAddr {
+        match self {
+            IpNet::V4(net) => IpAddr::V4(net.addr()),
+            IpNet::V6(net) => IpAddr::V6(net.addr()),
+        }
+    }
+
+    /// Returns the prefix length of the network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        match self {
+            IpNet::V4(net) => net.prefix_len(),
+            IpNet::V6(net) => net.prefix_len(),
+        }
+    }
+
+    /// Returns the network address, i.e. the address with all host bits cleared.
+    ///
+    /// See [`Ipv4Net::network`] and [`Ipv6Net::network`].
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn network(&self) -> IpAddr {
+        match self {
+            IpNet::V4(net) => IpAddr::V4(net.network()),
+            IpNet::V6(net) => IpAddr::V6(net.network()),
+        }
+    }
+
+    /// Returns the netmask of the network.
+    ///
+    /// See [`Ipv4Net::netmask`] and [`Ipv6Net::netmask`].
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn netmask(&self) -> IpAddr {
+        match self {
+            IpNet::V4(net) => IpAddr::V4(net.netmask()),
+            IpNet::V6(net) => IpAddr::V6(net.netmask()),
+        }
+    }
+
+    /// Returns the hostmask of the network.
+    ///
+    /// See [`Ipv4Net::hostmask`] and [`Ipv6Net::hostmask`].
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn hostmask(&self) -> IpAddr {
+        match self {
+            IpNet::V4(net) => IpAddr::V4(net.hostmask()),
+            IpNet::V6(net) => IpAddr::V6(net.hostmask()),
+        }
+    }
+
+    /// Returns the network with all host bits of its address cleared.
+    ///
+    /// See [`Ipv4Net::trunc`] and [`Ipv6Net::trunc`].
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn trunc(&self) -> IpNet {
+        match self {
+            IpNet::V4(net) => IpNet::V4(net.trunc()),
+            IpNet::V6(net) => IpNet::V6(net.trunc()),
+        }
+    }
+
+    /// Returns the network one bit shorter that contains this network, or
+    /// `None` if the prefix length is zero.
+    ///
+    /// See [`Ipv4Net::supernet`] and [`Ipv6Net::supernet`].
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn supernet(&self) -> Option<IpNet> {
+        match self {
+            IpNet::V4(net) => match net.supernet() {
+                Some(net) => Some(IpNet::V4(net)),
+                None => None,
+            },
+            IpNet::V6(net) => match net.supernet() {
+                Some(net) => Some(IpNet::V6(net)),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns [`true`] if the address is in the network. An address of the
+    /// other IP version is never in the network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::{IpAddr, IpNet, Ipv4Addr, Ipv6Addr};
+    ///
+    /// let net: IpNet = "::/0".parse().unwrap();
+    /// assert!(net.contains(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    /// assert!(!net.contains(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, addr: IpAddr) -> bool {
+        match (self, addr) {
+            (IpNet::V4(net), IpAddr::V4(addr)) => net.contains(addr),
+            (IpNet::V6(net), IpAddr::V6(addr)) => net.contains(addr),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if all addresses of the other network are in this
+    /// network. A network of the other IP version is never contained.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains_net(&self, other: IpNet) -> bool {
+        match (self, other) {
+            (IpNet::V4(net), IpNet::V4(other)) => net.contains_net(other),
+            (IpNet::V6(net), IpNet::V6(other)) => net.contains_net(other),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if this is an [`IPv4` network], and [`false`] otherwise.
+    ///
+    /// [`IPv4` network]: IpNet::V4
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_ipv4(&self) -> bool {
+        matches!(self, IpNet::V4(_))
+    }
+
+    /// Returns [`true`] if this is an [`IPv6` network], and [`false`] otherwise.
+    ///
+    /// [`IPv6` network]: IpNet::V6
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn is_ipv6(&self) -> bool {
+        matches!(self, IpNet::V6(_))
+    }
+}
+
+impl Ipv4Net {
+    /// Creates a new IPv4 network from an address and a prefix length, or
+    /// returns `None` if the prefix length is greater than 32.
+    ///
+    /// The address is kept as given; see [`trunc`] for the network with the
+    /// host bits cleared.
+    ///
+    /// [`trunc`]: Ipv4Net::trunc
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// assert!(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 8).is_some());
+    /// assert!(Ipv4Net::new(Ipv4Addr::new(10, 0, 0, 0), 33).is_none());
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn new(addr: Ipv4Addr, prefix_len: u8) -> Option<Ipv4Net> {
+        if prefix_len <= 32 { Some(Ipv4Net { addr, prefix_len }) } else { None }
+    }
+
+    /// Returns the address the network was created with.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn addr(&self) -> Ipv4Addr {
+        self.addr
+    }
+
+    /// Returns the prefix length of the network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the netmask of the network, with the leading `prefix_len` bits set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// let net: Ipv4Net = "10.1.0.0/20".parse().unwrap();
+    /// assert_eq!(net.netmask(), Ipv4Addr::new(255, 255, 240, 0));
+    /// assert_eq!(net.hostmask(), Ipv4Addr::new(0, 0, 15, 255));
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn netmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(self.netmask_bits())
+    }
+
+    /// Returns the hostmask of the network, with the trailing host bits set.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn hostmask(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(!self.netmask_bits())
+    }
+
+    /// Returns the network address, i.e. the address with all host bits cleared.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn network(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(self.addr.to_bits() & self.netmask_bits())
+    }
+
+    /// Returns the broadcast address, i.e. the address with all host bits set.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn broadcast(&self) -> Ipv4Addr {
+        Ipv4Addr::from_bits(self.addr.to_bits() | !self.netmask_bits())
+    }
+
+    /// Returns the network with all host bits of its address cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "192.168.1.17/24".parse().unwrap();
+    /// assert_eq!(net.trunc().to_string(), "192.168.1.0/24");
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn trunc(&self) -> Ipv4Net {
+        Ipv4Net { addr: self.network(), prefix_len: self.prefix_len }
+    }
+
+    /// Returns the network one bit shorter that contains this network, or
+    /// `None` if the prefix length is zero. Repeatedly taking the supernet
+    /// walks up to `0.0.0.0/0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::iter;
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.1.0.0/16".parse().unwrap();
+    /// assert_eq!(net.supernet().unwrap().to_string(), "10.0.0.0/15");
+    ///
+    /// let supernets: Vec<_> = iter::successors(net.supernet(), Ipv4Net::supernet).collect();
+    /// assert_eq!(supernets.len(), 16);
+    /// assert_eq!(supernets[15].to_string(), "0.0.0.0/0");
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn supernet(&self) -> Option<Ipv4Net> {
+        if self.prefix_len == 0 {
+            None
+        } else {
+            Some(Ipv4Net { addr: self.addr, prefix_len: self.prefix_len - 1 }.trunc())
+        }
+    }
+
+    /// Returns [`true`] if the address is in the network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, addr: Ipv4Addr) -> bool {
+        (addr.to_bits() ^ self.addr.to_bits()) & self.netmask_bits() == 0
+    }
+
+    /// Returns [`true`] if all addresses of the other network are in this network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.0.0.0/8".parse().unwrap();
+    /// assert!(net.contains_net("10.20.0.0/16".parse().unwrap()));
+    /// assert!(net.contains_net(net));
+    /// assert!(!net.contains_net("10.0.0.0/7".parse().unwrap()));
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains_net(&self, other: Ipv4Net) -> bool {
+        other.prefix_len >= self.prefix_len && self.contains(other.addr)
+    }
+
+    /// Returns an iterator over the addresses of the hosts in the network. This
+    /// excludes the network and broadcast addresses, except in `/31` networks
+    /// ([IETF RFC 3021]) and in `/32` networks, which consist of hosts only.
+    ///
+    /// [IETF RFC 3021]: https://tools.ietf.org/html/rfc3021
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// let net: Ipv4Net = "192.168.0.0/30".parse().unwrap();
+    /// assert!(net.hosts().eq([Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(192, 168, 0, 2)]));
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[inline]
+    pub fn hosts(&self) -> Ipv4Hosts {
+        let (network, broadcast) = (self.network().to_bits(), self.broadcast().to_bits());
+        if self.prefix_len < 31 {
+            Ipv4Hosts { front: network + 1, back: broadcast - 1, exhausted: false }
+        } else {
+            Ipv4Hosts { front: network, back: broadcast, exhausted: false }
+        }
+    }
+
+    /// Returns an iterator over the subnets of the network with the given
+    /// prefix length, in ascending order, or `None` if the prefix length is
+    /// less than the prefix length of the network or greater than 32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::Ipv4Net;
+    ///
+    /// let net: Ipv4Net = "10.0.0.0/8".parse().unwrap();
+    /// let subnets: Vec<_> = net.subnets(10).unwrap().map(|net| net.to_string()).collect();
+    /// assert_eq!(subnets, ["10.0.0.0/10", "10.64.0.0/10", "10.128.0.0/10", "10.192.0.0/10"]);
+    /// assert!(net.subnets(7).is_none());
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[inline]
+    pub fn subnets(&self, prefix_len: u8) -> Option<Ipv4Subnets> {
+        if prefix_len < self.prefix_len || prefix_len > 32 {
+            return None;
+        }
+        let last = Ipv4Net { addr: self.broadcast(), prefix_len }.network();
+        Some(Ipv4Subnets {
+            front: self.network().to_bits(),
+            back: last.to_bits(),
+            prefix_len,
+            exhausted: false,
+        })
+    }
+
+    const fn netmask_bits(&self) -> u32 {
+        match u32::MAX.checked_shl(32 - self.prefix_len as u32) {
+            Some(mask) => mask,
+            None => 0,
+        }
+    }
+}
+
+impl Ipv6Net {
+    /// Creates a new IPv6 network from an address and a prefix length, or
+    /// returns `None` if the prefix length is greater than 128.
+    ///
+    /// The address is kept as given; see [`trunc`] for the network with the
+    /// host bits cleared.
+    ///
+    /// [`trunc`]: Ipv6Net::trunc
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn new(addr: Ipv6Addr, prefix_len: u8) -> Option<Ipv6Net> {
+        if prefix_len <= 128 { Some(Ipv6Net { addr, prefix_len }) } else { None }
+    }
+
+    /// Returns the address the network was created with.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn addr(&self) -> Ipv6Addr {
+        self.addr
+    }
+
+    /// Returns the prefix length of the network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the netmask of the network, with the leading `prefix_len` bits set.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn netmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(self.netmask_bits())
+    }
+
+    /// Returns the hostmask of the network, with the trailing host bits set.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn hostmask(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(!self.netmask_bits())
+    }
+
+    /// Returns the network address, i.e. the address with all host bits cleared.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn network(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(self.addr.to_bits() & self.netmask_bits())
+    }
+
+    /// Returns the network with all host bits of its address cleared.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn trunc(&self) -> Ipv6Net {
+        Ipv6Net { addr: self.network(), prefix_len: self.prefix_len }
+    }
+
+    /// Returns the network one bit shorter that contains this network, or
+    /// `None` if the prefix length is zero.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn supernet(&self) -> Option<Ipv6Net> {
+        if self.prefix_len == 0 {
+            None
+        } else {
+            Some(Ipv6Net { addr: self.addr, prefix_len: self.prefix_len - 1 }.trunc())
+        }
+    }
+
+    /// Returns [`true`] if the address is in the network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, addr: Ipv6Addr) -> bool {
+        (addr.to_bits() ^ self.addr.to_bits()) & self.netmask_bits() == 0
+    }
+
+    /// Returns [`true`] if all addresses of the other network are in this network.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[rustc_const_unstable(feature = "ip_net", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub const fn contains_net(&self, other: Ipv6Net) -> bool {
+        other.prefix_len >= self.prefix_len && self.contains(other.addr)
+    }
+
+    /// Returns an iterator over all addresses in the network. Unlike IPv4,
+    /// IPv6 has no broadcast address to leave out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ip_net)]
+    /// use std::net::Ipv6Net;
+    ///
+    /// let net: Ipv6Net = "2001:db8::/126".parse().unwrap();
+    /// let hosts: Vec<_> = net.hosts().map(|addr| addr.to_string()).collect();
+    /// assert_eq!(hosts, ["2001:db8::", "2001:db8::1", "2001:db8::2", "2001:db8::3"]);
+    /// ```
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[inline]
+    pub fn hosts(&self) -> Ipv6Hosts {
+        let front = self.network().to_bits();
+        let back = front | !self.netmask_bits();
+        Ipv6Hosts { front, back, exhausted: false }
+    }
+
+    /// Returns an iterator over the subnets of the network with the given
+    /// prefix length, in ascending order, or `None` if the prefix length is
+    /// less than the prefix length of the network or greater than 128.
+    #[unstable(feature = "ip_net", issue = "none")]
+    #[inline]
+    pub fn subnets(&self, prefix_len: u8) -> Option<Ipv6Subnets> {
+        if prefix_len < self.prefix_len || prefix_len > 128 {
+            return None;
+        }
+        let front = self.network().to_bits();
+        let last = Ipv6Net { addr: Ipv6Addr::from_bits(front | !self.netmask_bits()), prefix_len };
+        Some(Ipv6Subnets { front, back: last.network().to_bits(), prefix_len, exhausted: false })
+    }
+
+    const fn netmask_bits(&self) -> u128 {
+        match u128::MAX.checked_shl(128 - self.prefix_len as u32) {
+            Some(mask) => mask,
+            None => 0,
+        }
+    }
+}
+
+/// An iterator over the host addresses of an [`Ipv4Net`].
+///
+/// This `struct` is created by [`Ipv4Net::hosts`]. See its documentation for more.
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct Ipv4Hosts {
+    front: u32,
+    back: u32,
+    exhausted: bool,
+}
+
+/// An iterator over the host addresses of an [`Ipv6Net`].
+///
+/// This `struct` is created by [`Ipv6Net::hosts`]. See its documentation for more.
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct Ipv6Hosts {
+    front: u128,
+    back: u128,
+    exhausted: bool,
+}
+
+/// An iterator over the subnets of an [`Ipv4Net`].
+///
+/// This `struct` is created by [`Ipv4Net::subnets`]. See its documentation for more.
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct Ipv4Subnets {
+    front: u32,
+    back: u32,
+    prefix_len: u8,
+    exhausted: bool,
+}
+
+/// An iterator over the subnets of an [`Ipv6Net`].
+///
+/// This `struct` is created by [`Ipv6Net::subnets`]. See its documentation for more.
+#[unstable(feature = "ip_net", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct Ipv6Subnets {
+    front: u128,
+    back: u128,
+    prefix_len: u8,
+    exhausted: bool,
+}
+
+impl Ipv4Hosts {
+    fn item(&self, bits: u32) -> Ipv4Addr {
+        Ipv4Addr::from_bits(bits)
+    }
+
+    fn step_shift(&self) -> u32 {
+        0
+    }
+}
+
+impl Ipv6Hosts {
+    fn item(&self, bits: u128) -> Ipv6Addr {
+        Ipv6Addr::from_bits(bits)
+    }
+
+    fn step_shift(&self) -> u32 {
+        0
+    }
+}
+
+impl Ipv4Subnets {
+    fn item(&self, bits: u32) -> Ipv4Net {
+        Ipv4Net { addr: Ipv4Addr::from_bits(bits), prefix_len: self.prefix_len }
+    }
+
+    fn step_shift(&self) -> u32 {
+        32 - u32::from(self.prefix_len)
+    }
+}
+
+impl Ipv6Subnets {
+    fn item(&self, bits: u128) -> Ipv6Net {
+        Ipv6Net { addr: Ipv6Addr::from_bits(bits), prefix_len: self.prefix_len }
+    }
+
+    fn step_shift(&self) -> u32 {
+        128 - u32::from(self.prefix_len)
+    }
+}
+
+// The iterators keep the bits of the first and the last item left to yield,
+// rather than a half-open range, so that they can cover the entire address
+// space. Consecutive items are `1 << step_shift()` apart; the shift can only
+// be the full width of the address for `/0` subnets, of which there is just
+// one, so it never steps.
+macro_rules! range_iterator {
+    ($Iter:ident, $Item:ty) => {
+        #[unstable(feature = "ip_net", issue = "none")]
+        impl Iterator for $Iter {
+            type Item = $Item;
+
+            fn next(&mut self) -> Option<$Item> {
+                if self.exhausted || self.front > self.back {
+                    return None;
+                }
+                let item = self.item(self.front);
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front += 1 << self.step_shift();
+                }
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted || self.front > self.back {
+                    return (0, Some(0));
+                }
+                let steps = (self.back - self.front).checked_shr(self.step_shift()).unwrap_or(0);
+                match steps.checked_add(1).and_then(|len| usize::try_from(len).ok()) {
+                    Some(len) => (len, Some(len)),
+                    None => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<$Item> {
+                self.next_back()
+            }
+        }
+
+        #[unstable(feature = "ip_net", issue = "none")]
+        impl DoubleEndedIterator for $Iter {
+            fn next_back(&mut self) -> Option<$Item> {
+                if self.exhausted || self.front > self.back {
+                    return None;
+                }
+                let item = self.item(self.back);
+                if self.front == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.back -= 1 << self.step_shift();
+                }
+                Some(item)
+            }
+        }
+
+        #[unstable(feature = "ip_net", issue = "none")]
+        impl FusedIterator for $Iter {}
+    };
+}
+
+range_iterator!(Ipv4Hosts, Ipv4Addr);
+range_iterator!(Ipv6Hosts, Ipv6Addr);
+range_iterator!(Ipv4Subnets, Ipv4Net);
+range_iterator!(Ipv6Subnets, Ipv6Net);
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl From<Ipv4Addr> for Ipv4Net {
+    /// Creates the network of the single address, with a prefix length of 32.
+    #[inline]
+    fn from(addr: Ipv4Addr) -> Ipv4Net {
+        Ipv4Net { addr, prefix_len: 32 }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl From<Ipv6Addr> for Ipv6Net {
+    /// Creates the network of the single address, with a prefix length of 128.
+    #[inline]
+    fn from(addr: Ipv6Addr) -> Ipv6Net {
+        Ipv6Net { addr, prefix_len: 128 }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl From<IpAddr> for IpNet {
+    /// Creates the network of the single address, with the full prefix length.
+    #[inline]
+    fn from(addr: IpAddr) -> IpNet {
+        match addr {
+            IpAddr::V4(addr) => IpNet::V4(addr.into()),
+            IpAddr::V6(addr) => IpNet::V6(addr.into()),
+        }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl From<Ipv4Net> for IpNet {
+    #[inline]
+    fn from(net: Ipv4Net) -> IpNet {
+        IpNet::V4(net)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl From<Ipv6Net> for IpNet {
+    #[inline]
+    fn from(net: Ipv6Net) -> IpNet {
+        IpNet::V6(net)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Display for IpNet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpNet::V4(net) => net.fmt(fmt),
+            IpNet::V6(net) => net.fmt(fmt),
+        }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Debug for IpNet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Display for Ipv4Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there are no alignment requirements, write the network directly to `f`.
+        // Otherwise, write it to a local buffer and then use `f.pad`.
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.prefix_len)
+        } else {
+            const LONGEST_IPV4_NET: &str = "255.255.255.255/32";
+
+            let mut buf = DisplayBuffer::<{ LONGEST_IPV4_NET.len() }>::new();
+            // Buffer is long enough for the longest possible IPv4 network, so this should never fail.
+            write!(buf, "{}/{}", self.addr, self.prefix_len).unwrap();
+
+            fmt.pad(buf.as_str())
+        }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Debug for Ipv4Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Display for Ipv6Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there are no alignment requirements, write the network directly to `f`.
+        // Otherwise, write it to a local buffer and then use `f.pad`.
+        if fmt.precision().is_none() && fmt.width().is_none() {
+            write!(fmt, "{}/{}", self.addr, self.prefix_len)
+        } else {
+            const LONGEST_IPV6_NET: &str = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128";
+
+            let mut buf = DisplayBuffer::<{ LONGEST_IPV6_NET.len() }>::new();
+            // Buffer is long enough for the longest possible IPv6 network, so this should never fail.
+            write!(buf, "{}/{}", self.addr, self.prefix_len).unwrap();
+
+            fmt.pad(buf.as_str())
+        }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl fmt::Debug for Ipv6Net {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
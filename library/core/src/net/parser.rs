@@ -1,11 +1,14 @@
-//! A private parser implementation of IPv4, IPv6, and socket addresses.
+//! A private parser implementation of IPv4, IPv6, and socket addresses, and of
+//! IPv4 and IPv6 networks.
 //!
 //! This module is "publicly exported" through the `FromStr` implementations
 //! below.
 
 use crate::error::Error;
 use crate::fmt;
-use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    IpAddr, IpNet, Ipv4Addr, Ipv4Net, Ipv6Addr, Ipv6Net, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
 use crate::str::FromStr;
 
 trait ReadNumberHelper: Sized {
@@ -290,6 +293,39 @@ fn read_socket_addr(&mut self) -> Option<SocketAddr> {
             .map(SocketAddr::V4)
             .or_else(|| self.read_socket_addr_v6().map(SocketAddr::V6))
     }
+
+    /// Reads a `/` followed by a prefix length in base 10, no greater than `max`.
+    fn read_prefix_len(&mut self, max: u8) -> Option<u8> {
+        self.read_atomically(|p| {
+            p.read_given_char('/')?;
+            // Disallow leading zeros, as for the octets of an IPv4 address.
+            let prefix_len = p.read_number(10, Some(3), false)?;
+            if prefix_len <= max { Some(prefix_len) } else { None }
+        })
+    }
+
+    /// Reads an IPv4 network in CIDR notation.
+    fn read_ipv4_net(&mut self) -> Option<Ipv4Net> {
+        self.read_atomically(|p| {
+            let addr = p.read_ipv4_addr()?;
+            let prefix_len = p.read_prefix_len(32)?;
+            Ipv4Net::new(addr, prefix_len)
+        })
+    }
+
+    /// Reads an IPv6 network in CIDR notation.
+    fn read_ipv6_net(&mut self) -> Option<Ipv6Net> {
+        self.read_atomically(|p| {
+            let addr = p.read_ipv6_addr()?;
+            let prefix_len = p.read_prefix_len(128)?;
+            Ipv6Net::new(addr, prefix_len)
+        })
+    }
+
+    /// Reads an IP network, either IPv4 or IPv6.
+    fn read_ip_net(&mut self) -> Option<IpNet> {
+        self.read_ipv4_net().map(IpNet::V4).or_else(move || self.read_ipv6_net().map(IpNet::V6))
+    }
 }
 
 impl IpAddr {
@@ -457,6 +493,91 @@ fn from_str(s: &str) -> Result<SocketAddr, AddrParseError> {
     }
 }
 
+impl IpNet {
+    /// Parse an IP network in CIDR notation from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_net)]
+    ///
+    /// use std::net::{IpAddr, IpNet, Ipv4Addr, Ipv6Addr};
+    ///
+    /// let net_v4 = IpNet::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
+    /// let net_v6 = IpNet::new(IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0)), 10).unwrap();
+    ///
+    /// assert_eq!(IpNet::parse_ascii(b"10.0.0.0/8"), Ok(net_v4));
+    /// assert_eq!(IpNet::parse_ascii(b"fe80::/10"), Ok(net_v6));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        Parser::new(b).parse_with(|p| p.read_ip_net(), AddrKind::IpNet)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl FromStr for IpNet {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<IpNet, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
+impl Ipv4Net {
+    /// Parse an IPv4 network in CIDR notation from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_net)]
+    ///
+    /// use std::net::{Ipv4Addr, Ipv4Net};
+    ///
+    /// let net = Ipv4Net::new(Ipv4Addr::new(192, 168, 0, 0), 16).unwrap();
+    ///
+    /// assert_eq!(Ipv4Net::parse_ascii(b"192.168.0.0/16"), Ok(net));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        // don't try to parse if too long
+        if b.len() > 18 {
+            Err(AddrParseError(AddrKind::Ipv4Net))
+        } else {
+            Parser::new(b).parse_with(|p| p.read_ipv4_net(), AddrKind::Ipv4Net)
+        }
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl FromStr for Ipv4Net {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv4Net, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
+impl Ipv6Net {
+    /// Parse an IPv6 network in CIDR notation from a slice of bytes.
+    ///
+    /// ```
+    /// #![feature(addr_parse_ascii, ip_net)]
+    ///
+    /// use std::net::{Ipv6Addr, Ipv6Net};
+    ///
+    /// let net = Ipv6Net::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32).unwrap();
+    ///
+    /// assert_eq!(Ipv6Net::parse_ascii(b"2001:db8::/32"), Ok(net));
+    /// ```
+    #[unstable(feature = "addr_parse_ascii", issue = "101035")]
+    pub fn parse_ascii(b: &[u8]) -> Result<Self, AddrParseError> {
+        Parser::new(b).parse_with(|p| p.read_ipv6_net(), AddrKind::Ipv6Net)
+    }
+}
+
+#[unstable(feature = "ip_net", issue = "none")]
+impl FromStr for Ipv6Net {
+    type Err = AddrParseError;
+    fn from_str(s: &str) -> Result<Ipv6Net, AddrParseError> {
+        Self::parse_ascii(s.as_bytes())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum AddrKind {
     Ip,
@@ -465,13 +586,17 @@ enum AddrKind {
     Socket,
     SocketV4,
     SocketV6,
+    IpNet,
+    Ipv4Net,
+    Ipv6Net,
 }
 
-/// An error which can be returned when parsing an IP address or a socket address.
+/// An error which can be returned when parsing an IP address, a socket address
+/// or an IP network.
 ///
 /// This error is used as the error type for the [`FromStr`] implementation for
-/// [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`], [`SocketAddr`], [`SocketAddrV4`], and
-/// [`SocketAddrV6`].
+/// [`IpAddr`], [`Ipv4Addr`], [`Ipv6Addr`], [`SocketAddr`], [`SocketAddrV4`],
+/// [`SocketAddrV6`], [`IpNet`], [`Ipv4Net`], and [`Ipv6Net`].
 ///
 /// # Potential causes
 ///
@@ -514,6 +639,9 @@ fn description(&self) -> &str {
             AddrKind::Socket => "invalid socket address syntax",
             AddrKind::SocketV4 => "invalid IPv4 socket address syntax",
             AddrKind::SocketV6 => "invalid IPv6 socket address syntax",
+            AddrKind::IpNet => "invalid IP network syntax",
+            AddrKind::Ipv4Net => "invalid IPv4 network syntax",
+            AddrKind::Ipv6Net => "invalid IPv6 network syntax",
         }
     }
 }
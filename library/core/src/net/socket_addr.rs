@@ -1,4 +1,4 @@
-use super::display_buffer::DisplayBuffer;
+use crate::fmt::display_buffer::DisplayBuffer;
 use crate::fmt::{self, Write};
 use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
@@ -0,0 +1,138 @@
+//! Converting hexadecimal strings such as `0x1.8p3` into IEEE 754 binary floating point numbers.
+//!
+//! Unlike decimal strings, hexadecimal strings describe a binary fraction exactly, so the result
+//! only needs to be rounded once, by looking at the bits that do not fit into the significand.
+//! This works on bit patterns rather than on `RawFloat`, so that it is shared by all float types
+//! including `f16` and `f128`.
+
+use super::{pfe_empty, pfe_invalid, ParseFloatError};
+
+/// Parses a hexadecimal float into the bit pattern of a float with `mantissa_bits` explicit
+/// mantissa bits and `exponent_bits` exponent bits.
+///
+/// The accepted grammar, when lowercased, is
+///
+/// ```txt
+/// Float  ::= Sign? ( 'inf' | 'infinity' | 'nan' | Number )
+/// Number ::= '0x' ( Digit+ | Digit+ '.' Digit* | Digit* '.' Digit+ ) Exp?
+/// Exp    ::= 'p' Sign? [0-9]+
+/// Sign   ::= [+-]
+/// Digit  ::= [0-9a-f]
+/// ```
+///
+/// The result is rounded to nearest, ties to even.
+pub(crate) fn hex2flt(
+    s: &str,
+    mantissa_bits: u32,
+    exponent_bits: u32,
+) -> Result<u128, ParseFloatError> {
+    let mut s = s.as_bytes();
+    let Some(&c) = s.first() else { return Err(pfe_empty()) };
+    let negative = c == b'-';
+    if c == b'-' || c == b'+' {
+        s = &s[1..];
+    }
+
+    let sign = (negative as u128) << (mantissa_bits + exponent_bits);
+    let infinity = ((1 << exponent_bits) - 1) << mantissa_bits;
+    if s.eq_ignore_ascii_case(b"inf") || s.eq_ignore_ascii_case(b"infinity") {
+        return Ok(sign | infinity);
+    }
+    if s.eq_ignore_ascii_case(b"nan") {
+        return Ok(sign | infinity | 1 << (mantissa_bits - 1));
+    }
+
+    let [b'0', b'x' | b'X', ref rest @ ..] = *s else { return Err(pfe_invalid()) };
+    s = rest;
+
+    // The value is `mantissa * 2^exponent`, plus something smaller than the last bit of
+    // `mantissa` if `sticky` is set. Digits stop being added to `mantissa` once it has more
+    // bits than any float needs to round correctly.
+    let mut mantissa = 0u128;
+    let mut exponent = 0i64;
+    let mut sticky = false;
+    let mut any_digits = false;
+    let mut seen_point = false;
+    while let [c, ref rest @ ..] = *s {
+        if c == b'.' && !seen_point {
+            seen_point = true;
+        } else if let Some(digit) = (c as char).to_digit(16) {
+            any_digits = true;
+            if mantissa >> 120 == 0 {
+                mantissa = mantissa << 4 | digit as u128;
+                if seen_point {
+                    exponent -= 4;
+                }
+            } else {
+                sticky |= digit != 0;
+                if !seen_point {
+                    exponent = exponent.saturating_add(4);
+                }
+            }
+        } else {
+            break;
+        }
+        s = rest;
+    }
+    if !any_digits {
+        return Err(pfe_invalid());
+    }
+
+    if let [b'p' | b'P', ref rest @ ..] = *s {
+        s = rest;
+        let exp_negative = s.first() == Some(&b'-');
+        if let [b'-' | b'+', ref rest @ ..] = *s {
+            s = rest;
+        }
+        if s.is_empty() {
+            return Err(pfe_invalid());
+        }
+        let mut exp = 0i64;
+        for &c in s {
+            let Some(digit) = (c as char).to_digit(10) else { return Err(pfe_invalid()) };
+            exp = exp.saturating_mul(10).saturating_add(digit as i64);
+        }
+        exponent =
+            if exp_negative { exponent.saturating_sub(exp) } else { exponent.saturating_add(exp) };
+    } else if !s.is_empty() {
+        return Err(pfe_invalid());
+    }
+
+    if mantissa == 0 {
+        return Ok(sign);
+    }
+
+    let mantissa_bits = mantissa_bits as i64;
+    let bias = (1i64 << (exponent_bits - 1)) - 1;
+    let (emin, emax) = (1 - bias, bias);
+    // The value is in `2^e..2^(e + 1)`.
+    let top = 127 - mantissa.leading_zeros() as i64;
+    let e = exponent.saturating_add(top);
+    if e > emax {
+        return Ok(sign | infinity);
+    }
+    if e < emin - mantissa_bits - 1 {
+        // Less than half of the smallest subnormal.
+        return Ok(sign);
+    }
+
+    // The number of bits of `mantissa` below the last bit of the result, which is between
+    // `-mantissa_bits` and 128 given the bounds checked above.
+    let shift = e.max(emin) - mantissa_bits - exponent;
+    let significand = if shift <= 0 {
+        mantissa << -shift
+    } else {
+        let (kept, rest) = if shift == 128 {
+            (0, mantissa)
+        } else {
+            (mantissa >> shift, mantissa & ((1 << shift) - 1))
+        };
+        let half = 1 << (shift - 1);
+        if rest > half || rest == half && (sticky || kept & 1 == 1) { kept + 1 } else { kept }
+    };
+
+    // A carry out of the significand increments the exponent, which is what we want, also
+    // for subnormals that round up to the smallest normal number.
+    let bits = (((e.max(emin) - emin) as u128) << mantissa_bits) + significand;
+    Ok(sign | bits.min(infinity))
+}
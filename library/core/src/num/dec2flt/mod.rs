@@ -77,6 +77,7 @@
 
 use self::common::BiasedFp;
 use self::float::RawFloat;
+pub(crate) use self::hex::hex2flt;
 use self::lemire::compute_float;
 use self::parse::{parse_inf_nan, parse_number};
 use self::slow::parse_long_mantissa;
@@ -87,6 +88,7 @@
 mod common;
 mod decimal;
 mod fpu;
+mod hex;
 mod slow;
 mod table;
 // float is used in flt2dec, and all are used in unit tests.
@@ -15,7 +15,7 @@
 #[cfg(not(test))]
 use crate::intrinsics;
 use crate::mem;
-use crate::num::FpCategory;
+use crate::num::{dec2flt, FpCategory, ParseFloatError};
 
 /// Basic mathematical constants.
 #[unstable(feature = "f128", issue = "116909")]
@@ -1150,6 +1150,41 @@ pub const fn from_bits(v: u128) -> Self {
         Self::from_bits(u128::from_ne_bytes(bytes))
     }
 
+    /// Parses a hexadecimal floating point string, in the syntax of C99
+    /// hexadecimal floating constants such as `0x1.8p3`.
+    ///
+    /// The string consists of an optional sign, the prefix `0x`, hexadecimal
+    /// digits with an optional point, and an optional binary exponent
+    /// introduced by `p`, which gives the power of two to multiply by. As when
+    /// parsing decimal strings, `inf`, `infinity` and `nan` are accepted, and
+    /// letters are not case-sensitive. This is the inverse of formatting with
+    /// `{:a}`, see [`LowerHexFloat`](crate::fmt::LowerHexFloat).
+    ///
+    /// Digits that do not fit into the significand are rounded to nearest,
+    /// ties to even.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `src` is empty or not of the form above, including
+    /// when it contains leading or trailing whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(f128, hex_float)]
+    /// # // FIXME(f16_f128): LLVM crashes on s390x, llvm/llvm-project#50374
+    /// # #[cfg(all(target_arch = "x86_64", target_os = "linux"))] {
+    ///
+    /// assert_eq!(f128::from_str_hex("0x1.8p3"), Ok(12.0));
+    /// assert_eq!(f128::from_str_hex("-0x1.999999999999999999999999999ap-4"), Ok(-0.1));
+    /// assert!(f128::from_str_hex("1.5").is_err());
+    /// # }
+    /// ```
+    #[unstable(feature = "hex_float", issue = "none")]
+    pub fn from_str_hex(src: &str) -> Result<f128, ParseFloatError> {
+        dec2flt::hex2flt(src, 112, 15).map(f128::from_bits)
+    }
+
     /// Returns the ordering between `self` and `other`.
     ///
     /// Unlike the standard partial comparison between floating point numbers,
@@ -73,7 +73,8 @@ pub fn write(&self, out: &mut [u8]) -> Option<usize> {
 #[allow(missing_debug_implementations)]
 #[derive(Clone)]
 pub struct Formatted<'a> {
-    /// A byte slice representing a sign, either `""`, `"-"` or `"+"`.
+    /// A byte slice representing a sign, either `""`, `"-"` or `"+"`, possibly
+    /// followed by the `0x` prefix of a hexadecimal float.
     pub sign: &'static str,
     /// Formatted parts to be rendered after a sign and optional zero padding.
     pub parts: &'a [Part<'a>],
@@ -4,11 +4,13 @@
 use crate::slice::sort::shared::smallsort::{
     insertion_sort_shift_left, StableSmallSortTypeImpl, SMALL_SORT_GENERAL_SCRATCH_LEN,
 };
+use crate::slice::sort::stable::radix::{StableSortByKeyImpl, StableSortOrdImpl};
 use crate::{cmp, intrinsics};
 
 pub(crate) mod drift;
 pub(crate) mod merge;
 pub(crate) mod quicksort;
+pub(crate) mod radix;
 
 /// Stable sort called driftsort by Orson Peters and Lukas Bergdoll.
 /// Design document:
@@ -45,6 +47,49 @@ pub fn sort<T, F: FnMut(&T, &T) -> bool, BufT: BufGuard<T>>(v: &mut [T], is_less
     driftsort_main::<T, F, BufT>(v, is_less);
 }
 
+/// Stable sort in the order of the [`Ord`] implementation of `T`.
+///
+/// Long slices of primitive integers are sorted with radix sort, which needs a scratch buffer as
+/// long as the input. Everything else is sorted with [`sort`].
+#[inline(always)]
+pub fn sort_ord<T: Ord, BufT: BufGuard<T>>(v: &mut [T]) {
+    <T as StableSortOrdImpl>::sort::<BufT>(v);
+}
+
+/// Stable sort of `f32`s in the order of [`f32::total_cmp`].
+///
+/// Long slices are sorted with radix sort, which needs a scratch buffer as long as the input.
+/// Everything else is sorted with [`sort`].
+#[inline(always)]
+pub fn sort_f32<BufT: BufGuard<f32>>(v: &mut [f32]) {
+    radix::sort_floats::<f32, _, BufT>(v, &mut |a, b| a.total_cmp(b).is_lt());
+}
+
+/// Stable sort of `f64`s in the order of [`f64::total_cmp`].
+///
+/// Long slices are sorted with radix sort, which needs a scratch buffer as long as the input.
+/// Everything else is sorted with [`sort`].
+#[inline(always)]
+pub fn sort_f64<BufT: BufGuard<f64>>(v: &mut [f64]) {
+    radix::sort_floats::<f64, _, BufT>(v, &mut |a, b| a.total_cmp(b).is_lt());
+}
+
+/// Stable sort in the order of the [`Ord`] implementation of the keys returned by `f`.
+///
+/// Long slices sorted by primitive integer keys are sorted with radix sort, which calls `f` once
+/// per element and needs a buffer of twice the input length for the keys and their indices.
+/// Everything else is sorted with [`sort`].
+#[inline(always)]
+pub fn sort_by_key<T, K, F, BufT, KeyBufT>(v: &mut [T], f: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+    BufT: BufGuard<T>,
+    KeyBufT: BufGuard<(K, u32)>,
+{
+    <K as StableSortByKeyImpl>::sort_by_key::<T, F, BufT, KeyBufT>(v, f);
+}
+
 /// See [`sort`]
 ///
 /// Deliberately don't inline the main sorting routine entrypoint to ensure the
@@ -0,0 +1,256 @@
+//! This module contains an LSD radix sort, used by the stable sort entry points for slices of
+//! primitive integers and floats, and for slices sorted by primitive integer keys.
+//!
+//! Radix sort distributes the elements into 256 buckets by one byte of their key at a time,
+//! starting with the least significant byte. It does not compare elements at all, and its run
+//! time is linear in the length of the input, which makes it several times faster than
+//! comparison based sorts for long inputs. It needs a scratch buffer as long as the input, and
+//! for short inputs the cost of counting and distributing into 256 buckets per byte dominates.
+
+use crate::mem::{self, MaybeUninit, SizedTypeProperties};
+use crate::slice::sort::stable::{sort, BufGuard};
+use crate::{ptr, slice};
+
+/// Inputs shorter than this many elements per byte of the key are sorted with driftsort instead,
+/// which is faster for them. Radix sort does one pass per byte, so the length from which it is
+/// faster grows with the size of the key.
+const RADIX_SORT_MIN_LEN_PER_BYTE: usize = 64;
+
+/// Keys that can be sorted by radix sort, which are the primitive integers and floats up to 64
+/// bits. Wider keys need so many passes that driftsort is faster.
+#[rustc_specialization_trait]
+pub(crate) trait RadixKey: Copy {
+    /// Returns an unsigned integer that is ordered like `self` according to its [`Ord`]
+    /// implementation, or for floats according to `total_cmp`.
+    fn radix_bits(self) -> u64;
+}
+
+macro_rules! impl_radix_key {
+    ($($t:ty => $u:ty),* $(,)?) => {$(
+        impl RadixKey for $t {
+            #[inline(always)]
+            fn radix_bits(self) -> u64 {
+                // Flipping the sign bit maps signed integers to unsigned integers in the same
+                // order, and leaves unsigned integers alone as their `MIN` is zero.
+                (self as $u ^ <$t>::MIN as $u) as u64
+            }
+        }
+    )*};
+}
+
+impl_radix_key! {
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize,
+}
+
+macro_rules! impl_radix_key_float {
+    ($($t:ty => $i:ty, $u:ty),* $(,)?) => {$(
+        impl RadixKey for $t {
+            #[inline(always)]
+            fn radix_bits(self) -> u64 {
+                // Like `total_cmp`, flipping all bits but the sign of negative floats orders them
+                // like signed integers, which flipping the sign bit then maps to unsigned ones.
+                let bits = self.to_bits() as $i;
+                let bits = bits ^ (((bits >> (<$i>::BITS - 1)) as $u) >> 1) as $i;
+                (bits as $u ^ <$i>::MIN as $u) as u64
+            }
+        }
+    )*};
+}
+
+impl_radix_key_float! {
+    f32 => i32, u32,
+    f64 => i64, u64,
+}
+
+/// Returns whether radix sort is expected to be faster than driftsort for `len` keys of type `K`.
+#[inline(always)]
+fn use_radix_sort<K: RadixKey>(len: usize) -> bool {
+    len >= RADIX_SORT_MIN_LEN_PER_BYTE * mem::size_of::<K>()
+}
+
+/// Using a trait allows us to specialize the stable sort of `T: Ord` on `RadixKey`.
+pub(crate) trait StableSortOrdImpl: Ord + Sized {
+    /// Sorts `v` in the order of [`Ord`].
+    fn sort<BufT: BufGuard<Self>>(v: &mut [Self]);
+}
+
+impl<T: Ord> StableSortOrdImpl for T {
+    #[inline(always)]
+    default fn sort<BufT: BufGuard<T>>(v: &mut [T]) {
+        sort::<T, _, BufT>(v, &mut T::lt);
+    }
+}
+
+impl<T: Ord + RadixKey> StableSortOrdImpl for T {
+    #[inline(always)]
+    fn sort<BufT: BufGuard<T>>(v: &mut [T]) {
+        if use_radix_sort::<T>(v.len()) {
+            radix_sort_main::<T, BufT>(v);
+        } else {
+            sort::<T, _, BufT>(v, &mut T::lt);
+        }
+    }
+}
+
+/// Sorts the floats in `v` in the order of `total_cmp`, which `is_less` has to implement.
+#[inline(always)]
+pub(crate) fn sort_floats<T, F, BufT>(v: &mut [T], is_less: &mut F)
+where
+    T: RadixKey,
+    F: FnMut(&T, &T) -> bool,
+    BufT: BufGuard<T>,
+{
+    if use_radix_sort::<T>(v.len()) {
+        radix_sort_main::<T, BufT>(v);
+    } else {
+        sort::<T, _, BufT>(v, is_less);
+    }
+}
+
+/// See [`StableSortOrdImpl::sort`] and [`sort_floats`]
+///
+/// Deliberately don't inline the radix sort, like the main driftsort routine.
+#[inline(never)]
+fn radix_sort_main<T: RadixKey, BufT: BufGuard<T>>(v: &mut [T]) {
+    let mut buf = BufT::with_capacity(v.len());
+    // SAFETY: the key of a primitive is the primitive itself.
+    unsafe { radix_sort(v, buf.as_uninit_slice_mut(), |&x| x) };
+}
+
+/// Using a trait allows us to specialize the stable sort by a key `K: Ord` on `RadixKey`.
+pub(crate) trait StableSortByKeyImpl: Ord + Sized {
+    /// Sorts `v` in the order of [`Ord`] for the keys returned by `f`.
+    fn sort_by_key<T, F, BufT, KeyBufT>(v: &mut [T], f: F)
+    where
+        F: FnMut(&T) -> Self,
+        BufT: BufGuard<T>,
+        KeyBufT: BufGuard<(Self, u32)>;
+}
+
+impl<K: Ord> StableSortByKeyImpl for K {
+    #[inline(always)]
+    default fn sort_by_key<T, F, BufT, KeyBufT>(v: &mut [T], mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        BufT: BufGuard<T>,
+        KeyBufT: BufGuard<(K, u32)>,
+    {
+        sort::<T, _, BufT>(v, &mut |a, b| f(a).lt(&f(b)));
+    }
+}
+
+impl<K: Ord + RadixKey> StableSortByKeyImpl for K {
+    #[inline(always)]
+    fn sort_by_key<T, F, BufT, KeyBufT>(v: &mut [T], mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        BufT: BufGuard<T>,
+        KeyBufT: BufGuard<(K, u32)>,
+    {
+        // The elements are indexed by `u32` to save memory and bandwidth, which only rules out
+        // slices that are too long to sort in memory on most systems anyway.
+        if use_radix_sort::<K>(v.len()) && v.len() <= u32::MAX as usize && !T::IS_ZST {
+            radix_sort_by_key_main::<T, K, F, KeyBufT>(v, f);
+        } else {
+            sort::<T, _, BufT>(v, &mut |a, b| f(a).lt(&f(b)));
+        }
+    }
+}
+
+/// See [`StableSortByKeyImpl::sort_by_key`]
+///
+/// The key function is called exactly once per element, before any element is moved, so a panic
+/// in it leaves `v` untouched. The keys are then sorted along with the indices of their
+/// elements, and the elements are finally moved into place according to the sorted indices.
+#[inline(never)]
+fn radix_sort_by_key_main<T, K, F, BufT>(v: &mut [T], mut f: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    BufT: BufGuard<(K, u32)>,
+{
+    let len = v.len();
+    let mut buf = BufT::with_capacity(2 * len);
+    let (keys, scratch) = buf.as_uninit_slice_mut()[..len * 2].split_at_mut(len);
+    for (i, (key, x)) in keys.iter_mut().zip(v.iter()).enumerate() {
+        key.write((f(x), i as u32));
+    }
+    // SAFETY: all keys have been initialized above.
+    let keys = unsafe { MaybeUninit::slice_assume_init_mut(keys) };
+    // SAFETY: the key of a pair is its first field.
+    unsafe { radix_sort(keys, scratch, |&(key, _)| key) };
+
+    // Like `sort_by_cached_key`, move the elements into place by following the indices, where
+    // the index of an element that was swapped away is found where it has been swapped to.
+    for i in 0..len {
+        let mut index = keys[i].1 as usize;
+        while index < i {
+            index = keys[index].1 as usize;
+        }
+        keys[i].1 = index as u32;
+        v.swap(i, index);
+    }
+}
+
+/// Sorts `v` stably by the keys of its elements, using `scratch` as the buffer that the elements
+/// are distributed into on every other pass.
+///
+/// # Safety
+///
+/// `key` must return the same key every time it is called with the same element.
+unsafe fn radix_sort<I: Copy, K: RadixKey>(
+    v: &mut [I],
+    scratch: &mut [MaybeUninit<I>],
+    key: impl Fn(&I) -> K,
+) {
+    // Passes for the bytes that are the same in all keys would not move anything, so they are
+    // skipped, which makes small keys in wide types fast to sort.
+    let first = key(&v[0]).radix_bits();
+    let mut differing_bits = 0;
+    for item in &*v {
+        differing_bits |= key(item).radix_bits() ^ first;
+    }
+
+    let len = v.len();
+    let scratch = &mut scratch[..len];
+    let v_base = v.as_mut_ptr();
+    let mut src = v_base;
+    let mut dst = MaybeUninit::slice_as_mut_ptr(scratch);
+
+    for shift in (0..u64::BITS).step_by(8) {
+        if (differing_bits >> shift) as u8 == 0 {
+            continue;
+        }
+        // SAFETY: `src` is either `v` or `scratch`, which are both valid for `len` elements and
+        // fully initialized at the start of every pass.
+        let src_slice = unsafe { slice::from_raw_parts(src, len) };
+
+        let mut offsets = [0usize; 256];
+        for item in src_slice {
+            offsets[(key(item).radix_bits() >> shift) as u8 as usize] += 1;
+        }
+        let mut sum = 0;
+        for offset in &mut offsets {
+            let count = *offset;
+            *offset = sum;
+            sum += count;
+        }
+
+        for item in src_slice {
+            let offset = &mut offsets[(key(item).radix_bits() >> shift) as u8 as usize];
+            // SAFETY: the offsets of the elements with a given byte start at the number of
+            // elements with a smaller byte, so they stay below `len` as long as the keys do not
+            // change between the two loops, as guaranteed by the caller.
+            unsafe { dst.add(*offset).write(*item) };
+            *offset += 1;
+        }
+        mem::swap(&mut src, &mut dst);
+    }
+
+    if src != v_base {
+        // SAFETY: `src` is `scratch`, which now holds all elements in order, and does not overlap
+        // with `v`.
+        unsafe { ptr::copy_nonoverlapping(src, v_base, len) };
+    }
+}
@@ -10,6 +10,8 @@
 mod count;
 mod error;
 mod iter;
+mod normalize;
+mod segmentation;
 mod traits;
 mod validations;
 
@@ -54,6 +56,10 @@
 pub use iter::{RSplitN, SplitN};
 #[stable(feature = "utf8_chunks", since = "1.79.0")]
 pub use lossy::{Utf8Chunk, Utf8Chunks};
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub use normalize::IsNormalized;
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+pub use segmentation::{Graphemes, WordBounds};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use traits::FromStr;
 #[unstable(feature = "str_internals", issue = "none")]
@@ -1019,6 +1025,83 @@ pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
         SplitAsciiWhitespace { inner }
     }
 
+    /// Returns an iterator over the extended grapheme clusters of a string
+    /// slice.
+    ///
+    /// A grapheme cluster is what a user perceives as a single character,
+    /// like a letter followed by combining marks, a Hangul syllable written
+    /// as separate jamo, a flag made of two regional indicators, or an emoji
+    /// ZWJ sequence. Unlike [`chars`], this never splits such a character.
+    ///
+    /// The clusters are determined by the rules of
+    /// [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/),
+    /// which depend on the version of Unicode in [`char::UNICODE_VERSION`].
+    ///
+    /// [`chars`]: str::chars
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_segmentation)]
+    ///
+    /// let s = "ye\u{301}s\r\n\u{1f1e9}\u{1f1ea}";
+    /// let graphemes = s.graphemes().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(s.chars().count(), 8);
+    /// assert_eq!(graphemes, ["y", "e\u{301}", "s", "\r\n", "\u{1f1e9}\u{1f1ea}"]);
+    /// ```
+    #[must_use = "this returns the clusters as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "unicode_segmentation", issue = "none")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes::new(self)
+    }
+
+    /// Returns an iterator over the substrings of a string slice between word
+    /// boundaries.
+    ///
+    /// The word boundaries are determined by the rules of
+    /// [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/).
+    /// Besides the words, the iterator returns the whitespace and punctuation
+    /// between them, so that the substrings concatenate to the original string
+    /// slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_segmentation)]
+    ///
+    /// let s = "The quick (\"brown\") fox can't jump 32.3 feet, right?";
+    /// let words = s.split_word_bounds().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(words, [
+    ///     "The", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", " ", "can't",
+    ///     " ", "jump", " ", "32.3", " ", "feet", ",", " ", "right", "?",
+    /// ]);
+    /// ```
+    ///
+    /// To get just the words, filter out the substrings without any
+    /// alphanumeric characters:
+    ///
+    /// ```
+    /// #![feature(unicode_segmentation)]
+    ///
+    /// let words = "Hello, wörld!"
+    ///     .split_word_bounds()
+    ///     .filter(|word| word.chars().any(char::is_alphanumeric))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(words, ["Hello", "wörld"]);
+    /// ```
+    #[must_use = "this returns the split string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "unicode_segmentation", issue = "none")]
+    #[inline]
+    pub fn split_word_bounds(&self) -> WordBounds<'_> {
+        WordBounds::new(self)
+    }
+
     /// Returns an iterator over the lines of a string, as string slices.
     ///
     /// Lines are split at line endings that are either newlines (`\n`) or
@@ -2736,6 +2819,99 @@ pub fn escape_unicode(&self) -> EscapeUnicode<'_> {
     pub fn substr_range(&self, substr: &str) -> Option<Range<usize>> {
         self.as_bytes().subslice_range(substr.as_bytes())
     }
+
+    /// Quickly checks whether this string slice is in Unicode Normalization
+    /// Form C (NFC), without normalizing it.
+    ///
+    /// This uses the quick check algorithm of [UAX #15], which may not be
+    /// able to tell, in which case [`IsNormalized::Maybe`] is returned. Most
+    /// strings in practice get a definite answer.
+    ///
+    /// [UAX #15]: https://www.unicode.org/reports/tr15/#Detecting_Normalization_Forms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    /// use std::str::IsNormalized;
+    ///
+    /// // U+00E9 LATIN SMALL LETTER E WITH ACUTE
+    /// assert_eq!("caf\u{e9}".is_nfc_quick(), IsNormalized::Yes);
+    /// // U+0065 LATIN SMALL LETTER E, U+0301 COMBINING ACUTE ACCENT
+    /// assert_eq!("cafe\u{301}".is_nfc_quick(), IsNormalized::Maybe);
+    /// // U+212B ANGSTROM SIGN, which normalizes to U+00C5
+    /// assert_eq!("\u{212b}".is_nfc_quick(), IsNormalized::No);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfc_quick(&self) -> IsNormalized {
+        normalize::quick_check(self, normalize::Form::Nfc)
+    }
+
+    /// Quickly checks whether this string slice is in Unicode Normalization
+    /// Form D (NFD), without normalizing it.
+    ///
+    /// Unlike for the composed forms, the quick check always gives a definite
+    /// answer for the decomposed forms, so this never returns
+    /// [`IsNormalized::Maybe`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    /// use std::str::IsNormalized;
+    ///
+    /// assert_eq!("cafe\u{301}".is_nfd_quick(), IsNormalized::Yes);
+    /// assert_eq!("caf\u{e9}".is_nfd_quick(), IsNormalized::No);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfd_quick(&self) -> IsNormalized {
+        normalize::quick_check(self, normalize::Form::Nfd)
+    }
+
+    /// Quickly checks whether this string slice is in Unicode Normalization
+    /// Form KC (NFKC), without normalizing it.
+    ///
+    /// See [`is_nfc_quick`](str::is_nfc_quick) for when the check is not able
+    /// to tell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    /// use std::str::IsNormalized;
+    ///
+    /// assert_eq!("caf\u{e9}".is_nfkc_quick(), IsNormalized::Yes);
+    /// // U+FB01 LATIN SMALL LIGATURE FI
+    /// assert_eq!("\u{fb01}".is_nfkc_quick(), IsNormalized::No);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfkc_quick(&self) -> IsNormalized {
+        normalize::quick_check(self, normalize::Form::Nfkc)
+    }
+
+    /// Quickly checks whether this string slice is in Unicode Normalization
+    /// Form KD (NFKD), without normalizing it.
+    ///
+    /// Like [`is_nfd_quick`](str::is_nfd_quick), this never returns
+    /// [`IsNormalized::Maybe`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_normalization)]
+    /// use std::str::IsNormalized;
+    ///
+    /// assert_eq!("fi".is_nfkd_quick(), IsNormalized::Yes);
+    /// assert_eq!("\u{fb01}".is_nfkd_quick(), IsNormalized::No);
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    pub fn is_nfkd_quick(&self) -> IsNormalized {
+        normalize::quick_check(self, normalize::Form::Nfkd)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
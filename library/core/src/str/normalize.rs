@@ -0,0 +1,70 @@
+//! Quick checks for the Unicode normalization forms of string slices.
+
+use crate::unicode::normalization::{combining_class, has_decomposition};
+use crate::unicode::{NFC_QC_Maybe, NFC_QC_No, NFKC_QC_No};
+
+/// The result of a quick check of whether a string is in a Unicode
+/// normalization form.
+///
+/// This `enum` is created by the [`is_nfc_quick`], [`is_nfd_quick`],
+/// [`is_nfkc_quick`] and [`is_nfkd_quick`] methods on [`str`]. See their
+/// documentation for more.
+///
+/// [`is_nfc_quick`]: str::is_nfc_quick
+/// [`is_nfd_quick`]: str::is_nfd_quick
+/// [`is_nfkc_quick`]: str::is_nfkc_quick
+/// [`is_nfkd_quick`]: str::is_nfkd_quick
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[unstable(feature = "unicode_normalization", issue = "none")]
+pub enum IsNormalized {
+    /// The string is in the normalization form.
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    Yes,
+    /// The string is not in the normalization form.
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    No,
+    /// The quick check cannot tell whether the string is in the normalization
+    /// form, only normalizing it can.
+    #[unstable(feature = "unicode_normalization", issue = "none")]
+    Maybe,
+}
+
+#[derive(Clone, Copy)]
+pub(super) enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+/// The quick check algorithm of UAX #15, section 9.
+pub(super) fn quick_check(s: &str, form: Form) -> IsNormalized {
+    let mut last_class = 0;
+    let mut result = IsNormalized::Yes;
+    for c in s.chars() {
+        // ASCII characters are in all forms, and are starters.
+        if c.is_ascii() {
+            last_class = 0;
+            continue;
+        }
+
+        let class = combining_class(c);
+        if class != 0 && last_class > class {
+            return IsNormalized::No;
+        }
+        let (no, maybe) = match form {
+            Form::Nfc => (NFC_QC_No(c), NFC_QC_Maybe(c)),
+            Form::Nfkc => (NFKC_QC_No(c), NFC_QC_Maybe(c)),
+            Form::Nfd => (has_decomposition(c, false), false),
+            Form::Nfkd => (has_decomposition(c, true), false),
+        };
+        if no {
+            return IsNormalized::No;
+        }
+        if maybe {
+            result = IsNormalized::Maybe;
+        }
+        last_class = class;
+    }
+    result
+}
@@ -1024,6 +1024,211 @@ fn strip_suffix_of<'a>(self, haystack: &'a str) -> Option<&'a str>
     }
 }
 
+/////////////////////////////////////////////////////////////////////////////
+// Impl for &MultiStrPattern
+/////////////////////////////////////////////////////////////////////////////
+
+/// A pattern that searches for any of several string slices at once.
+///
+/// Searching for each needle on its own takes one scan of the haystack per
+/// needle. This pattern instead looks at each position of the haystack once,
+/// and only compares the needles whose first two bytes fit the bytes there.
+///
+/// If several needles match at the same position, the one that comes first in
+/// the slice is picked, like with `|` in regular expressions. An empty needle
+/// matches at each character boundary where no needle before it matches.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern)]
+///
+/// use std::str::pattern::MultiStrPattern;
+///
+/// let keywords = MultiStrPattern::new(&["fn", "let", "loop"]);
+///
+/// assert_eq!("let x = f();".find(&keywords), Some(0));
+/// assert_eq!("x.loop_count".find(&keywords), Some(2));
+/// assert_eq!("x + y".find(&keywords), None);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MultiStrPattern<'b> {
+    /// The needles up to the first empty one, which no later needle can win
+    /// against.
+    needles: &'b [&'b str],
+    /// Whether the needles contain an empty one.
+    has_empty: bool,
+    /// The fingerprint tables of the Teddy algorithm from Hyperscan, without
+    /// the SIMD. The needles are put into eight buckets by their index, and
+    /// the tables hold the buckets that have a needle with the given low or
+    /// high nibble in the first and the second byte.
+    masks: [[u8; 16]; 4],
+}
+
+impl<'b> MultiStrPattern<'b> {
+    /// Creates a pattern searching for any of the `needles`.
+    pub fn new(needles: &'b [&'b str]) -> MultiStrPattern<'b> {
+        let (needles, has_empty) = match needles.iter().position(|needle| needle.is_empty()) {
+            Some(i) => (&needles[..i], true),
+            None => (needles, false),
+        };
+
+        let mut masks = [[0; 16]; 4];
+        for (i, needle) in needles.iter().enumerate() {
+            let bucket = 1 << (i % 8);
+            for (k, masks) in masks.chunks_exact_mut(2).enumerate() {
+                match needle.as_bytes().get(k) {
+                    Some(&byte) => {
+                        masks[0][usize::from(byte & 0xf)] |= bucket;
+                        masks[1][usize::from(byte >> 4)] |= bucket;
+                    }
+                    // A needle of a single byte fits any second byte.
+                    None => {
+                        for mask in masks.iter_mut().flatten() {
+                            *mask |= bucket;
+                        }
+                    }
+                }
+            }
+        }
+        MultiStrPattern { needles, has_empty, masks }
+    }
+
+    /// Returns the length of the needle matching at `pos`, which is smaller
+    /// than the length of the haystack.
+    #[inline]
+    fn match_at(&self, haystack: &[u8], pos: usize) -> Option<usize> {
+        let nibbles = |masks: &[[u8; 16]], byte: u8| {
+            masks[0][usize::from(byte & 0xf)] & masks[1][usize::from(byte >> 4)]
+        };
+        let mut buckets = nibbles(&self.masks[..2], haystack[pos]);
+        if let Some(&byte) = haystack.get(pos + 1) {
+            buckets &= nibbles(&self.masks[2..], byte);
+        }
+
+        // The needle with the smallest index wins, so each bucket only has to
+        // be checked up to the best match so far.
+        let mut best = self.needles.len();
+        while buckets != 0 {
+            let bucket = buckets.trailing_zeros() as usize;
+            buckets &= buckets - 1;
+            let mut i = bucket;
+            while i < best {
+                if haystack[pos..].starts_with(self.needles[i].as_bytes()) {
+                    best = i;
+                    break;
+                }
+                i += 8;
+            }
+        }
+        self.needles.get(best).map(|needle| needle.len())
+    }
+
+    /// Returns the first match starting at or after `start`, which is a char
+    /// boundary.
+    fn find(&self, haystack: &[u8], start: usize) -> Option<(usize, usize)> {
+        for pos in start..haystack.len() {
+            if let Some(len) = self.match_at(haystack, pos) {
+                return Some((pos, pos + len));
+            }
+            if self.has_empty {
+                return Some((pos, pos));
+            }
+        }
+        self.has_empty.then_some((haystack.len(), haystack.len()))
+    }
+}
+
+/// Associated type for `<&MultiStrPattern<'b> as Pattern>::Searcher<'a>`.
+#[derive(Clone, Debug)]
+pub struct MultiStrSearcher<'a, 'b> {
+    haystack: &'a str,
+    pattern: MultiStrPattern<'b>,
+    /// The start of the part of the haystack that was not returned yet.
+    position: usize,
+    /// A match found by `next` after the rejected part before it.
+    pending: Option<(usize, usize)>,
+    /// Whether an empty match was returned at `position`.
+    after_empty: bool,
+}
+
+impl<'a, 'b> MultiStrSearcher<'a, 'b> {
+    fn new(haystack: &'a str, pattern: MultiStrPattern<'b>) -> MultiStrSearcher<'a, 'b> {
+        MultiStrSearcher { haystack, pattern, position: 0, pending: None, after_empty: false }
+    }
+
+    fn find(&self) -> Option<(usize, usize)> {
+        let mut start = self.position;
+        if self.after_empty {
+            // Only the empty needle matched there, so the char after it is not
+            // part of any match.
+            start += self.haystack[start..].chars().next()?.len_utf8();
+        }
+        // Matches of non-empty needles lie on char boundaries, as both the
+        // haystack and the needles are valid UTF-8.
+        self.pattern.find(self.haystack.as_bytes(), start)
+    }
+}
+
+unsafe impl<'a, 'b> Searcher<'a> for MultiStrSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    fn next(&mut self) -> SearchStep {
+        let pos = self.position;
+        let Some((start, end)) = self.pending.take().or_else(|| self.find()) else {
+            self.position = self.haystack.len();
+            self.after_empty = false;
+            return if pos < self.haystack.len() {
+                SearchStep::Reject(pos, self.haystack.len())
+            } else {
+                SearchStep::Done
+            };
+        };
+        if pos < start {
+            self.pending = Some((start, end));
+            self.position = start;
+            self.after_empty = false;
+            return SearchStep::Reject(pos, start);
+        }
+        self.position = end;
+        self.after_empty = start == end;
+        SearchStep::Match(start, end)
+    }
+
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let (start, end) = self.pending.take().or_else(|| self.find())?;
+        self.position = end;
+        self.after_empty = start == end;
+        Some((start, end))
+    }
+}
+
+/// Searches for any of the needles of the pattern.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern)]
+///
+/// use std::str::pattern::MultiStrPattern;
+///
+/// let separators = MultiStrPattern::new(&[", ", " and "]);
+/// let words: Vec<&str> = "one, two and three".split(&separators).collect();
+/// assert_eq!(words, ["one", "two", "three"]);
+/// ```
+impl<'b, 'c> Pattern for &'c MultiStrPattern<'b> {
+    type Searcher<'a> = MultiStrSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> MultiStrSearcher<'_, 'b> {
+        MultiStrSearcher::new(haystack, *self)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Two Way substring searcher
 /////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,354 @@
+//! Grapheme cluster and word boundaries of string slices, see
+//! [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/).
+
+use crate::iter::FusedIterator;
+use crate::unicode::{
+    Extended_Pictographic, GraphemeClusterBreak, InCB_Consonant, InCB_Extend, InCB_Linker,
+    WordBreak, grapheme_cluster_break, word_break,
+};
+
+/// An iterator over the extended grapheme clusters of a string slice.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: str::graphemes
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Graphemes<'a> {
+    pub(super) fn new(s: &'a str) -> Graphemes<'a> {
+        Graphemes { rest: s }
+    }
+
+    /// Views the underlying data as a subslice of the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_segmentation)]
+    ///
+    /// let mut graphemes = "e\u{301}tude".graphemes();
+    ///
+    /// assert_eq!(graphemes.next(), Some("e\u{301}"));
+    /// assert_eq!(graphemes.as_str(), "tude");
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_segmentation", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (grapheme, rest) = self.rest.split_at(grapheme_len(self.rest));
+        self.rest = rest;
+        Some(grapheme)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every cluster is at least one `char` of at most four bytes.
+        let len = self.rest.len();
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+impl FusedIterator for Graphemes<'_> {}
+
+/// An iterator over the substrings of a string slice between word boundaries.
+///
+/// This struct is created by the [`split_word_bounds`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_word_bounds`]: str::split_word_bounds
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+pub struct WordBounds<'a> {
+    rest: &'a str,
+}
+
+impl<'a> WordBounds<'a> {
+    pub(super) fn new(s: &'a str) -> WordBounds<'a> {
+        WordBounds { rest: s }
+    }
+
+    /// Views the underlying data as a subslice of the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(unicode_segmentation)]
+    ///
+    /// let mut words = "can't stop".split_word_bounds();
+    ///
+    /// assert_eq!(words.next(), Some("can't"));
+    /// assert_eq!(words.as_str(), " stop");
+    /// ```
+    #[must_use]
+    #[unstable(feature = "unicode_segmentation", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+impl<'a> Iterator for WordBounds<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (word, rest) = self.rest.split_at(word_len(self.rest));
+        self.rest = rest;
+        Some(word)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every substring is at least one `char` of at most four bytes.
+        let len = self.rest.len();
+        (len.div_ceil(4), Some(len))
+    }
+}
+
+#[unstable(feature = "unicode_segmentation", issue = "none")]
+impl FusedIterator for WordBounds<'_> {}
+
+/// Returns the length in bytes of the first extended grapheme cluster of `s`.
+fn grapheme_len(s: &str) -> usize {
+    // Two ASCII characters are only kept together as CR LF, which is by far the
+    // most common case.
+    if let [first, second, ..] = *s.as_bytes() {
+        if first.is_ascii() && second.is_ascii() && first != b'\r' {
+            return 1;
+        }
+    }
+
+    let mut chars = s.char_indices();
+    let Some((_, first)) = chars.next() else { return 0 };
+    let mut state = GraphemeState::new(first);
+    for (i, c) in chars {
+        if state.is_boundary(c) {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// The context needed to decide whether there is a grapheme cluster boundary
+/// before the next `char`.
+struct GraphemeState {
+    prev: GraphemeClusterBreak,
+    /// The progress through an emoji ZWJ sequence, see GB11.
+    emoji: EmojiSequence,
+    /// The progress through an Indic conjunct, see GB9c.
+    conjunct: Conjunct,
+    /// Whether the previous `char`s end in an odd number of regional
+    /// indicators, see GB12 and GB13.
+    odd_regional_indicators: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EmojiSequence {
+    None,
+    /// An Extended_Pictographic `char`, followed by any number of Extend.
+    Pictographic,
+    /// The same, followed by a ZWJ.
+    Zwj,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    /// An InCB=Consonant, followed by any number of InCB=Extend.
+    Consonant,
+    /// The same, followed by at least one InCB=Linker and any number of
+    /// InCB=Extend.
+    Linker,
+}
+
+impl GraphemeState {
+    fn new(first: char) -> GraphemeState {
+        let mut state = GraphemeState {
+            prev: GraphemeClusterBreak::Other,
+            emoji: EmojiSequence::None,
+            conjunct: Conjunct::None,
+            odd_regional_indicators: false,
+        };
+        state.push(first, grapheme_cluster_break(first));
+        state
+    }
+
+    /// Returns whether there is a boundary between the previous `char`s and
+    /// `c`, and adds `c` to the context.
+    fn is_boundary(&mut self, c: char) -> bool {
+        use GraphemeClusterBreak::*;
+
+        let next = grapheme_cluster_break(c);
+        let boundary = match (self.prev, next) {
+            // GB3
+            (CR, LF) => false,
+            // GB4, GB5
+            (CR | LF | Control, _) | (_, CR | LF | Control) => true,
+            // GB6, GB7, GB8
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+            // GB9, GB9a, GB9b
+            (_, Extend | ZWJ | SpacingMark) | (Prepend, _) => false,
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) => !self.odd_regional_indicators,
+            // GB9c, GB11, GB999
+            _ => {
+                !(self.conjunct == Conjunct::Linker && InCB_Consonant(c)
+                    || self.emoji == EmojiSequence::Zwj && Extended_Pictographic(c))
+            }
+        };
+        self.push(c, next);
+        boundary
+    }
+
+    fn push(&mut self, c: char, class: GraphemeClusterBreak) {
+        use GraphemeClusterBreak::*;
+
+        self.prev = class;
+        self.odd_regional_indicators = class == RegionalIndicator && !self.odd_regional_indicators;
+        self.emoji = match (self.emoji, class) {
+            _ if Extended_Pictographic(c) => EmojiSequence::Pictographic,
+            (EmojiSequence::Pictographic, Extend) => EmojiSequence::Pictographic,
+            (EmojiSequence::Pictographic, ZWJ) => EmojiSequence::Zwj,
+            _ => EmojiSequence::None,
+        };
+        self.conjunct = match self.conjunct {
+            _ if InCB_Consonant(c) => Conjunct::Consonant,
+            Conjunct::None => Conjunct::None,
+            _ if InCB_Linker(c) => Conjunct::Linker,
+            conjunct if InCB_Extend(c) => conjunct,
+            _ => Conjunct::None,
+        };
+    }
+}
+
+/// Returns the length in bytes of the first substring of `s` that ends at a
+/// word boundary.
+fn word_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    let Some((_, first)) = chars.next() else { return 0 };
+    let mut state = WordState::new(word_break(first));
+    while let Some((i, c)) = chars.next() {
+        if state.is_boundary(c, chars.as_str()) {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// The context needed to decide whether there is a word boundary before the
+/// next `char`.
+struct WordState {
+    /// The previous `char`, for the rules that apply before WB4.
+    prev: WordBreak,
+    /// The last and the second to last `char` that is not ignored by WB4.
+    last: WordBreak,
+    second_to_last: WordBreak,
+    /// Whether the previous `char`s end in an odd number of regional
+    /// indicators, see WB15 and WB16.
+    odd_regional_indicators: bool,
+}
+
+impl WordState {
+    fn new(first: WordBreak) -> WordState {
+        WordState {
+            prev: first,
+            last: first,
+            second_to_last: WordBreak::Other,
+            odd_regional_indicators: first == WordBreak::RegionalIndicator,
+        }
+    }
+
+    /// Returns whether there is a boundary between the previous `char`s and
+    /// `c`, followed by `rest`, and adds `c` to the context.
+    fn is_boundary(&mut self, c: char, rest: &str) -> bool {
+        use WordBreak::*;
+
+        let next = word_break(c);
+        let boundary = match (self.prev, next) {
+            // WB3
+            (CR, LF) => false,
+            // WB3a, WB3b
+            (Newline | CR | LF, _) | (_, Newline | CR | LF) => true,
+            // WB3c
+            (ZWJ, _) if Extended_Pictographic(c) => false,
+            // WB3d
+            (WSegSpace, WSegSpace) => false,
+            // WB4
+            (_, Extend | Format | ZWJ) => {
+                self.prev = next;
+                return false;
+            }
+            _ => !self.is_kept_together(next, rest),
+        };
+        self.prev = next;
+        self.second_to_last = self.last;
+        self.last = next;
+        self.odd_regional_indicators = next == RegionalIndicator && !self.odd_regional_indicators;
+        boundary
+    }
+
+    /// The rules from WB5 on, which skip the `char`s ignored by WB4.
+    fn is_kept_together(&self, next: WordBreak, rest: &str) -> bool {
+        use WordBreak::*;
+
+        let is_letter = |class| matches!(class, ALetter | HebrewLetter);
+        let is_mid_letter = |class| matches!(class, MidLetter | MidNumLet | SingleQuote);
+        let is_mid_num = |class| matches!(class, MidNum | MidNumLet | SingleQuote);
+        // The class of the `char` after `next`, which is only needed by a few
+        // rules.
+        let after = || {
+            rest.chars()
+                .map(word_break)
+                .find(|class| !matches!(class, Extend | Format | ZWJ))
+                .unwrap_or(Other)
+        };
+        let (last, second_to_last) = (self.last, self.second_to_last);
+
+        // WB5, WB6, WB7
+        is_letter(last) && is_letter(next)
+            || is_letter(last) && is_mid_letter(next) && is_letter(after())
+            || is_letter(second_to_last) && is_mid_letter(last) && is_letter(next)
+            // WB7a, WB7b, WB7c
+            || last == HebrewLetter && next == SingleQuote
+            || last == HebrewLetter && next == DoubleQuote && after() == HebrewLetter
+            || second_to_last == HebrewLetter && last == DoubleQuote && next == HebrewLetter
+            // WB8, WB9, WB10
+            || (last == Numeric || is_letter(last)) && next == Numeric
+            || last == Numeric && is_letter(next)
+            // WB11, WB12
+            || second_to_last == Numeric && is_mid_num(last) && next == Numeric
+            || last == Numeric && is_mid_num(next) && after() == Numeric
+            // WB13, WB13a, WB13b
+            || last == Katakana && next == Katakana
+            || matches!(last, ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet)
+                && next == ExtendNumLet
+            || last == ExtendNumLet && matches!(next, ALetter | HebrewLetter | Numeric | Katakana)
+            // WB15, WB16
+            || last == RegionalIndicator
+                && next == RegionalIndicator
+                && self.odd_regional_indicators
+    }
+}
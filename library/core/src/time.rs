@@ -20,6 +20,7 @@
 //! ```
 
 use crate::fmt;
+use crate::fmt::display_buffer::DisplayBuffer;
 use crate::iter::Sum;
 use crate::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
@@ -1427,6 +1428,210 @@ fn fmt_decimal(
     }
 }
 
+/// The units of the human-friendly duration format, from the largest to the smallest, along
+/// with their length in nanoseconds. `us` is an ASCII alternative to `µs` when parsing.
+const UNITS: [(&str, u128); 8] = [
+    ("d", (SECS_PER_MINUTE * MINS_PER_HOUR * HOURS_PER_DAY) as u128 * NANOS_PER_SEC as u128),
+    ("h", (SECS_PER_MINUTE * MINS_PER_HOUR) as u128 * NANOS_PER_SEC as u128),
+    ("m", SECS_PER_MINUTE as u128 * NANOS_PER_SEC as u128),
+    ("s", NANOS_PER_SEC as u128),
+    ("ms", NANOS_PER_MILLI as u128),
+    ("µs", NANOS_PER_MICRO as u128),
+    ("us", NANOS_PER_MICRO as u128),
+    ("ns", 1),
+];
+
+impl Duration {
+    /// Returns an object that implements [`Display`] by writing the duration as a sequence of
+    /// integer components with units, from the largest to the smallest, such as `1h30m` or
+    /// `2s250ms`.
+    ///
+    /// The units are `d` (24 hours), `h`, `m`, `s`, `ms`, `µs` and `ns`, and components that are
+    /// zero are left out, except for a zero `Duration` which is written as `0s`. The output can be
+    /// parsed back into the same `Duration` with [`Duration::parse_human`]. Unlike [`Debug`], this
+    /// never rounds. Width, alignment and precision apply to the output as they do to strings.
+    ///
+    /// [`Display`]: fmt::Display
+    /// [`Debug`]: fmt::Debug
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_human)]
+    ///
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::from_secs(5400).display_human().to_string(), "1h30m");
+    /// assert_eq!(Duration::from_millis(2250).display_human().to_string(), "2s250ms");
+    /// assert_eq!(Duration::new(90_000, 1_500).display_human().to_string(), "1d1h1µs500ns");
+    /// assert_eq!(Duration::ZERO.display_human().to_string(), "0s");
+    /// assert_eq!(format!("{:>6}", Duration::from_millis(250).display_human()), " 250ms");
+    /// ```
+    #[unstable(feature = "duration_human", issue = "none")]
+    #[must_use = "this does not display the duration, \
+                  it returns an object that can be displayed"]
+    #[inline]
+    pub fn display_human(&self) -> DisplayHuman {
+        DisplayHuman { duration: *self }
+    }
+
+    /// Parses a `Duration` from a sequence of components with units, such as `1h30m` or `250ms`.
+    ///
+    /// Each component is a decimal number followed by one of the units `d` (24 hours), `h`, `m`,
+    /// `s`, `ms`, `µs` (or `us`) and `ns`. The units must appear from the largest to the smallest,
+    /// each at most once, and the components are added up. Whitespace and signs are not accepted.
+    ///
+    /// A number may have a fractional part, as in `1.5h`. The value of a component is truncated
+    /// to whole nanoseconds, so `1.5ns` is parsed as one nanosecond.
+    ///
+    /// This accepts everything that [`Duration::display_human`] produces.
+    ///
+    /// # Errors
+    ///
+    /// Parsing is overflow-checked rather than saturating: a string whose value is greater than
+    /// [`Duration::MAX`] results in an error, as does an empty string, a number without a unit, an
+    /// unknown unit or units that are out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(duration_human)]
+    ///
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Duration::parse_human("1h30m"), Ok(Duration::from_secs(5400)));
+    /// assert_eq!(Duration::parse_human("250ms"), Ok(Duration::from_millis(250)));
+    /// assert_eq!(Duration::parse_human("1.5s"), Ok(Duration::from_millis(1500)));
+    ///
+    /// assert!(Duration::parse_human("30m1h").is_err());
+    /// assert!(Duration::parse_human("10").is_err());
+    /// assert!(Duration::parse_human("213503982334602d").is_err());
+    /// ```
+    #[unstable(feature = "duration_human", issue = "none")]
+    pub fn parse_human(s: &str) -> Result<Duration, ParseDurationError> {
+        const INVALID: ParseDurationError =
+            ParseDurationError { kind: ParseDurationErrorKind::Invalid };
+
+        if s.is_empty() {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Empty });
+        }
+
+        // The total is computed in nanoseconds, saturating at a value far beyond
+        // `Duration::MAX`, and only checked for overflow at the end.
+        let mut s = s.as_bytes();
+        let mut total = 0u128;
+        let mut previous_unit = u128::MAX;
+        while !s.is_empty() {
+            let mut integer = 0u128;
+            let mut any_digits = false;
+            while let [c @ b'0'..=b'9', ref rest @ ..] = *s {
+                integer = integer.saturating_mul(10).saturating_add((c - b'0') as u128);
+                any_digits = true;
+                s = rest;
+            }
+
+            // At most 19 digits of the fraction are kept, which is more than enough for
+            // nanosecond precision even for days.
+            let mut fraction = 0u64;
+            let mut scale = 1u64;
+            if let [b'.', ref rest @ ..] = *s {
+                s = rest;
+                while let [c @ b'0'..=b'9', ref rest @ ..] = *s {
+                    if scale < 10_u64.pow(19) {
+                        fraction = fraction * 10 + (c - b'0') as u64;
+                        scale *= 10;
+                    }
+                    any_digits = true;
+                    s = rest;
+                }
+            }
+            if !any_digits {
+                return Err(INVALID);
+            }
+
+            let len = s.iter().position(|&c| c.is_ascii_digit() || c == b'.').unwrap_or(s.len());
+            let (unit, rest) = s.split_at(len);
+            s = rest;
+            let Some(&(_, unit)) = UNITS.iter().find(|(name, _)| name.as_bytes() == unit) else {
+                return Err(INVALID);
+            };
+            if unit >= previous_unit {
+                return Err(INVALID);
+            }
+            previous_unit = unit;
+
+            let component = integer
+                .saturating_mul(unit)
+                .saturating_add(fraction as u128 * unit / scale as u128);
+            total = total.saturating_add(component);
+        }
+
+        let secs = total / NANOS_PER_SEC as u128;
+        if secs > u64::MAX as u128 {
+            return Err(ParseDurationError { kind: ParseDurationErrorKind::Overflow });
+        }
+        Ok(Duration::new(secs as u64, (total % NANOS_PER_SEC as u128) as u32))
+    }
+
+    /// Writes `self` as a sequence of components such as `1h30m`, leaving out the components
+    /// that are zero.
+    fn fmt_units(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0s");
+        }
+        let secs_per_hour = SECS_PER_MINUTE * MINS_PER_HOUR;
+        let secs_per_day = secs_per_hour * HOURS_PER_DAY;
+        let nanos = self.nanos.0;
+        let components = [
+            self.secs / secs_per_day,
+            self.secs % secs_per_day / secs_per_hour,
+            self.secs % secs_per_hour / SECS_PER_MINUTE,
+            self.secs % SECS_PER_MINUTE,
+            (nanos / NANOS_PER_MILLI) as u64,
+            (nanos % NANOS_PER_MILLI / NANOS_PER_MICRO) as u64,
+            // `us` is skipped, it is only an alternative spelling of `µs`.
+            0,
+            (nanos % NANOS_PER_MICRO) as u64,
+        ];
+        for (value, (unit, _)) in components.into_iter().zip(UNITS) {
+            if value != 0 {
+                write!(f, "{value}{unit}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Helper struct for displaying a [`Duration`] in a human-friendly format.
+///
+/// This `struct` is created by the [`display_human`](Duration::display_human) method on
+/// [`Duration`]. See its documentation for more.
+#[unstable(feature = "duration_human", issue = "none")]
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayHuman {
+    duration: Duration,
+}
+
+#[unstable(feature = "duration_human", issue = "none")]
+impl fmt::Display for DisplayHuman {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // If there are no alignment requirements, write the duration directly to `f`.
+        // Otherwise, write it to a local buffer and then use `f.pad`.
+        if f.precision().is_none() && f.width().is_none() {
+            self.duration.fmt_units(f)
+        } else {
+            // Every component has its maximum length, although no `Duration` is this long.
+            const LONGEST_DURATION: &str = "213503982334601d23h59m59s999ms999µs999ns";
+
+            let mut buf = DisplayBuffer::<{ LONGEST_DURATION.len() }>::new();
+            // Buffer is long enough for the longest possible duration, so this should never fail.
+            self.duration.fmt_units(&mut buf).unwrap();
+
+            f.pad(buf.as_str())
+        }
+    }
+}
+
 /// An error which can be returned when converting a floating-point value of seconds
 /// into a [`Duration`].
 ///
@@ -1701,3 +1906,51 @@ pub fn try_from_secs_f64(secs: f64) -> Result<Duration, TryFromFloatSecsError> {
         )
     }
 }
+
+/// An error which can be returned when parsing a [`Duration`] from a string.
+///
+/// This error is returned from [`Duration::parse_human`].
+///
+/// # Example
+///
+/// ```
+/// #![feature(duration_human)]
+///
+/// use std::time::Duration;
+///
+/// if let Err(e) = Duration::parse_human("1 hour") {
+///     println!("Failed to parse Duration: {e}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[unstable(feature = "duration_human", issue = "none")]
+pub struct ParseDurationError {
+    kind: ParseDurationErrorKind,
+}
+
+impl ParseDurationError {
+    const fn description(&self) -> &'static str {
+        match self.kind {
+            ParseDurationErrorKind::Empty => "cannot parse duration from empty string",
+            ParseDurationErrorKind::Invalid => "invalid duration literal",
+            ParseDurationErrorKind::Overflow => "duration too large to fit in `Duration`",
+        }
+    }
+}
+
+#[unstable(feature = "duration_human", issue = "none")]
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseDurationErrorKind {
+    // The string is empty.
+    Empty,
+    // The string is not a sequence of numbers with known units in decreasing order.
+    Invalid,
+    // The value is greater than `Duration::MAX`.
+    Overflow,
+}
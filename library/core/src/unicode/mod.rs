@@ -10,12 +10,26 @@
 #[rustfmt::skip]
 pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
 pub(crate) use unicode_data::cc::lookup as Cc;
+pub(crate) use unicode_data::extended_pictographic::lookup as Extended_Pictographic;
 pub(crate) use unicode_data::grapheme_extend::lookup as Grapheme_Extend;
+pub(crate) use unicode_data::incb_consonant::lookup as InCB_Consonant;
+pub(crate) use unicode_data::incb_extend::lookup as InCB_Extend;
+pub(crate) use unicode_data::incb_linker::lookup as InCB_Linker;
 pub(crate) use unicode_data::lowercase::lookup as Lowercase;
 pub(crate) use unicode_data::n::lookup as N;
+pub(crate) use unicode_data::nfc_qc_m::lookup as NFC_QC_Maybe;
+pub(crate) use unicode_data::nfc_qc_n::lookup as NFC_QC_No;
+pub(crate) use unicode_data::nfkc_qc_n::lookup as NFKC_QC_No;
+pub(crate) use unicode_data::segmentation::{
+    grapheme_cluster_break, word_break, GraphemeClusterBreak, WordBreak,
+};
 pub(crate) use unicode_data::uppercase::lookup as Uppercase;
 pub(crate) use unicode_data::white_space::lookup as White_Space;
+pub(crate) use unicode_data::wide::lookup as Wide;
+pub(crate) use unicode_data::zero_width::lookup as Zero_Width;
 
+// for use in alloc, not re-exported in std.
+pub mod normalization;
 pub(crate) mod printable;
 mod unicode_data;
 
@@ -0,0 +1,93 @@
+//! Building blocks of the Unicode normalization algorithm, see
+//! [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/).
+
+pub use super::unicode_data::normalization::combining_class;
+use super::unicode_data::normalization::{
+    canonical_decomposition, compatibility_decomposition, compose_pair,
+};
+
+// Constants of the algorithmic (de)composition of Hangul syllables, see
+// section 3.12 of the Unicode Standard.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Calls `emit` with each character of the full canonical decomposition of
+/// `c`, or of its full compatibility decomposition if `compatibility` is set.
+///
+/// A character without a decomposition decomposes to itself. The emitted
+/// characters are not yet in canonical order.
+#[inline]
+pub fn decompose(c: char, compatibility: bool, mut emit: impl FnMut(char)) {
+    if c.is_ascii() {
+        emit(c);
+        return;
+    }
+
+    let s_index = (c as u32).wrapping_sub(S_BASE);
+    if s_index < S_COUNT {
+        let t_index = s_index % T_COUNT;
+        // SAFETY: the jamo of a Hangul syllable are all in the BMP, outside of
+        // the surrogate range.
+        unsafe {
+            emit(char::from_u32_unchecked(L_BASE + s_index / N_COUNT));
+            emit(char::from_u32_unchecked(V_BASE + (s_index % N_COUNT) / T_COUNT));
+            if t_index != 0 {
+                emit(char::from_u32_unchecked(T_BASE + t_index));
+            }
+        }
+        return;
+    }
+
+    let decomposition = if compatibility {
+        compatibility_decomposition(c).or_else(|| canonical_decomposition(c))
+    } else {
+        canonical_decomposition(c)
+    };
+    match decomposition {
+        Some(decomposition) => {
+            for &c in decomposition {
+                emit(c);
+            }
+        }
+        None => emit(c),
+    }
+}
+
+/// Returns whether `c` has a canonical decomposition, or with `compatibility`
+/// any decomposition, that is whether it is not in NFD or NFKD respectively.
+#[inline]
+pub fn has_decomposition(c: char, compatibility: bool) -> bool {
+    !c.is_ascii()
+        && ((c as u32).wrapping_sub(S_BASE) < S_COUNT
+            || canonical_decomposition(c).is_some()
+            || compatibility && compatibility_decomposition(c).is_some())
+}
+
+/// Returns the primary composite of the pair `a`, `b`, if there is one.
+#[inline]
+pub fn compose(a: char, b: char) -> Option<char> {
+    let l_index = (a as u32).wrapping_sub(L_BASE);
+    let v_index = (b as u32).wrapping_sub(V_BASE);
+    if l_index < L_COUNT && v_index < V_COUNT {
+        let s_index = (l_index * V_COUNT + v_index) * T_COUNT;
+        // SAFETY: the syllables are all in the BMP, outside of the surrogate
+        // range.
+        return Some(unsafe { char::from_u32_unchecked(S_BASE + s_index) });
+    }
+
+    let s_index = (a as u32).wrapping_sub(S_BASE);
+    let t_index = (b as u32).wrapping_sub(T_BASE);
+    if s_index < S_COUNT && s_index % T_COUNT == 0 && 0 < t_index && t_index < T_COUNT {
+        // SAFETY: as above.
+        return Some(unsafe { char::from_u32_unchecked(a as u32 + t_index) });
+    }
+
+    compose_pair(a, b)
+}
@@ -277,6 +277,33 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod extended_pictographic {
+    static SHORT_OFFSET_RUNS: [u32; 15] = [
+        8252, 10494746, 35662132, 174074629, 178270256, 195048087, 203550720, 211939584, 214037499,
+        268563774, 272758352, 276953856, 323091456, 325189630, 328400894,
+    ];
+    static OFFSETS: [u8; 157] = [
+        169, 1, 4, 1, 0, 1, 12, 1, 216, 1, 22, 1, 90, 6, 15, 2, 0, 2, 12, 1, 95, 1, 70, 1, 25, 11,
+        4, 3, 199, 1, 231, 2, 10, 1, 9, 1, 58, 4, 1, 6, 1, 12, 1, 114, 10, 118, 2, 11, 1, 1, 1, 1,
+        6, 1, 3, 1, 6, 1, 10, 2, 15, 1, 2, 1, 4, 1, 1, 1, 4, 3, 1, 1, 11, 5, 45, 3, 9, 1, 14, 1, 14,
+        1, 0, 2, 0, 3, 19, 2, 51, 1, 4, 1, 0, 1, 12, 1, 0, 1, 1, 1, 0, 0, 13, 3, 31, 1, 60, 6, 12,
+        2, 14, 1, 2, 10, 18, 57, 27, 15, 10, 1, 20, 1, 2, 9, 1, 4, 9, 0, 5, 0, 8, 0, 48, 128, 116,
+        12, 85, 43, 12, 4, 56, 8, 10, 6, 40, 8, 30, 82, 12, 47, 1, 10, 1, 0, 0, 0, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0xa9 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod grapheme_extend {
     static SHORT_OFFSET_RUNS: [u32; 33] = [
@@ -328,6 +355,86 @@ fn lookup_slow(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod incb_consonant {
+    static SHORT_OFFSET_RUNS: [u32; 2] = [
+        2325, 3214651,
+    ];
+    static OFFSETS: [u8; 53] = [
+        0, 37, 30, 8, 24, 8, 21, 20, 1, 7, 1, 1, 3, 4, 34, 2, 1, 1, 16, 2, 163, 20, 1, 7, 1, 2, 1,
+        5, 63, 1, 27, 20, 1, 7, 1, 2, 1, 5, 34, 2, 1, 1, 17, 1, 163, 20, 1, 16, 30, 3, 186, 38, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x915 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
+#[rustfmt::skip]
+pub mod incb_extend {
+    static SHORT_OFFSET_RUNS: [u32; 29] = [
+        768, 2098307, 10487185, 14685021, 253761300, 257957901, 354430191, 371208234, 383821423,
+        392210438, 409008926, 463535648, 467730941, 471927309, 484511012, 505482923, 509679673,
+        614537534, 618732607, 643898690, 648093506, 660695792, 664911006, 673304933, 677502976,
+        706863790, 736224492, 744614096, 749922635,
+    ];
+    static OFFSETS: [u8; 361] = [
+        0, 79, 1, 32, 0, 5, 0, 45, 1, 1, 1, 2, 1, 2, 1, 1, 72, 11, 48, 21, 16, 1, 101, 7, 2, 6, 2,
+        2, 1, 4, 35, 1, 30, 27, 160, 9, 9, 1, 24, 4, 1, 9, 1, 3, 1, 5, 43, 3, 60, 8, 42, 24, 1, 29,
+        60, 1, 20, 4, 103, 1, 65, 1, 61, 1, 16, 1, 110, 1, 127, 1, 144, 1, 110, 1, 24, 2, 101, 1,
+        16, 1, 109, 2, 141, 1, 109, 3, 13, 4, 108, 3, 13, 4, 76, 2, 27, 1, 1, 1, 1, 1, 55, 2, 1, 1,
+        5, 4, 2, 1, 1, 3, 1, 2, 62, 1, 112, 1, 1, 2, 82, 1, 0, 3, 0, 1, 189, 1, 10, 1, 203, 1, 143,
+        3, 219, 2, 71, 1, 20, 8, 2, 1, 48, 14, 1, 16, 101, 1, 54, 9, 55, 1, 58, 1, 80, 1, 152, 3, 1,
+        13, 1, 7, 4, 1, 6, 1, 3, 2, 198, 64, 0, 1, 194, 13, 4, 1, 3, 12, 0, 3, 141, 1, 96, 32, 0, 6,
+        105, 2, 0, 1, 4, 10, 32, 2, 80, 2, 0, 1, 37, 1, 151, 1, 27, 18, 57, 3, 133, 1, 252, 1, 1, 3,
+        2, 2, 5, 2, 1, 1, 52, 1, 246, 1, 0, 1, 0, 16, 0, 1, 226, 1, 149, 5, 0, 1, 1, 1, 40, 3, 4, 1,
+        165, 2, 0, 4, 0, 2, 80, 3, 70, 11, 49, 4, 192, 1, 41, 1, 14, 1, 57, 2, 69, 3, 48, 2, 62, 1,
+        86, 1, 107, 1, 178, 2, 80, 2, 41, 7, 3, 5, 205, 1, 3, 1, 23, 1, 99, 2, 251, 2, 126, 1, 119,
+        1, 115, 1, 0, 2, 0, 1, 4, 1, 156, 1, 83, 1, 18, 1, 81, 1, 0, 1, 0, 1, 1, 2, 81, 1, 0, 1, 0,
+        5, 59, 7, 0, 1, 0, 1, 1, 3, 4, 5, 8, 8, 2, 7, 30, 4, 148, 3, 0, 7, 1, 17, 2, 7, 1, 2, 1, 5,
+        100, 1, 160, 7, 0, 1, 61, 4, 0, 4, 0, 7, 109, 7, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x300 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
+#[rustfmt::skip]
+pub mod incb_linker {
+    static SHORT_OFFSET_RUNS: [u32; 2] = [
+        2381, 3214670,
+    ];
+    static OFFSETS: [u8; 13] = [
+        0, 1, 127, 1, 255, 1, 127, 1, 255, 1, 255, 1, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x94d && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod lowercase {
     const BITSET_CHUNKS_MAP: &'static [u8; 123] = &[
@@ -466,6 +573,100 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod nfc_qc_m {
+    static SHORT_OFFSET_RUNS: [u32; 13] = [
+        768, 2098771, 52431164, 56625982, 69210158, 119542113, 123738933, 132133017, 136384698,
+        140579646, 148968624, 157358384, 175249713,
+    ];
+    static OFFSETS: [u8; 85] = [
+        0, 5, 1, 7, 2, 1, 1, 1, 1, 2, 6, 1, 7, 6, 4, 2, 1, 2, 6, 1, 9, 1, 2, 1, 0, 3, 0, 1, 129, 1,
+        24, 1, 0, 1, 23, 2, 102, 1, 24, 1, 126, 1, 107, 1, 18, 2, 103, 1, 24, 1, 114, 1, 4, 1, 15,
+        1, 0, 1, 0, 21, 50, 27, 0, 1, 0, 2, 0, 1, 108, 1, 0, 1, 24, 1, 0, 1, 9, 1, 2, 1, 241, 1, 0,
+        1, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x300 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
+#[rustfmt::skip]
+pub mod nfc_qc_n {
+    static SHORT_OFFSET_RUNS: [u32; 13] = [
+        832, 2099544, 23072579, 56631153, 123740454, 207627049, 216017628, 220264704, 224459278,
+        226611550, 295892992, 304282142, 307493406,
+    ];
+    static OFFSETS: [u8; 147] = [
+        0, 2, 1, 2, 47, 1, 9, 1, 8, 1, 0, 8, 124, 2, 1, 1, 83, 1, 2, 1, 34, 3, 2, 1, 253, 2, 0, 1,
+        9, 1, 4, 1, 4, 1, 4, 1, 12, 1, 9, 1, 1, 2, 1, 1, 8, 1, 17, 1, 9, 1, 4, 1, 4, 1, 4, 1, 12, 1,
+        0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 61, 1, 2, 1, 10, 1, 1, 1, 7, 1, 7, 1, 7, 1, 7, 1,
+        2, 2, 9, 1, 1, 1, 1, 1, 2, 2, 0, 1, 3, 2, 0, 2, 0, 1, 0, 0, 2, 1, 1, 1, 2, 10, 1, 1, 1, 1,
+        2, 2, 3, 68, 2, 106, 67, 1, 1, 1, 10, 13, 1, 5, 1, 1, 1, 2, 1, 2, 1, 9, 0, 7, 86, 6, 0, 0,
+        0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x340 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
+#[rustfmt::skip]
+pub mod nfkc_qc_n {
+    static SHORT_OFFSET_RUNS: [u32; 27] = [
+        1415, 115345752, 123735603, 157290748, 236985644, 241181792, 505424396, 509619324,
+        522202783, 530592768, 591439516, 593537884, 610334976, 618723854, 620821822, 696321921,
+        773968222, 786551808, 794941094, 872536012, 876732464, 883027456, 887222528, 1025637360,
+        1063450624, 1067645470, 1070856734,
+    ];
+    static OFFSETS: [u8; 511] = [
+        160, 1, 7, 1, 1, 1, 4, 1, 2, 4, 2, 3, 1, 3, 115, 2, 11, 2, 8, 1, 53, 1, 68, 9, 36, 3, 188,
+        9, 31, 6, 2, 5, 91, 2, 1, 2, 47, 1, 5, 1, 3, 1, 5, 2, 1, 1, 72, 7, 25, 3, 1, 2, 3, 1, 0, 1,
+        237, 4, 0, 8, 124, 2, 1, 1, 83, 1, 2, 1, 34, 3, 2, 1, 253, 2, 0, 1, 127, 1, 40, 2, 46, 1,
+        54, 1, 9, 1, 4, 1, 4, 1, 4, 1, 12, 1, 9, 1, 1, 5, 7, 1, 17, 1, 9, 1, 4, 1, 4, 1, 4, 1, 12,
+        1, 0, 1, 0, 3, 1, 11, 1, 18, 1, 28, 13, 1, 34, 37, 218, 2, 213, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 61, 1, 1, 5, 7, 1, 1, 1, 1, 3, 3, 1, 7, 1, 1, 3, 3, 1, 7, 1, 1, 3, 9, 1, 1, 1,
+        1, 2, 1, 11, 6, 1, 5, 1, 12, 3, 8, 1, 3, 2, 1, 2, 4, 1, 1, 1, 8, 3, 13, 1, 7, 1, 16, 2, 2,
+        27, 1, 13, 11, 1, 87, 4, 1, 3, 1, 11, 1, 2, 2, 5, 2, 3, 1, 1, 1, 1, 1, 1, 1, 4, 1, 3, 1, 7,
+        1, 6, 4, 5, 6, 48, 9, 1, 162, 2, 1, 2, 248, 2, 0, 139, 0, 1, 103, 3, 101, 1, 0, 2, 241, 1,
+        0, 1, 83, 1, 12, 214, 42, 1, 53, 1, 1, 3, 96, 2, 2, 1, 95, 1, 49, 94, 3, 14, 96, 31, 1, 40,
+        8, 47, 1, 0, 0, 2, 210, 1, 129, 3, 3, 2, 0, 4, 9, 1, 0, 0, 2, 1, 1, 1, 2, 10, 1, 1, 1, 1, 2,
+        2, 3, 68, 2, 106, 38, 7, 12, 5, 5, 1, 1, 24, 1, 5, 1, 1, 1, 2, 1, 2, 1, 108, 33, 0, 18, 64,
+        2, 54, 40, 13, 19, 10, 22, 21, 2, 12, 1, 19, 1, 4, 4, 3, 1, 1, 1, 135, 4, 190, 3, 6, 2, 6,
+        2, 6, 2, 3, 3, 7, 1, 7, 0, 5, 1, 42, 1, 9, 0, 7, 86, 6, 0, 85, 1, 71, 1, 2, 2, 1, 2, 2, 2,
+        4, 1, 12, 1, 1, 1, 7, 1, 65, 1, 4, 2, 8, 1, 7, 1, 28, 1, 4, 1, 5, 1, 1, 3, 7, 1, 0, 2, 0, 2,
+        50, 0, 62, 0, 4, 1, 27, 1, 2, 1, 1, 2, 1, 1, 10, 1, 4, 1, 1, 1, 1, 6, 1, 4, 1, 1, 1, 1, 1,
+        1, 3, 1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 2, 4, 1, 7, 1, 4, 1, 4, 1, 1, 1,
+        10, 1, 17, 5, 3, 1, 5, 1, 17, 0, 11, 5, 31, 1, 32, 26, 3, 35, 1, 111, 3, 13, 44, 4, 9, 7, 2,
+        0, 10, 0, 0, 0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0xa0 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod uppercase {
     const BITSET_CHUNKS_MAP: &'static [u8; 125] = &[
@@ -582,6 +783,85 @@ pub fn lookup(c: char) -> bool {
     }
 }
 
+#[rustfmt::skip]
+pub mod wide {
+    static SHORT_OFFSET_RUNS: [u32; 25] = [
+        4352, 2106138, 6301181, 27274011, 136326784, 148917696, 201368717, 205564256, 211856384,
+        216061860, 218167552, 220265216, 222363152, 224489440, 249661432, 260148438, 264351728,
+        270643491, 285324028, 306311172, 308410636, 459407360, 501415934, 503578622, 508887038,
+    ];
+    static OFFSETS: [u8; 243] = [
+        0, 96, 0, 2, 13, 2, 190, 4, 3, 1, 2, 1, 0, 2, 21, 2, 50, 12, 43, 1, 19, 1, 13, 1, 8, 2, 17,
+        2, 5, 2, 8, 1, 5, 1, 21, 1, 7, 2, 1, 1, 4, 1, 2, 1, 7, 1, 4, 2, 28, 1, 35, 1, 1, 1, 4, 3, 1,
+        1, 61, 3, 24, 1, 14, 1, 0, 2, 51, 1, 4, 1, 0, 26, 1, 89, 12, 214, 26, 58, 4, 17, 2, 86, 4,
+        101, 5, 43, 1, 94, 1, 84, 11, 48, 1, 40, 8, 0, 64, 0, 3, 55, 0, 29, 0, 0, 0, 0, 0, 10, 22,
+        35, 1, 19, 1, 4, 149, 96, 127, 7, 0, 4, 12, 2, 14, 0, 8, 0, 42, 9, 0, 4, 1, 7, 1, 2, 1, 0,
+        15, 1, 29, 3, 2, 1, 14, 4, 8, 0, 0, 1, 202, 1, 190, 1, 2, 10, 101, 3, 13, 44, 4, 9, 7, 2,
+        14, 6, 154, 33, 12, 9, 1, 70, 1, 22, 12, 43, 4, 5, 12, 17, 3, 1, 3, 71, 1, 1, 1, 187, 2, 63,
+        13, 4, 1, 24, 18, 1, 26, 2, 13, 1, 86, 85, 48, 70, 6, 1, 3, 3, 2, 3, 4, 4, 11, 2, 7, 9, 227,
+        12, 4, 1, 0, 47, 1, 10, 1, 185, 112, 13, 3, 9, 7, 46, 1, 7, 8, 14, 4, 9, 7, 9, 0, 0, 2, 0,
+        0,
+    ];
+    #[inline]
+    pub fn lookup(c: char) -> bool {
+        (c as u32) >= 0x1100 && lookup_slow(c)
+    }
+    fn lookup_slow(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
+#[rustfmt::skip]
+pub mod zero_width {
+    static SHORT_OFFSET_RUNS: [u32; 33] = [
+        768, 10486915, 14681489, 18879325, 505419538, 509616139, 715140335, 736112682, 748725871,
+        757114882, 773913374, 903937536, 908132861, 924912129, 937495844, 962662059, 966858799,
+        1201740091, 1210129456, 1268850419, 1331770416, 1352755952, 1361145679, 1369554077,
+        1382141696, 1390530919, 1398921728, 1419894784, 1445061294, 1474421996, 1482811600,
+        1487798273, 1497301488,
+    ];
+    static OFFSETS: [u8; 719] = [
+        0, 32, 95, 33, 0, 112, 0, 7, 0, 45, 1, 1, 1, 2, 1, 2, 1, 1, 56, 6, 10, 11, 1, 1, 46, 21,
+        16, 1, 101, 8, 1, 6, 2, 2, 1, 4, 33, 1, 1, 1, 30, 27, 91, 11, 58, 9, 9, 1, 24, 4, 1, 9, 1,
+        3, 1, 5, 43, 3, 52, 2, 6, 8, 42, 57, 55, 1, 1, 1, 4, 8, 4, 1, 3, 7, 10, 2, 29, 1, 58, 1, 4,
+        4, 8, 1, 20, 2, 26, 1, 2, 2, 57, 1, 4, 2, 4, 2, 2, 3, 3, 1, 30, 2, 3, 1, 11, 2, 57, 1, 4, 5,
+        1, 2, 4, 1, 20, 2, 22, 6, 1, 1, 58, 1, 2, 1, 1, 4, 8, 1, 7, 2, 11, 2, 30, 1, 61, 1, 12, 1,
+        50, 1, 3, 1, 55, 1, 1, 3, 5, 3, 1, 4, 7, 2, 11, 2, 29, 1, 58, 1, 2, 1, 6, 1, 5, 2, 20, 2,
+        28, 2, 57, 2, 4, 4, 8, 1, 20, 2, 29, 1, 72, 1, 7, 3, 1, 1, 90, 1, 2, 7, 12, 8, 98, 1, 2, 9,
+        11, 7, 73, 2, 27, 1, 1, 1, 1, 1, 55, 14, 1, 5, 1, 2, 5, 11, 1, 36, 9, 1, 102, 4, 1, 6, 1, 2,
+        2, 2, 25, 2, 4, 3, 16, 4, 13, 1, 2, 2, 6, 1, 15, 1, 194, 160, 0, 3, 0, 3, 29, 2, 30, 2, 30,
+        2, 64, 2, 1, 7, 8, 1, 2, 11, 9, 1, 45, 5, 117, 2, 34, 1, 118, 3, 4, 2, 9, 1, 6, 3, 219, 2,
+        2, 1, 58, 1, 1, 7, 1, 1, 1, 1, 2, 8, 6, 10, 2, 1, 48, 31, 49, 4, 48, 1, 1, 5, 1, 1, 5, 1,
+        40, 9, 12, 2, 32, 4, 2, 2, 1, 3, 56, 1, 1, 2, 3, 1, 1, 3, 58, 8, 2, 2, 152, 3, 1, 13, 1, 7,
+        4, 1, 6, 1, 3, 2, 198, 64, 0, 5, 26, 5, 49, 5, 1, 10, 96, 33, 0, 3, 141, 1, 96, 32, 0, 4,
+        107, 2, 0, 4, 1, 10, 32, 2, 80, 2, 0, 1, 3, 1, 4, 1, 25, 2, 5, 1, 151, 2, 26, 18, 13, 1, 38,
+        8, 25, 11, 46, 3, 48, 1, 2, 4, 2, 2, 39, 1, 67, 6, 2, 2, 2, 2, 12, 1, 8, 1, 47, 1, 51, 1, 1,
+        3, 2, 2, 5, 2, 1, 1, 42, 2, 8, 1, 238, 1, 2, 1, 4, 1, 0, 1, 0, 16, 16, 16, 207, 1, 249, 3,
+        0, 1, 226, 1, 149, 5, 0, 3, 1, 2, 5, 4, 40, 3, 4, 1, 165, 2, 0, 4, 0, 2, 80, 3, 70, 11, 49,
+        4, 123, 1, 54, 15, 41, 1, 2, 2, 10, 3, 49, 4, 2, 2, 2, 1, 4, 1, 10, 1, 50, 3, 36, 5, 1, 8,
+        62, 1, 12, 2, 52, 9, 10, 4, 2, 1, 95, 3, 2, 1, 1, 2, 6, 1, 2, 1, 157, 1, 3, 8, 21, 2, 57, 2,
+        3, 1, 37, 7, 3, 5, 195, 8, 2, 3, 1, 1, 23, 1, 84, 6, 1, 1, 4, 2, 1, 2, 238, 4, 6, 2, 1, 2,
+        27, 2, 85, 8, 2, 1, 1, 2, 106, 1, 1, 1, 2, 6, 1, 1, 101, 3, 2, 4, 1, 5, 0, 9, 1, 2, 0, 2, 1,
+        1, 4, 1, 144, 4, 2, 2, 4, 1, 32, 10, 40, 6, 2, 4, 8, 1, 9, 6, 2, 3, 46, 13, 1, 2, 0, 7, 1,
+        6, 1, 1, 82, 22, 2, 7, 1, 2, 1, 2, 122, 6, 3, 1, 1, 2, 1, 7, 1, 1, 72, 2, 3, 1, 1, 1, 0, 2,
+        11, 2, 52, 5, 5, 1, 1, 1, 0, 17, 6, 15, 0, 5, 59, 7, 0, 1, 63, 4, 81, 1, 0, 2, 1, 4, 0, 46,
+        2, 23, 0, 3, 9, 16, 2, 7, 30, 4, 148, 3, 0, 55, 4, 50, 8, 1, 14, 1, 22, 5, 1, 15, 0, 7, 1,
+        17, 2, 7, 1, 2, 1, 5, 100, 1, 160, 7, 0, 1, 61, 4, 0, 4, 0, 7, 109, 7, 0, 1, 30, 96, 128,
+        240, 0,
+    ];
+    pub fn lookup(c: char) -> bool {
+        super::skip_search(
+            c as u32,
+            &SHORT_OFFSET_RUNS,
+            &OFFSETS,
+        )
+    }
+}
+
 #[rustfmt::skip]
 pub mod conversions {
     const INDEX_MASK: u32 = 0x400000;
@@ -1385,3 +1665,3471 @@ pub mod conversions {
         ['\u{544}', '\u{53d}', '\u{0}'],
     ];
 }
+
+#[rustfmt::skip]
+pub mod normalization {
+    const LEN_BITS: u32 = 5;
+
+    pub fn combining_class(c: char) -> u8 {
+        COMBINING_CLASS_TABLE
+            .binary_search_by(|&entry| (entry >> 8).cmp(&(c as u32)))
+            .map(|i| COMBINING_CLASS_TABLE[i] as u8)
+            .unwrap_or(0)
+    }
+
+    pub fn canonical_decomposition(c: char) -> Option<&'static [char]> {
+        decomposition(c, CANONICAL_DECOMPOSED, CANONICAL_DECOMPOSED_CHARS)
+    }
+
+    pub fn compatibility_decomposition(c: char) -> Option<&'static [char]> {
+        decomposition(c, COMPATIBILITY_DECOMPOSED, COMPATIBILITY_DECOMPOSED_CHARS)
+    }
+
+    fn decomposition(
+        c: char,
+        table: &[(char, u32)],
+        chars: &'static [char],
+    ) -> Option<&'static [char]> {
+        let i = table.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+        let start = (table[i].1 >> LEN_BITS) as usize;
+        let len = (table[i].1 & ((1 << LEN_BITS) - 1)) as usize;
+        chars.get(start..start + len)
+    }
+
+    pub fn compose_pair(a: char, b: char) -> Option<char> {
+        COMPOSITION_TABLE
+            .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+            .map(|i| COMPOSITION_TABLE[i].2)
+            .ok()
+    }
+
+    static COMBINING_CLASS_TABLE: &[u32] = &[
+        196838, 197094, 197350, 197606, 197862, 198118, 198374, 198630, 198886, 199142, 199398,
+        199654, 199910, 200166, 200422, 200678, 200934, 201190, 201446, 201702, 201958, 202216,
+        202460, 202716, 202972, 203228, 203496, 203736, 203996, 204252, 204508, 204764, 205020,
+        205258, 205514, 205788, 206044, 206300, 206556, 206794, 207050, 207324, 207580, 207836,
+        208092, 208348, 208604, 208860, 209116, 209372, 209628, 209884, 209921, 210177, 210433,
+        210689, 210945, 211420, 211676, 211932, 212188, 212454, 212710, 212966, 213222, 213478,
+        213734, 213990, 214246, 214512, 214758, 215004, 215260, 215516, 215782, 216038, 216294,
+        216540, 216796, 217318, 217574, 217830, 218076, 218332, 218588, 218844, 219110, 219368,
+        219612, 219868, 220134, 220393, 220650, 220906, 221161, 221418, 221674, 221929, 222182,
+        222438, 222694, 222950, 223206, 223462, 223718, 223974, 224230, 224486, 224742, 224998,
+        225254, 295910, 296166, 296422, 296678, 296934, 365020, 365286, 365542, 365798, 366054,
+        366300, 366566, 366822, 367078, 367326, 367580, 367846, 368102, 368358, 368614, 368870,
+        369126, 369372, 369628, 369884, 370140, 370396, 370652, 370918, 371174, 371420, 371686,
+        371942, 372190, 372452, 372710, 372746, 373003, 373260, 373517, 373774, 374031, 374288,
+        374545, 374802, 375059, 375315, 375572, 375829, 376086, 376599, 377112, 377369, 378086,
+        378332, 378642, 397542, 397798, 398054, 398310, 398566, 398822, 399078, 399334, 399390,
+        399647, 399904, 412443, 412700, 412957, 413214, 413471, 413728, 413985, 414242, 414694,
+        414950, 415196, 415452, 415718, 415974, 416230, 416486, 416742, 416988, 417254, 417510,
+        417756, 421923, 448230, 448486, 448742, 448998, 449254, 449510, 449766, 450534, 450790,
+        451046, 451302, 451548, 451814, 452582, 452838, 453340, 453606, 453862, 454108, 463140,
+        471270, 471516, 471782, 472038, 472284, 472550, 472806, 473052, 473308, 473564, 473830,
+        474076, 474332, 474598, 474844, 475110, 475366, 475622, 475868, 476134, 476380, 476646,
+        476892, 477158, 477404, 477670, 477926, 519142, 519398, 519654, 519910, 520166, 520422,
+        520678, 520924, 521190, 523740, 530150, 530406, 530662, 530918, 531430, 531686, 531942,
+        532198, 532454, 532710, 532966, 533222, 533478, 533990, 534246, 534502, 535014, 535270,
+        535526, 535782, 536038, 547292, 547548, 547804, 563430, 563676, 563932, 564188, 564454,
+        564710, 564966, 565222, 576230, 576486, 576742, 576998, 577254, 577500, 577756, 578012,
+        578268, 578524, 578790, 579046, 579302, 579558, 579814, 580070, 580326, 580582, 580838,
+        581094, 581350, 581606, 581862, 582118, 582620, 582886, 583142, 583388, 583654, 583910,
+        584156, 584422, 584678, 584934, 585180, 585436, 585692, 585755, 586012, 586269, 586726,
+        586982, 587238, 587484, 587750, 588006, 588252, 588508, 588774, 589030, 589286, 589542,
+        589798, 605191, 609545, 610790, 611036, 611302, 611558, 637959, 642313, 655078, 670727,
+        675081, 703495, 707849, 736263, 740617, 773385, 801799, 806153, 808276, 808539, 834567,
+        838921, 867081, 867337, 871689, 903689, 931943, 932199, 932361, 936043, 936299, 936555,
+        936811, 964726, 964982, 965129, 968826, 969082, 969338, 969594, 989404, 989660, 996828,
+        997340, 997848, 1012097, 1012354, 1012868, 1014402, 1014658, 1014914, 1015170, 1015938,
+        1016550, 1016806, 1016841, 1017574, 1017830, 1033948, 1062663, 1063177, 1063433, 1084892,
+        1269222, 1269478, 1269734, 1512457, 1512713, 1520649, 1561097, 1564134, 1616356, 1653214,
+        1653478, 1653724, 1710054, 1710300, 1728521, 1734118, 1734374, 1734630, 1734886, 1735142,
+        1735398, 1735654, 1735910, 1736668, 1749222, 1749478, 1749734, 1749990, 1750246, 1750492,
+        1750748, 1751004, 1751260, 1751516, 1751772, 1752038, 1752294, 1752540, 1753052, 1753308,
+        1753574, 1753830, 1754076, 1754332, 1754598, 1754854, 1755110, 1755366, 1755622, 1755868,
+        1756134, 1756390, 1756646, 1756902, 1782791, 1786889, 1797094, 1797340, 1797606, 1797862,
+        1798118, 1798374, 1798630, 1798886, 1799142, 1813001, 1813257, 1828359, 1831433, 1831689,
+        1849095, 1888486, 1888742, 1888998, 1889281, 1889756, 1890012, 1890268, 1890524, 1890780,
+        1891046, 1891302, 1891548, 1891804, 1892060, 1892316, 1892582, 1892865, 1893121, 1893377,
+        1893633, 1893889, 1894145, 1894401, 1895900, 1897702, 1898726, 1898982, 1949926, 1950182,
+        1950428, 1950694, 1950950, 1951206, 1951462, 1951718, 1951974, 1952230, 1952476, 1952742,
+        1952998, 1953258, 1953494, 1953756, 1953994, 1954278, 1954534, 1954790, 1955046, 1955302,
+        1955558, 1955814, 1956070, 1956326, 1956582, 1956838, 1957094, 1957350, 1957606, 1957862,
+        1958118, 1958374, 1958630, 1958886, 1959142, 1959398, 1959654, 1959910, 1960166, 1960422,
+        1960678, 1960934, 1961190, 1961446, 1961702, 1961958, 1962214, 1962470, 1962726, 1962982,
+        1963238, 1963494, 1963752, 1964004, 1964260, 1964508, 1964762, 1965030, 1965289, 1965532,
+        1965798, 1966044, 2150630, 2150886, 2150913, 2151169, 2151654, 2151910, 2152166, 2152422,
+        2152449, 2152705, 2152961, 2153446, 2153702, 2154982, 2155777, 2156033, 2156518, 2156764,
+        2157030, 2157057, 2157313, 2157788, 2158044, 2158300, 2158556, 2158822, 2944998, 2945254,
+        2945510, 2981641, 3006694, 3006950, 3007206, 3007462, 3007718, 3007974, 3008230, 3008486,
+        3008742, 3008998, 3009254, 3009510, 3009766, 3010022, 3010278, 3010534, 3010790, 3011046,
+        3011302, 3011558, 3011814, 3012070, 3012326, 3012582, 3012838, 3013094, 3013350, 3013606,
+        3013862, 3014118, 3014374, 3014630, 3156698, 3156964, 3157224, 3157470, 3157728, 3157984,
+        3184904, 3185160, 10907622, 10908902, 10909158, 10909414, 10909670, 10909926, 10910182,
+        10910438, 10910694, 10910950, 10911206, 10919654, 10919910, 10940646, 10940902, 11011593,
+        11021321, 11060233, 11067622, 11067878, 11068134, 11068390, 11068646, 11068902, 11069158,
+        11069414, 11069670, 11069926, 11070182, 11070438, 11070694, 11070950, 11071206, 11071462,
+        11071718, 11071974, 11086812, 11087068, 11087324, 11096841, 11121415, 11124745, 11186406,
+        11186918, 11187174, 11187420, 11188198, 11188454, 11189990, 11190246, 11190758, 11204105,
+        11267337, 16457242, 16654566, 16654822, 16655078, 16655334, 16655590, 16655846, 16656102,
+        16656348, 16656604, 16656860, 16657116, 16657372, 16657628, 16657884, 16658150, 16658406,
+        16907740, 16965852, 17004262, 17004518, 17004774, 17005030, 17005286, 17436124, 17436646,
+        17447142, 17447169, 17447644, 17448713, 17491430, 17491676, 17638630, 17638886, 17639142,
+        17639398, 17738726, 17738982, 17759708, 17759964, 17760220, 17778396, 17778652, 17778918,
+        17779174, 17779430, 17779676, 17779942, 17780188, 17780444, 17780700, 17780956, 17793766,
+        17794012, 17794278, 17794524, 17843721, 17854473, 17858313, 17873161, 17873415, 17891558,
+        17891814, 17892070, 17904393, 17904649, 17920775, 17940489, 17943047, 17970441, 17970695,
+        18016519, 18016777, 18037511, 18037767, 18042121, 18048742, 18048998, 18049254, 18049510,
+        18049766, 18050022, 18050278, 18051302, 18051558, 18051814, 18052070, 18052326, 18104841,
+        18105863, 18112230, 18137609, 18137863, 18202377, 18202631, 18235145, 18265609, 18265863,
+        18295561, 18364681, 18364935, 18431241, 18431497, 18432775, 18472969, 18494473, 18499337,
+        18520329, 18628361, 18694663, 18695177, 18695433, 18716425, 18825481, 18825737, 23785473,
+        23785729, 23785985, 23786241, 23786497, 23802086, 23802342, 23802598, 23802854, 23803110,
+        23803366, 23803622, 24113158, 24113414, 29138433, 30500312, 30500568, 30500609, 30500865,
+        30501121, 30502370, 30502616, 30502872, 30503128, 30503384, 30503640, 30505948, 30506204,
+        30506460, 30506716, 30506972, 30507228, 30507484, 30507740, 30508518, 30508774, 30509030,
+        30509286, 30509542, 30509788, 30510044, 30517990, 30518246, 30518502, 30518758, 30556902,
+        30557158, 30557414, 31457510, 31457766, 31458022, 31458278, 31458534, 31458790, 31459046,
+        31459558, 31459814, 31460070, 31460326, 31460582, 31460838, 31461094, 31461350, 31461606,
+        31461862, 31462118, 31462374, 31462630, 31462886, 31463142, 31463398, 31463654, 31464422,
+        31464678, 31464934, 31465190, 31465446, 31465702, 31465958, 31466470, 31466726, 31467238,
+        31467494, 31467750, 31468006, 31468262, 31494118, 31535334, 31535590, 31535846, 31536102,
+        31536358, 31536614, 31536870, 31633126, 31648998, 31649254, 31649510, 31649766, 31780072,
+        31780328, 31780572, 31780838, 32035036, 32035292, 32035548, 32035804, 32036060, 32036316,
+        32036572, 32064742, 32064998, 32065254, 32065510, 32065766, 32066022, 32066055,
+    ];
+
+    static CANONICAL_DECOMPOSED: &[(char, u32)] = &[
+        ('\u{c0}', 2), ('\u{c1}', 66), ('\u{c2}', 130), ('\u{c3}', 194), ('\u{c4}', 258),
+        ('\u{c5}', 322), ('\u{c7}', 386), ('\u{c8}', 450), ('\u{c9}', 514), ('\u{ca}', 578),
+        ('\u{cb}', 642), ('\u{cc}', 706), ('\u{cd}', 770), ('\u{ce}', 834), ('\u{cf}', 898),
+        ('\u{d1}', 962), ('\u{d2}', 1026), ('\u{d3}', 1090), ('\u{d4}', 1154), ('\u{d5}', 1218),
+        ('\u{d6}', 1282), ('\u{d9}', 1346), ('\u{da}', 1410), ('\u{db}', 1474), ('\u{dc}', 1538),
+        ('\u{dd}', 1602), ('\u{e0}', 1666), ('\u{e1}', 1730), ('\u{e2}', 1794), ('\u{e3}', 1858),
+        ('\u{e4}', 1922), ('\u{e5}', 1986), ('\u{e7}', 2050), ('\u{e8}', 2114), ('\u{e9}', 2178),
+        ('\u{ea}', 2242), ('\u{eb}', 2306), ('\u{ec}', 2370), ('\u{ed}', 2434), ('\u{ee}', 2498),
+        ('\u{ef}', 2562), ('\u{f1}', 2626), ('\u{f2}', 2690), ('\u{f3}', 2754), ('\u{f4}', 2818),
+        ('\u{f5}', 2882), ('\u{f6}', 2946), ('\u{f9}', 3010), ('\u{fa}', 3074), ('\u{fb}', 3138),
+        ('\u{fc}', 3202), ('\u{fd}', 3266), ('\u{ff}', 3330), ('\u{100}', 3394), ('\u{101}', 3458),
+        ('\u{102}', 3522), ('\u{103}', 3586), ('\u{104}', 3650), ('\u{105}', 3714),
+        ('\u{106}', 3778), ('\u{107}', 3842), ('\u{108}', 3906), ('\u{109}', 3970),
+        ('\u{10a}', 4034), ('\u{10b}', 4098), ('\u{10c}', 4162), ('\u{10d}', 4226),
+        ('\u{10e}', 4290), ('\u{10f}', 4354), ('\u{112}', 4418), ('\u{113}', 4482),
+        ('\u{114}', 4546), ('\u{115}', 4610), ('\u{116}', 4674), ('\u{117}', 4738),
+        ('\u{118}', 4802), ('\u{119}', 4866), ('\u{11a}', 4930), ('\u{11b}', 4994),
+        ('\u{11c}', 5058), ('\u{11d}', 5122), ('\u{11e}', 5186), ('\u{11f}', 5250),
+        ('\u{120}', 5314), ('\u{121}', 5378), ('\u{122}', 5442), ('\u{123}', 5506),
+        ('\u{124}', 5570), ('\u{125}', 5634), ('\u{128}', 5698), ('\u{129}', 5762),
+        ('\u{12a}', 5826), ('\u{12b}', 5890), ('\u{12c}', 5954), ('\u{12d}', 6018),
+        ('\u{12e}', 6082), ('\u{12f}', 6146), ('\u{130}', 6210), ('\u{134}', 6274),
+        ('\u{135}', 6338), ('\u{136}', 6402), ('\u{137}', 6466), ('\u{139}', 6530),
+        ('\u{13a}', 6594), ('\u{13b}', 6658), ('\u{13c}', 6722), ('\u{13d}', 6786),
+        ('\u{13e}', 6850), ('\u{143}', 6914), ('\u{144}', 6978), ('\u{145}', 7042),
+        ('\u{146}', 7106), ('\u{147}', 7170), ('\u{148}', 7234), ('\u{14c}', 7298),
+        ('\u{14d}', 7362), ('\u{14e}', 7426), ('\u{14f}', 7490), ('\u{150}', 7554),
+        ('\u{151}', 7618), ('\u{154}', 7682), ('\u{155}', 7746), ('\u{156}', 7810),
+        ('\u{157}', 7874), ('\u{158}', 7938), ('\u{159}', 8002), ('\u{15a}', 8066),
+        ('\u{15b}', 8130), ('\u{15c}', 8194), ('\u{15d}', 8258), ('\u{15e}', 8322),
+        ('\u{15f}', 8386), ('\u{160}', 8450), ('\u{161}', 8514), ('\u{162}', 8578),
+        ('\u{163}', 8642), ('\u{164}', 8706), ('\u{165}', 8770), ('\u{168}', 8834),
+        ('\u{169}', 8898), ('\u{16a}', 8962), ('\u{16b}', 9026), ('\u{16c}', 9090),
+        ('\u{16d}', 9154), ('\u{16e}', 9218), ('\u{16f}', 9282), ('\u{170}', 9346),
+        ('\u{171}', 9410), ('\u{172}', 9474), ('\u{173}', 9538), ('\u{174}', 9602),
+        ('\u{175}', 9666), ('\u{176}', 9730), ('\u{177}', 9794), ('\u{178}', 9858),
+        ('\u{179}', 9922), ('\u{17a}', 9986), ('\u{17b}', 10050), ('\u{17c}', 10114),
+        ('\u{17d}', 10178), ('\u{17e}', 10242), ('\u{1a0}', 10306), ('\u{1a1}', 10370),
+        ('\u{1af}', 10434), ('\u{1b0}', 10498), ('\u{1cd}', 10562), ('\u{1ce}', 10626),
+        ('\u{1cf}', 10690), ('\u{1d0}', 10754), ('\u{1d1}', 10818), ('\u{1d2}', 10882),
+        ('\u{1d3}', 10946), ('\u{1d4}', 11010), ('\u{1d5}', 11075), ('\u{1d6}', 11171),
+        ('\u{1d7}', 11267), ('\u{1d8}', 11363), ('\u{1d9}', 11459), ('\u{1da}', 11555),
+        ('\u{1db}', 11651), ('\u{1dc}', 11747), ('\u{1de}', 11843), ('\u{1df}', 11939),
+        ('\u{1e0}', 12035), ('\u{1e1}', 12131), ('\u{1e2}', 12226), ('\u{1e3}', 12290),
+        ('\u{1e6}', 12354), ('\u{1e7}', 12418), ('\u{1e8}', 12482), ('\u{1e9}', 12546),
+        ('\u{1ea}', 12610), ('\u{1eb}', 12674), ('\u{1ec}', 12739), ('\u{1ed}', 12835),
+        ('\u{1ee}', 12930), ('\u{1ef}', 12994), ('\u{1f0}', 13058), ('\u{1f4}', 13122),
+        ('\u{1f5}', 13186), ('\u{1f8}', 13250), ('\u{1f9}', 13314), ('\u{1fa}', 13379),
+        ('\u{1fb}', 13475), ('\u{1fc}', 13570), ('\u{1fd}', 13634), ('\u{1fe}', 13698),
+        ('\u{1ff}', 13762), ('\u{200}', 13826), ('\u{201}', 13890), ('\u{202}', 13954),
+        ('\u{203}', 14018), ('\u{204}', 14082), ('\u{205}', 14146), ('\u{206}', 14210),
+        ('\u{207}', 14274), ('\u{208}', 14338), ('\u{209}', 14402), ('\u{20a}', 14466),
+        ('\u{20b}', 14530), ('\u{20c}', 14594), ('\u{20d}', 14658), ('\u{20e}', 14722),
+        ('\u{20f}', 14786), ('\u{210}', 14850), ('\u{211}', 14914), ('\u{212}', 14978),
+        ('\u{213}', 15042), ('\u{214}', 15106), ('\u{215}', 15170), ('\u{216}', 15234),
+        ('\u{217}', 15298), ('\u{218}', 15362), ('\u{219}', 15426), ('\u{21a}', 15490),
+        ('\u{21b}', 15554), ('\u{21e}', 15618), ('\u{21f}', 15682), ('\u{226}', 15746),
+        ('\u{227}', 15810), ('\u{228}', 15874), ('\u{229}', 15938), ('\u{22a}', 16003),
+        ('\u{22b}', 16099), ('\u{22c}', 16195), ('\u{22d}', 16291), ('\u{22e}', 16386),
+        ('\u{22f}', 16450), ('\u{230}', 16515), ('\u{231}', 16611), ('\u{232}', 16706),
+        ('\u{233}', 16770), ('\u{340}', 16833), ('\u{341}', 16865), ('\u{343}', 16897),
+        ('\u{344}', 16930), ('\u{374}', 16993), ('\u{37e}', 17025), ('\u{385}', 17058),
+        ('\u{386}', 17122), ('\u{387}', 17185), ('\u{388}', 17218), ('\u{389}', 17282),
+        ('\u{38a}', 17346), ('\u{38c}', 17410), ('\u{38e}', 17474), ('\u{38f}', 17538),
+        ('\u{390}', 17603), ('\u{3aa}', 17698), ('\u{3ab}', 17762), ('\u{3ac}', 17826),
+        ('\u{3ad}', 17890), ('\u{3ae}', 17954), ('\u{3af}', 18018), ('\u{3b0}', 18083),
+        ('\u{3ca}', 18178), ('\u{3cb}', 18242), ('\u{3cc}', 18306), ('\u{3cd}', 18370),
+        ('\u{3ce}', 18434), ('\u{3d3}', 18498), ('\u{3d4}', 18562), ('\u{400}', 18626),
+        ('\u{401}', 18690), ('\u{403}', 18754), ('\u{407}', 18818), ('\u{40c}', 18882),
+        ('\u{40d}', 18946), ('\u{40e}', 19010), ('\u{419}', 19074), ('\u{439}', 19138),
+        ('\u{450}', 19202), ('\u{451}', 19266), ('\u{453}', 19330), ('\u{457}', 19394),
+        ('\u{45c}', 19458), ('\u{45d}', 19522), ('\u{45e}', 19586), ('\u{476}', 19650),
+        ('\u{477}', 19714), ('\u{4c1}', 19778), ('\u{4c2}', 19842), ('\u{4d0}', 19906),
+        ('\u{4d1}', 19970), ('\u{4d2}', 20034), ('\u{4d3}', 20098), ('\u{4d6}', 20162),
+        ('\u{4d7}', 20226), ('\u{4da}', 20290), ('\u{4db}', 20354), ('\u{4dc}', 20418),
+        ('\u{4dd}', 20482), ('\u{4de}', 20546), ('\u{4df}', 20610), ('\u{4e2}', 20674),
+        ('\u{4e3}', 20738), ('\u{4e4}', 20802), ('\u{4e5}', 20866), ('\u{4e6}', 20930),
+        ('\u{4e7}', 20994), ('\u{4ea}', 21058), ('\u{4eb}', 21122), ('\u{4ec}', 21186),
+        ('\u{4ed}', 21250), ('\u{4ee}', 21314), ('\u{4ef}', 21378), ('\u{4f0}', 21442),
+        ('\u{4f1}', 21506), ('\u{4f2}', 21570), ('\u{4f3}', 21634), ('\u{4f4}', 21698),
+        ('\u{4f5}', 21762), ('\u{4f8}', 21826), ('\u{4f9}', 21890), ('\u{622}', 21954),
+        ('\u{623}', 22018), ('\u{624}', 22082), ('\u{625}', 22146), ('\u{626}', 22210),
+        ('\u{6c0}', 22274), ('\u{6c2}', 22338), ('\u{6d3}', 22402), ('\u{929}', 22466),
+        ('\u{931}', 22530), ('\u{934}', 22594), ('\u{958}', 22658), ('\u{959}', 22722),
+        ('\u{95a}', 22786), ('\u{95b}', 22850), ('\u{95c}', 22914), ('\u{95d}', 22978),
+        ('\u{95e}', 23042), ('\u{95f}', 23106), ('\u{9cb}', 23170), ('\u{9cc}', 23234),
+        ('\u{9dc}', 23298), ('\u{9dd}', 23362), ('\u{9df}', 23426), ('\u{a33}', 23490),
+        ('\u{a36}', 23554), ('\u{a59}', 23618), ('\u{a5a}', 23682), ('\u{a5b}', 23746),
+        ('\u{a5e}', 23810), ('\u{b48}', 23874), ('\u{b4b}', 23938), ('\u{b4c}', 24002),
+        ('\u{b5c}', 24066), ('\u{b5d}', 24130), ('\u{b94}', 24194), ('\u{bca}', 24258),
+        ('\u{bcb}', 24322), ('\u{bcc}', 24386), ('\u{c48}', 24450), ('\u{cc0}', 24514),
+        ('\u{cc7}', 24578), ('\u{cc8}', 24642), ('\u{cca}', 24706), ('\u{ccb}', 24771),
+        ('\u{d4a}', 24866), ('\u{d4b}', 24930), ('\u{d4c}', 24994), ('\u{dda}', 25058),
+        ('\u{ddc}', 25122), ('\u{ddd}', 25187), ('\u{dde}', 25282), ('\u{f43}', 25346),
+        ('\u{f4d}', 25410), ('\u{f52}', 25474), ('\u{f57}', 25538), ('\u{f5c}', 25602),
+        ('\u{f69}', 25666), ('\u{f73}', 25730), ('\u{f75}', 25794), ('\u{f76}', 25858),
+        ('\u{f78}', 25922), ('\u{f81}', 25986), ('\u{f93}', 26050), ('\u{f9d}', 26114),
+        ('\u{fa2}', 26178), ('\u{fa7}', 26242), ('\u{fac}', 26306), ('\u{fb9}', 26370),
+        ('\u{1026}', 26434), ('\u{1b06}', 26498), ('\u{1b08}', 26562), ('\u{1b0a}', 26626),
+        ('\u{1b0c}', 26690), ('\u{1b0e}', 26754), ('\u{1b12}', 26818), ('\u{1b3b}', 26882),
+        ('\u{1b3d}', 26946), ('\u{1b40}', 27010), ('\u{1b41}', 27074), ('\u{1b43}', 27138),
+        ('\u{1e00}', 27202), ('\u{1e01}', 27266), ('\u{1e02}', 27330), ('\u{1e03}', 27394),
+        ('\u{1e04}', 27458), ('\u{1e05}', 27522), ('\u{1e06}', 27586), ('\u{1e07}', 27650),
+        ('\u{1e08}', 27715), ('\u{1e09}', 27811), ('\u{1e0a}', 27906), ('\u{1e0b}', 27970),
+        ('\u{1e0c}', 28034), ('\u{1e0d}', 28098), ('\u{1e0e}', 28162), ('\u{1e0f}', 28226),
+        ('\u{1e10}', 28290), ('\u{1e11}', 28354), ('\u{1e12}', 28418), ('\u{1e13}', 28482),
+        ('\u{1e14}', 28547), ('\u{1e15}', 28643), ('\u{1e16}', 28739), ('\u{1e17}', 28835),
+        ('\u{1e18}', 28930), ('\u{1e19}', 28994), ('\u{1e1a}', 29058), ('\u{1e1b}', 29122),
+        ('\u{1e1c}', 29187), ('\u{1e1d}', 29283), ('\u{1e1e}', 29378), ('\u{1e1f}', 29442),
+        ('\u{1e20}', 29506), ('\u{1e21}', 29570), ('\u{1e22}', 29634), ('\u{1e23}', 29698),
+        ('\u{1e24}', 29762), ('\u{1e25}', 29826), ('\u{1e26}', 29890), ('\u{1e27}', 29954),
+        ('\u{1e28}', 30018), ('\u{1e29}', 30082), ('\u{1e2a}', 30146), ('\u{1e2b}', 30210),
+        ('\u{1e2c}', 30274), ('\u{1e2d}', 30338), ('\u{1e2e}', 30403), ('\u{1e2f}', 30499),
+        ('\u{1e30}', 30594), ('\u{1e31}', 30658), ('\u{1e32}', 30722), ('\u{1e33}', 30786),
+        ('\u{1e34}', 30850), ('\u{1e35}', 30914), ('\u{1e36}', 30978), ('\u{1e37}', 31042),
+        ('\u{1e38}', 31107), ('\u{1e39}', 31203), ('\u{1e3a}', 31298), ('\u{1e3b}', 31362),
+        ('\u{1e3c}', 31426), ('\u{1e3d}', 31490), ('\u{1e3e}', 31554), ('\u{1e3f}', 31618),
+        ('\u{1e40}', 31682), ('\u{1e41}', 31746), ('\u{1e42}', 31810), ('\u{1e43}', 31874),
+        ('\u{1e44}', 31938), ('\u{1e45}', 32002), ('\u{1e46}', 32066), ('\u{1e47}', 32130),
+        ('\u{1e48}', 32194), ('\u{1e49}', 32258), ('\u{1e4a}', 32322), ('\u{1e4b}', 32386),
+        ('\u{1e4c}', 32451), ('\u{1e4d}', 32547), ('\u{1e4e}', 32643), ('\u{1e4f}', 32739),
+        ('\u{1e50}', 32835), ('\u{1e51}', 32931), ('\u{1e52}', 33027), ('\u{1e53}', 33123),
+        ('\u{1e54}', 33218), ('\u{1e55}', 33282), ('\u{1e56}', 33346), ('\u{1e57}', 33410),
+        ('\u{1e58}', 33474), ('\u{1e59}', 33538), ('\u{1e5a}', 33602), ('\u{1e5b}', 33666),
+        ('\u{1e5c}', 33731), ('\u{1e5d}', 33827), ('\u{1e5e}', 33922), ('\u{1e5f}', 33986),
+        ('\u{1e60}', 34050), ('\u{1e61}', 34114), ('\u{1e62}', 34178), ('\u{1e63}', 34242),
+        ('\u{1e64}', 34307), ('\u{1e65}', 34403), ('\u{1e66}', 34499), ('\u{1e67}', 34595),
+        ('\u{1e68}', 34691), ('\u{1e69}', 34787), ('\u{1e6a}', 34882), ('\u{1e6b}', 34946),
+        ('\u{1e6c}', 35010), ('\u{1e6d}', 35074), ('\u{1e6e}', 35138), ('\u{1e6f}', 35202),
+        ('\u{1e70}', 35266), ('\u{1e71}', 35330), ('\u{1e72}', 35394), ('\u{1e73}', 35458),
+        ('\u{1e74}', 35522), ('\u{1e75}', 35586), ('\u{1e76}', 35650), ('\u{1e77}', 35714),
+        ('\u{1e78}', 35779), ('\u{1e79}', 35875), ('\u{1e7a}', 35971), ('\u{1e7b}', 36067),
+        ('\u{1e7c}', 36162), ('\u{1e7d}', 36226), ('\u{1e7e}', 36290), ('\u{1e7f}', 36354),
+        ('\u{1e80}', 36418), ('\u{1e81}', 36482), ('\u{1e82}', 36546), ('\u{1e83}', 36610),
+        ('\u{1e84}', 36674), ('\u{1e85}', 36738), ('\u{1e86}', 36802), ('\u{1e87}', 36866),
+        ('\u{1e88}', 36930), ('\u{1e89}', 36994), ('\u{1e8a}', 37058), ('\u{1e8b}', 37122),
+        ('\u{1e8c}', 37186), ('\u{1e8d}', 37250), ('\u{1e8e}', 37314), ('\u{1e8f}', 37378),
+        ('\u{1e90}', 37442), ('\u{1e91}', 37506), ('\u{1e92}', 37570), ('\u{1e93}', 37634),
+        ('\u{1e94}', 37698), ('\u{1e95}', 37762), ('\u{1e96}', 37826), ('\u{1e97}', 37890),
+        ('\u{1e98}', 37954), ('\u{1e99}', 38018), ('\u{1e9b}', 38082), ('\u{1ea0}', 38146),
+        ('\u{1ea1}', 38210), ('\u{1ea2}', 38274), ('\u{1ea3}', 38338), ('\u{1ea4}', 38403),
+        ('\u{1ea5}', 38499), ('\u{1ea6}', 38595), ('\u{1ea7}', 38691), ('\u{1ea8}', 38787),
+        ('\u{1ea9}', 38883), ('\u{1eaa}', 38979), ('\u{1eab}', 39075), ('\u{1eac}', 39171),
+        ('\u{1ead}', 39267), ('\u{1eae}', 39363), ('\u{1eaf}', 39459), ('\u{1eb0}', 39555),
+        ('\u{1eb1}', 39651), ('\u{1eb2}', 39747), ('\u{1eb3}', 39843), ('\u{1eb4}', 39939),
+        ('\u{1eb5}', 40035), ('\u{1eb6}', 40131), ('\u{1eb7}', 40227), ('\u{1eb8}', 40322),
+        ('\u{1eb9}', 40386), ('\u{1eba}', 40450), ('\u{1ebb}', 40514), ('\u{1ebc}', 40578),
+        ('\u{1ebd}', 40642), ('\u{1ebe}', 40707), ('\u{1ebf}', 40803), ('\u{1ec0}', 40899),
+        ('\u{1ec1}', 40995), ('\u{1ec2}', 41091), ('\u{1ec3}', 41187), ('\u{1ec4}', 41283),
+        ('\u{1ec5}', 41379), ('\u{1ec6}', 41475), ('\u{1ec7}', 41571), ('\u{1ec8}', 41666),
+        ('\u{1ec9}', 41730), ('\u{1eca}', 41794), ('\u{1ecb}', 41858), ('\u{1ecc}', 41922),
+        ('\u{1ecd}', 41986), ('\u{1ece}', 42050), ('\u{1ecf}', 42114), ('\u{1ed0}', 42179),
+        ('\u{1ed1}', 42275), ('\u{1ed2}', 42371), ('\u{1ed3}', 42467), ('\u{1ed4}', 42563),
+        ('\u{1ed5}', 42659), ('\u{1ed6}', 42755), ('\u{1ed7}', 42851), ('\u{1ed8}', 42947),
+        ('\u{1ed9}', 43043), ('\u{1eda}', 43139), ('\u{1edb}', 43235), ('\u{1edc}', 43331),
+        ('\u{1edd}', 43427), ('\u{1ede}', 43523), ('\u{1edf}', 43619), ('\u{1ee0}', 43715),
+        ('\u{1ee1}', 43811), ('\u{1ee2}', 43907), ('\u{1ee3}', 44003), ('\u{1ee4}', 44098),
+        ('\u{1ee5}', 44162), ('\u{1ee6}', 44226), ('\u{1ee7}', 44290), ('\u{1ee8}', 44355),
+        ('\u{1ee9}', 44451), ('\u{1eea}', 44547), ('\u{1eeb}', 44643), ('\u{1eec}', 44739),
+        ('\u{1eed}', 44835), ('\u{1eee}', 44931), ('\u{1eef}', 45027), ('\u{1ef0}', 45123),
+        ('\u{1ef1}', 45219), ('\u{1ef2}', 45314), ('\u{1ef3}', 45378), ('\u{1ef4}', 45442),
+        ('\u{1ef5}', 45506), ('\u{1ef6}', 45570), ('\u{1ef7}', 45634), ('\u{1ef8}', 45698),
+        ('\u{1ef9}', 45762), ('\u{1f00}', 45826), ('\u{1f01}', 45890), ('\u{1f02}', 45955),
+        ('\u{1f03}', 46051), ('\u{1f04}', 46147), ('\u{1f05}', 46243), ('\u{1f06}', 46339),
+        ('\u{1f07}', 46435), ('\u{1f08}', 46530), ('\u{1f09}', 46594), ('\u{1f0a}', 46659),
+        ('\u{1f0b}', 46755), ('\u{1f0c}', 46851), ('\u{1f0d}', 46947), ('\u{1f0e}', 47043),
+        ('\u{1f0f}', 47139), ('\u{1f10}', 47234), ('\u{1f11}', 47298), ('\u{1f12}', 47363),
+        ('\u{1f13}', 47459), ('\u{1f14}', 47555), ('\u{1f15}', 47651), ('\u{1f18}', 47746),
+        ('\u{1f19}', 47810), ('\u{1f1a}', 47875), ('\u{1f1b}', 47971), ('\u{1f1c}', 48067),
+        ('\u{1f1d}', 48163), ('\u{1f20}', 48258), ('\u{1f21}', 48322), ('\u{1f22}', 48387),
+        ('\u{1f23}', 48483), ('\u{1f24}', 48579), ('\u{1f25}', 48675), ('\u{1f26}', 48771),
+        ('\u{1f27}', 48867), ('\u{1f28}', 48962), ('\u{1f29}', 49026), ('\u{1f2a}', 49091),
+        ('\u{1f2b}', 49187), ('\u{1f2c}', 49283), ('\u{1f2d}', 49379), ('\u{1f2e}', 49475),
+        ('\u{1f2f}', 49571), ('\u{1f30}', 49666), ('\u{1f31}', 49730), ('\u{1f32}', 49795),
+        ('\u{1f33}', 49891), ('\u{1f34}', 49987), ('\u{1f35}', 50083), ('\u{1f36}', 50179),
+        ('\u{1f37}', 50275), ('\u{1f38}', 50370), ('\u{1f39}', 50434), ('\u{1f3a}', 50499),
+        ('\u{1f3b}', 50595), ('\u{1f3c}', 50691), ('\u{1f3d}', 50787), ('\u{1f3e}', 50883),
+        ('\u{1f3f}', 50979), ('\u{1f40}', 51074), ('\u{1f41}', 51138), ('\u{1f42}', 51203),
+        ('\u{1f43}', 51299), ('\u{1f44}', 51395), ('\u{1f45}', 51491), ('\u{1f48}', 51586),
+        ('\u{1f49}', 51650), ('\u{1f4a}', 51715), ('\u{1f4b}', 51811), ('\u{1f4c}', 51907),
+        ('\u{1f4d}', 52003), ('\u{1f50}', 52098), ('\u{1f51}', 52162), ('\u{1f52}', 52227),
+        ('\u{1f53}', 52323), ('\u{1f54}', 52419), ('\u{1f55}', 52515), ('\u{1f56}', 52611),
+        ('\u{1f57}', 52707), ('\u{1f59}', 52802), ('\u{1f5b}', 52867), ('\u{1f5d}', 52963),
+        ('\u{1f5f}', 53059), ('\u{1f60}', 53154), ('\u{1f61}', 53218), ('\u{1f62}', 53283),
+        ('\u{1f63}', 53379), ('\u{1f64}', 53475), ('\u{1f65}', 53571), ('\u{1f66}', 53667),
+        ('\u{1f67}', 53763), ('\u{1f68}', 53858), ('\u{1f69}', 53922), ('\u{1f6a}', 53987),
+        ('\u{1f6b}', 54083), ('\u{1f6c}', 54179), ('\u{1f6d}', 54275), ('\u{1f6e}', 54371),
+        ('\u{1f6f}', 54467), ('\u{1f70}', 54562), ('\u{1f71}', 54626), ('\u{1f72}', 54690),
+        ('\u{1f73}', 54754), ('\u{1f74}', 54818), ('\u{1f75}', 54882), ('\u{1f76}', 54946),
+        ('\u{1f77}', 55010), ('\u{1f78}', 55074), ('\u{1f79}', 55138), ('\u{1f7a}', 55202),
+        ('\u{1f7b}', 55266), ('\u{1f7c}', 55330), ('\u{1f7d}', 55394), ('\u{1f80}', 55459),
+        ('\u{1f81}', 55555), ('\u{1f82}', 55652), ('\u{1f83}', 55780), ('\u{1f84}', 55908),
+        ('\u{1f85}', 56036), ('\u{1f86}', 56164), ('\u{1f87}', 56292), ('\u{1f88}', 56419),
+        ('\u{1f89}', 56515), ('\u{1f8a}', 56612), ('\u{1f8b}', 56740), ('\u{1f8c}', 56868),
+        ('\u{1f8d}', 56996), ('\u{1f8e}', 57124), ('\u{1f8f}', 57252), ('\u{1f90}', 57379),
+        ('\u{1f91}', 57475), ('\u{1f92}', 57572), ('\u{1f93}', 57700), ('\u{1f94}', 57828),
+        ('\u{1f95}', 57956), ('\u{1f96}', 58084), ('\u{1f97}', 58212), ('\u{1f98}', 58339),
+        ('\u{1f99}', 58435), ('\u{1f9a}', 58532), ('\u{1f9b}', 58660), ('\u{1f9c}', 58788),
+        ('\u{1f9d}', 58916), ('\u{1f9e}', 59044), ('\u{1f9f}', 59172), ('\u{1fa0}', 59299),
+        ('\u{1fa1}', 59395), ('\u{1fa2}', 59492), ('\u{1fa3}', 59620), ('\u{1fa4}', 59748),
+        ('\u{1fa5}', 59876), ('\u{1fa6}', 60004), ('\u{1fa7}', 60132), ('\u{1fa8}', 60259),
+        ('\u{1fa9}', 60355), ('\u{1faa}', 60452), ('\u{1fab}', 60580), ('\u{1fac}', 60708),
+        ('\u{1fad}', 60836), ('\u{1fae}', 60964), ('\u{1faf}', 61092), ('\u{1fb0}', 61218),
+        ('\u{1fb1}', 61282), ('\u{1fb2}', 61347), ('\u{1fb3}', 61442), ('\u{1fb4}', 61507),
+        ('\u{1fb6}', 61602), ('\u{1fb7}', 61667), ('\u{1fb8}', 61762), ('\u{1fb9}', 61826),
+        ('\u{1fba}', 61890), ('\u{1fbb}', 61954), ('\u{1fbc}', 62018), ('\u{1fbe}', 62081),
+        ('\u{1fc1}', 62114), ('\u{1fc2}', 62179), ('\u{1fc3}', 62274), ('\u{1fc4}', 62339),
+        ('\u{1fc6}', 62434), ('\u{1fc7}', 62499), ('\u{1fc8}', 62594), ('\u{1fc9}', 62658),
+        ('\u{1fca}', 62722), ('\u{1fcb}', 62786), ('\u{1fcc}', 62850), ('\u{1fcd}', 62914),
+        ('\u{1fce}', 62978), ('\u{1fcf}', 63042), ('\u{1fd0}', 63106), ('\u{1fd1}', 63170),
+        ('\u{1fd2}', 63235), ('\u{1fd3}', 63331), ('\u{1fd6}', 63426), ('\u{1fd7}', 63491),
+        ('\u{1fd8}', 63586), ('\u{1fd9}', 63650), ('\u{1fda}', 63714), ('\u{1fdb}', 63778),
+        ('\u{1fdd}', 63842), ('\u{1fde}', 63906), ('\u{1fdf}', 63970), ('\u{1fe0}', 64034),
+        ('\u{1fe1}', 64098), ('\u{1fe2}', 64163), ('\u{1fe3}', 64259), ('\u{1fe4}', 64354),
+        ('\u{1fe5}', 64418), ('\u{1fe6}', 64482), ('\u{1fe7}', 64547), ('\u{1fe8}', 64642),
+        ('\u{1fe9}', 64706), ('\u{1fea}', 64770), ('\u{1feb}', 64834), ('\u{1fec}', 64898),
+        ('\u{1fed}', 64962), ('\u{1fee}', 65026), ('\u{1fef}', 65089), ('\u{1ff2}', 65123),
+        ('\u{1ff3}', 65218), ('\u{1ff4}', 65283), ('\u{1ff6}', 65378), ('\u{1ff7}', 65443),
+        ('\u{1ff8}', 65538), ('\u{1ff9}', 65602), ('\u{1ffa}', 65666), ('\u{1ffb}', 65730),
+        ('\u{1ffc}', 65794), ('\u{1ffd}', 65857), ('\u{2000}', 65889), ('\u{2001}', 65921),
+        ('\u{2126}', 65953), ('\u{212a}', 65985), ('\u{212b}', 66018), ('\u{219a}', 66082),
+        ('\u{219b}', 66146), ('\u{21ae}', 66210), ('\u{21cd}', 66274), ('\u{21ce}', 66338),
+        ('\u{21cf}', 66402), ('\u{2204}', 66466), ('\u{2209}', 66530), ('\u{220c}', 66594),
+        ('\u{2224}', 66658), ('\u{2226}', 66722), ('\u{2241}', 66786), ('\u{2244}', 66850),
+        ('\u{2247}', 66914), ('\u{2249}', 66978), ('\u{2260}', 67042), ('\u{2262}', 67106),
+        ('\u{226d}', 67170), ('\u{226e}', 67234), ('\u{226f}', 67298), ('\u{2270}', 67362),
+        ('\u{2271}', 67426), ('\u{2274}', 67490), ('\u{2275}', 67554), ('\u{2278}', 67618),
+        ('\u{2279}', 67682), ('\u{2280}', 67746), ('\u{2281}', 67810), ('\u{2284}', 67874),
+        ('\u{2285}', 67938), ('\u{2288}', 68002), ('\u{2289}', 68066), ('\u{22ac}', 68130),
+        ('\u{22ad}', 68194), ('\u{22ae}', 68258), ('\u{22af}', 68322), ('\u{22e0}', 68386),
+        ('\u{22e1}', 68450), ('\u{22e2}', 68514), ('\u{22e3}', 68578), ('\u{22ea}', 68642),
+        ('\u{22eb}', 68706), ('\u{22ec}', 68770), ('\u{22ed}', 68834), ('\u{2329}', 68897),
+        ('\u{232a}', 68929), ('\u{2adc}', 68962), ('\u{304c}', 69026), ('\u{304e}', 69090),
+        ('\u{3050}', 69154), ('\u{3052}', 69218), ('\u{3054}', 69282), ('\u{3056}', 69346),
+        ('\u{3058}', 69410), ('\u{305a}', 69474), ('\u{305c}', 69538), ('\u{305e}', 69602),
+        ('\u{3060}', 69666), ('\u{3062}', 69730), ('\u{3065}', 69794), ('\u{3067}', 69858),
+        ('\u{3069}', 69922), ('\u{3070}', 69986), ('\u{3071}', 70050), ('\u{3073}', 70114),
+        ('\u{3074}', 70178), ('\u{3076}', 70242), ('\u{3077}', 70306), ('\u{3079}', 70370),
+        ('\u{307a}', 70434), ('\u{307c}', 70498), ('\u{307d}', 70562), ('\u{3094}', 70626),
+        ('\u{309e}', 70690), ('\u{30ac}', 70754), ('\u{30ae}', 70818), ('\u{30b0}', 70882),
+        ('\u{30b2}', 70946), ('\u{30b4}', 71010), ('\u{30b6}', 71074), ('\u{30b8}', 71138),
+        ('\u{30ba}', 71202), ('\u{30bc}', 71266), ('\u{30be}', 71330), ('\u{30c0}', 71394),
+        ('\u{30c2}', 71458), ('\u{30c5}', 71522), ('\u{30c7}', 71586), ('\u{30c9}', 71650),
+        ('\u{30d0}', 71714), ('\u{30d1}', 71778), ('\u{30d3}', 71842), ('\u{30d4}', 71906),
+        ('\u{30d6}', 71970), ('\u{30d7}', 72034), ('\u{30d9}', 72098), ('\u{30da}', 72162),
+        ('\u{30dc}', 72226), ('\u{30dd}', 72290), ('\u{30f4}', 72354), ('\u{30f7}', 72418),
+        ('\u{30f8}', 72482), ('\u{30f9}', 72546), ('\u{30fa}', 72610), ('\u{30fe}', 72674),
+        ('\u{f900}', 72737), ('\u{f901}', 72769), ('\u{f902}', 72801), ('\u{f903}', 72833),
+        ('\u{f904}', 72865), ('\u{f905}', 72897), ('\u{f906}', 72929), ('\u{f907}', 72961),
+        ('\u{f908}', 72993), ('\u{f909}', 73025), ('\u{f90a}', 73057), ('\u{f90b}', 73089),
+        ('\u{f90c}', 73121), ('\u{f90d}', 73153), ('\u{f90e}', 73185), ('\u{f90f}', 73217),
+        ('\u{f910}', 73249), ('\u{f911}', 73281), ('\u{f912}', 73313), ('\u{f913}', 73345),
+        ('\u{f914}', 73377), ('\u{f915}', 73409), ('\u{f916}', 73441), ('\u{f917}', 73473),
+        ('\u{f918}', 73505), ('\u{f919}', 73537), ('\u{f91a}', 73569), ('\u{f91b}', 73601),
+        ('\u{f91c}', 73633), ('\u{f91d}', 73665), ('\u{f91e}', 73697), ('\u{f91f}', 73729),
+        ('\u{f920}', 73761), ('\u{f921}', 73793), ('\u{f922}', 73825), ('\u{f923}', 73857),
+        ('\u{f924}', 73889), ('\u{f925}', 73921), ('\u{f926}', 73953), ('\u{f927}', 73985),
+        ('\u{f928}', 74017), ('\u{f929}', 74049), ('\u{f92a}', 74081), ('\u{f92b}', 74113),
+        ('\u{f92c}', 74145), ('\u{f92d}', 74177), ('\u{f92e}', 74209), ('\u{f92f}', 74241),
+        ('\u{f930}', 74273), ('\u{f931}', 74305), ('\u{f932}', 74337), ('\u{f933}', 74369),
+        ('\u{f934}', 74401), ('\u{f935}', 74433), ('\u{f936}', 74465), ('\u{f937}', 74497),
+        ('\u{f938}', 74529), ('\u{f939}', 74561), ('\u{f93a}', 74593), ('\u{f93b}', 74625),
+        ('\u{f93c}', 74657), ('\u{f93d}', 74689), ('\u{f93e}', 74721), ('\u{f93f}', 74753),
+        ('\u{f940}', 74785), ('\u{f941}', 74817), ('\u{f942}', 74849), ('\u{f943}', 74881),
+        ('\u{f944}', 74913), ('\u{f945}', 74945), ('\u{f946}', 74977), ('\u{f947}', 75009),
+        ('\u{f948}', 75041), ('\u{f949}', 75073), ('\u{f94a}', 75105), ('\u{f94b}', 75137),
+        ('\u{f94c}', 75169), ('\u{f94d}', 75201), ('\u{f94e}', 75233), ('\u{f94f}', 75265),
+        ('\u{f950}', 75297), ('\u{f951}', 75329), ('\u{f952}', 75361), ('\u{f953}', 75393),
+        ('\u{f954}', 75425), ('\u{f955}', 75457), ('\u{f956}', 75489), ('\u{f957}', 75521),
+        ('\u{f958}', 75553), ('\u{f959}', 75585), ('\u{f95a}', 75617), ('\u{f95b}', 75649),
+        ('\u{f95c}', 75681), ('\u{f95d}', 75713), ('\u{f95e}', 75745), ('\u{f95f}', 75777),
+        ('\u{f960}', 75809), ('\u{f961}', 75841), ('\u{f962}', 75873), ('\u{f963}', 75905),
+        ('\u{f964}', 75937), ('\u{f965}', 75969), ('\u{f966}', 76001), ('\u{f967}', 76033),
+        ('\u{f968}', 76065), ('\u{f969}', 76097), ('\u{f96a}', 76129), ('\u{f96b}', 76161),
+        ('\u{f96c}', 76193), ('\u{f96d}', 76225), ('\u{f96e}', 76257), ('\u{f96f}', 76289),
+        ('\u{f970}', 76321), ('\u{f971}', 76353), ('\u{f972}', 76385), ('\u{f973}', 76417),
+        ('\u{f974}', 76449), ('\u{f975}', 76481), ('\u{f976}', 76513), ('\u{f977}', 76545),
+        ('\u{f978}', 76577), ('\u{f979}', 76609), ('\u{f97a}', 76641), ('\u{f97b}', 76673),
+        ('\u{f97c}', 76705), ('\u{f97d}', 76737), ('\u{f97e}', 76769), ('\u{f97f}', 76801),
+        ('\u{f980}', 76833), ('\u{f981}', 76865), ('\u{f982}', 76897), ('\u{f983}', 76929),
+        ('\u{f984}', 76961), ('\u{f985}', 76993), ('\u{f986}', 77025), ('\u{f987}', 77057),
+        ('\u{f988}', 77089), ('\u{f989}', 77121), ('\u{f98a}', 77153), ('\u{f98b}', 77185),
+        ('\u{f98c}', 77217), ('\u{f98d}', 77249), ('\u{f98e}', 77281), ('\u{f98f}', 77313),
+        ('\u{f990}', 77345), ('\u{f991}', 77377), ('\u{f992}', 77409), ('\u{f993}', 77441),
+        ('\u{f994}', 77473), ('\u{f995}', 77505), ('\u{f996}', 77537), ('\u{f997}', 77569),
+        ('\u{f998}', 77601), ('\u{f999}', 77633), ('\u{f99a}', 77665), ('\u{f99b}', 77697),
+        ('\u{f99c}', 77729), ('\u{f99d}', 77761), ('\u{f99e}', 77793), ('\u{f99f}', 77825),
+        ('\u{f9a0}', 77857), ('\u{f9a1}', 77889), ('\u{f9a2}', 77921), ('\u{f9a3}', 77953),
+        ('\u{f9a4}', 77985), ('\u{f9a5}', 78017), ('\u{f9a6}', 78049), ('\u{f9a7}', 78081),
+        ('\u{f9a8}', 78113), ('\u{f9a9}', 78145), ('\u{f9aa}', 78177), ('\u{f9ab}', 78209),
+        ('\u{f9ac}', 78241), ('\u{f9ad}', 78273), ('\u{f9ae}', 78305), ('\u{f9af}', 78337),
+        ('\u{f9b0}', 78369), ('\u{f9b1}', 78401), ('\u{f9b2}', 78433), ('\u{f9b3}', 78465),
+        ('\u{f9b4}', 78497), ('\u{f9b5}', 78529), ('\u{f9b6}', 78561), ('\u{f9b7}', 78593),
+        ('\u{f9b8}', 78625), ('\u{f9b9}', 78657), ('\u{f9ba}', 78689), ('\u{f9bb}', 78721),
+        ('\u{f9bc}', 78753), ('\u{f9bd}', 78785), ('\u{f9be}', 78817), ('\u{f9bf}', 78849),
+        ('\u{f9c0}', 78881), ('\u{f9c1}', 78913), ('\u{f9c2}', 78945), ('\u{f9c3}', 78977),
+        ('\u{f9c4}', 79009), ('\u{f9c5}', 79041), ('\u{f9c6}', 79073), ('\u{f9c7}', 79105),
+        ('\u{f9c8}', 79137), ('\u{f9c9}', 79169), ('\u{f9ca}', 79201), ('\u{f9cb}', 79233),
+        ('\u{f9cc}', 79265), ('\u{f9cd}', 79297), ('\u{f9ce}', 79329), ('\u{f9cf}', 79361),
+        ('\u{f9d0}', 79393), ('\u{f9d1}', 79425), ('\u{f9d2}', 79457), ('\u{f9d3}', 79489),
+        ('\u{f9d4}', 79521), ('\u{f9d5}', 79553), ('\u{f9d6}', 79585), ('\u{f9d7}', 79617),
+        ('\u{f9d8}', 79649), ('\u{f9d9}', 79681), ('\u{f9da}', 79713), ('\u{f9db}', 79745),
+        ('\u{f9dc}', 79777), ('\u{f9dd}', 79809), ('\u{f9de}', 79841), ('\u{f9df}', 79873),
+        ('\u{f9e0}', 79905), ('\u{f9e1}', 79937), ('\u{f9e2}', 79969), ('\u{f9e3}', 80001),
+        ('\u{f9e4}', 80033), ('\u{f9e5}', 80065), ('\u{f9e6}', 80097), ('\u{f9e7}', 80129),
+        ('\u{f9e8}', 80161), ('\u{f9e9}', 80193), ('\u{f9ea}', 80225), ('\u{f9eb}', 80257),
+        ('\u{f9ec}', 80289), ('\u{f9ed}', 80321), ('\u{f9ee}', 80353), ('\u{f9ef}', 80385),
+        ('\u{f9f0}', 80417), ('\u{f9f1}', 80449), ('\u{f9f2}', 80481), ('\u{f9f3}', 80513),
+        ('\u{f9f4}', 80545), ('\u{f9f5}', 80577), ('\u{f9f6}', 80609), ('\u{f9f7}', 80641),
+        ('\u{f9f8}', 80673), ('\u{f9f9}', 80705), ('\u{f9fa}', 80737), ('\u{f9fb}', 80769),
+        ('\u{f9fc}', 80801), ('\u{f9fd}', 80833), ('\u{f9fe}', 80865), ('\u{f9ff}', 80897),
+        ('\u{fa00}', 80929), ('\u{fa01}', 80961), ('\u{fa02}', 80993), ('\u{fa03}', 81025),
+        ('\u{fa04}', 81057), ('\u{fa05}', 81089), ('\u{fa06}', 81121), ('\u{fa07}', 81153),
+        ('\u{fa08}', 81185), ('\u{fa09}', 81217), ('\u{fa0a}', 81249), ('\u{fa0b}', 81281),
+        ('\u{fa0c}', 81313), ('\u{fa0d}', 81345), ('\u{fa10}', 81377), ('\u{fa12}', 81409),
+        ('\u{fa15}', 81441), ('\u{fa16}', 81473), ('\u{fa17}', 81505), ('\u{fa18}', 81537),
+        ('\u{fa19}', 81569), ('\u{fa1a}', 81601), ('\u{fa1b}', 81633), ('\u{fa1c}', 81665),
+        ('\u{fa1d}', 81697), ('\u{fa1e}', 81729), ('\u{fa20}', 81761), ('\u{fa22}', 81793),
+        ('\u{fa25}', 81825), ('\u{fa26}', 81857), ('\u{fa2a}', 81889), ('\u{fa2b}', 81921),
+        ('\u{fa2c}', 81953), ('\u{fa2d}', 81985), ('\u{fa2e}', 82017), ('\u{fa2f}', 82049),
+        ('\u{fa30}', 82081), ('\u{fa31}', 82113), ('\u{fa32}', 82145), ('\u{fa33}', 82177),
+        ('\u{fa34}', 82209), ('\u{fa35}', 82241), ('\u{fa36}', 82273), ('\u{fa37}', 82305),
+        ('\u{fa38}', 82337), ('\u{fa39}', 82369), ('\u{fa3a}', 82401), ('\u{fa3b}', 82433),
+        ('\u{fa3c}', 82465), ('\u{fa3d}', 82497), ('\u{fa3e}', 82529), ('\u{fa3f}', 82561),
+        ('\u{fa40}', 82593), ('\u{fa41}', 82625), ('\u{fa42}', 82657), ('\u{fa43}', 82689),
+        ('\u{fa44}', 82721), ('\u{fa45}', 82753), ('\u{fa46}', 82785), ('\u{fa47}', 82817),
+        ('\u{fa48}', 82849), ('\u{fa49}', 82881), ('\u{fa4a}', 82913), ('\u{fa4b}', 82945),
+        ('\u{fa4c}', 82977), ('\u{fa4d}', 83009), ('\u{fa4e}', 83041), ('\u{fa4f}', 83073),
+        ('\u{fa50}', 83105), ('\u{fa51}', 83137), ('\u{fa52}', 83169), ('\u{fa53}', 83201),
+        ('\u{fa54}', 83233), ('\u{fa55}', 83265), ('\u{fa56}', 83297), ('\u{fa57}', 83329),
+        ('\u{fa58}', 83361), ('\u{fa59}', 83393), ('\u{fa5a}', 83425), ('\u{fa5b}', 83457),
+        ('\u{fa5c}', 83489), ('\u{fa5d}', 83521), ('\u{fa5e}', 83553), ('\u{fa5f}', 83585),
+        ('\u{fa60}', 83617), ('\u{fa61}', 83649), ('\u{fa62}', 83681), ('\u{fa63}', 83713),
+        ('\u{fa64}', 83745), ('\u{fa65}', 83777), ('\u{fa66}', 83809), ('\u{fa67}', 83841),
+        ('\u{fa68}', 83873), ('\u{fa69}', 83905), ('\u{fa6a}', 83937), ('\u{fa6b}', 83969),
+        ('\u{fa6c}', 84001), ('\u{fa6d}', 84033), ('\u{fa70}', 84065), ('\u{fa71}', 84097),
+        ('\u{fa72}', 84129), ('\u{fa73}', 84161), ('\u{fa74}', 84193), ('\u{fa75}', 84225),
+        ('\u{fa76}', 84257), ('\u{fa77}', 84289), ('\u{fa78}', 84321), ('\u{fa79}', 84353),
+        ('\u{fa7a}', 84385), ('\u{fa7b}', 84417), ('\u{fa7c}', 84449), ('\u{fa7d}', 84481),
+        ('\u{fa7e}', 84513), ('\u{fa7f}', 84545), ('\u{fa80}', 84577), ('\u{fa81}', 84609),
+        ('\u{fa82}', 84641), ('\u{fa83}', 84673), ('\u{fa84}', 84705), ('\u{fa85}', 84737),
+        ('\u{fa86}', 84769), ('\u{fa87}', 84801), ('\u{fa88}', 84833), ('\u{fa89}', 84865),
+        ('\u{fa8a}', 84897), ('\u{fa8b}', 84929), ('\u{fa8c}', 84961), ('\u{fa8d}', 84993),
+        ('\u{fa8e}', 85025), ('\u{fa8f}', 85057), ('\u{fa90}', 85089), ('\u{fa91}', 85121),
+        ('\u{fa92}', 85153), ('\u{fa93}', 85185), ('\u{fa94}', 85217), ('\u{fa95}', 85249),
+        ('\u{fa96}', 85281), ('\u{fa97}', 85313), ('\u{fa98}', 85345), ('\u{fa99}', 85377),
+        ('\u{fa9a}', 85409), ('\u{fa9b}', 85441), ('\u{fa9c}', 85473), ('\u{fa9d}', 85505),
+        ('\u{fa9e}', 85537), ('\u{fa9f}', 85569), ('\u{faa0}', 85601), ('\u{faa1}', 85633),
+        ('\u{faa2}', 85665), ('\u{faa3}', 85697), ('\u{faa4}', 85729), ('\u{faa5}', 85761),
+        ('\u{faa6}', 85793), ('\u{faa7}', 85825), ('\u{faa8}', 85857), ('\u{faa9}', 85889),
+        ('\u{faaa}', 85921), ('\u{faab}', 85953), ('\u{faac}', 85985), ('\u{faad}', 86017),
+        ('\u{faae}', 86049), ('\u{faaf}', 86081), ('\u{fab0}', 86113), ('\u{fab1}', 86145),
+        ('\u{fab2}', 86177), ('\u{fab3}', 86209), ('\u{fab4}', 86241), ('\u{fab5}', 86273),
+        ('\u{fab6}', 86305), ('\u{fab7}', 86337), ('\u{fab8}', 86369), ('\u{fab9}', 86401),
+        ('\u{faba}', 86433), ('\u{fabb}', 86465), ('\u{fabc}', 86497), ('\u{fabd}', 86529),
+        ('\u{fabe}', 86561), ('\u{fabf}', 86593), ('\u{fac0}', 86625), ('\u{fac1}', 86657),
+        ('\u{fac2}', 86689), ('\u{fac3}', 86721), ('\u{fac4}', 86753), ('\u{fac5}', 86785),
+        ('\u{fac6}', 86817), ('\u{fac7}', 86849), ('\u{fac8}', 86881), ('\u{fac9}', 86913),
+        ('\u{faca}', 86945), ('\u{facb}', 86977), ('\u{facc}', 87009), ('\u{facd}', 87041),
+        ('\u{face}', 87073), ('\u{facf}', 87105), ('\u{fad0}', 87137), ('\u{fad1}', 87169),
+        ('\u{fad2}', 87201), ('\u{fad3}', 87233), ('\u{fad4}', 87265), ('\u{fad5}', 87297),
+        ('\u{fad6}', 87329), ('\u{fad7}', 87361), ('\u{fad8}', 87393), ('\u{fad9}', 87425),
+        ('\u{fb1d}', 87458), ('\u{fb1f}', 87522), ('\u{fb2a}', 87586), ('\u{fb2b}', 87650),
+        ('\u{fb2c}', 87715), ('\u{fb2d}', 87811), ('\u{fb2e}', 87906), ('\u{fb2f}', 87970),
+        ('\u{fb30}', 88034), ('\u{fb31}', 88098), ('\u{fb32}', 88162), ('\u{fb33}', 88226),
+        ('\u{fb34}', 88290), ('\u{fb35}', 88354), ('\u{fb36}', 88418), ('\u{fb38}', 88482),
+        ('\u{fb39}', 88546), ('\u{fb3a}', 88610), ('\u{fb3b}', 88674), ('\u{fb3c}', 88738),
+        ('\u{fb3e}', 88802), ('\u{fb40}', 88866), ('\u{fb41}', 88930), ('\u{fb43}', 88994),
+        ('\u{fb44}', 89058), ('\u{fb46}', 89122), ('\u{fb47}', 89186), ('\u{fb48}', 89250),
+        ('\u{fb49}', 89314), ('\u{fb4a}', 89378), ('\u{fb4b}', 89442), ('\u{fb4c}', 89506),
+        ('\u{fb4d}', 89570), ('\u{fb4e}', 89634), ('\u{1109a}', 89698), ('\u{1109c}', 89762),
+        ('\u{110ab}', 89826), ('\u{1112e}', 89890), ('\u{1112f}', 89954), ('\u{1134b}', 90018),
+        ('\u{1134c}', 90082), ('\u{114bb}', 90146), ('\u{114bc}', 90210), ('\u{114be}', 90274),
+        ('\u{115ba}', 90338), ('\u{115bb}', 90402), ('\u{11938}', 90466), ('\u{1d15e}', 90530),
+        ('\u{1d15f}', 90594), ('\u{1d160}', 90659), ('\u{1d161}', 90755), ('\u{1d162}', 90851),
+        ('\u{1d163}', 90947), ('\u{1d164}', 91043), ('\u{1d1bb}', 91138), ('\u{1d1bc}', 91202),
+        ('\u{1d1bd}', 91267), ('\u{1d1be}', 91363), ('\u{1d1bf}', 91459), ('\u{1d1c0}', 91555),
+        ('\u{2f800}', 91649), ('\u{2f801}', 91681), ('\u{2f802}', 91713), ('\u{2f803}', 91745),
+        ('\u{2f804}', 91777), ('\u{2f805}', 91809), ('\u{2f806}', 91841), ('\u{2f807}', 91873),
+        ('\u{2f808}', 91905), ('\u{2f809}', 91937), ('\u{2f80a}', 91969), ('\u{2f80b}', 92001),
+        ('\u{2f80c}', 92033), ('\u{2f80d}', 92065), ('\u{2f80e}', 92097), ('\u{2f80f}', 92129),
+        ('\u{2f810}', 92161), ('\u{2f811}', 92193), ('\u{2f812}', 92225), ('\u{2f813}', 92257),
+        ('\u{2f814}', 92289), ('\u{2f815}', 92321), ('\u{2f816}', 92353), ('\u{2f817}', 92385),
+        ('\u{2f818}', 92417), ('\u{2f819}', 92449), ('\u{2f81a}', 92481), ('\u{2f81b}', 92513),
+        ('\u{2f81c}', 92545), ('\u{2f81d}', 92577), ('\u{2f81e}', 92609), ('\u{2f81f}', 92641),
+        ('\u{2f820}', 92673), ('\u{2f821}', 92705), ('\u{2f822}', 92737), ('\u{2f823}', 92769),
+        ('\u{2f824}', 92801), ('\u{2f825}', 92833), ('\u{2f826}', 92865), ('\u{2f827}', 92897),
+        ('\u{2f828}', 92929), ('\u{2f829}', 92961), ('\u{2f82a}', 92993), ('\u{2f82b}', 93025),
+        ('\u{2f82c}', 93057), ('\u{2f82d}', 93089), ('\u{2f82e}', 93121), ('\u{2f82f}', 93153),
+        ('\u{2f830}', 93185), ('\u{2f831}', 93217), ('\u{2f832}', 93249), ('\u{2f833}', 93281),
+        ('\u{2f834}', 93313), ('\u{2f835}', 93345), ('\u{2f836}', 93377), ('\u{2f837}', 93409),
+        ('\u{2f838}', 93441), ('\u{2f839}', 93473), ('\u{2f83a}', 93505), ('\u{2f83b}', 93537),
+        ('\u{2f83c}', 93569), ('\u{2f83d}', 93601), ('\u{2f83e}', 93633), ('\u{2f83f}', 93665),
+        ('\u{2f840}', 93697), ('\u{2f841}', 93729), ('\u{2f842}', 93761), ('\u{2f843}', 93793),
+        ('\u{2f844}', 93825), ('\u{2f845}', 93857), ('\u{2f846}', 93889), ('\u{2f847}', 93921),
+        ('\u{2f848}', 93953), ('\u{2f849}', 93985), ('\u{2f84a}', 94017), ('\u{2f84b}', 94049),
+        ('\u{2f84c}', 94081), ('\u{2f84d}', 94113), ('\u{2f84e}', 94145), ('\u{2f84f}', 94177),
+        ('\u{2f850}', 94209), ('\u{2f851}', 94241), ('\u{2f852}', 94273), ('\u{2f853}', 94305),
+        ('\u{2f854}', 94337), ('\u{2f855}', 94369), ('\u{2f856}', 94401), ('\u{2f857}', 94433),
+        ('\u{2f858}', 94465), ('\u{2f859}', 94497), ('\u{2f85a}', 94529), ('\u{2f85b}', 94561),
+        ('\u{2f85c}', 94593), ('\u{2f85d}', 94625), ('\u{2f85e}', 94657), ('\u{2f85f}', 94689),
+        ('\u{2f860}', 94721), ('\u{2f861}', 94753), ('\u{2f862}', 94785), ('\u{2f863}', 94817),
+        ('\u{2f864}', 94849), ('\u{2f865}', 94881), ('\u{2f866}', 94913), ('\u{2f867}', 94945),
+        ('\u{2f868}', 94977), ('\u{2f869}', 95009), ('\u{2f86a}', 95041), ('\u{2f86b}', 95073),
+        ('\u{2f86c}', 95105), ('\u{2f86d}', 95137), ('\u{2f86e}', 95169), ('\u{2f86f}', 95201),
+        ('\u{2f870}', 95233), ('\u{2f871}', 95265), ('\u{2f872}', 95297), ('\u{2f873}', 95329),
+        ('\u{2f874}', 95361), ('\u{2f875}', 95393), ('\u{2f876}', 95425), ('\u{2f877}', 95457),
+        ('\u{2f878}', 95489), ('\u{2f879}', 95521), ('\u{2f87a}', 95553), ('\u{2f87b}', 95585),
+        ('\u{2f87c}', 95617), ('\u{2f87d}', 95649), ('\u{2f87e}', 95681), ('\u{2f87f}', 95713),
+        ('\u{2f880}', 95745), ('\u{2f881}', 95777), ('\u{2f882}', 95809), ('\u{2f883}', 95841),
+        ('\u{2f884}', 95873), ('\u{2f885}', 95905), ('\u{2f886}', 95937), ('\u{2f887}', 95969),
+        ('\u{2f888}', 96001), ('\u{2f889}', 96033), ('\u{2f88a}', 96065), ('\u{2f88b}', 96097),
+        ('\u{2f88c}', 96129), ('\u{2f88d}', 96161), ('\u{2f88e}', 96193), ('\u{2f88f}', 96225),
+        ('\u{2f890}', 96257), ('\u{2f891}', 96289), ('\u{2f892}', 96321), ('\u{2f893}', 96353),
+        ('\u{2f894}', 96385), ('\u{2f895}', 96417), ('\u{2f896}', 96449), ('\u{2f897}', 96481),
+        ('\u{2f898}', 96513), ('\u{2f899}', 96545), ('\u{2f89a}', 96577), ('\u{2f89b}', 96609),
+        ('\u{2f89c}', 96641), ('\u{2f89d}', 96673), ('\u{2f89e}', 96705), ('\u{2f89f}', 96737),
+        ('\u{2f8a0}', 96769), ('\u{2f8a1}', 96801), ('\u{2f8a2}', 96833), ('\u{2f8a3}', 96865),
+        ('\u{2f8a4}', 96897), ('\u{2f8a5}', 96929), ('\u{2f8a6}', 96961), ('\u{2f8a7}', 96993),
+        ('\u{2f8a8}', 97025), ('\u{2f8a9}', 97057), ('\u{2f8aa}', 97089), ('\u{2f8ab}', 97121),
+        ('\u{2f8ac}', 97153), ('\u{2f8ad}', 97185), ('\u{2f8ae}', 97217), ('\u{2f8af}', 97249),
+        ('\u{2f8b0}', 97281), ('\u{2f8b1}', 97313), ('\u{2f8b2}', 97345), ('\u{2f8b3}', 97377),
+        ('\u{2f8b4}', 97409), ('\u{2f8b5}', 97441), ('\u{2f8b6}', 97473), ('\u{2f8b7}', 97505),
+        ('\u{2f8b8}', 97537), ('\u{2f8b9}', 97569), ('\u{2f8ba}', 97601), ('\u{2f8bb}', 97633),
+        ('\u{2f8bc}', 97665), ('\u{2f8bd}', 97697), ('\u{2f8be}', 97729), ('\u{2f8bf}', 97761),
+        ('\u{2f8c0}', 97793), ('\u{2f8c1}', 97825), ('\u{2f8c2}', 97857), ('\u{2f8c3}', 97889),
+        ('\u{2f8c4}', 97921), ('\u{2f8c5}', 97953), ('\u{2f8c6}', 97985), ('\u{2f8c7}', 98017),
+        ('\u{2f8c8}', 98049), ('\u{2f8c9}', 98081), ('\u{2f8ca}', 98113), ('\u{2f8cb}', 98145),
+        ('\u{2f8cc}', 98177), ('\u{2f8cd}', 98209), ('\u{2f8ce}', 98241), ('\u{2f8cf}', 98273),
+        ('\u{2f8d0}', 98305), ('\u{2f8d1}', 98337), ('\u{2f8d2}', 98369), ('\u{2f8d3}', 98401),
+        ('\u{2f8d4}', 98433), ('\u{2f8d5}', 98465), ('\u{2f8d6}', 98497), ('\u{2f8d7}', 98529),
+        ('\u{2f8d8}', 98561), ('\u{2f8d9}', 98593), ('\u{2f8da}', 98625), ('\u{2f8db}', 98657),
+        ('\u{2f8dc}', 98689), ('\u{2f8dd}', 98721), ('\u{2f8de}', 98753), ('\u{2f8df}', 98785),
+        ('\u{2f8e0}', 98817), ('\u{2f8e1}', 98849), ('\u{2f8e2}', 98881), ('\u{2f8e3}', 98913),
+        ('\u{2f8e4}', 98945), ('\u{2f8e5}', 98977), ('\u{2f8e6}', 99009), ('\u{2f8e7}', 99041),
+        ('\u{2f8e8}', 99073), ('\u{2f8e9}', 99105), ('\u{2f8ea}', 99137), ('\u{2f8eb}', 99169),
+        ('\u{2f8ec}', 99201), ('\u{2f8ed}', 99233), ('\u{2f8ee}', 99265), ('\u{2f8ef}', 99297),
+        ('\u{2f8f0}', 99329), ('\u{2f8f1}', 99361), ('\u{2f8f2}', 99393), ('\u{2f8f3}', 99425),
+        ('\u{2f8f4}', 99457), ('\u{2f8f5}', 99489), ('\u{2f8f6}', 99521), ('\u{2f8f7}', 99553),
+        ('\u{2f8f8}', 99585), ('\u{2f8f9}', 99617), ('\u{2f8fa}', 99649), ('\u{2f8fb}', 99681),
+        ('\u{2f8fc}', 99713), ('\u{2f8fd}', 99745), ('\u{2f8fe}', 99777), ('\u{2f8ff}', 99809),
+        ('\u{2f900}', 99841), ('\u{2f901}', 99873), ('\u{2f902}', 99905), ('\u{2f903}', 99937),
+        ('\u{2f904}', 99969), ('\u{2f905}', 100001), ('\u{2f906}', 100033), ('\u{2f907}', 100065),
+        ('\u{2f908}', 100097), ('\u{2f909}', 100129), ('\u{2f90a}', 100161), ('\u{2f90b}', 100193),
+        ('\u{2f90c}', 100225), ('\u{2f90d}', 100257), ('\u{2f90e}', 100289), ('\u{2f90f}', 100321),
+        ('\u{2f910}', 100353), ('\u{2f911}', 100385), ('\u{2f912}', 100417), ('\u{2f913}', 100449),
+        ('\u{2f914}', 100481), ('\u{2f915}', 100513), ('\u{2f916}', 100545), ('\u{2f917}', 100577),
+        ('\u{2f918}', 100609), ('\u{2f919}', 100641), ('\u{2f91a}', 100673), ('\u{2f91b}', 100705),
+        ('\u{2f91c}', 100737), ('\u{2f91d}', 100769), ('\u{2f91e}', 100801), ('\u{2f91f}', 100833),
+        ('\u{2f920}', 100865), ('\u{2f921}', 100897), ('\u{2f922}', 100929), ('\u{2f923}', 100961),
+        ('\u{2f924}', 100993), ('\u{2f925}', 101025), ('\u{2f926}', 101057), ('\u{2f927}', 101089),
+        ('\u{2f928}', 101121), ('\u{2f929}', 101153), ('\u{2f92a}', 101185), ('\u{2f92b}', 101217),
+        ('\u{2f92c}', 101249), ('\u{2f92d}', 101281), ('\u{2f92e}', 101313), ('\u{2f92f}', 101345),
+        ('\u{2f930}', 101377), ('\u{2f931}', 101409), ('\u{2f932}', 101441), ('\u{2f933}', 101473),
+        ('\u{2f934}', 101505), ('\u{2f935}', 101537), ('\u{2f936}', 101569), ('\u{2f937}', 101601),
+        ('\u{2f938}', 101633), ('\u{2f939}', 101665), ('\u{2f93a}', 101697), ('\u{2f93b}', 101729),
+        ('\u{2f93c}', 101761), ('\u{2f93d}', 101793), ('\u{2f93e}', 101825), ('\u{2f93f}', 101857),
+        ('\u{2f940}', 101889), ('\u{2f941}', 101921), ('\u{2f942}', 101953), ('\u{2f943}', 101985),
+        ('\u{2f944}', 102017), ('\u{2f945}', 102049), ('\u{2f946}', 102081), ('\u{2f947}', 102113),
+        ('\u{2f948}', 102145), ('\u{2f949}', 102177), ('\u{2f94a}', 102209), ('\u{2f94b}', 102241),
+        ('\u{2f94c}', 102273), ('\u{2f94d}', 102305), ('\u{2f94e}', 102337), ('\u{2f94f}', 102369),
+        ('\u{2f950}', 102401), ('\u{2f951}', 102433), ('\u{2f952}', 102465), ('\u{2f953}', 102497),
+        ('\u{2f954}', 102529), ('\u{2f955}', 102561), ('\u{2f956}', 102593), ('\u{2f957}', 102625),
+        ('\u{2f958}', 102657), ('\u{2f959}', 102689), ('\u{2f95a}', 102721), ('\u{2f95b}', 102753),
+        ('\u{2f95c}', 102785), ('\u{2f95d}', 102817), ('\u{2f95e}', 102849), ('\u{2f95f}', 102881),
+        ('\u{2f960}', 102913), ('\u{2f961}', 102945), ('\u{2f962}', 102977), ('\u{2f963}', 103009),
+        ('\u{2f964}', 103041), ('\u{2f965}', 103073), ('\u{2f966}', 103105), ('\u{2f967}', 103137),
+        ('\u{2f968}', 103169), ('\u{2f969}', 103201), ('\u{2f96a}', 103233), ('\u{2f96b}', 103265),
+        ('\u{2f96c}', 103297), ('\u{2f96d}', 103329), ('\u{2f96e}', 103361), ('\u{2f96f}', 103393),
+        ('\u{2f970}', 103425), ('\u{2f971}', 103457), ('\u{2f972}', 103489), ('\u{2f973}', 103521),
+        ('\u{2f974}', 103553), ('\u{2f975}', 103585), ('\u{2f976}', 103617), ('\u{2f977}', 103649),
+        ('\u{2f978}', 103681), ('\u{2f979}', 103713), ('\u{2f97a}', 103745), ('\u{2f97b}', 103777),
+        ('\u{2f97c}', 103809), ('\u{2f97d}', 103841), ('\u{2f97e}', 103873), ('\u{2f97f}', 103905),
+        ('\u{2f980}', 103937), ('\u{2f981}', 103969), ('\u{2f982}', 104001), ('\u{2f983}', 104033),
+        ('\u{2f984}', 104065), ('\u{2f985}', 104097), ('\u{2f986}', 104129), ('\u{2f987}', 104161),
+        ('\u{2f988}', 104193), ('\u{2f989}', 104225), ('\u{2f98a}', 104257), ('\u{2f98b}', 104289),
+        ('\u{2f98c}', 104321), ('\u{2f98d}', 104353), ('\u{2f98e}', 104385), ('\u{2f98f}', 104417),
+        ('\u{2f990}', 104449), ('\u{2f991}', 104481), ('\u{2f992}', 104513), ('\u{2f993}', 104545),
+        ('\u{2f994}', 104577), ('\u{2f995}', 104609), ('\u{2f996}', 104641), ('\u{2f997}', 104673),
+        ('\u{2f998}', 104705), ('\u{2f999}', 104737), ('\u{2f99a}', 104769), ('\u{2f99b}', 104801),
+        ('\u{2f99c}', 104833), ('\u{2f99d}', 104865), ('\u{2f99e}', 104897), ('\u{2f99f}', 104929),
+        ('\u{2f9a0}', 104961), ('\u{2f9a1}', 104993), ('\u{2f9a2}', 105025), ('\u{2f9a3}', 105057),
+        ('\u{2f9a4}', 105089), ('\u{2f9a5}', 105121), ('\u{2f9a6}', 105153), ('\u{2f9a7}', 105185),
+        ('\u{2f9a8}', 105217), ('\u{2f9a9}', 105249), ('\u{2f9aa}', 105281), ('\u{2f9ab}', 105313),
+        ('\u{2f9ac}', 105345), ('\u{2f9ad}', 105377), ('\u{2f9ae}', 105409), ('\u{2f9af}', 105441),
+        ('\u{2f9b0}', 105473), ('\u{2f9b1}', 105505), ('\u{2f9b2}', 105537), ('\u{2f9b3}', 105569),
+        ('\u{2f9b4}', 105601), ('\u{2f9b5}', 105633), ('\u{2f9b6}', 105665), ('\u{2f9b7}', 105697),
+        ('\u{2f9b8}', 105729), ('\u{2f9b9}', 105761), ('\u{2f9ba}', 105793), ('\u{2f9bb}', 105825),
+        ('\u{2f9bc}', 105857), ('\u{2f9bd}', 105889), ('\u{2f9be}', 105921), ('\u{2f9bf}', 105953),
+        ('\u{2f9c0}', 105985), ('\u{2f9c1}', 106017), ('\u{2f9c2}', 106049), ('\u{2f9c3}', 106081),
+        ('\u{2f9c4}', 106113), ('\u{2f9c5}', 106145), ('\u{2f9c6}', 106177), ('\u{2f9c7}', 106209),
+        ('\u{2f9c8}', 106241), ('\u{2f9c9}', 106273), ('\u{2f9ca}', 106305), ('\u{2f9cb}', 106337),
+        ('\u{2f9cc}', 106369), ('\u{2f9cd}', 106401), ('\u{2f9ce}', 106433), ('\u{2f9cf}', 106465),
+        ('\u{2f9d0}', 106497), ('\u{2f9d1}', 106529), ('\u{2f9d2}', 106561), ('\u{2f9d3}', 106593),
+        ('\u{2f9d4}', 106625), ('\u{2f9d5}', 106657), ('\u{2f9d6}', 106689), ('\u{2f9d7}', 106721),
+        ('\u{2f9d8}', 106753), ('\u{2f9d9}', 106785), ('\u{2f9da}', 106817), ('\u{2f9db}', 106849),
+        ('\u{2f9dc}', 106881), ('\u{2f9dd}', 106913), ('\u{2f9de}', 106945), ('\u{2f9df}', 106977),
+        ('\u{2f9e0}', 107009), ('\u{2f9e1}', 107041), ('\u{2f9e2}', 107073), ('\u{2f9e3}', 107105),
+        ('\u{2f9e4}', 107137), ('\u{2f9e5}', 107169), ('\u{2f9e6}', 107201), ('\u{2f9e7}', 107233),
+        ('\u{2f9e8}', 107265), ('\u{2f9e9}', 107297), ('\u{2f9ea}', 107329), ('\u{2f9eb}', 107361),
+        ('\u{2f9ec}', 107393), ('\u{2f9ed}', 107425), ('\u{2f9ee}', 107457), ('\u{2f9ef}', 107489),
+        ('\u{2f9f0}', 107521), ('\u{2f9f1}', 107553), ('\u{2f9f2}', 107585), ('\u{2f9f3}', 107617),
+        ('\u{2f9f4}', 107649), ('\u{2f9f5}', 107681), ('\u{2f9f6}', 107713), ('\u{2f9f7}', 107745),
+        ('\u{2f9f8}', 107777), ('\u{2f9f9}', 107809), ('\u{2f9fa}', 107841), ('\u{2f9fb}', 107873),
+        ('\u{2f9fc}', 107905), ('\u{2f9fd}', 107937), ('\u{2f9fe}', 107969), ('\u{2f9ff}', 108001),
+        ('\u{2fa00}', 108033), ('\u{2fa01}', 108065), ('\u{2fa02}', 108097), ('\u{2fa03}', 108129),
+        ('\u{2fa04}', 108161), ('\u{2fa05}', 108193), ('\u{2fa06}', 108225), ('\u{2fa07}', 108257),
+        ('\u{2fa08}', 108289), ('\u{2fa09}', 108321), ('\u{2fa0a}', 108353), ('\u{2fa0b}', 108385),
+        ('\u{2fa0c}', 108417), ('\u{2fa0d}', 108449), ('\u{2fa0e}', 108481), ('\u{2fa0f}', 108513),
+        ('\u{2fa10}', 108545), ('\u{2fa11}', 108577), ('\u{2fa12}', 108609), ('\u{2fa13}', 108641),
+        ('\u{2fa14}', 108673), ('\u{2fa15}', 108705), ('\u{2fa16}', 108737), ('\u{2fa17}', 108769),
+        ('\u{2fa18}', 108801), ('\u{2fa19}', 108833), ('\u{2fa1a}', 108865), ('\u{2fa1b}', 108897),
+        ('\u{2fa1c}', 108929), ('\u{2fa1d}', 108961),
+    ];
+
+    static CANONICAL_DECOMPOSED_CHARS: &[char] = &[
+        'A', '\u{300}', 'A', '\u{301}', 'A', '\u{302}', 'A', '\u{303}', 'A', '\u{308}', 'A',
+        '\u{30a}', 'C', '\u{327}', 'E', '\u{300}', 'E', '\u{301}', 'E', '\u{302}', 'E', '\u{308}',
+        'I', '\u{300}', 'I', '\u{301}', 'I', '\u{302}', 'I', '\u{308}', 'N', '\u{303}', 'O',
+        '\u{300}', 'O', '\u{301}', 'O', '\u{302}', 'O', '\u{303}', 'O', '\u{308}', 'U', '\u{300}',
+        'U', '\u{301}', 'U', '\u{302}', 'U', '\u{308}', 'Y', '\u{301}', 'a', '\u{300}', 'a',
+        '\u{301}', 'a', '\u{302}', 'a', '\u{303}', 'a', '\u{308}', 'a', '\u{30a}', 'c', '\u{327}',
+        'e', '\u{300}', 'e', '\u{301}', 'e', '\u{302}', 'e', '\u{308}', 'i', '\u{300}', 'i',
+        '\u{301}', 'i', '\u{302}', 'i', '\u{308}', 'n', '\u{303}', 'o', '\u{300}', 'o', '\u{301}',
+        'o', '\u{302}', 'o', '\u{303}', 'o', '\u{308}', 'u', '\u{300}', 'u', '\u{301}', 'u',
+        '\u{302}', 'u', '\u{308}', 'y', '\u{301}', 'y', '\u{308}', 'A', '\u{304}', 'a', '\u{304}',
+        'A', '\u{306}', 'a', '\u{306}', 'A', '\u{328}', 'a', '\u{328}', 'C', '\u{301}', 'c',
+        '\u{301}', 'C', '\u{302}', 'c', '\u{302}', 'C', '\u{307}', 'c', '\u{307}', 'C', '\u{30c}',
+        'c', '\u{30c}', 'D', '\u{30c}', 'd', '\u{30c}', 'E', '\u{304}', 'e', '\u{304}', 'E',
+        '\u{306}', 'e', '\u{306}', 'E', '\u{307}', 'e', '\u{307}', 'E', '\u{328}', 'e', '\u{328}',
+        'E', '\u{30c}', 'e', '\u{30c}', 'G', '\u{302}', 'g', '\u{302}', 'G', '\u{306}', 'g',
+        '\u{306}', 'G', '\u{307}', 'g', '\u{307}', 'G', '\u{327}', 'g', '\u{327}', 'H', '\u{302}',
+        'h', '\u{302}', 'I', '\u{303}', 'i', '\u{303}', 'I', '\u{304}', 'i', '\u{304}', 'I',
+        '\u{306}', 'i', '\u{306}', 'I', '\u{328}', 'i', '\u{328}', 'I', '\u{307}', 'J', '\u{302}',
+        'j', '\u{302}', 'K', '\u{327}', 'k', '\u{327}', 'L', '\u{301}', 'l', '\u{301}', 'L',
+        '\u{327}', 'l', '\u{327}', 'L', '\u{30c}', 'l', '\u{30c}', 'N', '\u{301}', 'n', '\u{301}',
+        'N', '\u{327}', 'n', '\u{327}', 'N', '\u{30c}', 'n', '\u{30c}', 'O', '\u{304}', 'o',
+        '\u{304}', 'O', '\u{306}', 'o', '\u{306}', 'O', '\u{30b}', 'o', '\u{30b}', 'R', '\u{301}',
+        'r', '\u{301}', 'R', '\u{327}', 'r', '\u{327}', 'R', '\u{30c}', 'r', '\u{30c}', 'S',
+        '\u{301}', 's', '\u{301}', 'S', '\u{302}', 's', '\u{302}', 'S', '\u{327}', 's', '\u{327}',
+        'S', '\u{30c}', 's', '\u{30c}', 'T', '\u{327}', 't', '\u{327}', 'T', '\u{30c}', 't',
+        '\u{30c}', 'U', '\u{303}', 'u', '\u{303}', 'U', '\u{304}', 'u', '\u{304}', 'U', '\u{306}',
+        'u', '\u{306}', 'U', '\u{30a}', 'u', '\u{30a}', 'U', '\u{30b}', 'u', '\u{30b}', 'U',
+        '\u{328}', 'u', '\u{328}', 'W', '\u{302}', 'w', '\u{302}', 'Y', '\u{302}', 'y', '\u{302}',
+        'Y', '\u{308}', 'Z', '\u{301}', 'z', '\u{301}', 'Z', '\u{307}', 'z', '\u{307}', 'Z',
+        '\u{30c}', 'z', '\u{30c}', 'O', '\u{31b}', 'o', '\u{31b}', 'U', '\u{31b}', 'u', '\u{31b}',
+        'A', '\u{30c}', 'a', '\u{30c}', 'I', '\u{30c}', 'i', '\u{30c}', 'O', '\u{30c}', 'o',
+        '\u{30c}', 'U', '\u{30c}', 'u', '\u{30c}', 'U', '\u{308}', '\u{304}', 'u', '\u{308}',
+        '\u{304}', 'U', '\u{308}', '\u{301}', 'u', '\u{308}', '\u{301}', 'U', '\u{308}', '\u{30c}',
+        'u', '\u{308}', '\u{30c}', 'U', '\u{308}', '\u{300}', 'u', '\u{308}', '\u{300}', 'A',
+        '\u{308}', '\u{304}', 'a', '\u{308}', '\u{304}', 'A', '\u{307}', '\u{304}', 'a', '\u{307}',
+        '\u{304}', '\u{c6}', '\u{304}', '\u{e6}', '\u{304}', 'G', '\u{30c}', 'g', '\u{30c}', 'K',
+        '\u{30c}', 'k', '\u{30c}', 'O', '\u{328}', 'o', '\u{328}', 'O', '\u{328}', '\u{304}', 'o',
+        '\u{328}', '\u{304}', '\u{1b7}', '\u{30c}', '\u{292}', '\u{30c}', 'j', '\u{30c}', 'G',
+        '\u{301}', 'g', '\u{301}', 'N', '\u{300}', 'n', '\u{300}', 'A', '\u{30a}', '\u{301}', 'a',
+        '\u{30a}', '\u{301}', '\u{c6}', '\u{301}', '\u{e6}', '\u{301}', '\u{d8}', '\u{301}',
+        '\u{f8}', '\u{301}', 'A', '\u{30f}', 'a', '\u{30f}', 'A', '\u{311}', 'a', '\u{311}', 'E',
+        '\u{30f}', 'e', '\u{30f}', 'E', '\u{311}', 'e', '\u{311}', 'I', '\u{30f}', 'i', '\u{30f}',
+        'I', '\u{311}', 'i', '\u{311}', 'O', '\u{30f}', 'o', '\u{30f}', 'O', '\u{311}', 'o',
+        '\u{311}', 'R', '\u{30f}', 'r', '\u{30f}', 'R', '\u{311}', 'r', '\u{311}', 'U', '\u{30f}',
+        'u', '\u{30f}', 'U', '\u{311}', 'u', '\u{311}', 'S', '\u{326}', 's', '\u{326}', 'T',
+        '\u{326}', 't', '\u{326}', 'H', '\u{30c}', 'h', '\u{30c}', 'A', '\u{307}', 'a', '\u{307}',
+        'E', '\u{327}', 'e', '\u{327}', 'O', '\u{308}', '\u{304}', 'o', '\u{308}', '\u{304}', 'O',
+        '\u{303}', '\u{304}', 'o', '\u{303}', '\u{304}', 'O', '\u{307}', 'o', '\u{307}', 'O',
+        '\u{307}', '\u{304}', 'o', '\u{307}', '\u{304}', 'Y', '\u{304}', 'y', '\u{304}', '\u{300}',
+        '\u{301}', '\u{313}', '\u{308}', '\u{301}', '\u{2b9}', ';', '\u{a8}', '\u{301}', '\u{391}',
+        '\u{301}', '\u{b7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}',
+        '\u{39f}', '\u{301}', '\u{3a5}', '\u{301}', '\u{3a9}', '\u{301}', '\u{3b9}', '\u{308}',
+        '\u{301}', '\u{399}', '\u{308}', '\u{3a5}', '\u{308}', '\u{3b1}', '\u{301}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{301}', '\u{3c5}', '\u{308}', '\u{301}',
+        '\u{3b9}', '\u{308}', '\u{3c5}', '\u{308}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{301}',
+        '\u{3c9}', '\u{301}', '\u{3d2}', '\u{301}', '\u{3d2}', '\u{308}', '\u{415}', '\u{300}',
+        '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41a}', '\u{301}',
+        '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+        '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}',
+        '\u{43a}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30f}',
+        '\u{475}', '\u{30f}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}',
+        '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}',
+        '\u{435}', '\u{306}', '\u{4d8}', '\u{308}', '\u{4d9}', '\u{308}', '\u{416}', '\u{308}',
+        '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}',
+        '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41e}', '\u{308}',
+        '\u{43e}', '\u{308}', '\u{4e8}', '\u{308}', '\u{4e9}', '\u{308}', '\u{42d}', '\u{308}',
+        '\u{44d}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}',
+        '\u{443}', '\u{308}', '\u{423}', '\u{30b}', '\u{443}', '\u{30b}', '\u{427}', '\u{308}',
+        '\u{447}', '\u{308}', '\u{42b}', '\u{308}', '\u{44b}', '\u{308}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{6d5}', '\u{654}', '\u{6c1}', '\u{654}', '\u{6d2}', '\u{654}', '\u{928}', '\u{93c}',
+        '\u{930}', '\u{93c}', '\u{933}', '\u{93c}', '\u{915}', '\u{93c}', '\u{916}', '\u{93c}',
+        '\u{917}', '\u{93c}', '\u{91c}', '\u{93c}', '\u{921}', '\u{93c}', '\u{922}', '\u{93c}',
+        '\u{92b}', '\u{93c}', '\u{92f}', '\u{93c}', '\u{9c7}', '\u{9be}', '\u{9c7}', '\u{9d7}',
+        '\u{9a1}', '\u{9bc}', '\u{9a2}', '\u{9bc}', '\u{9af}', '\u{9bc}', '\u{a32}', '\u{a3c}',
+        '\u{a38}', '\u{a3c}', '\u{a16}', '\u{a3c}', '\u{a17}', '\u{a3c}', '\u{a1c}', '\u{a3c}',
+        '\u{a2b}', '\u{a3c}', '\u{b47}', '\u{b56}', '\u{b47}', '\u{b3e}', '\u{b47}', '\u{b57}',
+        '\u{b21}', '\u{b3c}', '\u{b22}', '\u{b3c}', '\u{b92}', '\u{bd7}', '\u{bc6}', '\u{bbe}',
+        '\u{bc7}', '\u{bbe}', '\u{bc6}', '\u{bd7}', '\u{c46}', '\u{c56}', '\u{cbf}', '\u{cd5}',
+        '\u{cc6}', '\u{cd5}', '\u{cc6}', '\u{cd6}', '\u{cc6}', '\u{cc2}', '\u{cc6}', '\u{cc2}',
+        '\u{cd5}', '\u{d46}', '\u{d3e}', '\u{d47}', '\u{d3e}', '\u{d46}', '\u{d57}', '\u{dd9}',
+        '\u{dca}', '\u{dd9}', '\u{dcf}', '\u{dd9}', '\u{dcf}', '\u{dca}', '\u{dd9}', '\u{ddf}',
+        '\u{f42}', '\u{fb7}', '\u{f4c}', '\u{fb7}', '\u{f51}', '\u{fb7}', '\u{f56}', '\u{fb7}',
+        '\u{f5b}', '\u{fb7}', '\u{f40}', '\u{fb5}', '\u{f71}', '\u{f72}', '\u{f71}', '\u{f74}',
+        '\u{fb2}', '\u{f80}', '\u{fb3}', '\u{f80}', '\u{f71}', '\u{f80}', '\u{f92}', '\u{fb7}',
+        '\u{f9c}', '\u{fb7}', '\u{fa1}', '\u{fb7}', '\u{fa6}', '\u{fb7}', '\u{fab}', '\u{fb7}',
+        '\u{f90}', '\u{fb5}', '\u{1025}', '\u{102e}', '\u{1b05}', '\u{1b35}', '\u{1b07}',
+        '\u{1b35}', '\u{1b09}', '\u{1b35}', '\u{1b0b}', '\u{1b35}', '\u{1b0d}', '\u{1b35}',
+        '\u{1b11}', '\u{1b35}', '\u{1b3a}', '\u{1b35}', '\u{1b3c}', '\u{1b35}', '\u{1b3e}',
+        '\u{1b35}', '\u{1b3f}', '\u{1b35}', '\u{1b42}', '\u{1b35}', 'A', '\u{325}', 'a', '\u{325}',
+        'B', '\u{307}', 'b', '\u{307}', 'B', '\u{323}', 'b', '\u{323}', 'B', '\u{331}', 'b',
+        '\u{331}', 'C', '\u{327}', '\u{301}', 'c', '\u{327}', '\u{301}', 'D', '\u{307}', 'd',
+        '\u{307}', 'D', '\u{323}', 'd', '\u{323}', 'D', '\u{331}', 'd', '\u{331}', 'D', '\u{327}',
+        'd', '\u{327}', 'D', '\u{32d}', 'd', '\u{32d}', 'E', '\u{304}', '\u{300}', 'e', '\u{304}',
+        '\u{300}', 'E', '\u{304}', '\u{301}', 'e', '\u{304}', '\u{301}', 'E', '\u{32d}', 'e',
+        '\u{32d}', 'E', '\u{330}', 'e', '\u{330}', 'E', '\u{327}', '\u{306}', 'e', '\u{327}',
+        '\u{306}', 'F', '\u{307}', 'f', '\u{307}', 'G', '\u{304}', 'g', '\u{304}', 'H', '\u{307}',
+        'h', '\u{307}', 'H', '\u{323}', 'h', '\u{323}', 'H', '\u{308}', 'h', '\u{308}', 'H',
+        '\u{327}', 'h', '\u{327}', 'H', '\u{32e}', 'h', '\u{32e}', 'I', '\u{330}', 'i', '\u{330}',
+        'I', '\u{308}', '\u{301}', 'i', '\u{308}', '\u{301}', 'K', '\u{301}', 'k', '\u{301}', 'K',
+        '\u{323}', 'k', '\u{323}', 'K', '\u{331}', 'k', '\u{331}', 'L', '\u{323}', 'l', '\u{323}',
+        'L', '\u{323}', '\u{304}', 'l', '\u{323}', '\u{304}', 'L', '\u{331}', 'l', '\u{331}', 'L',
+        '\u{32d}', 'l', '\u{32d}', 'M', '\u{301}', 'm', '\u{301}', 'M', '\u{307}', 'm', '\u{307}',
+        'M', '\u{323}', 'm', '\u{323}', 'N', '\u{307}', 'n', '\u{307}', 'N', '\u{323}', 'n',
+        '\u{323}', 'N', '\u{331}', 'n', '\u{331}', 'N', '\u{32d}', 'n', '\u{32d}', 'O', '\u{303}',
+        '\u{301}', 'o', '\u{303}', '\u{301}', 'O', '\u{303}', '\u{308}', 'o', '\u{303}', '\u{308}',
+        'O', '\u{304}', '\u{300}', 'o', '\u{304}', '\u{300}', 'O', '\u{304}', '\u{301}', 'o',
+        '\u{304}', '\u{301}', 'P', '\u{301}', 'p', '\u{301}', 'P', '\u{307}', 'p', '\u{307}', 'R',
+        '\u{307}', 'r', '\u{307}', 'R', '\u{323}', 'r', '\u{323}', 'R', '\u{323}', '\u{304}', 'r',
+        '\u{323}', '\u{304}', 'R', '\u{331}', 'r', '\u{331}', 'S', '\u{307}', 's', '\u{307}', 'S',
+        '\u{323}', 's', '\u{323}', 'S', '\u{301}', '\u{307}', 's', '\u{301}', '\u{307}', 'S',
+        '\u{30c}', '\u{307}', 's', '\u{30c}', '\u{307}', 'S', '\u{323}', '\u{307}', 's', '\u{323}',
+        '\u{307}', 'T', '\u{307}', 't', '\u{307}', 'T', '\u{323}', 't', '\u{323}', 'T', '\u{331}',
+        't', '\u{331}', 'T', '\u{32d}', 't', '\u{32d}', 'U', '\u{324}', 'u', '\u{324}', 'U',
+        '\u{330}', 'u', '\u{330}', 'U', '\u{32d}', 'u', '\u{32d}', 'U', '\u{303}', '\u{301}', 'u',
+        '\u{303}', '\u{301}', 'U', '\u{304}', '\u{308}', 'u', '\u{304}', '\u{308}', 'V', '\u{303}',
+        'v', '\u{303}', 'V', '\u{323}', 'v', '\u{323}', 'W', '\u{300}', 'w', '\u{300}', 'W',
+        '\u{301}', 'w', '\u{301}', 'W', '\u{308}', 'w', '\u{308}', 'W', '\u{307}', 'w', '\u{307}',
+        'W', '\u{323}', 'w', '\u{323}', 'X', '\u{307}', 'x', '\u{307}', 'X', '\u{308}', 'x',
+        '\u{308}', 'Y', '\u{307}', 'y', '\u{307}', 'Z', '\u{302}', 'z', '\u{302}', 'Z', '\u{323}',
+        'z', '\u{323}', 'Z', '\u{331}', 'z', '\u{331}', 'h', '\u{331}', 't', '\u{308}', 'w',
+        '\u{30a}', 'y', '\u{30a}', '\u{17f}', '\u{307}', 'A', '\u{323}', 'a', '\u{323}', 'A',
+        '\u{309}', 'a', '\u{309}', 'A', '\u{302}', '\u{301}', 'a', '\u{302}', '\u{301}', 'A',
+        '\u{302}', '\u{300}', 'a', '\u{302}', '\u{300}', 'A', '\u{302}', '\u{309}', 'a', '\u{302}',
+        '\u{309}', 'A', '\u{302}', '\u{303}', 'a', '\u{302}', '\u{303}', 'A', '\u{323}', '\u{302}',
+        'a', '\u{323}', '\u{302}', 'A', '\u{306}', '\u{301}', 'a', '\u{306}', '\u{301}', 'A',
+        '\u{306}', '\u{300}', 'a', '\u{306}', '\u{300}', 'A', '\u{306}', '\u{309}', 'a', '\u{306}',
+        '\u{309}', 'A', '\u{306}', '\u{303}', 'a', '\u{306}', '\u{303}', 'A', '\u{323}', '\u{306}',
+        'a', '\u{323}', '\u{306}', 'E', '\u{323}', 'e', '\u{323}', 'E', '\u{309}', 'e', '\u{309}',
+        'E', '\u{303}', 'e', '\u{303}', 'E', '\u{302}', '\u{301}', 'e', '\u{302}', '\u{301}', 'E',
+        '\u{302}', '\u{300}', 'e', '\u{302}', '\u{300}', 'E', '\u{302}', '\u{309}', 'e', '\u{302}',
+        '\u{309}', 'E', '\u{302}', '\u{303}', 'e', '\u{302}', '\u{303}', 'E', '\u{323}', '\u{302}',
+        'e', '\u{323}', '\u{302}', 'I', '\u{309}', 'i', '\u{309}', 'I', '\u{323}', 'i', '\u{323}',
+        'O', '\u{323}', 'o', '\u{323}', 'O', '\u{309}', 'o', '\u{309}', 'O', '\u{302}', '\u{301}',
+        'o', '\u{302}', '\u{301}', 'O', '\u{302}', '\u{300}', 'o', '\u{302}', '\u{300}', 'O',
+        '\u{302}', '\u{309}', 'o', '\u{302}', '\u{309}', 'O', '\u{302}', '\u{303}', 'o', '\u{302}',
+        '\u{303}', 'O', '\u{323}', '\u{302}', 'o', '\u{323}', '\u{302}', 'O', '\u{31b}', '\u{301}',
+        'o', '\u{31b}', '\u{301}', 'O', '\u{31b}', '\u{300}', 'o', '\u{31b}', '\u{300}', 'O',
+        '\u{31b}', '\u{309}', 'o', '\u{31b}', '\u{309}', 'O', '\u{31b}', '\u{303}', 'o', '\u{31b}',
+        '\u{303}', 'O', '\u{31b}', '\u{323}', 'o', '\u{31b}', '\u{323}', 'U', '\u{323}', 'u',
+        '\u{323}', 'U', '\u{309}', 'u', '\u{309}', 'U', '\u{31b}', '\u{301}', 'u', '\u{31b}',
+        '\u{301}', 'U', '\u{31b}', '\u{300}', 'u', '\u{31b}', '\u{300}', 'U', '\u{31b}', '\u{309}',
+        'u', '\u{31b}', '\u{309}', 'U', '\u{31b}', '\u{303}', 'u', '\u{31b}', '\u{303}', 'U',
+        '\u{31b}', '\u{323}', 'u', '\u{31b}', '\u{323}', 'Y', '\u{300}', 'y', '\u{300}', 'Y',
+        '\u{323}', 'y', '\u{323}', 'Y', '\u{309}', 'y', '\u{309}', 'Y', '\u{303}', 'y', '\u{303}',
+        '\u{3b1}', '\u{313}', '\u{3b1}', '\u{314}', '\u{3b1}', '\u{313}', '\u{300}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{3b1}', '\u{313}', '\u{301}', '\u{3b1}', '\u{314}', '\u{301}',
+        '\u{3b1}', '\u{313}', '\u{342}', '\u{3b1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}',
+        '\u{391}', '\u{314}', '\u{391}', '\u{313}', '\u{300}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}', '\u{391}', '\u{313}',
+        '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3b5}', '\u{313}', '\u{3b5}', '\u{314}',
+        '\u{3b5}', '\u{313}', '\u{300}', '\u{3b5}', '\u{314}', '\u{300}', '\u{3b5}', '\u{313}',
+        '\u{301}', '\u{3b5}', '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}',
+        '\u{395}', '\u{313}', '\u{300}', '\u{395}', '\u{314}', '\u{300}', '\u{395}', '\u{313}',
+        '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{3b7}', '\u{314}',
+        '\u{3b7}', '\u{313}', '\u{300}', '\u{3b7}', '\u{314}', '\u{300}', '\u{3b7}', '\u{313}',
+        '\u{301}', '\u{3b7}', '\u{314}', '\u{301}', '\u{3b7}', '\u{313}', '\u{342}', '\u{3b7}',
+        '\u{314}', '\u{342}', '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}',
+        '\u{300}', '\u{397}', '\u{314}', '\u{300}', '\u{397}', '\u{313}', '\u{301}', '\u{397}',
+        '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}', '\u{314}', '\u{342}',
+        '\u{3b9}', '\u{313}', '\u{3b9}', '\u{314}', '\u{3b9}', '\u{313}', '\u{300}', '\u{3b9}',
+        '\u{314}', '\u{300}', '\u{3b9}', '\u{313}', '\u{301}', '\u{3b9}', '\u{314}', '\u{301}',
+        '\u{3b9}', '\u{313}', '\u{342}', '\u{3b9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}',
+        '\u{399}', '\u{314}', '\u{399}', '\u{313}', '\u{300}', '\u{399}', '\u{314}', '\u{300}',
+        '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}', '\u{399}', '\u{313}',
+        '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3bf}', '\u{313}', '\u{3bf}', '\u{314}',
+        '\u{3bf}', '\u{313}', '\u{300}', '\u{3bf}', '\u{314}', '\u{300}', '\u{3bf}', '\u{313}',
+        '\u{301}', '\u{3bf}', '\u{314}', '\u{301}', '\u{39f}', '\u{313}', '\u{39f}', '\u{314}',
+        '\u{39f}', '\u{313}', '\u{300}', '\u{39f}', '\u{314}', '\u{300}', '\u{39f}', '\u{313}',
+        '\u{301}', '\u{39f}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{3c5}', '\u{314}',
+        '\u{3c5}', '\u{313}', '\u{300}', '\u{3c5}', '\u{314}', '\u{300}', '\u{3c5}', '\u{313}',
+        '\u{301}', '\u{3c5}', '\u{314}', '\u{301}', '\u{3c5}', '\u{313}', '\u{342}', '\u{3c5}',
+        '\u{314}', '\u{342}', '\u{3a5}', '\u{314}', '\u{3a5}', '\u{314}', '\u{300}', '\u{3a5}',
+        '\u{314}', '\u{301}', '\u{3a5}', '\u{314}', '\u{342}', '\u{3c9}', '\u{313}', '\u{3c9}',
+        '\u{314}', '\u{3c9}', '\u{313}', '\u{300}', '\u{3c9}', '\u{314}', '\u{300}', '\u{3c9}',
+        '\u{313}', '\u{301}', '\u{3c9}', '\u{314}', '\u{301}', '\u{3c9}', '\u{313}', '\u{342}',
+        '\u{3c9}', '\u{314}', '\u{342}', '\u{3a9}', '\u{313}', '\u{3a9}', '\u{314}', '\u{3a9}',
+        '\u{313}', '\u{300}', '\u{3a9}', '\u{314}', '\u{300}', '\u{3a9}', '\u{313}', '\u{301}',
+        '\u{3a9}', '\u{314}', '\u{301}', '\u{3a9}', '\u{313}', '\u{342}', '\u{3a9}', '\u{314}',
+        '\u{342}', '\u{3b1}', '\u{300}', '\u{3b1}', '\u{301}', '\u{3b5}', '\u{300}', '\u{3b5}',
+        '\u{301}', '\u{3b7}', '\u{300}', '\u{3b7}', '\u{301}', '\u{3b9}', '\u{300}', '\u{3b9}',
+        '\u{301}', '\u{3bf}', '\u{300}', '\u{3bf}', '\u{301}', '\u{3c5}', '\u{300}', '\u{3c5}',
+        '\u{301}', '\u{3c9}', '\u{300}', '\u{3c9}', '\u{301}', '\u{3b1}', '\u{313}', '\u{345}',
+        '\u{3b1}', '\u{314}', '\u{345}', '\u{3b1}', '\u{313}', '\u{300}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3b1}', '\u{313}', '\u{301}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3b1}', '\u{313}', '\u{342}', '\u{345}', '\u{3b1}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{301}', '\u{345}', '\u{391}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b7}', '\u{313}', '\u{345}', '\u{3b7}', '\u{314}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{300}', '\u{345}', '\u{3b7}', '\u{314}', '\u{300}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{301}', '\u{345}', '\u{3b7}', '\u{314}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{313}', '\u{342}', '\u{345}', '\u{3b7}', '\u{314}', '\u{342}', '\u{345}', '\u{397}',
+        '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}', '\u{313}', '\u{300}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}',
+        '\u{345}', '\u{397}', '\u{314}', '\u{342}', '\u{345}', '\u{3c9}', '\u{313}', '\u{345}',
+        '\u{3c9}', '\u{314}', '\u{345}', '\u{3c9}', '\u{313}', '\u{300}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{300}', '\u{345}', '\u{3c9}', '\u{313}', '\u{301}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{301}', '\u{345}', '\u{3c9}', '\u{313}', '\u{342}', '\u{345}', '\u{3c9}',
+        '\u{314}', '\u{342}', '\u{345}', '\u{3a9}', '\u{313}', '\u{345}', '\u{3a9}', '\u{314}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{300}', '\u{345}', '\u{3a9}', '\u{314}', '\u{300}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{301}', '\u{345}', '\u{3a9}', '\u{314}', '\u{301}',
+        '\u{345}', '\u{3a9}', '\u{313}', '\u{342}', '\u{345}', '\u{3a9}', '\u{314}', '\u{342}',
+        '\u{345}', '\u{3b1}', '\u{306}', '\u{3b1}', '\u{304}', '\u{3b1}', '\u{300}', '\u{345}',
+        '\u{3b1}', '\u{345}', '\u{3b1}', '\u{301}', '\u{345}', '\u{3b1}', '\u{342}', '\u{3b1}',
+        '\u{342}', '\u{345}', '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}',
+        '\u{391}', '\u{301}', '\u{391}', '\u{345}', '\u{3b9}', '\u{a8}', '\u{342}', '\u{3b7}',
+        '\u{300}', '\u{345}', '\u{3b7}', '\u{345}', '\u{3b7}', '\u{301}', '\u{345}', '\u{3b7}',
+        '\u{342}', '\u{3b7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+        '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1fbf}', '\u{300}',
+        '\u{1fbf}', '\u{301}', '\u{1fbf}', '\u{342}', '\u{3b9}', '\u{306}', '\u{3b9}', '\u{304}',
+        '\u{3b9}', '\u{308}', '\u{300}', '\u{3b9}', '\u{308}', '\u{301}', '\u{3b9}', '\u{342}',
+        '\u{3b9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}', '\u{304}', '\u{399}',
+        '\u{300}', '\u{399}', '\u{301}', '\u{1ffe}', '\u{300}', '\u{1ffe}', '\u{301}', '\u{1ffe}',
+        '\u{342}', '\u{3c5}', '\u{306}', '\u{3c5}', '\u{304}', '\u{3c5}', '\u{308}', '\u{300}',
+        '\u{3c5}', '\u{308}', '\u{301}', '\u{3c1}', '\u{313}', '\u{3c1}', '\u{314}', '\u{3c5}',
+        '\u{342}', '\u{3c5}', '\u{308}', '\u{342}', '\u{3a5}', '\u{306}', '\u{3a5}', '\u{304}',
+        '\u{3a5}', '\u{300}', '\u{3a5}', '\u{301}', '\u{3a1}', '\u{314}', '\u{a8}', '\u{300}',
+        '\u{a8}', '\u{301}', '`', '\u{3c9}', '\u{300}', '\u{345}', '\u{3c9}', '\u{345}', '\u{3c9}',
+        '\u{301}', '\u{345}', '\u{3c9}', '\u{342}', '\u{3c9}', '\u{342}', '\u{345}', '\u{39f}',
+        '\u{300}', '\u{39f}', '\u{301}', '\u{3a9}', '\u{300}', '\u{3a9}', '\u{301}', '\u{3a9}',
+        '\u{345}', '\u{b4}', '\u{2002}', '\u{2003}', '\u{3a9}', 'K', 'A', '\u{30a}', '\u{2190}',
+        '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21d0}', '\u{338}', '\u{21d4}',
+        '\u{338}', '\u{21d2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}', '\u{338}', '\u{220b}',
+        '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223c}', '\u{338}', '\u{2243}',
+        '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '=', '\u{338}', '\u{2261}',
+        '\u{338}', '\u{224d}', '\u{338}', '<', '\u{338}', '>', '\u{338}', '\u{2264}', '\u{338}',
+        '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}', '\u{338}', '\u{2276}', '\u{338}',
+        '\u{2277}', '\u{338}', '\u{227a}', '\u{338}', '\u{227b}', '\u{338}', '\u{2282}', '\u{338}',
+        '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}', '\u{338}', '\u{22a2}', '\u{338}',
+        '\u{22a8}', '\u{338}', '\u{22a9}', '\u{338}', '\u{22ab}', '\u{338}', '\u{227c}', '\u{338}',
+        '\u{227d}', '\u{338}', '\u{2291}', '\u{338}', '\u{2292}', '\u{338}', '\u{22b2}', '\u{338}',
+        '\u{22b3}', '\u{338}', '\u{22b4}', '\u{338}', '\u{22b5}', '\u{338}', '\u{3008}', '\u{3009}',
+        '\u{2add}', '\u{338}', '\u{304b}', '\u{3099}', '\u{304d}', '\u{3099}', '\u{304f}',
+        '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}', '\u{3099}',
+        '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}', '\u{305b}', '\u{3099}', '\u{305d}',
+        '\u{3099}', '\u{305f}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}',
+        '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306f}', '\u{3099}', '\u{306f}',
+        '\u{309a}', '\u{3072}', '\u{3099}', '\u{3072}', '\u{309a}', '\u{3075}', '\u{3099}',
+        '\u{3075}', '\u{309a}', '\u{3078}', '\u{3099}', '\u{3078}', '\u{309a}', '\u{307b}',
+        '\u{3099}', '\u{307b}', '\u{309a}', '\u{3046}', '\u{3099}', '\u{309d}', '\u{3099}',
+        '\u{30ab}', '\u{3099}', '\u{30ad}', '\u{3099}', '\u{30af}', '\u{3099}', '\u{30b1}',
+        '\u{3099}', '\u{30b3}', '\u{3099}', '\u{30b5}', '\u{3099}', '\u{30b7}', '\u{3099}',
+        '\u{30b9}', '\u{3099}', '\u{30bb}', '\u{3099}', '\u{30bd}', '\u{3099}', '\u{30bf}',
+        '\u{3099}', '\u{30c1}', '\u{3099}', '\u{30c4}', '\u{3099}', '\u{30c6}', '\u{3099}',
+        '\u{30c8}', '\u{3099}', '\u{30cf}', '\u{3099}', '\u{30cf}', '\u{309a}', '\u{30d2}',
+        '\u{3099}', '\u{30d2}', '\u{309a}', '\u{30d5}', '\u{3099}', '\u{30d5}', '\u{309a}',
+        '\u{30d8}', '\u{3099}', '\u{30d8}', '\u{309a}', '\u{30db}', '\u{3099}', '\u{30db}',
+        '\u{309a}', '\u{30a6}', '\u{3099}', '\u{30ef}', '\u{3099}', '\u{30f0}', '\u{3099}',
+        '\u{30f1}', '\u{3099}', '\u{30f2}', '\u{3099}', '\u{30fd}', '\u{3099}', '\u{8c48}',
+        '\u{66f4}', '\u{8eca}', '\u{8cc8}', '\u{6ed1}', '\u{4e32}', '\u{53e5}', '\u{9f9c}',
+        '\u{9f9c}', '\u{5951}', '\u{91d1}', '\u{5587}', '\u{5948}', '\u{61f6}', '\u{7669}',
+        '\u{7f85}', '\u{863f}', '\u{87ba}', '\u{88f8}', '\u{908f}', '\u{6a02}', '\u{6d1b}',
+        '\u{70d9}', '\u{73de}', '\u{843d}', '\u{916a}', '\u{99f1}', '\u{4e82}', '\u{5375}',
+        '\u{6b04}', '\u{721b}', '\u{862d}', '\u{9e1e}', '\u{5d50}', '\u{6feb}', '\u{85cd}',
+        '\u{8964}', '\u{62c9}', '\u{81d8}', '\u{881f}', '\u{5eca}', '\u{6717}', '\u{6d6a}',
+        '\u{72fc}', '\u{90ce}', '\u{4f86}', '\u{51b7}', '\u{52de}', '\u{64c4}', '\u{6ad3}',
+        '\u{7210}', '\u{76e7}', '\u{8001}', '\u{8606}', '\u{865c}', '\u{8def}', '\u{9732}',
+        '\u{9b6f}', '\u{9dfa}', '\u{788c}', '\u{797f}', '\u{7da0}', '\u{83c9}', '\u{9304}',
+        '\u{9e7f}', '\u{8ad6}', '\u{58df}', '\u{5f04}', '\u{7c60}', '\u{807e}', '\u{7262}',
+        '\u{78ca}', '\u{8cc2}', '\u{96f7}', '\u{58d8}', '\u{5c62}', '\u{6a13}', '\u{6dda}',
+        '\u{6f0f}', '\u{7d2f}', '\u{7e37}', '\u{964b}', '\u{52d2}', '\u{808b}', '\u{51dc}',
+        '\u{51cc}', '\u{7a1c}', '\u{7dbe}', '\u{83f1}', '\u{9675}', '\u{8b80}', '\u{62cf}',
+        '\u{6a02}', '\u{8afe}', '\u{4e39}', '\u{5be7}', '\u{6012}', '\u{7387}', '\u{7570}',
+        '\u{5317}', '\u{78fb}', '\u{4fbf}', '\u{5fa9}', '\u{4e0d}', '\u{6ccc}', '\u{6578}',
+        '\u{7d22}', '\u{53c3}', '\u{585e}', '\u{7701}', '\u{8449}', '\u{8aaa}', '\u{6bba}',
+        '\u{8fb0}', '\u{6c88}', '\u{62fe}', '\u{82e5}', '\u{63a0}', '\u{7565}', '\u{4eae}',
+        '\u{5169}', '\u{51c9}', '\u{6881}', '\u{7ce7}', '\u{826f}', '\u{8ad2}', '\u{91cf}',
+        '\u{52f5}', '\u{5442}', '\u{5973}', '\u{5eec}', '\u{65c5}', '\u{6ffe}', '\u{792a}',
+        '\u{95ad}', '\u{9a6a}', '\u{9e97}', '\u{9ece}', '\u{529b}', '\u{66c6}', '\u{6b77}',
+        '\u{8f62}', '\u{5e74}', '\u{6190}', '\u{6200}', '\u{649a}', '\u{6f23}', '\u{7149}',
+        '\u{7489}', '\u{79ca}', '\u{7df4}', '\u{806f}', '\u{8f26}', '\u{84ee}', '\u{9023}',
+        '\u{934a}', '\u{5217}', '\u{52a3}', '\u{54bd}', '\u{70c8}', '\u{88c2}', '\u{8aaa}',
+        '\u{5ec9}', '\u{5ff5}', '\u{637b}', '\u{6bae}', '\u{7c3e}', '\u{7375}', '\u{4ee4}',
+        '\u{56f9}', '\u{5be7}', '\u{5dba}', '\u{601c}', '\u{73b2}', '\u{7469}', '\u{7f9a}',
+        '\u{8046}', '\u{9234}', '\u{96f6}', '\u{9748}', '\u{9818}', '\u{4f8b}', '\u{79ae}',
+        '\u{91b4}', '\u{96b8}', '\u{60e1}', '\u{4e86}', '\u{50da}', '\u{5bee}', '\u{5c3f}',
+        '\u{6599}', '\u{6a02}', '\u{71ce}', '\u{7642}', '\u{84fc}', '\u{907c}', '\u{9f8d}',
+        '\u{6688}', '\u{962e}', '\u{5289}', '\u{677b}', '\u{67f3}', '\u{6d41}', '\u{6e9c}',
+        '\u{7409}', '\u{7559}', '\u{786b}', '\u{7d10}', '\u{985e}', '\u{516d}', '\u{622e}',
+        '\u{9678}', '\u{502b}', '\u{5d19}', '\u{6dea}', '\u{8f2a}', '\u{5f8b}', '\u{6144}',
+        '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540f}', '\u{5c65}', '\u{6613}',
+        '\u{674e}', '\u{68a8}', '\u{6ce5}', '\u{7406}', '\u{75e2}', '\u{7f79}', '\u{88cf}',
+        '\u{88e1}', '\u{91cc}', '\u{96e2}', '\u{533f}', '\u{6eba}', '\u{541d}', '\u{71d0}',
+        '\u{7498}', '\u{85fa}', '\u{96a3}', '\u{9c57}', '\u{9e9f}', '\u{6797}', '\u{6dcb}',
+        '\u{81e8}', '\u{7acb}', '\u{7b20}', '\u{7c92}', '\u{72c0}', '\u{7099}', '\u{8b58}',
+        '\u{4ec0}', '\u{8336}', '\u{523a}', '\u{5207}', '\u{5ea6}', '\u{62d3}', '\u{7cd6}',
+        '\u{5b85}', '\u{6d1e}', '\u{66b4}', '\u{8f3b}', '\u{884c}', '\u{964d}', '\u{898b}',
+        '\u{5ed3}', '\u{5140}', '\u{55c0}', '\u{585a}', '\u{6674}', '\u{51de}', '\u{732a}',
+        '\u{76ca}', '\u{793c}', '\u{795e}', '\u{7965}', '\u{798f}', '\u{9756}', '\u{7cbe}',
+        '\u{7fbd}', '\u{8612}', '\u{8af8}', '\u{9038}', '\u{90fd}', '\u{98ef}', '\u{98fc}',
+        '\u{9928}', '\u{9db4}', '\u{90de}', '\u{96b7}', '\u{4fae}', '\u{50e7}', '\u{514d}',
+        '\u{52c9}', '\u{52e4}', '\u{5351}', '\u{559d}', '\u{5606}', '\u{5668}', '\u{5840}',
+        '\u{58a8}', '\u{5c64}', '\u{5c6e}', '\u{6094}', '\u{6168}', '\u{618e}', '\u{61f2}',
+        '\u{654f}', '\u{65e2}', '\u{6691}', '\u{6885}', '\u{6d77}', '\u{6e1a}', '\u{6f22}',
+        '\u{716e}', '\u{722b}', '\u{7422}', '\u{7891}', '\u{793e}', '\u{7949}', '\u{7948}',
+        '\u{7950}', '\u{7956}', '\u{795d}', '\u{798d}', '\u{798e}', '\u{7a40}', '\u{7a81}',
+        '\u{7bc0}', '\u{7df4}', '\u{7e09}', '\u{7e41}', '\u{7f72}', '\u{8005}', '\u{81ed}',
+        '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8b01}', '\u{8b39}',
+        '\u{8cd3}', '\u{8d08}', '\u{8fb6}', '\u{9038}', '\u{96e3}', '\u{97ff}', '\u{983b}',
+        '\u{6075}', '\u{242ee}', '\u{8218}', '\u{4e26}', '\u{51b5}', '\u{5168}', '\u{4f80}',
+        '\u{5145}', '\u{5180}', '\u{52c7}', '\u{52fa}', '\u{559d}', '\u{5555}', '\u{5599}',
+        '\u{55e2}', '\u{585a}', '\u{58b3}', '\u{5944}', '\u{5954}', '\u{5a62}', '\u{5b28}',
+        '\u{5ed2}', '\u{5ed9}', '\u{5f69}', '\u{5fad}', '\u{60d8}', '\u{614e}', '\u{6108}',
+        '\u{618e}', '\u{6160}', '\u{61f2}', '\u{6234}', '\u{63c4}', '\u{641c}', '\u{6452}',
+        '\u{6556}', '\u{6674}', '\u{6717}', '\u{671b}', '\u{6756}', '\u{6b79}', '\u{6bba}',
+        '\u{6d41}', '\u{6edb}', '\u{6ecb}', '\u{6f22}', '\u{701e}', '\u{716e}', '\u{77a7}',
+        '\u{7235}', '\u{72af}', '\u{732a}', '\u{7471}', '\u{7506}', '\u{753b}', '\u{761d}',
+        '\u{761f}', '\u{76ca}', '\u{76db}', '\u{76f4}', '\u{774a}', '\u{7740}', '\u{78cc}',
+        '\u{7ab1}', '\u{7bc0}', '\u{7c7b}', '\u{7d5b}', '\u{7df4}', '\u{7f3e}', '\u{8005}',
+        '\u{8352}', '\u{83ef}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}', '\u{8abf}',
+        '\u{8af8}', '\u{8acb}', '\u{8b01}', '\u{8afe}', '\u{8aed}', '\u{8b39}', '\u{8b8a}',
+        '\u{8d08}', '\u{8f38}', '\u{9072}', '\u{9199}', '\u{9276}', '\u{967c}', '\u{96e3}',
+        '\u{9756}', '\u{97db}', '\u{97ff}', '\u{980b}', '\u{983b}', '\u{9b12}', '\u{9f9c}',
+        '\u{2284a}', '\u{22844}', '\u{233d5}', '\u{3b9d}', '\u{4018}', '\u{4039}', '\u{25249}',
+        '\u{25cd0}', '\u{27ed3}', '\u{9f43}', '\u{9f8e}', '\u{5d9}', '\u{5b4}', '\u{5f2}',
+        '\u{5b7}', '\u{5e9}', '\u{5c1}', '\u{5e9}', '\u{5c2}', '\u{5e9}', '\u{5bc}', '\u{5c1}',
+        '\u{5e9}', '\u{5bc}', '\u{5c2}', '\u{5d0}', '\u{5b7}', '\u{5d0}', '\u{5b8}', '\u{5d0}',
+        '\u{5bc}', '\u{5d1}', '\u{5bc}', '\u{5d2}', '\u{5bc}', '\u{5d3}', '\u{5bc}', '\u{5d4}',
+        '\u{5bc}', '\u{5d5}', '\u{5bc}', '\u{5d6}', '\u{5bc}', '\u{5d8}', '\u{5bc}', '\u{5d9}',
+        '\u{5bc}', '\u{5da}', '\u{5bc}', '\u{5db}', '\u{5bc}', '\u{5dc}', '\u{5bc}', '\u{5de}',
+        '\u{5bc}', '\u{5e0}', '\u{5bc}', '\u{5e1}', '\u{5bc}', '\u{5e3}', '\u{5bc}', '\u{5e4}',
+        '\u{5bc}', '\u{5e6}', '\u{5bc}', '\u{5e7}', '\u{5bc}', '\u{5e8}', '\u{5bc}', '\u{5e9}',
+        '\u{5bc}', '\u{5ea}', '\u{5bc}', '\u{5d5}', '\u{5b9}', '\u{5d1}', '\u{5bf}', '\u{5db}',
+        '\u{5bf}', '\u{5e4}', '\u{5bf}', '\u{11099}', '\u{110ba}', '\u{1109b}', '\u{110ba}',
+        '\u{110a5}', '\u{110ba}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}',
+        '\u{1133e}', '\u{11347}', '\u{11357}', '\u{114b9}', '\u{114ba}', '\u{114b9}', '\u{114b0}',
+        '\u{114b9}', '\u{114bd}', '\u{115b8}', '\u{115af}', '\u{115b9}', '\u{115af}', '\u{11935}',
+        '\u{11930}', '\u{1d157}', '\u{1d165}', '\u{1d158}', '\u{1d165}', '\u{1d158}', '\u{1d165}',
+        '\u{1d16e}', '\u{1d158}', '\u{1d165}', '\u{1d16f}', '\u{1d158}', '\u{1d165}', '\u{1d170}',
+        '\u{1d158}', '\u{1d165}', '\u{1d171}', '\u{1d158}', '\u{1d165}', '\u{1d172}', '\u{1d1b9}',
+        '\u{1d165}', '\u{1d1ba}', '\u{1d165}', '\u{1d1b9}', '\u{1d165}', '\u{1d16e}', '\u{1d1ba}',
+        '\u{1d165}', '\u{1d16e}', '\u{1d1b9}', '\u{1d165}', '\u{1d16f}', '\u{1d1ba}', '\u{1d165}',
+        '\u{1d16f}', '\u{4e3d}', '\u{4e38}', '\u{4e41}', '\u{20122}', '\u{4f60}', '\u{4fae}',
+        '\u{4fbb}', '\u{5002}', '\u{507a}', '\u{5099}', '\u{50e7}', '\u{50cf}', '\u{349e}',
+        '\u{2063a}', '\u{514d}', '\u{5154}', '\u{5164}', '\u{5177}', '\u{2051c}', '\u{34b9}',
+        '\u{5167}', '\u{518d}', '\u{2054b}', '\u{5197}', '\u{51a4}', '\u{4ecc}', '\u{51ac}',
+        '\u{51b5}', '\u{291df}', '\u{51f5}', '\u{5203}', '\u{34df}', '\u{523b}', '\u{5246}',
+        '\u{5272}', '\u{5277}', '\u{3515}', '\u{52c7}', '\u{52c9}', '\u{52e4}', '\u{52fa}',
+        '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535a}', '\u{5373}',
+        '\u{537d}', '\u{537f}', '\u{537f}', '\u{537f}', '\u{20a2c}', '\u{7070}', '\u{53ca}',
+        '\u{53df}', '\u{20b63}', '\u{53eb}', '\u{53f1}', '\u{5406}', '\u{549e}', '\u{5438}',
+        '\u{5448}', '\u{5468}', '\u{54a2}', '\u{54f6}', '\u{5510}', '\u{5553}', '\u{5563}',
+        '\u{5584}', '\u{5584}', '\u{5599}', '\u{55ab}', '\u{55b3}', '\u{55c2}', '\u{5716}',
+        '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{5207}', '\u{58ee}', '\u{57ce}',
+        '\u{57f4}', '\u{580d}', '\u{578b}', '\u{5832}', '\u{5831}', '\u{58ac}', '\u{214e4}',
+        '\u{58f2}', '\u{58f7}', '\u{5906}', '\u{591a}', '\u{5922}', '\u{5962}', '\u{216a8}',
+        '\u{216ea}', '\u{59ec}', '\u{5a1b}', '\u{5a27}', '\u{59d8}', '\u{5a66}', '\u{36ee}',
+        '\u{36fc}', '\u{5b08}', '\u{5b3e}', '\u{5b3e}', '\u{219c8}', '\u{5bc3}', '\u{5bd8}',
+        '\u{5be7}', '\u{5bf3}', '\u{21b18}', '\u{5bff}', '\u{5c06}', '\u{5f53}', '\u{5c22}',
+        '\u{3781}', '\u{5c60}', '\u{5c6e}', '\u{5cc0}', '\u{5c8d}', '\u{21de4}', '\u{5d43}',
+        '\u{21de6}', '\u{5d6e}', '\u{5d6b}', '\u{5d7c}', '\u{5de1}', '\u{5de2}', '\u{382f}',
+        '\u{5dfd}', '\u{5e28}', '\u{5e3d}', '\u{5e69}', '\u{3862}', '\u{22183}', '\u{387c}',
+        '\u{5eb0}', '\u{5eb3}', '\u{5eb6}', '\u{5eca}', '\u{2a392}', '\u{5efe}', '\u{22331}',
+        '\u{22331}', '\u{8201}', '\u{5f22}', '\u{5f22}', '\u{38c7}', '\u{232b8}', '\u{261da}',
+        '\u{5f62}', '\u{5f6b}', '\u{38e3}', '\u{5f9a}', '\u{5fcd}', '\u{5fd7}', '\u{5ff9}',
+        '\u{6081}', '\u{393a}', '\u{391c}', '\u{6094}', '\u{226d4}', '\u{60c7}', '\u{6148}',
+        '\u{614c}', '\u{614e}', '\u{614c}', '\u{617a}', '\u{618e}', '\u{61b2}', '\u{61a4}',
+        '\u{61af}', '\u{61de}', '\u{61f2}', '\u{61f6}', '\u{6210}', '\u{621b}', '\u{625d}',
+        '\u{62b1}', '\u{62d4}', '\u{6350}', '\u{22b0c}', '\u{633d}', '\u{62fc}', '\u{6368}',
+        '\u{6383}', '\u{63e4}', '\u{22bf1}', '\u{6422}', '\u{63c5}', '\u{63a9}', '\u{3a2e}',
+        '\u{6469}', '\u{647e}', '\u{649d}', '\u{6477}', '\u{3a6c}', '\u{654f}', '\u{656c}',
+        '\u{2300a}', '\u{65e3}', '\u{66f8}', '\u{6649}', '\u{3b19}', '\u{6691}', '\u{3b08}',
+        '\u{3ae4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669c}', '\u{80ad}', '\u{43d9}',
+        '\u{6717}', '\u{671b}', '\u{6721}', '\u{675e}', '\u{6753}', '\u{233c3}', '\u{3b49}',
+        '\u{67fa}', '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346d}', '\u{688e}', '\u{681f}',
+        '\u{6914}', '\u{3b9d}', '\u{6942}', '\u{69a3}', '\u{69ea}', '\u{6aa8}', '\u{236a3}',
+        '\u{6adb}', '\u{3c18}', '\u{6b21}', '\u{238a7}', '\u{6b54}', '\u{3c4e}', '\u{6b72}',
+        '\u{6b9f}', '\u{6bba}', '\u{6bbb}', '\u{23a8d}', '\u{21d0b}', '\u{23afa}', '\u{6c4e}',
+        '\u{23cbc}', '\u{6cbf}', '\u{6ccd}', '\u{6c67}', '\u{6d16}', '\u{6d3e}', '\u{6d77}',
+        '\u{6d41}', '\u{6d69}', '\u{6d78}', '\u{6d85}', '\u{23d1e}', '\u{6d34}', '\u{6e2f}',
+        '\u{6e6e}', '\u{3d33}', '\u{6ecb}', '\u{6ec7}', '\u{23ed1}', '\u{6df9}', '\u{6f6e}',
+        '\u{23f5e}', '\u{23f8e}', '\u{6fc6}', '\u{7039}', '\u{701e}', '\u{701b}', '\u{3d96}',
+        '\u{704a}', '\u{707d}', '\u{7077}', '\u{70ad}', '\u{20525}', '\u{7145}', '\u{24263}',
+        '\u{719c}', '\u{243ab}', '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}',
+        '\u{7295}', '\u{24735}', '\u{24814}', '\u{737a}', '\u{738b}', '\u{3eac}', '\u{73a5}',
+        '\u{3eb8}', '\u{3eb8}', '\u{7447}', '\u{745c}', '\u{7471}', '\u{7485}', '\u{74ca}',
+        '\u{3f1b}', '\u{7524}', '\u{24c36}', '\u{753e}', '\u{24c92}', '\u{7570}', '\u{2219f}',
+        '\u{7610}', '\u{24fa1}', '\u{24fb8}', '\u{25044}', '\u{3ffc}', '\u{4008}', '\u{76f4}',
+        '\u{250f3}', '\u{250f2}', '\u{25119}', '\u{25133}', '\u{771e}', '\u{771f}', '\u{771f}',
+        '\u{774a}', '\u{4039}', '\u{778b}', '\u{4046}', '\u{4096}', '\u{2541d}', '\u{784e}',
+        '\u{788c}', '\u{78cc}', '\u{40e3}', '\u{25626}', '\u{7956}', '\u{2569a}', '\u{256c5}',
+        '\u{798f}', '\u{79eb}', '\u{412f}', '\u{7a40}', '\u{7a4a}', '\u{7a4f}', '\u{2597c}',
+        '\u{25aa7}', '\u{25aa7}', '\u{7aee}', '\u{4202}', '\u{25bab}', '\u{7bc6}', '\u{7bc9}',
+        '\u{4227}', '\u{25c80}', '\u{7cd2}', '\u{42a0}', '\u{7ce8}', '\u{7ce3}', '\u{7d00}',
+        '\u{25f86}', '\u{7d63}', '\u{4301}', '\u{7dc7}', '\u{7e02}', '\u{7e45}', '\u{4334}',
+        '\u{26228}', '\u{26247}', '\u{4359}', '\u{262d9}', '\u{7f7a}', '\u{2633e}', '\u{7f95}',
+        '\u{7ffa}', '\u{8005}', '\u{264da}', '\u{26523}', '\u{8060}', '\u{265a8}', '\u{8070}',
+        '\u{2335f}', '\u{43d5}', '\u{80b2}', '\u{8103}', '\u{440b}', '\u{813e}', '\u{5ab5}',
+        '\u{267a7}', '\u{267b5}', '\u{23393}', '\u{2339c}', '\u{8201}', '\u{8204}', '\u{8f9e}',
+        '\u{446b}', '\u{8291}', '\u{828b}', '\u{829d}', '\u{52b3}', '\u{82b1}', '\u{82b3}',
+        '\u{82bd}', '\u{82e6}', '\u{26b3c}', '\u{82e5}', '\u{831d}', '\u{8363}', '\u{83ad}',
+        '\u{8323}', '\u{83bd}', '\u{83e7}', '\u{8457}', '\u{8353}', '\u{83ca}', '\u{83cc}',
+        '\u{83dc}', '\u{26c36}', '\u{26d6b}', '\u{26cd5}', '\u{452b}', '\u{84f1}', '\u{84f3}',
+        '\u{8516}', '\u{273ca}', '\u{8564}', '\u{26f2c}', '\u{455d}', '\u{4561}', '\u{26fb1}',
+        '\u{270d2}', '\u{456b}', '\u{8650}', '\u{865c}', '\u{8667}', '\u{8669}', '\u{86a9}',
+        '\u{8688}', '\u{870e}', '\u{86e2}', '\u{8779}', '\u{8728}', '\u{876b}', '\u{8786}',
+        '\u{45d7}', '\u{87e1}', '\u{8801}', '\u{45f9}', '\u{8860}', '\u{8863}', '\u{27667}',
+        '\u{88d7}', '\u{88de}', '\u{4635}', '\u{88fa}', '\u{34bb}', '\u{278ae}', '\u{27966}',
+        '\u{46be}', '\u{46c7}', '\u{8aa0}', '\u{8aed}', '\u{8b8a}', '\u{8c55}', '\u{27ca8}',
+        '\u{8cab}', '\u{8cc1}', '\u{8d1b}', '\u{8d77}', '\u{27f2f}', '\u{20804}', '\u{8dcb}',
+        '\u{8dbc}', '\u{8df0}', '\u{208de}', '\u{8ed4}', '\u{8f38}', '\u{285d2}', '\u{285ed}',
+        '\u{9094}', '\u{90f1}', '\u{9111}', '\u{2872e}', '\u{911b}', '\u{9238}', '\u{92d7}',
+        '\u{92d8}', '\u{927c}', '\u{93f9}', '\u{9415}', '\u{28bfa}', '\u{958b}', '\u{4995}',
+        '\u{95b7}', '\u{28d77}', '\u{49e6}', '\u{96c3}', '\u{5db2}', '\u{9723}', '\u{29145}',
+        '\u{2921a}', '\u{4a6e}', '\u{4a76}', '\u{97e0}', '\u{2940a}', '\u{4ab2}', '\u{29496}',
+        '\u{980b}', '\u{980b}', '\u{9829}', '\u{295b6}', '\u{98e2}', '\u{4b33}', '\u{9929}',
+        '\u{99a7}', '\u{99c2}', '\u{99fe}', '\u{4bce}', '\u{29b30}', '\u{9b12}', '\u{9c40}',
+        '\u{9cfd}', '\u{4cce}', '\u{4ced}', '\u{9d67}', '\u{2a0ce}', '\u{4cf8}', '\u{2a105}',
+        '\u{2a20e}', '\u{2a291}', '\u{9ebb}', '\u{4d56}', '\u{9ef9}', '\u{9efe}', '\u{9f05}',
+        '\u{9f0f}', '\u{9f16}', '\u{9f3b}', '\u{2a600}',
+    ];
+
+    static COMPATIBILITY_DECOMPOSED: &[(char, u32)] = &[
+        ('\u{a0}', 1), ('\u{a8}', 34), ('\u{aa}', 97), ('\u{af}', 130), ('\u{b2}', 193),
+        ('\u{b3}', 225), ('\u{b4}', 258), ('\u{b5}', 321), ('\u{b8}', 354), ('\u{b9}', 417),
+        ('\u{ba}', 449), ('\u{bc}', 483), ('\u{bd}', 579), ('\u{be}', 675), ('\u{132}', 770),
+        ('\u{133}', 834), ('\u{13f}', 898), ('\u{140}', 962), ('\u{149}', 1026), ('\u{17f}', 1089),
+        ('\u{1c4}', 1123), ('\u{1c5}', 1219), ('\u{1c6}', 1315), ('\u{1c7}', 1410),
+        ('\u{1c8}', 1474), ('\u{1c9}', 1538), ('\u{1ca}', 1602), ('\u{1cb}', 1666),
+        ('\u{1cc}', 1730), ('\u{1f1}', 1794), ('\u{1f2}', 1858), ('\u{1f3}', 1922),
+        ('\u{2b0}', 1985), ('\u{2b1}', 2017), ('\u{2b2}', 2049), ('\u{2b3}', 2081),
+        ('\u{2b4}', 2113), ('\u{2b5}', 2145), ('\u{2b6}', 2177), ('\u{2b7}', 2209),
+        ('\u{2b8}', 2241), ('\u{2d8}', 2274), ('\u{2d9}', 2338), ('\u{2da}', 2402),
+        ('\u{2db}', 2466), ('\u{2dc}', 2530), ('\u{2dd}', 2594), ('\u{2e0}', 2657),
+        ('\u{2e1}', 2689), ('\u{2e2}', 2721), ('\u{2e3}', 2753), ('\u{2e4}', 2785),
+        ('\u{37a}', 2818), ('\u{384}', 2882), ('\u{385}', 2947), ('\u{3d0}', 3041),
+        ('\u{3d1}', 3073), ('\u{3d2}', 3105), ('\u{3d3}', 3138), ('\u{3d4}', 3202),
+        ('\u{3d5}', 3265), ('\u{3d6}', 3297), ('\u{3f0}', 3329), ('\u{3f1}', 3361),
+        ('\u{3f2}', 3393), ('\u{3f4}', 3425), ('\u{3f5}', 3457), ('\u{3f9}', 3489),
+        ('\u{587}', 3522), ('\u{675}', 3586), ('\u{676}', 3650), ('\u{677}', 3714),
+        ('\u{678}', 3778), ('\u{e33}', 3842), ('\u{eb3}', 3906), ('\u{edc}', 3970),
+        ('\u{edd}', 4034), ('\u{f0c}', 4097), ('\u{f77}', 4131), ('\u{f79}', 4227),
+        ('\u{10fc}', 4321), ('\u{1d2c}', 4353), ('\u{1d2d}', 4385), ('\u{1d2e}', 4417),
+        ('\u{1d30}', 4449), ('\u{1d31}', 4481), ('\u{1d32}', 4513), ('\u{1d33}', 4545),
+        ('\u{1d34}', 4577), ('\u{1d35}', 4609), ('\u{1d36}', 4641), ('\u{1d37}', 4673),
+        ('\u{1d38}', 4705), ('\u{1d39}', 4737), ('\u{1d3a}', 4769), ('\u{1d3c}', 4801),
+        ('\u{1d3d}', 4833), ('\u{1d3e}', 4865), ('\u{1d3f}', 4897), ('\u{1d40}', 4929),
+        ('\u{1d41}', 4961), ('\u{1d42}', 4993), ('\u{1d43}', 5025), ('\u{1d44}', 5057),
+        ('\u{1d45}', 5089), ('\u{1d46}', 5121), ('\u{1d47}', 5153), ('\u{1d48}', 5185),
+        ('\u{1d49}', 5217), ('\u{1d4a}', 5249), ('\u{1d4b}', 5281), ('\u{1d4c}', 5313),
+        ('\u{1d4d}', 5345), ('\u{1d4f}', 5377), ('\u{1d50}', 5409), ('\u{1d51}', 5441),
+        ('\u{1d52}', 5473), ('\u{1d53}', 5505), ('\u{1d54}', 5537), ('\u{1d55}', 5569),
+        ('\u{1d56}', 5601), ('\u{1d57}', 5633), ('\u{1d58}', 5665), ('\u{1d59}', 5697),
+        ('\u{1d5a}', 5729), ('\u{1d5b}', 5761), ('\u{1d5c}', 5793), ('\u{1d5d}', 5825),
+        ('\u{1d5e}', 5857), ('\u{1d5f}', 5889), ('\u{1d60}', 5921), ('\u{1d61}', 5953),
+        ('\u{1d62}', 5985), ('\u{1d63}', 6017), ('\u{1d64}', 6049), ('\u{1d65}', 6081),
+        ('\u{1d66}', 6113), ('\u{1d67}', 6145), ('\u{1d68}', 6177), ('\u{1d69}', 6209),
+        ('\u{1d6a}', 6241), ('\u{1d78}', 6273), ('\u{1d9b}', 6305), ('\u{1d9c}', 6337),
+        ('\u{1d9d}', 6369), ('\u{1d9e}', 6401), ('\u{1d9f}', 6433), ('\u{1da0}', 6465),
+        ('\u{1da1}', 6497), ('\u{1da2}', 6529), ('\u{1da3}', 6561), ('\u{1da4}', 6593),
+        ('\u{1da5}', 6625), ('\u{1da6}', 6657), ('\u{1da7}', 6689), ('\u{1da8}', 6721),
+        ('\u{1da9}', 6753), ('\u{1daa}', 6785), ('\u{1dab}', 6817), ('\u{1dac}', 6849),
+        ('\u{1dad}', 6881), ('\u{1dae}', 6913), ('\u{1daf}', 6945), ('\u{1db0}', 6977),
+        ('\u{1db1}', 7009), ('\u{1db2}', 7041), ('\u{1db3}', 7073), ('\u{1db4}', 7105),
+        ('\u{1db5}', 7137), ('\u{1db6}', 7169), ('\u{1db7}', 7201), ('\u{1db8}', 7233),
+        ('\u{1db9}', 7265), ('\u{1dba}', 7297), ('\u{1dbb}', 7329), ('\u{1dbc}', 7361),
+        ('\u{1dbd}', 7393), ('\u{1dbe}', 7425), ('\u{1dbf}', 7457), ('\u{1e9a}', 7490),
+        ('\u{1e9b}', 7554), ('\u{1fbd}', 7618), ('\u{1fbf}', 7682), ('\u{1fc0}', 7746),
+        ('\u{1fc1}', 7811), ('\u{1fcd}', 7907), ('\u{1fce}', 8003), ('\u{1fcf}', 8099),
+        ('\u{1fdd}', 8195), ('\u{1fde}', 8291), ('\u{1fdf}', 8387), ('\u{1fed}', 8483),
+        ('\u{1fee}', 8579), ('\u{1ffd}', 8674), ('\u{1ffe}', 8738), ('\u{2000}', 8801),
+        ('\u{2001}', 8833), ('\u{2002}', 8865), ('\u{2003}', 8897), ('\u{2004}', 8929),
+        ('\u{2005}', 8961), ('\u{2006}', 8993), ('\u{2007}', 9025), ('\u{2008}', 9057),
+        ('\u{2009}', 9089), ('\u{200a}', 9121), ('\u{2011}', 9153), ('\u{2017}', 9186),
+        ('\u{2024}', 9249), ('\u{2025}', 9282), ('\u{2026}', 9347), ('\u{202f}', 9441),
+        ('\u{2033}', 9474), ('\u{2034}', 9539), ('\u{2036}', 9634), ('\u{2037}', 9699),
+        ('\u{203c}', 9794), ('\u{203e}', 9858), ('\u{2047}', 9922), ('\u{2048}', 9986),
+        ('\u{2049}', 10050), ('\u{2057}', 10116), ('\u{205f}', 10241), ('\u{2070}', 10273),
+        ('\u{2071}', 10305), ('\u{2074}', 10337), ('\u{2075}', 10369), ('\u{2076}', 10401),
+        ('\u{2077}', 10433), ('\u{2078}', 10465), ('\u{2079}', 10497), ('\u{207a}', 10529),
+        ('\u{207b}', 10561), ('\u{207c}', 10593), ('\u{207d}', 10625), ('\u{207e}', 10657),
+        ('\u{207f}', 10689), ('\u{2080}', 10721), ('\u{2081}', 10753), ('\u{2082}', 10785),
+        ('\u{2083}', 10817), ('\u{2084}', 10849), ('\u{2085}', 10881), ('\u{2086}', 10913),
+        ('\u{2087}', 10945), ('\u{2088}', 10977), ('\u{2089}', 11009), ('\u{208a}', 11041),
+        ('\u{208b}', 11073), ('\u{208c}', 11105), ('\u{208d}', 11137), ('\u{208e}', 11169),
+        ('\u{2090}', 11201), ('\u{2091}', 11233), ('\u{2092}', 11265), ('\u{2093}', 11297),
+        ('\u{2094}', 11329), ('\u{2095}', 11361), ('\u{2096}', 11393), ('\u{2097}', 11425),
+        ('\u{2098}', 11457), ('\u{2099}', 11489), ('\u{209a}', 11521), ('\u{209b}', 11553),
+        ('\u{209c}', 11585), ('\u{20a8}', 11618), ('\u{2100}', 11683), ('\u{2101}', 11779),
+        ('\u{2102}', 11873), ('\u{2103}', 11906), ('\u{2105}', 11971), ('\u{2106}', 12067),
+        ('\u{2107}', 12161), ('\u{2109}', 12194), ('\u{210a}', 12257), ('\u{210b}', 12289),
+        ('\u{210c}', 12321), ('\u{210d}', 12353), ('\u{210e}', 12385), ('\u{210f}', 12417),
+        ('\u{2110}', 12449), ('\u{2111}', 12481), ('\u{2112}', 12513), ('\u{2113}', 12545),
+        ('\u{2115}', 12577), ('\u{2116}', 12610), ('\u{2119}', 12673), ('\u{211a}', 12705),
+        ('\u{211b}', 12737), ('\u{211c}', 12769), ('\u{211d}', 12801), ('\u{2120}', 12834),
+        ('\u{2121}', 12899), ('\u{2122}', 12994), ('\u{2124}', 13057), ('\u{2128}', 13089),
+        ('\u{212c}', 13121), ('\u{212d}', 13153), ('\u{212f}', 13185), ('\u{2130}', 13217),
+        ('\u{2131}', 13249), ('\u{2133}', 13281), ('\u{2134}', 13313), ('\u{2135}', 13345),
+        ('\u{2136}', 13377), ('\u{2137}', 13409), ('\u{2138}', 13441), ('\u{2139}', 13473),
+        ('\u{213b}', 13507), ('\u{213c}', 13601), ('\u{213d}', 13633), ('\u{213e}', 13665),
+        ('\u{213f}', 13697), ('\u{2140}', 13729), ('\u{2145}', 13761), ('\u{2146}', 13793),
+        ('\u{2147}', 13825), ('\u{2148}', 13857), ('\u{2149}', 13889), ('\u{2150}', 13923),
+        ('\u{2151}', 14019), ('\u{2152}', 14116), ('\u{2153}', 14243), ('\u{2154}', 14339),
+        ('\u{2155}', 14435), ('\u{2156}', 14531), ('\u{2157}', 14627), ('\u{2158}', 14723),
+        ('\u{2159}', 14819), ('\u{215a}', 14915), ('\u{215b}', 15011), ('\u{215c}', 15107),
+        ('\u{215d}', 15203), ('\u{215e}', 15299), ('\u{215f}', 15394), ('\u{2160}', 15457),
+        ('\u{2161}', 15490), ('\u{2162}', 15555), ('\u{2163}', 15650), ('\u{2164}', 15713),
+        ('\u{2165}', 15746), ('\u{2166}', 15811), ('\u{2167}', 15908), ('\u{2168}', 16034),
+        ('\u{2169}', 16097), ('\u{216a}', 16130), ('\u{216b}', 16195), ('\u{216c}', 16289),
+        ('\u{216d}', 16321), ('\u{216e}', 16353), ('\u{216f}', 16385), ('\u{2170}', 16417),
+        ('\u{2171}', 16450), ('\u{2172}', 16515), ('\u{2173}', 16610), ('\u{2174}', 16673),
+        ('\u{2175}', 16706), ('\u{2176}', 16771), ('\u{2177}', 16868), ('\u{2178}', 16994),
+        ('\u{2179}', 17057), ('\u{217a}', 17090), ('\u{217b}', 17155), ('\u{217c}', 17249),
+        ('\u{217d}', 17281), ('\u{217e}', 17313), ('\u{217f}', 17345), ('\u{2189}', 17379),
+        ('\u{222c}', 17474), ('\u{222d}', 17539), ('\u{222f}', 17634), ('\u{2230}', 17699),
+        ('\u{2460}', 17793), ('\u{2461}', 17825), ('\u{2462}', 17857), ('\u{2463}', 17889),
+        ('\u{2464}', 17921), ('\u{2465}', 17953), ('\u{2466}', 17985), ('\u{2467}', 18017),
+        ('\u{2468}', 18049), ('\u{2469}', 18082), ('\u{246a}', 18146), ('\u{246b}', 18210),
+        ('\u{246c}', 18274), ('\u{246d}', 18338), ('\u{246e}', 18402), ('\u{246f}', 18466),
+        ('\u{2470}', 18530), ('\u{2471}', 18594), ('\u{2472}', 18658), ('\u{2473}', 18722),
+        ('\u{2474}', 18787), ('\u{2475}', 18883), ('\u{2476}', 18979), ('\u{2477}', 19075),
+        ('\u{2478}', 19171), ('\u{2479}', 19267), ('\u{247a}', 19363), ('\u{247b}', 19459),
+        ('\u{247c}', 19555), ('\u{247d}', 19652), ('\u{247e}', 19780), ('\u{247f}', 19908),
+        ('\u{2480}', 20036), ('\u{2481}', 20164), ('\u{2482}', 20292), ('\u{2483}', 20420),
+        ('\u{2484}', 20548), ('\u{2485}', 20676), ('\u{2486}', 20804), ('\u{2487}', 20932),
+        ('\u{2488}', 21058), ('\u{2489}', 21122), ('\u{248a}', 21186), ('\u{248b}', 21250),
+        ('\u{248c}', 21314), ('\u{248d}', 21378), ('\u{248e}', 21442), ('\u{248f}', 21506),
+        ('\u{2490}', 21570), ('\u{2491}', 21635), ('\u{2492}', 21731), ('\u{2493}', 21827),
+        ('\u{2494}', 21923), ('\u{2495}', 22019), ('\u{2496}', 22115), ('\u{2497}', 22211),
+        ('\u{2498}', 22307), ('\u{2499}', 22403), ('\u{249a}', 22499), ('\u{249b}', 22595),
+        ('\u{249c}', 22691), ('\u{249d}', 22787), ('\u{249e}', 22883), ('\u{249f}', 22979),
+        ('\u{24a0}', 23075), ('\u{24a1}', 23171), ('\u{24a2}', 23267), ('\u{24a3}', 23363),
+        ('\u{24a4}', 23459), ('\u{24a5}', 23555), ('\u{24a6}', 23651), ('\u{24a7}', 23747),
+        ('\u{24a8}', 23843), ('\u{24a9}', 23939), ('\u{24aa}', 24035), ('\u{24ab}', 24131),
+        ('\u{24ac}', 24227), ('\u{24ad}', 24323), ('\u{24ae}', 24419), ('\u{24af}', 24515),
+        ('\u{24b0}', 24611), ('\u{24b1}', 24707), ('\u{24b2}', 24803), ('\u{24b3}', 24899),
+        ('\u{24b4}', 24995), ('\u{24b5}', 25091), ('\u{24b6}', 25185), ('\u{24b7}', 25217),
+        ('\u{24b8}', 25249), ('\u{24b9}', 25281), ('\u{24ba}', 25313), ('\u{24bb}', 25345),
+        ('\u{24bc}', 25377), ('\u{24bd}', 25409), ('\u{24be}', 25441), ('\u{24bf}', 25473),
+        ('\u{24c0}', 25505), ('\u{24c1}', 25537), ('\u{24c2}', 25569), ('\u{24c3}', 25601),
+        ('\u{24c4}', 25633), ('\u{24c5}', 25665), ('\u{24c6}', 25697), ('\u{24c7}', 25729),
+        ('\u{24c8}', 25761), ('\u{24c9}', 25793), ('\u{24ca}', 25825), ('\u{24cb}', 25857),
+        ('\u{24cc}', 25889), ('\u{24cd}', 25921), ('\u{24ce}', 25953), ('\u{24cf}', 25985),
+        ('\u{24d0}', 26017), ('\u{24d1}', 26049), ('\u{24d2}', 26081), ('\u{24d3}', 26113),
+        ('\u{24d4}', 26145), ('\u{24d5}', 26177), ('\u{24d6}', 26209), ('\u{24d7}', 26241),
+        ('\u{24d8}', 26273), ('\u{24d9}', 26305), ('\u{24da}', 26337), ('\u{24db}', 26369),
+        ('\u{24dc}', 26401), ('\u{24dd}', 26433), ('\u{24de}', 26465), ('\u{24df}', 26497),
+        ('\u{24e0}', 26529), ('\u{24e1}', 26561), ('\u{24e2}', 26593), ('\u{24e3}', 26625),
+        ('\u{24e4}', 26657), ('\u{24e5}', 26689), ('\u{24e6}', 26721), ('\u{24e7}', 26753),
+        ('\u{24e8}', 26785), ('\u{24e9}', 26817), ('\u{24ea}', 26849), ('\u{2a0c}', 26884),
+        ('\u{2a74}', 27011), ('\u{2a75}', 27106), ('\u{2a76}', 27171), ('\u{2c7c}', 27265),
+        ('\u{2c7d}', 27297), ('\u{2d6f}', 27329), ('\u{2e9f}', 27361), ('\u{2ef3}', 27393),
+        ('\u{2f00}', 27425), ('\u{2f01}', 27457), ('\u{2f02}', 27489), ('\u{2f03}', 27521),
+        ('\u{2f04}', 27553), ('\u{2f05}', 27585), ('\u{2f06}', 27617), ('\u{2f07}', 27649),
+        ('\u{2f08}', 27681), ('\u{2f09}', 27713), ('\u{2f0a}', 27745), ('\u{2f0b}', 27777),
+        ('\u{2f0c}', 27809), ('\u{2f0d}', 27841), ('\u{2f0e}', 27873), ('\u{2f0f}', 27905),
+        ('\u{2f10}', 27937), ('\u{2f11}', 27969), ('\u{2f12}', 28001), ('\u{2f13}', 28033),
+        ('\u{2f14}', 28065), ('\u{2f15}', 28097), ('\u{2f16}', 28129), ('\u{2f17}', 28161),
+        ('\u{2f18}', 28193), ('\u{2f19}', 28225), ('\u{2f1a}', 28257), ('\u{2f1b}', 28289),
+        ('\u{2f1c}', 28321), ('\u{2f1d}', 28353), ('\u{2f1e}', 28385), ('\u{2f1f}', 28417),
+        ('\u{2f20}', 28449), ('\u{2f21}', 28481), ('\u{2f22}', 28513), ('\u{2f23}', 28545),
+        ('\u{2f24}', 28577), ('\u{2f25}', 28609), ('\u{2f26}', 28641), ('\u{2f27}', 28673),
+        ('\u{2f28}', 28705), ('\u{2f29}', 28737), ('\u{2f2a}', 28769), ('\u{2f2b}', 28801),
+        ('\u{2f2c}', 28833), ('\u{2f2d}', 28865), ('\u{2f2e}', 28897), ('\u{2f2f}', 28929),
+        ('\u{2f30}', 28961), ('\u{2f31}', 28993), ('\u{2f32}', 29025), ('\u{2f33}', 29057),
+        ('\u{2f34}', 29089), ('\u{2f35}', 29121), ('\u{2f36}', 29153), ('\u{2f37}', 29185),
+        ('\u{2f38}', 29217), ('\u{2f39}', 29249), ('\u{2f3a}', 29281), ('\u{2f3b}', 29313),
+        ('\u{2f3c}', 29345), ('\u{2f3d}', 29377), ('\u{2f3e}', 29409), ('\u{2f3f}', 29441),
+        ('\u{2f40}', 29473), ('\u{2f41}', 29505), ('\u{2f42}', 29537), ('\u{2f43}', 29569),
+        ('\u{2f44}', 29601), ('\u{2f45}', 29633), ('\u{2f46}', 29665), ('\u{2f47}', 29697),
+        ('\u{2f48}', 29729), ('\u{2f49}', 29761), ('\u{2f4a}', 29793), ('\u{2f4b}', 29825),
+        ('\u{2f4c}', 29857), ('\u{2f4d}', 29889), ('\u{2f4e}', 29921), ('\u{2f4f}', 29953),
+        ('\u{2f50}', 29985), ('\u{2f51}', 30017), ('\u{2f52}', 30049), ('\u{2f53}', 30081),
+        ('\u{2f54}', 30113), ('\u{2f55}', 30145), ('\u{2f56}', 30177), ('\u{2f57}', 30209),
+        ('\u{2f58}', 30241), ('\u{2f59}', 30273), ('\u{2f5a}', 30305), ('\u{2f5b}', 30337),
+        ('\u{2f5c}', 30369), ('\u{2f5d}', 30401), ('\u{2f5e}', 30433), ('\u{2f5f}', 30465),
+        ('\u{2f60}', 30497), ('\u{2f61}', 30529), ('\u{2f62}', 30561), ('\u{2f63}', 30593),
+        ('\u{2f64}', 30625), ('\u{2f65}', 30657), ('\u{2f66}', 30689), ('\u{2f67}', 30721),
+        ('\u{2f68}', 30753), ('\u{2f69}', 30785), ('\u{2f6a}', 30817), ('\u{2f6b}', 30849),
+        ('\u{2f6c}', 30881), ('\u{2f6d}', 30913), ('\u{2f6e}', 30945), ('\u{2f6f}', 30977),
+        ('\u{2f70}', 31009), ('\u{2f71}', 31041), ('\u{2f72}', 31073), ('\u{2f73}', 31105),
+        ('\u{2f74}', 31137), ('\u{2f75}', 31169), ('\u{2f76}', 31201), ('\u{2f77}', 31233),
+        ('\u{2f78}', 31265), ('\u{2f79}', 31297), ('\u{2f7a}', 31329), ('\u{2f7b}', 31361),
+        ('\u{2f7c}', 31393), ('\u{2f7d}', 31425), ('\u{2f7e}', 31457), ('\u{2f7f}', 31489),
+        ('\u{2f80}', 31521), ('\u{2f81}', 31553), ('\u{2f82}', 31585), ('\u{2f83}', 31617),
+        ('\u{2f84}', 31649), ('\u{2f85}', 31681), ('\u{2f86}', 31713), ('\u{2f87}', 31745),
+        ('\u{2f88}', 31777), ('\u{2f89}', 31809), ('\u{2f8a}', 31841), ('\u{2f8b}', 31873),
+        ('\u{2f8c}', 31905), ('\u{2f8d}', 31937), ('\u{2f8e}', 31969), ('\u{2f8f}', 32001),
+        ('\u{2f90}', 32033), ('\u{2f91}', 32065), ('\u{2f92}', 32097), ('\u{2f93}', 32129),
+        ('\u{2f94}', 32161), ('\u{2f95}', 32193), ('\u{2f96}', 32225), ('\u{2f97}', 32257),
+        ('\u{2f98}', 32289), ('\u{2f99}', 32321), ('\u{2f9a}', 32353), ('\u{2f9b}', 32385),
+        ('\u{2f9c}', 32417), ('\u{2f9d}', 32449), ('\u{2f9e}', 32481), ('\u{2f9f}', 32513),
+        ('\u{2fa0}', 32545), ('\u{2fa1}', 32577), ('\u{2fa2}', 32609), ('\u{2fa3}', 32641),
+        ('\u{2fa4}', 32673), ('\u{2fa5}', 32705), ('\u{2fa6}', 32737), ('\u{2fa7}', 32769),
+        ('\u{2fa8}', 32801), ('\u{2fa9}', 32833), ('\u{2faa}', 32865), ('\u{2fab}', 32897),
+        ('\u{2fac}', 32929), ('\u{2fad}', 32961), ('\u{2fae}', 32993), ('\u{2faf}', 33025),
+        ('\u{2fb0}', 33057), ('\u{2fb1}', 33089), ('\u{2fb2}', 33121), ('\u{2fb3}', 33153),
+        ('\u{2fb4}', 33185), ('\u{2fb5}', 33217), ('\u{2fb6}', 33249), ('\u{2fb7}', 33281),
+        ('\u{2fb8}', 33313), ('\u{2fb9}', 33345), ('\u{2fba}', 33377), ('\u{2fbb}', 33409),
+        ('\u{2fbc}', 33441), ('\u{2fbd}', 33473), ('\u{2fbe}', 33505), ('\u{2fbf}', 33537),
+        ('\u{2fc0}', 33569), ('\u{2fc1}', 33601), ('\u{2fc2}', 33633), ('\u{2fc3}', 33665),
+        ('\u{2fc4}', 33697), ('\u{2fc5}', 33729), ('\u{2fc6}', 33761), ('\u{2fc7}', 33793),
+        ('\u{2fc8}', 33825), ('\u{2fc9}', 33857), ('\u{2fca}', 33889), ('\u{2fcb}', 33921),
+        ('\u{2fcc}', 33953), ('\u{2fcd}', 33985), ('\u{2fce}', 34017), ('\u{2fcf}', 34049),
+        ('\u{2fd0}', 34081), ('\u{2fd1}', 34113), ('\u{2fd2}', 34145), ('\u{2fd3}', 34177),
+        ('\u{2fd4}', 34209), ('\u{2fd5}', 34241), ('\u{3000}', 34273), ('\u{3036}', 34305),
+        ('\u{3038}', 34337), ('\u{3039}', 34369), ('\u{303a}', 34401), ('\u{309b}', 34434),
+        ('\u{309c}', 34498), ('\u{309f}', 34562), ('\u{30ff}', 34626), ('\u{3131}', 34689),
+        ('\u{3132}', 34721), ('\u{3133}', 34753), ('\u{3134}', 34785), ('\u{3135}', 34817),
+        ('\u{3136}', 34849), ('\u{3137}', 34881), ('\u{3138}', 34913), ('\u{3139}', 34945),
+        ('\u{313a}', 34977), ('\u{313b}', 35009), ('\u{313c}', 35041), ('\u{313d}', 35073),
+        ('\u{313e}', 35105), ('\u{313f}', 35137), ('\u{3140}', 35169), ('\u{3141}', 35201),
+        ('\u{3142}', 35233), ('\u{3143}', 35265), ('\u{3144}', 35297), ('\u{3145}', 35329),
+        ('\u{3146}', 35361), ('\u{3147}', 35393), ('\u{3148}', 35425), ('\u{3149}', 35457),
+        ('\u{314a}', 35489), ('\u{314b}', 35521), ('\u{314c}', 35553), ('\u{314d}', 35585),
+        ('\u{314e}', 35617), ('\u{314f}', 35649), ('\u{3150}', 35681), ('\u{3151}', 35713),
+        ('\u{3152}', 35745), ('\u{3153}', 35777), ('\u{3154}', 35809), ('\u{3155}', 35841),
+        ('\u{3156}', 35873), ('\u{3157}', 35905), ('\u{3158}', 35937), ('\u{3159}', 35969),
+        ('\u{315a}', 36001), ('\u{315b}', 36033), ('\u{315c}', 36065), ('\u{315d}', 36097),
+        ('\u{315e}', 36129), ('\u{315f}', 36161), ('\u{3160}', 36193), ('\u{3161}', 36225),
+        ('\u{3162}', 36257), ('\u{3163}', 36289), ('\u{3164}', 36321), ('\u{3165}', 36353),
+        ('\u{3166}', 36385), ('\u{3167}', 36417), ('\u{3168}', 36449), ('\u{3169}', 36481),
+        ('\u{316a}', 36513), ('\u{316b}', 36545), ('\u{316c}', 36577), ('\u{316d}', 36609),
+        ('\u{316e}', 36641), ('\u{316f}', 36673), ('\u{3170}', 36705), ('\u{3171}', 36737),
+        ('\u{3172}', 36769), ('\u{3173}', 36801), ('\u{3174}', 36833), ('\u{3175}', 36865),
+        ('\u{3176}', 36897), ('\u{3177}', 36929), ('\u{3178}', 36961), ('\u{3179}', 36993),
+        ('\u{317a}', 37025), ('\u{317b}', 37057), ('\u{317c}', 37089), ('\u{317d}', 37121),
+        ('\u{317e}', 37153), ('\u{317f}', 37185), ('\u{3180}', 37217), ('\u{3181}', 37249),
+        ('\u{3182}', 37281), ('\u{3183}', 37313), ('\u{3184}', 37345), ('\u{3185}', 37377),
+        ('\u{3186}', 37409), ('\u{3187}', 37441), ('\u{3188}', 37473), ('\u{3189}', 37505),
+        ('\u{318a}', 37537), ('\u{318b}', 37569), ('\u{318c}', 37601), ('\u{318d}', 37633),
+        ('\u{318e}', 37665), ('\u{3192}', 37697), ('\u{3193}', 37729), ('\u{3194}', 37761),
+        ('\u{3195}', 37793), ('\u{3196}', 37825), ('\u{3197}', 37857), ('\u{3198}', 37889),
+        ('\u{3199}', 37921), ('\u{319a}', 37953), ('\u{319b}', 37985), ('\u{319c}', 38017),
+        ('\u{319d}', 38049), ('\u{319e}', 38081), ('\u{319f}', 38113), ('\u{3200}', 38147),
+        ('\u{3201}', 38243), ('\u{3202}', 38339), ('\u{3203}', 38435), ('\u{3204}', 38531),
+        ('\u{3205}', 38627), ('\u{3206}', 38723), ('\u{3207}', 38819), ('\u{3208}', 38915),
+        ('\u{3209}', 39011), ('\u{320a}', 39107), ('\u{320b}', 39203), ('\u{320c}', 39299),
+        ('\u{320d}', 39395), ('\u{320e}', 39492), ('\u{320f}', 39620), ('\u{3210}', 39748),
+        ('\u{3211}', 39876), ('\u{3212}', 40004), ('\u{3213}', 40132), ('\u{3214}', 40260),
+        ('\u{3215}', 40388), ('\u{3216}', 40516), ('\u{3217}', 40644), ('\u{3218}', 40772),
+        ('\u{3219}', 40900), ('\u{321a}', 41028), ('\u{321b}', 41156), ('\u{321c}', 41284),
+        ('\u{321d}', 41415), ('\u{321e}', 41638), ('\u{3220}', 41827), ('\u{3221}', 41923),
+        ('\u{3222}', 42019), ('\u{3223}', 42115), ('\u{3224}', 42211), ('\u{3225}', 42307),
+        ('\u{3226}', 42403), ('\u{3227}', 42499), ('\u{3228}', 42595), ('\u{3229}', 42691),
+        ('\u{322a}', 42787), ('\u{322b}', 42883), ('\u{322c}', 42979), ('\u{322d}', 43075),
+        ('\u{322e}', 43171), ('\u{322f}', 43267), ('\u{3230}', 43363), ('\u{3231}', 43459),
+        ('\u{3232}', 43555), ('\u{3233}', 43651), ('\u{3234}', 43747), ('\u{3235}', 43843),
+        ('\u{3236}', 43939), ('\u{3237}', 44035), ('\u{3238}', 44131), ('\u{3239}', 44227),
+        ('\u{323a}', 44323), ('\u{323b}', 44419), ('\u{323c}', 44515), ('\u{323d}', 44611),
+        ('\u{323e}', 44707), ('\u{323f}', 44803), ('\u{3240}', 44899), ('\u{3241}', 44995),
+        ('\u{3242}', 45091), ('\u{3243}', 45187), ('\u{3244}', 45281), ('\u{3245}', 45313),
+        ('\u{3246}', 45345), ('\u{3247}', 45377), ('\u{3250}', 45411), ('\u{3251}', 45506),
+        ('\u{3252}', 45570), ('\u{3253}', 45634), ('\u{3254}', 45698), ('\u{3255}', 45762),
+        ('\u{3256}', 45826), ('\u{3257}', 45890), ('\u{3258}', 45954), ('\u{3259}', 46018),
+        ('\u{325a}', 46082), ('\u{325b}', 46146), ('\u{325c}', 46210), ('\u{325d}', 46274),
+        ('\u{325e}', 46338), ('\u{325f}', 46402), ('\u{3260}', 46465), ('\u{3261}', 46497),
+        ('\u{3262}', 46529), ('\u{3263}', 46561), ('\u{3264}', 46593), ('\u{3265}', 46625),
+        ('\u{3266}', 46657), ('\u{3267}', 46689), ('\u{3268}', 46721), ('\u{3269}', 46753),
+        ('\u{326a}', 46785), ('\u{326b}', 46817), ('\u{326c}', 46849), ('\u{326d}', 46881),
+        ('\u{326e}', 46914), ('\u{326f}', 46978), ('\u{3270}', 47042), ('\u{3271}', 47106),
+        ('\u{3272}', 47170), ('\u{3273}', 47234), ('\u{3274}', 47298), ('\u{3275}', 47362),
+        ('\u{3276}', 47426), ('\u{3277}', 47490), ('\u{3278}', 47554), ('\u{3279}', 47618),
+        ('\u{327a}', 47682), ('\u{327b}', 47746), ('\u{327c}', 47813), ('\u{327d}', 47972),
+        ('\u{327e}', 48098), ('\u{3280}', 48161), ('\u{3281}', 48193), ('\u{3282}', 48225),
+        ('\u{3283}', 48257), ('\u{3284}', 48289), ('\u{3285}', 48321), ('\u{3286}', 48353),
+        ('\u{3287}', 48385), ('\u{3288}', 48417), ('\u{3289}', 48449), ('\u{328a}', 48481),
+        ('\u{328b}', 48513), ('\u{328c}', 48545), ('\u{328d}', 48577), ('\u{328e}', 48609),
+        ('\u{328f}', 48641), ('\u{3290}', 48673), ('\u{3291}', 48705), ('\u{3292}', 48737),
+        ('\u{3293}', 48769), ('\u{3294}', 48801), ('\u{3295}', 48833), ('\u{3296}', 48865),
+        ('\u{3297}', 48897), ('\u{3298}', 48929), ('\u{3299}', 48961), ('\u{329a}', 48993),
+        ('\u{329b}', 49025), ('\u{329c}', 49057), ('\u{329d}', 49089), ('\u{329e}', 49121),
+        ('\u{329f}', 49153), ('\u{32a0}', 49185), ('\u{32a1}', 49217), ('\u{32a2}', 49249),
+        ('\u{32a3}', 49281), ('\u{32a4}', 49313), ('\u{32a5}', 49345), ('\u{32a6}', 49377),
+        ('\u{32a7}', 49409), ('\u{32a8}', 49441), ('\u{32a9}', 49473), ('\u{32aa}', 49505),
+        ('\u{32ab}', 49537), ('\u{32ac}', 49569), ('\u{32ad}', 49601), ('\u{32ae}', 49633),
+        ('\u{32af}', 49665), ('\u{32b0}', 49697), ('\u{32b1}', 49730), ('\u{32b2}', 49794),
+        ('\u{32b3}', 49858), ('\u{32b4}', 49922), ('\u{32b5}', 49986), ('\u{32b6}', 50050),
+        ('\u{32b7}', 50114), ('\u{32b8}', 50178), ('\u{32b9}', 50242), ('\u{32ba}', 50306),
+        ('\u{32bb}', 50370), ('\u{32bc}', 50434), ('\u{32bd}', 50498), ('\u{32be}', 50562),
+        ('\u{32bf}', 50626), ('\u{32c0}', 50690), ('\u{32c1}', 50754), ('\u{32c2}', 50818),
+        ('\u{32c3}', 50882), ('\u{32c4}', 50946), ('\u{32c5}', 51010), ('\u{32c6}', 51074),
+        ('\u{32c7}', 51138), ('\u{32c8}', 51202), ('\u{32c9}', 51267), ('\u{32ca}', 51363),
+        ('\u{32cb}', 51459), ('\u{32cc}', 51554), ('\u{32cd}', 51619), ('\u{32ce}', 51714),
+        ('\u{32cf}', 51779), ('\u{32d0}', 51873), ('\u{32d1}', 51905), ('\u{32d2}', 51937),
+        ('\u{32d3}', 51969), ('\u{32d4}', 52001), ('\u{32d5}', 52033), ('\u{32d6}', 52065),
+        ('\u{32d7}', 52097), ('\u{32d8}', 52129), ('\u{32d9}', 52161), ('\u{32da}', 52193),
+        ('\u{32db}', 52225), ('\u{32dc}', 52257), ('\u{32dd}', 52289), ('\u{32de}', 52321),
+        ('\u{32df}', 52353), ('\u{32e0}', 52385), ('\u{32e1}', 52417), ('\u{32e2}', 52449),
+        ('\u{32e3}', 52481), ('\u{32e4}', 52513), ('\u{32e5}', 52545), ('\u{32e6}', 52577),
+        ('\u{32e7}', 52609), ('\u{32e8}', 52641), ('\u{32e9}', 52673), ('\u{32ea}', 52705),
+        ('\u{32eb}', 52737), ('\u{32ec}', 52769), ('\u{32ed}', 52801), ('\u{32ee}', 52833),
+        ('\u{32ef}', 52865), ('\u{32f0}', 52897), ('\u{32f1}', 52929), ('\u{32f2}', 52961),
+        ('\u{32f3}', 52993), ('\u{32f4}', 53025), ('\u{32f5}', 53057), ('\u{32f6}', 53089),
+        ('\u{32f7}', 53121), ('\u{32f8}', 53153), ('\u{32f9}', 53185), ('\u{32fa}', 53217),
+        ('\u{32fb}', 53249), ('\u{32fc}', 53281), ('\u{32fd}', 53313), ('\u{32fe}', 53345),
+        ('\u{32ff}', 53378), ('\u{3300}', 53445), ('\u{3301}', 53604), ('\u{3302}', 53733),
+        ('\u{3303}', 53891), ('\u{3304}', 53989), ('\u{3305}', 54147), ('\u{3306}', 54243),
+        ('\u{3307}', 54342), ('\u{3308}', 54532), ('\u{3309}', 54659), ('\u{330a}', 54755),
+        ('\u{330b}', 54851), ('\u{330c}', 54948), ('\u{330d}', 55076), ('\u{330e}', 55204),
+        ('\u{330f}', 55332), ('\u{3310}', 55460), ('\u{3311}', 55588), ('\u{3312}', 55716),
+        ('\u{3313}', 55846), ('\u{3314}', 56034), ('\u{3315}', 56102), ('\u{3316}', 56294),
+        ('\u{3317}', 56485), ('\u{3318}', 56644), ('\u{3319}', 56774), ('\u{331a}', 56966),
+        ('\u{331b}', 57156), ('\u{331c}', 57283), ('\u{331d}', 57379), ('\u{331e}', 57476),
+        ('\u{331f}', 57604), ('\u{3320}', 57733), ('\u{3321}', 57893), ('\u{3322}', 58051),
+        ('\u{3323}', 58147), ('\u{3324}', 58244), ('\u{3325}', 58371), ('\u{3326}', 58467),
+        ('\u{3327}', 58562), ('\u{3328}', 58626), ('\u{3329}', 58691), ('\u{332a}', 58787),
+        ('\u{332b}', 58886), ('\u{332c}', 59076), ('\u{332d}', 59205), ('\u{332e}', 59366),
+        ('\u{332f}', 59556), ('\u{3330}', 59683), ('\u{3331}', 59779), ('\u{3332}', 59878),
+        ('\u{3333}', 60068), ('\u{3334}', 60198), ('\u{3335}', 60387), ('\u{3336}', 60485),
+        ('\u{3337}', 60643), ('\u{3338}', 60740), ('\u{3339}', 60867), ('\u{333a}', 60964),
+        ('\u{333b}', 61093), ('\u{333c}', 61252), ('\u{333d}', 61381), ('\u{333e}', 61540),
+        ('\u{333f}', 61666), ('\u{3340}', 61733), ('\u{3341}', 61891), ('\u{3342}', 61987),
+        ('\u{3343}', 62084), ('\u{3344}', 62211), ('\u{3345}', 62307), ('\u{3346}', 62403),
+        ('\u{3347}', 62501), ('\u{3348}', 62660), ('\u{3349}', 62786), ('\u{334a}', 62854),
+        ('\u{334b}', 63043), ('\u{334c}', 63141), ('\u{334d}', 63300), ('\u{334e}', 63428),
+        ('\u{334f}', 63555), ('\u{3350}', 63651), ('\u{3351}', 63748), ('\u{3352}', 63874),
+        ('\u{3353}', 63940), ('\u{3354}', 64069), ('\u{3355}', 64226), ('\u{3356}', 64294),
+        ('\u{3357}', 64483), ('\u{3358}', 64578), ('\u{3359}', 64642), ('\u{335a}', 64706),
+        ('\u{335b}', 64770), ('\u{335c}', 64834), ('\u{335d}', 64898), ('\u{335e}', 64962),
+        ('\u{335f}', 65026), ('\u{3360}', 65090), ('\u{3361}', 65154), ('\u{3362}', 65219),
+        ('\u{3363}', 65315), ('\u{3364}', 65411), ('\u{3365}', 65507), ('\u{3366}', 65603),
+        ('\u{3367}', 65699), ('\u{3368}', 65795), ('\u{3369}', 65891), ('\u{336a}', 65987),
+        ('\u{336b}', 66083), ('\u{336c}', 66179), ('\u{336d}', 66275), ('\u{336e}', 66371),
+        ('\u{336f}', 66467), ('\u{3370}', 66563), ('\u{3371}', 66659), ('\u{3372}', 66754),
+        ('\u{3373}', 66818), ('\u{3374}', 66883), ('\u{3375}', 66978), ('\u{3376}', 67042),
+        ('\u{3377}', 67106), ('\u{3378}', 67171), ('\u{3379}', 67267), ('\u{337a}', 67362),
+        ('\u{337b}', 67426), ('\u{337c}', 67490), ('\u{337d}', 67554), ('\u{337e}', 67618),
+        ('\u{337f}', 67684), ('\u{3380}', 67810), ('\u{3381}', 67874), ('\u{3382}', 67938),
+        ('\u{3383}', 68002), ('\u{3384}', 68066), ('\u{3385}', 68130), ('\u{3386}', 68194),
+        ('\u{3387}', 68258), ('\u{3388}', 68323), ('\u{3389}', 68420), ('\u{338a}', 68546),
+        ('\u{338b}', 68610), ('\u{338c}', 68674), ('\u{338d}', 68738), ('\u{338e}', 68802),
+        ('\u{338f}', 68866), ('\u{3390}', 68930), ('\u{3391}', 68995), ('\u{3392}', 69091),
+        ('\u{3393}', 69187), ('\u{3394}', 69283), ('\u{3395}', 69378), ('\u{3396}', 69442),
+        ('\u{3397}', 69506), ('\u{3398}', 69570), ('\u{3399}', 69634), ('\u{339a}', 69698),
+        ('\u{339b}', 69762), ('\u{339c}', 69826), ('\u{339d}', 69890), ('\u{339e}', 69954),
+        ('\u{339f}', 70019), ('\u{33a0}', 70115), ('\u{33a1}', 70210), ('\u{33a2}', 70275),
+        ('\u{33a3}', 70371), ('\u{33a4}', 70467), ('\u{33a5}', 70562), ('\u{33a6}', 70627),
+        ('\u{33a7}', 70723), ('\u{33a8}', 70820), ('\u{33a9}', 70946), ('\u{33aa}', 71011),
+        ('\u{33ab}', 71107), ('\u{33ac}', 71203), ('\u{33ad}', 71299), ('\u{33ae}', 71397),
+        ('\u{33af}', 71558), ('\u{33b0}', 71746), ('\u{33b1}', 71810), ('\u{33b2}', 71874),
+        ('\u{33b3}', 71938), ('\u{33b4}', 72002), ('\u{33b5}', 72066), ('\u{33b6}', 72130),
+        ('\u{33b7}', 72194), ('\u{33b8}', 72258), ('\u{33b9}', 72322), ('\u{33ba}', 72386),
+        ('\u{33bb}', 72450), ('\u{33bc}', 72514), ('\u{33bd}', 72578), ('\u{33be}', 72642),
+        ('\u{33bf}', 72706), ('\u{33c0}', 72770), ('\u{33c1}', 72834), ('\u{33c2}', 72900),
+        ('\u{33c3}', 73026), ('\u{33c4}', 73090), ('\u{33c5}', 73154), ('\u{33c6}', 73220),
+        ('\u{33c7}', 73347), ('\u{33c8}', 73442), ('\u{33c9}', 73506), ('\u{33ca}', 73570),
+        ('\u{33cb}', 73634), ('\u{33cc}', 73698), ('\u{33cd}', 73762), ('\u{33ce}', 73826),
+        ('\u{33cf}', 73890), ('\u{33d0}', 73954), ('\u{33d1}', 74018), ('\u{33d2}', 74083),
+        ('\u{33d3}', 74178), ('\u{33d4}', 74242), ('\u{33d5}', 74307), ('\u{33d6}', 74403),
+        ('\u{33d7}', 74498), ('\u{33d8}', 74564), ('\u{33d9}', 74691), ('\u{33da}', 74786),
+        ('\u{33db}', 74850), ('\u{33dc}', 74914), ('\u{33dd}', 74978), ('\u{33de}', 75043),
+        ('\u{33df}', 75139), ('\u{33e0}', 75234), ('\u{33e1}', 75298), ('\u{33e2}', 75362),
+        ('\u{33e3}', 75426), ('\u{33e4}', 75490), ('\u{33e5}', 75554), ('\u{33e6}', 75618),
+        ('\u{33e7}', 75682), ('\u{33e8}', 75746), ('\u{33e9}', 75811), ('\u{33ea}', 75907),
+        ('\u{33eb}', 76003), ('\u{33ec}', 76099), ('\u{33ed}', 76195), ('\u{33ee}', 76291),
+        ('\u{33ef}', 76387), ('\u{33f0}', 76483), ('\u{33f1}', 76579), ('\u{33f2}', 76675),
+        ('\u{33f3}', 76771), ('\u{33f4}', 76867), ('\u{33f5}', 76963), ('\u{33f6}', 77059),
+        ('\u{33f7}', 77155), ('\u{33f8}', 77251), ('\u{33f9}', 77347), ('\u{33fa}', 77443),
+        ('\u{33fb}', 77539), ('\u{33fc}', 77635), ('\u{33fd}', 77731), ('\u{33fe}', 77827),
+        ('\u{33ff}', 77923), ('\u{a69c}', 78017), ('\u{a69d}', 78049), ('\u{a770}', 78081),
+        ('\u{a7f2}', 78113), ('\u{a7f3}', 78145), ('\u{a7f4}', 78177), ('\u{a7f8}', 78209),
+        ('\u{a7f9}', 78241), ('\u{ab5c}', 78273), ('\u{ab5d}', 78305), ('\u{ab5e}', 78337),
+        ('\u{ab5f}', 78369), ('\u{ab69}', 78401), ('\u{fb00}', 78434), ('\u{fb01}', 78498),
+        ('\u{fb02}', 78562), ('\u{fb03}', 78627), ('\u{fb04}', 78723), ('\u{fb05}', 78818),
+        ('\u{fb06}', 78882), ('\u{fb13}', 78946), ('\u{fb14}', 79010), ('\u{fb15}', 79074),
+        ('\u{fb16}', 79138), ('\u{fb17}', 79202), ('\u{fb20}', 79265), ('\u{fb21}', 79297),
+        ('\u{fb22}', 79329), ('\u{fb23}', 79361), ('\u{fb24}', 79393), ('\u{fb25}', 79425),
+        ('\u{fb26}', 79457), ('\u{fb27}', 79489), ('\u{fb28}', 79521), ('\u{fb29}', 79553),
+        ('\u{fb4f}', 79586), ('\u{fb50}', 79649), ('\u{fb51}', 79681), ('\u{fb52}', 79713),
+        ('\u{fb53}', 79745), ('\u{fb54}', 79777), ('\u{fb55}', 79809), ('\u{fb56}', 79841),
+        ('\u{fb57}', 79873), ('\u{fb58}', 79905), ('\u{fb59}', 79937), ('\u{fb5a}', 79969),
+        ('\u{fb5b}', 80001), ('\u{fb5c}', 80033), ('\u{fb5d}', 80065), ('\u{fb5e}', 80097),
+        ('\u{fb5f}', 80129), ('\u{fb60}', 80161), ('\u{fb61}', 80193), ('\u{fb62}', 80225),
+        ('\u{fb63}', 80257), ('\u{fb64}', 80289), ('\u{fb65}', 80321), ('\u{fb66}', 80353),
+        ('\u{fb67}', 80385), ('\u{fb68}', 80417), ('\u{fb69}', 80449), ('\u{fb6a}', 80481),
+        ('\u{fb6b}', 80513), ('\u{fb6c}', 80545), ('\u{fb6d}', 80577), ('\u{fb6e}', 80609),
+        ('\u{fb6f}', 80641), ('\u{fb70}', 80673), ('\u{fb71}', 80705), ('\u{fb72}', 80737),
+        ('\u{fb73}', 80769), ('\u{fb74}', 80801), ('\u{fb75}', 80833), ('\u{fb76}', 80865),
+        ('\u{fb77}', 80897), ('\u{fb78}', 80929), ('\u{fb79}', 80961), ('\u{fb7a}', 80993),
+        ('\u{fb7b}', 81025), ('\u{fb7c}', 81057), ('\u{fb7d}', 81089), ('\u{fb7e}', 81121),
+        ('\u{fb7f}', 81153), ('\u{fb80}', 81185), ('\u{fb81}', 81217), ('\u{fb82}', 81249),
+        ('\u{fb83}', 81281), ('\u{fb84}', 81313), ('\u{fb85}', 81345), ('\u{fb86}', 81377),
+        ('\u{fb87}', 81409), ('\u{fb88}', 81441), ('\u{fb89}', 81473), ('\u{fb8a}', 81505),
+        ('\u{fb8b}', 81537), ('\u{fb8c}', 81569), ('\u{fb8d}', 81601), ('\u{fb8e}', 81633),
+        ('\u{fb8f}', 81665), ('\u{fb90}', 81697), ('\u{fb91}', 81729), ('\u{fb92}', 81761),
+        ('\u{fb93}', 81793), ('\u{fb94}', 81825), ('\u{fb95}', 81857), ('\u{fb96}', 81889),
+        ('\u{fb97}', 81921), ('\u{fb98}', 81953), ('\u{fb99}', 81985), ('\u{fb9a}', 82017),
+        ('\u{fb9b}', 82049), ('\u{fb9c}', 82081), ('\u{fb9d}', 82113), ('\u{fb9e}', 82145),
+        ('\u{fb9f}', 82177), ('\u{fba0}', 82209), ('\u{fba1}', 82241), ('\u{fba2}', 82273),
+        ('\u{fba3}', 82305), ('\u{fba4}', 82338), ('\u{fba5}', 82402), ('\u{fba6}', 82465),
+        ('\u{fba7}', 82497), ('\u{fba8}', 82529), ('\u{fba9}', 82561), ('\u{fbaa}', 82593),
+        ('\u{fbab}', 82625), ('\u{fbac}', 82657), ('\u{fbad}', 82689), ('\u{fbae}', 82721),
+        ('\u{fbaf}', 82753), ('\u{fbb0}', 82786), ('\u{fbb1}', 82850), ('\u{fbd3}', 82913),
+        ('\u{fbd4}', 82945), ('\u{fbd5}', 82977), ('\u{fbd6}', 83009), ('\u{fbd7}', 83041),
+        ('\u{fbd8}', 83073), ('\u{fbd9}', 83105), ('\u{fbda}', 83137), ('\u{fbdb}', 83169),
+        ('\u{fbdc}', 83201), ('\u{fbdd}', 83234), ('\u{fbde}', 83297), ('\u{fbdf}', 83329),
+        ('\u{fbe0}', 83361), ('\u{fbe1}', 83393), ('\u{fbe2}', 83425), ('\u{fbe3}', 83457),
+        ('\u{fbe4}', 83489), ('\u{fbe5}', 83521), ('\u{fbe6}', 83553), ('\u{fbe7}', 83585),
+        ('\u{fbe8}', 83617), ('\u{fbe9}', 83649), ('\u{fbea}', 83683), ('\u{fbeb}', 83779),
+        ('\u{fbec}', 83875), ('\u{fbed}', 83971), ('\u{fbee}', 84067), ('\u{fbef}', 84163),
+        ('\u{fbf0}', 84259), ('\u{fbf1}', 84355), ('\u{fbf2}', 84451), ('\u{fbf3}', 84547),
+        ('\u{fbf4}', 84643), ('\u{fbf5}', 84739), ('\u{fbf6}', 84835), ('\u{fbf7}', 84931),
+        ('\u{fbf8}', 85027), ('\u{fbf9}', 85123), ('\u{fbfa}', 85219), ('\u{fbfb}', 85315),
+        ('\u{fbfc}', 85409), ('\u{fbfd}', 85441), ('\u{fbfe}', 85473), ('\u{fbff}', 85505),
+        ('\u{fc00}', 85539), ('\u{fc01}', 85635), ('\u{fc02}', 85731), ('\u{fc03}', 85827),
+        ('\u{fc04}', 85923), ('\u{fc05}', 86018), ('\u{fc06}', 86082), ('\u{fc07}', 86146),
+        ('\u{fc08}', 86210), ('\u{fc09}', 86274), ('\u{fc0a}', 86338), ('\u{fc0b}', 86402),
+        ('\u{fc0c}', 86466), ('\u{fc0d}', 86530), ('\u{fc0e}', 86594), ('\u{fc0f}', 86658),
+        ('\u{fc10}', 86722), ('\u{fc11}', 86786), ('\u{fc12}', 86850), ('\u{fc13}', 86914),
+        ('\u{fc14}', 86978), ('\u{fc15}', 87042), ('\u{fc16}', 87106), ('\u{fc17}', 87170),
+        ('\u{fc18}', 87234), ('\u{fc19}', 87298), ('\u{fc1a}', 87362), ('\u{fc1b}', 87426),
+        ('\u{fc1c}', 87490), ('\u{fc1d}', 87554), ('\u{fc1e}', 87618), ('\u{fc1f}', 87682),
+        ('\u{fc20}', 87746), ('\u{fc21}', 87810), ('\u{fc22}', 87874), ('\u{fc23}', 87938),
+        ('\u{fc24}', 88002), ('\u{fc25}', 88066), ('\u{fc26}', 88130), ('\u{fc27}', 88194),
+        ('\u{fc28}', 88258), ('\u{fc29}', 88322), ('\u{fc2a}', 88386), ('\u{fc2b}', 88450),
+        ('\u{fc2c}', 88514), ('\u{fc2d}', 88578), ('\u{fc2e}', 88642), ('\u{fc2f}', 88706),
+        ('\u{fc30}', 88770), ('\u{fc31}', 88834), ('\u{fc32}', 88898), ('\u{fc33}', 88962),
+        ('\u{fc34}', 89026), ('\u{fc35}', 89090), ('\u{fc36}', 89154), ('\u{fc37}', 89218),
+        ('\u{fc38}', 89282), ('\u{fc39}', 89346), ('\u{fc3a}', 89410), ('\u{fc3b}', 89474),
+        ('\u{fc3c}', 89538), ('\u{fc3d}', 89602), ('\u{fc3e}', 89666), ('\u{fc3f}', 89730),
+        ('\u{fc40}', 89794), ('\u{fc41}', 89858), ('\u{fc42}', 89922), ('\u{fc43}', 89986),
+        ('\u{fc44}', 90050), ('\u{fc45}', 90114), ('\u{fc46}', 90178), ('\u{fc47}', 90242),
+        ('\u{fc48}', 90306), ('\u{fc49}', 90370), ('\u{fc4a}', 90434), ('\u{fc4b}', 90498),
+        ('\u{fc4c}', 90562), ('\u{fc4d}', 90626), ('\u{fc4e}', 90690), ('\u{fc4f}', 90754),
+        ('\u{fc50}', 90818), ('\u{fc51}', 90882), ('\u{fc52}', 90946), ('\u{fc53}', 91010),
+        ('\u{fc54}', 91074), ('\u{fc55}', 91138), ('\u{fc56}', 91202), ('\u{fc57}', 91266),
+        ('\u{fc58}', 91330), ('\u{fc59}', 91394), ('\u{fc5a}', 91458), ('\u{fc5b}', 91522),
+        ('\u{fc5c}', 91586), ('\u{fc5d}', 91650), ('\u{fc5e}', 91715), ('\u{fc5f}', 91811),
+        ('\u{fc60}', 91907), ('\u{fc61}', 92003), ('\u{fc62}', 92099), ('\u{fc63}', 92195),
+        ('\u{fc64}', 92291), ('\u{fc65}', 92387), ('\u{fc66}', 92483), ('\u{fc67}', 92579),
+        ('\u{fc68}', 92675), ('\u{fc69}', 92771), ('\u{fc6a}', 92866), ('\u{fc6b}', 92930),
+        ('\u{fc6c}', 92994), ('\u{fc6d}', 93058), ('\u{fc6e}', 93122), ('\u{fc6f}', 93186),
+        ('\u{fc70}', 93250), ('\u{fc71}', 93314), ('\u{fc72}', 93378), ('\u{fc73}', 93442),
+        ('\u{fc74}', 93506), ('\u{fc75}', 93570), ('\u{fc76}', 93634), ('\u{fc77}', 93698),
+        ('\u{fc78}', 93762), ('\u{fc79}', 93826), ('\u{fc7a}', 93890), ('\u{fc7b}', 93954),
+        ('\u{fc7c}', 94018), ('\u{fc7d}', 94082), ('\u{fc7e}', 94146), ('\u{fc7f}', 94210),
+        ('\u{fc80}', 94274), ('\u{fc81}', 94338), ('\u{fc82}', 94402), ('\u{fc83}', 94466),
+        ('\u{fc84}', 94530), ('\u{fc85}', 94594), ('\u{fc86}', 94658), ('\u{fc87}', 94722),
+        ('\u{fc88}', 94786), ('\u{fc89}', 94850), ('\u{fc8a}', 94914), ('\u{fc8b}', 94978),
+        ('\u{fc8c}', 95042), ('\u{fc8d}', 95106), ('\u{fc8e}', 95170), ('\u{fc8f}', 95234),
+        ('\u{fc90}', 95298), ('\u{fc91}', 95362), ('\u{fc92}', 95426), ('\u{fc93}', 95490),
+        ('\u{fc94}', 95554), ('\u{fc95}', 95618), ('\u{fc96}', 95682), ('\u{fc97}', 95747),
+        ('\u{fc98}', 95843), ('\u{fc99}', 95939), ('\u{fc9a}', 96035), ('\u{fc9b}', 96131),
+        ('\u{fc9c}', 96226), ('\u{fc9d}', 96290), ('\u{fc9e}', 96354), ('\u{fc9f}', 96418),
+        ('\u{fca0}', 96482), ('\u{fca1}', 96546), ('\u{fca2}', 96610), ('\u{fca3}', 96674),
+        ('\u{fca4}', 96738), ('\u{fca5}', 96802), ('\u{fca6}', 96866), ('\u{fca7}', 96930),
+        ('\u{fca8}', 96994), ('\u{fca9}', 97058), ('\u{fcaa}', 97122), ('\u{fcab}', 97186),
+        ('\u{fcac}', 97250), ('\u{fcad}', 97314), ('\u{fcae}', 97378), ('\u{fcaf}', 97442),
+        ('\u{fcb0}', 97506), ('\u{fcb1}', 97570), ('\u{fcb2}', 97634), ('\u{fcb3}', 97698),
+        ('\u{fcb4}', 97762), ('\u{fcb5}', 97826), ('\u{fcb6}', 97890), ('\u{fcb7}', 97954),
+        ('\u{fcb8}', 98018), ('\u{fcb9}', 98082), ('\u{fcba}', 98146), ('\u{fcbb}', 98210),
+        ('\u{fcbc}', 98274), ('\u{fcbd}', 98338), ('\u{fcbe}', 98402), ('\u{fcbf}', 98466),
+        ('\u{fcc0}', 98530), ('\u{fcc1}', 98594), ('\u{fcc2}', 98658), ('\u{fcc3}', 98722),
+        ('\u{fcc4}', 98786), ('\u{fcc5}', 98850), ('\u{fcc6}', 98914), ('\u{fcc7}', 98978),
+        ('\u{fcc8}', 99042), ('\u{fcc9}', 99106), ('\u{fcca}', 99170), ('\u{fccb}', 99234),
+        ('\u{fccc}', 99298), ('\u{fccd}', 99362), ('\u{fcce}', 99426), ('\u{fccf}', 99490),
+        ('\u{fcd0}', 99554), ('\u{fcd1}', 99618), ('\u{fcd2}', 99682), ('\u{fcd3}', 99746),
+        ('\u{fcd4}', 99810), ('\u{fcd5}', 99874), ('\u{fcd6}', 99938), ('\u{fcd7}', 100002),
+        ('\u{fcd8}', 100066), ('\u{fcd9}', 100130), ('\u{fcda}', 100194), ('\u{fcdb}', 100258),
+        ('\u{fcdc}', 100322), ('\u{fcdd}', 100386), ('\u{fcde}', 100450), ('\u{fcdf}', 100515),
+        ('\u{fce0}', 100611), ('\u{fce1}', 100706), ('\u{fce2}', 100770), ('\u{fce3}', 100834),
+        ('\u{fce4}', 100898), ('\u{fce5}', 100962), ('\u{fce6}', 101026), ('\u{fce7}', 101090),
+        ('\u{fce8}', 101154), ('\u{fce9}', 101218), ('\u{fcea}', 101282), ('\u{fceb}', 101346),
+        ('\u{fcec}', 101410), ('\u{fced}', 101474), ('\u{fcee}', 101538), ('\u{fcef}', 101602),
+        ('\u{fcf0}', 101666), ('\u{fcf1}', 101730), ('\u{fcf2}', 101795), ('\u{fcf3}', 101891),
+        ('\u{fcf4}', 101987), ('\u{fcf5}', 102082), ('\u{fcf6}', 102146), ('\u{fcf7}', 102210),
+        ('\u{fcf8}', 102274), ('\u{fcf9}', 102338), ('\u{fcfa}', 102402), ('\u{fcfb}', 102466),
+        ('\u{fcfc}', 102530), ('\u{fcfd}', 102594), ('\u{fcfe}', 102658), ('\u{fcff}', 102722),
+        ('\u{fd00}', 102786), ('\u{fd01}', 102850), ('\u{fd02}', 102914), ('\u{fd03}', 102978),
+        ('\u{fd04}', 103042), ('\u{fd05}', 103106), ('\u{fd06}', 103170), ('\u{fd07}', 103234),
+        ('\u{fd08}', 103298), ('\u{fd09}', 103362), ('\u{fd0a}', 103426), ('\u{fd0b}', 103490),
+        ('\u{fd0c}', 103554), ('\u{fd0d}', 103618), ('\u{fd0e}', 103682), ('\u{fd0f}', 103746),
+        ('\u{fd10}', 103810), ('\u{fd11}', 103874), ('\u{fd12}', 103938), ('\u{fd13}', 104002),
+        ('\u{fd14}', 104066), ('\u{fd15}', 104130), ('\u{fd16}', 104194), ('\u{fd17}', 104258),
+        ('\u{fd18}', 104322), ('\u{fd19}', 104386), ('\u{fd1a}', 104450), ('\u{fd1b}', 104514),
+        ('\u{fd1c}', 104578), ('\u{fd1d}', 104642), ('\u{fd1e}', 104706), ('\u{fd1f}', 104770),
+        ('\u{fd20}', 104834), ('\u{fd21}', 104898), ('\u{fd22}', 104962), ('\u{fd23}', 105026),
+        ('\u{fd24}', 105090), ('\u{fd25}', 105154), ('\u{fd26}', 105218), ('\u{fd27}', 105282),
+        ('\u{fd28}', 105346), ('\u{fd29}', 105410), ('\u{fd2a}', 105474), ('\u{fd2b}', 105538),
+        ('\u{fd2c}', 105602), ('\u{fd2d}', 105666), ('\u{fd2e}', 105730), ('\u{fd2f}', 105794),
+        ('\u{fd30}', 105858), ('\u{fd31}', 105922), ('\u{fd32}', 105986), ('\u{fd33}', 106050),
+        ('\u{fd34}', 106114), ('\u{fd35}', 106178), ('\u{fd36}', 106242), ('\u{fd37}', 106306),
+        ('\u{fd38}', 106370), ('\u{fd39}', 106434), ('\u{fd3a}', 106498), ('\u{fd3b}', 106562),
+        ('\u{fd3c}', 106626), ('\u{fd3d}', 106690), ('\u{fd50}', 106755), ('\u{fd51}', 106851),
+        ('\u{fd52}', 106947), ('\u{fd53}', 107043), ('\u{fd54}', 107139), ('\u{fd55}', 107235),
+        ('\u{fd56}', 107331), ('\u{fd57}', 107427), ('\u{fd58}', 107523), ('\u{fd59}', 107619),
+        ('\u{fd5a}', 107715), ('\u{fd5b}', 107811), ('\u{fd5c}', 107907), ('\u{fd5d}', 108003),
+        ('\u{fd5e}', 108099), ('\u{fd5f}', 108195), ('\u{fd60}', 108291), ('\u{fd61}', 108387),
+        ('\u{fd62}', 108483), ('\u{fd63}', 108579), ('\u{fd64}', 108675), ('\u{fd65}', 108771),
+        ('\u{fd66}', 108867), ('\u{fd67}', 108963), ('\u{fd68}', 109059), ('\u{fd69}', 109155),
+        ('\u{fd6a}', 109251), ('\u{fd6b}', 109347), ('\u{fd6c}', 109443), ('\u{fd6d}', 109539),
+        ('\u{fd6e}', 109635), ('\u{fd6f}', 109731), ('\u{fd70}', 109827), ('\u{fd71}', 109923),
+        ('\u{fd72}', 110019), ('\u{fd73}', 110115), ('\u{fd74}', 110211), ('\u{fd75}', 110307),
+        ('\u{fd76}', 110403), ('\u{fd77}', 110499), ('\u{fd78}', 110595), ('\u{fd79}', 110691),
+        ('\u{fd7a}', 110787), ('\u{fd7b}', 110883), ('\u{fd7c}', 110979), ('\u{fd7d}', 111075),
+        ('\u{fd7e}', 111171), ('\u{fd7f}', 111267), ('\u{fd80}', 111363), ('\u{fd81}', 111459),
+        ('\u{fd82}', 111555), ('\u{fd83}', 111651), ('\u{fd84}', 111747), ('\u{fd85}', 111843),
+        ('\u{fd86}', 111939), ('\u{fd87}', 112035), ('\u{fd88}', 112131), ('\u{fd89}', 112227),
+        ('\u{fd8a}', 112323), ('\u{fd8b}', 112419), ('\u{fd8c}', 112515), ('\u{fd8d}', 112611),
+        ('\u{fd8e}', 112707), ('\u{fd8f}', 112803), ('\u{fd92}', 112899), ('\u{fd93}', 112995),
+        ('\u{fd94}', 113091), ('\u{fd95}', 113187), ('\u{fd96}', 113283), ('\u{fd97}', 113379),
+        ('\u{fd98}', 113475), ('\u{fd99}', 113571), ('\u{fd9a}', 113667), ('\u{fd9b}', 113763),
+        ('\u{fd9c}', 113859), ('\u{fd9d}', 113955), ('\u{fd9e}', 114051), ('\u{fd9f}', 114147),
+        ('\u{fda0}', 114243), ('\u{fda1}', 114339), ('\u{fda2}', 114435), ('\u{fda3}', 114531),
+        ('\u{fda4}', 114627), ('\u{fda5}', 114723), ('\u{fda6}', 114819), ('\u{fda7}', 114915),
+        ('\u{fda8}', 115011), ('\u{fda9}', 115107), ('\u{fdaa}', 115203), ('\u{fdab}', 115299),
+        ('\u{fdac}', 115395), ('\u{fdad}', 115491), ('\u{fdae}', 115587), ('\u{fdaf}', 115683),
+        ('\u{fdb0}', 115779), ('\u{fdb1}', 115875), ('\u{fdb2}', 115971), ('\u{fdb3}', 116067),
+        ('\u{fdb4}', 116163), ('\u{fdb5}', 116259), ('\u{fdb6}', 116355), ('\u{fdb7}', 116451),
+        ('\u{fdb8}', 116547), ('\u{fdb9}', 116643), ('\u{fdba}', 116739), ('\u{fdbb}', 116835),
+        ('\u{fdbc}', 116931), ('\u{fdbd}', 117027), ('\u{fdbe}', 117123), ('\u{fdbf}', 117219),
+        ('\u{fdc0}', 117315), ('\u{fdc1}', 117411), ('\u{fdc2}', 117507), ('\u{fdc3}', 117603),
+        ('\u{fdc4}', 117699), ('\u{fdc5}', 117795), ('\u{fdc6}', 117891), ('\u{fdc7}', 117987),
+        ('\u{fdf0}', 118083), ('\u{fdf1}', 118179), ('\u{fdf2}', 118276), ('\u{fdf3}', 118404),
+        ('\u{fdf4}', 118532), ('\u{fdf5}', 118660), ('\u{fdf6}', 118788), ('\u{fdf7}', 118916),
+        ('\u{fdf8}', 119044), ('\u{fdf9}', 119171), ('\u{fdfa}', 119282), ('\u{fdfb}', 119848),
+        ('\u{fdfc}', 120100), ('\u{fe10}', 120225), ('\u{fe11}', 120257), ('\u{fe12}', 120289),
+        ('\u{fe13}', 120321), ('\u{fe14}', 120353), ('\u{fe15}', 120385), ('\u{fe16}', 120417),
+        ('\u{fe17}', 120449), ('\u{fe18}', 120481), ('\u{fe19}', 120515), ('\u{fe30}', 120610),
+        ('\u{fe31}', 120673), ('\u{fe32}', 120705), ('\u{fe33}', 120737), ('\u{fe34}', 120769),
+        ('\u{fe35}', 120801), ('\u{fe36}', 120833), ('\u{fe37}', 120865), ('\u{fe38}', 120897),
+        ('\u{fe39}', 120929), ('\u{fe3a}', 120961), ('\u{fe3b}', 120993), ('\u{fe3c}', 121025),
+        ('\u{fe3d}', 121057), ('\u{fe3e}', 121089), ('\u{fe3f}', 121121), ('\u{fe40}', 121153),
+        ('\u{fe41}', 121185), ('\u{fe42}', 121217), ('\u{fe43}', 121249), ('\u{fe44}', 121281),
+        ('\u{fe47}', 121313), ('\u{fe48}', 121345), ('\u{fe49}', 121378), ('\u{fe4a}', 121442),
+        ('\u{fe4b}', 121506), ('\u{fe4c}', 121570), ('\u{fe4d}', 121633), ('\u{fe4e}', 121665),
+        ('\u{fe4f}', 121697), ('\u{fe50}', 121729), ('\u{fe51}', 121761), ('\u{fe52}', 121793),
+        ('\u{fe54}', 121825), ('\u{fe55}', 121857), ('\u{fe56}', 121889), ('\u{fe57}', 121921),
+        ('\u{fe58}', 121953), ('\u{fe59}', 121985), ('\u{fe5a}', 122017), ('\u{fe5b}', 122049),
+        ('\u{fe5c}', 122081), ('\u{fe5d}', 122113), ('\u{fe5e}', 122145), ('\u{fe5f}', 122177),
+        ('\u{fe60}', 122209), ('\u{fe61}', 122241), ('\u{fe62}', 122273), ('\u{fe63}', 122305),
+        ('\u{fe64}', 122337), ('\u{fe65}', 122369), ('\u{fe66}', 122401), ('\u{fe68}', 122433),
+        ('\u{fe69}', 122465), ('\u{fe6a}', 122497), ('\u{fe6b}', 122529), ('\u{fe70}', 122562),
+        ('\u{fe71}', 122626), ('\u{fe72}', 122690), ('\u{fe74}', 122754), ('\u{fe76}', 122818),
+        ('\u{fe77}', 122882), ('\u{fe78}', 122946), ('\u{fe79}', 123010), ('\u{fe7a}', 123074),
+        ('\u{fe7b}', 123138), ('\u{fe7c}', 123202), ('\u{fe7d}', 123266), ('\u{fe7e}', 123330),
+        ('\u{fe7f}', 123394), ('\u{fe80}', 123457), ('\u{fe81}', 123490), ('\u{fe82}', 123554),
+        ('\u{fe83}', 123618), ('\u{fe84}', 123682), ('\u{fe85}', 123746), ('\u{fe86}', 123810),
+        ('\u{fe87}', 123874), ('\u{fe88}', 123938), ('\u{fe89}', 124002), ('\u{fe8a}', 124066),
+        ('\u{fe8b}', 124130), ('\u{fe8c}', 124194), ('\u{fe8d}', 124257), ('\u{fe8e}', 124289),
+        ('\u{fe8f}', 124321), ('\u{fe90}', 124353), ('\u{fe91}', 124385), ('\u{fe92}', 124417),
+        ('\u{fe93}', 124449), ('\u{fe94}', 124481), ('\u{fe95}', 124513), ('\u{fe96}', 124545),
+        ('\u{fe97}', 124577), ('\u{fe98}', 124609), ('\u{fe99}', 124641), ('\u{fe9a}', 124673),
+        ('\u{fe9b}', 124705), ('\u{fe9c}', 124737), ('\u{fe9d}', 124769), ('\u{fe9e}', 124801),
+        ('\u{fe9f}', 124833), ('\u{fea0}', 124865), ('\u{fea1}', 124897), ('\u{fea2}', 124929),
+        ('\u{fea3}', 124961), ('\u{fea4}', 124993), ('\u{fea5}', 125025), ('\u{fea6}', 125057),
+        ('\u{fea7}', 125089), ('\u{fea8}', 125121), ('\u{fea9}', 125153), ('\u{feaa}', 125185),
+        ('\u{feab}', 125217), ('\u{feac}', 125249), ('\u{fead}', 125281), ('\u{feae}', 125313),
+        ('\u{feaf}', 125345), ('\u{feb0}', 125377), ('\u{feb1}', 125409), ('\u{feb2}', 125441),
+        ('\u{feb3}', 125473), ('\u{feb4}', 125505), ('\u{feb5}', 125537), ('\u{feb6}', 125569),
+        ('\u{feb7}', 125601), ('\u{feb8}', 125633), ('\u{feb9}', 125665), ('\u{feba}', 125697),
+        ('\u{febb}', 125729), ('\u{febc}', 125761), ('\u{febd}', 125793), ('\u{febe}', 125825),
+        ('\u{febf}', 125857), ('\u{fec0}', 125889), ('\u{fec1}', 125921), ('\u{fec2}', 125953),
+        ('\u{fec3}', 125985), ('\u{fec4}', 126017), ('\u{fec5}', 126049), ('\u{fec6}', 126081),
+        ('\u{fec7}', 126113), ('\u{fec8}', 126145), ('\u{fec9}', 126177), ('\u{feca}', 126209),
+        ('\u{fecb}', 126241), ('\u{fecc}', 126273), ('\u{fecd}', 126305), ('\u{fece}', 126337),
+        ('\u{fecf}', 126369), ('\u{fed0}', 126401), ('\u{fed1}', 126433), ('\u{fed2}', 126465),
+        ('\u{fed3}', 126497), ('\u{fed4}', 126529), ('\u{fed5}', 126561), ('\u{fed6}', 126593),
+        ('\u{fed7}', 126625), ('\u{fed8}', 126657), ('\u{fed9}', 126689), ('\u{feda}', 126721),
+        ('\u{fedb}', 126753), ('\u{fedc}', 126785), ('\u{fedd}', 126817), ('\u{fede}', 126849),
+        ('\u{fedf}', 126881), ('\u{fee0}', 126913), ('\u{fee1}', 126945), ('\u{fee2}', 126977),
+        ('\u{fee3}', 127009), ('\u{fee4}', 127041), ('\u{fee5}', 127073), ('\u{fee6}', 127105),
+        ('\u{fee7}', 127137), ('\u{fee8}', 127169), ('\u{fee9}', 127201), ('\u{feea}', 127233),
+        ('\u{feeb}', 127265), ('\u{feec}', 127297), ('\u{feed}', 127329), ('\u{feee}', 127361),
+        ('\u{feef}', 127393), ('\u{fef0}', 127425), ('\u{fef1}', 127457), ('\u{fef2}', 127489),
+        ('\u{fef3}', 127521), ('\u{fef4}', 127553), ('\u{fef5}', 127587), ('\u{fef6}', 127683),
+        ('\u{fef7}', 127779), ('\u{fef8}', 127875), ('\u{fef9}', 127971), ('\u{fefa}', 128067),
+        ('\u{fefb}', 128162), ('\u{fefc}', 128226), ('\u{ff01}', 128289), ('\u{ff02}', 128321),
+        ('\u{ff03}', 128353), ('\u{ff04}', 128385), ('\u{ff05}', 128417), ('\u{ff06}', 128449),
+        ('\u{ff07}', 128481), ('\u{ff08}', 128513), ('\u{ff09}', 128545), ('\u{ff0a}', 128577),
+        ('\u{ff0b}', 128609), ('\u{ff0c}', 128641), ('\u{ff0d}', 128673), ('\u{ff0e}', 128705),
+        ('\u{ff0f}', 128737), ('\u{ff10}', 128769), ('\u{ff11}', 128801), ('\u{ff12}', 128833),
+        ('\u{ff13}', 128865), ('\u{ff14}', 128897), ('\u{ff15}', 128929), ('\u{ff16}', 128961),
+        ('\u{ff17}', 128993), ('\u{ff18}', 129025), ('\u{ff19}', 129057), ('\u{ff1a}', 129089),
+        ('\u{ff1b}', 129121), ('\u{ff1c}', 129153), ('\u{ff1d}', 129185), ('\u{ff1e}', 129217),
+        ('\u{ff1f}', 129249), ('\u{ff20}', 129281), ('\u{ff21}', 129313), ('\u{ff22}', 129345),
+        ('\u{ff23}', 129377), ('\u{ff24}', 129409), ('\u{ff25}', 129441), ('\u{ff26}', 129473),
+        ('\u{ff27}', 129505), ('\u{ff28}', 129537), ('\u{ff29}', 129569), ('\u{ff2a}', 129601),
+        ('\u{ff2b}', 129633), ('\u{ff2c}', 129665), ('\u{ff2d}', 129697), ('\u{ff2e}', 129729),
+        ('\u{ff2f}', 129761), ('\u{ff30}', 129793), ('\u{ff31}', 129825), ('\u{ff32}', 129857),
+        ('\u{ff33}', 129889), ('\u{ff34}', 129921), ('\u{ff35}', 129953), ('\u{ff36}', 129985),
+        ('\u{ff37}', 130017), ('\u{ff38}', 130049), ('\u{ff39}', 130081), ('\u{ff3a}', 130113),
+        ('\u{ff3b}', 130145), ('\u{ff3c}', 130177), ('\u{ff3d}', 130209), ('\u{ff3e}', 130241),
+        ('\u{ff3f}', 130273), ('\u{ff40}', 130305), ('\u{ff41}', 130337), ('\u{ff42}', 130369),
+        ('\u{ff43}', 130401), ('\u{ff44}', 130433), ('\u{ff45}', 130465), ('\u{ff46}', 130497),
+        ('\u{ff47}', 130529), ('\u{ff48}', 130561), ('\u{ff49}', 130593), ('\u{ff4a}', 130625),
+        ('\u{ff4b}', 130657), ('\u{ff4c}', 130689), ('\u{ff4d}', 130721), ('\u{ff4e}', 130753),
+        ('\u{ff4f}', 130785), ('\u{ff50}', 130817), ('\u{ff51}', 130849), ('\u{ff52}', 130881),
+        ('\u{ff53}', 130913), ('\u{ff54}', 130945), ('\u{ff55}', 130977), ('\u{ff56}', 131009),
+        ('\u{ff57}', 131041), ('\u{ff58}', 131073), ('\u{ff59}', 131105), ('\u{ff5a}', 131137),
+        ('\u{ff5b}', 131169), ('\u{ff5c}', 131201), ('\u{ff5d}', 131233), ('\u{ff5e}', 131265),
+        ('\u{ff5f}', 131297), ('\u{ff60}', 131329), ('\u{ff61}', 131361), ('\u{ff62}', 131393),
+        ('\u{ff63}', 131425), ('\u{ff64}', 131457), ('\u{ff65}', 131489), ('\u{ff66}', 131521),
+        ('\u{ff67}', 131553), ('\u{ff68}', 131585), ('\u{ff69}', 131617), ('\u{ff6a}', 131649),
+        ('\u{ff6b}', 131681), ('\u{ff6c}', 131713), ('\u{ff6d}', 131745), ('\u{ff6e}', 131777),
+        ('\u{ff6f}', 131809), ('\u{ff70}', 131841), ('\u{ff71}', 131873), ('\u{ff72}', 131905),
+        ('\u{ff73}', 131937), ('\u{ff74}', 131969), ('\u{ff75}', 132001), ('\u{ff76}', 132033),
+        ('\u{ff77}', 132065), ('\u{ff78}', 132097), ('\u{ff79}', 132129), ('\u{ff7a}', 132161),
+        ('\u{ff7b}', 132193), ('\u{ff7c}', 132225), ('\u{ff7d}', 132257), ('\u{ff7e}', 132289),
+        ('\u{ff7f}', 132321), ('\u{ff80}', 132353), ('\u{ff81}', 132385), ('\u{ff82}', 132417),
+        ('\u{ff83}', 132449), ('\u{ff84}', 132481), ('\u{ff85}', 132513), ('\u{ff86}', 132545),
+        ('\u{ff87}', 132577), ('\u{ff88}', 132609), ('\u{ff89}', 132641), ('\u{ff8a}', 132673),
+        ('\u{ff8b}', 132705), ('\u{ff8c}', 132737), ('\u{ff8d}', 132769), ('\u{ff8e}', 132801),
+        ('\u{ff8f}', 132833), ('\u{ff90}', 132865), ('\u{ff91}', 132897), ('\u{ff92}', 132929),
+        ('\u{ff93}', 132961), ('\u{ff94}', 132993), ('\u{ff95}', 133025), ('\u{ff96}', 133057),
+        ('\u{ff97}', 133089), ('\u{ff98}', 133121), ('\u{ff99}', 133153), ('\u{ff9a}', 133185),
+        ('\u{ff9b}', 133217), ('\u{ff9c}', 133249), ('\u{ff9d}', 133281), ('\u{ff9e}', 133313),
+        ('\u{ff9f}', 133345), ('\u{ffa0}', 133377), ('\u{ffa1}', 133409), ('\u{ffa2}', 133441),
+        ('\u{ffa3}', 133473), ('\u{ffa4}', 133505), ('\u{ffa5}', 133537), ('\u{ffa6}', 133569),
+        ('\u{ffa7}', 133601), ('\u{ffa8}', 133633), ('\u{ffa9}', 133665), ('\u{ffaa}', 133697),
+        ('\u{ffab}', 133729), ('\u{ffac}', 133761), ('\u{ffad}', 133793), ('\u{ffae}', 133825),
+        ('\u{ffaf}', 133857), ('\u{ffb0}', 133889), ('\u{ffb1}', 133921), ('\u{ffb2}', 133953),
+        ('\u{ffb3}', 133985), ('\u{ffb4}', 134017), ('\u{ffb5}', 134049), ('\u{ffb6}', 134081),
+        ('\u{ffb7}', 134113), ('\u{ffb8}', 134145), ('\u{ffb9}', 134177), ('\u{ffba}', 134209),
+        ('\u{ffbb}', 134241), ('\u{ffbc}', 134273), ('\u{ffbd}', 134305), ('\u{ffbe}', 134337),
+        ('\u{ffc2}', 134369), ('\u{ffc3}', 134401), ('\u{ffc4}', 134433), ('\u{ffc5}', 134465),
+        ('\u{ffc6}', 134497), ('\u{ffc7}', 134529), ('\u{ffca}', 134561), ('\u{ffcb}', 134593),
+        ('\u{ffcc}', 134625), ('\u{ffcd}', 134657), ('\u{ffce}', 134689), ('\u{ffcf}', 134721),
+        ('\u{ffd2}', 134753), ('\u{ffd3}', 134785), ('\u{ffd4}', 134817), ('\u{ffd5}', 134849),
+        ('\u{ffd6}', 134881), ('\u{ffd7}', 134913), ('\u{ffda}', 134945), ('\u{ffdb}', 134977),
+        ('\u{ffdc}', 135009), ('\u{ffe0}', 135041), ('\u{ffe1}', 135073), ('\u{ffe2}', 135105),
+        ('\u{ffe3}', 135138), ('\u{ffe4}', 135201), ('\u{ffe5}', 135233), ('\u{ffe6}', 135265),
+        ('\u{ffe8}', 135297), ('\u{ffe9}', 135329), ('\u{ffea}', 135361), ('\u{ffeb}', 135393),
+        ('\u{ffec}', 135425), ('\u{ffed}', 135457), ('\u{ffee}', 135489), ('\u{10781}', 135521),
+        ('\u{10782}', 135553), ('\u{10783}', 135585), ('\u{10784}', 135617), ('\u{10785}', 135649),
+        ('\u{10787}', 135681), ('\u{10788}', 135713), ('\u{10789}', 135745), ('\u{1078a}', 135777),
+        ('\u{1078b}', 135809), ('\u{1078c}', 135841), ('\u{1078d}', 135873), ('\u{1078e}', 135905),
+        ('\u{1078f}', 135937), ('\u{10790}', 135969), ('\u{10791}', 136001), ('\u{10792}', 136033),
+        ('\u{10793}', 136065), ('\u{10794}', 136097), ('\u{10795}', 136129), ('\u{10796}', 136161),
+        ('\u{10797}', 136193), ('\u{10798}', 136225), ('\u{10799}', 136257), ('\u{1079a}', 136289),
+        ('\u{1079b}', 136321), ('\u{1079c}', 136353), ('\u{1079d}', 136385), ('\u{1079e}', 136417),
+        ('\u{1079f}', 136449), ('\u{107a0}', 136481), ('\u{107a1}', 136513), ('\u{107a2}', 136545),
+        ('\u{107a3}', 136577), ('\u{107a4}', 136609), ('\u{107a5}', 136641), ('\u{107a6}', 136673),
+        ('\u{107a7}', 136705), ('\u{107a8}', 136737), ('\u{107a9}', 136769), ('\u{107aa}', 136801),
+        ('\u{107ab}', 136833), ('\u{107ac}', 136865), ('\u{107ad}', 136897), ('\u{107ae}', 136929),
+        ('\u{107af}', 136961), ('\u{107b0}', 136993), ('\u{107b2}', 137025), ('\u{107b3}', 137057),
+        ('\u{107b4}', 137089), ('\u{107b5}', 137121), ('\u{107b6}', 137153), ('\u{107b7}', 137185),
+        ('\u{107b8}', 137217), ('\u{107b9}', 137249), ('\u{107ba}', 137281), ('\u{1d400}', 137313),
+        ('\u{1d401}', 137345), ('\u{1d402}', 137377), ('\u{1d403}', 137409), ('\u{1d404}', 137441),
+        ('\u{1d405}', 137473), ('\u{1d406}', 137505), ('\u{1d407}', 137537), ('\u{1d408}', 137569),
+        ('\u{1d409}', 137601), ('\u{1d40a}', 137633), ('\u{1d40b}', 137665), ('\u{1d40c}', 137697),
+        ('\u{1d40d}', 137729), ('\u{1d40e}', 137761), ('\u{1d40f}', 137793), ('\u{1d410}', 137825),
+        ('\u{1d411}', 137857), ('\u{1d412}', 137889), ('\u{1d413}', 137921), ('\u{1d414}', 137953),
+        ('\u{1d415}', 137985), ('\u{1d416}', 138017), ('\u{1d417}', 138049), ('\u{1d418}', 138081),
+        ('\u{1d419}', 138113), ('\u{1d41a}', 138145), ('\u{1d41b}', 138177), ('\u{1d41c}', 138209),
+        ('\u{1d41d}', 138241), ('\u{1d41e}', 138273), ('\u{1d41f}', 138305), ('\u{1d420}', 138337),
+        ('\u{1d421}', 138369), ('\u{1d422}', 138401), ('\u{1d423}', 138433), ('\u{1d424}', 138465),
+        ('\u{1d425}', 138497), ('\u{1d426}', 138529), ('\u{1d427}', 138561), ('\u{1d428}', 138593),
+        ('\u{1d429}', 138625), ('\u{1d42a}', 138657), ('\u{1d42b}', 138689), ('\u{1d42c}', 138721),
+        ('\u{1d42d}', 138753), ('\u{1d42e}', 138785), ('\u{1d42f}', 138817), ('\u{1d430}', 138849),
+        ('\u{1d431}', 138881), ('\u{1d432}', 138913), ('\u{1d433}', 138945), ('\u{1d434}', 138977),
+        ('\u{1d435}', 139009), ('\u{1d436}', 139041), ('\u{1d437}', 139073), ('\u{1d438}', 139105),
+        ('\u{1d439}', 139137), ('\u{1d43a}', 139169), ('\u{1d43b}', 139201), ('\u{1d43c}', 139233),
+        ('\u{1d43d}', 139265), ('\u{1d43e}', 139297), ('\u{1d43f}', 139329), ('\u{1d440}', 139361),
+        ('\u{1d441}', 139393), ('\u{1d442}', 139425), ('\u{1d443}', 139457), ('\u{1d444}', 139489),
+        ('\u{1d445}', 139521), ('\u{1d446}', 139553), ('\u{1d447}', 139585), ('\u{1d448}', 139617),
+        ('\u{1d449}', 139649), ('\u{1d44a}', 139681), ('\u{1d44b}', 139713), ('\u{1d44c}', 139745),
+        ('\u{1d44d}', 139777), ('\u{1d44e}', 139809), ('\u{1d44f}', 139841), ('\u{1d450}', 139873),
+        ('\u{1d451}', 139905), ('\u{1d452}', 139937), ('\u{1d453}', 139969), ('\u{1d454}', 140001),
+        ('\u{1d456}', 140033), ('\u{1d457}', 140065), ('\u{1d458}', 140097), ('\u{1d459}', 140129),
+        ('\u{1d45a}', 140161), ('\u{1d45b}', 140193), ('\u{1d45c}', 140225), ('\u{1d45d}', 140257),
+        ('\u{1d45e}', 140289), ('\u{1d45f}', 140321), ('\u{1d460}', 140353), ('\u{1d461}', 140385),
+        ('\u{1d462}', 140417), ('\u{1d463}', 140449), ('\u{1d464}', 140481), ('\u{1d465}', 140513),
+        ('\u{1d466}', 140545), ('\u{1d467}', 140577), ('\u{1d468}', 140609), ('\u{1d469}', 140641),
+        ('\u{1d46a}', 140673), ('\u{1d46b}', 140705), ('\u{1d46c}', 140737), ('\u{1d46d}', 140769),
+        ('\u{1d46e}', 140801), ('\u{1d46f}', 140833), ('\u{1d470}', 140865), ('\u{1d471}', 140897),
+        ('\u{1d472}', 140929), ('\u{1d473}', 140961), ('\u{1d474}', 140993), ('\u{1d475}', 141025),
+        ('\u{1d476}', 141057), ('\u{1d477}', 141089), ('\u{1d478}', 141121), ('\u{1d479}', 141153),
+        ('\u{1d47a}', 141185), ('\u{1d47b}', 141217), ('\u{1d47c}', 141249), ('\u{1d47d}', 141281),
+        ('\u{1d47e}', 141313), ('\u{1d47f}', 141345), ('\u{1d480}', 141377), ('\u{1d481}', 141409),
+        ('\u{1d482}', 141441), ('\u{1d483}', 141473), ('\u{1d484}', 141505), ('\u{1d485}', 141537),
+        ('\u{1d486}', 141569), ('\u{1d487}', 141601), ('\u{1d488}', 141633), ('\u{1d489}', 141665),
+        ('\u{1d48a}', 141697), ('\u{1d48b}', 141729), ('\u{1d48c}', 141761), ('\u{1d48d}', 141793),
+        ('\u{1d48e}', 141825), ('\u{1d48f}', 141857), ('\u{1d490}', 141889), ('\u{1d491}', 141921),
+        ('\u{1d492}', 141953), ('\u{1d493}', 141985), ('\u{1d494}', 142017), ('\u{1d495}', 142049),
+        ('\u{1d496}', 142081), ('\u{1d497}', 142113), ('\u{1d498}', 142145), ('\u{1d499}', 142177),
+        ('\u{1d49a}', 142209), ('\u{1d49b}', 142241), ('\u{1d49c}', 142273), ('\u{1d49e}', 142305),
+        ('\u{1d49f}', 142337), ('\u{1d4a2}', 142369), ('\u{1d4a5}', 142401), ('\u{1d4a6}', 142433),
+        ('\u{1d4a9}', 142465), ('\u{1d4aa}', 142497), ('\u{1d4ab}', 142529), ('\u{1d4ac}', 142561),
+        ('\u{1d4ae}', 142593), ('\u{1d4af}', 142625), ('\u{1d4b0}', 142657), ('\u{1d4b1}', 142689),
+        ('\u{1d4b2}', 142721), ('\u{1d4b3}', 142753), ('\u{1d4b4}', 142785), ('\u{1d4b5}', 142817),
+        ('\u{1d4b6}', 142849), ('\u{1d4b7}', 142881), ('\u{1d4b8}', 142913), ('\u{1d4b9}', 142945),
+        ('\u{1d4bb}', 142977), ('\u{1d4bd}', 143009), ('\u{1d4be}', 143041), ('\u{1d4bf}', 143073),
+        ('\u{1d4c0}', 143105), ('\u{1d4c1}', 143137), ('\u{1d4c2}', 143169), ('\u{1d4c3}', 143201),
+        ('\u{1d4c5}', 143233), ('\u{1d4c6}', 143265), ('\u{1d4c7}', 143297), ('\u{1d4c8}', 143329),
+        ('\u{1d4c9}', 143361), ('\u{1d4ca}', 143393), ('\u{1d4cb}', 143425), ('\u{1d4cc}', 143457),
+        ('\u{1d4cd}', 143489), ('\u{1d4ce}', 143521), ('\u{1d4cf}', 143553), ('\u{1d4d0}', 143585),
+        ('\u{1d4d1}', 143617), ('\u{1d4d2}', 143649), ('\u{1d4d3}', 143681), ('\u{1d4d4}', 143713),
+        ('\u{1d4d5}', 143745), ('\u{1d4d6}', 143777), ('\u{1d4d7}', 143809), ('\u{1d4d8}', 143841),
+        ('\u{1d4d9}', 143873), ('\u{1d4da}', 143905), ('\u{1d4db}', 143937), ('\u{1d4dc}', 143969),
+        ('\u{1d4dd}', 144001), ('\u{1d4de}', 144033), ('\u{1d4df}', 144065), ('\u{1d4e0}', 144097),
+        ('\u{1d4e1}', 144129), ('\u{1d4e2}', 144161), ('\u{1d4e3}', 144193), ('\u{1d4e4}', 144225),
+        ('\u{1d4e5}', 144257), ('\u{1d4e6}', 144289), ('\u{1d4e7}', 144321), ('\u{1d4e8}', 144353),
+        ('\u{1d4e9}', 144385), ('\u{1d4ea}', 144417), ('\u{1d4eb}', 144449), ('\u{1d4ec}', 144481),
+        ('\u{1d4ed}', 144513), ('\u{1d4ee}', 144545), ('\u{1d4ef}', 144577), ('\u{1d4f0}', 144609),
+        ('\u{1d4f1}', 144641), ('\u{1d4f2}', 144673), ('\u{1d4f3}', 144705), ('\u{1d4f4}', 144737),
+        ('\u{1d4f5}', 144769), ('\u{1d4f6}', 144801), ('\u{1d4f7}', 144833), ('\u{1d4f8}', 144865),
+        ('\u{1d4f9}', 144897), ('\u{1d4fa}', 144929), ('\u{1d4fb}', 144961), ('\u{1d4fc}', 144993),
+        ('\u{1d4fd}', 145025), ('\u{1d4fe}', 145057), ('\u{1d4ff}', 145089), ('\u{1d500}', 145121),
+        ('\u{1d501}', 145153), ('\u{1d502}', 145185), ('\u{1d503}', 145217), ('\u{1d504}', 145249),
+        ('\u{1d505}', 145281), ('\u{1d507}', 145313), ('\u{1d508}', 145345), ('\u{1d509}', 145377),
+        ('\u{1d50a}', 145409), ('\u{1d50d}', 145441), ('\u{1d50e}', 145473), ('\u{1d50f}', 145505),
+        ('\u{1d510}', 145537), ('\u{1d511}', 145569), ('\u{1d512}', 145601), ('\u{1d513}', 145633),
+        ('\u{1d514}', 145665), ('\u{1d516}', 145697), ('\u{1d517}', 145729), ('\u{1d518}', 145761),
+        ('\u{1d519}', 145793), ('\u{1d51a}', 145825), ('\u{1d51b}', 145857), ('\u{1d51c}', 145889),
+        ('\u{1d51e}', 145921), ('\u{1d51f}', 145953), ('\u{1d520}', 145985), ('\u{1d521}', 146017),
+        ('\u{1d522}', 146049), ('\u{1d523}', 146081), ('\u{1d524}', 146113), ('\u{1d525}', 146145),
+        ('\u{1d526}', 146177), ('\u{1d527}', 146209), ('\u{1d528}', 146241), ('\u{1d529}', 146273),
+        ('\u{1d52a}', 146305), ('\u{1d52b}', 146337), ('\u{1d52c}', 146369), ('\u{1d52d}', 146401),
+        ('\u{1d52e}', 146433), ('\u{1d52f}', 146465), ('\u{1d530}', 146497), ('\u{1d531}', 146529),
+        ('\u{1d532}', 146561), ('\u{1d533}', 146593), ('\u{1d534}', 146625), ('\u{1d535}', 146657),
+        ('\u{1d536}', 146689), ('\u{1d537}', 146721), ('\u{1d538}', 146753), ('\u{1d539}', 146785),
+        ('\u{1d53b}', 146817), ('\u{1d53c}', 146849), ('\u{1d53d}', 146881), ('\u{1d53e}', 146913),
+        ('\u{1d540}', 146945), ('\u{1d541}', 146977), ('\u{1d542}', 147009), ('\u{1d543}', 147041),
+        ('\u{1d544}', 147073), ('\u{1d546}', 147105), ('\u{1d54a}', 147137), ('\u{1d54b}', 147169),
+        ('\u{1d54c}', 147201), ('\u{1d54d}', 147233), ('\u{1d54e}', 147265), ('\u{1d54f}', 147297),
+        ('\u{1d550}', 147329), ('\u{1d552}', 147361), ('\u{1d553}', 147393), ('\u{1d554}', 147425),
+        ('\u{1d555}', 147457), ('\u{1d556}', 147489), ('\u{1d557}', 147521), ('\u{1d558}', 147553),
+        ('\u{1d559}', 147585), ('\u{1d55a}', 147617), ('\u{1d55b}', 147649), ('\u{1d55c}', 147681),
+        ('\u{1d55d}', 147713), ('\u{1d55e}', 147745), ('\u{1d55f}', 147777), ('\u{1d560}', 147809),
+        ('\u{1d561}', 147841), ('\u{1d562}', 147873), ('\u{1d563}', 147905), ('\u{1d564}', 147937),
+        ('\u{1d565}', 147969), ('\u{1d566}', 148001), ('\u{1d567}', 148033), ('\u{1d568}', 148065),
+        ('\u{1d569}', 148097), ('\u{1d56a}', 148129), ('\u{1d56b}', 148161), ('\u{1d56c}', 148193),
+        ('\u{1d56d}', 148225), ('\u{1d56e}', 148257), ('\u{1d56f}', 148289), ('\u{1d570}', 148321),
+        ('\u{1d571}', 148353), ('\u{1d572}', 148385), ('\u{1d573}', 148417), ('\u{1d574}', 148449),
+        ('\u{1d575}', 148481), ('\u{1d576}', 148513), ('\u{1d577}', 148545), ('\u{1d578}', 148577),
+        ('\u{1d579}', 148609), ('\u{1d57a}', 148641), ('\u{1d57b}', 148673), ('\u{1d57c}', 148705),
+        ('\u{1d57d}', 148737), ('\u{1d57e}', 148769), ('\u{1d57f}', 148801), ('\u{1d580}', 148833),
+        ('\u{1d581}', 148865), ('\u{1d582}', 148897), ('\u{1d583}', 148929), ('\u{1d584}', 148961),
+        ('\u{1d585}', 148993), ('\u{1d586}', 149025), ('\u{1d587}', 149057), ('\u{1d588}', 149089),
+        ('\u{1d589}', 149121), ('\u{1d58a}', 149153), ('\u{1d58b}', 149185), ('\u{1d58c}', 149217),
+        ('\u{1d58d}', 149249), ('\u{1d58e}', 149281), ('\u{1d58f}', 149313), ('\u{1d590}', 149345),
+        ('\u{1d591}', 149377), ('\u{1d592}', 149409), ('\u{1d593}', 149441), ('\u{1d594}', 149473),
+        ('\u{1d595}', 149505), ('\u{1d596}', 149537), ('\u{1d597}', 149569), ('\u{1d598}', 149601),
+        ('\u{1d599}', 149633), ('\u{1d59a}', 149665), ('\u{1d59b}', 149697), ('\u{1d59c}', 149729),
+        ('\u{1d59d}', 149761), ('\u{1d59e}', 149793), ('\u{1d59f}', 149825), ('\u{1d5a0}', 149857),
+        ('\u{1d5a1}', 149889), ('\u{1d5a2}', 149921), ('\u{1d5a3}', 149953), ('\u{1d5a4}', 149985),
+        ('\u{1d5a5}', 150017), ('\u{1d5a6}', 150049), ('\u{1d5a7}', 150081), ('\u{1d5a8}', 150113),
+        ('\u{1d5a9}', 150145), ('\u{1d5aa}', 150177), ('\u{1d5ab}', 150209), ('\u{1d5ac}', 150241),
+        ('\u{1d5ad}', 150273), ('\u{1d5ae}', 150305), ('\u{1d5af}', 150337), ('\u{1d5b0}', 150369),
+        ('\u{1d5b1}', 150401), ('\u{1d5b2}', 150433), ('\u{1d5b3}', 150465), ('\u{1d5b4}', 150497),
+        ('\u{1d5b5}', 150529), ('\u{1d5b6}', 150561), ('\u{1d5b7}', 150593), ('\u{1d5b8}', 150625),
+        ('\u{1d5b9}', 150657), ('\u{1d5ba}', 150689), ('\u{1d5bb}', 150721), ('\u{1d5bc}', 150753),
+        ('\u{1d5bd}', 150785), ('\u{1d5be}', 150817), ('\u{1d5bf}', 150849), ('\u{1d5c0}', 150881),
+        ('\u{1d5c1}', 150913), ('\u{1d5c2}', 150945), ('\u{1d5c3}', 150977), ('\u{1d5c4}', 151009),
+        ('\u{1d5c5}', 151041), ('\u{1d5c6}', 151073), ('\u{1d5c7}', 151105), ('\u{1d5c8}', 151137),
+        ('\u{1d5c9}', 151169), ('\u{1d5ca}', 151201), ('\u{1d5cb}', 151233), ('\u{1d5cc}', 151265),
+        ('\u{1d5cd}', 151297), ('\u{1d5ce}', 151329), ('\u{1d5cf}', 151361), ('\u{1d5d0}', 151393),
+        ('\u{1d5d1}', 151425), ('\u{1d5d2}', 151457), ('\u{1d5d3}', 151489), ('\u{1d5d4}', 151521),
+        ('\u{1d5d5}', 151553), ('\u{1d5d6}', 151585), ('\u{1d5d7}', 151617), ('\u{1d5d8}', 151649),
+        ('\u{1d5d9}', 151681), ('\u{1d5da}', 151713), ('\u{1d5db}', 151745), ('\u{1d5dc}', 151777),
+        ('\u{1d5dd}', 151809), ('\u{1d5de}', 151841), ('\u{1d5df}', 151873), ('\u{1d5e0}', 151905),
+        ('\u{1d5e1}', 151937), ('\u{1d5e2}', 151969), ('\u{1d5e3}', 152001), ('\u{1d5e4}', 152033),
+        ('\u{1d5e5}', 152065), ('\u{1d5e6}', 152097), ('\u{1d5e7}', 152129), ('\u{1d5e8}', 152161),
+        ('\u{1d5e9}', 152193), ('\u{1d5ea}', 152225), ('\u{1d5eb}', 152257), ('\u{1d5ec}', 152289),
+        ('\u{1d5ed}', 152321), ('\u{1d5ee}', 152353), ('\u{1d5ef}', 152385), ('\u{1d5f0}', 152417),
+        ('\u{1d5f1}', 152449), ('\u{1d5f2}', 152481), ('\u{1d5f3}', 152513), ('\u{1d5f4}', 152545),
+        ('\u{1d5f5}', 152577), ('\u{1d5f6}', 152609), ('\u{1d5f7}', 152641), ('\u{1d5f8}', 152673),
+        ('\u{1d5f9}', 152705), ('\u{1d5fa}', 152737), ('\u{1d5fb}', 152769), ('\u{1d5fc}', 152801),
+        ('\u{1d5fd}', 152833), ('\u{1d5fe}', 152865), ('\u{1d5ff}', 152897), ('\u{1d600}', 152929),
+        ('\u{1d601}', 152961), ('\u{1d602}', 152993), ('\u{1d603}', 153025), ('\u{1d604}', 153057),
+        ('\u{1d605}', 153089), ('\u{1d606}', 153121), ('\u{1d607}', 153153), ('\u{1d608}', 153185),
+        ('\u{1d609}', 153217), ('\u{1d60a}', 153249), ('\u{1d60b}', 153281), ('\u{1d60c}', 153313),
+        ('\u{1d60d}', 153345), ('\u{1d60e}', 153377), ('\u{1d60f}', 153409), ('\u{1d610}', 153441),
+        ('\u{1d611}', 153473), ('\u{1d612}', 153505), ('\u{1d613}', 153537), ('\u{1d614}', 153569),
+        ('\u{1d615}', 153601), ('\u{1d616}', 153633), ('\u{1d617}', 153665), ('\u{1d618}', 153697),
+        ('\u{1d619}', 153729), ('\u{1d61a}', 153761), ('\u{1d61b}', 153793), ('\u{1d61c}', 153825),
+        ('\u{1d61d}', 153857), ('\u{1d61e}', 153889), ('\u{1d61f}', 153921), ('\u{1d620}', 153953),
+        ('\u{1d621}', 153985), ('\u{1d622}', 154017), ('\u{1d623}', 154049), ('\u{1d624}', 154081),
+        ('\u{1d625}', 154113), ('\u{1d626}', 154145), ('\u{1d627}', 154177), ('\u{1d628}', 154209),
+        ('\u{1d629}', 154241), ('\u{1d62a}', 154273), ('\u{1d62b}', 154305), ('\u{1d62c}', 154337),
+        ('\u{1d62d}', 154369), ('\u{1d62e}', 154401), ('\u{1d62f}', 154433), ('\u{1d630}', 154465),
+        ('\u{1d631}', 154497), ('\u{1d632}', 154529), ('\u{1d633}', 154561), ('\u{1d634}', 154593),
+        ('\u{1d635}', 154625), ('\u{1d636}', 154657), ('\u{1d637}', 154689), ('\u{1d638}', 154721),
+        ('\u{1d639}', 154753), ('\u{1d63a}', 154785), ('\u{1d63b}', 154817), ('\u{1d63c}', 154849),
+        ('\u{1d63d}', 154881), ('\u{1d63e}', 154913), ('\u{1d63f}', 154945), ('\u{1d640}', 154977),
+        ('\u{1d641}', 155009), ('\u{1d642}', 155041), ('\u{1d643}', 155073), ('\u{1d644}', 155105),
+        ('\u{1d645}', 155137), ('\u{1d646}', 155169), ('\u{1d647}', 155201), ('\u{1d648}', 155233),
+        ('\u{1d649}', 155265), ('\u{1d64a}', 155297), ('\u{1d64b}', 155329), ('\u{1d64c}', 155361),
+        ('\u{1d64d}', 155393), ('\u{1d64e}', 155425), ('\u{1d64f}', 155457), ('\u{1d650}', 155489),
+        ('\u{1d651}', 155521), ('\u{1d652}', 155553), ('\u{1d653}', 155585), ('\u{1d654}', 155617),
+        ('\u{1d655}', 155649), ('\u{1d656}', 155681), ('\u{1d657}', 155713), ('\u{1d658}', 155745),
+        ('\u{1d659}', 155777), ('\u{1d65a}', 155809), ('\u{1d65b}', 155841), ('\u{1d65c}', 155873),
+        ('\u{1d65d}', 155905), ('\u{1d65e}', 155937), ('\u{1d65f}', 155969), ('\u{1d660}', 156001),
+        ('\u{1d661}', 156033), ('\u{1d662}', 156065), ('\u{1d663}', 156097), ('\u{1d664}', 156129),
+        ('\u{1d665}', 156161), ('\u{1d666}', 156193), ('\u{1d667}', 156225), ('\u{1d668}', 156257),
+        ('\u{1d669}', 156289), ('\u{1d66a}', 156321), ('\u{1d66b}', 156353), ('\u{1d66c}', 156385),
+        ('\u{1d66d}', 156417), ('\u{1d66e}', 156449), ('\u{1d66f}', 156481), ('\u{1d670}', 156513),
+        ('\u{1d671}', 156545), ('\u{1d672}', 156577), ('\u{1d673}', 156609), ('\u{1d674}', 156641),
+        ('\u{1d675}', 156673), ('\u{1d676}', 156705), ('\u{1d677}', 156737), ('\u{1d678}', 156769),
+        ('\u{1d679}', 156801), ('\u{1d67a}', 156833), ('\u{1d67b}', 156865), ('\u{1d67c}', 156897),
+        ('\u{1d67d}', 156929), ('\u{1d67e}', 156961), ('\u{1d67f}', 156993), ('\u{1d680}', 157025),
+        ('\u{1d681}', 157057), ('\u{1d682}', 157089), ('\u{1d683}', 157121), ('\u{1d684}', 157153),
+        ('\u{1d685}', 157185), ('\u{1d686}', 157217), ('\u{1d687}', 157249), ('\u{1d688}', 157281),
+        ('\u{1d689}', 157313), ('\u{1d68a}', 157345), ('\u{1d68b}', 157377), ('\u{1d68c}', 157409),
+        ('\u{1d68d}', 157441), ('\u{1d68e}', 157473), ('\u{1d68f}', 157505), ('\u{1d690}', 157537),
+        ('\u{1d691}', 157569), ('\u{1d692}', 157601), ('\u{1d693}', 157633), ('\u{1d694}', 157665),
+        ('\u{1d695}', 157697), ('\u{1d696}', 157729), ('\u{1d697}', 157761), ('\u{1d698}', 157793),
+        ('\u{1d699}', 157825), ('\u{1d69a}', 157857), ('\u{1d69b}', 157889), ('\u{1d69c}', 157921),
+        ('\u{1d69d}', 157953), ('\u{1d69e}', 157985), ('\u{1d69f}', 158017), ('\u{1d6a0}', 158049),
+        ('\u{1d6a1}', 158081), ('\u{1d6a2}', 158113), ('\u{1d6a3}', 158145), ('\u{1d6a4}', 158177),
+        ('\u{1d6a5}', 158209), ('\u{1d6a8}', 158241), ('\u{1d6a9}', 158273), ('\u{1d6aa}', 158305),
+        ('\u{1d6ab}', 158337), ('\u{1d6ac}', 158369), ('\u{1d6ad}', 158401), ('\u{1d6ae}', 158433),
+        ('\u{1d6af}', 158465), ('\u{1d6b0}', 158497), ('\u{1d6b1}', 158529), ('\u{1d6b2}', 158561),
+        ('\u{1d6b3}', 158593), ('\u{1d6b4}', 158625), ('\u{1d6b5}', 158657), ('\u{1d6b6}', 158689),
+        ('\u{1d6b7}', 158721), ('\u{1d6b8}', 158753), ('\u{1d6b9}', 158785), ('\u{1d6ba}', 158817),
+        ('\u{1d6bb}', 158849), ('\u{1d6bc}', 158881), ('\u{1d6bd}', 158913), ('\u{1d6be}', 158945),
+        ('\u{1d6bf}', 158977), ('\u{1d6c0}', 159009), ('\u{1d6c1}', 159041), ('\u{1d6c2}', 159073),
+        ('\u{1d6c3}', 159105), ('\u{1d6c4}', 159137), ('\u{1d6c5}', 159169), ('\u{1d6c6}', 159201),
+        ('\u{1d6c7}', 159233), ('\u{1d6c8}', 159265), ('\u{1d6c9}', 159297), ('\u{1d6ca}', 159329),
+        ('\u{1d6cb}', 159361), ('\u{1d6cc}', 159393), ('\u{1d6cd}', 159425), ('\u{1d6ce}', 159457),
+        ('\u{1d6cf}', 159489), ('\u{1d6d0}', 159521), ('\u{1d6d1}', 159553), ('\u{1d6d2}', 159585),
+        ('\u{1d6d3}', 159617), ('\u{1d6d4}', 159649), ('\u{1d6d5}', 159681), ('\u{1d6d6}', 159713),
+        ('\u{1d6d7}', 159745), ('\u{1d6d8}', 159777), ('\u{1d6d9}', 159809), ('\u{1d6da}', 159841),
+        ('\u{1d6db}', 159873), ('\u{1d6dc}', 159905), ('\u{1d6dd}', 159937), ('\u{1d6de}', 159969),
+        ('\u{1d6df}', 160001), ('\u{1d6e0}', 160033), ('\u{1d6e1}', 160065), ('\u{1d6e2}', 160097),
+        ('\u{1d6e3}', 160129), ('\u{1d6e4}', 160161), ('\u{1d6e5}', 160193), ('\u{1d6e6}', 160225),
+        ('\u{1d6e7}', 160257), ('\u{1d6e8}', 160289), ('\u{1d6e9}', 160321), ('\u{1d6ea}', 160353),
+        ('\u{1d6eb}', 160385), ('\u{1d6ec}', 160417), ('\u{1d6ed}', 160449), ('\u{1d6ee}', 160481),
+        ('\u{1d6ef}', 160513), ('\u{1d6f0}', 160545), ('\u{1d6f1}', 160577), ('\u{1d6f2}', 160609),
+        ('\u{1d6f3}', 160641), ('\u{1d6f4}', 160673), ('\u{1d6f5}', 160705), ('\u{1d6f6}', 160737),
+        ('\u{1d6f7}', 160769), ('\u{1d6f8}', 160801), ('\u{1d6f9}', 160833), ('\u{1d6fa}', 160865),
+        ('\u{1d6fb}', 160897), ('\u{1d6fc}', 160929), ('\u{1d6fd}', 160961), ('\u{1d6fe}', 160993),
+        ('\u{1d6ff}', 161025), ('\u{1d700}', 161057), ('\u{1d701}', 161089), ('\u{1d702}', 161121),
+        ('\u{1d703}', 161153), ('\u{1d704}', 161185), ('\u{1d705}', 161217), ('\u{1d706}', 161249),
+        ('\u{1d707}', 161281), ('\u{1d708}', 161313), ('\u{1d709}', 161345), ('\u{1d70a}', 161377),
+        ('\u{1d70b}', 161409), ('\u{1d70c}', 161441), ('\u{1d70d}', 161473), ('\u{1d70e}', 161505),
+        ('\u{1d70f}', 161537), ('\u{1d710}', 161569), ('\u{1d711}', 161601), ('\u{1d712}', 161633),
+        ('\u{1d713}', 161665), ('\u{1d714}', 161697), ('\u{1d715}', 161729), ('\u{1d716}', 161761),
+        ('\u{1d717}', 161793), ('\u{1d718}', 161825), ('\u{1d719}', 161857), ('\u{1d71a}', 161889),
+        ('\u{1d71b}', 161921), ('\u{1d71c}', 161953), ('\u{1d71d}', 161985), ('\u{1d71e}', 162017),
+        ('\u{1d71f}', 162049), ('\u{1d720}', 162081), ('\u{1d721}', 162113), ('\u{1d722}', 162145),
+        ('\u{1d723}', 162177), ('\u{1d724}', 162209), ('\u{1d725}', 162241), ('\u{1d726}', 162273),
+        ('\u{1d727}', 162305), ('\u{1d728}', 162337), ('\u{1d729}', 162369), ('\u{1d72a}', 162401),
+        ('\u{1d72b}', 162433), ('\u{1d72c}', 162465), ('\u{1d72d}', 162497), ('\u{1d72e}', 162529),
+        ('\u{1d72f}', 162561), ('\u{1d730}', 162593), ('\u{1d731}', 162625), ('\u{1d732}', 162657),
+        ('\u{1d733}', 162689), ('\u{1d734}', 162721), ('\u{1d735}', 162753), ('\u{1d736}', 162785),
+        ('\u{1d737}', 162817), ('\u{1d738}', 162849), ('\u{1d739}', 162881), ('\u{1d73a}', 162913),
+        ('\u{1d73b}', 162945), ('\u{1d73c}', 162977), ('\u{1d73d}', 163009), ('\u{1d73e}', 163041),
+        ('\u{1d73f}', 163073), ('\u{1d740}', 163105), ('\u{1d741}', 163137), ('\u{1d742}', 163169),
+        ('\u{1d743}', 163201), ('\u{1d744}', 163233), ('\u{1d745}', 163265), ('\u{1d746}', 163297),
+        ('\u{1d747}', 163329), ('\u{1d748}', 163361), ('\u{1d749}', 163393), ('\u{1d74a}', 163425),
+        ('\u{1d74b}', 163457), ('\u{1d74c}', 163489), ('\u{1d74d}', 163521), ('\u{1d74e}', 163553),
+        ('\u{1d74f}', 163585), ('\u{1d750}', 163617), ('\u{1d751}', 163649), ('\u{1d752}', 163681),
+        ('\u{1d753}', 163713), ('\u{1d754}', 163745), ('\u{1d755}', 163777), ('\u{1d756}', 163809),
+        ('\u{1d757}', 163841), ('\u{1d758}', 163873), ('\u{1d759}', 163905), ('\u{1d75a}', 163937),
+        ('\u{1d75b}', 163969), ('\u{1d75c}', 164001), ('\u{1d75d}', 164033), ('\u{1d75e}', 164065),
+        ('\u{1d75f}', 164097), ('\u{1d760}', 164129), ('\u{1d761}', 164161), ('\u{1d762}', 164193),
+        ('\u{1d763}', 164225), ('\u{1d764}', 164257), ('\u{1d765}', 164289), ('\u{1d766}', 164321),
+        ('\u{1d767}', 164353), ('\u{1d768}', 164385), ('\u{1d769}', 164417), ('\u{1d76a}', 164449),
+        ('\u{1d76b}', 164481), ('\u{1d76c}', 164513), ('\u{1d76d}', 164545), ('\u{1d76e}', 164577),
+        ('\u{1d76f}', 164609), ('\u{1d770}', 164641), ('\u{1d771}', 164673), ('\u{1d772}', 164705),
+        ('\u{1d773}', 164737), ('\u{1d774}', 164769), ('\u{1d775}', 164801), ('\u{1d776}', 164833),
+        ('\u{1d777}', 164865), ('\u{1d778}', 164897), ('\u{1d779}', 164929), ('\u{1d77a}', 164961),
+        ('\u{1d77b}', 164993), ('\u{1d77c}', 165025), ('\u{1d77d}', 165057), ('\u{1d77e}', 165089),
+        ('\u{1d77f}', 165121), ('\u{1d780}', 165153), ('\u{1d781}', 165185), ('\u{1d782}', 165217),
+        ('\u{1d783}', 165249), ('\u{1d784}', 165281), ('\u{1d785}', 165313), ('\u{1d786}', 165345),
+        ('\u{1d787}', 165377), ('\u{1d788}', 165409), ('\u{1d789}', 165441), ('\u{1d78a}', 165473),
+        ('\u{1d78b}', 165505), ('\u{1d78c}', 165537), ('\u{1d78d}', 165569), ('\u{1d78e}', 165601),
+        ('\u{1d78f}', 165633), ('\u{1d790}', 165665), ('\u{1d791}', 165697), ('\u{1d792}', 165729),
+        ('\u{1d793}', 165761), ('\u{1d794}', 165793), ('\u{1d795}', 165825), ('\u{1d796}', 165857),
+        ('\u{1d797}', 165889), ('\u{1d798}', 165921), ('\u{1d799}', 165953), ('\u{1d79a}', 165985),
+        ('\u{1d79b}', 166017), ('\u{1d79c}', 166049), ('\u{1d79d}', 166081), ('\u{1d79e}', 166113),
+        ('\u{1d79f}', 166145), ('\u{1d7a0}', 166177), ('\u{1d7a1}', 166209), ('\u{1d7a2}', 166241),
+        ('\u{1d7a3}', 166273), ('\u{1d7a4}', 166305), ('\u{1d7a5}', 166337), ('\u{1d7a6}', 166369),
+        ('\u{1d7a7}', 166401), ('\u{1d7a8}', 166433), ('\u{1d7a9}', 166465), ('\u{1d7aa}', 166497),
+        ('\u{1d7ab}', 166529), ('\u{1d7ac}', 166561), ('\u{1d7ad}', 166593), ('\u{1d7ae}', 166625),
+        ('\u{1d7af}', 166657), ('\u{1d7b0}', 166689), ('\u{1d7b1}', 166721), ('\u{1d7b2}', 166753),
+        ('\u{1d7b3}', 166785), ('\u{1d7b4}', 166817), ('\u{1d7b5}', 166849), ('\u{1d7b6}', 166881),
+        ('\u{1d7b7}', 166913), ('\u{1d7b8}', 166945), ('\u{1d7b9}', 166977), ('\u{1d7ba}', 167009),
+        ('\u{1d7bb}', 167041), ('\u{1d7bc}', 167073), ('\u{1d7bd}', 167105), ('\u{1d7be}', 167137),
+        ('\u{1d7bf}', 167169), ('\u{1d7c0}', 167201), ('\u{1d7c1}', 167233), ('\u{1d7c2}', 167265),
+        ('\u{1d7c3}', 167297), ('\u{1d7c4}', 167329), ('\u{1d7c5}', 167361), ('\u{1d7c6}', 167393),
+        ('\u{1d7c7}', 167425), ('\u{1d7c8}', 167457), ('\u{1d7c9}', 167489), ('\u{1d7ca}', 167521),
+        ('\u{1d7cb}', 167553), ('\u{1d7ce}', 167585), ('\u{1d7cf}', 167617), ('\u{1d7d0}', 167649),
+        ('\u{1d7d1}', 167681), ('\u{1d7d2}', 167713), ('\u{1d7d3}', 167745), ('\u{1d7d4}', 167777),
+        ('\u{1d7d5}', 167809), ('\u{1d7d6}', 167841), ('\u{1d7d7}', 167873), ('\u{1d7d8}', 167905),
+        ('\u{1d7d9}', 167937), ('\u{1d7da}', 167969), ('\u{1d7db}', 168001), ('\u{1d7dc}', 168033),
+        ('\u{1d7dd}', 168065), ('\u{1d7de}', 168097), ('\u{1d7df}', 168129), ('\u{1d7e0}', 168161),
+        ('\u{1d7e1}', 168193), ('\u{1d7e2}', 168225), ('\u{1d7e3}', 168257), ('\u{1d7e4}', 168289),
+        ('\u{1d7e5}', 168321), ('\u{1d7e6}', 168353), ('\u{1d7e7}', 168385), ('\u{1d7e8}', 168417),
+        ('\u{1d7e9}', 168449), ('\u{1d7ea}', 168481), ('\u{1d7eb}', 168513), ('\u{1d7ec}', 168545),
+        ('\u{1d7ed}', 168577), ('\u{1d7ee}', 168609), ('\u{1d7ef}', 168641), ('\u{1d7f0}', 168673),
+        ('\u{1d7f1}', 168705), ('\u{1d7f2}', 168737), ('\u{1d7f3}', 168769), ('\u{1d7f4}', 168801),
+        ('\u{1d7f5}', 168833), ('\u{1d7f6}', 168865), ('\u{1d7f7}', 168897), ('\u{1d7f8}', 168929),
+        ('\u{1d7f9}', 168961), ('\u{1d7fa}', 168993), ('\u{1d7fb}', 169025), ('\u{1d7fc}', 169057),
+        ('\u{1d7fd}', 169089), ('\u{1d7fe}', 169121), ('\u{1d7ff}', 169153), ('\u{1e030}', 169185),
+        ('\u{1e031}', 169217), ('\u{1e032}', 169249), ('\u{1e033}', 169281), ('\u{1e034}', 169313),
+        ('\u{1e035}', 169345), ('\u{1e036}', 169377), ('\u{1e037}', 169409), ('\u{1e038}', 169441),
+        ('\u{1e039}', 169473), ('\u{1e03a}', 169505), ('\u{1e03b}', 169537), ('\u{1e03c}', 169569),
+        ('\u{1e03d}', 169601), ('\u{1e03e}', 169633), ('\u{1e03f}', 169665), ('\u{1e040}', 169697),
+        ('\u{1e041}', 169729), ('\u{1e042}', 169761), ('\u{1e043}', 169793), ('\u{1e044}', 169825),
+        ('\u{1e045}', 169857), ('\u{1e046}', 169889), ('\u{1e047}', 169921), ('\u{1e048}', 169953),
+        ('\u{1e049}', 169985), ('\u{1e04a}', 170017), ('\u{1e04b}', 170049), ('\u{1e04c}', 170081),
+        ('\u{1e04d}', 170113), ('\u{1e04e}', 170145), ('\u{1e04f}', 170177), ('\u{1e050}', 170209),
+        ('\u{1e051}', 170241), ('\u{1e052}', 170273), ('\u{1e053}', 170305), ('\u{1e054}', 170337),
+        ('\u{1e055}', 170369), ('\u{1e056}', 170401), ('\u{1e057}', 170433), ('\u{1e058}', 170465),
+        ('\u{1e059}', 170497), ('\u{1e05a}', 170529), ('\u{1e05b}', 170561), ('\u{1e05c}', 170593),
+        ('\u{1e05d}', 170625), ('\u{1e05e}', 170657), ('\u{1e05f}', 170689), ('\u{1e060}', 170721),
+        ('\u{1e061}', 170753), ('\u{1e062}', 170785), ('\u{1e063}', 170817), ('\u{1e064}', 170849),
+        ('\u{1e065}', 170881), ('\u{1e066}', 170913), ('\u{1e067}', 170945), ('\u{1e068}', 170977),
+        ('\u{1e069}', 171009), ('\u{1e06a}', 171041), ('\u{1e06b}', 171073), ('\u{1e06c}', 171105),
+        ('\u{1e06d}', 171137), ('\u{1ee00}', 171169), ('\u{1ee01}', 171201), ('\u{1ee02}', 171233),
+        ('\u{1ee03}', 171265), ('\u{1ee05}', 171297), ('\u{1ee06}', 171329), ('\u{1ee07}', 171361),
+        ('\u{1ee08}', 171393), ('\u{1ee09}', 171425), ('\u{1ee0a}', 171457), ('\u{1ee0b}', 171489),
+        ('\u{1ee0c}', 171521), ('\u{1ee0d}', 171553), ('\u{1ee0e}', 171585), ('\u{1ee0f}', 171617),
+        ('\u{1ee10}', 171649), ('\u{1ee11}', 171681), ('\u{1ee12}', 171713), ('\u{1ee13}', 171745),
+        ('\u{1ee14}', 171777), ('\u{1ee15}', 171809), ('\u{1ee16}', 171841), ('\u{1ee17}', 171873),
+        ('\u{1ee18}', 171905), ('\u{1ee19}', 171937), ('\u{1ee1a}', 171969), ('\u{1ee1b}', 172001),
+        ('\u{1ee1c}', 172033), ('\u{1ee1d}', 172065), ('\u{1ee1e}', 172097), ('\u{1ee1f}', 172129),
+        ('\u{1ee21}', 172161), ('\u{1ee22}', 172193), ('\u{1ee24}', 172225), ('\u{1ee27}', 172257),
+        ('\u{1ee29}', 172289), ('\u{1ee2a}', 172321), ('\u{1ee2b}', 172353), ('\u{1ee2c}', 172385),
+        ('\u{1ee2d}', 172417), ('\u{1ee2e}', 172449), ('\u{1ee2f}', 172481), ('\u{1ee30}', 172513),
+        ('\u{1ee31}', 172545), ('\u{1ee32}', 172577), ('\u{1ee34}', 172609), ('\u{1ee35}', 172641),
+        ('\u{1ee36}', 172673), ('\u{1ee37}', 172705), ('\u{1ee39}', 172737), ('\u{1ee3b}', 172769),
+        ('\u{1ee42}', 172801), ('\u{1ee47}', 172833), ('\u{1ee49}', 172865), ('\u{1ee4b}', 172897),
+        ('\u{1ee4d}', 172929), ('\u{1ee4e}', 172961), ('\u{1ee4f}', 172993), ('\u{1ee51}', 173025),
+        ('\u{1ee52}', 173057), ('\u{1ee54}', 173089), ('\u{1ee57}', 173121), ('\u{1ee59}', 173153),
+        ('\u{1ee5b}', 173185), ('\u{1ee5d}', 173217), ('\u{1ee5f}', 173249), ('\u{1ee61}', 173281),
+        ('\u{1ee62}', 173313), ('\u{1ee64}', 173345), ('\u{1ee67}', 173377), ('\u{1ee68}', 173409),
+        ('\u{1ee69}', 173441), ('\u{1ee6a}', 173473), ('\u{1ee6c}', 173505), ('\u{1ee6d}', 173537),
+        ('\u{1ee6e}', 173569), ('\u{1ee6f}', 173601), ('\u{1ee70}', 173633), ('\u{1ee71}', 173665),
+        ('\u{1ee72}', 173697), ('\u{1ee74}', 173729), ('\u{1ee75}', 173761), ('\u{1ee76}', 173793),
+        ('\u{1ee77}', 173825), ('\u{1ee79}', 173857), ('\u{1ee7a}', 173889), ('\u{1ee7b}', 173921),
+        ('\u{1ee7c}', 173953), ('\u{1ee7e}', 173985), ('\u{1ee80}', 174017), ('\u{1ee81}', 174049),
+        ('\u{1ee82}', 174081), ('\u{1ee83}', 174113), ('\u{1ee84}', 174145), ('\u{1ee85}', 174177),
+        ('\u{1ee86}', 174209), ('\u{1ee87}', 174241), ('\u{1ee88}', 174273), ('\u{1ee89}', 174305),
+        ('\u{1ee8b}', 174337), ('\u{1ee8c}', 174369), ('\u{1ee8d}', 174401), ('\u{1ee8e}', 174433),
+        ('\u{1ee8f}', 174465), ('\u{1ee90}', 174497), ('\u{1ee91}', 174529), ('\u{1ee92}', 174561),
+        ('\u{1ee93}', 174593), ('\u{1ee94}', 174625), ('\u{1ee95}', 174657), ('\u{1ee96}', 174689),
+        ('\u{1ee97}', 174721), ('\u{1ee98}', 174753), ('\u{1ee99}', 174785), ('\u{1ee9a}', 174817),
+        ('\u{1ee9b}', 174849), ('\u{1eea1}', 174881), ('\u{1eea2}', 174913), ('\u{1eea3}', 174945),
+        ('\u{1eea5}', 174977), ('\u{1eea6}', 175009), ('\u{1eea7}', 175041), ('\u{1eea8}', 175073),
+        ('\u{1eea9}', 175105), ('\u{1eeab}', 175137), ('\u{1eeac}', 175169), ('\u{1eead}', 175201),
+        ('\u{1eeae}', 175233), ('\u{1eeaf}', 175265), ('\u{1eeb0}', 175297), ('\u{1eeb1}', 175329),
+        ('\u{1eeb2}', 175361), ('\u{1eeb3}', 175393), ('\u{1eeb4}', 175425), ('\u{1eeb5}', 175457),
+        ('\u{1eeb6}', 175489), ('\u{1eeb7}', 175521), ('\u{1eeb8}', 175553), ('\u{1eeb9}', 175585),
+        ('\u{1eeba}', 175617), ('\u{1eebb}', 175649), ('\u{1f100}', 175682), ('\u{1f101}', 175746),
+        ('\u{1f102}', 175810), ('\u{1f103}', 175874), ('\u{1f104}', 175938), ('\u{1f105}', 176002),
+        ('\u{1f106}', 176066), ('\u{1f107}', 176130), ('\u{1f108}', 176194), ('\u{1f109}', 176258),
+        ('\u{1f10a}', 176322), ('\u{1f110}', 176387), ('\u{1f111}', 176483), ('\u{1f112}', 176579),
+        ('\u{1f113}', 176675), ('\u{1f114}', 176771), ('\u{1f115}', 176867), ('\u{1f116}', 176963),
+        ('\u{1f117}', 177059), ('\u{1f118}', 177155), ('\u{1f119}', 177251), ('\u{1f11a}', 177347),
+        ('\u{1f11b}', 177443), ('\u{1f11c}', 177539), ('\u{1f11d}', 177635), ('\u{1f11e}', 177731),
+        ('\u{1f11f}', 177827), ('\u{1f120}', 177923), ('\u{1f121}', 178019), ('\u{1f122}', 178115),
+        ('\u{1f123}', 178211), ('\u{1f124}', 178307), ('\u{1f125}', 178403), ('\u{1f126}', 178499),
+        ('\u{1f127}', 178595), ('\u{1f128}', 178691), ('\u{1f129}', 178787), ('\u{1f12a}', 178883),
+        ('\u{1f12b}', 178977), ('\u{1f12c}', 179009), ('\u{1f12d}', 179042), ('\u{1f12e}', 179106),
+        ('\u{1f130}', 179169), ('\u{1f131}', 179201), ('\u{1f132}', 179233), ('\u{1f133}', 179265),
+        ('\u{1f134}', 179297), ('\u{1f135}', 179329), ('\u{1f136}', 179361), ('\u{1f137}', 179393),
+        ('\u{1f138}', 179425), ('\u{1f139}', 179457), ('\u{1f13a}', 179489), ('\u{1f13b}', 179521),
+        ('\u{1f13c}', 179553), ('\u{1f13d}', 179585), ('\u{1f13e}', 179617), ('\u{1f13f}', 179649),
+        ('\u{1f140}', 179681), ('\u{1f141}', 179713), ('\u{1f142}', 179745), ('\u{1f143}', 179777),
+        ('\u{1f144}', 179809), ('\u{1f145}', 179841), ('\u{1f146}', 179873), ('\u{1f147}', 179905),
+        ('\u{1f148}', 179937), ('\u{1f149}', 179969), ('\u{1f14a}', 180002), ('\u{1f14b}', 180066),
+        ('\u{1f14c}', 180130), ('\u{1f14d}', 180194), ('\u{1f14e}', 180259), ('\u{1f14f}', 180354),
+        ('\u{1f16a}', 180418), ('\u{1f16b}', 180482), ('\u{1f16c}', 180546), ('\u{1f190}', 180610),
+        ('\u{1f200}', 180674), ('\u{1f201}', 180738), ('\u{1f202}', 180801), ('\u{1f210}', 180833),
+        ('\u{1f211}', 180865), ('\u{1f212}', 180897), ('\u{1f213}', 180930), ('\u{1f214}', 180993),
+        ('\u{1f215}', 181025), ('\u{1f216}', 181057), ('\u{1f217}', 181089), ('\u{1f218}', 181121),
+        ('\u{1f219}', 181153), ('\u{1f21a}', 181185), ('\u{1f21b}', 181217), ('\u{1f21c}', 181249),
+        ('\u{1f21d}', 181281), ('\u{1f21e}', 181313), ('\u{1f21f}', 181345), ('\u{1f220}', 181377),
+        ('\u{1f221}', 181409), ('\u{1f222}', 181441), ('\u{1f223}', 181473), ('\u{1f224}', 181505),
+        ('\u{1f225}', 181537), ('\u{1f226}', 181569), ('\u{1f227}', 181601), ('\u{1f228}', 181633),
+        ('\u{1f229}', 181665), ('\u{1f22a}', 181697), ('\u{1f22b}', 181729), ('\u{1f22c}', 181761),
+        ('\u{1f22d}', 181793), ('\u{1f22e}', 181825), ('\u{1f22f}', 181857), ('\u{1f230}', 181889),
+        ('\u{1f231}', 181921), ('\u{1f232}', 181953), ('\u{1f233}', 181985), ('\u{1f234}', 182017),
+        ('\u{1f235}', 182049), ('\u{1f236}', 182081), ('\u{1f237}', 182113), ('\u{1f238}', 182145),
+        ('\u{1f239}', 182177), ('\u{1f23a}', 182209), ('\u{1f23b}', 182241), ('\u{1f240}', 182275),
+        ('\u{1f241}', 182371), ('\u{1f242}', 182467), ('\u{1f243}', 182563), ('\u{1f244}', 182659),
+        ('\u{1f245}', 182755), ('\u{1f246}', 182851), ('\u{1f247}', 182947), ('\u{1f248}', 183043),
+        ('\u{1f250}', 183137), ('\u{1f251}', 183169), ('\u{1fbf0}', 183201), ('\u{1fbf1}', 183233),
+        ('\u{1fbf2}', 183265), ('\u{1fbf3}', 183297), ('\u{1fbf4}', 183329), ('\u{1fbf5}', 183361),
+        ('\u{1fbf6}', 183393), ('\u{1fbf7}', 183425), ('\u{1fbf8}', 183457), ('\u{1fbf9}', 183489),
+    ];
+
+    static COMPATIBILITY_DECOMPOSED_CHARS: &[char] = &[
+        ' ', ' ', '\u{308}', 'a', ' ', '\u{304}', '2', '3', ' ', '\u{301}', '\u{3bc}', ' ',
+        '\u{327}', '1', 'o', '1', '\u{2044}', '4', '1', '\u{2044}', '2', '3', '\u{2044}', '4', 'I',
+        'J', 'i', 'j', 'L', '\u{b7}', 'l', '\u{b7}', '\u{2bc}', 'n', 's', 'D', 'Z', '\u{30c}', 'D',
+        'z', '\u{30c}', 'd', 'z', '\u{30c}', 'L', 'J', 'L', 'j', 'l', 'j', 'N', 'J', 'N', 'j', 'n',
+        'j', 'D', 'Z', 'D', 'z', 'd', 'z', 'h', '\u{266}', 'j', 'r', '\u{279}', '\u{27b}',
+        '\u{281}', 'w', 'y', ' ', '\u{306}', ' ', '\u{307}', ' ', '\u{30a}', ' ', '\u{328}', ' ',
+        '\u{303}', ' ', '\u{30b}', '\u{263}', 'l', 's', 'x', '\u{295}', ' ', '\u{345}', ' ',
+        '\u{301}', ' ', '\u{308}', '\u{301}', '\u{3b2}', '\u{3b8}', '\u{3a5}', '\u{3a5}', '\u{301}',
+        '\u{3a5}', '\u{308}', '\u{3c6}', '\u{3c0}', '\u{3ba}', '\u{3c1}', '\u{3c2}', '\u{398}',
+        '\u{3b5}', '\u{3a3}', '\u{565}', '\u{582}', '\u{627}', '\u{674}', '\u{648}', '\u{674}',
+        '\u{6c7}', '\u{674}', '\u{64a}', '\u{674}', '\u{e4d}', '\u{e32}', '\u{ecd}', '\u{eb2}',
+        '\u{eab}', '\u{e99}', '\u{eab}', '\u{ea1}', '\u{f0b}', '\u{fb2}', '\u{f71}', '\u{f80}',
+        '\u{fb3}', '\u{f71}', '\u{f80}', '\u{10dc}', 'A', '\u{c6}', 'B', 'D', 'E', '\u{18e}', 'G',
+        'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', '\u{222}', 'P', 'R', 'T', 'U', 'W', 'a', '\u{250}',
+        '\u{251}', '\u{1d02}', 'b', 'd', 'e', '\u{259}', '\u{25b}', '\u{25c}', 'g', 'k', 'm',
+        '\u{14b}', 'o', '\u{254}', '\u{1d16}', '\u{1d17}', 'p', 't', 'u', '\u{1d1d}', '\u{26f}',
+        'v', '\u{1d25}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3c6}', '\u{3c7}', 'i', 'r', 'u', 'v',
+        '\u{3b2}', '\u{3b3}', '\u{3c1}', '\u{3c6}', '\u{3c7}', '\u{43d}', '\u{252}', 'c', '\u{255}',
+        '\u{f0}', '\u{25c}', 'f', '\u{25f}', '\u{261}', '\u{265}', '\u{268}', '\u{269}', '\u{26a}',
+        '\u{1d7b}', '\u{29d}', '\u{26d}', '\u{1d85}', '\u{29f}', '\u{271}', '\u{270}', '\u{272}',
+        '\u{273}', '\u{274}', '\u{275}', '\u{278}', '\u{282}', '\u{283}', '\u{1ab}', '\u{289}',
+        '\u{28a}', '\u{1d1c}', '\u{28b}', '\u{28c}', 'z', '\u{290}', '\u{291}', '\u{292}',
+        '\u{3b8}', 'a', '\u{2be}', 's', '\u{307}', ' ', '\u{313}', ' ', '\u{313}', ' ', '\u{342}',
+        ' ', '\u{308}', '\u{342}', ' ', '\u{313}', '\u{300}', ' ', '\u{313}', '\u{301}', ' ',
+        '\u{313}', '\u{342}', ' ', '\u{314}', '\u{300}', ' ', '\u{314}', '\u{301}', ' ', '\u{314}',
+        '\u{342}', ' ', '\u{308}', '\u{300}', ' ', '\u{308}', '\u{301}', ' ', '\u{301}', ' ',
+        '\u{314}', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '\u{2010}', ' ',
+        '\u{333}', '.', '.', '.', '.', '.', '.', ' ', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '!',
+        '!', ' ', '\u{305}', '?', '?', '?', '!', '!', '?', '\u{2032}', '\u{2032}', '\u{2032}',
+        '\u{2032}', ' ', '0', 'i', '4', '5', '6', '7', '8', '9', '+', '\u{2212}', '=', '(', ')',
+        'n', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '\u{2212}', '=', '(', ')', 'a',
+        'e', 'o', 'x', '\u{259}', 'h', 'k', 'l', 'm', 'n', 'p', 's', 't', 'R', 's', 'a', '/', 'c',
+        'a', '/', 's', 'C', '\u{b0}', 'C', 'c', '/', 'o', 'c', '/', 'u', '\u{190}', '\u{b0}', 'F',
+        'g', 'H', 'H', 'H', 'h', '\u{127}', 'I', 'I', 'L', 'l', 'N', 'N', 'o', 'P', 'Q', 'R', 'R',
+        'R', 'S', 'M', 'T', 'E', 'L', 'T', 'M', 'Z', 'Z', 'B', 'C', 'e', 'E', 'F', 'M', 'o',
+        '\u{5d0}', '\u{5d1}', '\u{5d2}', '\u{5d3}', 'i', 'F', 'A', 'X', '\u{3c0}', '\u{3b3}',
+        '\u{393}', '\u{3a0}', '\u{2211}', 'D', 'd', 'e', 'i', 'j', '1', '\u{2044}', '7', '1',
+        '\u{2044}', '9', '1', '\u{2044}', '1', '0', '1', '\u{2044}', '3', '2', '\u{2044}', '3', '1',
+        '\u{2044}', '5', '2', '\u{2044}', '5', '3', '\u{2044}', '5', '4', '\u{2044}', '5', '1',
+        '\u{2044}', '6', '5', '\u{2044}', '6', '1', '\u{2044}', '8', '3', '\u{2044}', '8', '5',
+        '\u{2044}', '8', '7', '\u{2044}', '8', '1', '\u{2044}', 'I', 'I', 'I', 'I', 'I', 'I', 'I',
+        'V', 'V', 'V', 'I', 'V', 'I', 'I', 'V', 'I', 'I', 'I', 'I', 'X', 'X', 'X', 'I', 'X', 'I',
+        'I', 'L', 'C', 'D', 'M', 'i', 'i', 'i', 'i', 'i', 'i', 'i', 'v', 'v', 'v', 'i', 'v', 'i',
+        'i', 'v', 'i', 'i', 'i', 'i', 'x', 'x', 'x', 'i', 'x', 'i', 'i', 'l', 'c', 'd', 'm', '0',
+        '\u{2044}', '3', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222e}',
+        '\u{222e}', '\u{222e}', '\u{222e}', '\u{222e}', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+        '1', '0', '1', '1', '1', '2', '1', '3', '1', '4', '1', '5', '1', '6', '1', '7', '1', '8',
+        '1', '9', '2', '0', '(', '1', ')', '(', '2', ')', '(', '3', ')', '(', '4', ')', '(', '5',
+        ')', '(', '6', ')', '(', '7', ')', '(', '8', ')', '(', '9', ')', '(', '1', '0', ')', '(',
+        '1', '1', ')', '(', '1', '2', ')', '(', '1', '3', ')', '(', '1', '4', ')', '(', '1', '5',
+        ')', '(', '1', '6', ')', '(', '1', '7', ')', '(', '1', '8', ')', '(', '1', '9', ')', '(',
+        '2', '0', ')', '1', '.', '2', '.', '3', '.', '4', '.', '5', '.', '6', '.', '7', '.', '8',
+        '.', '9', '.', '1', '0', '.', '1', '1', '.', '1', '2', '.', '1', '3', '.', '1', '4', '.',
+        '1', '5', '.', '1', '6', '.', '1', '7', '.', '1', '8', '.', '1', '9', '.', '2', '0', '.',
+        '(', 'a', ')', '(', 'b', ')', '(', 'c', ')', '(', 'd', ')', '(', 'e', ')', '(', 'f', ')',
+        '(', 'g', ')', '(', 'h', ')', '(', 'i', ')', '(', 'j', ')', '(', 'k', ')', '(', 'l', ')',
+        '(', 'm', ')', '(', 'n', ')', '(', 'o', ')', '(', 'p', ')', '(', 'q', ')', '(', 'r', ')',
+        '(', 's', ')', '(', 't', ')', '(', 'u', ')', '(', 'v', ')', '(', 'w', ')', '(', 'x', ')',
+        '(', 'y', ')', '(', 'z', ')', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L',
+        'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd',
+        'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v',
+        'w', 'x', 'y', 'z', '0', '\u{222b}', '\u{222b}', '\u{222b}', '\u{222b}', ':', ':', '=', '=',
+        '=', '=', '=', '=', 'j', 'V', '\u{2d61}', '\u{6bcd}', '\u{9f9f}', '\u{4e00}', '\u{4e28}',
+        '\u{4e36}', '\u{4e3f}', '\u{4e59}', '\u{4e85}', '\u{4e8c}', '\u{4ea0}', '\u{4eba}',
+        '\u{513f}', '\u{5165}', '\u{516b}', '\u{5182}', '\u{5196}', '\u{51ab}', '\u{51e0}',
+        '\u{51f5}', '\u{5200}', '\u{529b}', '\u{52f9}', '\u{5315}', '\u{531a}', '\u{5338}',
+        '\u{5341}', '\u{535c}', '\u{5369}', '\u{5382}', '\u{53b6}', '\u{53c8}', '\u{53e3}',
+        '\u{56d7}', '\u{571f}', '\u{58eb}', '\u{5902}', '\u{590a}', '\u{5915}', '\u{5927}',
+        '\u{5973}', '\u{5b50}', '\u{5b80}', '\u{5bf8}', '\u{5c0f}', '\u{5c22}', '\u{5c38}',
+        '\u{5c6e}', '\u{5c71}', '\u{5ddb}', '\u{5de5}', '\u{5df1}', '\u{5dfe}', '\u{5e72}',
+        '\u{5e7a}', '\u{5e7f}', '\u{5ef4}', '\u{5efe}', '\u{5f0b}', '\u{5f13}', '\u{5f50}',
+        '\u{5f61}', '\u{5f73}', '\u{5fc3}', '\u{6208}', '\u{6236}', '\u{624b}', '\u{652f}',
+        '\u{6534}', '\u{6587}', '\u{6597}', '\u{65a4}', '\u{65b9}', '\u{65e0}', '\u{65e5}',
+        '\u{66f0}', '\u{6708}', '\u{6728}', '\u{6b20}', '\u{6b62}', '\u{6b79}', '\u{6bb3}',
+        '\u{6bcb}', '\u{6bd4}', '\u{6bdb}', '\u{6c0f}', '\u{6c14}', '\u{6c34}', '\u{706b}',
+        '\u{722a}', '\u{7236}', '\u{723b}', '\u{723f}', '\u{7247}', '\u{7259}', '\u{725b}',
+        '\u{72ac}', '\u{7384}', '\u{7389}', '\u{74dc}', '\u{74e6}', '\u{7518}', '\u{751f}',
+        '\u{7528}', '\u{7530}', '\u{758b}', '\u{7592}', '\u{7676}', '\u{767d}', '\u{76ae}',
+        '\u{76bf}', '\u{76ee}', '\u{77db}', '\u{77e2}', '\u{77f3}', '\u{793a}', '\u{79b8}',
+        '\u{79be}', '\u{7a74}', '\u{7acb}', '\u{7af9}', '\u{7c73}', '\u{7cf8}', '\u{7f36}',
+        '\u{7f51}', '\u{7f8a}', '\u{7fbd}', '\u{8001}', '\u{800c}', '\u{8012}', '\u{8033}',
+        '\u{807f}', '\u{8089}', '\u{81e3}', '\u{81ea}', '\u{81f3}', '\u{81fc}', '\u{820c}',
+        '\u{821b}', '\u{821f}', '\u{826e}', '\u{8272}', '\u{8278}', '\u{864d}', '\u{866b}',
+        '\u{8840}', '\u{884c}', '\u{8863}', '\u{897e}', '\u{898b}', '\u{89d2}', '\u{8a00}',
+        '\u{8c37}', '\u{8c46}', '\u{8c55}', '\u{8c78}', '\u{8c9d}', '\u{8d64}', '\u{8d70}',
+        '\u{8db3}', '\u{8eab}', '\u{8eca}', '\u{8f9b}', '\u{8fb0}', '\u{8fb5}', '\u{9091}',
+        '\u{9149}', '\u{91c6}', '\u{91cc}', '\u{91d1}', '\u{9577}', '\u{9580}', '\u{961c}',
+        '\u{96b6}', '\u{96b9}', '\u{96e8}', '\u{9751}', '\u{975e}', '\u{9762}', '\u{9769}',
+        '\u{97cb}', '\u{97ed}', '\u{97f3}', '\u{9801}', '\u{98a8}', '\u{98db}', '\u{98df}',
+        '\u{9996}', '\u{9999}', '\u{99ac}', '\u{9aa8}', '\u{9ad8}', '\u{9adf}', '\u{9b25}',
+        '\u{9b2f}', '\u{9b32}', '\u{9b3c}', '\u{9b5a}', '\u{9ce5}', '\u{9e75}', '\u{9e7f}',
+        '\u{9ea5}', '\u{9ebb}', '\u{9ec3}', '\u{9ecd}', '\u{9ed1}', '\u{9ef9}', '\u{9efd}',
+        '\u{9f0e}', '\u{9f13}', '\u{9f20}', '\u{9f3b}', '\u{9f4a}', '\u{9f52}', '\u{9f8d}',
+        '\u{9f9c}', '\u{9fa0}', ' ', '\u{3012}', '\u{5341}', '\u{5344}', '\u{5345}', ' ',
+        '\u{3099}', ' ', '\u{309a}', '\u{3088}', '\u{308a}', '\u{30b3}', '\u{30c8}', '\u{1100}',
+        '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}', '\u{11ad}', '\u{1103}', '\u{1104}',
+        '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}', '\u{11b3}', '\u{11b4}', '\u{11b5}',
+        '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110a}',
+        '\u{110b}', '\u{110c}', '\u{110d}', '\u{110e}', '\u{110f}', '\u{1110}', '\u{1111}',
+        '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}',
+        '\u{1167}', '\u{1168}', '\u{1169}', '\u{116a}', '\u{116b}', '\u{116c}', '\u{116d}',
+        '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}', '\u{1172}', '\u{1173}', '\u{1174}',
+        '\u{1175}', '\u{1160}', '\u{1114}', '\u{1115}', '\u{11c7}', '\u{11c8}', '\u{11cc}',
+        '\u{11ce}', '\u{11d3}', '\u{11d7}', '\u{11d9}', '\u{111c}', '\u{11dd}', '\u{11df}',
+        '\u{111d}', '\u{111e}', '\u{1120}', '\u{1122}', '\u{1123}', '\u{1127}', '\u{1129}',
+        '\u{112b}', '\u{112c}', '\u{112d}', '\u{112e}', '\u{112f}', '\u{1132}', '\u{1136}',
+        '\u{1140}', '\u{1147}', '\u{114c}', '\u{11f1}', '\u{11f2}', '\u{1157}', '\u{1158}',
+        '\u{1159}', '\u{1184}', '\u{1185}', '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}',
+        '\u{119e}', '\u{11a1}', '\u{4e00}', '\u{4e8c}', '\u{4e09}', '\u{56db}', '\u{4e0a}',
+        '\u{4e2d}', '\u{4e0b}', '\u{7532}', '\u{4e59}', '\u{4e19}', '\u{4e01}', '\u{5929}',
+        '\u{5730}', '\u{4eba}', '(', '\u{1100}', ')', '(', '\u{1102}', ')', '(', '\u{1103}', ')',
+        '(', '\u{1105}', ')', '(', '\u{1106}', ')', '(', '\u{1107}', ')', '(', '\u{1109}', ')', '(',
+        '\u{110b}', ')', '(', '\u{110c}', ')', '(', '\u{110e}', ')', '(', '\u{110f}', ')', '(',
+        '\u{1110}', ')', '(', '\u{1111}', ')', '(', '\u{1112}', ')', '(', '\u{1100}', '\u{1161}',
+        ')', '(', '\u{1102}', '\u{1161}', ')', '(', '\u{1103}', '\u{1161}', ')', '(', '\u{1105}',
+        '\u{1161}', ')', '(', '\u{1106}', '\u{1161}', ')', '(', '\u{1107}', '\u{1161}', ')', '(',
+        '\u{1109}', '\u{1161}', ')', '(', '\u{110b}', '\u{1161}', ')', '(', '\u{110c}', '\u{1161}',
+        ')', '(', '\u{110e}', '\u{1161}', ')', '(', '\u{110f}', '\u{1161}', ')', '(', '\u{1110}',
+        '\u{1161}', ')', '(', '\u{1111}', '\u{1161}', ')', '(', '\u{1112}', '\u{1161}', ')', '(',
+        '\u{110c}', '\u{116e}', ')', '(', '\u{110b}', '\u{1169}', '\u{110c}', '\u{1165}',
+        '\u{11ab}', ')', '(', '\u{110b}', '\u{1169}', '\u{1112}', '\u{116e}', ')', '(', '\u{4e00}',
+        ')', '(', '\u{4e8c}', ')', '(', '\u{4e09}', ')', '(', '\u{56db}', ')', '(', '\u{4e94}', ')',
+        '(', '\u{516d}', ')', '(', '\u{4e03}', ')', '(', '\u{516b}', ')', '(', '\u{4e5d}', ')', '(',
+        '\u{5341}', ')', '(', '\u{6708}', ')', '(', '\u{706b}', ')', '(', '\u{6c34}', ')', '(',
+        '\u{6728}', ')', '(', '\u{91d1}', ')', '(', '\u{571f}', ')', '(', '\u{65e5}', ')', '(',
+        '\u{682a}', ')', '(', '\u{6709}', ')', '(', '\u{793e}', ')', '(', '\u{540d}', ')', '(',
+        '\u{7279}', ')', '(', '\u{8ca1}', ')', '(', '\u{795d}', ')', '(', '\u{52b4}', ')', '(',
+        '\u{4ee3}', ')', '(', '\u{547c}', ')', '(', '\u{5b66}', ')', '(', '\u{76e3}', ')', '(',
+        '\u{4f01}', ')', '(', '\u{8cc7}', ')', '(', '\u{5354}', ')', '(', '\u{796d}', ')', '(',
+        '\u{4f11}', ')', '(', '\u{81ea}', ')', '(', '\u{81f3}', ')', '\u{554f}', '\u{5e7c}',
+        '\u{6587}', '\u{7b8f}', 'P', 'T', 'E', '2', '1', '2', '2', '2', '3', '2', '4', '2', '5',
+        '2', '6', '2', '7', '2', '8', '2', '9', '3', '0', '3', '1', '3', '2', '3', '3', '3', '4',
+        '3', '5', '\u{1100}', '\u{1102}', '\u{1103}', '\u{1105}', '\u{1106}', '\u{1107}',
+        '\u{1109}', '\u{110b}', '\u{110c}', '\u{110e}', '\u{110f}', '\u{1110}', '\u{1111}',
+        '\u{1112}', '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}',
+        '\u{1105}', '\u{1161}', '\u{1106}', '\u{1161}', '\u{1107}', '\u{1161}', '\u{1109}',
+        '\u{1161}', '\u{110b}', '\u{1161}', '\u{110c}', '\u{1161}', '\u{110e}', '\u{1161}',
+        '\u{110f}', '\u{1161}', '\u{1110}', '\u{1161}', '\u{1111}', '\u{1161}', '\u{1112}',
+        '\u{1161}', '\u{110e}', '\u{1161}', '\u{11b7}', '\u{1100}', '\u{1169}', '\u{110c}',
+        '\u{116e}', '\u{110b}', '\u{1174}', '\u{110b}', '\u{116e}', '\u{4e00}', '\u{4e8c}',
+        '\u{4e09}', '\u{56db}', '\u{4e94}', '\u{516d}', '\u{4e03}', '\u{516b}', '\u{4e5d}',
+        '\u{5341}', '\u{6708}', '\u{706b}', '\u{6c34}', '\u{6728}', '\u{91d1}', '\u{571f}',
+        '\u{65e5}', '\u{682a}', '\u{6709}', '\u{793e}', '\u{540d}', '\u{7279}', '\u{8ca1}',
+        '\u{795d}', '\u{52b4}', '\u{79d8}', '\u{7537}', '\u{5973}', '\u{9069}', '\u{512a}',
+        '\u{5370}', '\u{6ce8}', '\u{9805}', '\u{4f11}', '\u{5199}', '\u{6b63}', '\u{4e0a}',
+        '\u{4e2d}', '\u{4e0b}', '\u{5de6}', '\u{53f3}', '\u{533b}', '\u{5b97}', '\u{5b66}',
+        '\u{76e3}', '\u{4f01}', '\u{8cc7}', '\u{5354}', '\u{591c}', '3', '6', '3', '7', '3', '8',
+        '3', '9', '4', '0', '4', '1', '4', '2', '4', '3', '4', '4', '4', '5', '4', '6', '4', '7',
+        '4', '8', '4', '9', '5', '0', '1', '\u{6708}', '2', '\u{6708}', '3', '\u{6708}', '4',
+        '\u{6708}', '5', '\u{6708}', '6', '\u{6708}', '7', '\u{6708}', '8', '\u{6708}', '9',
+        '\u{6708}', '1', '0', '\u{6708}', '1', '1', '\u{6708}', '1', '2', '\u{6708}', 'H', 'g', 'e',
+        'r', 'g', 'e', 'V', 'L', 'T', 'D', '\u{30a2}', '\u{30a4}', '\u{30a6}', '\u{30a8}',
+        '\u{30aa}', '\u{30ab}', '\u{30ad}', '\u{30af}', '\u{30b1}', '\u{30b3}', '\u{30b5}',
+        '\u{30b7}', '\u{30b9}', '\u{30bb}', '\u{30bd}', '\u{30bf}', '\u{30c1}', '\u{30c4}',
+        '\u{30c6}', '\u{30c8}', '\u{30ca}', '\u{30cb}', '\u{30cc}', '\u{30cd}', '\u{30ce}',
+        '\u{30cf}', '\u{30d2}', '\u{30d5}', '\u{30d8}', '\u{30db}', '\u{30de}', '\u{30df}',
+        '\u{30e0}', '\u{30e1}', '\u{30e2}', '\u{30e4}', '\u{30e6}', '\u{30e8}', '\u{30e9}',
+        '\u{30ea}', '\u{30eb}', '\u{30ec}', '\u{30ed}', '\u{30ef}', '\u{30f0}', '\u{30f1}',
+        '\u{30f2}', '\u{4ee4}', '\u{548c}', '\u{30a2}', '\u{30cf}', '\u{309a}', '\u{30fc}',
+        '\u{30c8}', '\u{30a2}', '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30a2}', '\u{30f3}',
+        '\u{30d8}', '\u{309a}', '\u{30a2}', '\u{30a2}', '\u{30fc}', '\u{30eb}', '\u{30a4}',
+        '\u{30cb}', '\u{30f3}', '\u{30af}', '\u{3099}', '\u{30a4}', '\u{30f3}', '\u{30c1}',
+        '\u{30a6}', '\u{30a9}', '\u{30f3}', '\u{30a8}', '\u{30b9}', '\u{30af}', '\u{30fc}',
+        '\u{30c8}', '\u{3099}', '\u{30a8}', '\u{30fc}', '\u{30ab}', '\u{30fc}', '\u{30aa}',
+        '\u{30f3}', '\u{30b9}', '\u{30aa}', '\u{30fc}', '\u{30e0}', '\u{30ab}', '\u{30a4}',
+        '\u{30ea}', '\u{30ab}', '\u{30e9}', '\u{30c3}', '\u{30c8}', '\u{30ab}', '\u{30ed}',
+        '\u{30ea}', '\u{30fc}', '\u{30ab}', '\u{3099}', '\u{30ed}', '\u{30f3}', '\u{30ab}',
+        '\u{3099}', '\u{30f3}', '\u{30de}', '\u{30ad}', '\u{3099}', '\u{30ab}', '\u{3099}',
+        '\u{30ad}', '\u{3099}', '\u{30cb}', '\u{30fc}', '\u{30ad}', '\u{30e5}', '\u{30ea}',
+        '\u{30fc}', '\u{30ad}', '\u{3099}', '\u{30eb}', '\u{30bf}', '\u{3099}', '\u{30fc}',
+        '\u{30ad}', '\u{30ed}', '\u{30ad}', '\u{30ed}', '\u{30af}', '\u{3099}', '\u{30e9}',
+        '\u{30e0}', '\u{30ad}', '\u{30ed}', '\u{30e1}', '\u{30fc}', '\u{30c8}', '\u{30eb}',
+        '\u{30ad}', '\u{30ed}', '\u{30ef}', '\u{30c3}', '\u{30c8}', '\u{30af}', '\u{3099}',
+        '\u{30e9}', '\u{30e0}', '\u{30af}', '\u{3099}', '\u{30e9}', '\u{30e0}', '\u{30c8}',
+        '\u{30f3}', '\u{30af}', '\u{30eb}', '\u{30bb}', '\u{3099}', '\u{30a4}', '\u{30ed}',
+        '\u{30af}', '\u{30ed}', '\u{30fc}', '\u{30cd}', '\u{30b1}', '\u{30fc}', '\u{30b9}',
+        '\u{30b3}', '\u{30eb}', '\u{30ca}', '\u{30b3}', '\u{30fc}', '\u{30db}', '\u{309a}',
+        '\u{30b5}', '\u{30a4}', '\u{30af}', '\u{30eb}', '\u{30b5}', '\u{30f3}', '\u{30c1}',
+        '\u{30fc}', '\u{30e0}', '\u{30b7}', '\u{30ea}', '\u{30f3}', '\u{30af}', '\u{3099}',
+        '\u{30bb}', '\u{30f3}', '\u{30c1}', '\u{30bb}', '\u{30f3}', '\u{30c8}', '\u{30bf}',
+        '\u{3099}', '\u{30fc}', '\u{30b9}', '\u{30c6}', '\u{3099}', '\u{30b7}', '\u{30c8}',
+        '\u{3099}', '\u{30eb}', '\u{30c8}', '\u{30f3}', '\u{30ca}', '\u{30ce}', '\u{30ce}',
+        '\u{30c3}', '\u{30c8}', '\u{30cf}', '\u{30a4}', '\u{30c4}', '\u{30cf}', '\u{309a}',
+        '\u{30fc}', '\u{30bb}', '\u{30f3}', '\u{30c8}', '\u{30cf}', '\u{309a}', '\u{30fc}',
+        '\u{30c4}', '\u{30cf}', '\u{3099}', '\u{30fc}', '\u{30ec}', '\u{30eb}', '\u{30d2}',
+        '\u{309a}', '\u{30a2}', '\u{30b9}', '\u{30c8}', '\u{30eb}', '\u{30d2}', '\u{309a}',
+        '\u{30af}', '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30b3}', '\u{30d2}', '\u{3099}',
+        '\u{30eb}', '\u{30d5}', '\u{30a1}', '\u{30e9}', '\u{30c3}', '\u{30c8}', '\u{3099}',
+        '\u{30d5}', '\u{30a3}', '\u{30fc}', '\u{30c8}', '\u{30d5}', '\u{3099}', '\u{30c3}',
+        '\u{30b7}', '\u{30a7}', '\u{30eb}', '\u{30d5}', '\u{30e9}', '\u{30f3}', '\u{30d8}',
+        '\u{30af}', '\u{30bf}', '\u{30fc}', '\u{30eb}', '\u{30d8}', '\u{309a}', '\u{30bd}',
+        '\u{30d8}', '\u{309a}', '\u{30cb}', '\u{30d2}', '\u{30d8}', '\u{30eb}', '\u{30c4}',
+        '\u{30d8}', '\u{309a}', '\u{30f3}', '\u{30b9}', '\u{30d8}', '\u{309a}', '\u{30fc}',
+        '\u{30b7}', '\u{3099}', '\u{30d8}', '\u{3099}', '\u{30fc}', '\u{30bf}', '\u{30db}',
+        '\u{309a}', '\u{30a4}', '\u{30f3}', '\u{30c8}', '\u{30db}', '\u{3099}', '\u{30eb}',
+        '\u{30c8}', '\u{30db}', '\u{30f3}', '\u{30db}', '\u{309a}', '\u{30f3}', '\u{30c8}',
+        '\u{3099}', '\u{30db}', '\u{30fc}', '\u{30eb}', '\u{30db}', '\u{30fc}', '\u{30f3}',
+        '\u{30de}', '\u{30a4}', '\u{30af}', '\u{30ed}', '\u{30de}', '\u{30a4}', '\u{30eb}',
+        '\u{30de}', '\u{30c3}', '\u{30cf}', '\u{30de}', '\u{30eb}', '\u{30af}', '\u{30de}',
+        '\u{30f3}', '\u{30b7}', '\u{30e7}', '\u{30f3}', '\u{30df}', '\u{30af}', '\u{30ed}',
+        '\u{30f3}', '\u{30df}', '\u{30ea}', '\u{30df}', '\u{30ea}', '\u{30cf}', '\u{3099}',
+        '\u{30fc}', '\u{30eb}', '\u{30e1}', '\u{30ab}', '\u{3099}', '\u{30e1}', '\u{30ab}',
+        '\u{3099}', '\u{30c8}', '\u{30f3}', '\u{30e1}', '\u{30fc}', '\u{30c8}', '\u{30eb}',
+        '\u{30e4}', '\u{30fc}', '\u{30c8}', '\u{3099}', '\u{30e4}', '\u{30fc}', '\u{30eb}',
+        '\u{30e6}', '\u{30a2}', '\u{30f3}', '\u{30ea}', '\u{30c3}', '\u{30c8}', '\u{30eb}',
+        '\u{30ea}', '\u{30e9}', '\u{30eb}', '\u{30d2}', '\u{309a}', '\u{30fc}', '\u{30eb}',
+        '\u{30fc}', '\u{30d5}', '\u{3099}', '\u{30eb}', '\u{30ec}', '\u{30e0}', '\u{30ec}',
+        '\u{30f3}', '\u{30c8}', '\u{30b1}', '\u{3099}', '\u{30f3}', '\u{30ef}', '\u{30c3}',
+        '\u{30c8}', '0', '\u{70b9}', '1', '\u{70b9}', '2', '\u{70b9}', '3', '\u{70b9}', '4',
+        '\u{70b9}', '5', '\u{70b9}', '6', '\u{70b9}', '7', '\u{70b9}', '8', '\u{70b9}', '9',
+        '\u{70b9}', '1', '0', '\u{70b9}', '1', '1', '\u{70b9}', '1', '2', '\u{70b9}', '1', '3',
+        '\u{70b9}', '1', '4', '\u{70b9}', '1', '5', '\u{70b9}', '1', '6', '\u{70b9}', '1', '7',
+        '\u{70b9}', '1', '8', '\u{70b9}', '1', '9', '\u{70b9}', '2', '0', '\u{70b9}', '2', '1',
+        '\u{70b9}', '2', '2', '\u{70b9}', '2', '3', '\u{70b9}', '2', '4', '\u{70b9}', 'h', 'P', 'a',
+        'd', 'a', 'A', 'U', 'b', 'a', 'r', 'o', 'V', 'p', 'c', 'd', 'm', 'd', 'm', '2', 'd', 'm',
+        '3', 'I', 'U', '\u{5e73}', '\u{6210}', '\u{662d}', '\u{548c}', '\u{5927}', '\u{6b63}',
+        '\u{660e}', '\u{6cbb}', '\u{682a}', '\u{5f0f}', '\u{4f1a}', '\u{793e}', 'p', 'A', 'n', 'A',
+        '\u{3bc}', 'A', 'm', 'A', 'k', 'A', 'K', 'B', 'M', 'B', 'G', 'B', 'c', 'a', 'l', 'k', 'c',
+        'a', 'l', 'p', 'F', 'n', 'F', '\u{3bc}', 'F', '\u{3bc}', 'g', 'm', 'g', 'k', 'g', 'H', 'z',
+        'k', 'H', 'z', 'M', 'H', 'z', 'G', 'H', 'z', 'T', 'H', 'z', '\u{3bc}', 'l', 'm', 'l', 'd',
+        'l', 'k', 'l', 'f', 'm', 'n', 'm', '\u{3bc}', 'm', 'm', 'm', 'c', 'm', 'k', 'm', 'm', 'm',
+        '2', 'c', 'm', '2', 'm', '2', 'k', 'm', '2', 'm', 'm', '3', 'c', 'm', '3', 'm', '3', 'k',
+        'm', '3', 'm', '\u{2215}', 's', 'm', '\u{2215}', 's', '2', 'P', 'a', 'k', 'P', 'a', 'M',
+        'P', 'a', 'G', 'P', 'a', 'r', 'a', 'd', 'r', 'a', 'd', '\u{2215}', 's', 'r', 'a', 'd',
+        '\u{2215}', 's', '2', 'p', 's', 'n', 's', '\u{3bc}', 's', 'm', 's', 'p', 'V', 'n', 'V',
+        '\u{3bc}', 'V', 'm', 'V', 'k', 'V', 'M', 'V', 'p', 'W', 'n', 'W', '\u{3bc}', 'W', 'm', 'W',
+        'k', 'W', 'M', 'W', 'k', '\u{3a9}', 'M', '\u{3a9}', 'a', '.', 'm', '.', 'B', 'q', 'c', 'c',
+        'c', 'd', 'C', '\u{2215}', 'k', 'g', 'C', 'o', '.', 'd', 'B', 'G', 'y', 'h', 'a', 'H', 'P',
+        'i', 'n', 'K', 'K', 'K', 'M', 'k', 't', 'l', 'm', 'l', 'n', 'l', 'o', 'g', 'l', 'x', 'm',
+        'b', 'm', 'i', 'l', 'm', 'o', 'l', 'P', 'H', 'p', '.', 'm', '.', 'P', 'P', 'M', 'P', 'R',
+        's', 'r', 'S', 'v', 'W', 'b', 'V', '\u{2215}', 'm', 'A', '\u{2215}', 'm', '1', '\u{65e5}',
+        '2', '\u{65e5}', '3', '\u{65e5}', '4', '\u{65e5}', '5', '\u{65e5}', '6', '\u{65e5}', '7',
+        '\u{65e5}', '8', '\u{65e5}', '9', '\u{65e5}', '1', '0', '\u{65e5}', '1', '1', '\u{65e5}',
+        '1', '2', '\u{65e5}', '1', '3', '\u{65e5}', '1', '4', '\u{65e5}', '1', '5', '\u{65e5}', '1',
+        '6', '\u{65e5}', '1', '7', '\u{65e5}', '1', '8', '\u{65e5}', '1', '9', '\u{65e5}', '2', '0',
+        '\u{65e5}', '2', '1', '\u{65e5}', '2', '2', '\u{65e5}', '2', '3', '\u{65e5}', '2', '4',
+        '\u{65e5}', '2', '5', '\u{65e5}', '2', '6', '\u{65e5}', '2', '7', '\u{65e5}', '2', '8',
+        '\u{65e5}', '2', '9', '\u{65e5}', '3', '0', '\u{65e5}', '3', '1', '\u{65e5}', 'g', 'a', 'l',
+        '\u{44a}', '\u{44c}', '\u{a76f}', 'C', 'F', 'Q', '\u{126}', '\u{153}', '\u{a727}',
+        '\u{ab37}', '\u{26b}', '\u{ab52}', '\u{28d}', 'f', 'f', 'f', 'i', 'f', 'l', 'f', 'f', 'i',
+        'f', 'f', 'l', 's', 't', 's', 't', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}',
+        '\u{56b}', '\u{57e}', '\u{576}', '\u{574}', '\u{56d}', '\u{5e2}', '\u{5d0}', '\u{5d3}',
+        '\u{5d4}', '\u{5db}', '\u{5dc}', '\u{5dd}', '\u{5e8}', '\u{5ea}', '+', '\u{5d0}', '\u{5dc}',
+        '\u{671}', '\u{671}', '\u{67b}', '\u{67b}', '\u{67b}', '\u{67b}', '\u{67e}', '\u{67e}',
+        '\u{67e}', '\u{67e}', '\u{680}', '\u{680}', '\u{680}', '\u{680}', '\u{67a}', '\u{67a}',
+        '\u{67a}', '\u{67a}', '\u{67f}', '\u{67f}', '\u{67f}', '\u{67f}', '\u{679}', '\u{679}',
+        '\u{679}', '\u{679}', '\u{6a4}', '\u{6a4}', '\u{6a4}', '\u{6a4}', '\u{6a6}', '\u{6a6}',
+        '\u{6a6}', '\u{6a6}', '\u{684}', '\u{684}', '\u{684}', '\u{684}', '\u{683}', '\u{683}',
+        '\u{683}', '\u{683}', '\u{686}', '\u{686}', '\u{686}', '\u{686}', '\u{687}', '\u{687}',
+        '\u{687}', '\u{687}', '\u{68d}', '\u{68d}', '\u{68c}', '\u{68c}', '\u{68e}', '\u{68e}',
+        '\u{688}', '\u{688}', '\u{698}', '\u{698}', '\u{691}', '\u{691}', '\u{6a9}', '\u{6a9}',
+        '\u{6a9}', '\u{6a9}', '\u{6af}', '\u{6af}', '\u{6af}', '\u{6af}', '\u{6b3}', '\u{6b3}',
+        '\u{6b3}', '\u{6b3}', '\u{6b1}', '\u{6b1}', '\u{6b1}', '\u{6b1}', '\u{6ba}', '\u{6ba}',
+        '\u{6bb}', '\u{6bb}', '\u{6bb}', '\u{6bb}', '\u{6d5}', '\u{654}', '\u{6d5}', '\u{654}',
+        '\u{6c1}', '\u{6c1}', '\u{6c1}', '\u{6c1}', '\u{6be}', '\u{6be}', '\u{6be}', '\u{6be}',
+        '\u{6d2}', '\u{6d2}', '\u{6d2}', '\u{654}', '\u{6d2}', '\u{654}', '\u{6ad}', '\u{6ad}',
+        '\u{6ad}', '\u{6ad}', '\u{6c7}', '\u{6c7}', '\u{6c6}', '\u{6c6}', '\u{6c8}', '\u{6c8}',
+        '\u{6c7}', '\u{674}', '\u{6cb}', '\u{6cb}', '\u{6c5}', '\u{6c5}', '\u{6c9}', '\u{6c9}',
+        '\u{6d0}', '\u{6d0}', '\u{6d0}', '\u{6d0}', '\u{649}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{627}', '\u{64a}', '\u{654}', '\u{627}', '\u{64a}', '\u{654}', '\u{6d5}', '\u{64a}',
+        '\u{654}', '\u{6d5}', '\u{64a}', '\u{654}', '\u{648}', '\u{64a}', '\u{654}', '\u{648}',
+        '\u{64a}', '\u{654}', '\u{6c7}', '\u{64a}', '\u{654}', '\u{6c7}', '\u{64a}', '\u{654}',
+        '\u{6c6}', '\u{64a}', '\u{654}', '\u{6c6}', '\u{64a}', '\u{654}', '\u{6c8}', '\u{64a}',
+        '\u{654}', '\u{6c8}', '\u{64a}', '\u{654}', '\u{6d0}', '\u{64a}', '\u{654}', '\u{6d0}',
+        '\u{64a}', '\u{654}', '\u{6d0}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{649}', '\u{64a}', '\u{654}', '\u{649}', '\u{6cc}', '\u{6cc}', '\u{6cc}', '\u{6cc}',
+        '\u{64a}', '\u{654}', '\u{62c}', '\u{64a}', '\u{654}', '\u{62d}', '\u{64a}', '\u{654}',
+        '\u{645}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}', '\u{64a}', '\u{628}',
+        '\u{62c}', '\u{628}', '\u{62d}', '\u{628}', '\u{62e}', '\u{628}', '\u{645}', '\u{628}',
+        '\u{649}', '\u{628}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{62a}',
+        '\u{62e}', '\u{62a}', '\u{645}', '\u{62a}', '\u{649}', '\u{62a}', '\u{64a}', '\u{62b}',
+        '\u{62c}', '\u{62b}', '\u{645}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{62c}',
+        '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{62d}', '\u{645}', '\u{62e}',
+        '\u{62c}', '\u{62e}', '\u{62d}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}',
+        '\u{62d}', '\u{633}', '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}',
+        '\u{645}', '\u{636}', '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}', '\u{636}',
+        '\u{645}', '\u{637}', '\u{62d}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}',
+        '\u{62c}', '\u{639}', '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}',
+        '\u{62c}', '\u{641}', '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{641}',
+        '\u{649}', '\u{641}', '\u{64a}', '\u{642}', '\u{62d}', '\u{642}', '\u{645}', '\u{642}',
+        '\u{649}', '\u{642}', '\u{64a}', '\u{643}', '\u{627}', '\u{643}', '\u{62c}', '\u{643}',
+        '\u{62d}', '\u{643}', '\u{62e}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}',
+        '\u{649}', '\u{643}', '\u{64a}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}', '\u{644}',
+        '\u{62e}', '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}',
+        '\u{62c}', '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{645}',
+        '\u{649}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{646}', '\u{62d}', '\u{646}',
+        '\u{62e}', '\u{646}', '\u{645}', '\u{646}', '\u{649}', '\u{646}', '\u{64a}', '\u{647}',
+        '\u{62c}', '\u{647}', '\u{645}', '\u{647}', '\u{649}', '\u{647}', '\u{64a}', '\u{64a}',
+        '\u{62c}', '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{645}', '\u{64a}',
+        '\u{649}', '\u{64a}', '\u{64a}', '\u{630}', '\u{670}', '\u{631}', '\u{670}', '\u{649}',
+        '\u{670}', ' ', '\u{64c}', '\u{651}', ' ', '\u{64d}', '\u{651}', ' ', '\u{64e}', '\u{651}',
+        ' ', '\u{64f}', '\u{651}', ' ', '\u{650}', '\u{651}', ' ', '\u{651}', '\u{670}', '\u{64a}',
+        '\u{654}', '\u{631}', '\u{64a}', '\u{654}', '\u{632}', '\u{64a}', '\u{654}', '\u{645}',
+        '\u{64a}', '\u{654}', '\u{646}', '\u{64a}', '\u{654}', '\u{649}', '\u{64a}', '\u{654}',
+        '\u{64a}', '\u{628}', '\u{631}', '\u{628}', '\u{632}', '\u{628}', '\u{645}', '\u{628}',
+        '\u{646}', '\u{628}', '\u{649}', '\u{628}', '\u{64a}', '\u{62a}', '\u{631}', '\u{62a}',
+        '\u{632}', '\u{62a}', '\u{645}', '\u{62a}', '\u{646}', '\u{62a}', '\u{649}', '\u{62a}',
+        '\u{64a}', '\u{62b}', '\u{631}', '\u{62b}', '\u{632}', '\u{62b}', '\u{645}', '\u{62b}',
+        '\u{646}', '\u{62b}', '\u{649}', '\u{62b}', '\u{64a}', '\u{641}', '\u{649}', '\u{641}',
+        '\u{64a}', '\u{642}', '\u{649}', '\u{642}', '\u{64a}', '\u{643}', '\u{627}', '\u{643}',
+        '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}', '\u{643}', '\u{64a}', '\u{644}',
+        '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64a}', '\u{645}', '\u{627}', '\u{645}',
+        '\u{645}', '\u{646}', '\u{631}', '\u{646}', '\u{632}', '\u{646}', '\u{645}', '\u{646}',
+        '\u{646}', '\u{646}', '\u{649}', '\u{646}', '\u{64a}', '\u{649}', '\u{670}', '\u{64a}',
+        '\u{631}', '\u{64a}', '\u{632}', '\u{64a}', '\u{645}', '\u{64a}', '\u{646}', '\u{64a}',
+        '\u{649}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{654}', '\u{62c}', '\u{64a}', '\u{654}',
+        '\u{62d}', '\u{64a}', '\u{654}', '\u{62e}', '\u{64a}', '\u{654}', '\u{645}', '\u{64a}',
+        '\u{654}', '\u{647}', '\u{628}', '\u{62c}', '\u{628}', '\u{62d}', '\u{628}', '\u{62e}',
+        '\u{628}', '\u{645}', '\u{628}', '\u{647}', '\u{62a}', '\u{62c}', '\u{62a}', '\u{62d}',
+        '\u{62a}', '\u{62e}', '\u{62a}', '\u{645}', '\u{62a}', '\u{647}', '\u{62b}', '\u{645}',
+        '\u{62c}', '\u{62d}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{62d}', '\u{645}',
+        '\u{62e}', '\u{62c}', '\u{62e}', '\u{645}', '\u{633}', '\u{62c}', '\u{633}', '\u{62d}',
+        '\u{633}', '\u{62e}', '\u{633}', '\u{645}', '\u{635}', '\u{62d}', '\u{635}', '\u{62e}',
+        '\u{635}', '\u{645}', '\u{636}', '\u{62c}', '\u{636}', '\u{62d}', '\u{636}', '\u{62e}',
+        '\u{636}', '\u{645}', '\u{637}', '\u{62d}', '\u{638}', '\u{645}', '\u{639}', '\u{62c}',
+        '\u{639}', '\u{645}', '\u{63a}', '\u{62c}', '\u{63a}', '\u{645}', '\u{641}', '\u{62c}',
+        '\u{641}', '\u{62d}', '\u{641}', '\u{62e}', '\u{641}', '\u{645}', '\u{642}', '\u{62d}',
+        '\u{642}', '\u{645}', '\u{643}', '\u{62c}', '\u{643}', '\u{62d}', '\u{643}', '\u{62e}',
+        '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}', '\u{62c}', '\u{644}', '\u{62d}',
+        '\u{644}', '\u{62e}', '\u{644}', '\u{645}', '\u{644}', '\u{647}', '\u{645}', '\u{62c}',
+        '\u{645}', '\u{62d}', '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{646}', '\u{62c}',
+        '\u{646}', '\u{62d}', '\u{646}', '\u{62e}', '\u{646}', '\u{645}', '\u{646}', '\u{647}',
+        '\u{647}', '\u{62c}', '\u{647}', '\u{645}', '\u{647}', '\u{670}', '\u{64a}', '\u{62c}',
+        '\u{64a}', '\u{62d}', '\u{64a}', '\u{62e}', '\u{64a}', '\u{645}', '\u{64a}', '\u{647}',
+        '\u{64a}', '\u{654}', '\u{645}', '\u{64a}', '\u{654}', '\u{647}', '\u{628}', '\u{645}',
+        '\u{628}', '\u{647}', '\u{62a}', '\u{645}', '\u{62a}', '\u{647}', '\u{62b}', '\u{645}',
+        '\u{62b}', '\u{647}', '\u{633}', '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{645}',
+        '\u{634}', '\u{647}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}', '\u{645}',
+        '\u{646}', '\u{645}', '\u{646}', '\u{647}', '\u{64a}', '\u{645}', '\u{64a}', '\u{647}',
+        '\u{640}', '\u{64e}', '\u{651}', '\u{640}', '\u{64f}', '\u{651}', '\u{640}', '\u{650}',
+        '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}', '\u{639}', '\u{649}', '\u{639}',
+        '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}', '\u{633}', '\u{649}', '\u{633}',
+        '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}', '\u{62d}', '\u{649}', '\u{62d}',
+        '\u{64a}', '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}', '\u{62e}', '\u{649}', '\u{62e}',
+        '\u{64a}', '\u{635}', '\u{649}', '\u{635}', '\u{64a}', '\u{636}', '\u{649}', '\u{636}',
+        '\u{64a}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}',
+        '\u{631}', '\u{637}', '\u{649}', '\u{637}', '\u{64a}', '\u{639}', '\u{649}', '\u{639}',
+        '\u{64a}', '\u{63a}', '\u{649}', '\u{63a}', '\u{64a}', '\u{633}', '\u{649}', '\u{633}',
+        '\u{64a}', '\u{634}', '\u{649}', '\u{634}', '\u{64a}', '\u{62d}', '\u{649}', '\u{62d}',
+        '\u{64a}', '\u{62c}', '\u{649}', '\u{62c}', '\u{64a}', '\u{62e}', '\u{649}', '\u{62e}',
+        '\u{64a}', '\u{635}', '\u{649}', '\u{635}', '\u{64a}', '\u{636}', '\u{649}', '\u{636}',
+        '\u{64a}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}',
+        '\u{631}', '\u{634}', '\u{62c}', '\u{634}', '\u{62d}', '\u{634}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{647}', '\u{637}', '\u{645}', '\u{633}',
+        '\u{62c}', '\u{633}', '\u{62d}', '\u{633}', '\u{62e}', '\u{634}', '\u{62c}', '\u{634}',
+        '\u{62d}', '\u{634}', '\u{62e}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{627}',
+        '\u{64b}', '\u{627}', '\u{64b}', '\u{62a}', '\u{62c}', '\u{645}', '\u{62a}', '\u{62d}',
+        '\u{62c}', '\u{62a}', '\u{62d}', '\u{62c}', '\u{62a}', '\u{62d}', '\u{645}', '\u{62a}',
+        '\u{62e}', '\u{645}', '\u{62a}', '\u{645}', '\u{62c}', '\u{62a}', '\u{645}', '\u{62d}',
+        '\u{62a}', '\u{645}', '\u{62e}', '\u{62c}', '\u{645}', '\u{62d}', '\u{62c}', '\u{645}',
+        '\u{62d}', '\u{62d}', '\u{645}', '\u{64a}', '\u{62d}', '\u{645}', '\u{649}', '\u{633}',
+        '\u{62d}', '\u{62c}', '\u{633}', '\u{62c}', '\u{62d}', '\u{633}', '\u{62c}', '\u{649}',
+        '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}', '\u{62d}', '\u{633}', '\u{645}',
+        '\u{62c}', '\u{633}', '\u{645}', '\u{645}', '\u{633}', '\u{645}', '\u{645}', '\u{635}',
+        '\u{62d}', '\u{62d}', '\u{635}', '\u{62d}', '\u{62d}', '\u{635}', '\u{645}', '\u{645}',
+        '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62d}', '\u{645}', '\u{634}', '\u{62c}',
+        '\u{64a}', '\u{634}', '\u{645}', '\u{62e}', '\u{634}', '\u{645}', '\u{62e}', '\u{634}',
+        '\u{645}', '\u{645}', '\u{634}', '\u{645}', '\u{645}', '\u{636}', '\u{62d}', '\u{649}',
+        '\u{636}', '\u{62e}', '\u{645}', '\u{636}', '\u{62e}', '\u{645}', '\u{637}', '\u{645}',
+        '\u{62d}', '\u{637}', '\u{645}', '\u{62d}', '\u{637}', '\u{645}', '\u{645}', '\u{637}',
+        '\u{645}', '\u{64a}', '\u{639}', '\u{62c}', '\u{645}', '\u{639}', '\u{645}', '\u{645}',
+        '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{649}', '\u{63a}', '\u{645}',
+        '\u{645}', '\u{63a}', '\u{645}', '\u{64a}', '\u{63a}', '\u{645}', '\u{649}', '\u{641}',
+        '\u{62e}', '\u{645}', '\u{641}', '\u{62e}', '\u{645}', '\u{642}', '\u{645}', '\u{62d}',
+        '\u{642}', '\u{645}', '\u{645}', '\u{644}', '\u{62d}', '\u{645}', '\u{644}', '\u{62d}',
+        '\u{64a}', '\u{644}', '\u{62d}', '\u{649}', '\u{644}', '\u{62c}', '\u{62c}', '\u{644}',
+        '\u{62c}', '\u{62c}', '\u{644}', '\u{62e}', '\u{645}', '\u{644}', '\u{62e}', '\u{645}',
+        '\u{644}', '\u{645}', '\u{62d}', '\u{644}', '\u{645}', '\u{62d}', '\u{645}', '\u{62d}',
+        '\u{62c}', '\u{645}', '\u{62d}', '\u{645}', '\u{645}', '\u{62d}', '\u{64a}', '\u{645}',
+        '\u{62c}', '\u{62d}', '\u{645}', '\u{62c}', '\u{645}', '\u{645}', '\u{62e}', '\u{62c}',
+        '\u{645}', '\u{62e}', '\u{645}', '\u{645}', '\u{62c}', '\u{62e}', '\u{647}', '\u{645}',
+        '\u{62c}', '\u{647}', '\u{645}', '\u{645}', '\u{646}', '\u{62d}', '\u{645}', '\u{646}',
+        '\u{62d}', '\u{649}', '\u{646}', '\u{62c}', '\u{645}', '\u{646}', '\u{62c}', '\u{645}',
+        '\u{646}', '\u{62c}', '\u{649}', '\u{646}', '\u{645}', '\u{64a}', '\u{646}', '\u{645}',
+        '\u{649}', '\u{64a}', '\u{645}', '\u{645}', '\u{64a}', '\u{645}', '\u{645}', '\u{628}',
+        '\u{62e}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{64a}', '\u{62a}', '\u{62c}', '\u{649}',
+        '\u{62a}', '\u{62e}', '\u{64a}', '\u{62a}', '\u{62e}', '\u{649}', '\u{62a}', '\u{645}',
+        '\u{64a}', '\u{62a}', '\u{645}', '\u{649}', '\u{62c}', '\u{645}', '\u{64a}', '\u{62c}',
+        '\u{62d}', '\u{649}', '\u{62c}', '\u{645}', '\u{649}', '\u{633}', '\u{62e}', '\u{649}',
+        '\u{635}', '\u{62d}', '\u{64a}', '\u{634}', '\u{62d}', '\u{64a}', '\u{636}', '\u{62d}',
+        '\u{64a}', '\u{644}', '\u{62c}', '\u{64a}', '\u{644}', '\u{645}', '\u{64a}', '\u{64a}',
+        '\u{62d}', '\u{64a}', '\u{64a}', '\u{62c}', '\u{64a}', '\u{64a}', '\u{645}', '\u{64a}',
+        '\u{645}', '\u{645}', '\u{64a}', '\u{642}', '\u{645}', '\u{64a}', '\u{646}', '\u{62d}',
+        '\u{64a}', '\u{642}', '\u{645}', '\u{62d}', '\u{644}', '\u{62d}', '\u{645}', '\u{639}',
+        '\u{645}', '\u{64a}', '\u{643}', '\u{645}', '\u{64a}', '\u{646}', '\u{62c}', '\u{62d}',
+        '\u{645}', '\u{62e}', '\u{64a}', '\u{644}', '\u{62c}', '\u{645}', '\u{643}', '\u{645}',
+        '\u{645}', '\u{644}', '\u{62c}', '\u{645}', '\u{646}', '\u{62c}', '\u{62d}', '\u{62c}',
+        '\u{62d}', '\u{64a}', '\u{62d}', '\u{62c}', '\u{64a}', '\u{645}', '\u{62c}', '\u{64a}',
+        '\u{641}', '\u{645}', '\u{64a}', '\u{628}', '\u{62d}', '\u{64a}', '\u{643}', '\u{645}',
+        '\u{645}', '\u{639}', '\u{62c}', '\u{645}', '\u{635}', '\u{645}', '\u{645}', '\u{633}',
+        '\u{62e}', '\u{64a}', '\u{646}', '\u{62c}', '\u{64a}', '\u{635}', '\u{644}', '\u{6d2}',
+        '\u{642}', '\u{644}', '\u{6d2}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{627}',
+        '\u{643}', '\u{628}', '\u{631}', '\u{645}', '\u{62d}', '\u{645}', '\u{62f}', '\u{635}',
+        '\u{644}', '\u{639}', '\u{645}', '\u{631}', '\u{633}', '\u{648}', '\u{644}', '\u{639}',
+        '\u{644}', '\u{64a}', '\u{647}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{635}',
+        '\u{644}', '\u{649}', '\u{635}', '\u{644}', '\u{649}', ' ', '\u{627}', '\u{644}', '\u{644}',
+        '\u{647}', ' ', '\u{639}', '\u{644}', '\u{64a}', '\u{647}', ' ', '\u{648}', '\u{633}',
+        '\u{644}', '\u{645}', '\u{62c}', '\u{644}', ' ', '\u{62c}', '\u{644}', '\u{627}', '\u{644}',
+        '\u{647}', '\u{631}', '\u{6cc}', '\u{627}', '\u{644}', ',', '\u{3001}', '\u{3002}', ':',
+        ';', '!', '?', '\u{3016}', '\u{3017}', '.', '.', '.', '.', '.', '\u{2014}', '\u{2013}', '_',
+        '_', '(', ')', '{', '}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300a}',
+        '\u{300b}', '\u{3008}', '\u{3009}', '\u{300c}', '\u{300d}', '\u{300e}', '\u{300f}', '[',
+        ']', ' ', '\u{305}', ' ', '\u{305}', ' ', '\u{305}', ' ', '\u{305}', '_', '_', '_', ',',
+        '\u{3001}', '.', ';', ':', '?', '!', '\u{2014}', '(', ')', '{', '}', '\u{3014}', '\u{3015}',
+        '#', '&', '*', '+', '-', '<', '>', '=', '\\', '$', '%', '@', ' ', '\u{64b}', '\u{640}',
+        '\u{64b}', ' ', '\u{64c}', ' ', '\u{64d}', ' ', '\u{64e}', '\u{640}', '\u{64e}', ' ',
+        '\u{64f}', '\u{640}', '\u{64f}', ' ', '\u{650}', '\u{640}', '\u{650}', ' ', '\u{651}',
+        '\u{640}', '\u{651}', ' ', '\u{652}', '\u{640}', '\u{652}', '\u{621}', '\u{627}', '\u{653}',
+        '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{627}', '\u{654}', '\u{648}', '\u{654}',
+        '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{627}', '\u{655}', '\u{64a}', '\u{654}',
+        '\u{64a}', '\u{654}', '\u{64a}', '\u{654}', '\u{64a}', '\u{654}', '\u{627}', '\u{627}',
+        '\u{628}', '\u{628}', '\u{628}', '\u{628}', '\u{629}', '\u{629}', '\u{62a}', '\u{62a}',
+        '\u{62a}', '\u{62a}', '\u{62b}', '\u{62b}', '\u{62b}', '\u{62b}', '\u{62c}', '\u{62c}',
+        '\u{62c}', '\u{62c}', '\u{62d}', '\u{62d}', '\u{62d}', '\u{62d}', '\u{62e}', '\u{62e}',
+        '\u{62e}', '\u{62e}', '\u{62f}', '\u{62f}', '\u{630}', '\u{630}', '\u{631}', '\u{631}',
+        '\u{632}', '\u{632}', '\u{633}', '\u{633}', '\u{633}', '\u{633}', '\u{634}', '\u{634}',
+        '\u{634}', '\u{634}', '\u{635}', '\u{635}', '\u{635}', '\u{635}', '\u{636}', '\u{636}',
+        '\u{636}', '\u{636}', '\u{637}', '\u{637}', '\u{637}', '\u{637}', '\u{638}', '\u{638}',
+        '\u{638}', '\u{638}', '\u{639}', '\u{639}', '\u{639}', '\u{639}', '\u{63a}', '\u{63a}',
+        '\u{63a}', '\u{63a}', '\u{641}', '\u{641}', '\u{641}', '\u{641}', '\u{642}', '\u{642}',
+        '\u{642}', '\u{642}', '\u{643}', '\u{643}', '\u{643}', '\u{643}', '\u{644}', '\u{644}',
+        '\u{644}', '\u{644}', '\u{645}', '\u{645}', '\u{645}', '\u{645}', '\u{646}', '\u{646}',
+        '\u{646}', '\u{646}', '\u{647}', '\u{647}', '\u{647}', '\u{647}', '\u{648}', '\u{648}',
+        '\u{649}', '\u{649}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{64a}', '\u{644}', '\u{627}',
+        '\u{653}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{654}', '\u{644}',
+        '\u{627}', '\u{654}', '\u{644}', '\u{627}', '\u{655}', '\u{644}', '\u{627}', '\u{655}',
+        '\u{644}', '\u{627}', '\u{644}', '\u{627}', '!', '\"', '#', '$', '%', '&', '\'', '(', ')',
+        '*', '+', ',', '-', '.', '/', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';',
+        '<', '=', '>', '?', '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[', '\\', ']', '^', '_',
+        '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+        'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '{', '|', '}', '~', '\u{2985}', '\u{2986}',
+        '\u{3002}', '\u{300c}', '\u{300d}', '\u{3001}', '\u{30fb}', '\u{30f2}', '\u{30a1}',
+        '\u{30a3}', '\u{30a5}', '\u{30a7}', '\u{30a9}', '\u{30e3}', '\u{30e5}', '\u{30e7}',
+        '\u{30c3}', '\u{30fc}', '\u{30a2}', '\u{30a4}', '\u{30a6}', '\u{30a8}', '\u{30aa}',
+        '\u{30ab}', '\u{30ad}', '\u{30af}', '\u{30b1}', '\u{30b3}', '\u{30b5}', '\u{30b7}',
+        '\u{30b9}', '\u{30bb}', '\u{30bd}', '\u{30bf}', '\u{30c1}', '\u{30c4}', '\u{30c6}',
+        '\u{30c8}', '\u{30ca}', '\u{30cb}', '\u{30cc}', '\u{30cd}', '\u{30ce}', '\u{30cf}',
+        '\u{30d2}', '\u{30d5}', '\u{30d8}', '\u{30db}', '\u{30de}', '\u{30df}', '\u{30e0}',
+        '\u{30e1}', '\u{30e2}', '\u{30e4}', '\u{30e6}', '\u{30e8}', '\u{30e9}', '\u{30ea}',
+        '\u{30eb}', '\u{30ec}', '\u{30ed}', '\u{30ef}', '\u{30f3}', '\u{3099}', '\u{309a}',
+        '\u{1160}', '\u{1100}', '\u{1101}', '\u{11aa}', '\u{1102}', '\u{11ac}', '\u{11ad}',
+        '\u{1103}', '\u{1104}', '\u{1105}', '\u{11b0}', '\u{11b1}', '\u{11b2}', '\u{11b3}',
+        '\u{11b4}', '\u{11b5}', '\u{111a}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}',
+        '\u{1109}', '\u{110a}', '\u{110b}', '\u{110c}', '\u{110d}', '\u{110e}', '\u{110f}',
+        '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}',
+        '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}', '\u{116a}', '\u{116b}',
+        '\u{116c}', '\u{116d}', '\u{116e}', '\u{116f}', '\u{1170}', '\u{1171}', '\u{1172}',
+        '\u{1173}', '\u{1174}', '\u{1175}', '\u{a2}', '\u{a3}', '\u{ac}', ' ', '\u{304}', '\u{a6}',
+        '\u{a5}', '\u{20a9}', '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}', '\u{2193}',
+        '\u{25a0}', '\u{25cb}', '\u{2d0}', '\u{2d1}', '\u{e6}', '\u{299}', '\u{253}', '\u{2a3}',
+        '\u{ab66}', '\u{2a5}', '\u{2a4}', '\u{256}', '\u{257}', '\u{1d91}', '\u{258}', '\u{25e}',
+        '\u{2a9}', '\u{264}', '\u{262}', '\u{260}', '\u{29b}', '\u{127}', '\u{29c}', '\u{267}',
+        '\u{284}', '\u{2aa}', '\u{2ab}', '\u{26c}', '\u{1df04}', '\u{a78e}', '\u{26e}', '\u{1df05}',
+        '\u{28e}', '\u{1df06}', '\u{f8}', '\u{276}', '\u{277}', 'q', '\u{27a}', '\u{1df08}',
+        '\u{27d}', '\u{27e}', '\u{280}', '\u{2a8}', '\u{2a6}', '\u{ab67}', '\u{2a7}', '\u{288}',
+        '\u{2c71}', '\u{28f}', '\u{2a1}', '\u{2a2}', '\u{298}', '\u{1c0}', '\u{1c1}', '\u{1c2}',
+        '\u{1df0a}', '\u{1df1e}', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e',
+        'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
+        'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j',
+        'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'C',
+        'D', 'G', 'J', 'K', 'N', 'O', 'P', 'Q', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b',
+        'c', 'd', 'f', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+        'z', 'A', 'B', 'D', 'E', 'F', 'G', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'S', 'T', 'U',
+        'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n',
+        'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'D', 'E', 'F', 'G',
+        'I', 'J', 'K', 'L', 'M', 'O', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'a', 'b', 'c', 'd', 'e',
+        'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w',
+        'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+        'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+        'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q',
+        'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
+        'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A',
+        'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+        'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k',
+        'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C',
+        'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U',
+        'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E',
+        'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W',
+        'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+        'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+        'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y',
+        'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
+        'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '\u{131}', '\u{237}', '\u{391}', '\u{392}',
+        '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}',
+        '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}',
+        '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}',
+        '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}',
+        '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}',
+        '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}',
+        '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}',
+        '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}',
+        '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}',
+        '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}',
+        '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}',
+        '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}',
+        '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}',
+        '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}',
+        '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}',
+        '\u{397}', '\u{398}', '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}',
+        '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}',
+        '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}',
+        '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}',
+        '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}',
+        '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}',
+        '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}',
+        '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}', '\u{39b}', '\u{39c}',
+        '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}', '\u{3a3}', '\u{3a4}',
+        '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}', '\u{3b1}', '\u{3b2}',
+        '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}', '\u{3b9}', '\u{3ba}',
+        '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}', '\u{3c1}', '\u{3c2}',
+        '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}', '\u{3c9}', '\u{2202}',
+        '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}', '\u{391}', '\u{392}',
+        '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39a}',
+        '\u{39b}', '\u{39c}', '\u{39d}', '\u{39e}', '\u{39f}', '\u{3a0}', '\u{3a1}', '\u{398}',
+        '\u{3a3}', '\u{3a4}', '\u{3a5}', '\u{3a6}', '\u{3a7}', '\u{3a8}', '\u{3a9}', '\u{2207}',
+        '\u{3b1}', '\u{3b2}', '\u{3b3}', '\u{3b4}', '\u{3b5}', '\u{3b6}', '\u{3b7}', '\u{3b8}',
+        '\u{3b9}', '\u{3ba}', '\u{3bb}', '\u{3bc}', '\u{3bd}', '\u{3be}', '\u{3bf}', '\u{3c0}',
+        '\u{3c1}', '\u{3c2}', '\u{3c3}', '\u{3c4}', '\u{3c5}', '\u{3c6}', '\u{3c7}', '\u{3c8}',
+        '\u{3c9}', '\u{2202}', '\u{3b5}', '\u{3b8}', '\u{3ba}', '\u{3c6}', '\u{3c1}', '\u{3c0}',
+        '\u{3dc}', '\u{3dd}', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '1', '2', '3',
+        '4', '5', '6', '7', '8', '9', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '0', '1',
+        '2', '3', '4', '5', '6', '7', '8', '9', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+        '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}', '\u{437}',
+        '\u{438}', '\u{43a}', '\u{43b}', '\u{43c}', '\u{43e}', '\u{43f}', '\u{440}', '\u{441}',
+        '\u{442}', '\u{443}', '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{44b}',
+        '\u{44d}', '\u{44e}', '\u{a689}', '\u{4d9}', '\u{456}', '\u{458}', '\u{4e9}', '\u{4af}',
+        '\u{4cf}', '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}',
+        '\u{437}', '\u{438}', '\u{43a}', '\u{43b}', '\u{43e}', '\u{43f}', '\u{441}', '\u{443}',
+        '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{44a}', '\u{44b}', '\u{491}',
+        '\u{456}', '\u{455}', '\u{45f}', '\u{4ab}', '\u{a651}', '\u{4b1}', '\u{627}', '\u{628}',
+        '\u{62c}', '\u{62f}', '\u{648}', '\u{632}', '\u{62d}', '\u{637}', '\u{64a}', '\u{643}',
+        '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}',
+        '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}', '\u{636}', '\u{638}',
+        '\u{63a}', '\u{66e}', '\u{6ba}', '\u{6a1}', '\u{66f}', '\u{628}', '\u{62c}', '\u{647}',
+        '\u{62d}', '\u{64a}', '\u{643}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}',
+        '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{636}',
+        '\u{63a}', '\u{62c}', '\u{62d}', '\u{64a}', '\u{644}', '\u{646}', '\u{633}', '\u{639}',
+        '\u{635}', '\u{642}', '\u{634}', '\u{62e}', '\u{636}', '\u{63a}', '\u{6ba}', '\u{66f}',
+        '\u{628}', '\u{62c}', '\u{647}', '\u{62d}', '\u{637}', '\u{64a}', '\u{643}', '\u{645}',
+        '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62a}',
+        '\u{62b}', '\u{62e}', '\u{636}', '\u{638}', '\u{63a}', '\u{66e}', '\u{6a1}', '\u{627}',
+        '\u{628}', '\u{62c}', '\u{62f}', '\u{647}', '\u{648}', '\u{632}', '\u{62d}', '\u{637}',
+        '\u{64a}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}',
+        '\u{642}', '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}', '\u{636}',
+        '\u{638}', '\u{63a}', '\u{628}', '\u{62c}', '\u{62f}', '\u{648}', '\u{632}', '\u{62d}',
+        '\u{637}', '\u{64a}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}',
+        '\u{635}', '\u{642}', '\u{631}', '\u{634}', '\u{62a}', '\u{62b}', '\u{62e}', '\u{630}',
+        '\u{636}', '\u{638}', '\u{63a}', '0', '.', '0', ',', '1', ',', '2', ',', '3', ',', '4', ',',
+        '5', ',', '6', ',', '7', ',', '8', ',', '9', ',', '(', 'A', ')', '(', 'B', ')', '(', 'C',
+        ')', '(', 'D', ')', '(', 'E', ')', '(', 'F', ')', '(', 'G', ')', '(', 'H', ')', '(', 'I',
+        ')', '(', 'J', ')', '(', 'K', ')', '(', 'L', ')', '(', 'M', ')', '(', 'N', ')', '(', 'O',
+        ')', '(', 'P', ')', '(', 'Q', ')', '(', 'R', ')', '(', 'S', ')', '(', 'T', ')', '(', 'U',
+        ')', '(', 'V', ')', '(', 'W', ')', '(', 'X', ')', '(', 'Y', ')', '(', 'Z', ')', '\u{3014}',
+        'S', '\u{3015}', 'C', 'R', 'C', 'D', 'W', 'Z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I',
+        'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'H',
+        'V', 'M', 'V', 'S', 'D', 'S', 'S', 'P', 'P', 'V', 'W', 'C', 'M', 'C', 'M', 'D', 'M', 'R',
+        'D', 'J', '\u{307b}', '\u{304b}', '\u{30b3}', '\u{30b3}', '\u{30b5}', '\u{624b}',
+        '\u{5b57}', '\u{53cc}', '\u{30c6}', '\u{3099}', '\u{4e8c}', '\u{591a}', '\u{89e3}',
+        '\u{5929}', '\u{4ea4}', '\u{6620}', '\u{7121}', '\u{6599}', '\u{524d}', '\u{5f8c}',
+        '\u{518d}', '\u{65b0}', '\u{521d}', '\u{7d42}', '\u{751f}', '\u{8ca9}', '\u{58f0}',
+        '\u{5439}', '\u{6f14}', '\u{6295}', '\u{6355}', '\u{4e00}', '\u{4e09}', '\u{904a}',
+        '\u{5de6}', '\u{4e2d}', '\u{53f3}', '\u{6307}', '\u{8d70}', '\u{6253}', '\u{7981}',
+        '\u{7a7a}', '\u{5408}', '\u{6e80}', '\u{6709}', '\u{6708}', '\u{7533}', '\u{5272}',
+        '\u{55b6}', '\u{914d}', '\u{3014}', '\u{672c}', '\u{3015}', '\u{3014}', '\u{4e09}',
+        '\u{3015}', '\u{3014}', '\u{4e8c}', '\u{3015}', '\u{3014}', '\u{5b89}', '\u{3015}',
+        '\u{3014}', '\u{70b9}', '\u{3015}', '\u{3014}', '\u{6253}', '\u{3015}', '\u{3014}',
+        '\u{76d7}', '\u{3015}', '\u{3014}', '\u{52dd}', '\u{3015}', '\u{3014}', '\u{6557}',
+        '\u{3015}', '\u{5f97}', '\u{53ef}', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    ];
+
+    static COMPOSITION_TABLE: &[(char, char, char)] = &[
+        ('<', '\u{338}', '\u{226e}'), ('=', '\u{338}', '\u{2260}'), ('>', '\u{338}', '\u{226f}'),
+        ('A', '\u{300}', '\u{c0}'), ('A', '\u{301}', '\u{c1}'), ('A', '\u{302}', '\u{c2}'),
+        ('A', '\u{303}', '\u{c3}'), ('A', '\u{304}', '\u{100}'), ('A', '\u{306}', '\u{102}'),
+        ('A', '\u{307}', '\u{226}'), ('A', '\u{308}', '\u{c4}'), ('A', '\u{309}', '\u{1ea2}'),
+        ('A', '\u{30a}', '\u{c5}'), ('A', '\u{30c}', '\u{1cd}'), ('A', '\u{30f}', '\u{200}'),
+        ('A', '\u{311}', '\u{202}'), ('A', '\u{323}', '\u{1ea0}'), ('A', '\u{325}', '\u{1e00}'),
+        ('A', '\u{328}', '\u{104}'), ('B', '\u{307}', '\u{1e02}'), ('B', '\u{323}', '\u{1e04}'),
+        ('B', '\u{331}', '\u{1e06}'), ('C', '\u{301}', '\u{106}'), ('C', '\u{302}', '\u{108}'),
+        ('C', '\u{307}', '\u{10a}'), ('C', '\u{30c}', '\u{10c}'), ('C', '\u{327}', '\u{c7}'),
+        ('D', '\u{307}', '\u{1e0a}'), ('D', '\u{30c}', '\u{10e}'), ('D', '\u{323}', '\u{1e0c}'),
+        ('D', '\u{327}', '\u{1e10}'), ('D', '\u{32d}', '\u{1e12}'), ('D', '\u{331}', '\u{1e0e}'),
+        ('E', '\u{300}', '\u{c8}'), ('E', '\u{301}', '\u{c9}'), ('E', '\u{302}', '\u{ca}'),
+        ('E', '\u{303}', '\u{1ebc}'), ('E', '\u{304}', '\u{112}'), ('E', '\u{306}', '\u{114}'),
+        ('E', '\u{307}', '\u{116}'), ('E', '\u{308}', '\u{cb}'), ('E', '\u{309}', '\u{1eba}'),
+        ('E', '\u{30c}', '\u{11a}'), ('E', '\u{30f}', '\u{204}'), ('E', '\u{311}', '\u{206}'),
+        ('E', '\u{323}', '\u{1eb8}'), ('E', '\u{327}', '\u{228}'), ('E', '\u{328}', '\u{118}'),
+        ('E', '\u{32d}', '\u{1e18}'), ('E', '\u{330}', '\u{1e1a}'), ('F', '\u{307}', '\u{1e1e}'),
+        ('G', '\u{301}', '\u{1f4}'), ('G', '\u{302}', '\u{11c}'), ('G', '\u{304}', '\u{1e20}'),
+        ('G', '\u{306}', '\u{11e}'), ('G', '\u{307}', '\u{120}'), ('G', '\u{30c}', '\u{1e6}'),
+        ('G', '\u{327}', '\u{122}'), ('H', '\u{302}', '\u{124}'), ('H', '\u{307}', '\u{1e22}'),
+        ('H', '\u{308}', '\u{1e26}'), ('H', '\u{30c}', '\u{21e}'), ('H', '\u{323}', '\u{1e24}'),
+        ('H', '\u{327}', '\u{1e28}'), ('H', '\u{32e}', '\u{1e2a}'), ('I', '\u{300}', '\u{cc}'),
+        ('I', '\u{301}', '\u{cd}'), ('I', '\u{302}', '\u{ce}'), ('I', '\u{303}', '\u{128}'),
+        ('I', '\u{304}', '\u{12a}'), ('I', '\u{306}', '\u{12c}'), ('I', '\u{307}', '\u{130}'),
+        ('I', '\u{308}', '\u{cf}'), ('I', '\u{309}', '\u{1ec8}'), ('I', '\u{30c}', '\u{1cf}'),
+        ('I', '\u{30f}', '\u{208}'), ('I', '\u{311}', '\u{20a}'), ('I', '\u{323}', '\u{1eca}'),
+        ('I', '\u{328}', '\u{12e}'), ('I', '\u{330}', '\u{1e2c}'), ('J', '\u{302}', '\u{134}'),
+        ('K', '\u{301}', '\u{1e30}'), ('K', '\u{30c}', '\u{1e8}'), ('K', '\u{323}', '\u{1e32}'),
+        ('K', '\u{327}', '\u{136}'), ('K', '\u{331}', '\u{1e34}'), ('L', '\u{301}', '\u{139}'),
+        ('L', '\u{30c}', '\u{13d}'), ('L', '\u{323}', '\u{1e36}'), ('L', '\u{327}', '\u{13b}'),
+        ('L', '\u{32d}', '\u{1e3c}'), ('L', '\u{331}', '\u{1e3a}'), ('M', '\u{301}', '\u{1e3e}'),
+        ('M', '\u{307}', '\u{1e40}'), ('M', '\u{323}', '\u{1e42}'), ('N', '\u{300}', '\u{1f8}'),
+        ('N', '\u{301}', '\u{143}'), ('N', '\u{303}', '\u{d1}'), ('N', '\u{307}', '\u{1e44}'),
+        ('N', '\u{30c}', '\u{147}'), ('N', '\u{323}', '\u{1e46}'), ('N', '\u{327}', '\u{145}'),
+        ('N', '\u{32d}', '\u{1e4a}'), ('N', '\u{331}', '\u{1e48}'), ('O', '\u{300}', '\u{d2}'),
+        ('O', '\u{301}', '\u{d3}'), ('O', '\u{302}', '\u{d4}'), ('O', '\u{303}', '\u{d5}'),
+        ('O', '\u{304}', '\u{14c}'), ('O', '\u{306}', '\u{14e}'), ('O', '\u{307}', '\u{22e}'),
+        ('O', '\u{308}', '\u{d6}'), ('O', '\u{309}', '\u{1ece}'), ('O', '\u{30b}', '\u{150}'),
+        ('O', '\u{30c}', '\u{1d1}'), ('O', '\u{30f}', '\u{20c}'), ('O', '\u{311}', '\u{20e}'),
+        ('O', '\u{31b}', '\u{1a0}'), ('O', '\u{323}', '\u{1ecc}'), ('O', '\u{328}', '\u{1ea}'),
+        ('P', '\u{301}', '\u{1e54}'), ('P', '\u{307}', '\u{1e56}'), ('R', '\u{301}', '\u{154}'),
+        ('R', '\u{307}', '\u{1e58}'), ('R', '\u{30c}', '\u{158}'), ('R', '\u{30f}', '\u{210}'),
+        ('R', '\u{311}', '\u{212}'), ('R', '\u{323}', '\u{1e5a}'), ('R', '\u{327}', '\u{156}'),
+        ('R', '\u{331}', '\u{1e5e}'), ('S', '\u{301}', '\u{15a}'), ('S', '\u{302}', '\u{15c}'),
+        ('S', '\u{307}', '\u{1e60}'), ('S', '\u{30c}', '\u{160}'), ('S', '\u{323}', '\u{1e62}'),
+        ('S', '\u{326}', '\u{218}'), ('S', '\u{327}', '\u{15e}'), ('T', '\u{307}', '\u{1e6a}'),
+        ('T', '\u{30c}', '\u{164}'), ('T', '\u{323}', '\u{1e6c}'), ('T', '\u{326}', '\u{21a}'),
+        ('T', '\u{327}', '\u{162}'), ('T', '\u{32d}', '\u{1e70}'), ('T', '\u{331}', '\u{1e6e}'),
+        ('U', '\u{300}', '\u{d9}'), ('U', '\u{301}', '\u{da}'), ('U', '\u{302}', '\u{db}'),
+        ('U', '\u{303}', '\u{168}'), ('U', '\u{304}', '\u{16a}'), ('U', '\u{306}', '\u{16c}'),
+        ('U', '\u{308}', '\u{dc}'), ('U', '\u{309}', '\u{1ee6}'), ('U', '\u{30a}', '\u{16e}'),
+        ('U', '\u{30b}', '\u{170}'), ('U', '\u{30c}', '\u{1d3}'), ('U', '\u{30f}', '\u{214}'),
+        ('U', '\u{311}', '\u{216}'), ('U', '\u{31b}', '\u{1af}'), ('U', '\u{323}', '\u{1ee4}'),
+        ('U', '\u{324}', '\u{1e72}'), ('U', '\u{328}', '\u{172}'), ('U', '\u{32d}', '\u{1e76}'),
+        ('U', '\u{330}', '\u{1e74}'), ('V', '\u{303}', '\u{1e7c}'), ('V', '\u{323}', '\u{1e7e}'),
+        ('W', '\u{300}', '\u{1e80}'), ('W', '\u{301}', '\u{1e82}'), ('W', '\u{302}', '\u{174}'),
+        ('W', '\u{307}', '\u{1e86}'), ('W', '\u{308}', '\u{1e84}'), ('W', '\u{323}', '\u{1e88}'),
+        ('X', '\u{307}', '\u{1e8a}'), ('X', '\u{308}', '\u{1e8c}'), ('Y', '\u{300}', '\u{1ef2}'),
+        ('Y', '\u{301}', '\u{dd}'), ('Y', '\u{302}', '\u{176}'), ('Y', '\u{303}', '\u{1ef8}'),
+        ('Y', '\u{304}', '\u{232}'), ('Y', '\u{307}', '\u{1e8e}'), ('Y', '\u{308}', '\u{178}'),
+        ('Y', '\u{309}', '\u{1ef6}'), ('Y', '\u{323}', '\u{1ef4}'), ('Z', '\u{301}', '\u{179}'),
+        ('Z', '\u{302}', '\u{1e90}'), ('Z', '\u{307}', '\u{17b}'), ('Z', '\u{30c}', '\u{17d}'),
+        ('Z', '\u{323}', '\u{1e92}'), ('Z', '\u{331}', '\u{1e94}'), ('a', '\u{300}', '\u{e0}'),
+        ('a', '\u{301}', '\u{e1}'), ('a', '\u{302}', '\u{e2}'), ('a', '\u{303}', '\u{e3}'),
+        ('a', '\u{304}', '\u{101}'), ('a', '\u{306}', '\u{103}'), ('a', '\u{307}', '\u{227}'),
+        ('a', '\u{308}', '\u{e4}'), ('a', '\u{309}', '\u{1ea3}'), ('a', '\u{30a}', '\u{e5}'),
+        ('a', '\u{30c}', '\u{1ce}'), ('a', '\u{30f}', '\u{201}'), ('a', '\u{311}', '\u{203}'),
+        ('a', '\u{323}', '\u{1ea1}'), ('a', '\u{325}', '\u{1e01}'), ('a', '\u{328}', '\u{105}'),
+        ('b', '\u{307}', '\u{1e03}'), ('b', '\u{323}', '\u{1e05}'), ('b', '\u{331}', '\u{1e07}'),
+        ('c', '\u{301}', '\u{107}'), ('c', '\u{302}', '\u{109}'), ('c', '\u{307}', '\u{10b}'),
+        ('c', '\u{30c}', '\u{10d}'), ('c', '\u{327}', '\u{e7}'), ('d', '\u{307}', '\u{1e0b}'),
+        ('d', '\u{30c}', '\u{10f}'), ('d', '\u{323}', '\u{1e0d}'), ('d', '\u{327}', '\u{1e11}'),
+        ('d', '\u{32d}', '\u{1e13}'), ('d', '\u{331}', '\u{1e0f}'), ('e', '\u{300}', '\u{e8}'),
+        ('e', '\u{301}', '\u{e9}'), ('e', '\u{302}', '\u{ea}'), ('e', '\u{303}', '\u{1ebd}'),
+        ('e', '\u{304}', '\u{113}'), ('e', '\u{306}', '\u{115}'), ('e', '\u{307}', '\u{117}'),
+        ('e', '\u{308}', '\u{eb}'), ('e', '\u{309}', '\u{1ebb}'), ('e', '\u{30c}', '\u{11b}'),
+        ('e', '\u{30f}', '\u{205}'), ('e', '\u{311}', '\u{207}'), ('e', '\u{323}', '\u{1eb9}'),
+        ('e', '\u{327}', '\u{229}'), ('e', '\u{328}', '\u{119}'), ('e', '\u{32d}', '\u{1e19}'),
+        ('e', '\u{330}', '\u{1e1b}'), ('f', '\u{307}', '\u{1e1f}'), ('g', '\u{301}', '\u{1f5}'),
+        ('g', '\u{302}', '\u{11d}'), ('g', '\u{304}', '\u{1e21}'), ('g', '\u{306}', '\u{11f}'),
+        ('g', '\u{307}', '\u{121}'), ('g', '\u{30c}', '\u{1e7}'), ('g', '\u{327}', '\u{123}'),
+        ('h', '\u{302}', '\u{125}'), ('h', '\u{307}', '\u{1e23}'), ('h', '\u{308}', '\u{1e27}'),
+        ('h', '\u{30c}', '\u{21f}'), ('h', '\u{323}', '\u{1e25}'), ('h', '\u{327}', '\u{1e29}'),
+        ('h', '\u{32e}', '\u{1e2b}'), ('h', '\u{331}', '\u{1e96}'), ('i', '\u{300}', '\u{ec}'),
+        ('i', '\u{301}', '\u{ed}'), ('i', '\u{302}', '\u{ee}'), ('i', '\u{303}', '\u{129}'),
+        ('i', '\u{304}', '\u{12b}'), ('i', '\u{306}', '\u{12d}'), ('i', '\u{308}', '\u{ef}'),
+        ('i', '\u{309}', '\u{1ec9}'), ('i', '\u{30c}', '\u{1d0}'), ('i', '\u{30f}', '\u{209}'),
+        ('i', '\u{311}', '\u{20b}'), ('i', '\u{323}', '\u{1ecb}'), ('i', '\u{328}', '\u{12f}'),
+        ('i', '\u{330}', '\u{1e2d}'), ('j', '\u{302}', '\u{135}'), ('j', '\u{30c}', '\u{1f0}'),
+        ('k', '\u{301}', '\u{1e31}'), ('k', '\u{30c}', '\u{1e9}'), ('k', '\u{323}', '\u{1e33}'),
+        ('k', '\u{327}', '\u{137}'), ('k', '\u{331}', '\u{1e35}'), ('l', '\u{301}', '\u{13a}'),
+        ('l', '\u{30c}', '\u{13e}'), ('l', '\u{323}', '\u{1e37}'), ('l', '\u{327}', '\u{13c}'),
+        ('l', '\u{32d}', '\u{1e3d}'), ('l', '\u{331}', '\u{1e3b}'), ('m', '\u{301}', '\u{1e3f}'),
+        ('m', '\u{307}', '\u{1e41}'), ('m', '\u{323}', '\u{1e43}'), ('n', '\u{300}', '\u{1f9}'),
+        ('n', '\u{301}', '\u{144}'), ('n', '\u{303}', '\u{f1}'), ('n', '\u{307}', '\u{1e45}'),
+        ('n', '\u{30c}', '\u{148}'), ('n', '\u{323}', '\u{1e47}'), ('n', '\u{327}', '\u{146}'),
+        ('n', '\u{32d}', '\u{1e4b}'), ('n', '\u{331}', '\u{1e49}'), ('o', '\u{300}', '\u{f2}'),
+        ('o', '\u{301}', '\u{f3}'), ('o', '\u{302}', '\u{f4}'), ('o', '\u{303}', '\u{f5}'),
+        ('o', '\u{304}', '\u{14d}'), ('o', '\u{306}', '\u{14f}'), ('o', '\u{307}', '\u{22f}'),
+        ('o', '\u{308}', '\u{f6}'), ('o', '\u{309}', '\u{1ecf}'), ('o', '\u{30b}', '\u{151}'),
+        ('o', '\u{30c}', '\u{1d2}'), ('o', '\u{30f}', '\u{20d}'), ('o', '\u{311}', '\u{20f}'),
+        ('o', '\u{31b}', '\u{1a1}'), ('o', '\u{323}', '\u{1ecd}'), ('o', '\u{328}', '\u{1eb}'),
+        ('p', '\u{301}', '\u{1e55}'), ('p', '\u{307}', '\u{1e57}'), ('r', '\u{301}', '\u{155}'),
+        ('r', '\u{307}', '\u{1e59}'), ('r', '\u{30c}', '\u{159}'), ('r', '\u{30f}', '\u{211}'),
+        ('r', '\u{311}', '\u{213}'), ('r', '\u{323}', '\u{1e5b}'), ('r', '\u{327}', '\u{157}'),
+        ('r', '\u{331}', '\u{1e5f}'), ('s', '\u{301}', '\u{15b}'), ('s', '\u{302}', '\u{15d}'),
+        ('s', '\u{307}', '\u{1e61}'), ('s', '\u{30c}', '\u{161}'), ('s', '\u{323}', '\u{1e63}'),
+        ('s', '\u{326}', '\u{219}'), ('s', '\u{327}', '\u{15f}'), ('t', '\u{307}', '\u{1e6b}'),
+        ('t', '\u{308}', '\u{1e97}'), ('t', '\u{30c}', '\u{165}'), ('t', '\u{323}', '\u{1e6d}'),
+        ('t', '\u{326}', '\u{21b}'), ('t', '\u{327}', '\u{163}'), ('t', '\u{32d}', '\u{1e71}'),
+        ('t', '\u{331}', '\u{1e6f}'), ('u', '\u{300}', '\u{f9}'), ('u', '\u{301}', '\u{fa}'),
+        ('u', '\u{302}', '\u{fb}'), ('u', '\u{303}', '\u{169}'), ('u', '\u{304}', '\u{16b}'),
+        ('u', '\u{306}', '\u{16d}'), ('u', '\u{308}', '\u{fc}'), ('u', '\u{309}', '\u{1ee7}'),
+        ('u', '\u{30a}', '\u{16f}'), ('u', '\u{30b}', '\u{171}'), ('u', '\u{30c}', '\u{1d4}'),
+        ('u', '\u{30f}', '\u{215}'), ('u', '\u{311}', '\u{217}'), ('u', '\u{31b}', '\u{1b0}'),
+        ('u', '\u{323}', '\u{1ee5}'), ('u', '\u{324}', '\u{1e73}'), ('u', '\u{328}', '\u{173}'),
+        ('u', '\u{32d}', '\u{1e77}'), ('u', '\u{330}', '\u{1e75}'), ('v', '\u{303}', '\u{1e7d}'),
+        ('v', '\u{323}', '\u{1e7f}'), ('w', '\u{300}', '\u{1e81}'), ('w', '\u{301}', '\u{1e83}'),
+        ('w', '\u{302}', '\u{175}'), ('w', '\u{307}', '\u{1e87}'), ('w', '\u{308}', '\u{1e85}'),
+        ('w', '\u{30a}', '\u{1e98}'), ('w', '\u{323}', '\u{1e89}'), ('x', '\u{307}', '\u{1e8b}'),
+        ('x', '\u{308}', '\u{1e8d}'), ('y', '\u{300}', '\u{1ef3}'), ('y', '\u{301}', '\u{fd}'),
+        ('y', '\u{302}', '\u{177}'), ('y', '\u{303}', '\u{1ef9}'), ('y', '\u{304}', '\u{233}'),
+        ('y', '\u{307}', '\u{1e8f}'), ('y', '\u{308}', '\u{ff}'), ('y', '\u{309}', '\u{1ef7}'),
+        ('y', '\u{30a}', '\u{1e99}'), ('y', '\u{323}', '\u{1ef5}'), ('z', '\u{301}', '\u{17a}'),
+        ('z', '\u{302}', '\u{1e91}'), ('z', '\u{307}', '\u{17c}'), ('z', '\u{30c}', '\u{17e}'),
+        ('z', '\u{323}', '\u{1e93}'), ('z', '\u{331}', '\u{1e95}'),
+        ('\u{a8}', '\u{300}', '\u{1fed}'), ('\u{a8}', '\u{301}', '\u{385}'),
+        ('\u{a8}', '\u{342}', '\u{1fc1}'), ('\u{c2}', '\u{300}', '\u{1ea6}'),
+        ('\u{c2}', '\u{301}', '\u{1ea4}'), ('\u{c2}', '\u{303}', '\u{1eaa}'),
+        ('\u{c2}', '\u{309}', '\u{1ea8}'), ('\u{c4}', '\u{304}', '\u{1de}'),
+        ('\u{c5}', '\u{301}', '\u{1fa}'), ('\u{c6}', '\u{301}', '\u{1fc}'),
+        ('\u{c6}', '\u{304}', '\u{1e2}'), ('\u{c7}', '\u{301}', '\u{1e08}'),
+        ('\u{ca}', '\u{300}', '\u{1ec0}'), ('\u{ca}', '\u{301}', '\u{1ebe}'),
+        ('\u{ca}', '\u{303}', '\u{1ec4}'), ('\u{ca}', '\u{309}', '\u{1ec2}'),
+        ('\u{cf}', '\u{301}', '\u{1e2e}'), ('\u{d4}', '\u{300}', '\u{1ed2}'),
+        ('\u{d4}', '\u{301}', '\u{1ed0}'), ('\u{d4}', '\u{303}', '\u{1ed6}'),
+        ('\u{d4}', '\u{309}', '\u{1ed4}'), ('\u{d5}', '\u{301}', '\u{1e4c}'),
+        ('\u{d5}', '\u{304}', '\u{22c}'), ('\u{d5}', '\u{308}', '\u{1e4e}'),
+        ('\u{d6}', '\u{304}', '\u{22a}'), ('\u{d8}', '\u{301}', '\u{1fe}'),
+        ('\u{dc}', '\u{300}', '\u{1db}'), ('\u{dc}', '\u{301}', '\u{1d7}'),
+        ('\u{dc}', '\u{304}', '\u{1d5}'), ('\u{dc}', '\u{30c}', '\u{1d9}'),
+        ('\u{e2}', '\u{300}', '\u{1ea7}'), ('\u{e2}', '\u{301}', '\u{1ea5}'),
+        ('\u{e2}', '\u{303}', '\u{1eab}'), ('\u{e2}', '\u{309}', '\u{1ea9}'),
+        ('\u{e4}', '\u{304}', '\u{1df}'), ('\u{e5}', '\u{301}', '\u{1fb}'),
+        ('\u{e6}', '\u{301}', '\u{1fd}'), ('\u{e6}', '\u{304}', '\u{1e3}'),
+        ('\u{e7}', '\u{301}', '\u{1e09}'), ('\u{ea}', '\u{300}', '\u{1ec1}'),
+        ('\u{ea}', '\u{301}', '\u{1ebf}'), ('\u{ea}', '\u{303}', '\u{1ec5}'),
+        ('\u{ea}', '\u{309}', '\u{1ec3}'), ('\u{ef}', '\u{301}', '\u{1e2f}'),
+        ('\u{f4}', '\u{300}', '\u{1ed3}'), ('\u{f4}', '\u{301}', '\u{1ed1}'),
+        ('\u{f4}', '\u{303}', '\u{1ed7}'), ('\u{f4}', '\u{309}', '\u{1ed5}'),
+        ('\u{f5}', '\u{301}', '\u{1e4d}'), ('\u{f5}', '\u{304}', '\u{22d}'),
+        ('\u{f5}', '\u{308}', '\u{1e4f}'), ('\u{f6}', '\u{304}', '\u{22b}'),
+        ('\u{f8}', '\u{301}', '\u{1ff}'), ('\u{fc}', '\u{300}', '\u{1dc}'),
+        ('\u{fc}', '\u{301}', '\u{1d8}'), ('\u{fc}', '\u{304}', '\u{1d6}'),
+        ('\u{fc}', '\u{30c}', '\u{1da}'), ('\u{102}', '\u{300}', '\u{1eb0}'),
+        ('\u{102}', '\u{301}', '\u{1eae}'), ('\u{102}', '\u{303}', '\u{1eb4}'),
+        ('\u{102}', '\u{309}', '\u{1eb2}'), ('\u{103}', '\u{300}', '\u{1eb1}'),
+        ('\u{103}', '\u{301}', '\u{1eaf}'), ('\u{103}', '\u{303}', '\u{1eb5}'),
+        ('\u{103}', '\u{309}', '\u{1eb3}'), ('\u{112}', '\u{300}', '\u{1e14}'),
+        ('\u{112}', '\u{301}', '\u{1e16}'), ('\u{113}', '\u{300}', '\u{1e15}'),
+        ('\u{113}', '\u{301}', '\u{1e17}'), ('\u{14c}', '\u{300}', '\u{1e50}'),
+        ('\u{14c}', '\u{301}', '\u{1e52}'), ('\u{14d}', '\u{300}', '\u{1e51}'),
+        ('\u{14d}', '\u{301}', '\u{1e53}'), ('\u{15a}', '\u{307}', '\u{1e64}'),
+        ('\u{15b}', '\u{307}', '\u{1e65}'), ('\u{160}', '\u{307}', '\u{1e66}'),
+        ('\u{161}', '\u{307}', '\u{1e67}'), ('\u{168}', '\u{301}', '\u{1e78}'),
+        ('\u{169}', '\u{301}', '\u{1e79}'), ('\u{16a}', '\u{308}', '\u{1e7a}'),
+        ('\u{16b}', '\u{308}', '\u{1e7b}'), ('\u{17f}', '\u{307}', '\u{1e9b}'),
+        ('\u{1a0}', '\u{300}', '\u{1edc}'), ('\u{1a0}', '\u{301}', '\u{1eda}'),
+        ('\u{1a0}', '\u{303}', '\u{1ee0}'), ('\u{1a0}', '\u{309}', '\u{1ede}'),
+        ('\u{1a0}', '\u{323}', '\u{1ee2}'), ('\u{1a1}', '\u{300}', '\u{1edd}'),
+        ('\u{1a1}', '\u{301}', '\u{1edb}'), ('\u{1a1}', '\u{303}', '\u{1ee1}'),
+        ('\u{1a1}', '\u{309}', '\u{1edf}'), ('\u{1a1}', '\u{323}', '\u{1ee3}'),
+        ('\u{1af}', '\u{300}', '\u{1eea}'), ('\u{1af}', '\u{301}', '\u{1ee8}'),
+        ('\u{1af}', '\u{303}', '\u{1eee}'), ('\u{1af}', '\u{309}', '\u{1eec}'),
+        ('\u{1af}', '\u{323}', '\u{1ef0}'), ('\u{1b0}', '\u{300}', '\u{1eeb}'),
+        ('\u{1b0}', '\u{301}', '\u{1ee9}'), ('\u{1b0}', '\u{303}', '\u{1eef}'),
+        ('\u{1b0}', '\u{309}', '\u{1eed}'), ('\u{1b0}', '\u{323}', '\u{1ef1}'),
+        ('\u{1b7}', '\u{30c}', '\u{1ee}'), ('\u{1ea}', '\u{304}', '\u{1ec}'),
+        ('\u{1eb}', '\u{304}', '\u{1ed}'), ('\u{226}', '\u{304}', '\u{1e0}'),
+        ('\u{227}', '\u{304}', '\u{1e1}'), ('\u{228}', '\u{306}', '\u{1e1c}'),
+        ('\u{229}', '\u{306}', '\u{1e1d}'), ('\u{22e}', '\u{304}', '\u{230}'),
+        ('\u{22f}', '\u{304}', '\u{231}'), ('\u{292}', '\u{30c}', '\u{1ef}'),
+        ('\u{391}', '\u{300}', '\u{1fba}'), ('\u{391}', '\u{301}', '\u{386}'),
+        ('\u{391}', '\u{304}', '\u{1fb9}'), ('\u{391}', '\u{306}', '\u{1fb8}'),
+        ('\u{391}', '\u{313}', '\u{1f08}'), ('\u{391}', '\u{314}', '\u{1f09}'),
+        ('\u{391}', '\u{345}', '\u{1fbc}'), ('\u{395}', '\u{300}', '\u{1fc8}'),
+        ('\u{395}', '\u{301}', '\u{388}'), ('\u{395}', '\u{313}', '\u{1f18}'),
+        ('\u{395}', '\u{314}', '\u{1f19}'), ('\u{397}', '\u{300}', '\u{1fca}'),
+        ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1f28}'),
+        ('\u{397}', '\u{314}', '\u{1f29}'), ('\u{397}', '\u{345}', '\u{1fcc}'),
+        ('\u{399}', '\u{300}', '\u{1fda}'), ('\u{399}', '\u{301}', '\u{38a}'),
+        ('\u{399}', '\u{304}', '\u{1fd9}'), ('\u{399}', '\u{306}', '\u{1fd8}'),
+        ('\u{399}', '\u{308}', '\u{3aa}'), ('\u{399}', '\u{313}', '\u{1f38}'),
+        ('\u{399}', '\u{314}', '\u{1f39}'), ('\u{39f}', '\u{300}', '\u{1ff8}'),
+        ('\u{39f}', '\u{301}', '\u{38c}'), ('\u{39f}', '\u{313}', '\u{1f48}'),
+        ('\u{39f}', '\u{314}', '\u{1f49}'), ('\u{3a1}', '\u{314}', '\u{1fec}'),
+        ('\u{3a5}', '\u{300}', '\u{1fea}'), ('\u{3a5}', '\u{301}', '\u{38e}'),
+        ('\u{3a5}', '\u{304}', '\u{1fe9}'), ('\u{3a5}', '\u{306}', '\u{1fe8}'),
+        ('\u{3a5}', '\u{308}', '\u{3ab}'), ('\u{3a5}', '\u{314}', '\u{1f59}'),
+        ('\u{3a9}', '\u{300}', '\u{1ffa}'), ('\u{3a9}', '\u{301}', '\u{38f}'),
+        ('\u{3a9}', '\u{313}', '\u{1f68}'), ('\u{3a9}', '\u{314}', '\u{1f69}'),
+        ('\u{3a9}', '\u{345}', '\u{1ffc}'), ('\u{3ac}', '\u{345}', '\u{1fb4}'),
+        ('\u{3ae}', '\u{345}', '\u{1fc4}'), ('\u{3b1}', '\u{300}', '\u{1f70}'),
+        ('\u{3b1}', '\u{301}', '\u{3ac}'), ('\u{3b1}', '\u{304}', '\u{1fb1}'),
+        ('\u{3b1}', '\u{306}', '\u{1fb0}'), ('\u{3b1}', '\u{313}', '\u{1f00}'),
+        ('\u{3b1}', '\u{314}', '\u{1f01}'), ('\u{3b1}', '\u{342}', '\u{1fb6}'),
+        ('\u{3b1}', '\u{345}', '\u{1fb3}'), ('\u{3b5}', '\u{300}', '\u{1f72}'),
+        ('\u{3b5}', '\u{301}', '\u{3ad}'), ('\u{3b5}', '\u{313}', '\u{1f10}'),
+        ('\u{3b5}', '\u{314}', '\u{1f11}'), ('\u{3b7}', '\u{300}', '\u{1f74}'),
+        ('\u{3b7}', '\u{301}', '\u{3ae}'), ('\u{3b7}', '\u{313}', '\u{1f20}'),
+        ('\u{3b7}', '\u{314}', '\u{1f21}'), ('\u{3b7}', '\u{342}', '\u{1fc6}'),
+        ('\u{3b7}', '\u{345}', '\u{1fc3}'), ('\u{3b9}', '\u{300}', '\u{1f76}'),
+        ('\u{3b9}', '\u{301}', '\u{3af}'), ('\u{3b9}', '\u{304}', '\u{1fd1}'),
+        ('\u{3b9}', '\u{306}', '\u{1fd0}'), ('\u{3b9}', '\u{308}', '\u{3ca}'),
+        ('\u{3b9}', '\u{313}', '\u{1f30}'), ('\u{3b9}', '\u{314}', '\u{1f31}'),
+        ('\u{3b9}', '\u{342}', '\u{1fd6}'), ('\u{3bf}', '\u{300}', '\u{1f78}'),
+        ('\u{3bf}', '\u{301}', '\u{3cc}'), ('\u{3bf}', '\u{313}', '\u{1f40}'),
+        ('\u{3bf}', '\u{314}', '\u{1f41}'), ('\u{3c1}', '\u{313}', '\u{1fe4}'),
+        ('\u{3c1}', '\u{314}', '\u{1fe5}'), ('\u{3c5}', '\u{300}', '\u{1f7a}'),
+        ('\u{3c5}', '\u{301}', '\u{3cd}'), ('\u{3c5}', '\u{304}', '\u{1fe1}'),
+        ('\u{3c5}', '\u{306}', '\u{1fe0}'), ('\u{3c5}', '\u{308}', '\u{3cb}'),
+        ('\u{3c5}', '\u{313}', '\u{1f50}'), ('\u{3c5}', '\u{314}', '\u{1f51}'),
+        ('\u{3c5}', '\u{342}', '\u{1fe6}'), ('\u{3c9}', '\u{300}', '\u{1f7c}'),
+        ('\u{3c9}', '\u{301}', '\u{3ce}'), ('\u{3c9}', '\u{313}', '\u{1f60}'),
+        ('\u{3c9}', '\u{314}', '\u{1f61}'), ('\u{3c9}', '\u{342}', '\u{1ff6}'),
+        ('\u{3c9}', '\u{345}', '\u{1ff3}'), ('\u{3ca}', '\u{300}', '\u{1fd2}'),
+        ('\u{3ca}', '\u{301}', '\u{390}'), ('\u{3ca}', '\u{342}', '\u{1fd7}'),
+        ('\u{3cb}', '\u{300}', '\u{1fe2}'), ('\u{3cb}', '\u{301}', '\u{3b0}'),
+        ('\u{3cb}', '\u{342}', '\u{1fe7}'), ('\u{3ce}', '\u{345}', '\u{1ff4}'),
+        ('\u{3d2}', '\u{301}', '\u{3d3}'), ('\u{3d2}', '\u{308}', '\u{3d4}'),
+        ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4d0}'),
+        ('\u{410}', '\u{308}', '\u{4d2}'), ('\u{413}', '\u{301}', '\u{403}'),
+        ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4d6}'),
+        ('\u{415}', '\u{308}', '\u{401}'), ('\u{416}', '\u{306}', '\u{4c1}'),
+        ('\u{416}', '\u{308}', '\u{4dc}'), ('\u{417}', '\u{308}', '\u{4de}'),
+        ('\u{418}', '\u{300}', '\u{40d}'), ('\u{418}', '\u{304}', '\u{4e2}'),
+        ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4e4}'),
+        ('\u{41a}', '\u{301}', '\u{40c}'), ('\u{41e}', '\u{308}', '\u{4e6}'),
+        ('\u{423}', '\u{304}', '\u{4ee}'), ('\u{423}', '\u{306}', '\u{40e}'),
+        ('\u{423}', '\u{308}', '\u{4f0}'), ('\u{423}', '\u{30b}', '\u{4f2}'),
+        ('\u{427}', '\u{308}', '\u{4f4}'), ('\u{42b}', '\u{308}', '\u{4f8}'),
+        ('\u{42d}', '\u{308}', '\u{4ec}'), ('\u{430}', '\u{306}', '\u{4d1}'),
+        ('\u{430}', '\u{308}', '\u{4d3}'), ('\u{433}', '\u{301}', '\u{453}'),
+        ('\u{435}', '\u{300}', '\u{450}'), ('\u{435}', '\u{306}', '\u{4d7}'),
+        ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4c2}'),
+        ('\u{436}', '\u{308}', '\u{4dd}'), ('\u{437}', '\u{308}', '\u{4df}'),
+        ('\u{438}', '\u{300}', '\u{45d}'), ('\u{438}', '\u{304}', '\u{4e3}'),
+        ('\u{438}', '\u{306}', '\u{439}'), ('\u{438}', '\u{308}', '\u{4e5}'),
+        ('\u{43a}', '\u{301}', '\u{45c}'), ('\u{43e}', '\u{308}', '\u{4e7}'),
+        ('\u{443}', '\u{304}', '\u{4ef}'), ('\u{443}', '\u{306}', '\u{45e}'),
+        ('\u{443}', '\u{308}', '\u{4f1}'), ('\u{443}', '\u{30b}', '\u{4f3}'),
+        ('\u{447}', '\u{308}', '\u{4f5}'), ('\u{44b}', '\u{308}', '\u{4f9}'),
+        ('\u{44d}', '\u{308}', '\u{4ed}'), ('\u{456}', '\u{308}', '\u{457}'),
+        ('\u{474}', '\u{30f}', '\u{476}'), ('\u{475}', '\u{30f}', '\u{477}'),
+        ('\u{4d8}', '\u{308}', '\u{4da}'), ('\u{4d9}', '\u{308}', '\u{4db}'),
+        ('\u{4e8}', '\u{308}', '\u{4ea}'), ('\u{4e9}', '\u{308}', '\u{4eb}'),
+        ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'),
+        ('\u{627}', '\u{655}', '\u{625}'), ('\u{648}', '\u{654}', '\u{624}'),
+        ('\u{64a}', '\u{654}', '\u{626}'), ('\u{6c1}', '\u{654}', '\u{6c2}'),
+        ('\u{6d2}', '\u{654}', '\u{6d3}'), ('\u{6d5}', '\u{654}', '\u{6c0}'),
+        ('\u{928}', '\u{93c}', '\u{929}'), ('\u{930}', '\u{93c}', '\u{931}'),
+        ('\u{933}', '\u{93c}', '\u{934}'), ('\u{9c7}', '\u{9be}', '\u{9cb}'),
+        ('\u{9c7}', '\u{9d7}', '\u{9cc}'), ('\u{b47}', '\u{b3e}', '\u{b4b}'),
+        ('\u{b47}', '\u{b56}', '\u{b48}'), ('\u{b47}', '\u{b57}', '\u{b4c}'),
+        ('\u{b92}', '\u{bd7}', '\u{b94}'), ('\u{bc6}', '\u{bbe}', '\u{bca}'),
+        ('\u{bc6}', '\u{bd7}', '\u{bcc}'), ('\u{bc7}', '\u{bbe}', '\u{bcb}'),
+        ('\u{c46}', '\u{c56}', '\u{c48}'), ('\u{cbf}', '\u{cd5}', '\u{cc0}'),
+        ('\u{cc6}', '\u{cc2}', '\u{cca}'), ('\u{cc6}', '\u{cd5}', '\u{cc7}'),
+        ('\u{cc6}', '\u{cd6}', '\u{cc8}'), ('\u{cca}', '\u{cd5}', '\u{ccb}'),
+        ('\u{d46}', '\u{d3e}', '\u{d4a}'), ('\u{d46}', '\u{d57}', '\u{d4c}'),
+        ('\u{d47}', '\u{d3e}', '\u{d4b}'), ('\u{dd9}', '\u{dca}', '\u{dda}'),
+        ('\u{dd9}', '\u{dcf}', '\u{ddc}'), ('\u{dd9}', '\u{ddf}', '\u{dde}'),
+        ('\u{ddc}', '\u{dca}', '\u{ddd}'), ('\u{1025}', '\u{102e}', '\u{1026}'),
+        ('\u{1b05}', '\u{1b35}', '\u{1b06}'), ('\u{1b07}', '\u{1b35}', '\u{1b08}'),
+        ('\u{1b09}', '\u{1b35}', '\u{1b0a}'), ('\u{1b0b}', '\u{1b35}', '\u{1b0c}'),
+        ('\u{1b0d}', '\u{1b35}', '\u{1b0e}'), ('\u{1b11}', '\u{1b35}', '\u{1b12}'),
+        ('\u{1b3a}', '\u{1b35}', '\u{1b3b}'), ('\u{1b3c}', '\u{1b35}', '\u{1b3d}'),
+        ('\u{1b3e}', '\u{1b35}', '\u{1b40}'), ('\u{1b3f}', '\u{1b35}', '\u{1b41}'),
+        ('\u{1b42}', '\u{1b35}', '\u{1b43}'), ('\u{1e36}', '\u{304}', '\u{1e38}'),
+        ('\u{1e37}', '\u{304}', '\u{1e39}'), ('\u{1e5a}', '\u{304}', '\u{1e5c}'),
+        ('\u{1e5b}', '\u{304}', '\u{1e5d}'), ('\u{1e62}', '\u{307}', '\u{1e68}'),
+        ('\u{1e63}', '\u{307}', '\u{1e69}'), ('\u{1ea0}', '\u{302}', '\u{1eac}'),
+        ('\u{1ea0}', '\u{306}', '\u{1eb6}'), ('\u{1ea1}', '\u{302}', '\u{1ead}'),
+        ('\u{1ea1}', '\u{306}', '\u{1eb7}'), ('\u{1eb8}', '\u{302}', '\u{1ec6}'),
+        ('\u{1eb9}', '\u{302}', '\u{1ec7}'), ('\u{1ecc}', '\u{302}', '\u{1ed8}'),
+        ('\u{1ecd}', '\u{302}', '\u{1ed9}'), ('\u{1f00}', '\u{300}', '\u{1f02}'),
+        ('\u{1f00}', '\u{301}', '\u{1f04}'), ('\u{1f00}', '\u{342}', '\u{1f06}'),
+        ('\u{1f00}', '\u{345}', '\u{1f80}'), ('\u{1f01}', '\u{300}', '\u{1f03}'),
+        ('\u{1f01}', '\u{301}', '\u{1f05}'), ('\u{1f01}', '\u{342}', '\u{1f07}'),
+        ('\u{1f01}', '\u{345}', '\u{1f81}'), ('\u{1f02}', '\u{345}', '\u{1f82}'),
+        ('\u{1f03}', '\u{345}', '\u{1f83}'), ('\u{1f04}', '\u{345}', '\u{1f84}'),
+        ('\u{1f05}', '\u{345}', '\u{1f85}'), ('\u{1f06}', '\u{345}', '\u{1f86}'),
+        ('\u{1f07}', '\u{345}', '\u{1f87}'), ('\u{1f08}', '\u{300}', '\u{1f0a}'),
+        ('\u{1f08}', '\u{301}', '\u{1f0c}'), ('\u{1f08}', '\u{342}', '\u{1f0e}'),
+        ('\u{1f08}', '\u{345}', '\u{1f88}'), ('\u{1f09}', '\u{300}', '\u{1f0b}'),
+        ('\u{1f09}', '\u{301}', '\u{1f0d}'), ('\u{1f09}', '\u{342}', '\u{1f0f}'),
+        ('\u{1f09}', '\u{345}', '\u{1f89}'), ('\u{1f0a}', '\u{345}', '\u{1f8a}'),
+        ('\u{1f0b}', '\u{345}', '\u{1f8b}'), ('\u{1f0c}', '\u{345}', '\u{1f8c}'),
+        ('\u{1f0d}', '\u{345}', '\u{1f8d}'), ('\u{1f0e}', '\u{345}', '\u{1f8e}'),
+        ('\u{1f0f}', '\u{345}', '\u{1f8f}'), ('\u{1f10}', '\u{300}', '\u{1f12}'),
+        ('\u{1f10}', '\u{301}', '\u{1f14}'), ('\u{1f11}', '\u{300}', '\u{1f13}'),
+        ('\u{1f11}', '\u{301}', '\u{1f15}'), ('\u{1f18}', '\u{300}', '\u{1f1a}'),
+        ('\u{1f18}', '\u{301}', '\u{1f1c}'), ('\u{1f19}', '\u{300}', '\u{1f1b}'),
+        ('\u{1f19}', '\u{301}', '\u{1f1d}'), ('\u{1f20}', '\u{300}', '\u{1f22}'),
+        ('\u{1f20}', '\u{301}', '\u{1f24}'), ('\u{1f20}', '\u{342}', '\u{1f26}'),
+        ('\u{1f20}', '\u{345}', '\u{1f90}'), ('\u{1f21}', '\u{300}', '\u{1f23}'),
+        ('\u{1f21}', '\u{301}', '\u{1f25}'), ('\u{1f21}', '\u{342}', '\u{1f27}'),
+        ('\u{1f21}', '\u{345}', '\u{1f91}'), ('\u{1f22}', '\u{345}', '\u{1f92}'),
+        ('\u{1f23}', '\u{345}', '\u{1f93}'), ('\u{1f24}', '\u{345}', '\u{1f94}'),
+        ('\u{1f25}', '\u{345}', '\u{1f95}'), ('\u{1f26}', '\u{345}', '\u{1f96}'),
+        ('\u{1f27}', '\u{345}', '\u{1f97}'), ('\u{1f28}', '\u{300}', '\u{1f2a}'),
+        ('\u{1f28}', '\u{301}', '\u{1f2c}'), ('\u{1f28}', '\u{342}', '\u{1f2e}'),
+        ('\u{1f28}', '\u{345}', '\u{1f98}'), ('\u{1f29}', '\u{300}', '\u{1f2b}'),
+        ('\u{1f29}', '\u{301}', '\u{1f2d}'), ('\u{1f29}', '\u{342}', '\u{1f2f}'),
+        ('\u{1f29}', '\u{345}', '\u{1f99}'), ('\u{1f2a}', '\u{345}', '\u{1f9a}'),
+        ('\u{1f2b}', '\u{345}', '\u{1f9b}'), ('\u{1f2c}', '\u{345}', '\u{1f9c}'),
+        ('\u{1f2d}', '\u{345}', '\u{1f9d}'), ('\u{1f2e}', '\u{345}', '\u{1f9e}'),
+        ('\u{1f2f}', '\u{345}', '\u{1f9f}'), ('\u{1f30}', '\u{300}', '\u{1f32}'),
+        ('\u{1f30}', '\u{301}', '\u{1f34}'), ('\u{1f30}', '\u{342}', '\u{1f36}'),
+        ('\u{1f31}', '\u{300}', '\u{1f33}'), ('\u{1f31}', '\u{301}', '\u{1f35}'),
+        ('\u{1f31}', '\u{342}', '\u{1f37}'), ('\u{1f38}', '\u{300}', '\u{1f3a}'),
+        ('\u{1f38}', '\u{301}', '\u{1f3c}'), ('\u{1f38}', '\u{342}', '\u{1f3e}'),
+        ('\u{1f39}', '\u{300}', '\u{1f3b}'), ('\u{1f39}', '\u{301}', '\u{1f3d}'),
+        ('\u{1f39}', '\u{342}', '\u{1f3f}'), ('\u{1f40}', '\u{300}', '\u{1f42}'),
+        ('\u{1f40}', '\u{301}', '\u{1f44}'), ('\u{1f41}', '\u{300}', '\u{1f43}'),
+        ('\u{1f41}', '\u{301}', '\u{1f45}'), ('\u{1f48}', '\u{300}', '\u{1f4a}'),
+        ('\u{1f48}', '\u{301}', '\u{1f4c}'), ('\u{1f49}', '\u{300}', '\u{1f4b}'),
+        ('\u{1f49}', '\u{301}', '\u{1f4d}'), ('\u{1f50}', '\u{300}', '\u{1f52}'),
+        ('\u{1f50}', '\u{301}', '\u{1f54}'), ('\u{1f50}', '\u{342}', '\u{1f56}'),
+        ('\u{1f51}', '\u{300}', '\u{1f53}'), ('\u{1f51}', '\u{301}', '\u{1f55}'),
+        ('\u{1f51}', '\u{342}', '\u{1f57}'), ('\u{1f59}', '\u{300}', '\u{1f5b}'),
+        ('\u{1f59}', '\u{301}', '\u{1f5d}'), ('\u{1f59}', '\u{342}', '\u{1f5f}'),
+        ('\u{1f60}', '\u{300}', '\u{1f62}'), ('\u{1f60}', '\u{301}', '\u{1f64}'),
+        ('\u{1f60}', '\u{342}', '\u{1f66}'), ('\u{1f60}', '\u{345}', '\u{1fa0}'),
+        ('\u{1f61}', '\u{300}', '\u{1f63}'), ('\u{1f61}', '\u{301}', '\u{1f65}'),
+        ('\u{1f61}', '\u{342}', '\u{1f67}'), ('\u{1f61}', '\u{345}', '\u{1fa1}'),
+        ('\u{1f62}', '\u{345}', '\u{1fa2}'), ('\u{1f63}', '\u{345}', '\u{1fa3}'),
+        ('\u{1f64}', '\u{345}', '\u{1fa4}'), ('\u{1f65}', '\u{345}', '\u{1fa5}'),
+        ('\u{1f66}', '\u{345}', '\u{1fa6}'), ('\u{1f67}', '\u{345}', '\u{1fa7}'),
+        ('\u{1f68}', '\u{300}', '\u{1f6a}'), ('\u{1f68}', '\u{301}', '\u{1f6c}'),
+        ('\u{1f68}', '\u{342}', '\u{1f6e}'), ('\u{1f68}', '\u{345}', '\u{1fa8}'),
+        ('\u{1f69}', '\u{300}', '\u{1f6b}'), ('\u{1f69}', '\u{301}', '\u{1f6d}'),
+        ('\u{1f69}', '\u{342}', '\u{1f6f}'), ('\u{1f69}', '\u{345}', '\u{1fa9}'),
+        ('\u{1f6a}', '\u{345}', '\u{1faa}'), ('\u{1f6b}', '\u{345}', '\u{1fab}'),
+        ('\u{1f6c}', '\u{345}', '\u{1fac}'), ('\u{1f6d}', '\u{345}', '\u{1fad}'),
+        ('\u{1f6e}', '\u{345}', '\u{1fae}'), ('\u{1f6f}', '\u{345}', '\u{1faf}'),
+        ('\u{1f70}', '\u{345}', '\u{1fb2}'), ('\u{1f74}', '\u{345}', '\u{1fc2}'),
+        ('\u{1f7c}', '\u{345}', '\u{1ff2}'), ('\u{1fb6}', '\u{345}', '\u{1fb7}'),
+        ('\u{1fbf}', '\u{300}', '\u{1fcd}'), ('\u{1fbf}', '\u{301}', '\u{1fce}'),
+        ('\u{1fbf}', '\u{342}', '\u{1fcf}'), ('\u{1fc6}', '\u{345}', '\u{1fc7}'),
+        ('\u{1ff6}', '\u{345}', '\u{1ff7}'), ('\u{1ffe}', '\u{300}', '\u{1fdd}'),
+        ('\u{1ffe}', '\u{301}', '\u{1fde}'), ('\u{1ffe}', '\u{342}', '\u{1fdf}'),
+        ('\u{2190}', '\u{338}', '\u{219a}'), ('\u{2192}', '\u{338}', '\u{219b}'),
+        ('\u{2194}', '\u{338}', '\u{21ae}'), ('\u{21d0}', '\u{338}', '\u{21cd}'),
+        ('\u{21d2}', '\u{338}', '\u{21cf}'), ('\u{21d4}', '\u{338}', '\u{21ce}'),
+        ('\u{2203}', '\u{338}', '\u{2204}'), ('\u{2208}', '\u{338}', '\u{2209}'),
+        ('\u{220b}', '\u{338}', '\u{220c}'), ('\u{2223}', '\u{338}', '\u{2224}'),
+        ('\u{2225}', '\u{338}', '\u{2226}'), ('\u{223c}', '\u{338}', '\u{2241}'),
+        ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'),
+        ('\u{2248}', '\u{338}', '\u{2249}'), ('\u{224d}', '\u{338}', '\u{226d}'),
+        ('\u{2261}', '\u{338}', '\u{2262}'), ('\u{2264}', '\u{338}', '\u{2270}'),
+        ('\u{2265}', '\u{338}', '\u{2271}'), ('\u{2272}', '\u{338}', '\u{2274}'),
+        ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'),
+        ('\u{2277}', '\u{338}', '\u{2279}'), ('\u{227a}', '\u{338}', '\u{2280}'),
+        ('\u{227b}', '\u{338}', '\u{2281}'), ('\u{227c}', '\u{338}', '\u{22e0}'),
+        ('\u{227d}', '\u{338}', '\u{22e1}'), ('\u{2282}', '\u{338}', '\u{2284}'),
+        ('\u{2283}', '\u{338}', '\u{2285}'), ('\u{2286}', '\u{338}', '\u{2288}'),
+        ('\u{2287}', '\u{338}', '\u{2289}'), ('\u{2291}', '\u{338}', '\u{22e2}'),
+        ('\u{2292}', '\u{338}', '\u{22e3}'), ('\u{22a2}', '\u{338}', '\u{22ac}'),
+        ('\u{22a8}', '\u{338}', '\u{22ad}'), ('\u{22a9}', '\u{338}', '\u{22ae}'),
+        ('\u{22ab}', '\u{338}', '\u{22af}'), ('\u{22b2}', '\u{338}', '\u{22ea}'),
+        ('\u{22b3}', '\u{338}', '\u{22eb}'), ('\u{22b4}', '\u{338}', '\u{22ec}'),
+        ('\u{22b5}', '\u{338}', '\u{22ed}'), ('\u{3046}', '\u{3099}', '\u{3094}'),
+        ('\u{304b}', '\u{3099}', '\u{304c}'), ('\u{304d}', '\u{3099}', '\u{304e}'),
+        ('\u{304f}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'),
+        ('\u{3053}', '\u{3099}', '\u{3054}'), ('\u{3055}', '\u{3099}', '\u{3056}'),
+        ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305a}'),
+        ('\u{305b}', '\u{3099}', '\u{305c}'), ('\u{305d}', '\u{3099}', '\u{305e}'),
+        ('\u{305f}', '\u{3099}', '\u{3060}'), ('\u{3061}', '\u{3099}', '\u{3062}'),
+        ('\u{3064}', '\u{3099}', '\u{3065}'), ('\u{3066}', '\u{3099}', '\u{3067}'),
+        ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306f}', '\u{3099}', '\u{3070}'),
+        ('\u{306f}', '\u{309a}', '\u{3071}'), ('\u{3072}', '\u{3099}', '\u{3073}'),
+        ('\u{3072}', '\u{309a}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'),
+        ('\u{3075}', '\u{309a}', '\u{3077}'), ('\u{3078}', '\u{3099}', '\u{3079}'),
+        ('\u{3078}', '\u{309a}', '\u{307a}'), ('\u{307b}', '\u{3099}', '\u{307c}'),
+        ('\u{307b}', '\u{309a}', '\u{307d}'), ('\u{309d}', '\u{3099}', '\u{309e}'),
+        ('\u{30a6}', '\u{3099}', '\u{30f4}'), ('\u{30ab}', '\u{3099}', '\u{30ac}'),
+        ('\u{30ad}', '\u{3099}', '\u{30ae}'), ('\u{30af}', '\u{3099}', '\u{30b0}'),
+        ('\u{30b1}', '\u{3099}', '\u{30b2}'), ('\u{30b3}', '\u{3099}', '\u{30b4}'),
+        ('\u{30b5}', '\u{3099}', '\u{30b6}'), ('\u{30b7}', '\u{3099}', '\u{30b8}'),
+        ('\u{30b9}', '\u{3099}', '\u{30ba}'), ('\u{30bb}', '\u{3099}', '\u{30bc}'),
+        ('\u{30bd}', '\u{3099}', '\u{30be}'), ('\u{30bf}', '\u{3099}', '\u{30c0}'),
+        ('\u{30c1}', '\u{3099}', '\u{30c2}'), ('\u{30c4}', '\u{3099}', '\u{30c5}'),
+        ('\u{30c6}', '\u{3099}', '\u{30c7}'), ('\u{30c8}', '\u{3099}', '\u{30c9}'),
+        ('\u{30cf}', '\u{3099}', '\u{30d0}'), ('\u{30cf}', '\u{309a}', '\u{30d1}'),
+        ('\u{30d2}', '\u{3099}', '\u{30d3}'), ('\u{30d2}', '\u{309a}', '\u{30d4}'),
+        ('\u{30d5}', '\u{3099}', '\u{30d6}'), ('\u{30d5}', '\u{309a}', '\u{30d7}'),
+        ('\u{30d8}', '\u{3099}', '\u{30d9}'), ('\u{30d8}', '\u{309a}', '\u{30da}'),
+        ('\u{30db}', '\u{3099}', '\u{30dc}'), ('\u{30db}', '\u{309a}', '\u{30dd}'),
+        ('\u{30ef}', '\u{3099}', '\u{30f7}'), ('\u{30f0}', '\u{3099}', '\u{30f8}'),
+        ('\u{30f1}', '\u{3099}', '\u{30f9}'), ('\u{30f2}', '\u{3099}', '\u{30fa}'),
+        ('\u{30fd}', '\u{3099}', '\u{30fe}'), ('\u{11099}', '\u{110ba}', '\u{1109a}'),
+        ('\u{1109b}', '\u{110ba}', '\u{1109c}'), ('\u{110a5}', '\u{110ba}', '\u{110ab}'),
+        ('\u{11131}', '\u{11127}', '\u{1112e}'), ('\u{11132}', '\u{11127}', '\u{1112f}'),
+        ('\u{11347}', '\u{1133e}', '\u{1134b}'), ('\u{11347}', '\u{11357}', '\u{1134c}'),
+        ('\u{114b9}', '\u{114b0}', '\u{114bc}'), ('\u{114b9}', '\u{114ba}', '\u{114bb}'),
+        ('\u{114b9}', '\u{114bd}', '\u{114be}'), ('\u{115b8}', '\u{115af}', '\u{115ba}'),
+        ('\u{115b9}', '\u{115af}', '\u{115bb}'), ('\u{11935}', '\u{11930}', '\u{11938}'),
+    ];
+}
+
+#[rustfmt::skip]
+pub mod segmentation {
+    /// Returns the index of the value of the run that `c` is in.
+    fn lookup(c: char, runs: &[u32]) -> usize {
+        // The first run starts at U+0000, so there always is one.
+        let i = runs.partition_point(|&run| run >> 8 <= c as u32) - 1;
+        (runs[i] & 0xff) as usize
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum GraphemeClusterBreak {
+        Other,
+        CR,
+        Control,
+        Extend,
+        L,
+        LF,
+        LV,
+        LVT,
+        Prepend,
+        RegionalIndicator,
+        SpacingMark,
+        T,
+        V,
+        ZWJ,
+    }
+
+    pub fn grapheme_cluster_break(c: char) -> GraphemeClusterBreak {
+        GRAPHEME_CLUSTER_BREAK_VALUES[lookup(c, GRAPHEME_CLUSTER_BREAK_RUNS)]
+    }
+
+    static GRAPHEME_CLUSTER_BREAK_VALUES: &[GraphemeClusterBreak] = &[
+        GraphemeClusterBreak::Other, GraphemeClusterBreak::CR, GraphemeClusterBreak::Control,
+        GraphemeClusterBreak::Extend, GraphemeClusterBreak::L, GraphemeClusterBreak::LF,
+        GraphemeClusterBreak::LV, GraphemeClusterBreak::LVT, GraphemeClusterBreak::Prepend,
+        GraphemeClusterBreak::RegionalIndicator, GraphemeClusterBreak::SpacingMark,
+        GraphemeClusterBreak::T, GraphemeClusterBreak::V, GraphemeClusterBreak::ZWJ,
+    ];
+
+    static GRAPHEME_CLUSTER_BREAK_RUNS: &[u32] = &[
+        2, 2565, 2818, 3329, 3586, 8192, 32514, 40960, 44290, 44544, 196611, 225280, 295683,
+        297472, 364803, 376320, 376579, 376832, 377091, 377600, 377859, 378368, 378627, 378880,
+        393224, 394752, 397315, 400128, 400386, 400640, 412419, 417792, 421891, 422144, 448003,
+        449800, 450048, 450307, 451840, 452355, 452864, 453123, 454144, 462600, 462848, 463107,
+        463360, 471043, 477952, 501251, 504064, 518915, 521216, 523523, 523776, 529923, 530944,
+        531203, 533504, 533763, 534528, 534787, 536064, 547075, 547840, 561160, 561664, 563203,
+        565248, 576003, 582152, 582403, 590602, 590848, 604675, 604938, 605187, 605440, 605706,
+        606467, 608522, 609539, 609802, 610304, 610563, 612352, 614915, 615424, 622851, 623114,
+        623616, 637955, 638208, 638467, 638730, 639235, 640256, 640778, 641280, 641802, 642307,
+        642560, 644867, 645120, 647683, 648192, 654851, 655104, 655619, 656138, 656384, 670723,
+        670976, 671242, 672003, 672512, 673539, 674048, 674563, 675328, 676099, 676352, 684035,
+        684544, 685315, 685568, 688387, 688906, 689152, 703491, 703744, 704010, 704771, 706048,
+        706307, 706826, 707072, 707338, 707843, 708096, 713219, 713728, 719363, 720896, 721155,
+        721418, 721920, 736259, 736512, 736771, 737290, 737539, 738560, 739082, 739584, 740106,
+        740611, 740864, 742659, 743424, 745987, 746496, 754179, 754432, 769539, 769802, 770051,
+        770314, 770816, 771594, 772352, 772618, 773379, 773632, 775939, 776192, 786435, 786698,
+        787459, 787712, 801795, 802048, 802307, 803082, 804096, 804355, 805120, 805379, 806400,
+        808195, 808704, 811523, 812032, 819459, 819722, 820224, 834563, 834816, 835082, 835331,
+        835594, 836099, 836362, 836864, 837123, 837386, 837888, 838154, 838659, 839168, 840963,
+        841472, 844291, 844800, 848650, 848896, 851971, 852490, 852992, 867075, 867584, 867843,
+        868106, 868611, 869632, 869898, 870656, 870922, 871683, 871944, 872192, 874243, 874496,
+        877059, 877568, 884995, 885258, 885760, 903683, 903936, 904963, 905226, 905731, 906496,
+        906755, 907008, 907274, 909059, 909312, 913930, 914432, 930051, 930304, 930570, 930819,
+        932608, 935683, 937728, 962819, 963072, 963338, 963587, 965888, 968707, 970496, 989187,
+        989696, 996611, 996864, 997123, 997376, 997635, 997888, 998922, 999424, 1011971, 1015562,
+        1015811, 1017088, 1017347, 1017856, 1019139, 1021952, 1022211, 1031424, 1033731, 1033984,
+        1060099, 1061130, 1061379, 1062912, 1063171, 1063690, 1064195, 1064704, 1070602, 1071107,
+        1071616, 1072643, 1073408, 1077507, 1078528, 1081859, 1082112, 1082378, 1082627, 1083136,
+        1084675, 1084928, 1088771, 1089024, 1114116, 1138700, 1157131, 1179648, 1268995, 1269760,
+        1511939, 1512714, 1512960, 1520131, 1520650, 1520896, 1528323, 1528832, 1536515, 1537024,
+        1553411, 1553930, 1554179, 1555978, 1558019, 1558282, 1558787, 1561600, 1563907, 1564160,
+        1575683, 1576450, 1576707, 1576960, 1606915, 1607424, 1616131, 1616384, 1646595, 1647370,
+        1648387, 1648906, 1649664, 1650698, 1651203, 1651466, 1652995, 1653760, 1709827, 1710346,
+        1710851, 1711104, 1725706, 1725955, 1726218, 1726467, 1728256, 1728515, 1728768, 1729027,
+        1729280, 1729795, 1731850, 1733379, 1735936, 1736451, 1736704, 1748995, 1756928, 1769475,
+        1770506, 1770752, 1782787, 1784586, 1784835, 1785098, 1786371, 1786634, 1787136, 1796867,
+        1799168, 1802243, 1802762, 1803008, 1810698, 1810947, 1811978, 1812483, 1813002, 1813251,
+        1814016, 1828355, 1828618, 1828867, 1829386, 1830147, 1830410, 1830659, 1831434, 1831936,
+        1844234, 1846275, 1848330, 1848835, 1849344, 1888259, 1889024, 1889283, 1892618, 1892867,
+        1894656, 1895683, 1895936, 1897475, 1897728, 1898250, 1898499, 1899008, 1949699, 1966080,
+        2099970, 2100227, 2100493, 2100738, 2101248, 2107394, 2109184, 2121730, 2125824, 2150403,
+        2158848, 2944771, 2945536, 2981635, 2981888, 3006467, 3014656, 3156483, 3158016, 3184899,
+        3185408, 10907395, 10908416, 10908675, 10911232, 10919427, 10919936, 10940419, 10940928,
+        11010563, 11010816, 11011587, 11011840, 11012867, 11013120, 11019018, 11019523, 11020042,
+        11020288, 11021315, 11021568, 11042826, 11043328, 11056138, 11060227, 11060736, 11067395,
+        11072000, 11075331, 11075584, 11085315, 11087360, 11093763, 11096586, 11097088, 11100164,
+        11107584, 11108355, 11109130, 11109376, 11121411, 11121674, 11122179, 11123210, 11123715,
+        11124234, 11124992, 11134211, 11134464, 11151619, 11153162, 11153667, 11154186, 11154691,
+        11155200, 11158275, 11158528, 11160579, 11160842, 11161088, 11172867, 11173120, 11186179,
+        11186432, 11186691, 11187456, 11187971, 11188480, 11189763, 11190272, 11190531, 11190784,
+        11201290, 11201539, 11202058, 11202560, 11203850, 11204099, 11204352, 11264778, 11265283,
+        11265546, 11266051, 11266314, 11266816, 11267082, 11267331, 11267584, 11272198, 11272455,
+        11279366, 11279623, 11286534, 11286791, 11293702, 11293959, 11300870, 11301127, 11308038,
+        11308295, 11315206, 11315463, 11322374, 11322631, 11329542, 11329799, 11336710, 11336967,
+        11343878, 11344135, 11351046, 11351303, 11358214, 11358471, 11365382, 11365639, 11372550,
+        11372807, 11379718, 11379975, 11386886, 11387143, 11394054, 11394311, 11401222, 11401479,
+        11408390, 11408647, 11415558, 11415815, 11422726, 11422983, 11429894, 11430151, 11437062,
+        11437319, 11444230, 11444487, 11451398, 11451655, 11458566, 11458823, 11465734, 11465991,
+        11472902, 11473159, 11480070, 11480327, 11487238, 11487495, 11494406, 11494663, 11501574,
+        11501831, 11508742, 11508999, 11515910, 11516167, 11523078, 11523335, 11530246, 11530503,
+        11537414, 11537671, 11544582, 11544839, 11551750, 11552007, 11558918, 11559175, 11566086,
+        11566343, 11573254, 11573511, 11580422, 11580679, 11587590, 11587847, 11594758, 11595015,
+        11601926, 11602183, 11609094, 11609351, 11616262, 11616519, 11623430, 11623687, 11630598,
+        11630855, 11637766, 11638023, 11644934, 11645191, 11652102, 11652359, 11659270, 11659527,
+        11666438, 11666695, 11673606, 11673863, 11680774, 11681031, 11687942, 11688199, 11695110,
+        11695367, 11702278, 11702535, 11709446, 11709703, 11716614, 11716871, 11723782, 11724039,
+        11730950, 11731207, 11738118, 11738375, 11745286, 11745543, 11752454, 11752711, 11759622,
+        11759879, 11766790, 11767047, 11773958, 11774215, 11781126, 11781383, 11788294, 11788551,
+        11795462, 11795719, 11802630, 11802887, 11809798, 11810055, 11816966, 11817223, 11824134,
+        11824391, 11831302, 11831559, 11838470, 11838727, 11845638, 11845895, 11852806, 11853063,
+        11859974, 11860231, 11867142, 11867399, 11874310, 11874567, 11881478, 11881735, 11888646,
+        11888903, 11895814, 11896071, 11902982, 11903239, 11910150, 11910407, 11917318, 11917575,
+        11924486, 11924743, 11931654, 11931911, 11938822, 11939079, 11945990, 11946247, 11953158,
+        11953415, 11960326, 11960583, 11967494, 11967751, 11974662, 11974919, 11981830, 11982087,
+        11988998, 11989255, 11996166, 11996423, 12003334, 12003591, 12010502, 12010759, 12017670,
+        12017927, 12024838, 12025095, 12032006, 12032263, 12039174, 12039431, 12046342, 12046599,
+        12053510, 12053767, 12060678, 12060935, 12067846, 12068103, 12075014, 12075271, 12082182,
+        12082439, 12089350, 12089607, 12096518, 12096775, 12103686, 12103943, 12110854, 12111111,
+        12118022, 12118279, 12125190, 12125447, 12132358, 12132615, 12139526, 12139783, 12146694,
+        12146951, 12153862, 12154119, 12161030, 12161287, 12168198, 12168455, 12175366, 12175623,
+        12182534, 12182791, 12189702, 12189959, 12196870, 12197127, 12204038, 12204295, 12211206,
+        12211463, 12218374, 12218631, 12225542, 12225799, 12232710, 12232967, 12239878, 12240135,
+        12247046, 12247303, 12254214, 12254471, 12261382, 12261639, 12268550, 12268807, 12275718,
+        12275975, 12282886, 12283143, 12290054, 12290311, 12297222, 12297479, 12304390, 12304647,
+        12311558, 12311815, 12318726, 12318983, 12325894, 12326151, 12333062, 12333319, 12340230,
+        12340487, 12347398, 12347655, 12354566, 12354823, 12361734, 12361991, 12368902, 12369159,
+        12376070, 12376327, 12383238, 12383495, 12390406, 12390663, 12397574, 12397831, 12404742,
+        12404999, 12411910, 12412167, 12419078, 12419335, 12426246, 12426503, 12433414, 12433671,
+        12440582, 12440839, 12447750, 12448007, 12454918, 12455175, 12462086, 12462343, 12469254,
+        12469511, 12476422, 12476679, 12483590, 12483847, 12490758, 12491015, 12497926, 12498183,
+        12505094, 12505351, 12512262, 12512519, 12519430, 12519687, 12526598, 12526855, 12533766,
+        12534023, 12540934, 12541191, 12548102, 12548359, 12555270, 12555527, 12562438, 12562695,
+        12569606, 12569863, 12576774, 12577031, 12583942, 12584199, 12591110, 12591367, 12598278,
+        12598535, 12605446, 12605703, 12612614, 12612871, 12619782, 12620039, 12626950, 12627207,
+        12634118, 12634375, 12641286, 12641543, 12648454, 12648711, 12655622, 12655879, 12662790,
+        12663047, 12669958, 12670215, 12677126, 12677383, 12684294, 12684551, 12691462, 12691719,
+        12698630, 12698887, 12705798, 12706055, 12712966, 12713223, 12720134, 12720391, 12727302,
+        12727559, 12734470, 12734727, 12741638, 12741895, 12748806, 12749063, 12755974, 12756231,
+        12763142, 12763399, 12770310, 12770567, 12777478, 12777735, 12784646, 12784903, 12791814,
+        12792071, 12798982, 12799239, 12806150, 12806407, 12813318, 12813575, 12820486, 12820743,
+        12827654, 12827911, 12834822, 12835079, 12841990, 12842247, 12849158, 12849415, 12856326,
+        12856583, 12863494, 12863751, 12870662, 12870919, 12877830, 12878087, 12884998, 12885255,
+        12892166, 12892423, 12899334, 12899591, 12906502, 12906759, 12913670, 12913927, 12920838,
+        12921095, 12928006, 12928263, 12935174, 12935431, 12942342, 12942599, 12949510, 12949767,
+        12956678, 12956935, 12963846, 12964103, 12971014, 12971271, 12978182, 12978439, 12985350,
+        12985607, 12992518, 12992775, 12999686, 12999943, 13006854, 13007111, 13014022, 13014279,
+        13021190, 13021447, 13028358, 13028615, 13035526, 13035783, 13042694, 13042951, 13049862,
+        13050119, 13057030, 13057287, 13064198, 13064455, 13071366, 13071623, 13078534, 13078791,
+        13085702, 13085959, 13092870, 13093127, 13100038, 13100295, 13107206, 13107463, 13114374,
+        13114631, 13121542, 13121799, 13128710, 13128967, 13135878, 13136135, 13143046, 13143303,
+        13150214, 13150471, 13157382, 13157639, 13164550, 13164807, 13171718, 13171975, 13178886,
+        13179143, 13186054, 13186311, 13193222, 13193479, 13200390, 13200647, 13207558, 13207815,
+        13214726, 13214983, 13221894, 13222151, 13229062, 13229319, 13236230, 13236487, 13243398,
+        13243655, 13250566, 13250823, 13257734, 13257991, 13264902, 13265159, 13272070, 13272327,
+        13279238, 13279495, 13286406, 13286663, 13293574, 13293831, 13300742, 13300999, 13307910,
+        13308167, 13315078, 13315335, 13322246, 13322503, 13329414, 13329671, 13336582, 13336839,
+        13343750, 13344007, 13350918, 13351175, 13358086, 13358343, 13365254, 13365511, 13372422,
+        13372679, 13379590, 13379847, 13386758, 13387015, 13393926, 13394183, 13401094, 13401351,
+        13408262, 13408519, 13415430, 13415687, 13422598, 13422855, 13429766, 13430023, 13436934,
+        13437191, 13444102, 13444359, 13451270, 13451527, 13458438, 13458695, 13465606, 13465863,
+        13472774, 13473031, 13479942, 13480199, 13487110, 13487367, 13494278, 13494535, 13501446,
+        13501703, 13508614, 13508871, 13515782, 13516039, 13522950, 13523207, 13530118, 13530375,
+        13537286, 13537543, 13544454, 13544711, 13551622, 13551879, 13558790, 13559047, 13565958,
+        13566215, 13573126, 13573383, 13580294, 13580551, 13587462, 13587719, 13594630, 13594887,
+        13601798, 13602055, 13608966, 13609223, 13616134, 13616391, 13623302, 13623559, 13630470,
+        13630727, 13637638, 13637895, 13644806, 13645063, 13651974, 13652231, 13659142, 13659399,
+        13666310, 13666567, 13673478, 13673735, 13680646, 13680903, 13687814, 13688071, 13694982,
+        13695239, 13702150, 13702407, 13709318, 13709575, 13716486, 13716743, 13723654, 13723911,
+        13730822, 13731079, 13737990, 13738247, 13745158, 13745415, 13752326, 13752583, 13759494,
+        13759751, 13766662, 13766919, 13773830, 13774087, 13780998, 13781255, 13788166, 13788423,
+        13795334, 13795591, 13802502, 13802759, 13809670, 13809927, 13816838, 13817095, 13824006,
+        13824263, 13831174, 13831431, 13838342, 13838599, 13845510, 13845767, 13852678, 13852935,
+        13859846, 13860103, 13867014, 13867271, 13874182, 13874439, 13881350, 13881607, 13888518,
+        13888775, 13895686, 13895943, 13902854, 13903111, 13910022, 13910279, 13917190, 13917447,
+        13924358, 13924615, 13931526, 13931783, 13938694, 13938951, 13945862, 13946119, 13953030,
+        13953287, 13960198, 13960455, 13967366, 13967623, 13974534, 13974791, 13981702, 13981959,
+        13988870, 13989127, 13996038, 13996295, 14003206, 14003463, 14010374, 14010631, 14017542,
+        14017799, 14024710, 14024967, 14031878, 14032135, 14039046, 14039303, 14046214, 14046471,
+        14053382, 14053639, 14060550, 14060807, 14067718, 14067975, 14074886, 14075143, 14082054,
+        14082311, 14089222, 14089479, 14096390, 14096647, 14103558, 14103815, 14110726, 14110983,
+        14117894, 14118151, 14125062, 14125319, 14132224, 14135308, 14141184, 14142219, 14154752,
+        16457219, 16457472, 16646147, 16650240, 16654339, 16658432, 16711426, 16711680, 16752131,
+        16752640, 16773122, 16776192, 16907523, 16907776, 16965635, 16965888, 17004035, 17005312,
+        17432835, 17433600, 17433859, 17434368, 17435651, 17436672, 17446915, 17447680, 17448707,
+        17448960, 17491203, 17491712, 17638403, 17639424, 17738499, 17739008, 17759491, 17760256,
+        17778179, 17780992, 17793539, 17794560, 17825802, 17826051, 17826314, 17826560, 17840131,
+        17843968, 17854467, 17854720, 17855235, 17855744, 17858307, 17859082, 17859328, 17870858,
+        17871619, 17872650, 17873155, 17873664, 17874184, 17874432, 17875459, 17875712, 17878280,
+        17878528, 17891331, 17892096, 17901315, 17902602, 17902851, 17904896, 17909002, 17909504,
+        17920771, 17921024, 17924099, 17924618, 17924864, 17937162, 17937923, 17940234, 17940736,
+        17941000, 17941504, 17942787, 17943808, 17944074, 17944323, 17944576, 17968138, 17968899,
+        17969674, 17970179, 17970442, 17970691, 17971200, 17972739, 17972992, 17973507, 17973760,
+        18013955, 18014218, 18014979, 18017024, 18022403, 18022922, 18023424, 18037507, 18038016,
+        18038275, 18038538, 18038787, 18039050, 18040064, 18040586, 18041088, 18041610, 18042368,
+        18044675, 18044928, 18047498, 18048000, 18048515, 18050304, 18051075, 18052352, 18101514,
+        18102275, 18104330, 18104835, 18105610, 18105859, 18106112, 18112003, 18112256, 18132995,
+        18133258, 18133763, 18135306, 18135555, 18135818, 18136323, 18136586, 18136835, 18137354,
+        18137603, 18138112, 18198275, 18198538, 18199043, 18200064, 18200586, 18201603, 18202122,
+        18202371, 18202880, 18209795, 18210304, 18231306, 18232067, 18234122, 18234627, 18234890,
+        18235139, 18235648, 18262787, 18263050, 18263299, 18263562, 18264067, 18265610, 18265859,
+        18266112, 18291971, 18292736, 18293251, 18294282, 18294531, 18295808, 18361354, 18362115,
+        18364426, 18364675, 18365184, 18427907, 18428170, 18429440, 18429706, 18430208, 18430723,
+        18431242, 18431491, 18431752, 18432010, 18432264, 18432522, 18432771, 18433024, 18469130,
+        18469891, 18470912, 18471427, 18471946, 18472963, 18473216, 18473994, 18474240, 18481411,
+        18483968, 18494211, 18495754, 18496008, 18496259, 18497280, 18499331, 18499584, 18501891,
+        18503434, 18503939, 18504704, 18514952, 18516483, 18519818, 18520067, 18520576, 18624266,
+        18624515, 18626304, 18626563, 18628106, 18628355, 18628608, 18649603, 18655232, 18655498,
+        18655747, 18657546, 18657795, 18658314, 18658563, 18659072, 18690307, 18691840, 18692611,
+        18692864, 18693123, 18693632, 18693891, 18695688, 18695939, 18696192, 18713098, 18714368,
+        18714627, 18715136, 18715402, 18715907, 18716170, 18716419, 18716672, 18805507, 18806026,
+        18806528, 18808835, 18809352, 18809610, 18809856, 18822154, 18822659, 18823936, 18824714,
+        18825219, 18825482, 18825731, 18825984, 20197378, 20201475, 20201728, 20203267, 20207104,
+        23785475, 23786752, 23801859, 23803648, 24071939, 24072192, 24072458, 24086528, 24088323,
+        24089344, 24110083, 24110336, 24113162, 24113664, 29138179, 29138688, 29138946, 29139968,
+        30343171, 30354944, 30355459, 30361344, 30500099, 30500362, 30500611, 30501376, 30502154,
+        30502403, 30503682, 30505731, 30507776, 30508291, 30510080, 30517763, 30518784, 30556675,
+        30557440, 31064067, 31078144, 31079171, 31091968, 31094019, 31094272, 31097859, 31098112,
+        31103747, 31105024, 31105283, 31109120, 31457283, 31459072, 31459331, 31463680, 31464195,
+        31465984, 31466243, 31466752, 31467011, 31468288, 31493891, 31494144, 31535107, 31536896,
+        31632899, 31633152, 31648771, 31649792, 31779843, 31780864, 32034819, 32036608, 32064515,
+        32066304, 32630281, 32636928, 32766723, 32768000, 234881026, 234889219, 234913794,
+        234946563, 235008002, 235929600,
+    ];
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum WordBreak {
+        Other,
+        ALetter,
+        CR,
+        DoubleQuote,
+        Extend,
+        ExtendNumLet,
+        Format,
+        HebrewLetter,
+        Katakana,
+        LF,
+        MidLetter,
+        MidNum,
+        MidNumLet,
+        Newline,
+        Numeric,
+        RegionalIndicator,
+        SingleQuote,
+        WSegSpace,
+        ZWJ,
+    }
+
+    pub fn word_break(c: char) -> WordBreak {
+        WORD_BREAK_VALUES[lookup(c, WORD_BREAK_RUNS)]
+    }
+
+    static WORD_BREAK_VALUES: &[WordBreak] = &[
+        WordBreak::Other, WordBreak::ALetter, WordBreak::CR, WordBreak::DoubleQuote,
+        WordBreak::Extend, WordBreak::ExtendNumLet, WordBreak::Format, WordBreak::HebrewLetter,
+        WordBreak::Katakana, WordBreak::LF, WordBreak::MidLetter, WordBreak::MidNum,
+        WordBreak::MidNumLet, WordBreak::Newline, WordBreak::Numeric, WordBreak::RegionalIndicator,
+        WordBreak::SingleQuote, WordBreak::WSegSpace, WordBreak::ZWJ,
+    ];
+
+    static WORD_BREAK_RUNS: &[u32] = &[
+        0, 2569, 2829, 3330, 3584, 8209, 8448, 8707, 8960, 10000, 10240, 11275, 11520, 11788,
+        12032, 12302, 14858, 15115, 15360, 16641, 23296, 24325, 24576, 24833, 31488, 34061, 34304,
+        43521, 43776, 44294, 44544, 46337, 46592, 46858, 47104, 47617, 47872, 49153, 55040, 55297,
+        63232, 63489, 186368, 187905, 196612, 225281, 226560, 226817, 227328, 227841, 228875,
+        229121, 229376, 230913, 231178, 231425, 232192, 232449, 232704, 232961, 238080, 238337,
+        259584, 259841, 295424, 295684, 297473, 339968, 340225, 349952, 350465, 351488, 351745,
+        352010, 352257, 362763, 363009, 363264, 364804, 376320, 376580, 376832, 377092, 377600,
+        377860, 378368, 378628, 378880, 380935, 387840, 388871, 389889, 390154, 390400, 393230,
+        394752, 396299, 396800, 397316, 400128, 400390, 400640, 401409, 412420, 417806, 420352,
+        420622, 420875, 421120, 421377, 421892, 422145, 447488, 447745, 448004, 449806, 450048,
+        450308, 451841, 452356, 452864, 453124, 454145, 454670, 457217, 457984, 458497, 458752,
+        462593, 463108, 463361, 471044, 477952, 478465, 501252, 504065, 504320, 507918, 510465,
+        518916, 521217, 521728, 522251, 522496, 522753, 523008, 523524, 523776, 524289, 529924,
+        530945, 531204, 533505, 533764, 534529, 534788, 536064, 540673, 547076, 547840, 548865,
+        551680, 552961, 559104, 559361, 560896, 561166, 561664, 563204, 565249, 576004, 582158,
+        582404, 590849, 604676, 605441, 605700, 610305, 610564, 612353, 614916, 615424, 615950,
+        618496, 618753, 622852, 623616, 623873, 625920, 626433, 626944, 627457, 633088, 633345,
+        635136, 635393, 635648, 636417, 637440, 637956, 638209, 638468, 640256, 640772, 641280,
+        641796, 642561, 642816, 644868, 645120, 646145, 646656, 646913, 647684, 648192, 648718,
+        651265, 651776, 654337, 654592, 654852, 655104, 655620, 656384, 656641, 658176, 659201,
+        659712, 660225, 665856, 666113, 667904, 668161, 668672, 668929, 669440, 669697, 670208,
+        670724, 670976, 671236, 672512, 673540, 674048, 674564, 675328, 676100, 676352, 678145,
+        679168, 679425, 679680, 681486, 684036, 684545, 685316, 685568, 688388, 689152, 689409,
+        691712, 691969, 692736, 692993, 698624, 698881, 700672, 700929, 701440, 701697, 702976,
+        703492, 703745, 704004, 706048, 706308, 707072, 707332, 708096, 708609, 708864, 712705,
+        713220, 713728, 714254, 716800, 719105, 719364, 720896, 721156, 721920, 722177, 724224,
+        724737, 725248, 725761, 731392, 731649, 733440, 733697, 734208, 734465, 735744, 736260,
+        736513, 736772, 738560, 739076, 739584, 740100, 740864, 742660, 743424, 744449, 744960,
+        745217, 745988, 746496, 747022, 749568, 749825, 750080, 754180, 754433, 754688, 754945,
+        756480, 757249, 758016, 758273, 759296, 760065, 760576, 760833, 761088, 761345, 761856,
+        762625, 763136, 763905, 764672, 765441, 768512, 769540, 770816, 771588, 772352, 772612,
+        773632, 774145, 774400, 775940, 776192, 779790, 782336, 786436, 787713, 789760, 790017,
+        790784, 791041, 796928, 797185, 801280, 801796, 802049, 802308, 804096, 804356, 805120,
+        805380, 806400, 808196, 808704, 808961, 809728, 810241, 810496, 811009, 811524, 812032,
+        812558, 815104, 819201, 819460, 820224, 820481, 822528, 822785, 823552, 823809, 829696,
+        829953, 832512, 832769, 834048, 834564, 834817, 835076, 836864, 837124, 837888, 838148,
+        839168, 840964, 841472, 843009, 843520, 843777, 844292, 844800, 845326, 847872, 848129,
+        848644, 848896, 851972, 852993, 855296, 855553, 856320, 856577, 867076, 867585, 867844,
+        869632, 869892, 870656, 870916, 871937, 872192, 873473, 874244, 874496, 876289, 877060,
+        877568, 878094, 880640, 883201, 884736, 884996, 885760, 886017, 890624, 891393, 897536,
+        897793, 900096, 900353, 900608, 901121, 902912, 903684, 903936, 904964, 906496, 906756,
+        907008, 907268, 909312, 910862, 913408, 913924, 914432, 930052, 930304, 930820, 932608,
+        935684, 937728, 937998, 940544, 962820, 963072, 963588, 965888, 968708, 970496, 970766,
+        973312, 983041, 983296, 989188, 989696, 991246, 993792, 996612, 996864, 997124, 997376,
+        997636, 997888, 998916, 999425, 1001472, 1001729, 1010944, 1011972, 1017088, 1017348,
+        1017857, 1019140, 1021952, 1022212, 1031424, 1033732, 1033984, 1059588, 1064704, 1064974,
+        1067520, 1070596, 1071616, 1072644, 1073408, 1073668, 1074432, 1074948, 1076736, 1077508,
+        1078528, 1081860, 1084928, 1085188, 1085454, 1088004, 1089024, 1089537, 1099264, 1099521,
+        1099776, 1101057, 1101312, 1101825, 1112832, 1113089, 1198336, 1198593, 1199616, 1200129,
+        1201920, 1202177, 1202432, 1202689, 1203712, 1204225, 1214720, 1214977, 1216000, 1216513,
+        1224960, 1225217, 1226240, 1226753, 1228544, 1228801, 1229056, 1229313, 1230336, 1230849,
+        1234688, 1234945, 1249536, 1249793, 1250816, 1251329, 1268480, 1268996, 1269760, 1277953,
+        1282048, 1286145, 1308160, 1308673, 1310208, 1310977, 1469696, 1470209, 1474577, 1474817,
+        1481472, 1482753, 1501952, 1502721, 1505536, 1507329, 1511940, 1512960, 1515265, 1520132,
+        1520896, 1523713, 1528324, 1528832, 1531905, 1535232, 1535489, 1536256, 1536516, 1537024,
+        1553412, 1561600, 1563908, 1564160, 1564686, 1567232, 1575684, 1576454, 1576708, 1576974,
+        1579520, 1581057, 1603840, 1605633, 1606916, 1607425, 1616132, 1616385, 1616640, 1617921,
+        1635840, 1638401, 1646336, 1646596, 1649664, 1650692, 1653760, 1656334, 1658880, 1691662,
+        1694208, 1703937, 1709828, 1711104, 1725700, 1728256, 1728516, 1735936, 1736452, 1736718,
+        1739264, 1740814, 1743360, 1748996, 1756928, 1769476, 1770753, 1782788, 1787137, 1789184,
+        1789966, 1792512, 1796868, 1799168, 1802244, 1803009, 1810692, 1814017, 1814542, 1817089,
+        1828356, 1831936, 1835009, 1844228, 1849344, 1851406, 1853952, 1854721, 1855502, 1858049,
+        1867264, 1867777, 1870080, 1871873, 1882880, 1883393, 1884160, 1888260, 1889024, 1889284,
+        1894657, 1895684, 1895937, 1897476, 1897729, 1898244, 1899009, 1899264, 1900545, 1949700,
+        1966081, 2037248, 2037761, 2039296, 2039809, 2049536, 2050049, 2051584, 2052097, 2054144,
+        2054401, 2054656, 2054913, 2055168, 2055425, 2055680, 2055937, 2063872, 2064385, 2077952,
+        2078209, 2080000, 2080257, 2080512, 2081281, 2082048, 2082305, 2084096, 2084865, 2085888,
+        2086401, 2087936, 2088961, 2092288, 2093569, 2094336, 2094593, 2096384, 2097169, 2098944,
+        2099217, 2099968, 2100228, 2100498, 2100742, 2101248, 2103308, 2103808, 2106380, 2106624,
+        2107146, 2107405, 2107910, 2109189, 2109440, 2113285, 2113792, 2114571, 2114816, 2118661,
+        2118912, 2121489, 2121734, 2123008, 2123270, 2125824, 2126081, 2126336, 2129665, 2129920,
+        2134017, 2137344, 2150404, 2158848, 2163201, 2163456, 2164481, 2164736, 2165249, 2167808,
+        2168065, 2168320, 2169089, 2170368, 2171905, 2172160, 2172417, 2172672, 2172929, 2173184,
+        2173441, 2174464, 2174721, 2177536, 2178049, 2179072, 2180353, 2181632, 2182657, 2182912,
+        2187265, 2197760, 2405889, 2419200, 2883585, 2942208, 2943745, 2944772, 2945537, 2946048,
+        2949121, 2958848, 2959105, 2959360, 2960641, 2960896, 2961409, 2975744, 2977537, 2977792,
+        2981636, 2981889, 2987776, 2990081, 2991872, 2992129, 2993920, 2994177, 2995968, 2996225,
+        2998016, 2998273, 3000064, 3000321, 3002112, 3002369, 3004160, 3004417, 3006208, 3006468,
+        3014656, 3026689, 3026944, 3145745, 3145984, 3147009, 3147264, 3156484, 3158016, 3158280,
+        3159552, 3160833, 3161344, 3184900, 3185416, 3185920, 3186696, 3209984, 3210248, 3211264,
+        3212545, 3223552, 3223809, 3247872, 3252225, 3260416, 3272712, 3276800, 3330056, 3342080,
+        3342344, 3364864, 10485761, 10784000, 10801153, 10812928, 10813441, 10882304, 10883073,
+        10887182, 10889729, 10890240, 10895361, 10907396, 10908416, 10908676, 10911232, 10911489,
+        10919428, 10919937, 10940420, 10940928, 10946561, 10996480, 10997761, 10998272, 10998529,
+        10998784, 10999041, 11000320, 11006465, 11010564, 11010817, 11011588, 11011841, 11012868,
+        11013121, 11019012, 11020288, 11021316, 11021568, 11026433, 11039744, 11042820, 11043329,
+        11056132, 11060736, 11063310, 11065856, 11067396, 11072001, 11073536, 11074305, 11074560,
+        11074817, 11075332, 11075598, 11078145, 11085316, 11087360, 11087873, 11093764, 11097088,
+        11100161, 11107584, 11108356, 11109377, 11121412, 11124992, 11128577, 11128846, 11131392,
+        11134212, 11134464, 11137038, 11139584, 11141121, 11151620, 11155200, 11157505, 11158276,
+        11158529, 11160580, 11161088, 11161614, 11164160, 11172612, 11173376, 11186180, 11186432,
+        11186692, 11187456, 11187972, 11188480, 11189764, 11190272, 11190532, 11190784, 11198465,
+        11201284, 11202560, 11203073, 11203844, 11204352, 11206913, 11208448, 11208961, 11210496,
+        11211009, 11212544, 11214849, 11216640, 11216897, 11218688, 11218945, 11233792, 11235329,
+        11264772, 11266816, 11267076, 11267584, 11268110, 11270656, 11272193, 14132224, 14135297,
+        14141184, 14142209, 14154752, 16449537, 16451328, 16454401, 16455680, 16456967, 16457220,
+        16457479, 16460032, 16460295, 16463616, 16463879, 16465152, 16465415, 16465664, 16465927,
+        16466432, 16466695, 16467200, 16467463, 16470017, 16495104, 16503553, 16596480, 16601089,
+        16617472, 16617985, 16631808, 16642049, 16645120, 16646148, 16650251, 16650496, 16651018,
+        16651275, 16651520, 16654340, 16658432, 16659205, 16659712, 16665861, 16666635, 16666880,
+        16667148, 16667392, 16667659, 16667914, 16668160, 16674817, 16676096, 16676353, 16710912,
+        16711430, 16711680, 16713484, 16713728, 16714763, 16715008, 16715276, 16715520, 16715790,
+        16718346, 16718603, 16718848, 16720129, 16726784, 16727813, 16728064, 16728321, 16734976,
+        16737800, 16752132, 16752641, 16760576, 16761345, 16762880, 16763393, 16764928, 16765441,
+        16766976, 16767489, 16768256, 16775430, 16776192, 16777217, 16780288, 16780545, 16787200,
+        16787457, 16792320, 16792577, 16793088, 16793345, 16797184, 16797697, 16801280, 16809985,
+        16841472, 16859137, 16872704, 16907524, 16907776, 16941057, 16948480, 16949249, 16961792,
+        16965636, 16965888, 16973825, 16982016, 16985345, 16993024, 16994305, 17004036, 17005312,
+        17006593, 17014272, 17014785, 17024000, 17025025, 17027072, 17027329, 17028608, 17039361,
+        17079808, 17080334, 17082880, 17084417, 17093632, 17094657, 17103872, 17104897, 17115136,
+        17117185, 17130496, 17133569, 17136384, 17136641, 17140480, 17140737, 17142528, 17142785,
+        17143296, 17143553, 17146368, 17146625, 17150464, 17150721, 17152512, 17152769, 17153280,
+        17170433, 17250048, 17252353, 17257984, 17260545, 17262592, 17268737, 17270272, 17270529,
+        17281280, 17281537, 17283840, 17301505, 17303040, 17303553, 17303808, 17304065, 17315328,
+        17315585, 17316096, 17316865, 17317120, 17317633, 17323520, 17326081, 17331968, 17334273,
+        17342208, 17358849, 17363712, 17363969, 17364480, 17367041, 17372672, 17375233, 17381888,
+        17399809, 17414144, 17415681, 17416192, 17432577, 17432836, 17433600, 17433860, 17434368,
+        17435652, 17436673, 17437696, 17437953, 17438720, 17438977, 17446400, 17446916, 17447680,
+        17448708, 17448960, 17457153, 17464576, 17465345, 17472768, 17481729, 17483776, 17484033,
+        17491204, 17491712, 17498113, 17511936, 17514497, 17520128, 17522689, 17527552, 17530881,
+        17535488, 17563649, 17582336, 17596417, 17609472, 17612801, 17625856, 17629185, 17638404,
+        17639424, 17641486, 17644032, 17727489, 17738240, 17738500, 17739008, 17739777, 17740288,
+        17759492, 17760257, 17767680, 17770241, 17770496, 17772545, 17778180, 17780992, 17788929,
+        17793540, 17794560, 17805313, 17810688, 17817601, 17823488, 17825796, 17826561, 17840132,
+        17843968, 17851918, 17854468, 17854721, 17855236, 17855745, 17856000, 17858308, 17859329,
+        17870852, 17873664, 17874190, 17874432, 17875460, 17875712, 17878286, 17878528, 17879041,
+        17885440, 17887246, 17889792, 17891332, 17892097, 17901316, 17904896, 17905166, 17907712,
+        17908737, 17908996, 17909505, 17909760, 17911809, 17920772, 17921024, 17921537, 17921792,
+        17924100, 17924865, 17937156, 17940737, 17941760, 17942788, 17943808, 17944068, 17944590,
+        17947137, 17947392, 17947649, 17947904, 17956865, 17961472, 17961729, 17968132, 17971200,
+        17972740, 17972993, 17973508, 17973760, 17989633, 17991424, 17991681, 17991936, 17992193,
+        17993216, 17993473, 17997312, 17997569, 18000128, 18001921, 18013956, 18017024, 18018318,
+        18020864, 18022404, 18023424, 18023681, 18025728, 18026241, 18026752, 18027265, 18032896,
+        18033153, 18034944, 18035201, 18035712, 18035969, 18037248, 18037508, 18038017, 18038276,
+        18040064, 18040580, 18041088, 18041604, 18042368, 18042881, 18043136, 18044676, 18044928,
+        18046209, 18047492, 18048000, 18048516, 18050304, 18051076, 18052352, 18087937, 18101508,
+        18106113, 18107136, 18108430, 18110976, 18112004, 18112257, 18113024, 18120705, 18132996,
+        18138113, 18138624, 18138881, 18139136, 18141198, 18143744, 18186241, 18198276, 18200064,
+        18200580, 18202880, 18208769, 18209796, 18210304, 18219009, 18231300, 18235648, 18236417,
+        18236672, 18239502, 18242048, 18251777, 18262788, 18266113, 18266368, 18268174, 18270720,
+        18291972, 18295808, 18296846, 18299392, 18350081, 18361348, 18365184, 18391041, 18407438,
+        18409984, 18415361, 18417408, 18417921, 18418176, 18418689, 18420736, 18420993, 18421504,
+        18421761, 18427908, 18429440, 18429700, 18430208, 18430724, 18431745, 18432004, 18432257,
+        18432516, 18433024, 18436110, 18438656, 18456577, 18458624, 18459137, 18469124, 18470912,
+        18471428, 18473217, 18473472, 18473729, 18473988, 18474240, 18481153, 18481412, 18483969,
+        18494212, 18496001, 18496260, 18497280, 18499332, 18499584, 18501633, 18501892, 18504705,
+        18516484, 18520576, 18521345, 18521600, 18526209, 18544896, 18612225, 18614528, 18614785,
+        18624260, 18626304, 18626564, 18628609, 18628864, 18632718, 18635264, 18641409, 18649088,
+        18649604, 18655232, 18655492, 18659072, 18677761, 18679552, 18679809, 18680320, 18680577,
+        18690308, 18691840, 18692612, 18692864, 18693124, 18693632, 18693892, 18695681, 18695940,
+        18696192, 18698254, 18700800, 18702337, 18703872, 18704129, 18704640, 18704897, 18713092,
+        18714368, 18714628, 18715136, 18715396, 18716673, 18716928, 18718734, 18721280, 18800641,
+        18805508, 18806528, 18808836, 18809345, 18809604, 18809857, 18813184, 18813441, 18822148,
+        18823936, 18824708, 18825984, 18829326, 18831872, 18853889, 18854144, 18874369, 19110400,
+        19136513, 19164928, 19169281, 19219456, 19894273, 19919104, 19922945, 20197382, 20201476,
+        20201729, 20203268, 20207104, 21233665, 21382912, 23592961, 23738624, 23740417, 23748352,
+        23748622, 23751168, 23752705, 23772928, 23773198, 23775744, 23777281, 23784960, 23785476,
+        23786752, 23789569, 23801860, 23803648, 23805953, 23806976, 23810062, 23812608, 23814913,
+        23820288, 23821569, 23826432, 24002561, 24018944, 24051713, 24070912, 24071940, 24072193,
+        24072452, 24086528, 24088324, 24089345, 24092672, 24109057, 24109568, 24109825, 24110084,
+        24110336, 24113156, 24113664, 28307464, 28308480, 28308744, 28310528, 28310792, 28311296,
+        28311560, 28311808, 28385288, 28386048, 28398856, 28399104, 28402696, 28403712, 29097985,
+        29125376, 29126657, 29129984, 29130753, 29133056, 29134849, 29137408, 29138180, 29138688,
+        29138950, 29139968, 30343172, 30354944, 30355460, 30361344, 30500100, 30501376, 30502148,
+        30503686, 30505732, 30507776, 30508292, 30510080, 30517764, 30518784, 30556676, 30557440,
+        30670849, 30692608, 30692865, 30711040, 30711297, 30711808, 30712321, 30712576, 30713089,
+        30713600, 30714113, 30715136, 30715393, 30718464, 30718721, 30718976, 30719233, 30721024,
+        30721281, 30737920, 30738177, 30739200, 30739713, 30741760, 30742017, 30743808, 30744065,
+        30751232, 30751489, 30752512, 30752769, 30754048, 30754305, 30754560, 30755329, 30757120,
+        30757377, 30844416, 30844929, 30851328, 30851585, 30857984, 30858241, 30866176, 30866433,
+        30872832, 30873089, 30881024, 30881281, 30887680, 30887937, 30895872, 30896129, 30902528,
+        30902785, 30910720, 30910977, 30917376, 30917633, 30919680, 30920206, 30932992, 31064068,
+        31078144, 31079172, 31091968, 31094020, 31094272, 31097860, 31098112, 31103748, 31105024,
+        31105284, 31109120, 31391745, 31399680, 31401217, 31402752, 31457284, 31459072, 31459332,
+        31463680, 31464196, 31465984, 31466244, 31466752, 31467012, 31468288, 31469569, 31485440,
+        31493892, 31494144, 31522817, 31534336, 31535108, 31536897, 31538688, 31539214, 31541760,
+        31542785, 31543040, 31625217, 31632900, 31633152, 31637505, 31648772, 31649806, 31652352,
+        31772673, 31779844, 31780878, 31783424, 31973377, 31975168, 31975425, 31976448, 31976705,
+        31977216, 31977473, 31981312, 31981569, 32032000, 32034820, 32036608, 32047105, 32064516,
+        32066305, 32066560, 32067598, 32070144, 32374785, 32375808, 32376065, 32382976, 32383233,
+        32383744, 32384001, 32384256, 32384769, 32385024, 32385281, 32387840, 32388097, 32389120,
+        32389377, 32389632, 32389889, 32390144, 32391681, 32391936, 32392961, 32393216, 32393473,
+        32393728, 32393985, 32394240, 32394497, 32395264, 32395521, 32396032, 32396289, 32396544,
+        32397057, 32397312, 32397569, 32397824, 32398081, 32398336, 32398593, 32398848, 32399105,
+        32399360, 32399617, 32400128, 32400385, 32400640, 32401153, 32402176, 32402433, 32404224,
+        32404481, 32405504, 32405761, 32406784, 32407041, 32407296, 32407553, 32410112, 32410369,
+        32414720, 32416001, 32416768, 32417025, 32418304, 32418561, 32422912, 32583681, 32590336,
+        32591873, 32598528, 32600065, 32606720, 32630287, 32636928, 32766724, 32768000, 33288206,
+        33290752, 234881286, 234881536, 234889220, 234913792, 234946564, 235008000,
+    ];
+}
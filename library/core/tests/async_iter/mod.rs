@@ -1,6 +1,7 @@
 use core::async_iter::{self, AsyncIterator, IntoAsyncIterator};
-use core::pin::pin;
-use core::task::Poll;
+use core::future::Future;
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll, Waker};
 
 #[test]
 fn into_async_iter() {
@@ -14,3 +15,140 @@ fn into_async_iter() {
     assert_eq!(async_iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(2)));
     assert_eq!(async_iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
 }
+
+/// Polls `iter` to completion, returning its items and the number of times it
+/// was pending.
+fn poll_all<I: AsyncIterator>(iter: I) -> (Vec<I::Item>, usize) {
+    let mut iter = pin!(iter);
+    let mut cx = Context::from_waker(Waker::noop());
+    let (mut items, mut pending) = (Vec::new(), 0);
+    loop {
+        match iter.as_mut().poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => return (items, pending),
+            Poll::Pending => pending += 1,
+        }
+    }
+}
+
+/// An async iterator over `0..end` that is pending once before every item,
+/// and panics when polled after it has finished.
+struct Slow {
+    next: usize,
+    end: usize,
+    ready: bool,
+}
+
+fn slow(end: usize) -> Slow {
+    Slow { next: 0, end, ready: false }
+}
+
+impl AsyncIterator for Slow {
+    type Item = usize;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<usize>> {
+        assert!(self.next <= self.end, "polled after completion");
+        if !self.ready {
+            self.ready = true;
+            return Poll::Pending;
+        }
+        self.ready = false;
+        self.next += 1;
+        Poll::Ready((self.next <= self.end).then_some(self.next - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end.saturating_sub(self.next);
+        (n, Some(n))
+    }
+}
+
+#[test]
+fn next() {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut iter = async_iter::from_iter([1, 2]);
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(1)));
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(Some(2)));
+    assert_eq!(pin!(iter.next()).poll(&mut cx), Poll::Ready(None));
+
+    let mut iter = slow(1);
+    let mut next = iter.next();
+    assert_eq!(Pin::new(&mut next).poll(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut next).poll(&mut cx), Poll::Ready(Some(0)));
+}
+
+#[test]
+fn map_filter() {
+    let iter = slow(10).map(|x| x * 3).filter(|x| x % 2 == 0);
+    assert_eq!(iter.size_hint(), (0, Some(10)));
+    assert_eq!(poll_all(iter), (vec![0, 6, 12, 18, 24], 11));
+}
+
+#[test]
+fn then() {
+    let iter = async_iter::from_iter(0..4).then(|x| async move {
+        Yield(x % 2 == 0).await;
+        x * 10
+    });
+    assert_eq!(poll_all(iter), (vec![0, 10, 20, 30], 2));
+
+    let offset = 5;
+    let iter = slow(3).then(async |x| x + offset);
+    assert_eq!(poll_all(iter), (vec![5, 6, 7], 4));
+}
+
+#[test]
+fn take_fuse() {
+    // `slow` panics if it is polled after completion.
+    assert_eq!(poll_all(slow(5).take(2)), (vec![0, 1], 2));
+    assert_eq!(poll_all(slow(2).take(5)), (vec![0, 1], 3));
+    assert_eq!(slow(5).take(2).size_hint(), (2, Some(2)));
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut iter = slow(0).fuse();
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Pending);
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(None));
+    assert!(iter.is_done());
+    assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(None));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn chain() {
+    let iter = slow(2).fuse().chain(async_iter::from_iter([7, 8]));
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(poll_all(iter), (vec![0, 1, 7, 8], 3));
+
+    // The first async iterator is not polled again once it has finished.
+    assert_eq!(poll_all(slow(1).chain(slow(1))), (vec![0, 0], 4));
+}
+
+#[test]
+fn zip() {
+    let iter = async_iter::from_iter("abc".chars()).zip(slow(2));
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+    assert_eq!(poll_all(iter), (vec![('a', 0), ('b', 1)], 3));
+
+    // An item of the first async iterator is kept while the second one is pending.
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut iter = pin!(async_iter::from_iter([1, 2]).zip(slow(2)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Pending);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some((1, 0))));
+}
+
+/// A future that is pending once if `self.0` is set.
+struct Yield(bool);
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            self.0 = false;
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
@@ -170,6 +170,47 @@ fn test_format_f32_rounds_ties_to_even() {
     assert_eq!("-1.28E2", format!("{:.2E}", -128.5f32));
 }
 
+#[test]
+#[cfg(not(bootstrap))]
+fn test_format_hex_float() {
+    assert_eq!("0x1p0", format!("{:a}", 1.0f64));
+    assert_eq!("0x1.8p3", format!("{:a}", 12.0f64));
+    assert_eq!("-0x1.999999999999ap-4", format!("{:a}", -0.1f64));
+    assert_eq!("0x1.99999ap-4", format!("{:a}", 0.1f32));
+    assert_eq!("0x1.F4P9", format!("{:A}", 1000.0f64));
+    assert_eq!("0x0p0", format!("{:a}", 0.0f64));
+    assert_eq!("-0x0p0", format!("{:a}", -0.0f64));
+    assert_eq!("0x1.fffffffffffffp1023", format!("{:a}", f64::MAX));
+    assert_eq!("0x1p-1022", format!("{:a}", f64::MIN_POSITIVE));
+    assert_eq!("0x0.0000000000001p-1022", format!("{:a}", f64::from_bits(1)));
+    assert_eq!("0x0.000002p-126", format!("{:a}", f32::from_bits(1)));
+    assert_eq!("inf", format!("{:a}", f64::INFINITY));
+    assert_eq!("-inf", format!("{:a}", f32::NEG_INFINITY));
+    assert_eq!("NaN", format!("{:a}", f64::NAN));
+
+    assert_eq!("0x1.8000p3", format!("{:.4a}", 12.0f64));
+    assert_eq!("0x1.ap-4", format!("{:.1a}", 0.1f64));
+    assert_eq!("0x1p-1022", format!("{:.0a}", f64::from_bits(0x000f_ffff_ffff_ffff)));
+    assert_eq!("+0x1.8p3", format!("{:+a}", 12.0f64));
+    assert_eq!("0x001.8p3", format!("{:09a}", 12.0f64));
+    assert_eq!("-0x01.8p3", format!("{:09a}", -12.0f64));
+    assert_eq!("  0x1.8p3", format!("{:>9a}", 12.0f64));
+    assert_eq!("0x1.8p3  ", format!("{:<9a}", 12.0f64));
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn test_format_hex_float_rounds_ties_to_even() {
+    assert_eq!("0x2p0", format!("{:.0a}", 1.5f64));
+    assert_eq!("0x1p1", format!("{:.0a}", 2.5f64));
+    assert_eq!("0x1.2p0", format!("{:.1a}", 0x128 as f64 / 256.0));
+    assert_eq!("0x1.4p0", format!("{:.1a}", 0x138 as f64 / 256.0));
+    assert_eq!("0x2.0p0", format!("{:.1a}", 0x1f8 as f64 / 256.0));
+    assert_eq!("0x1.3p0", format!("{:.1a}", 0x1280_0001 as f64 / 268435456.0));
+    assert_eq!("-0x1.2p0", format!("{:.1a}", -(0x128 as f64) / 256.0));
+    assert_eq!("0x0p-1022", format!("{:.0a}", f64::MIN_POSITIVE / 2.0));
+}
+
 fn is_exponential(s: &str) -> bool {
     s.contains("e") || s.contains("E")
 }
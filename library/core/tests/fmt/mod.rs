@@ -43,3 +43,28 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 
     assert_eq!(format!("{Bar:<03}"), "1  0051  ");
 }
+
+#[test]
+fn pad_by_display_width() {
+    use core::fmt::DisplayWidth;
+
+    // Wide characters take up two columns, combining marks none.
+    assert_eq!(format!("|{:<6}|", DisplayWidth("日本")), "|日本  |");
+    assert_eq!(format!("|{:^7}|", DisplayWidth("한국")), "| 한국  |");
+    assert_eq!(format!("|{:>3}|", DisplayWidth("a\u{308}")), "|  a\u{308}|");
+    assert_eq!(format!("|{:>3}|", DisplayWidth('中')), "| 中|");
+    assert_eq!(format!("|{:4}|", DisplayWidth("🦀")), "|🦀  |");
+
+    // The precision is measured in columns as well, and a wide character that
+    // does not fit is left out.
+    assert_eq!(format!("|{:.3}|", DisplayWidth("日本語")), "|日|");
+    assert_eq!(format!("|{:4.3}|", DisplayWidth("日本語")), "|日  |");
+    assert_eq!(format!("|{:.1}|", DisplayWidth("e\u{301}x")), "|e\u{301}|");
+
+    // Without the wrapper, `char`s are counted.
+    assert_eq!(format!("|{:<6}|", "日本"), "|日本    |");
+    assert_eq!(format!("|{:.1}|", "e\u{301}x"), "|e|");
+
+    // The flag does not leak into the next argument.
+    assert_eq!(format!("|{:<4}|{:<4}|", DisplayWidth("日"), "日"), "|日  |日   |");
+}
@@ -1,7 +1,8 @@
-use std::future::{join, Future};
-use std::pin::Pin;
+use std::cell::Cell;
+use std::future::{join, pending, poll_fn, race, ready, select, Future};
+use std::pin::{pin, Pin};
 use std::sync::Arc;
-use std::task::{Context, Poll, Wake};
+use std::task::{Context, Poll, Wake, Waker};
 use std::thread;
 
 struct PollN {
@@ -98,6 +99,146 @@ fn join_is_able_to_handle_temporaries() {
     }
 }
 
+/// A future that never completes, and sets its flag when it is dropped.
+struct DropFlag<'a>(&'a Cell<bool>);
+
+impl Future for DropFlag<'_> {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<usize> {
+        Poll::Pending
+    }
+}
+
+impl Drop for DropFlag<'_> {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn test_race() {
+    block_on(async {
+        assert_eq!(race(poll_n(0, 3), poll_n(1, 2)).await, 1);
+        assert_eq!(race(poll_n(0, 2), poll_n(1, 2)).await, 0);
+        assert_eq!(race(pending(), async { 2 }).await, 2);
+
+        let dropped = Cell::new(false);
+        let mut race = pin!(race(DropFlag(&dropped), poll_n(3, 2)));
+        assert_eq!(poll_fn(|cx| race.as_mut().poll(cx)).await, 3);
+        // The loser is dropped before the `Race` itself.
+        assert!(dropped.get());
+    });
+}
+
+#[test]
+#[should_panic = "`Race` polled after completion"]
+fn test_race_polled_after_completion() {
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut race = pin!(race(ready(0), ready(1)));
+    assert_eq!(race.as_mut().poll(&mut cx), Poll::Ready(0));
+    let _ = race.as_mut().poll(&mut cx);
+}
+
+#[test]
+fn test_select() {
+    block_on(async {
+        let x = select! {
+            x = poll_n(0, 3) => x,
+            y = poll_n(1, 2) => y + 10,
+        };
+        assert_eq!(x, 11);
+
+        // Biased towards the first branch.
+        let x = select! {
+            x = async { 0 } => x,
+            _ = async { 1 } => unreachable!(),
+        };
+        assert_eq!(x, 0);
+
+        // Branches with different output types, and futures that are evaluated
+        // before any of them is polled.
+        let s = String::from("a");
+        let x = select! {
+            () = pending() => unreachable!(),
+            n = ready(s.len()) => n.to_string(),
+            s = async { s.clone() } => s,
+        };
+        assert_eq!(x, "1");
+
+        // The handler runs after the other futures have been dropped.
+        let dropped = Cell::new(false);
+        let x = select! {
+            x = DropFlag(&dropped) => x,
+            y = poll_n(4, 2) => {
+                assert!(dropped.get());
+                y
+            }
+        };
+        assert_eq!(x, 4);
+    });
+}
+
+#[test]
+fn test_select_patterns() {
+    block_on(async {
+        let x = select! {
+            Some(x) = async { None::<usize> } => x,
+            Ok(x) | Err(x) = async { Err::<usize, usize>(1) } => x,
+        };
+        assert_eq!(x, 1);
+
+        // A branch is disabled when its pattern does not match, also if it
+        // completes first.
+        let x = select! {
+            Some(x) = ready(None) => x,
+            x = poll_n(2, 3) => x,
+            else => unreachable!(),
+        };
+        assert_eq!(x, 2);
+
+        let x = select! {
+            Some(x) = ready(None::<usize>) => x,
+            1 = poll_n(2, 2) => 1,
+            else => 3,
+        };
+        assert_eq!(x, 3);
+    });
+}
+
+#[test]
+#[should_panic = "all branches of `select!` are disabled and there is no `else` branch"]
+fn test_select_all_disabled() {
+    block_on(async {
+        select! {
+            Some(()) = ready(None) => {}
+        }
+    });
+}
+
+#[test]
+fn test_select_control_flow() {
+    async fn first(values: &[Option<usize>]) -> Option<usize> {
+        for &value in values {
+            select! {
+                x = ready(value) => {
+                    let x = x?;
+                    if x > 1 {
+                        return Some(x);
+                    }
+                },
+                else => break,
+            }
+        }
+        None
+    }
+
+    block_on(async {
+        assert_eq!(first(&[Some(1), Some(2)]).await, Some(2));
+        assert_eq!(first(&[Some(1), None, Some(2)]).await, None);
+    });
+}
+
 fn block_on(fut: impl Future) {
     struct Waker;
     impl Wake for Waker {
@@ -1 +1,2 @@
 mod borrowed_buf;
+mod traits;
@@ -0,0 +1,193 @@
+use core::io::{
+    BorrowedBuf, BufRead, Cursor, Error, ErrorKind, ErrorType, Read, Seek, SeekFrom, Write,
+};
+use core::mem::MaybeUninit;
+
+#[test]
+fn slice_read() {
+    let mut reader: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7];
+    let mut buf = [];
+    assert_eq!(reader.read(&mut buf), Ok(0));
+    let mut buf = [0];
+    assert_eq!(reader.read(&mut buf), Ok(1));
+    assert_eq!(reader.len(), 7);
+    assert_eq!(buf, [0]);
+    let mut buf = [0; 4];
+    assert_eq!(reader.read(&mut buf), Ok(4));
+    assert_eq!(buf, [1, 2, 3, 4]);
+    let mut buf = [0; 4];
+    assert_eq!(reader.read(&mut buf), Ok(3));
+    assert_eq!(&buf[..3], [5, 6, 7]);
+    assert_eq!(reader.read(&mut buf), Ok(0));
+}
+
+#[test]
+fn slice_read_exact() {
+    let mut reader: &[u8] = &[0, 1, 2, 3, 4];
+    let mut buf = [0; 3];
+    assert_eq!(reader.read_exact(&mut buf), Ok(()));
+    assert_eq!(buf, [0, 1, 2]);
+    assert_eq!(reader.read_exact(&mut buf), Err(ErrorKind::UnexpectedEof));
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn slice_read_buf() {
+    let mut reader: &[u8] = &[1, 2, 3, 4, 5];
+    let buf: &mut [_] = &mut [MaybeUninit::uninit(); 3];
+    let mut buf: BorrowedBuf<'_> = buf.into();
+    assert_eq!(reader.read_buf(buf.unfilled()), Ok(()));
+    assert_eq!(buf.filled(), [1, 2, 3]);
+    assert_eq!(reader, [4, 5]);
+}
+
+#[test]
+fn slice_buf_read() {
+    let mut reader: &[u8] = b"hello";
+    assert_eq!(reader.fill_buf(), Ok(&b"hello"[..]));
+    reader.consume(3);
+    assert_eq!(reader.fill_buf(), Ok(&b"lo"[..]));
+    reader.consume(2);
+    assert_eq!(reader.fill_buf(), Ok(&b""[..]));
+}
+
+#[test]
+fn slice_write() {
+    let mut buf = [0; 4];
+    {
+        let mut writer = &mut buf[..];
+        assert_eq!(writer.write(&[1, 2]), Ok(2));
+        assert_eq!(writer.write(&[3, 4, 5]), Ok(2));
+        assert_eq!(writer.write(&[6]), Ok(0));
+        assert_eq!(writer.flush(), Ok(()));
+    }
+    assert_eq!(buf, [1, 2, 3, 4]);
+
+    let mut writer = &mut buf[..];
+    assert_eq!(writer.write_all(&[9; 5]), Err(ErrorKind::WriteZero));
+}
+
+#[test]
+fn slice_write_fmt() {
+    let mut buf = [0; 8];
+    let mut writer = &mut buf[..];
+    assert_eq!(write!(writer, "{}+{}", 12, 34), Ok(()));
+    assert_eq!(writer.len(), 3);
+    assert_eq!(&buf[..5], b"12+34");
+
+    let mut writer = &mut buf[..];
+    assert_eq!(write!(writer, "{}", 123_456_789), Err(ErrorKind::WriteZero));
+}
+
+#[test]
+fn cursor_read_and_seek() {
+    let mut cursor = Cursor::new([0u8, 1, 2, 3, 4, 5, 6, 7]);
+    let mut buf = [0; 3];
+    assert_eq!(cursor.read(&mut buf), Ok(3));
+    assert_eq!(buf, [0, 1, 2]);
+    assert_eq!(cursor.stream_position(), Ok(3));
+
+    assert_eq!(cursor.seek(SeekFrom::End(-2)), Ok(6));
+    assert_eq!(cursor.read(&mut buf), Ok(2));
+    assert_eq!(&buf[..2], [6, 7]);
+
+    assert_eq!(cursor.seek(SeekFrom::Current(-10)), Err(ErrorKind::InvalidInput));
+    assert_eq!(cursor.position(), 8);
+
+    assert_eq!(cursor.rewind(), Ok(()));
+    assert_eq!(cursor.fill_buf(), Ok(&[0, 1, 2, 3, 4, 5, 6, 7][..]));
+    cursor.consume(5);
+    assert_eq!(cursor.read_exact(&mut buf), Ok(()));
+    assert_eq!(buf, [5, 6, 7]);
+    assert_eq!(cursor.read_exact(&mut buf), Err(ErrorKind::UnexpectedEof));
+}
+
+#[test]
+fn cursor_write() {
+    let mut cursor = Cursor::new([0u8; 4]);
+    assert_eq!(cursor.write(&[1, 2, 3]), Ok(3));
+    assert_eq!(cursor.write(&[4, 5, 6]), Ok(1));
+    assert_eq!(cursor.write(&[7]), Ok(0));
+    assert_eq!(cursor.get_ref(), &[1, 2, 3, 4]);
+
+    let mut buf = [0u8; 4];
+    let mut cursor = Cursor::new(&mut buf[..]);
+    cursor.set_position(2);
+    assert_eq!(cursor.write_all(&[8, 9]), Ok(()));
+    assert_eq!(cursor.write_all(&[10]), Err(ErrorKind::WriteZero));
+    assert_eq!(buf, [0, 0, 8, 9]);
+}
+
+#[derive(Debug, PartialEq)]
+enum DeviceError {
+    Io(ErrorKind),
+    Nack,
+}
+
+impl From<ErrorKind> for DeviceError {
+    fn from(kind: ErrorKind) -> Self {
+        DeviceError::Io(kind)
+    }
+}
+
+impl Error for DeviceError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            DeviceError::Io(kind) => *kind,
+            DeviceError::Nack => ErrorKind::Other,
+        }
+    }
+}
+
+/// A device that hands out its bytes one at a time, interrupting every
+/// other call, and fails once it runs out.
+struct Device<'a> {
+    data: &'a [u8],
+    interrupt: bool,
+}
+
+impl ErrorType for Device<'_> {
+    type Error = DeviceError;
+}
+
+impl Read for Device<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, DeviceError> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(ErrorKind::Interrupted.into());
+        }
+        match self.data.split_first() {
+            Some((&first, rest)) if !buf.is_empty() => {
+                buf[0] = first;
+                self.data = rest;
+                Ok(1)
+            }
+            Some(_) => Ok(0),
+            None => Err(DeviceError::Nack),
+        }
+    }
+}
+
+fn read_header<R: Read>(mut reader: R) -> Result<[u8; 2], R::Error> {
+    let mut header = [0; 2];
+    reader.read_exact(&mut header)?;
+    Ok(header)
+}
+
+#[test]
+fn custom_error() {
+    let mut device = Device { data: &[1, 2, 3], interrupt: false };
+    assert_eq!(read_header(&mut device), Ok([1, 2]));
+    assert_eq!(read_header(&mut device), Err(DeviceError::Nack));
+    assert_eq!(DeviceError::Nack.kind(), ErrorKind::Other);
+
+    let mut device = Device { data: &[], interrupt: false };
+    let mut buf = [];
+    assert_eq!(device.read_exact(&mut buf), Ok(()));
+}
+
+#[test]
+fn error_kind() {
+    assert_eq!(Error::kind(&ErrorKind::NotFound), ErrorKind::NotFound);
+    assert_eq!(ErrorKind::UnexpectedEof.to_string(), "unexpected end of file");
+}
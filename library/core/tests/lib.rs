@@ -8,6 +8,8 @@
 #![feature(array_windows)]
 #![feature(ascii_char)]
 #![feature(ascii_char_variants)]
+#![feature(async_closure)]
+#![feature(async_iter_adapters)]
 #![feature(async_iter_from_iter)]
 #![feature(async_iterator)]
 #![feature(bigint_helper_methods)]
@@ -39,6 +41,7 @@
 #![feature(const_three_way_compare)]
 #![feature(const_trait_impl)]
 #![feature(core_intrinsics)]
+#![feature(core_io)]
 #![feature(core_io_borrowed_buf)]
 #![feature(core_private_bignum)]
 #![feature(core_private_diy_float)]
@@ -47,20 +50,26 @@
 #![feature(duration_constants)]
 #![feature(duration_constructors)]
 #![feature(duration_consts_float)]
+#![feature(duration_human)]
 #![feature(error_generic_member_access)]
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
 #![feature(float_minimum_maximum)]
 #![feature(flt2dec)]
+#![feature(fmt_display_width)]
 #![feature(fmt_internals)]
 #![feature(freeze)]
 #![feature(future_join)]
+#![feature(future_race)]
+#![feature(future_select)]
 #![feature(generic_assert_internals)]
 #![feature(get_many_mut)]
 #![feature(hasher_prefixfree_extras)]
 #![feature(hashmap_internals)]
+#![feature(hex_float)]
 #![feature(int_roundings)]
 #![feature(ip)]
+#![feature(ip_net)]
 #![feature(is_ascii_octdigit)]
 #![feature(isqrt)]
 #![feature(iter_advance_by)]
@@ -0,0 +1,230 @@
+use core::net::{IpAddr, IpNet, Ipv4Addr, Ipv4Net, Ipv6Addr, Ipv6Net};
+use core::str::FromStr;
+
+fn v4(s: &str) -> Ipv4Net {
+    s.parse().unwrap()
+}
+
+fn v6(s: &str) -> Ipv6Net {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_ipv4_net() {
+    let net = v4("10.0.0.0/8");
+    assert_eq!(net.addr(), Ipv4Addr::new(10, 0, 0, 0));
+    assert_eq!(net.prefix_len(), 8);
+    assert_eq!(v4("192.168.1.17/24").addr(), Ipv4Addr::new(192, 168, 1, 17));
+    assert_eq!(v4("0.0.0.0/0").prefix_len(), 0);
+    assert_eq!(v4("255.255.255.255/32").prefix_len(), 32);
+
+    assert!(Ipv4Net::from_str("10.0.0.0").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/33").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/08").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/256").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/8/8").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0/+8").is_err());
+    assert!(Ipv4Net::from_str("010.0.0.0/8").is_err());
+    assert!(Ipv4Net::from_str("10.0.0.0:80/8").is_err());
+    assert!(Ipv4Net::from_str("::/0").is_err());
+    assert!(Ipv4Net::from_str("255.255.255.255/320").is_err());
+}
+
+#[test]
+fn parse_ipv6_net() {
+    let net = v6("2001:db8::/32");
+    assert_eq!(net.addr(), Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0));
+    assert_eq!(net.prefix_len(), 32);
+    assert_eq!(v6("::/0").prefix_len(), 0);
+    assert_eq!(v6("::ffff:192.168.0.0/112").prefix_len(), 112);
+    assert_eq!(v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128").prefix_len(), 128);
+
+    assert!(Ipv6Net::from_str("2001:db8::").is_err());
+    assert!(Ipv6Net::from_str("2001:db8::/129").is_err());
+    assert!(Ipv6Net::from_str("2001:db8::/032").is_err());
+    assert!(Ipv6Net::from_str("2001:db8::/1280").is_err());
+    assert!(Ipv6Net::from_str("[2001:db8::]/32").is_err());
+    assert!(Ipv6Net::from_str("10.0.0.0/8").is_err());
+}
+
+#[test]
+fn parse_ip_net() {
+    assert_eq!(IpNet::from_str("10.0.0.0/8"), Ok(IpNet::V4(v4("10.0.0.0/8"))));
+    assert_eq!(IpNet::from_str("fe80::/10"), Ok(IpNet::V6(v6("fe80::/10"))));
+    assert!(IpNet::from_str("10.0.0.0/33").is_err());
+    assert!(IpNet::from_str("fe80::/129").is_err());
+    assert!(IpNet::from_str("10.0.0.0").is_err());
+}
+
+#[test]
+fn ip_net_parse_error() {
+    assert_eq!(
+        Ipv4Net::from_str("10.0.0.0/33").unwrap_err().to_string(),
+        "invalid IPv4 network syntax"
+    );
+    assert_eq!(Ipv6Net::from_str("::/129").unwrap_err().to_string(), "invalid IPv6 network syntax");
+    assert_eq!(IpNet::from_str("").unwrap_err().to_string(), "invalid IP network syntax");
+}
+
+#[test]
+fn ip_net_display() {
+    assert_eq!(v4("192.168.1.17/24").to_string(), "192.168.1.17/24");
+    assert_eq!(v6("2001:0db8:0::0/32").to_string(), "2001:db8::/32");
+    assert_eq!(IpNet::V4(v4("10.0.0.0/8")).to_string(), "10.0.0.0/8");
+    assert_eq!(format!("{:?}", v4("10.0.0.0/8")), "10.0.0.0/8");
+
+    assert_eq!(format!("{:>12}", v4("10.0.0.0/8")), "  10.0.0.0/8");
+    assert_eq!(format!("{:<14}|", v6("::1/128")), "::1/128       |");
+    assert_eq!(format!("{:.4}", v4("10.0.0.0/8")), "10.0");
+    assert_eq!(
+        format!("{:44}|", v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128")),
+        "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128 |"
+    );
+    assert_eq!(format!("{:19}|", v4("255.255.255.255/32")), "255.255.255.255/32 |");
+}
+
+#[test]
+fn ipv4_net_masks() {
+    let net = v4("192.168.1.17/20");
+    assert_eq!(net.netmask(), Ipv4Addr::new(255, 255, 240, 0));
+    assert_eq!(net.hostmask(), Ipv4Addr::new(0, 0, 15, 255));
+    assert_eq!(net.network(), Ipv4Addr::new(192, 168, 0, 0));
+    assert_eq!(net.broadcast(), Ipv4Addr::new(192, 168, 15, 255));
+    assert_eq!(net.trunc(), v4("192.168.0.0/20"));
+
+    assert_eq!(v4("1.2.3.4/0").netmask(), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(v4("1.2.3.4/0").network(), Ipv4Addr::UNSPECIFIED);
+    assert_eq!(v4("1.2.3.4/32").netmask(), Ipv4Addr::BROADCAST);
+    assert_eq!(v4("1.2.3.4/32").broadcast(), Ipv4Addr::new(1, 2, 3, 4));
+}
+
+#[test]
+fn ipv6_net_masks() {
+    let net = v6("2001:db8:1234::1/36");
+    assert_eq!(net.netmask(), Ipv6Addr::new(0xffff, 0xffff, 0xf000, 0, 0, 0, 0, 0));
+    assert_eq!(net.hostmask(), Ipv6Addr::from_bits((1 << 92) - 1));
+    assert_eq!(net.network(), Ipv6Addr::new(0x2001, 0xdb8, 0x1000, 0, 0, 0, 0, 0));
+    assert_eq!(v6("::1/0").netmask(), Ipv6Addr::UNSPECIFIED);
+    assert_eq!(v6("::1/128").netmask().to_bits(), u128::MAX);
+}
+
+#[test]
+fn ip_net_contains() {
+    let net = v4("10.0.0.0/8");
+    assert!(net.contains(Ipv4Addr::new(10, 0, 0, 0)));
+    assert!(net.contains(Ipv4Addr::new(10, 255, 255, 255)));
+    assert!(!net.contains(Ipv4Addr::new(11, 0, 0, 0)));
+    assert!(!net.contains(Ipv4Addr::new(9, 255, 255, 255)));
+    assert!(v4("10.1.2.3/8").contains(Ipv4Addr::new(10, 200, 0, 1)));
+    assert!(v4("0.0.0.0/0").contains(Ipv4Addr::BROADCAST));
+    assert!(v4("1.2.3.4/32").contains(Ipv4Addr::new(1, 2, 3, 4)));
+    assert!(!v4("1.2.3.4/32").contains(Ipv4Addr::new(1, 2, 3, 5)));
+
+    assert!(net.contains_net(v4("10.20.0.0/16")));
+    assert!(net.contains_net(net));
+    assert!(net.contains_net(v4("10.255.255.255/32")));
+    assert!(!net.contains_net(v4("10.0.0.0/7")));
+    assert!(!net.contains_net(v4("11.0.0.0/16")));
+
+    let net = v6("fe80::/10");
+    assert!(net.contains(Ipv6Addr::new(0xfebf, 0, 0, 0, 0, 0, 0, 1)));
+    assert!(!net.contains(Ipv6Addr::new(0xfec0, 0, 0, 0, 0, 0, 0, 0)));
+    assert!(net.contains_net(v6("fe80::/64")));
+    assert!(!net.contains_net(v6("fe80::/9")));
+
+    let net = IpNet::V4(v4("127.0.0.0/8"));
+    assert!(net.contains(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert!(!net.contains(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    assert!(!net.contains_net(IpNet::V6(v6("::/0"))));
+}
+
+#[test]
+fn ip_net_supernet() {
+    assert_eq!(v4("10.1.0.0/16").supernet(), Some(v4("10.0.0.0/15")));
+    assert_eq!(v4("255.255.255.255/32").supernet(), Some(v4("255.255.255.254/31")));
+    assert_eq!(v4("128.0.0.0/1").supernet(), Some(v4("0.0.0.0/0")));
+    assert_eq!(v4("0.0.0.0/0").supernet(), None);
+    assert_eq!(v6("2001:db8::/32").supernet(), Some(v6("2001:db8::/31")));
+    assert_eq!(v6("::/0").supernet(), None);
+
+    let supernets = core::iter::successors(Some(v4("1.2.3.4/32")), Ipv4Net::supernet);
+    assert_eq!(supernets.count(), 33);
+}
+
+#[test]
+fn ipv4_net_hosts() {
+    let hosts = v4("192.168.0.0/30").hosts();
+    assert_eq!(hosts.size_hint(), (2, Some(2)));
+    assert!(hosts.eq([Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(192, 168, 0, 2)]));
+
+    assert!(v4("10.0.0.0/31").hosts().eq([Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 1)]));
+    assert!(v4("10.0.0.7/32").hosts().eq([Ipv4Addr::new(10, 0, 0, 7)]));
+    assert_eq!(v4("10.0.0.0/24").hosts().count(), 254);
+    assert_eq!(v4("10.0.0.0/24").hosts().last(), Some(Ipv4Addr::new(10, 0, 0, 254)));
+
+    let mut hosts = v4("0.0.0.0/0").hosts();
+    assert_eq!(hosts.size_hint(), (u32::MAX as usize - 1, Some(u32::MAX as usize - 1)));
+    assert_eq!(hosts.next(), Some(Ipv4Addr::new(0, 0, 0, 1)));
+    assert_eq!(hosts.next_back(), Some(Ipv4Addr::new(255, 255, 255, 254)));
+
+    let mut hosts = v4("10.0.0.0/30").hosts();
+    assert_eq!(hosts.next_back(), Some(Ipv4Addr::new(10, 0, 0, 2)));
+    assert_eq!(hosts.next(), Some(Ipv4Addr::new(10, 0, 0, 1)));
+    assert_eq!(hosts.next(), None);
+    assert_eq!(hosts.next_back(), None);
+    assert_eq!(hosts.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn ipv6_net_hosts() {
+    assert!(v6("2001:db8::/127").hosts().eq([
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0),
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)
+    ]));
+    assert_eq!(v6("::1/128").hosts().collect::<Vec<_>>(), [Ipv6Addr::LOCALHOST]);
+    assert_eq!(v6("2001:db8::/120").hosts().rev().count(), 256);
+
+    let mut hosts = v6("::/0").hosts();
+    assert_eq!(hosts.size_hint(), (usize::MAX, None));
+    assert_eq!(hosts.next(), Some(Ipv6Addr::UNSPECIFIED));
+    assert_eq!(hosts.next_back(), Some(Ipv6Addr::from_bits(u128::MAX)));
+}
+
+#[test]
+fn ip_net_subnets() {
+    let subnets = v4("10.0.0.0/8").subnets(10).unwrap();
+    assert_eq!(subnets.size_hint(), (4, Some(4)));
+    assert!(subnets.eq([
+        v4("10.0.0.0/10"),
+        v4("10.64.0.0/10"),
+        v4("10.128.0.0/10"),
+        v4("10.192.0.0/10")
+    ]));
+    assert!(v4("10.1.2.3/8").subnets(8).unwrap().eq([v4("10.0.0.0/8")]));
+    assert!(v4("0.0.0.0/0").subnets(0).unwrap().eq([v4("0.0.0.0/0")]));
+    assert!(v4("0.0.0.0/0").subnets(1).unwrap().eq([v4("0.0.0.0/1"), v4("128.0.0.0/1")]));
+    assert_eq!(v4("0.0.0.0/0").subnets(31).unwrap().size_hint(), (1 << 31, Some(1 << 31)));
+    assert_eq!(v4("255.255.255.0/24").subnets(32).unwrap().last(), Some(v4("255.255.255.255/32")));
+    assert!(v4("10.0.0.0/8").subnets(7).is_none());
+    assert!(v4("10.0.0.0/8").subnets(33).is_none());
+
+    let mut subnets = v6("2001:db8::/32").subnets(34).unwrap();
+    assert_eq!(subnets.next_back(), Some(v6("2001:db8:c000::/34")));
+    assert_eq!(subnets.next(), Some(v6("2001:db8::/34")));
+    assert_eq!(subnets.size_hint(), (2, Some(2)));
+    assert!(v6("::/0").subnets(1).unwrap().eq([v6("::/1"), v6("8000::/1")]));
+    assert_eq!(v6("::/0").subnets(128).unwrap().size_hint(), (usize::MAX, None));
+    assert!(v6("::/64").subnets(129).is_none());
+}
+
+#[test]
+fn ip_net_from() {
+    assert_eq!(Ipv4Net::from(Ipv4Addr::LOCALHOST), v4("127.0.0.1/32"));
+    assert_eq!(Ipv6Net::from(Ipv6Addr::LOCALHOST), v6("::1/128"));
+    assert_eq!(IpNet::from(IpAddr::V6(Ipv6Addr::LOCALHOST)), IpNet::V6(v6("::1/128")));
+    assert_eq!(IpNet::from(v4("10.0.0.0/8")), IpNet::V4(v4("10.0.0.0/8")));
+    assert_eq!(Ipv4Net::new(Ipv4Addr::LOCALHOST, 33), None);
+    assert_eq!(Ipv6Net::new(Ipv6Addr::LOCALHOST, 129), None);
+    assert_eq!(IpNet::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8), Some(IpNet::V4(v4("127.0.0.1/8"))));
+}
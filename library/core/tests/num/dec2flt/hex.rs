@@ -0,0 +1,78 @@
+use core::num::dec2flt::pfe_invalid;
+
+#[test]
+fn ordinary() {
+    assert_eq!(f64::from_str_hex("0x1p0"), Ok(1.0));
+    assert_eq!(f64::from_str_hex("0x1.8p3"), Ok(12.0));
+    assert_eq!(f64::from_str_hex("0X1.8P3"), Ok(12.0));
+    assert_eq!(f64::from_str_hex("-0x18"), Ok(-24.0));
+    assert_eq!(f64::from_str_hex("+0x.8"), Ok(0.5));
+    assert_eq!(f64::from_str_hex("0x8.p-4"), Ok(0.5));
+    assert_eq!(f64::from_str_hex("0x1.999999999999ap-4"), Ok(0.1));
+    assert_eq!(f64::from_str_hex("0x0001.000p+0002"), Ok(4.0));
+    assert_eq!(f32::from_str_hex("0x1.99999ap-4"), Ok(0.1));
+    assert_eq!(f64::from_str_hex("0x0p0").map(f64::to_bits), Ok(0));
+    assert_eq!(f64::from_str_hex("-0x0.0p99").map(f64::to_bits), Ok((-0.0f64).to_bits()));
+}
+
+#[test]
+fn special() {
+    assert_eq!(f64::from_str_hex("inf"), Ok(f64::INFINITY));
+    assert_eq!(f64::from_str_hex("-Infinity"), Ok(f64::NEG_INFINITY));
+    assert!(f64::from_str_hex("NaN").unwrap().is_nan());
+    assert!(f32::from_str_hex("-nan").unwrap().is_sign_negative());
+}
+
+#[test]
+fn rounding() {
+    // Ties to even, both in the normal and the subnormal range.
+    assert_eq!(f64::from_str_hex("0x1.00000000000008p0"), Ok(1.0));
+    assert_eq!(f64::from_str_hex("0x1.00000000000018p0"), Ok(1.0 + 2.0 * f64::EPSILON));
+    assert_eq!(f64::from_str_hex("0x1.000000000000080000000000001p0"), Ok(1.0 + f64::EPSILON));
+    assert_eq!(f64::from_str_hex("0x1p-1075"), Ok(0.0));
+    assert_eq!(f64::from_str_hex("0x1.0000000000000000001p-1075"), Ok(f64::from_bits(1)));
+    assert_eq!(f64::from_str_hex("0x1.8p-1074"), Ok(f64::from_bits(2)));
+    assert_eq!(f64::from_str_hex("0x0.fffffffffffff8p-1022"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(f32::from_str_hex("0x1.000001p0"), Ok(1.0));
+    assert_eq!(f32::from_str_hex("0x1.000003p0"), Ok(1.0 + 2.0 * f32::EPSILON));
+
+    // Overflow and underflow, also for exponents that do not fit into any integer.
+    assert_eq!(f64::from_str_hex("0x1.fffffffffffff7ffp1023"), Ok(f64::MAX));
+    assert_eq!(f64::from_str_hex("0x1.fffffffffffff8p1023"), Ok(f64::INFINITY));
+    assert_eq!(f64::from_str_hex("-0x1p99999999999999999999"), Ok(f64::NEG_INFINITY));
+    assert_eq!(f64::from_str_hex("0x1p-99999999999999999999"), Ok(0.0));
+    assert_eq!(f32::from_str_hex("0x1p128"), Ok(f32::INFINITY));
+}
+
+#[test]
+fn invalid() {
+    let invalid = [
+        "", "-", "+", "0x", "-0x", "0x.", "0x.p1", "0x1p", "0x1p+", "0x1p1.5", "0x1.2.3", "0x1g",
+        "1.5", "1p3", "x1p3", " 0x1", "0x1 ", "0x 1", "0x1e+3", "0x-1", "infinite", "nan1",
+    ];
+    for s in invalid {
+        assert!(f64::from_str_hex(s).is_err(), "accepted {s:?}");
+    }
+    assert_eq!(f64::from_str_hex("0x1q"), Err(pfe_invalid()));
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn roundtrip() {
+    for bits in (0..=u32::MAX).step_by(65521) {
+        let x = f32::from_bits(bits);
+        if !x.is_nan() {
+            assert_eq!(f32::from_str_hex(&format!("{x:a}")).map(f32::to_bits), Ok(bits));
+            assert_eq!(f32::from_str_hex(&format!("{x:A}")).map(f32::to_bits), Ok(bits));
+        }
+    }
+
+    let mut bits = 0x0123_4567_89ab_cdefu64;
+    for _ in 0..10_000 {
+        bits = bits.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let x = f64::from_bits(bits);
+        if !x.is_nan() {
+            assert_eq!(f64::from_str_hex(&format!("{x:a}")).map(f64::to_bits), Ok(bits));
+        }
+    }
+}
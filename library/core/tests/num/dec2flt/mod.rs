@@ -1,6 +1,7 @@
 #![allow(overflowing_literals)]
 
 mod float;
+mod hex;
 mod lemire;
 mod parse;
 
@@ -501,3 +501,41 @@ fn double_ended_regression_test() {
         [InRange(10, 13), Rejects(13, 14), InRange(37, 40), Rejects(34, 37), Done]
     );
 }
+
+#[test]
+fn test_multi_str_search() {
+    search_asserts!(
+        "abcabdab",
+        &MultiStrPattern::new(&["abd", "ab", "b"]),
+        "the first needle in the slice wins",
+        [next, next, next, next, next],
+        [Matches(0, 2), Rejects(2, 3), Matches(3, 6), Matches(6, 8), Done]
+    );
+
+    search_asserts!(
+        "aé",
+        &MultiStrPattern::new(&["é", ""]),
+        "empty needle",
+        [next, next, next, next, next],
+        [Matches(0, 0), Rejects(0, 1), Matches(1, 3), Matches(3, 3), Done]
+    );
+
+    search_asserts!(
+        STRESS,
+        &MultiStrPattern::new(&["각a", "ꁁ"]),
+        "next_match for multibyte needles",
+        [next_match, next_match, next_match, next_match],
+        [InRange(10, 13), InRange(28, 32), InRange(37, 40), Done]
+    );
+
+    let needles: Vec<String> = (0..20).map(|i| format!("<{i}>")).collect();
+    let needles: Vec<&str> = needles.iter().map(String::as_str).collect();
+    let pattern = MultiStrPattern::new(&needles);
+    search_asserts!(
+        "<1<19><20><7>",
+        &pattern,
+        "more needles than buckets",
+        [next, next, next, next, next],
+        [Rejects(0, 2), Matches(2, 6), Rejects(6, 10), Matches(10, 13), Done]
+    );
+}
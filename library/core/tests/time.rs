@@ -562,3 +562,111 @@ fn from_neg_zero() {
     assert_eq!(Duration::from_secs_f32(-0.0), Duration::ZERO);
     assert_eq!(Duration::from_secs_f64(-0.0), Duration::ZERO);
 }
+
+#[test]
+fn display_formatting() {
+    assert_eq!(Duration::ZERO.display_human().to_string(), "0s");
+    assert_eq!(Duration::from_secs(5400).display_human().to_string(), "1h30m");
+    assert_eq!(Duration::from_secs(3600).display_human().to_string(), "1h");
+    assert_eq!(Duration::from_secs(86_400 + 61).display_human().to_string(), "1d1m1s");
+    assert_eq!(Duration::from_millis(250).display_human().to_string(), "250ms");
+    assert_eq!(Duration::new(1, 1_001_001).display_human().to_string(), "1s1ms1µs1ns");
+    assert_eq!(Duration::MAX.display_human().to_string(), "213503982334601d7h15s999ms999µs999ns");
+}
+
+#[test]
+fn display_formatting_padding() {
+    assert_eq!(format!("{:<7}", Duration::from_millis(250).display_human()), "250ms  ");
+    assert_eq!(format!("{:>7}", Duration::from_millis(250).display_human()), "  250ms");
+    assert_eq!(format!("{:^7}", Duration::from_millis(250).display_human()), " 250ms ");
+    assert_eq!(format!("{:*<4}", Duration::ZERO.display_human()), "0s**");
+    assert_eq!(format!("{:2}", Duration::from_secs(5400).display_human()), "1h30m");
+    assert_eq!(format!("{:.2}", Duration::from_secs(5400).display_human()), "1h");
+    assert_eq!(
+        format!("{:>60}", Duration::MAX.display_human()).trim_start(),
+        Duration::MAX.display_human().to_string()
+    );
+    let longest = Duration::new(213_503_982_334_600 * 86_400 + 86_399, 999_999_999);
+    assert_eq!(
+        format!("{:>60}", longest.display_human()).trim_start(),
+        longest.display_human().to_string()
+    );
+}
+
+#[test]
+fn parse_human() {
+    assert_eq!(Duration::parse_human("0s"), Ok(Duration::ZERO));
+    assert_eq!(Duration::parse_human("0ns"), Ok(Duration::ZERO));
+    assert_eq!(Duration::parse_human("1h30m"), Ok(Duration::from_secs(5400)));
+    assert_eq!(Duration::parse_human("90m"), Ok(Duration::from_secs(5400)));
+    assert_eq!(Duration::parse_human("250ms"), Ok(Duration::from_millis(250)));
+    assert_eq!(Duration::parse_human("2d"), Ok(Duration::from_secs(172_800)));
+    assert_eq!(Duration::parse_human("1s1ms1µs1ns"), Ok(Duration::new(1, 1_001_001)));
+    assert_eq!(Duration::parse_human("1s1ms1us1ns"), Ok(Duration::new(1, 1_001_001)));
+    assert_eq!(Duration::parse_human("007s"), Ok(Duration::from_secs(7)));
+    assert_eq!(Duration::parse_human("1000000000ns"), Ok(Duration::from_secs(1)));
+    assert_eq!(
+        Duration::parse_human("100000000000000000000ns"),
+        Ok(Duration::from_secs(100_000_000_000))
+    );
+}
+
+#[test]
+fn parse_human_fractions() {
+    assert_eq!(Duration::parse_human("1.5h"), Ok(Duration::from_secs(5400)));
+    assert_eq!(Duration::parse_human("1.5s"), Ok(Duration::from_millis(1500)));
+    assert_eq!(Duration::parse_human(".5s"), Ok(Duration::from_millis(500)));
+    assert_eq!(Duration::parse_human("5.s"), Ok(Duration::from_secs(5)));
+    assert_eq!(Duration::parse_human("0.000000001s"), Ok(Duration::from_nanos(1)));
+    assert_eq!(Duration::parse_human("1h0.5m"), Ok(Duration::from_secs(3630)));
+    // Fractions of a nanosecond are truncated.
+    assert_eq!(Duration::parse_human("1.5ns"), Ok(Duration::from_nanos(1)));
+    assert_eq!(Duration::parse_human("0.9999999999s"), Ok(Duration::new(0, 999_999_999)));
+    assert_eq!(
+        Duration::parse_human("0.33333333333333333333d"),
+        Ok(Duration::new(28_799, 999_999_999))
+    );
+    assert_eq!(Duration::parse_human("0.5000000000000000000000001d"), Ok(Duration::new(43_200, 0)));
+}
+
+#[test]
+fn parse_human_errors() {
+    use core::time::ParseDurationError;
+
+    fn err(s: &str) -> ParseDurationError {
+        Duration::parse_human(s).unwrap_err()
+    }
+
+    let empty = err("");
+    let invalid = err("1x");
+    let overflow = err("213503982334601d7h16s");
+    assert_eq!(empty.to_string(), "cannot parse duration from empty string");
+    assert_eq!(invalid.to_string(), "invalid duration literal");
+    assert_eq!(overflow.to_string(), "duration too large to fit in `Duration`");
+
+    for s in ["0", "10", "s", ".s", "1.2.3s", "1h 30m", " 1h", "1h ", "-1s", "+1s", "1S", "1hour"] {
+        assert_eq!(err(s), invalid, "{s:?}");
+    }
+    // Units must be in decreasing order, and appear at most once.
+    for s in ["30m1h", "1s1s", "1us1µs", "1ms1m"] {
+        assert_eq!(err(s), invalid, "{s:?}");
+    }
+    for s in ["18446744073709551616s", "300000000000000d", "99999999999999999999999999999999999h"] {
+        assert_eq!(err(s), overflow, "{s:?}");
+    }
+}
+
+#[test]
+fn display_parse_human_roundtrip() {
+    for d in [
+        Duration::ZERO,
+        Duration::new(0, 1),
+        Duration::new(59, 999_999_999),
+        Duration::new(3661, 1_000),
+        Duration::new(1_000_000, 500_000_000),
+        Duration::MAX,
+    ] {
+        assert_eq!(Duration::parse_human(&d.display_human().to_string()), Ok(d));
+    }
+    assert_eq!(Duration::parse_human("213503982334601d7h15s999ms999µs999ns"), Ok(Duration::MAX));
+}
@@ -1124,3 +1124,30 @@ fn const_with_hasher() {
     const X: HashMap<(), (), ()> = HashMap::with_hasher(());
     assert_eq!(X.len(), 0);
 }
+
+#[test]
+fn test_new_in() {
+    use crate::alloc::System;
+
+    let mut map = HashMap::new_in(System);
+    assert!(map.is_empty());
+    map.insert(1, "a");
+    map.entry(2).or_insert("b");
+    *map.get_mut(&1).unwrap() = "c";
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&1], "c");
+
+    let copy = map.clone();
+    assert_eq!(copy, map);
+
+    let mut pairs: Vec<_> = map.into_iter().collect();
+    pairs.sort();
+    assert_eq!(pairs, [(1, "c"), (2, "b")]);
+
+    let mut map: HashMap<i32, i32, _, _> = HashMap::with_capacity_in(10, System);
+    assert!(map.capacity() >= 10);
+    map.extend([(1, 1), (2, 4)]);
+    let mut drained: Vec<_> = map.drain().collect();
+    drained.sort();
+    assert_eq!(drained, [(1, 1), (2, 4)]);
+}
@@ -0,0 +1,1528 @@
+#[cfg(test)]
+mod tests;
+
+use super::raw::{Bucket, HashValue, RawIndexMap};
+use crate::borrow::Borrow;
+use crate::cmp::Ordering;
+use crate::collections::TryReserveError;
+use crate::fmt::{self, Debug};
+use crate::hash::{BuildHasher, Hash, RandomState};
+use crate::iter::FusedIterator;
+use crate::ops::{Index, IndexMut, RangeBounds};
+use crate::{mem, slice, vec};
+
+/// A hash map that preserves the insertion order of its entries.
+///
+/// `IndexMap` stores its entries in a contiguous vector, in the order they
+/// were inserted, next to a [`HashMap`]-style hash table that maps each key
+/// to its position. Iteration therefore always yields the entries in a
+/// deterministic order, and every entry can also be addressed by its index
+/// in `0..len()`.
+///
+/// The key requirements are the same as for [`HashMap`]: keys must implement
+/// [`Eq`] and [`Hash`], and it is a logic error for a key to be modified in
+/// such a way that its hash or equality changes while it is in the map.
+///
+/// Lookups, insertions and [`swap_remove`] run in expected constant time.
+/// Removing an entry while keeping the order of the others, with
+/// [`shift_remove`], takes time proportional to the number of entries after
+/// it.
+///
+/// [`HashMap`]: crate::collections::HashMap
+/// [`swap_remove`]: IndexMap::swap_remove
+/// [`shift_remove`]: IndexMap::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexMap;
+///
+/// let mut letters = IndexMap::new();
+/// for ch in "a short treatise on fungi".chars() {
+///     *letters.entry(ch).or_insert(0) += 1;
+/// }
+///
+/// assert_eq!(letters[&'s'], 2);
+/// assert_eq!(letters[&'t'], 3);
+/// assert_eq!(letters[&'u'], 1);
+/// assert_eq!(letters.get(&'y'), None);
+///
+/// // Entries are kept in the order their keys first appeared.
+/// assert_eq!(letters.get_index(0), Some((&'a', &2)));
+/// assert_eq!(letters.keys().take(4).collect::<String>(), "a sh");
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexMap<K, V, S = RandomState> {
+    raw: RawIndexMap<K, V>,
+    hash_builder: S,
+}
+
+impl<K, V> IndexMap<K, V, RandomState> {
+    /// Creates an empty `IndexMap`.
+    ///
+    /// The map is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let mut map: IndexMap<&str, i32> = IndexMap::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexMap<K, V, RandomState> {
+        Default::default()
+    }
+
+    /// Creates an empty `IndexMap` with at least the specified capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    /// let map: IndexMap<&str, i32> = IndexMap::with_capacity(10);
+    /// assert!(map.capacity() >= 10);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexMap<K, V, RandomState> {
+        IndexMap::with_capacity_and_hasher(capacity, Default::default())
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S> {
+    /// Creates an empty `IndexMap` which will use the given hash builder to
+    /// hash keys.
+    ///
+    /// The same caveats as for [`HashMap::with_hasher`] apply.
+    ///
+    /// [`HashMap::with_hasher`]: crate::collections::HashMap::with_hasher
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hash_builder: S) -> IndexMap<K, V, S> {
+        IndexMap { raw: RawIndexMap::new(), hash_builder }
+    }
+
+    /// Creates an empty `IndexMap` with at least the specified capacity, using
+    /// `hasher` to hash the keys.
+    ///
+    /// The same caveats as for [`HashMap::with_hasher`] apply.
+    ///
+    /// [`HashMap::with_hasher`]: crate::collections::HashMap::with_hasher
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> IndexMap<K, V, S> {
+        IndexMap { raw: RawIndexMap::with_capacity(capacity), hash_builder: hasher }
+    }
+
+    /// Returns the number of elements the map can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.raw.capacity()
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut a = IndexMap::new();
+    /// assert_eq!(a.len(), 0);
+    /// a.insert(1, "a");
+    /// assert_eq!(a.len(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Returns `true` if the map contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the map's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Clears the map, removing all key-value pairs. Keeps the allocated
+    /// memory for reuse.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.raw.clear();
+    }
+
+    /// Shortens the map, keeping the first `len` entries and dropping the
+    /// rest.
+    ///
+    /// If `len` is greater than the map's current length, this has no effect.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn truncate(&mut self, len: usize) {
+        self.raw.truncate(len);
+    }
+
+    /// Removes the entries in the specified index range from the map, and
+    /// returns them in order as an iterator.
+    ///
+    /// The entries after the range are shifted down to close the gap. If the
+    /// returned iterator is dropped before being fully consumed, it drops the
+    /// remaining removed entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the
+    /// end point is greater than the length of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+    /// let drained: Vec<_> = map.drain(1..3).collect();
+    /// assert_eq!(drained, [(2, 'b'), (3, 'c')]);
+    /// assert_eq!(map.get_index_of(&4), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, K, V>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain { iter: self.raw.drain(range) }
+    }
+
+    /// An iterator visiting all key-value pairs in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { iter: self.raw.entries().iter() }
+    }
+
+    /// An iterator visiting all key-value pairs in order, with mutable
+    /// references to the values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { iter: self.raw.entries_mut().iter_mut() }
+    }
+
+    /// An iterator visiting all keys in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.raw.entries().iter() }
+    }
+
+    /// Creates a consuming iterator visiting all the keys in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys { iter: self.raw.into_entries().into_iter() }
+    }
+
+    /// An iterator visiting all values in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.raw.entries().iter() }
+    }
+
+    /// An iterator visiting all values mutably in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { iter: self.raw.entries_mut().iter_mut() }
+    }
+
+    /// Creates a consuming iterator visiting all the values in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues { iter: self.raw.into_entries().into_iter() }
+    }
+
+    /// Returns the key-value pair at `index`, if it is in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let map = IndexMap::from([("a", 1), ("b", 2)]);
+    /// assert_eq!(map.get_index(1), Some((&"b", &2)));
+    /// assert_eq!(map.get_index(2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.raw.entries().get(index).map(Bucket::refs)
+    }
+
+    /// Returns the key and a mutable reference to the value at `index`, if it
+    /// is in bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.raw.entries_mut().get_mut(index).map(Bucket::ref_mut)
+    }
+
+    /// Returns the first key-value pair, or `None` if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.raw.entries().first().map(Bucket::refs)
+    }
+
+    /// Returns the last key-value pair, or `None` if the map is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.raw.entries().last().map(Bucket::refs)
+    }
+
+    /// Removes the last key-value pair and returns it, or `None` if the map is
+    /// empty.
+    ///
+    /// This preserves the order of the remaining entries.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.raw.pop()
+    }
+
+    /// Removes the key-value pair at `index` and returns it, or `None` if
+    /// `index` is out of bounds.
+    ///
+    /// The last entry of the map takes the place of the removed one, which
+    /// disturbs the order of the map but takes constant time.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.raw.swap_remove_index(index)
+    }
+
+    /// Removes the key-value pair at `index` and returns it, or `None` if
+    /// `index` is out of bounds.
+    ///
+    /// All entries after it are shifted down, which preserves the order of
+    /// the map but takes time proportional to the number of those entries.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        self.raw.shift_remove_index(index)
+    }
+
+    /// Swaps the positions of the entries at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.raw.swap_indices(a, b);
+    }
+
+    /// Moves the entry at index `from` to index `to`, shifting the entries in
+    /// between to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// map.move_index(0, 2);
+    /// assert_eq!(map.keys().copied().collect::<String>(), "bca");
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        assert!(from < self.len() && to < self.len(), "index out of bounds");
+        self.raw.move_index(from, to);
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the
+    /// order of the retained ones.
+    ///
+    /// In other words, remove all pairs `(k, v)` for which `f(&k, &mut v)`
+    /// returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map: IndexMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// map.retain(|&k, _| k % 2 == 0);
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 2, 4, 6]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.raw.retain(f);
+    }
+
+    /// Sorts the map's entries by key.
+    ///
+    /// The sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.raw.with_entries(|entries| entries.sort_by(|a, b| a.key.cmp(&b.key)));
+    }
+
+    /// Sorts the map's entries with a comparator function on the key-value
+    /// pairs.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([("b", 2), ("c", 1), ("a", 2)]);
+    /// map.sort_by(|_, v1, _, v2| v2.cmp(v1));
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["b", "a", "c"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.raw.with_entries(|entries| {
+            entries.sort_by(|a, b| cmp(&a.key, &a.value, &b.key, &b.value))
+        });
+    }
+
+    /// Sorts the map's entries with a comparator function on the key-value
+    /// pairs, without preserving the order of equal entries.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.raw.with_entries(|entries| {
+            entries.sort_unstable_by(|a, b| cmp(&a.key, &a.value, &b.key, &b.value))
+        });
+    }
+
+    /// Sorts the map's entries with a key extraction function.
+    ///
+    /// The sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by_key<T, F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.raw.with_entries(|entries| entries.sort_by_key(|e| f(&e.key, &e.value)));
+    }
+
+    /// Reverses the order of the map's entries.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.raw.with_entries(|entries| entries.reverse());
+    }
+}
+
+impl<K, V, S> IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn hash<Q: ?Sized + Hash>(&self, key: &Q) -> HashValue {
+        HashValue(self.hash_builder.hash_one(key))
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows [`usize`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.raw.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the map.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.raw.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the map as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.raw.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the map with a lower limit.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.raw.shrink_to(min_capacity);
+    }
+
+    /// Gets the given key's corresponding entry in the map for in-place
+    /// manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut letters = IndexMap::new();
+    ///
+    /// for ch in "a short treatise on fungi".chars() {
+    ///     letters.entry(ch).and_modify(|counter| *counter += 1).or_insert(1);
+    /// }
+    ///
+    /// assert_eq!(letters[&'s'], 2);
+    /// assert_eq!(letters.entry('s').index(), 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let hash = self.hash(&key);
+        match self.raw.get_index_of(hash, &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { raw: &mut self.raw, index }),
+            None => Entry::Vacant(VacantEntry { raw: &mut self.raw, hash, key }),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Hash`] and [`Eq`] on the borrowed form *must* match those for
+    /// the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// map.insert(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_full(k).map(|(_, _, v)| v)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_key_value<Q: ?Sized>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_full(k).map(|(_, k, v)| (k, v))
+    }
+
+    /// Returns the index and the key-value pair corresponding to the supplied
+    /// key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, k: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(k)?;
+        let (k, v) = self.raw.entries()[index].refs();
+        Some((index, k, v))
+    }
+
+    /// Returns the index of the entry for the supplied key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let map = IndexMap::from([("a", 1), ("b", 2)]);
+    /// assert_eq!(map.get_index_of("b"), Some(1));
+    /// assert_eq!(map.get_index_of("c"), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        self.raw.get_index_of(self.hash(k), k)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains_key<Q: ?Sized>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.get_index_of(k).is_some()
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut<Q: ?Sized>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(k)?;
+        Some(&mut self.raw.entries_mut()[index].value)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map did not have this key present, the pair is appended to the
+    /// end of the map and [`None`] is returned.
+    ///
+    /// If the map did have this key present, the value is updated in place,
+    /// keeping the position of the entry, and the old value is returned. The
+    /// key is not updated, though.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::new();
+    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.insert(42, "b"), None);
+    ///
+    /// assert_eq!(map.insert(37, "c"), Some("a"));
+    /// assert_eq!(map.get_index(0), Some((&37, &"c")));
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.insert_full(k, v).1
+    }
+
+    /// Inserts a key-value pair into the map, and returns its index along
+    /// with the old value, if any.
+    ///
+    /// See [`insert`](IndexMap::insert) for details.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, k: K, v: V) -> (usize, Option<V>) {
+        let hash = self.hash(&k);
+        self.raw.insert_full(hash, k, v)
+    }
+
+    /// Removes a key from the map by swapping it with the last entry,
+    /// returning the value at the key if the key was previously in the map.
+    ///
+    /// This disturbs the order of the map but takes constant time. Use
+    /// [`shift_remove`](IndexMap::shift_remove) to preserve the order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert_eq!(map.swap_remove(&'a'), Some(1));
+    /// assert_eq!(map.keys().copied().collect::<String>(), "cb");
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.swap_remove_full(k).map(|(_, _, v)| v)
+    }
+
+    /// Removes a key from the map by swapping it with the last entry,
+    /// returning the stored key and value if the key was previously in the
+    /// map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry<Q: ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.swap_remove_full(k).map(|(_, k, v)| (k, v))
+    }
+
+    /// Removes a key from the map by swapping it with the last entry,
+    /// returning its former index along with the stored key and value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_full<Q: ?Sized>(&mut self, k: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(k)?;
+        let (k, v) = self.raw.swap_remove_index(index)?;
+        Some((index, k, v))
+    }
+
+    /// Removes a key from the map, shifting all following entries down and
+    /// returning the value at the key if the key was previously in the map.
+    ///
+    /// This preserves the order of the map but takes time proportional to the
+    /// number of entries after the removed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexMap;
+    ///
+    /// let mut map = IndexMap::from([('a', 1), ('b', 2), ('c', 3)]);
+    /// assert_eq!(map.shift_remove(&'a'), Some(1));
+    /// assert_eq!(map.keys().copied().collect::<String>(), "bc");
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.shift_remove_full(k).map(|(_, _, v)| v)
+    }
+
+    /// Removes a key from the map, shifting all following entries down and
+    /// returning the stored key and value if the key was previously in the
+    /// map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry<Q: ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.shift_remove_full(k).map(|(_, k, v)| (k, v))
+    }
+
+    /// Removes a key from the map, shifting all following entries down and
+    /// returning its former index along with the stored key and value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_full<Q: ?Sized>(&mut self, k: &Q) -> Option<(usize, K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        let index = self.get_index_of(k)?;
+        let (k, v) = self.raw.shift_remove_index(index)?;
+        Some((index, k, v))
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Clone for IndexMap<K, V, S>
+where
+    K: Clone,
+    V: Clone,
+    S: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { raw: self.raw.clone(), hash_builder: self.hash_builder.clone() }
+    }
+}
+
+/// Two maps are equal if they contain the same key-value pairs, regardless
+/// of their order.
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> PartialEq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexMap<K, V, S>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        self.iter().all(|(key, value)| other.get(key).map_or(false, |v| *value == *v))
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Eq for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Debug for IndexMap<K, V, S>
+where
+    K: Debug,
+    V: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Default for IndexMap<K, V, S>
+where
+    S: Default,
+{
+    /// Creates an empty `IndexMap<K, V, S>`, with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexMap<K, V, S> {
+        IndexMap::with_hasher(Default::default())
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, Q: ?Sized, V, S> Index<&Q> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Returns a reference to the value corresponding to the supplied key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the `IndexMap`.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Index<usize> for IndexMap<K, V, S> {
+    type Output = V;
+
+    /// Returns a reference to the value at the supplied index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &V {
+        &self.raw.entries()[index].value
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> IndexMut<usize> for IndexMap<K, V, S> {
+    /// Returns a mutable reference to the value at the supplied index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut V {
+        &mut self.raw.entries_mut()[index].value
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, const N: usize> From<[(K, V); N]> for IndexMap<K, V, RandomState>
+where
+    K: Eq + Hash,
+{
+    /// Converts a `[(K, V); N]` into an `IndexMap<K, V>`, in array order.
+    ///
+    /// If any entries in the array have equal keys, all but one of the
+    /// corresponding values will be dropped, and the entry keeps the position
+    /// of the first occurrence of the key.
+    fn from(arr: [(K, V); N]) -> Self {
+        IndexMap::from_iter(arr)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> FromIterator<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> IndexMap<K, V, S> {
+        let mut map = IndexMap::with_hasher(Default::default());
+        map.extend(iter);
+        map
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> Extend<(K, V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Inserts all key-value pairs of the iterator, in order.
+    ///
+    /// Existing keys keep their position and have their value updated.
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let reserve = if self.is_empty() { iter.size_hint().0 } else { (iter.size_hint().0 + 1) / 2 };
+        self.reserve(reserve);
+        iter.for_each(move |(k, v)| {
+            self.insert(k, v);
+        });
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> Extend<(&'a K, &'a V)> for IndexMap<K, V, S>
+where
+    K: Eq + Hash + Copy,
+    V: Copy,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: T) {
+        self.extend(iter.into_iter().map(|(&key, &value)| (key, value)));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a IndexMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, K, V, S> IntoIterator for &'a mut IndexMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V, S> IntoIterator for IndexMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Creates a consuming iterator, that is, one that moves each key-value
+    /// pair out of the map in order.
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter { iter: self.raw.into_entries().into_iter() }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or
+/// occupied.
+///
+/// This `enum` is constructed from the [`entry`] method on [`IndexMap`].
+///
+/// [`entry`]: IndexMap::entry
+#[unstable(feature = "index_map", issue = "none")]
+pub enum Entry<'a, K: 'a, V: 'a> {
+    /// An occupied entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// A vacant entry.
+    #[unstable(feature = "index_map", issue = "none")]
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Entry::Vacant(ref v) => f.debug_tuple("Entry").field(v).finish(),
+            Entry::Occupied(ref o) => f.debug_tuple("Entry").field(o).finish(),
+        }
+    }
+}
+
+/// A view into an occupied entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct OccupiedEntry<'a, K: 'a, V: 'a> {
+    raw: &'a mut RawIndexMap<K, V>,
+    index: usize,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for OccupiedEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A view into a vacant entry in an `IndexMap`.
+/// It is part of the [`Entry`] enum.
+#[unstable(feature = "index_map", issue = "none")]
+pub struct VacantEntry<'a, K: 'a, V: 'a> {
+    raw: &'a mut RawIndexMap<K, V>,
+    hash: HashValue,
+    key: K,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for VacantEntry<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default if empty, and
+    /// returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default
+    /// function if empty, and returns a mutable reference to the value in the
+    /// entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting, if empty, the result of
+    /// the default function, which is given a reference to the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Returns a reference to this entry's key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        match *self {
+            Entry::Occupied(ref entry) => entry.key(),
+            Entry::Vacant(ref entry) => entry.key(),
+        }
+    }
+
+    /// Returns the index of this entry, which for a vacant entry is the index
+    /// it would be inserted at.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        match *self {
+            Entry::Occupied(ref entry) => entry.index(),
+            Entry::Vacant(ref entry) => entry.index(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V: Default> Entry<'a, K, V> {
+    /// Ensures a value is in the entry by inserting the default value if
+    /// empty, and returns a mutable reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn or_default(self) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Gets a reference to the key in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.raw.entries()[self.index].key
+    }
+
+    /// Returns the index of the entry in the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets a reference to the value in the entry.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get(&self) -> &V {
+        &self.raw.entries()[self.index].value
+    }
+
+    /// Gets a mutable reference to the value in the entry.
+    ///
+    /// If you need a reference to the `OccupiedEntry` which may outlive the
+    /// destruction of the `Entry` value, see [`into_mut`].
+    ///
+    /// [`into_mut`]: Self::into_mut
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.raw.entries_mut()[self.index].value
+    }
+
+    /// Converts the `OccupiedEntry` into a mutable reference to the value in
+    /// the entry with a lifetime bound to the map itself.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.raw.entries_mut()[self.index].value
+    }
+
+    /// Sets the value of the entry, and returns the entry's old value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map by swapping it with the last entry, and
+    /// returns its value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove(self) -> V {
+        self.swap_remove_entry().1
+    }
+
+    /// Removes the entry from the map by swapping it with the last entry, and
+    /// returns its key and value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_entry(self) -> (K, V) {
+        self.raw.swap_remove_index(self.index).unwrap()
+    }
+
+    /// Removes the entry from the map, shifting all following entries down,
+    /// and returns its value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove(self) -> V {
+        self.shift_remove_entry().1
+    }
+
+    /// Removes the entry from the map, shifting all following entries down,
+    /// and returns its key and value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_entry(self) -> (K, V) {
+        self.raw.shift_remove_index(self.index).unwrap()
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Gets a reference to the key that would be used when inserting a value
+    /// through the `VacantEntry`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Take ownership of the key.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Returns the index the entry will be inserted at, which is the end of
+    /// the map.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn index(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Appends the entry to the map with the `VacantEntry`'s key, and returns
+    /// a mutable reference to its value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.raw.push(self.hash, self.key, value);
+        &mut self.raw.entries_mut()[index].value
+    }
+}
+
+macro_rules! iterator {
+    (@clone true $name:ident<$($lt:lifetime,)? K, V>) => {
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> Clone for $name<$($lt,)? K, V> {
+            #[inline]
+            fn clone(&self) -> Self {
+                $name { iter: self.iter.clone() }
+            }
+        }
+    };
+    (@clone false $name:ident<$($lt:lifetime,)? K, V>) => {};
+    (
+        $(#[$attr:meta])*
+        struct $name:ident<$($lt:lifetime,)? K, V> {
+            iter: $iter:ty,
+        }
+        type Item = $item:ty;
+        map = $map:expr;
+        clone = $clone:tt;
+    ) => {
+        $(#[$attr])*
+        #[unstable(feature = "index_map", issue = "none")]
+        pub struct $name<$($lt,)? K, V> {
+            pub(super) iter: $iter,
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> Iterator for $name<$($lt,)? K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                self.iter.next().map($map)
+            }
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+            #[inline]
+            fn count(self) -> usize {
+                self.iter.len()
+            }
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<$item> {
+                self.iter.nth(n).map($map)
+            }
+            #[inline]
+            fn last(mut self) -> Option<$item> {
+                self.next_back()
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> DoubleEndedIterator for $name<$($lt,)? K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                self.iter.next_back().map($map)
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> ExactSizeIterator for $name<$($lt,)? K, V> {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        #[unstable(feature = "index_map", issue = "none")]
+        impl<$($lt,)? K, V> FusedIterator for $name<$($lt,)? K, V> {}
+
+        iterator!(@clone $clone $name<$($lt,)? K, V>);
+    };
+}
+
+iterator! {
+    /// An iterator over the entries of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`iter`] method on [`IndexMap`].
+    ///
+    /// [`iter`]: IndexMap::iter
+    struct Iter<'a, K, V> {
+        iter: slice::Iter<'a, Bucket<K, V>>,
+    }
+    type Item = (&'a K, &'a V);
+    map = Bucket::refs;
+    clone = true;
+}
+
+iterator! {
+    /// A mutable iterator over the entries of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`iter_mut`] method on [`IndexMap`].
+    ///
+    /// [`iter_mut`]: IndexMap::iter_mut
+    struct IterMut<'a, K, V> {
+        iter: slice::IterMut<'a, Bucket<K, V>>,
+    }
+    type Item = (&'a K, &'a mut V);
+    map = Bucket::ref_mut;
+    clone = false;
+}
+
+iterator! {
+    /// An owning iterator over the entries of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`into_iter`] method on [`IndexMap`]
+    /// (provided by the [`IntoIterator`] trait).
+    ///
+    /// [`into_iter`]: IntoIterator::into_iter
+    struct IntoIter<K, V> {
+        iter: vec::IntoIter<Bucket<K, V>>,
+    }
+    type Item = (K, V);
+    map = Bucket::key_value;
+    clone = false;
+}
+
+iterator! {
+    /// A draining iterator over a range of entries of an `IndexMap`.
+    ///
+    /// This `struct` is created by the [`drain`] method on [`IndexMap`].
+    ///
+    /// [`drain`]: IndexMap::drain
+    struct Drain<'a, K, V> {
+        iter: vec::Drain<'a, Bucket<K, V>>,
+    }
+    type Item = (K, V);
+    map = Bucket::key_value;
+    clone = false;
+}
+
+iterator! {
+    /// An iterator over the keys of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`keys`] method on [`IndexMap`].
+    ///
+    /// [`keys`]: IndexMap::keys
+    struct Keys<'a, K, V> {
+        iter: slice::Iter<'a, Bucket<K, V>>,
+    }
+    type Item = &'a K;
+    map = |bucket: &'a Bucket<K, V>| &bucket.key;
+    clone = true;
+}
+
+iterator! {
+    /// An owning iterator over the keys of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`into_keys`] method on [`IndexMap`].
+    ///
+    /// [`into_keys`]: IndexMap::into_keys
+    struct IntoKeys<K, V> {
+        iter: vec::IntoIter<Bucket<K, V>>,
+    }
+    type Item = K;
+    map = |bucket: Bucket<K, V>| bucket.key;
+    clone = false;
+}
+
+iterator! {
+    /// An iterator over the values of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`values`] method on [`IndexMap`].
+    ///
+    /// [`values`]: IndexMap::values
+    struct Values<'a, K, V> {
+        iter: slice::Iter<'a, Bucket<K, V>>,
+    }
+    type Item = &'a V;
+    map = |bucket: &'a Bucket<K, V>| &bucket.value;
+    clone = true;
+}
+
+iterator! {
+    /// A mutable iterator over the values of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`values_mut`] method on [`IndexMap`].
+    ///
+    /// [`values_mut`]: IndexMap::values_mut
+    struct ValuesMut<'a, K, V> {
+        iter: slice::IterMut<'a, Bucket<K, V>>,
+    }
+    type Item = &'a mut V;
+    map = |bucket: &'a mut Bucket<K, V>| &mut bucket.value;
+    clone = false;
+}
+
+iterator! {
+    /// An owning iterator over the values of an `IndexMap`, in order.
+    ///
+    /// This `struct` is created by the [`into_values`] method on [`IndexMap`].
+    ///
+    /// [`into_values`]: IndexMap::into_values
+    struct IntoValues<K, V> {
+        iter: vec::IntoIter<Bucket<K, V>>,
+    }
+    type Item = V;
+    map = |bucket: Bucket<K, V>| bucket.value;
+    clone = false;
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for IterMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = self.iter.as_slice().iter().map(Bucket::refs);
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for IntoIter<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = self.iter.as_slice().iter().map(Bucket::refs);
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V: Debug> Debug for Drain<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = self.iter.as_slice().iter().map(Bucket::refs);
+        f.debug_list().entries(iter).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for Keys<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K: Debug, V> Debug for IntoKeys<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|b| &b.key)).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for Values<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for ValuesMut<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|b| &b.value)).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<K, V: Debug> Debug for IntoValues<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter.as_slice().iter().map(|b| &b.value)).finish()
+    }
+}
@@ -0,0 +1,200 @@
+use rand::Rng;
+
+use super::Entry::{Occupied, Vacant};
+use super::IndexMap;
+use crate::test_helpers::test_rng;
+
+/// Checks that every key can be found at the index it is stored at.
+fn assert_consistent<K: Eq + crate::hash::Hash, V>(map: &IndexMap<K, V>) {
+    for (i, (k, _)) in map.iter().enumerate() {
+        assert_eq!(map.get_index_of(k), Some(i));
+    }
+}
+
+#[test]
+fn test_insertion_order() {
+    let mut map = IndexMap::new();
+    for i in (0..100).rev() {
+        assert_eq!(map.insert(i, i * 10), None);
+    }
+    assert_eq!(map.insert(50, 0), Some(500));
+    assert_eq!(map.len(), 100);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), (0..100).rev().collect::<Vec<_>>());
+    assert_eq!(map.get_index(49), Some((&50, &0)));
+    assert_eq!(map[49], 0);
+    assert_eq!(map[&51], 510);
+    assert_eq!(map.first(), Some((&99, &990)));
+    assert_eq!(map.last(), Some((&0, &0)));
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut map: IndexMap<i32, i32> = (0..5).map(|i| (i, i)).collect();
+    assert_eq!(map.swap_remove(&1), Some(1));
+    assert_eq!(map.swap_remove(&1), None);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 4, 2, 3]);
+    assert_eq!(map.swap_remove_full(&3), Some((3, 3, 3)));
+    assert_eq!(map.swap_remove_index(0), Some((0, 0)));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 4]);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_shift_remove() {
+    // Large enough to take both the per-entry and the full-table paths.
+    let mut map: IndexMap<i32, i32> = (0..200).map(|i| (i, i)).collect();
+    assert_eq!(map.shift_remove(&1), Some(1));
+    assert_eq!(map.shift_remove(&190), Some(190));
+    assert_eq!(map.shift_remove_full(&0), Some((0, 0, 0)));
+    assert_eq!(map.shift_remove_index(196), Some((199, 199)));
+    assert_eq!(map.pop(), Some((198, 198)));
+    let expected: Vec<i32> = (2..198).filter(|&i| i != 190).collect();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), expected);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_random_operations() {
+    let mut rng = test_rng();
+    let mut map = IndexMap::new();
+    let mut model: Vec<(u32, u32)> = Vec::new();
+
+    for _ in 0..2000 {
+        let key = rng.gen_range(0..64);
+        match rng.gen_range(0..4) {
+            0 | 1 => {
+                let value = rng.gen();
+                let old = map.insert(key, value);
+                match model.iter_mut().find(|(k, _)| *k == key) {
+                    Some(entry) => assert_eq!(old, Some(crate::mem::replace(&mut entry.1, value))),
+                    None => {
+                        assert_eq!(old, None);
+                        model.push((key, value));
+                    }
+                }
+            }
+            2 => {
+                let pos = model.iter().position(|(k, _)| *k == key);
+                assert_eq!(map.swap_remove(&key), pos.map(|i| model.swap_remove(i).1));
+            }
+            _ => {
+                let pos = model.iter().position(|(k, _)| *k == key);
+                assert_eq!(map.shift_remove(&key), pos.map(|i| model.remove(i).1));
+            }
+        }
+        assert!(map.iter().map(|(&k, &v)| (k, v)).eq(model.iter().copied()));
+    }
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_entry() {
+    let mut map: IndexMap<&str, i32> = IndexMap::from([("a", 1), ("b", 2)]);
+
+    match map.entry("b") {
+        Vacant(_) => unreachable!(),
+        Occupied(mut view) => {
+            assert_eq!(view.index(), 1);
+            assert_eq!(view.insert(20), 2);
+            assert_eq!(*view.get(), 20);
+        }
+    }
+
+    match map.entry("c") {
+        Occupied(_) => unreachable!(),
+        Vacant(view) => {
+            assert_eq!(view.index(), 2);
+            *view.insert(3) += 1;
+        }
+    }
+    assert_eq!(map.get_full("c"), Some((2, &"c", &4)));
+
+    *map.entry("a").or_default() += 10;
+    map.entry("d").and_modify(|_| unreachable!()).or_insert_with(|| 5);
+    assert_eq!(map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), [
+        ("a", 11),
+        ("b", 20),
+        ("c", 4),
+        ("d", 5)
+    ]);
+
+    match map.entry("a") {
+        Vacant(_) => unreachable!(),
+        Occupied(view) => assert_eq!(view.shift_remove_entry(), ("a", 11)),
+    }
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["b", "c", "d"]);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_sort() {
+    let mut map: IndexMap<i32, char> = IndexMap::from([(3, 'c'), (1, 'a'), (2, 'b'), (0, 'a')]);
+    map.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 0, 2, 3]);
+    assert_consistent(&map);
+
+    map.sort_keys();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_consistent(&map);
+
+    map.reverse();
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 2, 1, 0]);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_drain_and_truncate() {
+    let mut map: IndexMap<i32, i32> = (0..10).map(|i| (i, i)).collect();
+    assert_eq!(map.drain(2..5).map(|(k, _)| k).collect::<Vec<_>>(), [2, 3, 4]);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 1, 5, 6, 7, 8, 9]);
+    assert_consistent(&map);
+
+    // Dropping the iterator early still removes the whole range.
+    drop(map.drain(..2));
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [5, 6, 7, 8, 9]);
+    assert_consistent(&map);
+
+    map.truncate(2);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [5, 6]);
+    assert!(!map.contains_key(&7));
+    assert_consistent(&map);
+
+    assert_eq!(map.drain(..).count(), 2);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_move_and_swap_indices() {
+    let mut map: IndexMap<i32, ()> = (0..6).map(|i| (i, ())).collect();
+    map.move_index(1, 4);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 2, 3, 4, 1, 5]);
+    assert_consistent(&map);
+    map.move_index(5, 0);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [5, 0, 2, 3, 4, 1]);
+    assert_consistent(&map);
+    map.swap_indices(0, 5);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 0, 2, 3, 4, 5]);
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_retain() {
+    let mut map: IndexMap<i32, i32> = (0..100).map(|x| (x, x * 10)).collect();
+    map.retain(|&k, v| {
+        *v += 1;
+        k % 3 == 0
+    });
+    assert_eq!(map.len(), 34);
+    assert_eq!(map.get_index(1), Some((&3, &31)));
+    assert_consistent(&map);
+}
+
+#[test]
+fn test_eq_ignores_order() {
+    let a = IndexMap::from([(1, 'a'), (2, 'b')]);
+    let b = IndexMap::from([(2, 'b'), (1, 'a')]);
+    assert_eq!(a, b);
+    assert_ne!(a, IndexMap::from([(1, 'a'), (2, 'c')]));
+    assert_eq!(format!("{a:?}"), "{1: 'a', 2: 'b'}");
+}
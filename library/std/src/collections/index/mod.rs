@@ -0,0 +1,6 @@
+//! Insertion-ordered containers, implemented as a vector of entries indexed
+//! by a hash table
+
+pub mod map;
+mod raw;
+pub mod set;
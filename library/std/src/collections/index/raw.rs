@@ -0,0 +1,291 @@
+//! The storage shared by `IndexMap` and `IndexSet`.
+//!
+//! Entries live in a `Vec` in insertion order, together with the hash of
+//! their key. The hash table only stores indices into that vector, so it can
+//! be rebuilt or fixed up from the cached hashes without rehashing any key.
+
+use hashbrown::HashTable;
+
+use crate::borrow::Borrow;
+use crate::collections::TryReserveError;
+use crate::collections::hash::map::map_try_reserve_error;
+use crate::ops::{Range, RangeBounds};
+use crate::{mem, slice, vec};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) struct HashValue(pub(super) u64);
+
+#[derive(Clone)]
+pub(super) struct Bucket<K, V> {
+    pub(super) hash: HashValue,
+    pub(super) key: K,
+    pub(super) value: V,
+}
+
+impl<K, V> Bucket<K, V> {
+    pub(super) fn refs(&self) -> (&K, &V) {
+        (&self.key, &self.value)
+    }
+
+    pub(super) fn ref_mut(&mut self) -> (&K, &mut V) {
+        (&self.key, &mut self.value)
+    }
+
+    pub(super) fn key_value(self) -> (K, V) {
+        (self.key, self.value)
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct RawIndexMap<K, V> {
+    indices: HashTable<usize>,
+    entries: Vec<Bucket<K, V>>,
+}
+
+fn get_hash<K, V>(entries: &[Bucket<K, V>]) -> impl Fn(&usize) -> u64 + '_ {
+    move |&i| entries[i].hash.0
+}
+
+fn erase_index(indices: &mut HashTable<usize>, hash: HashValue, index: usize) {
+    match indices.find_entry(hash.0, move |&i| i == index) {
+        Ok(entry) => drop(entry.remove()),
+        Err(_) => unreachable!("index not found"),
+    }
+}
+
+fn equivalent<'a, K, V, Q>(key: &'a Q, entries: &'a [Bucket<K, V>]) -> impl Fn(&usize) -> bool + 'a
+where
+    K: Borrow<Q>,
+    Q: ?Sized + Eq,
+{
+    move |&i| entries[i].key.borrow() == key
+}
+
+impl<K, V> RawIndexMap<K, V> {
+    pub(super) fn new() -> Self {
+        RawIndexMap { indices: HashTable::new(), entries: Vec::new() }
+    }
+
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        RawIndexMap {
+            indices: HashTable::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(super) fn capacity(&self) -> usize {
+        Ord::min(self.indices.capacity(), self.entries.capacity())
+    }
+
+    pub(super) fn entries(&self) -> &[Bucket<K, V>] {
+        &self.entries
+    }
+
+    pub(super) fn entries_mut(&mut self) -> &mut [Bucket<K, V>] {
+        &mut self.entries
+    }
+
+    pub(super) fn into_entries(self) -> Vec<Bucket<K, V>> {
+        self.entries
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.indices.clear();
+        self.entries.clear();
+    }
+
+    pub(super) fn reserve(&mut self, additional: usize) {
+        self.indices.reserve(additional, get_hash(&self.entries));
+        self.entries.reserve_exact(self.indices.capacity().saturating_sub(self.entries.len()));
+    }
+
+    pub(super) fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.indices
+            .try_reserve(additional, get_hash(&self.entries))
+            .map_err(map_try_reserve_error)?;
+        self.entries.try_reserve_exact(self.indices.capacity().saturating_sub(self.entries.len()))
+    }
+
+    pub(super) fn shrink_to(&mut self, min_capacity: usize) {
+        self.indices.shrink_to(min_capacity, get_hash(&self.entries));
+        self.entries.shrink_to(min_capacity);
+    }
+
+    pub(super) fn get_index_of<Q>(&self, hash: HashValue, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        self.indices.find(hash.0, equivalent(key, &self.entries)).copied()
+    }
+
+    /// Appends a new entry, which must not be present in the map yet, and
+    /// returns its index.
+    pub(super) fn push(&mut self, hash: HashValue, key: K, value: V) -> usize {
+        let index = self.entries.len();
+        self.indices.insert_unique(hash.0, index, get_hash(&self.entries));
+        if self.entries.len() == self.entries.capacity() {
+            // Keep the two allocations in step, rather than letting the
+            // vector double on its own.
+            self.entries.reserve_exact(self.indices.capacity().saturating_sub(self.entries.len()));
+        }
+        self.entries.push(Bucket { hash, key, value });
+        index
+    }
+
+    pub(super) fn insert_full(&mut self, hash: HashValue, key: K, value: V) -> (usize, Option<V>)
+    where
+        K: Eq,
+    {
+        match self.get_index_of(hash, &key) {
+            Some(i) => (i, Some(mem::replace(&mut self.entries[i].value, value))),
+            None => (self.push(hash, key, value), None),
+        }
+    }
+
+    pub(super) fn pop(&mut self) -> Option<(K, V)> {
+        let entry = self.entries.pop()?;
+        let last = self.entries.len();
+        erase_index(&mut self.indices, entry.hash, last);
+        Some(entry.key_value())
+    }
+
+    /// Removes the entry at `index` by swapping it with the last entry.
+    pub(super) fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        erase_index(&mut self.indices, hash, index);
+        let entry = self.entries.swap_remove(index);
+        // The last entry, if any, now lives at `index`.
+        if let Some(moved) = self.entries.get(index) {
+            let last = self.entries.len();
+            *self.indices.find_mut(moved.hash.0, move |&i| i == last).unwrap() = index;
+        }
+        Some(entry.key_value())
+    }
+
+    /// Removes the entry at `index`, shifting all following entries down.
+    pub(super) fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        erase_index(&mut self.indices, hash, index);
+        self.shift_indices_down(index + 1, 1);
+        Some(self.entries.remove(index).key_value())
+    }
+
+    /// Removes the indices of the entries in `range` from the hash table,
+    /// leaving the entries themselves to the caller.
+    fn erase_range(&mut self, range: Range<usize>) {
+        if range.start == 0 && range.end == self.entries.len() {
+            self.indices.clear();
+        } else if range.len() < self.indices.len() / 2 {
+            for (i, entry) in (range.start..).zip(&self.entries[range.clone()]) {
+                erase_index(&mut self.indices, entry.hash, i);
+            }
+        } else {
+            self.indices.retain(|&mut i| !range.contains(&i));
+        }
+    }
+
+    /// Subtracts `amount` from the indices of all entries from `start` on,
+    /// ahead of the entries being moved down by that much. The indices of the
+    /// `amount` entries before `start` must already have been erased.
+    fn shift_indices_down(&mut self, start: usize, amount: usize) {
+        let shifted = &self.entries[start..];
+        if shifted.len() > self.indices.len() / 2 {
+            for i in self.indices.iter_mut() {
+                if *i >= start {
+                    *i -= amount;
+                }
+            }
+        } else {
+            for (i, entry) in (start..).zip(shifted) {
+                *self.indices.find_mut(entry.hash.0, move |&j| j == i).unwrap() = i - amount;
+            }
+        }
+    }
+
+    pub(super) fn drain<R>(&mut self, range: R) -> vec::Drain<'_, Bucket<K, V>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = slice::range(range, ..self.entries.len());
+        if !range.is_empty() {
+            self.erase_range(range.clone());
+            // The remaining entries are moved down once the drain is dropped.
+            self.shift_indices_down(range.end, range.len());
+        }
+        self.entries.drain(range)
+    }
+
+    pub(super) fn truncate(&mut self, len: usize) {
+        if len < self.entries.len() {
+            self.erase_range(len..self.entries.len());
+            self.entries.truncate(len);
+        }
+    }
+
+    pub(super) fn swap_indices(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let (hash_a, hash_b) = (self.entries[a].hash, self.entries[b].hash);
+        match self.indices.get_many_mut([hash_a.0, hash_b.0], move |n, &i| i == [a, b][n]) {
+            Some([ref_a, ref_b]) => mem::swap(ref_a, ref_b),
+            None => unreachable!("index not found"),
+        }
+        self.entries.swap(a, b);
+    }
+
+    pub(super) fn move_index(&mut self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        let hash = self.entries[from].hash;
+        // Park the moved entry at an index that is not in use, shift the
+        // entries in between by one, and then put it in its final place.
+        let parked = usize::MAX;
+        *self.indices.find_mut(hash.0, move |&i| i == from).unwrap() = parked;
+        if from < to {
+            for (i, entry) in (from + 1..=to).zip(&self.entries[from + 1..=to]) {
+                *self.indices.find_mut(entry.hash.0, move |&j| j == i).unwrap() = i - 1;
+            }
+            self.entries[from..=to].rotate_left(1);
+        } else {
+            for (i, entry) in (to..from).zip(&self.entries[to..from]).rev() {
+                *self.indices.find_mut(entry.hash.0, move |&j| j == i).unwrap() = i + 1;
+            }
+            self.entries[to..=from].rotate_right(1);
+        }
+        *self.indices.find_mut(hash.0, move |&i| i == parked).unwrap() = to;
+    }
+
+    pub(super) fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|entry| keep(&entry.key, &mut entry.value));
+        if self.entries.len() < self.indices.len() {
+            self.rebuild_hash_table();
+        }
+    }
+
+    /// Reorders the entries with `f`, which must keep the set of entries the
+    /// same, and rebuilds the hash table to match.
+    pub(super) fn with_entries<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut [Bucket<K, V>]),
+    {
+        f(&mut self.entries);
+        self.rebuild_hash_table();
+    }
+
+    fn rebuild_hash_table(&mut self) {
+        self.indices.clear();
+        for (i, entry) in self.entries.iter().enumerate() {
+            self.indices.insert_unique(entry.hash.0, i, get_hash(&self.entries));
+        }
+    }
+}
@@ -0,0 +1,762 @@
+#[cfg(test)]
+mod tests;
+
+use super::map::{self, IndexMap};
+use crate::borrow::Borrow;
+use crate::cmp::Ordering;
+use crate::collections::TryReserveError;
+use crate::fmt;
+use crate::hash::{BuildHasher, Hash, RandomState};
+use crate::iter::FusedIterator;
+use crate::ops::{Index, RangeBounds};
+
+/// A hash set that preserves the insertion order of its elements.
+///
+/// `IndexSet` is implemented as an [`IndexMap`] where the value is `()`, and
+/// shares its characteristics: iteration follows insertion order, every
+/// element has an index in `0..len()`, and elements can be removed either in
+/// constant time with [`swap_remove`] or order-preserving with
+/// [`shift_remove`].
+///
+/// [`swap_remove`]: IndexSet::swap_remove
+/// [`shift_remove`]: IndexSet::shift_remove
+///
+/// # Examples
+///
+/// ```
+/// #![feature(index_map)]
+/// use std::collections::IndexSet;
+///
+/// let mut seen = IndexSet::new();
+/// for word in "the quick fox jumps over the lazy fox".split(' ') {
+///     seen.insert(word);
+/// }
+///
+/// assert_eq!(seen.len(), 6);
+/// assert_eq!(seen.get_index_of("fox"), Some(2));
+/// assert_eq!(seen.iter().copied().collect::<Vec<_>>(), [
+///     "the", "quick", "fox", "jumps", "over", "lazy",
+/// ]);
+/// ```
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IndexSet<T, S = RandomState> {
+    map: IndexMap<T, (), S>,
+}
+
+impl<T> IndexSet<T, RandomState> {
+    /// Creates an empty `IndexSet`.
+    ///
+    /// The set is initially created with a capacity of 0, so it will not
+    /// allocate until it is first inserted into.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn new() -> IndexSet<T, RandomState> {
+        Default::default()
+    }
+
+    /// Creates an empty `IndexSet` with at least the specified capacity.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity(capacity: usize) -> IndexSet<T, RandomState> {
+        IndexSet { map: IndexMap::with_capacity(capacity) }
+    }
+}
+
+impl<T, S> IndexSet<T, S> {
+    /// Creates an empty `IndexSet` which will use the given hash builder to
+    /// hash elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_hasher(hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_hasher(hasher) }
+    }
+
+    /// Creates an empty `IndexSet` with at least the specified capacity, using
+    /// `hasher` to hash the elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::with_capacity_and_hasher(capacity, hasher) }
+    }
+
+    /// Returns the number of elements the set can hold without reallocating.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of elements in the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns a reference to the set's [`BuildHasher`].
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Clears the set, removing all values.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Shortens the set, keeping the first `len` elements and dropping the
+    /// rest.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn truncate(&mut self, len: usize) {
+        self.map.truncate(len);
+    }
+
+    /// Removes the elements in the specified index range from the set, and
+    /// returns them in order as an iterator.
+    ///
+    /// See [`IndexMap::drain`] for details.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        Drain { iter: self.map.drain(range) }
+    }
+
+    /// An iterator visiting all elements in order.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { iter: self.map.keys() }
+    }
+
+    /// Returns the element at `index`, if it is in bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index(&self, index: usize) -> Option<&T> {
+        self.map.get_index(index).map(|(k, _)| k)
+    }
+
+    /// Returns the first element, or `None` if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first().map(|(k, _)| k)
+    }
+
+    /// Returns the last element, or `None` if the set is empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last().map(|(k, _)| k)
+    }
+
+    /// Removes the last element and returns it, or `None` if the set is
+    /// empty.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn pop(&mut self) -> Option<T> {
+        self.map.pop().map(|(k, ())| k)
+    }
+
+    /// Removes the element at `index` by swapping it with the last element,
+    /// and returns it.
+    ///
+    /// See [`IndexMap::swap_remove_index`] for details.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.swap_remove_index(index).map(|(k, ())| k)
+    }
+
+    /// Removes the element at `index`, shifting all following elements down,
+    /// and returns it.
+    ///
+    /// See [`IndexMap::shift_remove_index`] for details.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<T> {
+        self.map.shift_remove_index(index).map(|(k, ())| k)
+    }
+
+    /// Swaps the positions of the elements at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_indices(&mut self, a: usize, b: usize) {
+        self.map.swap_indices(a, b);
+    }
+
+    /// Moves the element at index `from` to index `to`, shifting the elements
+    /// in between to make room.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` are out of bounds.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn move_index(&mut self, from: usize, to: usize) {
+        self.map.move_index(from, to);
+    }
+
+    /// Retains only the elements specified by the predicate, preserving the
+    /// order of the retained ones.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|k, _| f(k));
+    }
+
+    /// Sorts the set's elements.
+    ///
+    /// The sort is stable.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.map.sort_keys();
+    }
+
+    /// Sorts the set's elements with a comparator function.
+    ///
+    /// The sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(index_map)]
+    /// use std::collections::IndexSet;
+    ///
+    /// let mut set = IndexSet::from(["ccc", "a", "bb"]);
+    /// set.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// assert_eq!(set.get_index_of("bb"), Some(1));
+    /// ```
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_by(|a, _, b, _| cmp(a, b));
+    }
+
+    /// Sorts the set's elements with a comparator function, without
+    /// preserving the order of equal elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn sort_unstable_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.map.sort_unstable_by(|a, _, b, _| cmp(a, b));
+    }
+
+    /// Reverses the order of the set's elements.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reverse(&mut self) {
+        self.map.reverse();
+    }
+}
+
+impl<T, S> IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Reserves capacity for at least `additional` more elements to be
+    /// inserted in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new allocation size overflows `usize`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the set.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows, or the allocator reports a failure, then an
+    /// error is returned.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the set as much as possible.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit();
+    }
+
+    /// Adds a value to the set.
+    ///
+    /// Returns whether the value was newly inserted. A new value is appended
+    /// to the end of the set; an existing one keeps its position.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_full(value).1
+    }
+
+    /// Adds a value to the set, and returns its index along with whether it
+    /// was newly inserted.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn insert_full(&mut self, value: T) -> (usize, bool) {
+        match self.map.entry(value) {
+            map::Entry::Occupied(entry) => (entry.index(), false),
+            map::Entry::Vacant(entry) => {
+                let index = entry.index();
+                entry.insert(());
+                (index, true)
+            }
+        }
+    }
+
+    /// Returns `true` if the set contains a value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to
+    /// the given value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_key_value(value).map(|(k, _)| k)
+    }
+
+    /// Returns the index of and a reference to the value in the set, if any,
+    /// that is equal to the given value.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_full<Q: ?Sized>(&self, value: &Q) -> Option<(usize, &T)>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_full(value).map(|(i, k, _)| (i, k))
+    }
+
+    /// Returns the index of the value in the set, if any.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn get_index_of<Q: ?Sized>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.get_index_of(value)
+    }
+
+    /// Removes a value from the set by swapping it with the last element.
+    /// Returns whether the value was present in the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove(value).is_some()
+    }
+
+    /// Removes a value from the set, shifting all following elements down.
+    /// Returns whether the value was present in the set.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_remove<Q: ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the
+    /// given one, by swapping it with the last element.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn swap_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.swap_remove_entry(value).map(|(k, ())| k)
+    }
+
+    /// Removes and returns the value in the set, if any, that is equal to the
+    /// given one, shifting all following elements down.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn shift_take<Q: ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq,
+    {
+        self.map.shift_remove_entry(value).map(|(k, ())| k)
+    }
+
+    /// Returns `true` if `self` has no elements in common with `other`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_disjoint(&self, other: &IndexSet<T, S>) -> bool {
+        if self.len() <= other.len() {
+            self.iter().all(|v| !other.contains(v))
+        } else {
+            other.iter().all(|v| !self.contains(v))
+        }
+    }
+
+    /// Returns `true` if the set is a subset of another, i.e., `other`
+    /// contains at least all the values in `self`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_subset(&self, other: &IndexSet<T, S>) -> bool {
+        self.len() <= other.len() && self.iter().all(|v| other.contains(v))
+    }
+
+    /// Returns `true` if the set is a superset of another, i.e., `self`
+    /// contains at least all the values in `other`.
+    #[inline]
+    #[unstable(feature = "index_map", issue = "none")]
+    pub fn is_superset(&self, other: &IndexSet<T, S>) -> bool {
+        other.is_subset(self)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: Clone, S: Clone> Clone for IndexSet<T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map.clone() }
+    }
+}
+
+/// Two sets are equal if they contain the same values, regardless of their
+/// order.
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> PartialEq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &IndexSet<T, S>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Eq for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug, S> fmt::Debug for IndexSet<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S: Default> Default for IndexSet<T, S> {
+    /// Creates an empty `IndexSet<T, S>` with the `Default` value for the hasher.
+    #[inline]
+    fn default() -> IndexSet<T, S> {
+        IndexSet { map: IndexMap::default() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Index<usize> for IndexSet<T, S> {
+    type Output = T;
+
+    /// Returns a reference to the element at the supplied index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get_index(index).expect("index out of bounds")
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, const N: usize> From<[T; N]> for IndexSet<T, RandomState>
+where
+    T: Eq + Hash,
+{
+    /// Converts a `[T; N]` into an `IndexSet<T>`, in array order.
+    ///
+    /// If the array contains any equal values, all but the first will be
+    /// dropped.
+    fn from(arr: [T; N]) -> Self {
+        IndexSet::from_iter(arr)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> FromIterator<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> IndexSet<T, S> {
+        let mut set = IndexSet::with_hasher(Default::default());
+        set.extend(iter);
+        set
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> Extend<T> for IndexSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|k| (k, ())));
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> Extend<&'a T> for IndexSet<T, S>
+where
+    T: 'a + Eq + Hash + Copy,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T, S> IntoIterator for &'a IndexSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T, S> IntoIterator for IndexSet<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out
+    /// of the set in order.
+    #[inline]
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { iter: self.map.into_keys() }
+    }
+}
+
+/// An iterator over the elements of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`iter`] method on [`IndexSet`].
+///
+/// [`iter`]: IndexSet::iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Iter<'a, T: 'a> {
+    iter: map::Keys<'a, T, ()>,
+}
+
+/// An owning iterator over the elements of an `IndexSet`, in order.
+///
+/// This `struct` is created by the [`into_iter`] method on [`IndexSet`]
+/// (provided by the [`IntoIterator`] trait).
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[unstable(feature = "index_map", issue = "none")]
+pub struct IntoIter<T> {
+    iter: map::IntoKeys<T, ()>,
+}
+
+/// A draining iterator over a range of elements of an `IndexSet`.
+///
+/// This `struct` is created by the [`drain`] method on [`IndexSet`].
+///
+/// [`drain`]: IndexSet::drain
+#[unstable(feature = "index_map", issue = "none")]
+pub struct Drain<'a, T: 'a> {
+    iter: map::Drain<'a, T, ()>,
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Iter { iter: self.iter.clone() }
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Iter<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for IntoIter<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for IntoIter<T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.iter, f)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|(k, ())| k)
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|(k, ())| k)
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> ExactSizeIterator for Drain<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T> FusedIterator for Drain<'_, T> {}
+
+#[unstable(feature = "index_map", issue = "none")]
+impl<T: fmt::Debug> fmt::Debug for Drain<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let iter = self.iter.iter.as_slice().iter().map(|bucket| &bucket.key);
+        f.debug_list().entries(iter).finish()
+    }
+}
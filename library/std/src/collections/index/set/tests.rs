@@ -0,0 +1,45 @@
+use super::IndexSet;
+
+#[test]
+fn test_insert_full() {
+    let mut set = IndexSet::new();
+    assert_eq!(set.insert_full('b'), (0, true));
+    assert_eq!(set.insert_full('a'), (1, true));
+    assert_eq!(set.insert_full('b'), (0, false));
+    assert_eq!(set.iter().collect::<String>(), "ba");
+    assert_eq!(set[1], 'a');
+}
+
+#[test]
+fn test_remove() {
+    let mut set: IndexSet<i32> = (0..6).collect();
+    assert!(set.swap_remove(&0));
+    assert!(!set.swap_remove(&0));
+    assert_eq!(set.shift_take(&2), Some(2));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [5, 1, 3, 4]);
+    assert_eq!(set.get_index_of(&4), Some(3));
+    assert_eq!(set.pop(), Some(4));
+}
+
+#[test]
+fn test_sort_and_set_relations() {
+    let mut a = IndexSet::from([3, 1, 2]);
+    let b = IndexSet::from([1, 2, 3, 4]);
+    assert!(a.is_subset(&b));
+    assert!(b.is_superset(&a));
+    assert!(!a.is_disjoint(&b));
+    assert_eq!(a, IndexSet::from([1, 2, 3]));
+
+    a.sort();
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    a.sort_by(|x, y| y.cmp(x));
+    assert_eq!(a.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+    assert_eq!(a.get_index_of(&1), Some(2));
+}
+
+#[test]
+fn test_drain() {
+    let mut set: IndexSet<i32> = (0..5).collect();
+    assert_eq!(set.drain(1..3).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), [0, 3, 4]);
+}
@@ -967,3 +967,21 @@ fn s_nan() -> f128 {
     assert_eq!(Ordering::Less, (-s_nan()).total_cmp(&f128::INFINITY));
     assert_eq!(Ordering::Less, (-s_nan()).total_cmp(&s_nan()));
 }
+
+#[test]
+#[cfg(not(bootstrap))]
+fn test_hex_float() {
+    let tiny = f128::from_bits(1);
+    assert_eq!(format!("{:a}", 1.5f128), "0x1.8p0");
+    assert_eq!(format!("{:a}", 0.1f128), "0x1.999999999999999999999999999ap-4");
+    assert_eq!(format!("{:A}", f128::MAX), "0x1.FFFFFFFFFFFFFFFFFFFFFFFFFFFFP16383");
+    assert_eq!(format!("{:a}", tiny), "0x0.0000000000000000000000000001p-16382");
+    assert_eq!(format!("{:.3a}", 0.1f128), "0x1.99ap-4");
+
+    for x in [0.0, -0.0, 0.1, 1.0 / 3.0, -12.5, f128::MAX, f128::MIN_POSITIVE, tiny, tiny * 3.0] {
+        assert_eq!(f128::from_str_hex(&format!("{x:a}")).unwrap().to_bits(), x.to_bits());
+    }
+    assert_eq!(f128::from_str_hex("0x1p-16494"), Ok(tiny));
+    assert_eq!(f128::from_str_hex("0x1p-16495"), Ok(0.0));
+    assert_eq!(f128::from_str_hex("-0x1p16384"), Ok(f128::NEG_INFINITY));
+}
@@ -956,3 +956,34 @@ fn s_nan() -> f16 {
     assert_eq!(Ordering::Less, (-s_nan()).total_cmp(&f16::INFINITY));
     assert_eq!(Ordering::Less, (-s_nan()).total_cmp(&s_nan()));
 }
+
+#[test]
+#[cfg(not(bootstrap))]
+fn test_hex_float_roundtrip() {
+    for bits in 0..=u16::MAX {
+        let x = f16::from_bits(bits);
+        for s in [format!("{x:a}"), format!("{x:A}"), format!("{x:+.5a}")] {
+            let y = f16::from_str_hex(&s).unwrap();
+            if x.is_nan() {
+                assert!(y.is_nan(), "{s}");
+            } else {
+                assert_eq!(y.to_bits(), bits, "{s}");
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(not(bootstrap))]
+fn test_hex_float() {
+    assert_eq!(format!("{:a}", 1.5f16), "0x1.8p0");
+    assert_eq!(format!("{:a}", 0.1f16), "0x1.998p-4");
+    assert_eq!(format!("{:a}", f16::MAX), "0x1.ffcp15");
+    assert_eq!(format!("{:a}", f16::MIN_POSITIVE), "0x1p-14");
+    assert_eq!(format!("{:a}", f16::from_bits(TINY_BITS)), "0x0.004p-14");
+    assert_eq!(format!("{:.1a}", 0.1f16), "0x1.ap-4");
+    assert_eq!(f16::from_str_hex("0x1.ffep15"), Ok(f16::INFINITY));
+    assert_eq!(f16::from_str_hex("0x1.ffdfp15"), Ok(f16::MAX));
+    assert_eq!(f16::from_str_hex("0x1p-25"), Ok(0.0));
+    assert_eq!(f16::from_str_hex("0x1.01p-25").unwrap().to_bits(), TINY_BITS);
+}
@@ -11,7 +11,7 @@
 #[cfg(all(test, not(any(target_os = "emscripten", target_env = "sgx", target_os = "xous"))))]
 mod tests;
 
-use crate::ffi::OsString;
+use crate::ffi::{OsStr, OsString};
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
 use crate::path::{Path, PathBuf};
@@ -229,6 +229,19 @@ pub struct DirBuilder {
     recursive: bool,
 }
 
+/// A builder used to atomically replace the contents of a file.
+///
+/// This is the builder form of [`write_atomic`], exposing whether the
+/// permissions of an existing file are kept and whether data is flushed to
+/// disk before and after the file is replaced.
+#[unstable(feature = "fs_write_atomic", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct AtomicWriteOptions {
+    preserve_permissions: bool,
+    permissions: Option<Permissions>,
+    sync: bool,
+}
+
 /// Reads the entire contents of a file into a bytes vector.
 ///
 /// This is a convenience function for using [`File::open`] and [`read_to_end`]
@@ -343,6 +356,50 @@ fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
     inner(path.as_ref(), contents.as_ref())
 }
 
+/// Atomically replaces the entire contents of a file with a slice.
+///
+/// Unlike [`write`], which truncates the file in place, this function writes
+/// `contents` to a temporary file in the same directory, flushes it to disk,
+/// and then renames it over `path`. Readers therefore observe either the old
+/// contents or the new contents, never a partially written file, and a crash
+/// leaves the previous contents intact. Once the file has been replaced the
+/// parent directory is flushed as well so that the rename itself is durable.
+///
+/// If `path` already exists its permissions are carried over to the new file.
+/// If `path` is a symbolic link, the link itself is replaced by a regular file.
+/// Use [`AtomicWriteOptions`] to change either of these defaults.
+///
+/// # Platform-specific behavior
+///
+/// This function currently corresponds to `rename` on Unix platforms and to
+/// `ReplaceFileW` on Windows. On platforms without an atomic rename this is
+/// only as atomic as [`rename`].
+/// Note that, this [may change in the future][changes].
+///
+/// [changes]: io#platform-specific-behavior
+///
+/// # Errors
+///
+/// This function will return an error if the temporary file cannot be created
+/// in the parent directory of `path`, if writing or flushing fails, or if the
+/// file cannot be replaced. The temporary file is removed on error.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(fs_write_atomic)]
+/// use std::fs;
+///
+/// fn main() -> std::io::Result<()> {
+///     fs::write_atomic("config.toml", b"answer = 42\n")?;
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "fs_write_atomic", issue = "none")]
+pub fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    AtomicWriteOptions::new().write(path, contents)
+}
+
 impl File {
     /// Attempts to open a file in read-only mode.
     ///
@@ -2623,6 +2680,149 @@ pub fn set_permissions<P: AsRef<Path>>(path: P, perm: Permissions) -> io::Result
     fs_imp::set_perm(path.as_ref(), perm.0)
 }
 
+impl AtomicWriteOptions {
+    /// Creates a blank set of options.
+    ///
+    /// By default the permissions of an existing file are preserved and the
+    /// data and the parent directory are both flushed to disk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fs_write_atomic)]
+    /// use std::fs::AtomicWriteOptions;
+    ///
+    /// let options = AtomicWriteOptions::new();
+    /// ```
+    #[unstable(feature = "fs_write_atomic", issue = "none")]
+    #[must_use]
+    pub fn new() -> AtomicWriteOptions {
+        AtomicWriteOptions { preserve_permissions: true, permissions: None, sync: true }
+    }
+
+    /// Sets whether the permissions of an existing file are applied to the
+    /// replacement.
+    ///
+    /// When this is `false`, or when the file does not exist yet, the new file
+    /// gets the same permissions as a file created by [`File::create`].
+    ///
+    /// This option defaults to `true` and is ignored if explicit permissions
+    /// are set with [`AtomicWriteOptions::permissions`].
+    #[unstable(feature = "fs_write_atomic", issue = "none")]
+    pub fn preserve_permissions(&mut self, preserve: bool) -> &mut Self {
+        self.preserve_permissions = preserve;
+        self
+    }
+
+    /// Sets the permissions the replacement file is given.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(fs_write_atomic)]
+    /// use std::fs::{self, AtomicWriteOptions};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut perms = fs::metadata("template.toml")?.permissions();
+    ///     perms.set_readonly(true);
+    ///     AtomicWriteOptions::new().permissions(perms).write("config.toml", "answer = 42")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "fs_write_atomic", issue = "none")]
+    pub fn permissions(&mut self, perm: Permissions) -> &mut Self {
+        self.permissions = Some(perm);
+        self
+    }
+
+    /// Sets whether the new contents and the directory entry are flushed to
+    /// disk.
+    ///
+    /// Turning this off keeps the replacement atomic for concurrent readers but
+    /// gives up durability: after a crash the file may contain either version,
+    /// or on some filesystems be empty.
+    ///
+    /// This option defaults to `true`.
+    #[unstable(feature = "fs_write_atomic", issue = "none")]
+    pub fn sync(&mut self, sync: bool) -> &mut Self {
+        self.sync = sync;
+        self
+    }
+
+    /// Atomically replaces the contents of the file at `path` with `contents`
+    /// using the options in `self`.
+    ///
+    /// See [`write_atomic`] for details.
+    #[unstable(feature = "fs_write_atomic", issue = "none")]
+    pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(&self, path: P, contents: C) -> io::Result<()> {
+        self._write(path.as_ref(), contents.as_ref())
+    }
+
+    fn _write(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let Some(file_name) = path.file_name() else {
+            return Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "path does not name a file",
+            ));
+        };
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+
+        let perm = match self.permissions {
+            Some(ref perm) => Some(perm.clone()),
+            None if self.preserve_permissions => match metadata(path) {
+                Ok(meta) => Some(meta.permissions()),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+
+        let (mut file, tmp) = create_temporary(dir, file_name)?;
+        let result: io::Result<()> = try {
+            file.write_all(contents)?;
+            if let Some(perm) = perm {
+                file.set_permissions(perm)?;
+            }
+            if self.sync {
+                file.sync_all()?;
+            }
+            drop(file);
+            fs_imp::replace(&tmp, path)?;
+        };
+        if let Err(e) = result {
+            let _ = fs_imp::unlink(&tmp);
+            return Err(e);
+        }
+
+        if self.sync { fs_imp::sync_dir(dir) } else { Ok(()) }
+    }
+}
+
+/// Creates a uniquely named, empty file next to `file_name` in `dir`.
+fn create_temporary(dir: &Path, file_name: &OsStr) -> io::Result<(File, PathBuf)> {
+    const ATTEMPTS: usize = 16;
+
+    for _ in 0..ATTEMPTS {
+        let (key, _) = crate::sys::hashmap_random_keys();
+        let mut name = OsString::from(".");
+        name.push(file_name);
+        name.push(format!(".{key:016x}.tmp"));
+        let tmp = dir.join(name);
+        match OpenOptions::new().write(true).create_new(true).open(&tmp) {
+            Ok(file) => return Ok((file, tmp)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Err(io::const_io_error!(
+        io::ErrorKind::AlreadyExists,
+        "failed to create a uniquely named temporary file",
+    ))
+}
+
 impl DirBuilder {
     /// Creates a new set of options with default mode/security settings for all
     /// platforms and also non-recursive.
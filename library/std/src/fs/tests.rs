@@ -1218,6 +1218,68 @@ fn write_then_read() {
     assert_eq!(string, s);
 }
 
+#[test]
+fn write_atomic_creates_and_replaces() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("atomic");
+
+    check!(fs::write_atomic(&path, b"first"));
+    assert_eq!(check!(fs::read(&path)), b"first");
+
+    check!(fs::write_atomic(&path, b"second, and longer"));
+    assert_eq!(check!(fs::read(&path)), b"second, and longer");
+
+    // No temporary files may be left behind.
+    let entries: Vec<_> = check!(fs::read_dir(tmpdir.path())).map(|e| e.unwrap().path()).collect();
+    assert_eq!(entries, [path]);
+}
+
+#[test]
+fn write_atomic_missing_dir() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("missing").join("atomic");
+    assert!(fs::write_atomic(&path, b"contents").is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn write_atomic_over_dir() {
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("atomic");
+    check!(fs::create_dir(&path));
+    check!(fs::write(path.join("inner"), b"inner"));
+
+    assert!(fs::write_atomic(&path, b"contents").is_err());
+
+    // The directory is left untouched, and the temporary file is removed.
+    assert_eq!(check!(fs::read(path.join("inner"))), b"inner");
+    let entries: Vec<_> = check!(fs::read_dir(tmpdir.path())).map(|e| e.unwrap().path()).collect();
+    assert_eq!(entries, [path]);
+}
+
+#[test]
+#[cfg(unix)]
+fn write_atomic_permissions() {
+    use crate::fs::AtomicWriteOptions;
+    use crate::os::unix::fs::PermissionsExt;
+
+    let tmpdir = tmpdir();
+    let path = tmpdir.join("atomic");
+    check!(fs::write(&path, b"old"));
+    check!(fs::set_permissions(&path, fs::Permissions::from_mode(0o600)));
+
+    check!(fs::write_atomic(&path, b"new"));
+    assert_eq!(check!(fs::metadata(&path)).permissions().mode() & 0o777, 0o600);
+
+    check!(
+        AtomicWriteOptions::new()
+            .permissions(fs::Permissions::from_mode(0o640))
+            .write(&path, b"newer")
+    );
+    assert_eq!(check!(fs::metadata(&path)).permissions().mode() & 0o777, 0o640);
+    assert_eq!(check!(fs::read(&path)), b"newer");
+}
+
 #[test]
 fn file_try_clone() {
     let tmpdir = tmpdir();
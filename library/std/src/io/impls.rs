@@ -549,3 +549,180 @@ fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
 }
+
+// =============================================================================
+// `core::io` implementations
+//
+// Every I/O object of the standard library implements the traits of `core::io`
+// as well, with `io::Error` as their error type. Each of these implementations
+// holds exactly when the type implements the corresponding trait of this
+// module, and forwards to that implementation.
+//
+// The orphan rules rule out a blanket implementation for every implementor of
+// this module's traits, so the bridge is spelled out here for the types of the
+// standard library. The types of `core` and `alloc` (byte slices, `Cursor`,
+// `Vec<u8>`, `VecDeque<u8>` and `Box`) implement the traits of `core::io` in
+// their own crates, with `io::ErrorKind` as the error type where they can fail.
+
+macro_rules! core_io_impls {
+    ($($(#[$attr:meta])* [$($gen:tt)*] $ty:ty: $($trait_:ident),+;)*) => {$(
+        $(#[$attr])*
+        #[unstable(feature = "core_io", issue = "none")]
+        impl<$($gen)*> core::io::ErrorType for $ty {
+            type Error = io::Error;
+        }
+
+        $(core_io_impls!(@$trait_ $(#[$attr])* [$($gen)*] $ty);)+
+    )*};
+    (@Read $(#[$attr:meta])* [$($gen:tt)*] $ty:ty) => {
+        $(#[$attr])*
+        #[unstable(feature = "core_io", issue = "none")]
+        impl<$($gen)*> core::io::Read for $ty
+        where
+            $ty: Read,
+        {
+            #[inline]
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                Read::read(self, buf)
+            }
+
+            #[inline]
+            fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                Read::read_exact(self, buf)
+            }
+
+            #[inline]
+            fn read_buf(&mut self, buf: BorrowedCursor<'_>) -> io::Result<()> {
+                Read::read_buf(self, buf)
+            }
+        }
+    };
+    (@BufRead $(#[$attr:meta])* [$($gen:tt)*] $ty:ty) => {
+        $(#[$attr])*
+        #[unstable(feature = "core_io", issue = "none")]
+        impl<$($gen)*> core::io::BufRead for $ty
+        where
+            $ty: BufRead,
+        {
+            #[inline]
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                BufRead::fill_buf(self)
+            }
+
+            #[inline]
+            fn consume(&mut self, amt: usize) {
+                BufRead::consume(self, amt)
+            }
+        }
+    };
+    (@Write $(#[$attr:meta])* [$($gen:tt)*] $ty:ty) => {
+        $(#[$attr])*
+        #[unstable(feature = "core_io", issue = "none")]
+        impl<$($gen)*> core::io::Write for $ty
+        where
+            $ty: Write,
+        {
+            #[inline]
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Write::write(self, buf)
+            }
+
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                Write::flush(self)
+            }
+
+            #[inline]
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                Write::write_all(self, buf)
+            }
+
+            #[inline]
+            fn write_fmt(&mut self, fmt: fmt::Arguments<'_>) -> io::Result<()> {
+                Write::write_fmt(self, fmt)
+            }
+        }
+    };
+    (@Seek $(#[$attr:meta])* [$($gen:tt)*] $ty:ty) => {
+        $(#[$attr])*
+        #[unstable(feature = "core_io", issue = "none")]
+        impl<$($gen)*> core::io::Seek for $ty
+        where
+            $ty: Seek,
+        {
+            #[inline]
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                Seek::seek(self, pos)
+            }
+
+            #[inline]
+            fn rewind(&mut self) -> io::Result<()> {
+                Seek::rewind(self)
+            }
+
+            #[inline]
+            fn stream_position(&mut self) -> io::Result<u64> {
+                Seek::stream_position(self)
+            }
+        }
+    };
+}
+
+core_io_impls! {
+    // Adapters and buffers of `std::io`, whenever their inner I/O objects
+    // implement the trait.
+    [R: ?Sized] io::BufReader<R>: Read, BufRead, Seek;
+    [W: ?Sized + Write] io::BufWriter<W>: Write, Seek;
+    [W: ?Sized + Write] io::LineWriter<W>: Write;
+    [T] io::Take<T>: Read, BufRead;
+    [T, U] io::Chain<T, U>: Read, BufRead;
+    [] io::Empty: Read, BufRead, Write, Seek;
+    ['a] &'a io::Empty: Write;
+    [] io::Repeat: Read;
+    [] io::Sink: Write;
+    ['a] &'a io::Sink: Write;
+
+    // Trait objects, including boxed ones through the implementations for
+    // `Box` in `alloc`.
+    ['a] dyn Read + 'a: Read;
+    ['a] dyn Read + Send + 'a: Read;
+    ['a] dyn Read + Send + Sync + 'a: Read;
+    ['a] dyn BufRead + 'a: Read, BufRead;
+    ['a] dyn BufRead + Send + 'a: Read, BufRead;
+    ['a] dyn BufRead + Send + Sync + 'a: Read, BufRead;
+    ['a] dyn Write + 'a: Write;
+    ['a] dyn Write + Send + 'a: Write;
+    ['a] dyn Write + Send + Sync + 'a: Write;
+    ['a] dyn Seek + 'a: Seek;
+    ['a] dyn Seek + Send + 'a: Seek;
+    ['a] dyn Seek + Send + Sync + 'a: Seek;
+
+    // Standard streams.
+    [] io::Stdin: Read;
+    ['a] &'a io::Stdin: Read;
+    ['a] io::StdinLock<'a>: Read, BufRead;
+    [] io::Stdout: Write;
+    ['a] &'a io::Stdout: Write;
+    ['a] io::StdoutLock<'a>: Write;
+    [] io::Stderr: Write;
+    ['a] &'a io::Stderr: Write;
+    ['a] io::StderrLock<'a>: Write;
+
+    // Files, sockets, pipes and child processes.
+    [] crate::fs::File: Read, Write, Seek;
+    ['a] &'a crate::fs::File: Read, Write, Seek;
+    [] crate::net::TcpStream: Read, Write;
+    ['a] &'a crate::net::TcpStream: Read, Write;
+    #[cfg(unix)]
+    [] crate::os::unix::net::UnixStream: Read, Write;
+    #[cfg(unix)]
+    ['a] &'a crate::os::unix::net::UnixStream: Read, Write;
+    [] crate::pipe::PipeReader: Read;
+    ['a] &'a crate::pipe::PipeReader: Read;
+    [] crate::pipe::PipeWriter: Write;
+    ['a] &'a crate::pipe::PipeWriter: Write;
+    [] crate::process::ChildStdin: Write;
+    ['a] &'a crate::process::ChildStdin: Write;
+    [] crate::process::ChildStdout: Read;
+    [] crate::process::ChildStderr: Read;
+}
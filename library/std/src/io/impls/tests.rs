@@ -1,3 +1,4 @@
+use crate::io;
 use crate::io::prelude::*;
 
 #[bench]
@@ -55,3 +56,58 @@ fn bench_write_vec(b: &mut test::Bencher) {
         }
     })
 }
+
+fn core_read_to_vec<R: core::io::Read + ?Sized>(reader: &mut R) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut buf = [0; 3];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return out,
+            Ok(n) => out.extend_from_slice(&buf[..n]),
+            Err(e) => panic!("read failed: {e}"),
+        }
+    }
+}
+
+fn core_write<W: core::io::Write + ?Sized>(writer: &mut W, buf: &[u8]) {
+    assert!(writer.write_all(buf).is_ok());
+    assert!(writer.flush().is_ok());
+}
+
+#[test]
+fn core_io_bridge_readers() {
+    let mut slice: &[u8] = b"hello";
+    assert_eq!(core_read_to_vec(&mut slice), b"hello");
+
+    let mut reader = io::BufReader::with_capacity(2, &b"hello"[..]);
+    assert_eq!(core::io::BufRead::fill_buf(&mut reader).ok(), Some(&b"he"[..]));
+    assert_eq!(core_read_to_vec(&mut reader), b"hello");
+
+    let mut reader: Box<dyn Read> = Box::new((&b"hel"[..]).chain(&b"lo"[..]));
+    assert_eq!(core_read_to_vec(&mut reader), b"hello");
+
+    let mut reader: Box<dyn BufRead + Send> = Box::new(io::Cursor::new(b"hello".to_vec()));
+    assert_eq!(core_read_to_vec(&mut *reader), b"hello");
+}
+
+#[test]
+fn core_io_bridge_writers() {
+    let mut vec = Vec::new();
+    core_write(&mut vec, b"hello");
+    assert_eq!(vec, b"hello");
+
+    let mut writer = io::BufWriter::with_capacity(2, Vec::new());
+    core_write(&mut writer, b"hello");
+    assert_eq!(writer.get_ref(), b"hello");
+
+    let mut cursor = io::Cursor::new(b"hello".to_vec());
+    cursor.set_position(3);
+    core_write(&mut cursor, b"p!");
+    assert_eq!(cursor.position(), 5);
+    cursor.set_position(7);
+    core_write(&mut cursor, b"?");
+    assert_eq!(cursor.get_ref(), b"help!\0\0?");
+
+    let mut writer: Box<dyn Write> = Box::new(io::sink());
+    core_write(&mut writer, b"hello");
+}
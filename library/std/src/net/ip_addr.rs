@@ -8,6 +8,8 @@
 pub use core::net::Ipv6MulticastScope;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::net::{Ipv4Addr, Ipv6Addr};
+#[unstable(feature = "ip_net", issue = "none")]
+pub use core::net::{IpNet, Ipv4Hosts, Ipv4Net, Ipv4Subnets, Ipv6Hosts, Ipv6Net, Ipv6Subnets};
 
 use crate::sys::net::netc as c;
 use crate::sys_common::{FromInner, IntoInner};
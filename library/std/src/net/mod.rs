@@ -11,6 +11,8 @@
 //!   [`Ipv6Addr`] are respectively IPv4 and IPv6 addresses
 //! * [`SocketAddr`] represents socket addresses of either IPv4 or IPv6; [`SocketAddrV4`]
 //!   and [`SocketAddrV6`] are respectively IPv4 and IPv6 socket addresses
+//! * [`IpNet`] represents IP networks of either IPv4 or IPv6; [`Ipv4Net`] and
+//!   [`Ipv6Net`] are respectively IPv4 and IPv6 networks in CIDR notation
 //! * [`ToSocketAddrs`] is a trait that is used for generic address resolution when interacting
 //!   with networking objects like [`TcpListener`], [`TcpStream`] or [`UdpSocket`]
 //! * Other types are return or parameter types for various methods in this module
@@ -26,6 +28,8 @@
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::ip_addr::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope};
+#[unstable(feature = "ip_net", issue = "none")]
+pub use self::ip_addr::{IpNet, Ipv4Hosts, Ipv4Net, Ipv4Subnets, Ipv6Hosts, Ipv6Net, Ipv6Subnets};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::socket_addr::{SocketAddr, SocketAddrV4, SocketAddrV6, ToSocketAddrs};
 #[unstable(feature = "tcplistener_into_incoming", issue = "88373")]
@@ -5,10 +5,11 @@
 use crate::sealed::Sealed;
 use crate::sys_common::AsInner;
 
-/// Linux-specific functionality for `AF_UNIX` sockets [`UnixDatagram`]
-/// and [`UnixStream`].
+/// Linux-specific functionality for `AF_UNIX` sockets [`UnixDatagram`],
+/// [`UnixSeqpacket`] and [`UnixStream`].
 ///
 /// [`UnixDatagram`]: net::UnixDatagram
+/// [`UnixSeqpacket`]: net::UnixSeqpacket
 /// [`UnixStream`]: net::UnixStream
 #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
 pub trait UnixSocketExt: Sealed {
@@ -61,3 +62,14 @@ fn set_passcred(&self, passcred: bool) -> io::Result<()> {
         self.as_inner().set_passcred(passcred)
     }
 }
+
+#[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+impl UnixSocketExt for net::UnixSeqpacket {
+    fn passcred(&self) -> io::Result<bool> {
+        self.as_inner().passcred()
+    }
+
+    fn set_passcred(&self, passcred: bool) -> io::Result<()> {
+        self.as_inner().set_passcred(passcred)
+    }
+}
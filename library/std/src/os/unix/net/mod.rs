@@ -9,6 +9,17 @@
 mod ancillary;
 mod datagram;
 mod listener;
+#[cfg(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "illumos",
+    target_os = "solaris",
+))]
+mod seqpacket;
 mod stream;
 #[cfg(all(test, not(target_os = "emscripten")))]
 mod tests;
@@ -33,6 +44,18 @@
 pub use self::datagram::*;
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub use self::listener::*;
+#[cfg(any(
+    target_os = "android",
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "illumos",
+    target_os = "solaris",
+))]
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub use self::seqpacket::*;
 #[stable(feature = "unix_socket", since = "1.10.0")]
 pub use self::stream::*;
 #[cfg(any(
@@ -0,0 +1,695 @@
+#[cfg(any(doc, target_os = "android", target_os = "linux"))]
+use super::{recv_vectored_with_ancillary_from, send_vectored_with_ancillary_to, SocketAncillary};
+use super::{sockaddr_un, SocketAddr};
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::Shutdown;
+use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use crate::path::Path;
+use crate::sealed::Sealed;
+use crate::sys::cvt;
+use crate::sys::net::Socket;
+use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::Duration;
+use crate::{fmt, mem};
+
+#[cfg(target_os = "linux")]
+const BACKLOG: core::ffi::c_int = -1;
+#[cfg(not(target_os = "linux"))]
+const BACKLOG: core::ffi::c_int = 128;
+
+/// A Unix sequenced-packet socket.
+///
+/// Like a [`UnixStream`], a `UnixSeqpacket` is connection-oriented and
+/// reliable, but like a [`UnixDatagram`] it preserves message boundaries:
+/// each [`send`] is received by exactly one [`recv`] on the peer. A received
+/// message that does not fit in the supplied buffer is truncated, and the
+/// excess bytes are discarded.
+///
+/// Connections are accepted by a [`UnixSeqpacketListener`], or created in
+/// pairs by [`UnixSeqpacket::pair`].
+///
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`UnixDatagram`]: crate::os::unix::net::UnixDatagram
+/// [`send`]: UnixSeqpacket::send
+/// [`recv`]: UnixSeqpacket::recv
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::os::unix::net::UnixSeqpacket;
+///
+/// fn main() -> std::io::Result<()> {
+///     let socket = UnixSeqpacket::connect("/path/to/my/socket")?;
+///     socket.send(b"hello")?;
+///     socket.send(b"world")?;
+///     let mut buf = [0; 100];
+///     let count = socket.recv(&mut buf)?;
+///     println!("received message {:?}", &buf[..count]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacket(Socket);
+
+/// Allows extension traits within `std`.
+#[unstable(feature = "sealed", issue = "none")]
+impl Sealed for UnixSeqpacket {}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacket {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacket");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        if let Ok(addr) = self.peer_addr() {
+            builder.field("peer", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacket {
+    /// Connects to the socket named by `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let socket = match UnixSeqpacket::connect("/tmp/sock") {
+    ///     Ok(sock) => sock,
+    ///     Err(e) => {
+    ///         println!("Couldn't connect: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::connect(inner.as_raw_fd(), core::ptr::addr_of!(addr) as *const _, len))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Connects to the socket specified by [`address`].
+    ///
+    /// On Linux and Android, this can be an address in the abstract namespace,
+    /// created with [`SocketAddrExt::from_abstract_name`].
+    ///
+    /// [`address`]: crate::os::unix::net::SocketAddr
+    /// [`SocketAddrExt::from_abstract_name`]: crate::os::linux::net::SocketAddrExt::from_abstract_name
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::linux::net::SocketAddrExt;
+    /// use std::os::unix::net::{SocketAddr, UnixSeqpacket};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_name(b"supervisor")?;
+    ///     let socket = UnixSeqpacket::connect_addr(&addr)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn connect_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacket> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::connect(
+                inner.as_raw_fd(),
+                core::ptr::addr_of!(socket_addr.addr) as *const _,
+                socket_addr.len,
+            ))?;
+            Ok(UnixSeqpacket(inner))
+        }
+    }
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// Returns two `UnixSeqpacket`s which are connected to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// let (sock1, sock2) = match UnixSeqpacket::pair() {
+    ///     Ok((sock1, sock2)) => (sock1, sock2),
+    ///     Err(e) => {
+    ///         println!("Couldn't create a pair of sockets: {e:?}");
+    ///         return
+    ///     }
+    /// };
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn pair() -> io::Result<(UnixSeqpacket, UnixSeqpacket)> {
+        let (i1, i2) = Socket::new_pair(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+        Ok((UnixSeqpacket(i1), UnixSeqpacket(i2)))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    ///
+    /// The returned `UnixSeqpacket` is a reference to the same socket that this
+    /// object references. Both handles send and receive on the same connection,
+    /// and options set on one socket will be propagated to the other socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacket> {
+        self.0.duplicate().map(UnixSeqpacket)
+    }
+
+    /// Returns the socket address of the local half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Returns the socket address of the remote half of this connection.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getpeername(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Sends a message on the socket.
+    ///
+    /// The message is sent as a whole, so on success the returned number of
+    /// bytes written is the length of `buf`. Messages that are too long for
+    /// the socket fail with an error instead of being split.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     socket.send(b"omelette au fromage").expect("send function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        unsafe {
+            let count = cvt(libc::send(
+                self.as_raw_fd(),
+                buf.as_ptr() as *const _,
+                buf.len(),
+                libc::MSG_NOSIGNAL,
+            ))?;
+            Ok(count as usize)
+        }
+    }
+
+    /// Like [`send`], except that the message is gathered from a slice of
+    /// buffers.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn send_vectored(&self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        unsafe {
+            let mut msg: libc::msghdr = mem::zeroed();
+            msg.msg_iov = bufs.as_ptr() as *mut _;
+            msg.msg_iovlen = bufs.len() as _;
+            let count = cvt(libc::sendmsg(self.as_raw_fd(), &msg, libc::MSG_NOSIGNAL))?;
+            Ok(count as usize)
+        }
+    }
+
+    /// Receives a single message from the socket.
+    ///
+    /// On success, returns the number of bytes read. If the message is longer
+    /// than `buf`, the excess bytes are discarded; a return value of `0` with a
+    /// non-empty `buf` means either an empty message or that the peer has shut
+    /// down the connection.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::unix::net::UnixSeqpacket;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = vec![0; 10];
+    ///     socket.recv(buf.as_mut_slice()).expect("recv function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+
+    /// Like [`recv`], except that the message is scattered into a slice of
+    /// buffers.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn recv_vectored(&self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        self.0.read_vectored(bufs)
+    }
+
+    /// Receives the next message from the socket, without removing it from
+    /// the queue. On success, returns the number of bytes peeked.
+    ///
+    /// Successive calls return the same message. This is accomplished by
+    /// passing `MSG_PEEK` as a flag to the underlying `recv` system call.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.peek(buf)
+    }
+
+    /// Receives a single message and ancillary data from the socket.
+    ///
+    /// On success, returns the number of bytes read and whether the message
+    /// was truncated to fit in `bufs`.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{AncillaryData, SocketAncillary, UnixSeqpacket};
+    /// use std::io::IoSliceMut;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let mut buf = [0; 64];
+    ///     let bufs = &mut [IoSliceMut::new(&mut buf)][..];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     let (size, truncated) = socket.recv_vectored_with_ancillary(bufs, &mut ancillary)?;
+    ///     println!("received {size}, truncated: {truncated}");
+    ///     for ancillary_result in ancillary.messages() {
+    ///         if let AncillaryData::ScmRights(scm_rights) = ancillary_result.unwrap() {
+    ///             for fd in scm_rights {
+    ///                 println!("receive file descriptor: {fd}");
+    ///             }
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn recv_vectored_with_ancillary(
+        &self,
+        bufs: &mut [IoSliceMut<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<(usize, bool)> {
+        let (count, truncated, _) = recv_vectored_with_ancillary_from(&self.0, bufs, ancillary)?;
+
+        Ok((count, truncated))
+    }
+
+    /// Sends a single message and ancillary data on the socket.
+    ///
+    /// On success, returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket, unix_socket_ancillary_data)]
+    /// use std::os::unix::net::{SocketAncillary, UnixSeqpacket};
+    /// use std::io::IoSlice;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let socket = UnixSeqpacket::connect("/tmp/sock")?;
+    ///     let bufs = &[IoSlice::new(b"take these")][..];
+    ///     let fds = [0, 1, 2];
+    ///     let mut ancillary_buffer = [0; 128];
+    ///     let mut ancillary = SocketAncillary::new(&mut ancillary_buffer[..]);
+    ///     ancillary.add_fds(&fds[..]);
+    ///     socket.send_vectored_with_ancillary(bufs, &mut ancillary)
+    ///         .expect("send_vectored_with_ancillary function failed");
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(any(doc, target_os = "android", target_os = "linux"))]
+    #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
+    pub fn send_vectored_with_ancillary(
+        &self,
+        bufs: &[IoSlice<'_>],
+        ancillary: &mut SocketAncillary<'_>,
+    ) -> io::Result<usize> {
+        send_vectored_with_ancillary_to(&self.0, None, bufs, ancillary)
+    }
+
+    /// Sets the read timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`recv`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`recv`]: UnixSeqpacket::recv
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_RCVTIMEO)
+    }
+
+    /// Sets the write timeout for the socket.
+    ///
+    /// If the provided value is [`None`], then [`send`] calls will block
+    /// indefinitely. An [`Err`] is returned if the zero [`Duration`] is passed
+    /// to this method.
+    ///
+    /// [`send`]: UnixSeqpacket::send
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.0.set_timeout(timeout, libc::SO_SNDTIMEO)
+    }
+
+    /// Returns the read timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn read_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_RCVTIMEO)
+    }
+
+    /// Returns the write timeout of this socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn write_timeout(&self) -> io::Result<Option<Duration>> {
+        self.0.timeout(libc::SO_SNDTIMEO)
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// This function will cause all pending and future I/O calls on the
+    /// specified portions to immediately return with an appropriate value
+    /// (see the documentation of [`Shutdown`]).
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.0.shutdown(how)
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacket {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacket {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacket {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacket {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacket {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacket> for OwnedFd {
+    /// Takes ownership of a [`UnixSeqpacket`]'s socket file descriptor.
+    #[inline]
+    fn from(socket: UnixSeqpacket) -> OwnedFd {
+        socket.0.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacket {
+    #[inline]
+    fn from(owned: OwnedFd) -> Self {
+        UnixSeqpacket(Socket::from_inner(FromInner::from_inner(owned)))
+    }
+}
+
+impl AsInner<Socket> for UnixSeqpacket {
+    #[inline]
+    fn as_inner(&self) -> &Socket {
+        &self.0
+    }
+}
+
+/// A structure representing a Unix domain sequenced-packet socket server.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_seqpacket)]
+/// use std::thread;
+/// use std::os::unix::net::{UnixSeqpacket, UnixSeqpacketListener};
+///
+/// fn handle_client(socket: UnixSeqpacket) {
+///     // ...
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let listener = UnixSeqpacketListener::bind("/path/to/the/socket")?;
+///
+///     // accept connections and process them, spawning a new thread for each one
+///     for socket in listener.incoming() {
+///         match socket {
+///             Ok(socket) => {
+///                 /* connection succeeded */
+///                 thread::spawn(|| handle_client(socket));
+///             }
+///             Err(err) => {
+///                 /* connection failed */
+///                 break;
+///             }
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct UnixSeqpacketListener(Socket);
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl fmt::Debug for UnixSeqpacketListener {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut builder = fmt.debug_struct("UnixSeqpacketListener");
+        builder.field("fd", self.0.as_inner());
+        if let Ok(addr) = self.local_addr() {
+            builder.field("local", &addr);
+        }
+        builder.finish()
+    }
+}
+
+impl UnixSeqpacketListener {
+    /// Creates a new `UnixSeqpacketListener` bound to the specified socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind<P: AsRef<Path>>(path: P) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            let (addr, len) = sockaddr_un(path.as_ref())?;
+
+            cvt(libc::bind(inner.as_raw_fd(), core::ptr::addr_of!(addr) as *const _, len as _))?;
+            cvt(libc::listen(inner.as_raw_fd(), BACKLOG))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Creates a new `UnixSeqpacketListener` bound to the specified [`socket address`].
+    ///
+    /// On Linux and Android, this can be an address in the abstract namespace,
+    /// which leaves no file behind in the file system.
+    ///
+    /// [`socket address`]: crate::os::unix::net::SocketAddr
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(any(target_os = "android", target_os = "linux"), doc = "```no_run")]
+    #[cfg_attr(not(any(target_os = "android", target_os = "linux")), doc = "```ignore")]
+    /// #![feature(unix_seqpacket)]
+    /// use std::os::linux::net::SocketAddrExt;
+    /// use std::os::unix::net::{SocketAddr, UnixSeqpacketListener};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let addr = SocketAddr::from_abstract_name(b"supervisor")?;
+    ///     let listener = UnixSeqpacketListener::bind_addr(&addr)?;
+    ///     let (socket, _) = listener.accept()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn bind_addr(socket_addr: &SocketAddr) -> io::Result<UnixSeqpacketListener> {
+        unsafe {
+            let inner = Socket::new_raw(libc::AF_UNIX, libc::SOCK_SEQPACKET)?;
+            cvt(libc::bind(
+                inner.as_raw_fd(),
+                core::ptr::addr_of!(socket_addr.addr) as *const _,
+                socket_addr.len as _,
+            ))?;
+            cvt(libc::listen(inner.as_raw_fd(), BACKLOG))?;
+            Ok(UnixSeqpacketListener(inner))
+        }
+    }
+
+    /// Accepts a new incoming connection to this listener.
+    ///
+    /// This function will block the calling thread until a new connection is
+    /// established. When established, the corresponding [`UnixSeqpacket`] and
+    /// the remote peer's address will be returned.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn accept(&self) -> io::Result<(UnixSeqpacket, SocketAddr)> {
+        let mut storage: libc::sockaddr_un = unsafe { mem::zeroed() };
+        let mut len = mem::size_of_val(&storage) as libc::socklen_t;
+        let sock = self.0.accept(core::ptr::addr_of_mut!(storage) as *mut _, &mut len)?;
+        let addr = SocketAddr::from_parts(storage, len)?;
+        Ok((UnixSeqpacket(sock), addr))
+    }
+
+    /// Creates a new independently owned handle to the underlying socket.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn try_clone(&self) -> io::Result<UnixSeqpacketListener> {
+        self.0.duplicate().map(UnixSeqpacketListener)
+    }
+
+    /// Returns the local socket address of this listener.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        SocketAddr::new(|addr, len| unsafe { libc::getsockname(self.as_raw_fd(), addr, len) })
+    }
+
+    /// Moves the socket into or out of nonblocking mode.
+    ///
+    /// This will result in the `accept` operation becoming nonblocking, see
+    /// [`UnixListener::set_nonblocking`].
+    ///
+    /// [`UnixListener::set_nonblocking`]: crate::os::unix::net::UnixListener::set_nonblocking
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.0.set_nonblocking(nonblocking)
+    }
+
+    /// Returns the value of the `SO_ERROR` option.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn take_error(&self) -> io::Result<Option<io::Error>> {
+        self.0.take_error()
+    }
+
+    /// Returns an iterator over incoming connections.
+    ///
+    /// The iterator will never return [`None`] and will also not yield the
+    /// peer's [`SocketAddr`] structure.
+    #[unstable(feature = "unix_seqpacket", issue = "none")]
+    pub fn incoming(&self) -> IncomingSeqpacket<'_> {
+        IncomingSeqpacket { listener: self }
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl FromRawFd for UnixSeqpacketListener {
+    #[inline]
+    unsafe fn from_raw_fd(fd: RawFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(OwnedFd::from_raw_fd(fd))))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl IntoRawFd for UnixSeqpacketListener {
+    #[inline]
+    fn into_raw_fd(self) -> RawFd {
+        self.0.into_raw_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl AsFd for UnixSeqpacketListener {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<OwnedFd> for UnixSeqpacketListener {
+    #[inline]
+    fn from(fd: OwnedFd) -> UnixSeqpacketListener {
+        UnixSeqpacketListener(Socket::from_inner(FromInner::from_inner(fd)))
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl From<UnixSeqpacketListener> for OwnedFd {
+    /// Takes ownership of a [`UnixSeqpacketListener`]'s socket file descriptor.
+    #[inline]
+    fn from(listener: UnixSeqpacketListener) -> OwnedFd {
+        listener.0.into_inner().into_inner()
+    }
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> IntoIterator for &'a UnixSeqpacketListener {
+    type Item = io::Result<UnixSeqpacket>;
+    type IntoIter = IncomingSeqpacket<'a>;
+
+    fn into_iter(self) -> IncomingSeqpacket<'a> {
+        self.incoming()
+    }
+}
+
+/// An iterator over incoming connections to a [`UnixSeqpacketListener`].
+///
+/// It will never return [`None`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+pub struct IncomingSeqpacket<'a> {
+    listener: &'a UnixSeqpacketListener,
+}
+
+#[unstable(feature = "unix_seqpacket", issue = "none")]
+impl<'a> Iterator for IncomingSeqpacket<'a> {
+    type Item = io::Result<UnixSeqpacket>;
+
+    fn next(&mut self) -> Option<io::Result<UnixSeqpacket>> {
+        Some(self.listener.accept().map(|s| s.0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
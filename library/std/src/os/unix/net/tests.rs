@@ -774,3 +774,144 @@ fn test_send_vectored_with_ancillary_unix_datagram() {
         unreachable!("must be ScmRights");
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_message_boundaries() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+
+    assert_eq!(or_panic!(s1.send(b"hello")), 5);
+    assert_eq!(or_panic!(s1.send_vectored(&[IoSlice::new(b"wor"), IoSlice::new(b"ld!")])), 6);
+    assert_eq!(or_panic!(s1.send(b"truncated")), 9);
+
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(s2.peek(&mut buf)), 5);
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 6);
+    assert_eq!(&buf[..6], b"world!");
+
+    // The rest of a message that does not fit in the buffer is discarded.
+    let mut small = [0; 5];
+    assert_eq!(or_panic!(s2.recv(&mut small)), 5);
+    assert_eq!(&small, b"trunc");
+
+    drop(s1);
+    assert_eq!(or_panic!(s2.recv(&mut buf)), 0);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_unix_seqpacket_send_to_closed_peer() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+    drop(s2);
+
+    // The signal mask is per thread, so change it on a thread of our own.
+    thread::spawn(move || unsafe {
+        // The test harness ignores SIGPIPE, but a blocked signal stays pending
+        // instead, so that raising it can be detected.
+        let mut set: libc::sigset_t = crate::mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGPIPE);
+        assert_eq!(libc::pthread_sigmask(libc::SIG_BLOCK, &set, crate::ptr::null_mut()), 0);
+
+        assert_eq!(s1.send(b"hello").unwrap_err().kind(), ErrorKind::BrokenPipe);
+        let bufs = [IoSlice::new(b"hel"), IoSlice::new(b"lo")];
+        assert_eq!(s1.send_vectored(&bufs).unwrap_err().kind(), ErrorKind::BrokenPipe);
+
+        let mut pending: libc::sigset_t = crate::mem::zeroed();
+        assert_eq!(libc::sigpending(&mut pending), 0);
+        assert_eq!(libc::sigismember(&pending, libc::SIGPIPE), 0);
+    })
+    .join()
+    .unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+#[cfg_attr(target_os = "android", ignore)] // Android SELinux rules prevent creating Unix sockets
+fn test_unix_seqpacket_listener() {
+    let dir = tmpdir();
+    let socket_path = dir.path().join("sock");
+
+    let listener = or_panic!(UnixSeqpacketListener::bind(&socket_path));
+    let thread = thread::spawn(move || {
+        let socket = or_panic!(listener.accept()).0;
+        let mut buf = [0; 16];
+        let len = or_panic!(socket.recv(&mut buf));
+        or_panic!(socket.send(&buf[..len]));
+    });
+
+    let socket = or_panic!(UnixSeqpacket::connect(&socket_path));
+    assert_eq!(Some(&*socket_path), socket.peer_addr().unwrap().as_pathname());
+    or_panic!(socket.send(b"echo"));
+    let mut buf = [0; 16];
+    assert_eq!(or_panic!(socket.recv(&mut buf)), 4);
+    assert_eq!(&buf[..4], b"echo");
+
+    thread.join().unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_abstract_seqpacket_connect() {
+    let addr = or_panic!(SocketAddr::from_abstract_name(b"seqpacket"));
+    let listener = or_panic!(UnixSeqpacketListener::bind_addr(&addr));
+    assert_eq!(or_panic!(listener.local_addr()).as_abstract_name().unwrap(), b"seqpacket");
+
+    let thread = thread::spawn(move || {
+        for socket in listener.incoming().take(2) {
+            let socket = or_panic!(socket);
+            or_panic!(socket.send(b"hi"));
+        }
+    });
+
+    for _ in 0..2 {
+        let socket = or_panic!(UnixSeqpacket::connect_addr(&addr));
+        assert_eq!(or_panic!(socket.peer_addr()).as_abstract_name().unwrap(), b"seqpacket");
+        let mut buf = [0; 4];
+        assert_eq!(or_panic!(socket.recv(&mut buf)), 2);
+    }
+
+    thread.join().unwrap();
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_send_vectored_fds_unix_seqpacket() {
+    let (s1, s2) = or_panic!(UnixSeqpacket::pair());
+
+    let buf1 = [1; 8];
+    let bufs_send = &[IoSlice::new(&buf1[..])][..];
+
+    let mut ancillary1_buffer = [0; 128];
+    let mut ancillary1 = SocketAncillary::new(&mut ancillary1_buffer[..]);
+    assert!(ancillary1.add_fds(&[s1.as_raw_fd()][..]));
+
+    let usize = or_panic!(s1.send_vectored_with_ancillary(&bufs_send, &mut ancillary1));
+    assert_eq!(usize, 8);
+
+    let mut buf2 = [0; 4];
+    let mut bufs_recv = &mut [IoSliceMut::new(&mut buf2[..])][..];
+
+    let mut ancillary2_buffer = [0; 128];
+    let mut ancillary2 = SocketAncillary::new(&mut ancillary2_buffer[..]);
+
+    let (usize, truncated) =
+        or_panic!(s2.recv_vectored_with_ancillary(&mut bufs_recv, &mut ancillary2));
+    assert_eq!(usize, 4);
+    assert!(truncated);
+    assert_eq!(buf2, [1; 4]);
+
+    let mut ancillary_data_vec = Vec::from_iter(ancillary2.messages());
+    assert_eq!(ancillary_data_vec.len(), 1);
+    if let AncillaryData::ScmRights(scm_rights) = ancillary_data_vec.pop().unwrap().unwrap() {
+        let fd_vec = Vec::from_iter(scm_rights);
+        assert_eq!(fd_vec.len(), 1);
+        unsafe {
+            libc::close(fd_vec[0]);
+        }
+    } else {
+        unreachable!("must be ScmRights");
+    }
+}
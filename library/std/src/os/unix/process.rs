@@ -6,11 +6,12 @@
 
 use cfg_if::cfg_if;
 
-use crate::ffi::OsStr;
+use crate::ffi::{CString, OsStr};
+use crate::fs::File;
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use crate::sealed::Sealed;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
-use crate::{io, process, sys};
+use crate::{fmt, io, process, sys};
 
 cfg_if! {
     if #[cfg(any(target_os = "vxworks", target_os = "espidf", target_os = "horizon", target_os = "vita"))] {
@@ -196,6 +197,40 @@ fn arg0<S>(&mut self, arg: S) -> &mut process::Command
     /// ```
     #[stable(feature = "process_set_process_group", since = "1.64.0")]
     fn process_group(&mut self, pgroup: i32) -> &mut process::Command;
+
+    /// Runs the child process in a new session with `pty` as its controlling
+    /// terminal.
+    ///
+    /// The slave side of the pseudo-terminal is opened when the child is
+    /// spawned, and any of stdin, stdout and stderr that haven't been
+    /// configured explicitly are connected to it. The parent interacts with
+    /// the child through [`Pty::master`].
+    ///
+    /// The child calls `setsid` before exec, so it is not possible to also
+    /// place it in a [process group](Self::process_group); attempting to
+    /// spawn such a command fails with an
+    /// [`InvalidInput`](io::ErrorKind::InvalidInput) error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(unix_pty)]
+    /// use std::io::Read;
+    /// use std::os::unix::process::{CommandExt, Pty};
+    /// use std::process::Command;
+    ///
+    /// let pty = Pty::open()?;
+    /// let mut child = Command::new("tty").pty(&pty).spawn()?;
+    /// child.wait()?;
+    ///
+    /// let mut output = [0; 64];
+    /// let n = pty.master().read(&mut output)?;
+    /// println!("{}", String::from_utf8_lossy(&output[..n]));
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[unstable(feature = "unix_pty", issue = "none")]
+    fn pty(&mut self, pty: &Pty) -> &mut process::Command;
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -241,6 +276,199 @@ fn process_group(&mut self, pgroup: i32) -> &mut process::Command {
         self.as_inner_mut().pgroup(pgroup);
         self
     }
+
+    fn pty(&mut self, pty: &Pty) -> &mut process::Command {
+        self.as_inner_mut().pty(&pty.slave);
+        self
+    }
+}
+
+/// Unix-specific extensions to [`process::Child`].
+///
+/// This trait is sealed: it cannot be implemented outside the standard library.
+/// This is so that future additional methods are not breaking changes.
+#[unstable(feature = "process_kill_group", issue = "none")]
+pub trait ChildExt: Sealed {
+    /// Sends `signal` to every process in the child's process group.
+    ///
+    /// This is most useful for a child spawned as the leader of a new process
+    /// group with [`CommandExt::process_group`], in which case the signal also
+    /// reaches any processes the child has spawned, such as the commands run
+    /// by a shell.
+    ///
+    /// As with [`Child::kill`](process::Child::kill), this returns `Ok(())` if
+    /// the child has already been waited on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidInput`](io::ErrorKind::InvalidInput) error if the
+    /// child is in the process group of the current process, since signalling
+    /// that group would signal the current process as well.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_kill_group)]
+    /// use std::os::unix::process::{ChildExt, CommandExt};
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("sh")
+    ///     .arg("-c")
+    ///     .arg("sleep 100 & sleep 100")
+    ///     .process_group(0)
+    ///     .spawn()?;
+    ///
+    /// // Terminates the shell and both `sleep`s.
+    /// const SIGTERM: i32 = 15;
+    /// child.kill_group(SIGTERM)?;
+    /// child.wait()?;
+    /// #
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn kill_group(&mut self, signal: i32) -> io::Result<()>;
+}
+
+#[unstable(feature = "process_kill_group", issue = "none")]
+impl ChildExt for process::Child {
+    fn kill_group(&mut self, signal: i32) -> io::Result<()> {
+        self.handle.kill_group(signal)
+    }
+}
+
+/// A pseudo-terminal, for running a child process as if it were attached to
+/// an interactive terminal.
+///
+/// The child is connected to the terminal with [`CommandExt::pty`]. The parent
+/// reads the child's output from, and writes its input to, the [master] side
+/// of the terminal. As with any terminal, input is subject to line editing
+/// and echoing according to the terminal's mode, and output may have had
+/// line endings translated.
+///
+/// Once every process using the terminal has exited, reads from the master
+/// return end-of-file or, on some platforms such as Linux, fail with `EIO`.
+///
+/// [master]: Pty::master
+///
+/// # Platform-specific behavior
+///
+/// Pseudo-terminals are currently supported on Linux, Android, macOS, FreeBSD,
+/// DragonFly BSD, NetBSD and OpenBSD. Elsewhere, [`Pty::open`] returns an
+/// error of kind [`Unsupported`](io::ErrorKind::Unsupported).
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(unix_pty)]
+/// use std::io::{Read, Write};
+/// use std::os::unix::process::{CommandExt, Pty, WindowSize};
+/// use std::process::Command;
+///
+/// let pty = Pty::open()?;
+/// pty.set_window_size(WindowSize { rows: 24, cols: 80, ..Default::default() })?;
+///
+/// let mut child = Command::new("sh").pty(&pty).spawn()?;
+/// pty.master().write_all(b"stty size; exit\n")?;
+/// child.wait()?;
+///
+/// let mut output = Vec::new();
+/// let _ = pty.master().read_to_end(&mut output);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[unstable(feature = "unix_pty", issue = "none")]
+pub struct Pty {
+    master: File,
+    slave: CString,
+}
+
+impl Pty {
+    /// Allocates a new pseudo-terminal.
+    ///
+    /// This corresponds to the `posix_openpt`, `grantpt` and `unlockpt`
+    /// functions.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub fn open() -> io::Result<Pty> {
+        let (master, slave) = sys::pty::open()?;
+        Ok(Pty { master: File::from(master), slave })
+    }
+
+    /// Returns the master side of the pseudo-terminal.
+    ///
+    /// Since [`Read`](io::Read) and [`Write`](io::Write) are implemented for
+    /// `&File`, the returned reference can be used to talk to the child
+    /// directly. Use [`File::try_clone`] to obtain an owned handle.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    #[must_use]
+    pub fn master(&self) -> &File {
+        &self.master
+    }
+
+    /// Consumes the pseudo-terminal, returning its master side.
+    ///
+    /// Children that have already been spawned keep their terminal, but no
+    /// further children can be attached to it.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub fn into_master(self) -> File {
+        self.master
+    }
+
+    /// Returns the size of the terminal.
+    ///
+    /// This corresponds to the `TIOCGWINSZ` ioctl.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub fn window_size(&self) -> io::Result<WindowSize> {
+        sys::pty::window_size(self.master.as_fd())
+    }
+
+    /// Sets the size of the terminal.
+    ///
+    /// The foreground process group of the terminal is sent `SIGWINCH`
+    /// if the size changed. This corresponds to the `TIOCSWINSZ` ioctl.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub fn set_window_size(&self, size: WindowSize) -> io::Result<()> {
+        sys::pty::set_window_size(self.master.as_fd(), size)
+    }
+}
+
+#[unstable(feature = "unix_pty", issue = "none")]
+impl fmt::Debug for Pty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pty").field("master", &self.master).field("slave", &self.slave).finish()
+    }
+}
+
+#[unstable(feature = "unix_pty", issue = "none")]
+impl AsFd for Pty {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.master.as_fd()
+    }
+}
+
+#[unstable(feature = "unix_pty", issue = "none")]
+impl AsRawFd for Pty {
+    #[inline]
+    fn as_raw_fd(&self) -> RawFd {
+        self.master.as_raw_fd()
+    }
+}
+
+/// The size of a terminal, as used by [`Pty::window_size`] and
+/// [`Pty::set_window_size`].
+#[unstable(feature = "unix_pty", issue = "none")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WindowSize {
+    /// The number of rows of characters.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub rows: u16,
+    /// The number of columns of characters.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub cols: u16,
+    /// The width of the terminal in pixels. Usually unused, and left as zero.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub pixel_width: u16,
+    /// The height of the terminal in pixels. Usually unused, and left as zero.
+    #[unstable(feature = "unix_pty", issue = "none")]
+    pub pixel_height: u16,
 }
 
 /// Unix-specific extensions to [`process::ExitStatus`] and
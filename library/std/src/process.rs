@@ -162,6 +162,7 @@
 #[stable(feature = "command_access", since = "1.57.0")]
 pub use crate::sys_common::process::CommandEnvs;
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
+use crate::time::Duration;
 use crate::{fmt, fs, str};
 
 /// Representation of a running or exited child process.
@@ -1011,6 +1012,48 @@ pub fn stderr<T: Into<Stdio>>(&mut self, cfg: T) -> &mut Command {
         self
     }
 
+    /// Sets whether the child process is killed when its [`Child`] handle is
+    /// dropped.
+    ///
+    /// By default a child keeps running after its handle is dropped. When
+    /// this is set to `true`, dropping a [`Child`] that has not been waited on
+    /// kills the child, as [`Child::kill`] would, and then reaps it. This is
+    /// useful to make sure children do not outlive a panicking or early
+    /// returning parent.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Windows the child is placed in a new Job Object which is configured
+    /// to kill all of its processes when closed. As a result, processes
+    /// spawned by the child are killed as well, both on drop and by
+    /// [`Child::kill`]. On Unix only the child itself is killed; spawn it in
+    /// its own process group with [`process_group`] and use [`kill_group`]
+    /// to signal its descendants as well.
+    ///
+    /// [`process_group`]: crate::os::unix::process::CommandExt::process_group
+    /// [`kill_group`]: crate::os::unix::process::ChildExt::kill_group
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(process_kill_on_drop)]
+    /// use std::process::Command;
+    ///
+    /// let child = Command::new("sleep")
+    ///     .arg("100")
+    ///     .kill_on_drop(true)
+    ///     .spawn()
+    ///     .expect("sleep failed to start");
+    ///
+    /// // `sleep` is killed here.
+    /// drop(child);
+    /// ```
+    #[unstable(feature = "process_kill_on_drop", issue = "none")]
+    pub fn kill_on_drop(&mut self, kill_on_drop: bool) -> &mut Command {
+        self.inner.kill_on_drop(kill_on_drop);
+        self
+    }
+
     /// Executes the command as a child process, returning a handle to it.
     ///
     /// By default, stdin, stdout and stderr are inherited from the parent.
@@ -2184,6 +2227,55 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         Ok(self.handle.try_wait()?.map(ExitStatus))
     }
 
+    /// Waits for the child to exit for at most `timeout`, returning the status
+    /// that it exited with.
+    ///
+    /// If the child exits before the timeout elapses then `Ok(Some(status))` is
+    /// returned, and on Unix the process ID is reaped. If the timeout elapses
+    /// first then `Ok(None)` is returned and the child keeps running. As with
+    /// [`try_wait`], this function is guaranteed to repeatedly return a
+    /// successful exit status once the child has exited.
+    ///
+    /// Note that unlike `wait`, this function will not attempt to drop stdin,
+    /// so the child can still be written to after a timeout.
+    ///
+    /// [`try_wait`]: Child::try_wait
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux this waits on a pidfd with `poll`, opening one for the child
+    /// if it was not spawned with one. On other Unix platforms, and on Linux
+    /// kernels without pidfd support, the child is polled with `waitpid` and
+    /// `WNOHANG` at increasing intervals; `SIGCHLD` is never used. On Windows
+    /// this uses `WaitForSingleObject`.
+    /// Note that, this [may change in the future][changes].
+    ///
+    /// [changes]: io#platform-specific-behavior
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(child_wait_timeout)]
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    ///
+    /// let mut child = Command::new("sleep").arg("10").spawn().unwrap();
+    ///
+    /// match child.wait_timeout(Duration::from_secs(1)) {
+    ///     Ok(Some(status)) => println!("exited with: {status}"),
+    ///     Ok(None) => {
+    ///         println!("still running after a second, killing it");
+    ///         child.kill().unwrap();
+    ///         child.wait().unwrap();
+    ///     }
+    ///     Err(e) => println!("error attempting to wait: {e}"),
+    /// }
+    /// ```
+    #[unstable(feature = "child_wait_timeout", issue = "none")]
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        Ok(self.handle.wait_timeout(timeout)?.map(ExitStatus))
+    }
+
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
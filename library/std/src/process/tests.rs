@@ -3,6 +3,7 @@
 use crate::io::{BorrowedBuf, ErrorKind};
 use crate::mem::MaybeUninit;
 use crate::str;
+use crate::time::{Duration, Instant};
 
 fn known_command() -> Command {
     if cfg!(windows) { Command::new("help") } else { Command::new("echo") }
@@ -68,6 +69,106 @@ fn signal_reported_right() {
     }
 }
 
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn kill_group_reaches_descendants() {
+    use crate::os::unix::process::{ChildExt, CommandExt, ExitStatusExt};
+
+    let mut p = shell_cmd().arg("-c").arg("sleep 60 & wait").process_group(0).spawn().unwrap();
+    p.kill_group(libc::SIGKILL).unwrap();
+    match p.wait().unwrap().signal() {
+        Some(9) => {}
+        result => panic!("not terminated by signal 9 (instead, {result:?})"),
+    }
+    // Killing the group again after reaping is a no-op.
+    p.kill_group(libc::SIGKILL).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn kill_group_refuses_own_group() {
+    use crate::os::unix::process::ChildExt;
+
+    let mut p = shell_cmd().arg("-c").arg("read a").stdin(Stdio::piped()).spawn().unwrap();
+    assert_eq!(p.kill_group(libc::SIGKILL).unwrap_err().kind(), ErrorKind::InvalidInput);
+    p.kill().unwrap();
+    p.wait().unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn kill_on_drop() {
+    let mut p = shell_cmd()
+        .arg("-c")
+        .arg("exec sleep 1000")
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .unwrap();
+    let mut stdout = p.stdout.take().unwrap();
+    drop(p);
+    // The child held the only write end of the pipe, so reading reaches end-of-file
+    // once it has been killed.
+    let mut output = Vec::new();
+    stdout.read_to_end(&mut output).unwrap();
+    assert!(output.is_empty());
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+fn pty_controlling_terminal() {
+    use crate::os::unix::process::{CommandExt, Pty, WindowSize};
+
+    let pty = Pty::open().unwrap();
+    let size = WindowSize { rows: 31, cols: 97, ..Default::default() };
+    pty.set_window_size(size).unwrap();
+    assert_eq!(pty.window_size().unwrap(), size);
+
+    let mut p =
+        shell_cmd().arg("-c").arg("test -t 0 && test -t 1 && stty size").pty(&pty).spawn().unwrap();
+    assert!(p.wait().unwrap().success());
+
+    // Once the child has exited, reading drains its output and then either
+    // reaches end-of-file or fails with `EIO`, depending on the platform.
+    let mut output = Vec::new();
+    let mut buf = [0; 64];
+    loop {
+        match pty.master().read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => output.extend_from_slice(&buf[..n]),
+            Err(e) if e.raw_os_error() == Some(libc::EIO) => break,
+            Err(e) => panic!("unexpected error: {e}"),
+        }
+    }
+    assert_eq!(str::from_utf8(&output).unwrap().trim(), "31 97");
+}
+
+#[test]
+#[cfg(unix)]
+fn pty_with_process_group() {
+    use crate::os::unix::process::{CommandExt, Pty};
+
+    let Ok(pty) = Pty::open() else { return };
+    let err = shell_cmd().arg("-c").arg("true").pty(&pty).process_group(0).spawn().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+#[cfg(windows)]
+fn kill_on_drop_kills_tree() {
+    let mut p = Command::new("cmd")
+        .args(&["/C", "ping -n 60 127.0.0.1 > NUL"])
+        .kill_on_drop(true)
+        .spawn()
+        .unwrap();
+    // Killing the job reports the exit code used to terminate it.
+    p.kill().unwrap();
+    assert_eq!(p.wait().unwrap().code(), Some(1));
+}
+
 pub fn run_output(mut cmd: Command) -> String {
     let p = cmd.spawn();
     assert!(p.is_ok());
@@ -229,6 +330,38 @@ fn test_finish_twice() {
     assert!(prog.wait().unwrap().code() == Some(1));
 }
 
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_wait_timeout_exited() {
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "exit 1"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("false").spawn().unwrap()
+    };
+    let status = prog.wait_timeout(Duration::from_secs(60)).unwrap();
+    assert_eq!(status.and_then(|s| s.code()), Some(1));
+    // Once reaped, the status keeps being reported.
+    assert_eq!(prog.wait_timeout(Duration::ZERO).unwrap().and_then(|s| s.code()), Some(1));
+    assert!(prog.wait().unwrap().code() == Some(1));
+}
+
+#[test]
+#[cfg_attr(any(target_os = "vxworks"), ignore)]
+fn test_wait_timeout_elapsed() {
+    let mut prog = if cfg!(target_os = "windows") {
+        Command::new("cmd").args(&["/C", "ping -n 60 127.0.0.1 > NUL"]).spawn().unwrap()
+    } else {
+        shell_cmd().arg("-c").arg("sleep 60").spawn().unwrap()
+    };
+    let start = Instant::now();
+    assert!(prog.wait_timeout(Duration::from_millis(100)).unwrap().is_none());
+    assert!(start.elapsed() >= Duration::from_millis(100));
+    assert!(prog.wait_timeout(Duration::ZERO).unwrap().is_none());
+
+    prog.kill().unwrap();
+    assert!(prog.wait_timeout(Duration::from_secs(60)).unwrap().is_some());
+}
+
 #[test]
 #[cfg_attr(any(target_os = "vxworks"), ignore)]
 fn test_wait_with_output_once() {
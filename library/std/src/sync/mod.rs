@@ -189,16 +189,20 @@
 pub use self::rwlock::{MappedRwLockReadGuard, MappedRwLockWriteGuard};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[unstable(feature = "sync_semaphore", issue = "none")]
+pub use self::semaphore::{Semaphore, SemaphorePermit};
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub mod mpmc;
 pub mod mpsc;
 
 mod barrier;
 mod condvar;
 mod lazy_lock;
-mod mpmc;
 mod mutex;
 pub(crate) mod once;
 mod once_lock;
 mod poison;
 mod reentrant_lock;
 mod rwlock;
+mod semaphore;
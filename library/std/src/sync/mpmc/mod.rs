@@ -1,8 +1,13 @@
-//! Multi-producer multi-consumer channels.
-
-// This module is not currently exposed publicly, but is used
-// as the implementation for the channels in `sync::mpsc`. The
-// implementation comes from the crossbeam-channel crate:
+//! Multi-producer, multi-consumer FIFO queue communication primitives.
+//!
+//! Unlike the channels in [`mpsc`], both the [`Sender`] and the [`Receiver`]
+//! of these channels can be cloned, and several channel operations can be
+//! waited on at once with [`Select`].
+//!
+//! [`mpsc`]: crate::sync::mpsc
+
+// This module is also used as the implementation for the channels in
+// `sync::mpsc`. The implementation comes from the crossbeam-channel crate:
 //
 // Copyright (c) 2019 The Crossbeam Project Developers
 //
@@ -30,6 +35,9 @@
 // IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
 // DEALINGS IN THE SOFTWARE.
 
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
 mod array;
 mod context;
 mod counter;
@@ -40,8 +48,13 @@
 mod waker;
 mod zero;
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 pub use error::*;
+#[unstable(feature = "mpmc_channel", issue = "none")]
+pub use select::{Select, SelectedOperation};
 
+use self::context::Context;
+use self::select::{Operation, SelectHandle, Token};
 use crate::fmt;
 use crate::panic::{RefUnwindSafe, UnwindSafe};
 use crate::time::{Duration, Instant};
@@ -49,6 +62,7 @@
 /// Creates a channel of unbounded capacity.
 ///
 /// This channel has a growable buffer that can hold any number of messages at a time.
+#[unstable(feature = "mpmc_channel", issue = "none")]
 pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
     let (s, r) = counter::new(list::Channel::new());
     let s = Sender { flavor: SenderFlavor::List(s) };
@@ -62,6 +76,7 @@ pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
 ///
 /// A special case is zero-capacity channel, which cannot hold any messages. Instead, send and
 /// receive operations must appear at the same time in order to pair up and pass the message over.
+#[unstable(feature = "mpmc_channel", issue = "none")]
 pub fn sync_channel<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
     if cap == 0 {
         let (s, r) = counter::new(zero::Channel::new());
@@ -77,6 +92,7 @@ pub fn sync_channel<T>(cap: usize) -> (Sender<T>, Receiver<T>) {
 }
 
 /// The sending side of a channel.
+#[unstable(feature = "mpmc_channel", issue = "none")]
 pub struct Sender<T> {
     flavor: SenderFlavor<T>,
 }
@@ -93,10 +109,14 @@ enum SenderFlavor<T> {
     Zero(counter::Sender<zero::Channel<T>>),
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 unsafe impl<T: Send> Send for Sender<T> {}
+#[unstable(feature = "mpmc_channel", issue = "none")]
 unsafe impl<T: Send> Sync for Sender<T> {}
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> UnwindSafe for Sender<T> {}
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> RefUnwindSafe for Sender<T> {}
 
 impl<T> Sender<T> {
@@ -107,6 +127,7 @@ impl<T> Sender<T> {
     ///
     /// If called on a zero-capacity channel, this method will send the message only if there
     /// happens to be a receive operation on the other side of the channel at the same time.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.try_send(msg),
@@ -123,6 +144,7 @@ pub fn try_send(&self, msg: T) -> Result<(), TrySendError<T>> {
     ///
     /// If called on a zero-capacity channel, this method will wait for a receive operation to
     /// appear on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.send(msg, None),
@@ -134,13 +156,7 @@ pub fn send(&self, msg: T) -> Result<(), SendError<T>> {
             SendTimeoutError::Timeout(_) => unreachable!(),
         })
     }
-}
 
-// The methods below are not used by `sync::mpsc`, but
-// are useful and we'll likely want to expose them
-// eventually
-#[allow(unused)]
-impl<T> Sender<T> {
     /// Waits for a message to be sent into the channel, but only for a limited time.
     ///
     /// If the channel is full and not disconnected, this call will block until the send operation
@@ -149,6 +165,7 @@ impl<T> Sender<T> {
     ///
     /// If called on a zero-capacity channel, this method will wait for a receive operation to
     /// appear on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn send_timeout(&self, msg: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
         match Instant::now().checked_add(timeout) {
             Some(deadline) => self.send_deadline(msg, deadline),
@@ -165,6 +182,7 @@ pub fn send_timeout(&self, msg: T, timeout: Duration) -> Result<(), SendTimeoutE
     ///
     /// If called on a zero-capacity channel, this method will wait for a receive operation to
     /// appear on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn send_deadline(&self, msg: T, deadline: Instant) -> Result<(), SendTimeoutError<T>> {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.send(msg, Some(deadline)),
@@ -176,6 +194,7 @@ pub fn send_deadline(&self, msg: T, deadline: Instant) -> Result<(), SendTimeout
     /// Returns `true` if the channel is empty.
     ///
     /// Note: Zero-capacity channels are always empty.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn is_empty(&self) -> bool {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.is_empty(),
@@ -187,6 +206,7 @@ pub fn is_empty(&self) -> bool {
     /// Returns `true` if the channel is full.
     ///
     /// Note: Zero-capacity channels are always full.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn is_full(&self) -> bool {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.is_full(),
@@ -196,6 +216,7 @@ pub fn is_full(&self) -> bool {
     }
 
     /// Returns the number of messages in the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn len(&self) -> usize {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.len(),
@@ -205,6 +226,7 @@ pub fn len(&self) -> usize {
     }
 
     /// If the channel is bounded, returns its capacity.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn capacity(&self) -> Option<usize> {
         match &self.flavor {
             SenderFlavor::Array(chan) => chan.capacity(),
@@ -214,6 +236,7 @@ pub fn capacity(&self) -> Option<usize> {
     }
 
     /// Returns `true` if senders belong to the same channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn same_channel(&self, other: &Sender<T>) -> bool {
         match (&self.flavor, &other.flavor) {
             (SenderFlavor::Array(ref a), SenderFlavor::Array(ref b)) => a == b,
@@ -224,6 +247,20 @@ pub fn same_channel(&self, other: &Sender<T>) -> bool {
     }
 }
 
+impl<T> Sender<T> {
+    /// Writes a message into the channel after a successful select.
+    pub(crate) unsafe fn write(&self, token: &mut Token, msg: T) -> Result<(), T> {
+        unsafe {
+            match &self.flavor {
+                SenderFlavor::Array(chan) => chan.write(token, msg),
+                SenderFlavor::List(chan) => chan.write(token, msg),
+                SenderFlavor::Zero(chan) => chan.write(token, msg),
+            }
+        }
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> Drop for Sender<T> {
     fn drop(&mut self) {
         unsafe {
@@ -236,6 +273,7 @@ fn drop(&mut self) {
     }
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> Clone for Sender<T> {
     fn clone(&self) -> Self {
         let flavor = match &self.flavor {
@@ -248,6 +286,7 @@ fn clone(&self) -> Self {
     }
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> fmt::Debug for Sender<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad("Sender { .. }")
@@ -255,6 +294,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 /// The receiving side of a channel.
+#[unstable(feature = "mpmc_channel", issue = "none")]
 pub struct Receiver<T> {
     flavor: ReceiverFlavor<T>,
 }
@@ -271,10 +311,14 @@ enum ReceiverFlavor<T> {
     Zero(counter::Receiver<zero::Channel<T>>),
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 unsafe impl<T: Send> Send for Receiver<T> {}
+#[unstable(feature = "mpmc_channel", issue = "none")]
 unsafe impl<T: Send> Sync for Receiver<T> {}
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> UnwindSafe for Receiver<T> {}
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> RefUnwindSafe for Receiver<T> {}
 
 impl<T> Receiver<T> {
@@ -285,6 +329,7 @@ impl<T> Receiver<T> {
     ///
     /// If called on a zero-capacity channel, this method will receive a message only if there
     /// happens to be a send operation on the other side of the channel at the same time.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn try_recv(&self) -> Result<T, TryRecvError> {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.try_recv(),
@@ -302,6 +347,7 @@ pub fn try_recv(&self) -> Result<T, TryRecvError> {
     ///
     /// If called on a zero-capacity channel, this method will wait for a send operation to appear
     /// on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn recv(&self) -> Result<T, RecvError> {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.recv(None),
@@ -319,6 +365,7 @@ pub fn recv(&self) -> Result<T, RecvError> {
     ///
     /// If called on a zero-capacity channel, this method will wait for a send operation to appear
     /// on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
         match Instant::now().checked_add(timeout) {
             Some(deadline) => self.recv_deadline(deadline),
@@ -335,6 +382,7 @@ pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
     ///
     /// If called on a zero-capacity channel, this method will wait for a send operation to appear
     /// on the other side of the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.recv(Some(deadline)),
@@ -342,16 +390,11 @@ pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
             ReceiverFlavor::Zero(chan) => chan.recv(Some(deadline)),
         }
     }
-}
 
-// The methods below are not used by `sync::mpsc`, but
-// are useful and we'll likely want to expose them
-// eventually
-#[allow(unused)]
-impl<T> Receiver<T> {
     /// Returns `true` if the channel is empty.
     ///
     /// Note: Zero-capacity channels are always empty.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn is_empty(&self) -> bool {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.is_empty(),
@@ -363,6 +406,7 @@ pub fn is_empty(&self) -> bool {
     /// Returns `true` if the channel is full.
     ///
     /// Note: Zero-capacity channels are always full.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn is_full(&self) -> bool {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.is_full(),
@@ -372,6 +416,7 @@ pub fn is_full(&self) -> bool {
     }
 
     /// Returns the number of messages in the channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn len(&self) -> usize {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.len(),
@@ -381,6 +426,7 @@ pub fn len(&self) -> usize {
     }
 
     /// If the channel is bounded, returns its capacity.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn capacity(&self) -> Option<usize> {
         match &self.flavor {
             ReceiverFlavor::Array(chan) => chan.capacity(),
@@ -390,6 +436,7 @@ pub fn capacity(&self) -> Option<usize> {
     }
 
     /// Returns `true` if receivers belong to the same channel.
+    #[unstable(feature = "mpmc_channel", issue = "none")]
     pub fn same_channel(&self, other: &Receiver<T>) -> bool {
         match (&self.flavor, &other.flavor) {
             (ReceiverFlavor::Array(a), ReceiverFlavor::Array(b)) => a == b,
@@ -400,6 +447,20 @@ pub fn same_channel(&self, other: &Receiver<T>) -> bool {
     }
 }
 
+impl<T> Receiver<T> {
+    /// Reads a message from the channel after a successful select.
+    pub(crate) unsafe fn read(&self, token: &mut Token) -> Result<T, ()> {
+        unsafe {
+            match &self.flavor {
+                ReceiverFlavor::Array(chan) => chan.read(token),
+                ReceiverFlavor::List(chan) => chan.read(token),
+                ReceiverFlavor::Zero(chan) => chan.read(token),
+            }
+        }
+    }
+}
+
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> Drop for Receiver<T> {
     fn drop(&mut self) {
         unsafe {
@@ -412,6 +473,7 @@ fn drop(&mut self) {
     }
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> Clone for Receiver<T> {
     fn clone(&self) -> Self {
         let flavor = match &self.flavor {
@@ -424,8 +486,93 @@ fn clone(&self) -> Self {
     }
 }
 
+#[unstable(feature = "mpmc_channel", issue = "none")]
 impl<T> fmt::Debug for Receiver<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.pad("Receiver { .. }")
     }
 }
+
+impl<T> SelectHandle for Sender<T> {
+    fn try_select(&self, token: &mut Token) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().try_select(token),
+            SenderFlavor::List(chan) => chan.sender().try_select(token),
+            SenderFlavor::Zero(chan) => chan.sender().try_select(token),
+        }
+    }
+
+    fn register(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().register(oper, cx),
+            SenderFlavor::List(chan) => chan.sender().register(oper, cx),
+            SenderFlavor::Zero(chan) => chan.sender().register(oper, cx),
+        }
+    }
+
+    fn unregister(&self, oper: Operation) {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().unregister(oper),
+            SenderFlavor::List(chan) => chan.sender().unregister(oper),
+            SenderFlavor::Zero(chan) => chan.sender().unregister(oper),
+        }
+    }
+
+    fn accept(&self, token: &mut Token, cx: &Context) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().accept(token, cx),
+            SenderFlavor::List(chan) => chan.sender().accept(token, cx),
+            SenderFlavor::Zero(chan) => chan.sender().accept(token, cx),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            SenderFlavor::Array(chan) => chan.sender().is_ready(),
+            SenderFlavor::List(chan) => chan.sender().is_ready(),
+            SenderFlavor::Zero(chan) => chan.sender().is_ready(),
+        }
+    }
+}
+
+impl<T> SelectHandle for Receiver<T> {
+    fn try_select(&self, token: &mut Token) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().try_select(token),
+            ReceiverFlavor::List(chan) => chan.receiver().try_select(token),
+            ReceiverFlavor::Zero(chan) => chan.receiver().try_select(token),
+        }
+    }
+
+    fn register(&self, oper: Operation, cx: &Context) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().register(oper, cx),
+            ReceiverFlavor::List(chan) => chan.receiver().register(oper, cx),
+            ReceiverFlavor::Zero(chan) => chan.receiver().register(oper, cx),
+        }
+    }
+
+    fn unregister(&self, oper: Operation) {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().unregister(oper),
+            ReceiverFlavor::List(chan) => chan.receiver().unregister(oper),
+            ReceiverFlavor::Zero(chan) => chan.receiver().unregister(oper),
+        }
+    }
+
+    fn accept(&self, token: &mut Token, cx: &Context) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().accept(token, cx),
+            ReceiverFlavor::List(chan) => chan.receiver().accept(token, cx),
+            ReceiverFlavor::Zero(chan) => chan.receiver().accept(token, cx),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match &self.flavor {
+            ReceiverFlavor::Array(chan) => chan.receiver().is_ready(),
+            ReceiverFlavor::List(chan) => chan.receiver().is_ready(),
+            ReceiverFlavor::Zero(chan) => chan.receiver().is_ready(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+use crate::thread;
+
+#[test]
+fn select_ready() {
+    let (s1, r1) = channel();
+    let (_s2, r2) = channel::<i32>();
+    s1.send(1).unwrap();
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&r1);
+    let _oper2 = sel.recv(&r2);
+    let oper = sel.try_select().unwrap();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&r1), Ok(1));
+
+    assert_eq!(sel.try_select().unwrap_err(), TrySelectError);
+}
+
+#[test]
+fn select_disconnected() {
+    let (s1, r1) = channel::<i32>();
+    let (s2, r2) = sync_channel::<i32>(0);
+    drop(s1);
+
+    let mut sel = Select::new();
+    let oper1 = sel.recv(&r1);
+    let oper2 = sel.recv(&r2);
+
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper1);
+    assert_eq!(oper.recv(&r1), Err(RecvError));
+
+    sel.remove(oper1);
+    assert!(sel.try_select().is_err());
+
+    drop(s2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    assert_eq!(oper.recv(&r2), Err(RecvError));
+}
+
+#[test]
+fn select_timeout() {
+    let (_s, r) = sync_channel::<i32>(1);
+
+    let mut sel = Select::new();
+    sel.recv(&r);
+    assert_eq!(sel.select_timeout(Duration::from_millis(10)).unwrap_err(), SelectTimeoutError);
+
+    let deadline = Instant::now() + Duration::from_millis(10);
+    assert!(sel.select_deadline(deadline).is_err());
+    assert!(Instant::now() >= deadline);
+}
+
+#[test]
+fn select_send() {
+    let (s1, r1) = sync_channel(1);
+    let (s2, r2) = sync_channel(1);
+    s1.send(1).unwrap();
+
+    // Only the second channel has room for another message.
+    let mut sel = Select::new();
+    let _oper1 = sel.send(&s1);
+    let oper2 = sel.send(&s2);
+    let oper = sel.select();
+    assert_eq!(oper.index(), oper2);
+    oper.send(&s2, 2).unwrap();
+
+    assert_eq!(r1.try_recv(), Ok(1));
+    assert_eq!(r2.try_recv(), Ok(2));
+}
+
+#[test]
+fn select_wakes_up() {
+    for cap in [0, 1] {
+        let (s1, r1) = sync_channel::<i32>(cap);
+        let (s2, r2) = channel::<i32>();
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(10));
+                s1.send(1).unwrap();
+            });
+
+            let mut sel = Select::new();
+            let oper1 = sel.recv(&r1);
+            let _oper2 = sel.recv(&r2);
+            let oper = sel.select();
+            assert_eq!(oper.index(), oper1);
+            assert_eq!(oper.recv(&r1), Ok(1));
+        });
+
+        drop(s2);
+    }
+}
+
+#[test]
+fn select_rendezvous() {
+    let (s, r) = sync_channel::<i32>(0);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            let mut sel = Select::new();
+            let oper = sel.send(&s);
+            let selected = sel.select();
+            assert_eq!(selected.index(), oper);
+            selected.send(&s, 7).unwrap();
+        });
+
+        let mut sel = Select::new();
+        sel.recv(&r);
+        assert_eq!(sel.select().recv(&r), Ok(7));
+    });
+}
+
+#[test]
+#[should_panic = "passed a receiver that wasn't selected"]
+fn select_wrong_receiver() {
+    let (s1, r1) = channel();
+    let (_s2, r2) = channel::<i32>();
+    s1.send(1).unwrap();
+
+    let mut sel = Select::new();
+    sel.recv(&r1);
+    let _ = sel.select().recv(&r2);
+}
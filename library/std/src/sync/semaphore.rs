@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::sys::sync as sys;
+use crate::time::Duration;
+
+/// A counting semaphore.
+///
+/// A semaphore holds a number of permits. [`acquire`] takes one of them,
+/// blocking until one is available, and returns a [`SemaphorePermit`] which
+/// gives the permit back when it is dropped. This is typically used to limit
+/// the number of threads that may use some resource at the same time.
+///
+/// Unlike a [`Mutex`], a semaphore doesn't protect any data, and permits
+/// aren't tied to the thread that acquired them. Permits can also be added to
+/// the semaphore at any time with [`release`], so a semaphore can be used
+/// to signal that work has become available.
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`release`]: Semaphore::release
+/// [`Mutex`]: crate::sync::Mutex
+///
+/// # Examples
+///
+/// ```
+/// #![feature(sync_semaphore)]
+/// use std::sync::Semaphore;
+/// use std::thread;
+///
+/// // At most two of the threads below run their critical section at a time.
+/// let semaphore = Semaphore::new(2);
+///
+/// thread::scope(|s| {
+///     for i in 0..8 {
+///         let semaphore = &semaphore;
+///         s.spawn(move || {
+///             let _permit = semaphore.acquire();
+///             println!("thread {i} is working");
+///         });
+///     }
+/// });
+///
+/// assert_eq!(semaphore.available_permits(), 2);
+/// ```
+#[unstable(feature = "sync_semaphore", issue = "none")]
+pub struct Semaphore {
+    inner: sys::Semaphore,
+}
+
+/// A permit acquired from a [`Semaphore`].
+///
+/// The permit is returned to the semaphore when this structure is dropped,
+/// unless it is [forgotten](SemaphorePermit::forget).
+///
+/// This structure is created by the [`acquire`], [`try_acquire`] and
+/// [`acquire_timeout`] methods on [`Semaphore`].
+///
+/// [`acquire`]: Semaphore::acquire
+/// [`try_acquire`]: Semaphore::try_acquire
+/// [`acquire_timeout`]: Semaphore::acquire_timeout
+#[must_use = "if unused the permit will immediately be returned"]
+#[unstable(feature = "sync_semaphore", issue = "none")]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Semaphore {
+    /// The maximum number of permits a semaphore can hold.
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub const MAX_PERMITS: usize = u32::MAX as usize;
+
+    /// Creates a new semaphore holding `permits` permits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permits` exceeds [`Semaphore::MAX_PERMITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(3);
+    /// assert_eq!(semaphore.available_permits(), 3);
+    /// ```
+    #[inline]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub const fn new(permits: usize) -> Semaphore {
+        assert!(permits <= Self::MAX_PERMITS, "too many permits for a semaphore");
+        Semaphore { inner: sys::Semaphore::new(permits as u32) }
+    }
+
+    /// Acquires a permit, blocking the current thread until one is available.
+    ///
+    /// The permit is returned to the semaphore when the returned
+    /// [`SemaphorePermit`] is dropped.
+    ///
+    /// Waiting threads are not served in any particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.acquire();
+    /// assert_eq!(semaphore.available_permits(), 0);
+    /// drop(permit);
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn acquire(&self) -> SemaphorePermit<'_> {
+        self.inner.acquire();
+        SemaphorePermit { semaphore: self }
+    }
+
+    /// Attempts to acquire a permit without blocking.
+    ///
+    /// Returns `None` if no permit is currently available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(1);
+    /// let permit = semaphore.try_acquire().unwrap();
+    /// assert!(semaphore.try_acquire().is_none());
+    /// drop(permit);
+    /// assert!(semaphore.try_acquire().is_some());
+    /// ```
+    #[inline]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        if self.inner.try_acquire() { Some(SemaphorePermit { semaphore: self }) } else { None }
+    }
+
+    /// Acquires a permit, blocking the current thread for at most `timeout`.
+    ///
+    /// Returns `None` if no permit became available before the timeout
+    /// elapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    /// use std::time::Duration;
+    ///
+    /// let semaphore = Semaphore::new(0);
+    /// assert!(semaphore.acquire_timeout(Duration::from_millis(10)).is_none());
+    ///
+    /// semaphore.release(1);
+    /// assert!(semaphore.acquire_timeout(Duration::from_millis(10)).is_some());
+    /// ```
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn acquire_timeout(&self, timeout: Duration) -> Option<SemaphorePermit<'_>> {
+        if self.inner.acquire_timeout(timeout) {
+            Some(SemaphorePermit { semaphore: self })
+        } else {
+            None
+        }
+    }
+
+    /// Adds `n` permits to the semaphore, waking up as many waiting threads
+    /// as can now acquire one.
+    ///
+    /// This does not need to be matched with an earlier acquisition, so it
+    /// can be used to grow the semaphore or to give back permits that were
+    /// [forgotten](SemaphorePermit::forget).
+    ///
+    /// # Panics
+    ///
+    /// Panics if this would make the number of available permits exceed
+    /// [`Semaphore::MAX_PERMITS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(0);
+    /// semaphore.release(2);
+    /// assert_eq!(semaphore.available_permits(), 2);
+    /// ```
+    #[inline]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn release(&self, n: usize) {
+        let n = u32::try_from(n).expect("overflow in semaphore permit count");
+        self.inner.release(n);
+    }
+
+    /// Returns the number of permits that are currently available.
+    ///
+    /// Other threads may acquire or release permits at any time, so the
+    /// result may already be out of date by the time it is returned.
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn available_permits(&self) -> usize {
+        self.inner.available() as usize
+    }
+}
+
+#[unstable(feature = "sync_semaphore", issue = "none")]
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore").field("permits", &self.available_permits()).finish()
+    }
+}
+
+impl SemaphorePermit<'_> {
+    /// Consumes the permit without returning it to the semaphore.
+    ///
+    /// This permanently reduces the number of permits held by the semaphore,
+    /// unless they are added back with [`Semaphore::release`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(sync_semaphore)]
+    /// use std::sync::Semaphore;
+    ///
+    /// let semaphore = Semaphore::new(2);
+    /// semaphore.acquire().forget();
+    /// assert_eq!(semaphore.available_permits(), 1);
+    /// ```
+    #[inline]
+    #[unstable(feature = "sync_semaphore", issue = "none")]
+    pub fn forget(self) {
+        crate::mem::forget(self);
+    }
+}
+
+#[unstable(feature = "sync_semaphore", issue = "none")]
+impl Drop for SemaphorePermit<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.semaphore.inner.release(1);
+    }
+}
+
+#[unstable(feature = "sync_semaphore", issue = "none")]
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,96 @@
+use crate::sync::atomic::AtomicUsize;
+use crate::sync::atomic::Ordering::SeqCst;
+use crate::sync::mpsc::channel;
+use crate::sync::{Arc, Semaphore};
+use crate::thread;
+use crate::time::{Duration, Instant};
+
+#[test]
+fn smoke() {
+    let s = Semaphore::new(2);
+    let a = s.acquire();
+    let b = s.try_acquire().unwrap();
+    assert!(s.try_acquire().is_none());
+    assert_eq!(s.available_permits(), 0);
+    drop(a);
+    assert_eq!(s.available_permits(), 1);
+    drop(b);
+    assert_eq!(s.available_permits(), 2);
+}
+
+#[test]
+fn release_and_forget() {
+    let s = Semaphore::new(0);
+    assert!(s.try_acquire().is_none());
+    s.release(3);
+    s.acquire().forget();
+    assert_eq!(s.available_permits(), 2);
+    s.release(0);
+    assert_eq!(s.available_permits(), 2);
+}
+
+#[test]
+#[should_panic = "overflow in semaphore permit count"]
+fn release_overflow() {
+    let s = Semaphore::new(Semaphore::MAX_PERMITS);
+    s.release(1);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn acquire_timeout() {
+    let s = Semaphore::new(0);
+    let start = Instant::now();
+    assert!(s.acquire_timeout(Duration::from_millis(20)).is_none());
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    s.release(1);
+    assert!(s.acquire_timeout(Duration::from_millis(20)).is_some());
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn release_wakes_waiter() {
+    let s = Arc::new(Semaphore::new(0));
+    let (tx, rx) = channel();
+
+    let s2 = s.clone();
+    let t = thread::spawn(move || {
+        let _permit = s2.acquire();
+        tx.send(()).unwrap();
+    });
+
+    assert!(rx.recv_timeout(Duration::from_millis(20)).is_err());
+    s.release(1);
+    rx.recv().unwrap();
+    t.join().unwrap();
+    assert_eq!(s.available_permits(), 1);
+}
+
+#[test]
+#[cfg_attr(target_os = "emscripten", ignore)]
+fn limits_concurrency() {
+    const PERMITS: usize = 3;
+    const THREADS: usize = 10;
+
+    let s = Semaphore::new(PERMITS);
+    let active = AtomicUsize::new(0);
+    let max_active = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    let _permit = s.acquire();
+                    let n = active.fetch_add(1, SeqCst) + 1;
+                    max_active.fetch_max(n, SeqCst);
+                    thread::yield_now();
+                    active.fetch_sub(1, SeqCst);
+                }
+            });
+        }
+    });
+
+    assert!(max_active.load(SeqCst) <= PERMITS);
+    assert_eq!(s.available_permits(), PERMITS);
+}
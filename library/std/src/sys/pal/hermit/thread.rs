@@ -4,6 +4,7 @@
 use crate::ffi::CStr;
 use crate::mem::ManuallyDrop;
 use crate::num::NonZero;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{io, ptr};
 
@@ -105,3 +106,11 @@ pub fn into_id(self) -> Tid {
 pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     unsafe { Ok(NonZero::new_unchecked(hermit_abi::available_parallelism())) }
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    super::unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    super::unsupported()
+}
@@ -10,6 +10,7 @@
 use crate::num::NonZero;
 use crate::ptr::NonNull;
 use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{hint, io};
 
@@ -352,3 +353,11 @@ unsafe fn terminate_and_delete_current_task() -> ! {
 pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     super::unsupported()
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    super::unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    super::unsupported()
+}
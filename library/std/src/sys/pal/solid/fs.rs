@@ -9,7 +9,7 @@
 use crate::sync::Arc;
 use crate::sys::time::SystemTime;
 use crate::sys::unsupported;
-pub use crate::sys_common::fs::exists;
+pub use crate::sys_common::fs::{exists, replace, sync_dir};
 use crate::sys_common::ignore_notfound;
 
 /// A file descriptor.
@@ -2,6 +2,7 @@
 use crate::mem::{self, ManuallyDrop};
 use crate::num::NonZero;
 use crate::sys::os;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{cmp, io, ptr};
 
@@ -138,6 +139,14 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     Err(io::Error::UNKNOWN_THREAD_COUNT)
 }
 
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    crate::sys::unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    crate::sys::unsupported()
+}
+
 fn min_stack_size(_: *const libc::pthread_attr_t) -> usize {
     libc::PTHREAD_STACK_MIN.try_into().expect("Infallible")
 }
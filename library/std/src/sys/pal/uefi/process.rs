@@ -9,6 +9,7 @@
 use crate::sys::pipe::AnonPipe;
 use crate::sys::unsupported;
 use crate::sys_common::process::{CommandEnv, CommandEnvs};
+use crate::time::Duration;
 use crate::{fmt, io};
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -67,6 +68,10 @@ pub fn stderr(&mut self, stderr: Stdio) {
         self.stderr = Some(stderr);
     }
 
+    pub fn kill_on_drop(&mut self, _kill_on_drop: bool) {
+        // Only `output` is supported, and it always waits for the child.
+    }
+
     pub fn get_program(&self) -> &OsStr {
         self.prog.as_ref()
     }
@@ -261,6 +266,10 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         self.0
     }
+
+    pub fn wait_timeout(&mut self, _timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        self.0
+    }
 }
 
 pub struct CommandArgs<'a> {
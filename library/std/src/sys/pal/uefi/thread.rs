@@ -3,6 +3,7 @@
 use crate::io;
 use crate::num::NonZero;
 use crate::ptr::NonNull;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -48,3 +49,11 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     // UEFI is single threaded
     Ok(NonZero::new(1).unwrap())
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    unsupported()
+}
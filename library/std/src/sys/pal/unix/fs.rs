@@ -90,7 +90,7 @@
 #[cfg(target_os = "android")]
 use crate::sys::weak::weak;
 use crate::sys::{cvt, cvt_r};
-pub use crate::sys_common::fs::exists;
+pub use crate::sys_common::fs::{exists, replace};
 use crate::sys_common::{AsInner, AsInnerMut, FromInner, IntoInner};
 use crate::{mem, ptr};
 
@@ -1682,6 +1682,13 @@ pub fn rename(old: &Path, new: &Path) -> io::Result<()> {
     })
 }
 
+/// Flushes the directory entries of `p` to the underlying storage.
+pub fn sync_dir(p: &Path) -> io::Result<()> {
+    let mut opts = OpenOptions::new();
+    opts.read(true);
+    File::open(p, &opts)?.fsync()
+}
+
 pub fn set_perm(p: &Path, perm: FilePermissions) -> io::Result<()> {
     run_path_with_cstr(p, &|p| cvt_r(|| unsafe { libc::chmod(p.as_ptr(), perm.mode) }).map(|_| ()))
 }
@@ -1,9 +1,10 @@
-use crate::io;
 use crate::os::fd::{AsRawFd, FromRawFd, RawFd};
 use crate::sys::cvt;
 use crate::sys::pal::unix::fd::FileDesc;
 use crate::sys::process::ExitStatus;
 use crate::sys_common::{AsInner, FromInner, IntoInner};
+use crate::time::{Duration, Instant};
+use crate::{cmp, io};
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +13,12 @@
 pub(crate) struct PidFd(FileDesc);
 
 impl PidFd {
+    /// Opens a new pidfd referring to the (unreaped) child with the given pid.
+    pub fn open(pid: libc::pid_t) -> io::Result<Self> {
+        let fd = cvt(unsafe { libc::syscall(libc::SYS_pidfd_open, pid, 0) })?;
+        Ok(unsafe { Self::from_raw_fd(fd as RawFd) })
+    }
+
     pub fn kill(&self) -> io::Result<()> {
         cvt(unsafe {
             libc::syscall(
@@ -33,6 +40,33 @@ pub fn wait(&self) -> io::Result<ExitStatus> {
         Ok(ExitStatus::from_waitid_siginfo(siginfo))
     }
 
+    /// Waits for the process to exit for at most `timeout`, and returns
+    /// whether it has exited.
+    ///
+    /// A pidfd becomes readable once its process has terminated, so this
+    /// blocks in `poll`. The process is not reaped.
+    pub fn wait_exited(&self, timeout: Duration) -> io::Result<bool> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut pollfd = libc::pollfd { fd: self.0.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+        loop {
+            let timeout = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    // Round up so that we never spin on a sub-millisecond remainder.
+                    let millis = remaining.as_nanos().div_ceil(1_000_000);
+                    cmp::min(millis, libc::c_int::MAX as u128) as libc::c_int
+                }
+                None => -1,
+            };
+            match cvt(unsafe { libc::poll(&mut pollfd, 1, timeout) }) {
+                Ok(0) if timeout != 0 => {}
+                Ok(n) => return Ok(n != 0),
+                Err(err) if err.is_interrupted() => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     pub fn try_wait(&self) -> io::Result<Option<ExitStatus>> {
         let mut siginfo: libc::siginfo_t = unsafe { crate::mem::zeroed() };
 
@@ -1,6 +1,6 @@
 use crate::assert_matches::assert_matches;
-use crate::os::fd::{AsRawFd, RawFd};
-use crate::os::linux::process::{ChildExt, CommandExt as _};
+use crate::os::fd::{AsFd, AsRawFd, RawFd};
+use crate::os::linux::process::{ChildExt, CommandExt as _, PidFd};
 use crate::os::unix::process::{CommandExt as _, ExitStatusExt};
 use crate::process::Command;
 
@@ -86,6 +86,47 @@ fn test_pidfd() {
     assert_matches!(res, Err(e) if e.raw_os_error() == Some(libc::ESRCH));
 }
 
+#[test]
+fn test_kill_on_drop_pidfd() {
+    if !probe_pidfd_support() {
+        return;
+    }
+
+    let child = Command::new("sleep")
+        .arg("1000")
+        .create_pidfd(true)
+        .kill_on_drop(true)
+        .spawn()
+        .expect("executing 'sleep' failed");
+    let fd = PidFd::from(child.pidfd().unwrap().as_fd().try_clone_to_owned().unwrap());
+    drop(child);
+
+    // Dropping the child killed and reaped it, so there is nothing left to wait for.
+    let res = fd.try_wait();
+    assert_matches!(res, Err(e) if e.raw_os_error() == Some(libc::ECHILD));
+}
+
+#[test]
+fn test_into_pidfd_disarms_kill_on_drop() {
+    if !probe_pidfd_support() {
+        return;
+    }
+
+    let child = Command::new("sleep")
+        .arg("1000")
+        .create_pidfd(true)
+        .kill_on_drop(true)
+        .spawn()
+        .expect("executing 'sleep' failed");
+
+    // The pidfd now owns the child, which must still be running.
+    let fd = child.into_pidfd().unwrap();
+    assert_matches!(fd.try_wait(), Ok(None));
+    fd.kill().expect("kill failed");
+    let status = fd.wait().expect("wait failed");
+    assert_eq!(status.signal(), Some(libc::SIGKILL));
+}
+
 fn probe_pidfd_support() -> bool {
     // pidfds require the pidfd_open syscall
     let our_pid = crate::process::id();
@@ -26,6 +26,7 @@
 pub mod os;
 pub mod pipe;
 pub mod process;
+pub mod pty;
 pub mod rand;
 pub mod stack_overflow;
 pub mod stdio;
@@ -104,6 +104,8 @@ pub struct Command {
     #[cfg(target_os = "linux")]
     create_pidfd: bool,
     pgroup: Option<pid_t>,
+    kill_on_drop: bool,
+    pty: Option<CString>,
 }
 
 // Create a new type for argv, so that we can make it `Send` and `Sync`
@@ -129,6 +131,10 @@ pub struct ChildPipes {
     pub stdin: ChildStdio,
     pub stdout: ChildStdio,
     pub stderr: ChildStdio,
+    /// The slave side of the pseudo-terminal that becomes the child's
+    /// controlling terminal, if any.
+    #[cfg_attr(any(target_os = "fuchsia", target_os = "vxworks"), allow(dead_code))]
+    pub pty: Option<FileDesc>,
 }
 
 pub enum ChildStdio {
@@ -196,6 +202,8 @@ pub fn new(program: &OsStr) -> Command {
             stdout: None,
             stderr: None,
             pgroup: None,
+            kill_on_drop: false,
+            pty: None,
         }
     }
 
@@ -221,6 +229,8 @@ pub fn new(program: &OsStr) -> Command {
             stderr: None,
             create_pidfd: false,
             pgroup: None,
+            kill_on_drop: false,
+            pty: None,
         }
     }
 
@@ -259,6 +269,12 @@ pub fn groups(&mut self, groups: &[gid_t]) {
     pub fn pgroup(&mut self, pgroup: pid_t) {
         self.pgroup = Some(pgroup);
     }
+    pub fn kill_on_drop(&mut self, kill_on_drop: bool) {
+        self.kill_on_drop = kill_on_drop;
+    }
+    pub fn pty(&mut self, slave: &CStr) {
+        self.pty = Some(slave.to_owned());
+    }
 
     #[cfg(target_os = "linux")]
     pub fn create_pidfd(&mut self, val: bool) {
@@ -331,6 +347,14 @@ pub fn get_groups(&self) -> Option<&[gid_t]> {
     pub fn get_pgroup(&self) -> Option<pid_t> {
         self.pgroup
     }
+    #[allow(dead_code)]
+    pub fn get_kill_on_drop(&self) -> bool {
+        self.kill_on_drop
+    }
+    #[allow(dead_code)]
+    pub fn get_pty(&self) -> Option<&CStr> {
+        self.pty.as_deref()
+    }
 
     pub fn get_closures(&mut self) -> &mut Vec<Box<dyn FnMut() -> io::Result<()> + Send + Sync>> {
         &mut self.closures
@@ -378,14 +402,35 @@ pub fn setup_io(
     ) -> io::Result<(StdioPipes, ChildPipes)> {
         let null = Stdio::Null;
         let default_stdin = if needs_stdin { &default } else { &null };
-        let stdin = self.stdin.as_ref().unwrap_or(default_stdin);
-        let stdout = self.stdout.as_ref().unwrap_or(&default);
-        let stderr = self.stderr.as_ref().unwrap_or(&default);
-        let (their_stdin, our_stdin) = stdin.to_child_stdio(true)?;
-        let (their_stdout, our_stdout) = stdout.to_child_stdio(false)?;
-        let (their_stderr, our_stderr) = stderr.to_child_stdio(false)?;
+
+        let pty = match self.pty {
+            Some(ref path) => {
+                // `setsid` makes the child the leader of a new process group,
+                // after which it can no longer join another one.
+                if self.pgroup.is_some() {
+                    return Err(io::const_io_error!(
+                        io::ErrorKind::InvalidInput,
+                        "a process group cannot be combined with a pseudo-terminal",
+                    ));
+                }
+                Some(crate::sys::pty::open_slave(path)?)
+            }
+            None => None,
+        };
+
+        // Streams that weren't configured explicitly are connected to the
+        // pseudo-terminal, if there is one, instead of the default.
+        let to_child_stdio = |stdio: Option<&Stdio>, default: &Stdio, readable| match (stdio, &pty)
+        {
+            (None, Some(pty)) => Ok((ChildStdio::Owned(pty.duplicate()?), None)),
+            _ => stdio.unwrap_or(default).to_child_stdio(readable),
+        };
+        let (their_stdin, our_stdin) = to_child_stdio(self.stdin.as_ref(), default_stdin, true)?;
+        let (their_stdout, our_stdout) = to_child_stdio(self.stdout.as_ref(), &default, false)?;
+        let (their_stderr, our_stderr) = to_child_stdio(self.stderr.as_ref(), &default, false)?;
         let ours = StdioPipes { stdin: our_stdin, stdout: our_stdout, stderr: our_stderr };
-        let theirs = ChildPipes { stdin: their_stdin, stdout: their_stdout, stderr: their_stderr };
+        let theirs =
+            ChildPipes { stdin: their_stdin, stdout: their_stdout, stderr: their_stderr, pty };
         Ok((ours, theirs))
     }
 }
@@ -572,6 +617,12 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             if self.pgroup.is_some() {
                 debug_command.field("pgroup", &self.pgroup);
             }
+            if self.kill_on_drop {
+                debug_command.field("kill_on_drop", &self.kill_on_drop);
+            }
+            if self.pty.is_some() {
+                debug_command.field("pty", &self.pty);
+            }
 
             #[cfg(target_os = "linux")]
             {
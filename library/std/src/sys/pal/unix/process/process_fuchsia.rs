@@ -3,6 +3,8 @@
 use crate::num::NonZero;
 use crate::sys::process::process_common::*;
 use crate::sys::process::zircon::{zx_handle_t, Handle};
+use crate::sys_common::process::wait_timeout_by_polling;
+use crate::time::Duration;
 use crate::{fmt, io, mem, ptr};
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -28,7 +30,8 @@ pub fn spawn(
 
         let process_handle = unsafe { self.do_exec(theirs, envp.as_ref())? };
 
-        Ok((Process { handle: Handle::new(process_handle) }, ours))
+        let kill_on_drop = self.get_kill_on_drop();
+        Ok((Process { handle: Handle::new(process_handle), kill_on_drop }, ours))
     }
 
     pub fn output(&mut self) -> io::Result<(ExitStatus, Vec<u8>, Vec<u8>)> {
@@ -144,6 +147,7 @@ unsafe fn do_exec(
 
 pub struct Process {
     handle: Handle,
+    kill_on_drop: bool,
 }
 
 impl Process {
@@ -161,6 +165,13 @@ pub fn kill(&mut self) -> io::Result<()> {
         Ok(())
     }
 
+    pub fn kill_group(&mut self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "process groups are not supported on this platform",
+        ))
+    }
+
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
         use crate::sys::process::zircon::*;
 
@@ -229,6 +240,20 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
         Ok(Some(ExitStatus(proc_info.return_code)))
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        // Killing a task that has already terminated is harmless, and there
+        // is nothing to reap on Fuchsia.
+        if self.kill_on_drop {
+            let _ = self.kill();
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
@@ -16,6 +16,8 @@
 #[cfg(target_os = "linux")]
 use crate::sys::pal::unix::linux::pidfd::PidFd;
 use crate::sys::process::process_common::*;
+use crate::sys_common::process::wait_timeout_by_polling;
+use crate::time::Duration;
 use crate::{fmt, mem, sys};
 
 cfg_if::cfg_if! {
@@ -69,7 +71,8 @@ pub fn spawn(
 
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
 
-        if let Some(ret) = self.posix_spawn(&theirs, envp.as_ref())? {
+        if let Some(mut ret) = self.posix_spawn(&theirs, envp.as_ref())? {
+            ret.kill_on_drop = self.get_kill_on_drop();
             return Ok((ret, ours));
         }
 
@@ -136,7 +139,10 @@ pub fn spawn(
         // loop to handle EINTR
         loop {
             match input.read(&mut bytes) {
-                Ok(0) => return Ok((p, ours)),
+                Ok(0) => {
+                    p.kill_on_drop = self.get_kill_on_drop();
+                    return Ok((p, ours));
+                }
                 Ok(8) => {
                     let (errno, footer) = bytes.split_at(4);
                     assert_eq!(
@@ -301,6 +307,9 @@ unsafe fn do_exec(
         if let Some(fd) = stdio.stderr.fd() {
             cvt_r(|| libc::dup2(fd, libc::STDERR_FILENO))?;
         }
+        if let Some(ref pty) = stdio.pty {
+            sys::pty::set_controlling_terminal(pty)?;
+        }
 
         #[cfg(not(target_os = "l4re"))]
         {
@@ -456,6 +465,7 @@ fn posix_spawn(
             || (self.env_saw_path() && !self.program_is_path())
             || !self.get_closures().is_empty()
             || self.get_groups().is_some()
+            || self.get_pty().is_some()
         {
             return Ok(None);
         }
@@ -885,6 +895,9 @@ pub struct Process {
     // (e.g. the `pidfd_open` syscall was not available).
     #[cfg(target_os = "linux")]
     pidfd: Option<PidFd>,
+    // Whether the child is killed and reaped when this handle is dropped
+    // before it has been waited on.
+    kill_on_drop: bool,
 }
 
 impl Process {
@@ -900,12 +913,12 @@ unsafe fn new(pid: pid_t, pidfd: pid_t) -> Self {
         use crate::sys_common::FromInner;
         // Safety: If `pidfd` is nonnegative, we assume it's valid and otherwise unowned.
         let pidfd = (pidfd >= 0).then(|| PidFd::from_inner(sys::fd::FileDesc::from_raw_fd(pidfd)));
-        Process { pid, status: None, pidfd }
+        Process { pid, status: None, pidfd, kill_on_drop: false }
     }
 
     #[cfg(not(target_os = "linux"))]
     unsafe fn new(pid: pid_t, _pidfd: pid_t) -> Self {
-        Process { pid, status: None }
+        Process { pid, status: None, kill_on_drop: false }
     }
 
     pub fn id(&self) -> u32 {
@@ -927,6 +940,23 @@ pub fn kill(&mut self) -> io::Result<()> {
         cvt(unsafe { libc::kill(self.pid, libc::SIGKILL) }).map(drop)
     }
 
+    pub fn kill_group(&mut self, signal: c_int) -> io::Result<()> {
+        // As with `kill`, the pid may have been recycled once we've waited on
+        // the child, and so might the process group it used to lead.
+        if self.status.is_some() {
+            return Ok(());
+        }
+        let pgid = cvt(unsafe { libc::getpgid(self.pid) })?;
+        // Signalling our own process group would take us down with the child.
+        if pgid == cvt(unsafe { libc::getpgid(0) })? {
+            return Err(io::const_io_error!(
+                ErrorKind::InvalidInput,
+                "the child is in the process group of the current process",
+            ));
+        }
+        cvt(unsafe { libc::killpg(pgid, signal) }).map(drop)
+    }
+
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
         use crate::sys::cvt_r;
         if let Some(status) = self.status {
@@ -965,6 +995,46 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        if let Some(status) = self.status {
+            return Ok(Some(status));
+        }
+        #[cfg(target_os = "linux")]
+        {
+            // Without a pidfd from spawn we can still open one for our own
+            // unreaped child, which lets us block in `poll` instead of polling.
+            let transient;
+            let pid_fd = match self.pidfd.as_ref() {
+                Some(pid_fd) => Some(pid_fd),
+                None => {
+                    transient = PidFd::open(self.pid).ok();
+                    transient.as_ref()
+                }
+            };
+            if let Some(pid_fd) = pid_fd {
+                if !pid_fd.wait_exited(timeout)? {
+                    return Ok(None);
+                }
+                // Reap the child through `try_wait`, which falls back to
+                // `waitpid` for a transient pidfd. `waitid(P_PIDFD)` is only
+                // available from Linux 5.4, while `pidfd_open` exists in 5.3.
+                return self.try_wait();
+            }
+        }
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        if self.kill_on_drop && self.status.is_none() {
+            // Reap the child as well so that it doesn't linger as a zombie.
+            if self.kill().is_ok() {
+                let _ = self.wait();
+            }
+        }
+    }
 }
 
 /// Unix exit statuses
@@ -1207,11 +1277,11 @@ fn pidfd(&self) -> io::Result<&os::PidFd> {
         }
 
         fn into_pidfd(mut self) -> Result<os::PidFd, Self> {
-            self.handle
-                .pidfd
-                .take()
-                .map(|fd| <os::PidFd as FromInner<imp::PidFd>>::from_inner(fd))
-                .ok_or_else(|| self)
+            let Some(fd) = self.handle.pidfd.take() else { return Err(self) };
+            // The caller now owns the child through the pidfd, so dropping `self` must neither
+            // kill nor reap it.
+            self.handle.kill_on_drop = false;
+            Ok(<os::PidFd as FromInner<imp::PidFd>>::from_inner(fd))
         }
     }
 }
@@ -4,6 +4,7 @@
 use crate::num::NonZero;
 use crate::sys::pal::unix::unsupported::*;
 use crate::sys::process::process_common::*;
+use crate::time::Duration;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Command
@@ -44,6 +45,10 @@ pub fn kill(&mut self) -> io::Result<()> {
         unsupported()
     }
 
+    pub fn kill_group(&mut self, _signal: c_int) -> io::Result<()> {
+        unsupported()
+    }
+
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
         unsupported()
     }
@@ -51,6 +56,10 @@ pub fn wait(&mut self) -> io::Result<ExitStatus> {
     pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         unsupported()
     }
+
+    pub fn wait_timeout(&mut self, _timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        unsupported()
+    }
 }
 
 mod wait_status;
@@ -6,6 +6,8 @@
 use crate::sys::cvt;
 use crate::sys::pal::unix::thread;
 use crate::sys::process::process_common::*;
+use crate::sys_common::process::wait_timeout_by_polling;
+use crate::time::Duration;
 use crate::{fmt, sys};
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -28,7 +30,7 @@ pub fn spawn(
             ));
         }
         let (ours, theirs) = self.setup_io(default, needs_stdin)?;
-        let mut p = Process { pid: 0, status: None };
+        let mut p = Process { pid: 0, status: None, kill_on_drop: false };
 
         unsafe {
             macro_rules! t {
@@ -105,6 +107,7 @@ macro_rules! t {
 
             if ret != libc::RTP_ID_ERROR {
                 p.pid = ret;
+                p.kill_on_drop = self.get_kill_on_drop();
                 Ok((p, ours))
             } else {
                 Err(io::Error::last_os_error())
@@ -138,6 +141,7 @@ pub fn exec(&mut self, default: Stdio) -> io::Error {
 pub struct Process {
     pid: RTP_ID,
     status: Option<ExitStatus>,
+    kill_on_drop: bool,
 }
 
 impl Process {
@@ -156,6 +160,13 @@ pub fn kill(&mut self) -> io::Result<()> {
         }
     }
 
+    pub fn kill_group(&mut self, _signal: c_int) -> io::Result<()> {
+        Err(io::const_io_error!(
+            ErrorKind::Unsupported,
+            "process groups are not supported on this platform",
+        ))
+    }
+
     pub fn wait(&mut self) -> io::Result<ExitStatus> {
         use crate::sys::cvt_r;
         if let Some(status) = self.status {
@@ -180,6 +191,20 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
             Ok(Some(ExitStatus::new(status)))
         }
     }
+
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        wait_timeout_by_polling(timeout, || self.try_wait())
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        if self.kill_on_drop && self.status.is_none() {
+            if self.kill().is_ok() {
+                let _ = self.wait();
+            }
+        }
+    }
 }
 
 /// Unix exit statuses
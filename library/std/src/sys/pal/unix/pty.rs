@@ -0,0 +1,141 @@
+//! Pseudo-terminal allocation.
+//!
+//! A pseudo-terminal is opened through `posix_openpt`, which hands back the
+//! master side. The slave side is only ever referred to by its path, and is
+//! opened in the parent just before spawning so that the child's copy is the
+//! only one left open once the spawn completes.
+
+use crate::ffi::{CStr, CString};
+use crate::io;
+use crate::os::unix::io::{BorrowedFd, OwnedFd};
+use crate::os::unix::process::WindowSize;
+use crate::sys::fd::FileDesc;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    ))] {
+        use crate::os::unix::io::{AsRawFd, FromRawFd};
+        use crate::sys::{cvt, cvt_r};
+        use crate::sys_common::IntoInner;
+
+        /// Allocates a new pseudo-terminal, returning its master side and the
+        /// path of its slave side.
+        pub fn open() -> io::Result<(OwnedFd, CString)> {
+            let master = unsafe {
+                let fd = cvt(libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY))?;
+                FileDesc::from_raw_fd(fd)
+            };
+            // `posix_openpt` does not accept `O_CLOEXEC` everywhere.
+            master.set_cloexec()?;
+            unsafe {
+                cvt(libc::grantpt(master.as_raw_fd()))?;
+                cvt(libc::unlockpt(master.as_raw_fd()))?;
+            }
+            let slave = slave_name(&master)?;
+            Ok((master.into_inner(), slave))
+        }
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        fn slave_name(master: &FileDesc) -> io::Result<CString> {
+            let mut buf = [0 as libc::c_char; 64];
+            let res = unsafe { libc::ptsname_r(master.as_raw_fd(), buf.as_mut_ptr(), buf.len()) };
+            if res != 0 {
+                return Err(io::Error::from_raw_os_error(res));
+            }
+            Ok(unsafe { CStr::from_ptr(buf.as_ptr()) }.to_owned())
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        fn slave_name(master: &FileDesc) -> io::Result<CString> {
+            use crate::sync::Mutex;
+
+            // `ptsname` returns a pointer to a static buffer, so calls must be
+            // serialized and the result copied out while the lock is held.
+            static PTSNAME_LOCK: Mutex<()> = Mutex::new(());
+
+            let _guard = PTSNAME_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+            let ptr = unsafe { libc::ptsname(master.as_raw_fd()) };
+            if ptr.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(unsafe { CStr::from_ptr(ptr) }.to_owned())
+        }
+
+        /// Opens the slave side of a pseudo-terminal for use as the stdio and
+        /// controlling terminal of a child process.
+        pub fn open_slave(path: &CStr) -> io::Result<FileDesc> {
+            let fd = cvt_r(|| unsafe {
+                libc::open(path.as_ptr(), libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC)
+            })?;
+            Ok(unsafe { FileDesc::from_raw_fd(fd) })
+        }
+
+        /// Makes `tty` the controlling terminal of the calling process, which
+        /// becomes the leader of a new session.
+        ///
+        /// This is called in the child between `fork` and `exec`, so it must
+        /// be async-signal-safe.
+        pub unsafe fn set_controlling_terminal(tty: &FileDesc) -> io::Result<()> {
+            unsafe {
+                cvt(libc::setsid())?;
+                cvt(libc::ioctl(tty.as_raw_fd(), libc::TIOCSCTTY as _, 0))?;
+            }
+            Ok(())
+        }
+
+        pub fn window_size(fd: BorrowedFd<'_>) -> io::Result<WindowSize> {
+            let mut ws: libc::winsize = unsafe { crate::mem::zeroed() };
+            cvt(unsafe { libc::ioctl(fd.as_raw_fd(), libc::TIOCGWINSZ, &mut ws) })?;
+            Ok(WindowSize {
+                rows: ws.ws_row,
+                cols: ws.ws_col,
+                pixel_width: ws.ws_xpixel,
+                pixel_height: ws.ws_ypixel,
+            })
+        }
+
+        pub fn set_window_size(fd: BorrowedFd<'_>, size: WindowSize) -> io::Result<()> {
+            let ws = libc::winsize {
+                ws_row: size.rows,
+                ws_col: size.cols,
+                ws_xpixel: size.pixel_width,
+                ws_ypixel: size.pixel_height,
+            };
+            cvt(unsafe { libc::ioctl(fd.as_raw_fd(), libc::TIOCSWINSZ, &ws) })?;
+            Ok(())
+        }
+    } else {
+        const UNSUPPORTED: io::Error = io::const_io_error!(
+            io::ErrorKind::Unsupported,
+            "pseudo-terminals are not supported on this platform",
+        );
+
+        pub fn open() -> io::Result<(OwnedFd, CString)> {
+            Err(UNSUPPORTED)
+        }
+
+        pub fn open_slave(_path: &CStr) -> io::Result<FileDesc> {
+            Err(UNSUPPORTED)
+        }
+
+        #[allow(dead_code)]
+        pub unsafe fn set_controlling_terminal(_tty: &FileDesc) -> io::Result<()> {
+            Err(UNSUPPORTED)
+        }
+
+        pub fn window_size(_fd: BorrowedFd<'_>) -> io::Result<WindowSize> {
+            Err(UNSUPPORTED)
+        }
+
+        pub fn set_window_size(_fd: BorrowedFd<'_>, _size: WindowSize) -> io::Result<()> {
+            Err(UNSUPPORTED)
+        }
+    }
+}
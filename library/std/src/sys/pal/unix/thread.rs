@@ -6,6 +6,7 @@
 #[cfg(any(target_os = "solaris", target_os = "illumos", target_os = "nto",))]
 use crate::sys::weak::weak;
 use crate::sys::{os, stack_overflow};
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{cmp, io, ptr};
 #[cfg(not(any(target_os = "l4re", target_os = "vxworks", target_os = "espidf")))]
@@ -507,6 +508,122 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     }
 }
 
+pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "android", target_os = "linux"))] {
+            use crate::sys::cvt;
+
+            let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+            for &cpu in cpus {
+                if cpu >= libc::CPU_SETSIZE as usize {
+                    return Err(CPU_OUT_OF_RANGE);
+                }
+                unsafe { libc::CPU_SET(cpu, &mut set) };
+            }
+            // A pid of 0 refers to the calling thread, not the whole process.
+            cvt(unsafe { libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) })?;
+            Ok(())
+        } else if #[cfg(target_os = "freebsd")] {
+            use crate::sys::cvt;
+
+            let mut set: libc::cpuset_t = unsafe { mem::zeroed() };
+            for &cpu in cpus {
+                if cpu >= libc::CPU_SETSIZE as usize {
+                    return Err(CPU_OUT_OF_RANGE);
+                }
+                unsafe { libc::CPU_SET(cpu, &mut set) };
+            }
+            cvt(unsafe {
+                libc::cpuset_setaffinity(
+                    libc::CPU_LEVEL_WHICH,
+                    libc::CPU_WHICH_TID,
+                    -1,
+                    mem::size_of::<libc::cpuset_t>(),
+                    &set,
+                )
+            })?;
+            Ok(())
+        } else {
+            let _ = cpus;
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "setting the thread affinity is not supported on this platform",
+            ))
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
+const CPU_OUT_OF_RANGE: io::Error = io::const_io_error!(
+    io::ErrorKind::InvalidInput,
+    "CPU index is out of range for the thread affinity mask",
+);
+
+pub fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "android", target_os = "linux"))] {
+            use crate::sys::cvt;
+
+            // Linux only honours static priorities for the real-time policies;
+            // for ordinary threads the niceness is tracked per thread instead.
+            let nice = match priority {
+                ThreadPriority::Lowest => 19,
+                ThreadPriority::BelowNormal => 10,
+                // Not requested by `Builder`, which lets new threads inherit
+                // the niceness instead, but kept so that the mapping is complete.
+                ThreadPriority::Normal => 0,
+                ThreadPriority::AboveNormal => -10,
+                ThreadPriority::Highest => -20,
+            };
+            let tid = unsafe { libc::syscall(libc::SYS_gettid) } as libc::id_t;
+            cvt(unsafe { libc::setpriority(libc::PRIO_PROCESS, tid, nice) })?;
+            Ok(())
+        } else if #[cfg(any(
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "illumos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "solaris",
+            target_vendor = "apple",
+        ))] {
+            use crate::sys::cvt;
+
+            unsafe {
+                let thread = libc::pthread_self();
+                let mut policy = 0;
+                let mut param: libc::sched_param = mem::zeroed();
+                let res = libc::pthread_getschedparam(thread, &mut policy, &mut param);
+                if res != 0 {
+                    return Err(io::Error::from_raw_os_error(res));
+                }
+                // Spread the requested priority evenly over the range that
+                // the current scheduling policy accepts.
+                let min = cvt(libc::sched_get_priority_min(policy))?;
+                let max = cvt(libc::sched_get_priority_max(policy))?;
+                param.sched_priority = match priority {
+                    ThreadPriority::Lowest => min,
+                    ThreadPriority::BelowNormal => min + (max - min) / 4,
+                    ThreadPriority::Normal => min + (max - min) / 2,
+                    ThreadPriority::AboveNormal => max - (max - min) / 4,
+                    ThreadPriority::Highest => max,
+                };
+                let res = libc::pthread_setschedparam(thread, policy, &param);
+                if res != 0 {
+                    return Err(io::Error::from_raw_os_error(res));
+                }
+            }
+            Ok(())
+        } else {
+            let _ = priority;
+            Err(io::const_io_error!(
+                io::ErrorKind::Unsupported,
+                "setting the thread priority is not supported on this platform",
+            ))
+        }
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod cgroups {
     //! Currently not covered
@@ -2,6 +2,7 @@
 use crate::ffi::CStr;
 use crate::io;
 use crate::num::NonZero;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -34,3 +35,11 @@ pub fn join(self) {
 pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     unsupported()
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    unsupported()
+}
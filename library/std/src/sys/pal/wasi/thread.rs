@@ -3,6 +3,7 @@
 use crate::ffi::CStr;
 use crate::num::NonZero;
 use crate::sys::unsupported;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{io, mem};
 
@@ -189,3 +190,11 @@ pub fn join(self) {
 pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     unsupported()
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    unsupported()
+}
@@ -2,6 +2,7 @@
 use crate::io;
 use crate::num::NonZero;
 use crate::sys::unsupported;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 
 pub struct Thread(!);
@@ -48,6 +49,14 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     unsupported()
 }
 
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    unsupported()
+}
+
 pub mod guard {
     pub type Guard = !;
     pub unsafe fn current() -> Option<Guard> {
@@ -1103,6 +1103,48 @@ pub fn rename(old: &Path, new: &Path) -> io::Result<()> {
     Ok(())
 }
 
+/// Atomically replaces `dst` with `src`.
+///
+/// `ReplaceFileW` keeps the ACLs, attributes and alternate streams of the file
+/// being replaced, but it requires `dst` to exist. When it does not we fall
+/// back to a write-through `MoveFileExW`.
+pub fn replace(src: &Path, dst: &Path) -> io::Result<()> {
+    let src = maybe_verbatim(src)?;
+    let dst = maybe_verbatim(dst)?;
+    let replaced = unsafe {
+        c::ReplaceFileW(
+            dst.as_ptr(),
+            src.as_ptr(),
+            ptr::null(),
+            c::REPLACEFILE_WRITE_THROUGH | c::REPLACEFILE_IGNORE_MERGE_ERRORS,
+            ptr::null(),
+            ptr::null(),
+        )
+    };
+    if replaced != 0 {
+        return Ok(());
+    }
+    match api::get_last_error() {
+        WinError::FILE_NOT_FOUND => {
+            cvt(unsafe {
+                c::MoveFileExW(
+                    src.as_ptr(),
+                    dst.as_ptr(),
+                    c::MOVEFILE_REPLACE_EXISTING | c::MOVEFILE_WRITE_THROUGH,
+                )
+            })?;
+            Ok(())
+        }
+        err => Err(io::Error::from_raw_os_error(err.code as i32)),
+    }
+}
+
+pub fn sync_dir(_p: &Path) -> io::Result<()> {
+    // Directory entries are flushed by `MOVEFILE_WRITE_THROUGH` and
+    // `REPLACEFILE_WRITE_THROUGH`; directories cannot be flushed on their own.
+    Ok(())
+}
+
 pub fn rmdir(p: &Path) -> io::Result<()> {
     let p = maybe_verbatim(p)?;
     cvt(unsafe { c::RemoveDirectoryW(p.as_ptr()) })?;
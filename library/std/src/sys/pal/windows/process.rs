@@ -24,6 +24,7 @@
 use crate::sys::{cvt, path, stdio};
 use crate::sys_common::process::{CommandEnv, CommandEnvs};
 use crate::sys_common::IntoInner;
+use crate::time::Duration;
 use crate::{cmp, env, fmt, mem, ptr};
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -163,6 +164,7 @@ pub struct Command {
     stderr: Option<Stdio>,
     force_quotes_enabled: bool,
     proc_thread_attributes: BTreeMap<usize, ProcThreadAttributeValue>,
+    kill_on_drop: bool,
 }
 
 pub enum Stdio {
@@ -195,6 +197,7 @@ pub fn new(program: &OsStr) -> Command {
             stderr: None,
             force_quotes_enabled: false,
             proc_thread_attributes: Default::default(),
+            kill_on_drop: false,
         }
     }
 
@@ -227,6 +230,10 @@ pub fn force_quotes(&mut self, enabled: bool) {
         self.force_quotes_enabled = enabled;
     }
 
+    pub fn kill_on_drop(&mut self, kill_on_drop: bool) {
+        self.kill_on_drop = kill_on_drop;
+    }
+
     pub fn raw_arg(&mut self, command_str_to_append: &OsStr) {
         self.args.push(Arg::Raw(command_str_to_append.to_os_string()))
     }
@@ -307,6 +314,16 @@ pub fn spawn(
             flags |= c::DETACHED_PROCESS | c::CREATE_NEW_PROCESS_GROUP;
         }
 
+        // Killing the child on drop is implemented with a Job Object so that
+        // any processes the child spawns are killed along with it. The child
+        // is started suspended until it has been assigned to the job, as
+        // otherwise its own children could escape the job.
+        let job = if self.kill_on_drop { Some(KillOnCloseJob::new()?) } else { None };
+        let resume = job.is_some() && self.flags & c::CREATE_SUSPENDED == 0;
+        if resume {
+            flags |= c::CREATE_SUSPENDED;
+        }
+
         let (envp, _data) = make_envp(maybe_env)?;
         let (dirp, _data) = make_dirp(self.cwd.as_ref())?;
         let mut pi = zeroed_process_information();
@@ -389,15 +406,27 @@ pub fn spawn(
             ))
         }?;
 
-        unsafe {
-            Ok((
-                Process {
-                    handle: Handle::from_raw_handle(pi.hProcess),
-                    main_thread_handle: Handle::from_raw_handle(pi.hThread),
-                },
-                pipes,
-            ))
+        let process = unsafe {
+            Process {
+                handle: Handle::from_raw_handle(pi.hProcess),
+                main_thread_handle: Handle::from_raw_handle(pi.hThread),
+                job,
+            }
+        };
+        if let Some(job) = &process.job {
+            if let Err(err) = job.assign(&process.handle) {
+                unsafe { c::TerminateProcess(process.handle.as_raw_handle(), 1) };
+                return Err(err);
+            }
         }
+        if resume {
+            if unsafe { c::ResumeThread(process.main_thread_handle.as_raw_handle()) } == u32::MAX {
+                let err = io::Error::last_os_error();
+                unsafe { c::TerminateProcess(process.handle.as_raw_handle(), 1) };
+                return Err(err);
+            }
+        }
+        Ok((process, pipes))
     }
 
     pub fn output(&mut self) -> io::Result<(ExitStatus, Vec<u8>, Vec<u8>)> {
@@ -657,10 +686,15 @@ fn from(_: io::Stderr) -> Stdio {
 pub struct Process {
     handle: Handle,
     main_thread_handle: Handle,
+    job: Option<KillOnCloseJob>,
 }
 
 impl Process {
     pub fn kill(&mut self) -> io::Result<()> {
+        if let Some(job) = &self.job {
+            // Also terminates every process the child has spawned.
+            return job.terminate(1);
+        }
         let result = unsafe { c::TerminateProcess(self.handle.as_raw_handle(), 1) };
         if result == c::FALSE {
             let error = api::get_last_error();
@@ -709,15 +743,75 @@ pub fn try_wait(&mut self) -> io::Result<Option<ExitStatus>> {
         }
     }
 
+    pub fn wait_timeout(&mut self, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        unsafe {
+            let timeout = super::dur2timeout(timeout);
+            match c::WaitForSingleObject(self.handle.as_raw_handle(), timeout) {
+                c::WAIT_OBJECT_0 => {}
+                c::WAIT_TIMEOUT => {
+                    return Ok(None);
+                }
+                _ => return Err(io::Error::last_os_error()),
+            }
+            let mut status = 0;
+            cvt(c::GetExitCodeProcess(self.handle.as_raw_handle(), &mut status))?;
+            Ok(Some(ExitStatus(status)))
+        }
+    }
+
     pub fn handle(&self) -> &Handle {
         &self.handle
     }
 
     pub fn into_handle(self) -> Handle {
+        // Whoever takes the handle takes over the child, so it must outlive
+        // the job that is closed along with `self`.
+        if let Some(job) = &self.job {
+            let _ = job.set_limit_flags(0);
+        }
         self.handle
     }
 }
 
+/// A Job Object that terminates all of its processes once its last handle is
+/// closed.
+struct KillOnCloseJob(Handle);
+
+impl KillOnCloseJob {
+    fn new() -> io::Result<KillOnCloseJob> {
+        let job = unsafe { c::CreateJobObjectW(ptr::null(), ptr::null()) };
+        if job.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        let job = KillOnCloseJob(unsafe { Handle::from_raw_handle(job) });
+        job.set_limit_flags(c::JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE)?;
+        Ok(job)
+    }
+
+    fn set_limit_flags(&self, flags: c::JOB_OBJECT_LIMIT) -> io::Result<()> {
+        let mut info: c::JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { mem::zeroed() };
+        info.BasicLimitInformation.LimitFlags = flags;
+        cvt(unsafe {
+            c::SetInformationJobObject(
+                self.0.as_raw_handle(),
+                c::JobObjectExtendedLimitInformation,
+                ptr::addr_of!(info).cast(),
+                mem::size_of_val(&info) as u32,
+            )
+        })
+        .map(drop)
+    }
+
+    fn assign(&self, process: &Handle) -> io::Result<()> {
+        cvt(unsafe { c::AssignProcessToJobObject(self.0.as_raw_handle(), process.as_raw_handle()) })
+            .map(drop)
+    }
+
+    fn terminate(&self, exit_code: u32) -> io::Result<()> {
+        cvt(unsafe { c::TerminateJobObject(self.0.as_raw_handle(), exit_code) }).map(drop)
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
 pub struct ExitStatus(u32);
 
@@ -6,8 +6,9 @@
 use crate::num::NonZero;
 use crate::os::windows::io::{AsRawHandle, HandleOrNull};
 use crate::sys::handle::Handle;
-use crate::sys::{c, stack_overflow};
+use crate::sys::{c, cvt, stack_overflow};
 use crate::sys_common::FromInner;
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 use crate::{io, ptr};
 
@@ -125,3 +126,34 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
         cpus => Ok(unsafe { NonZero::new_unchecked(cpus) }),
     }
 }
+
+pub fn set_affinity(cpus: &[usize]) -> io::Result<()> {
+    // Only the processor group the thread currently belongs to can be
+    // addressed through a plain affinity mask.
+    let mut mask = 0usize;
+    for &cpu in cpus {
+        if cpu >= usize::BITS as usize {
+            return Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "CPU index is out of range for the thread affinity mask",
+            ));
+        }
+        mask |= 1 << cpu;
+    }
+    cvt(unsafe { c::SetThreadAffinityMask(c::GetCurrentThread(), mask) })?;
+    Ok(())
+}
+
+pub fn set_priority(priority: ThreadPriority) -> io::Result<()> {
+    let priority = match priority {
+        ThreadPriority::Lowest => c::THREAD_PRIORITY_LOWEST,
+        ThreadPriority::BelowNormal => c::THREAD_PRIORITY_BELOW_NORMAL,
+        // Not requested by `Builder`, as new threads start at this priority,
+        // but kept so that the mapping is complete.
+        ThreadPriority::Normal => c::THREAD_PRIORITY_NORMAL,
+        ThreadPriority::AboveNormal => c::THREAD_PRIORITY_ABOVE_NORMAL,
+        ThreadPriority::Highest => c::THREAD_PRIORITY_HIGHEST,
+    };
+    cvt(unsafe { c::SetThreadPriority(c::GetCurrentThread(), priority) })?;
+    Ok(())
+}
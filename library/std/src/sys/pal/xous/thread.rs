@@ -8,6 +8,7 @@
     MemoryFlags, Syscall, ThreadId,
 };
 use crate::os::xous::services::{ticktimer_server, TicktimerScalar};
+use crate::thread::ThreadPriority;
 use crate::time::Duration;
 
 pub struct Thread {
@@ -137,3 +138,11 @@ pub fn available_parallelism() -> io::Result<NonZero<usize>> {
     // We're unicore right now.
     Ok(unsafe { NonZero::new_unchecked(1) })
 }
+
+pub fn set_affinity(_cpus: &[usize]) -> io::Result<()> {
+    crate::sys::unsupported()
+}
+
+pub fn set_priority(_priority: ThreadPriority) -> io::Result<()> {
+    crate::sys::unsupported()
+}
@@ -2,10 +2,12 @@
 mod mutex;
 mod once;
 mod rwlock;
+mod semaphore;
 mod thread_parking;
 
 pub use condvar::Condvar;
 pub use mutex::Mutex;
 pub use once::{Once, OnceState};
 pub use rwlock::RwLock;
+pub use semaphore::Semaphore;
 pub use thread_parking::Parker;
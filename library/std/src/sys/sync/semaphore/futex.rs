@@ -0,0 +1,112 @@
+use crate::sync::atomic::AtomicU32;
+use crate::sync::atomic::Ordering::{Acquire, Relaxed, SeqCst};
+use crate::sys::futex::{futex_wait, futex_wake, futex_wake_all};
+use crate::time::{Duration, Instant};
+
+pub struct Semaphore {
+    /// The number of available permits. This is also the futex that waiting
+    /// threads sleep on while it is zero.
+    permits: AtomicU32,
+    /// The number of threads that are waiting, or about to wait, for a permit.
+    /// This lets `release` skip the wake-up syscall when nobody is waiting.
+    waiters: AtomicU32,
+}
+
+impl Semaphore {
+    #[inline]
+    pub const fn new(permits: u32) -> Self {
+        Self { permits: AtomicU32::new(permits), waiters: AtomicU32::new(0) }
+    }
+
+    #[inline]
+    pub fn available(&self) -> u32 {
+        self.permits.load(Relaxed)
+    }
+
+    #[inline]
+    pub fn try_acquire(&self) -> bool {
+        let mut permits = self.permits.load(Relaxed);
+        loop {
+            if permits == 0 {
+                return false;
+            }
+            match self.permits.compare_exchange_weak(permits, permits - 1, Acquire, Relaxed) {
+                Ok(_) => return true,
+                Err(p) => permits = p,
+            }
+        }
+    }
+
+    #[inline]
+    pub fn acquire(&self) {
+        if !self.try_acquire() {
+            self.acquire_contended(None);
+        }
+    }
+
+    #[inline]
+    pub fn acquire_timeout(&self, timeout: Duration) -> bool {
+        self.try_acquire() || self.acquire_contended(Some(timeout))
+    }
+
+    #[cold]
+    fn acquire_contended(&self, timeout: Option<Duration>) -> bool {
+        // If the deadline overflows, we wait forever.
+        let deadline = timeout.and_then(|t| Instant::now().checked_add(t));
+
+        // Announce ourselves before looking at the permit count again. Both
+        // this and the increment in `release` are `SeqCst`, so either we see
+        // the released permit below, or `release` sees us and wakes us up.
+        self.waiters.fetch_add(1, SeqCst);
+
+        let acquired = loop {
+            let permits = self.permits.load(SeqCst);
+            if permits > 0 {
+                match self.permits.compare_exchange(permits, permits - 1, Acquire, Relaxed) {
+                    Ok(_) => break true,
+                    Err(_) => continue,
+                }
+            }
+
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(t) if !t.is_zero() => Some(t),
+                    _ => break false,
+                },
+                None => None,
+            };
+
+            // Sleep until a permit is released, which changes the count from
+            // the zero we just observed.
+            futex_wait(&self.permits, permits, timeout);
+        };
+
+        self.waiters.fetch_sub(1, Relaxed);
+        acquired
+    }
+
+    #[inline]
+    pub fn release(&self, n: u32) {
+        if n == 0 {
+            return;
+        }
+        if self.permits.fetch_update(SeqCst, Relaxed, |p| p.checked_add(n)).is_err() {
+            panic!("overflow in semaphore permit count");
+        }
+        if self.waiters.load(SeqCst) != 0 {
+            self.wake(n);
+        }
+    }
+
+    #[cold]
+    fn wake(&self, n: u32) {
+        // Every waiter takes a single permit, so one wake-up per permit is
+        // enough. For more than one permit, waking everybody is cheaper than
+        // issuing a separate syscall for each of them.
+        if n == 1 {
+            futex_wake(&self.permits);
+        } else {
+            futex_wake_all(&self.permits);
+        }
+    }
+}
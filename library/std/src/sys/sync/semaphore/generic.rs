@@ -0,0 +1,69 @@
+//! A semaphore built on `Mutex` and `Condvar`, for platforms without futexes.
+
+use crate::sync::{Condvar, Mutex, PoisonError};
+use crate::time::Duration;
+
+pub struct Semaphore {
+    permits: Mutex<u32>,
+    cvar: Condvar,
+}
+
+impl Semaphore {
+    #[inline]
+    pub const fn new(permits: u32) -> Self {
+        Self { permits: Mutex::new(permits), cvar: Condvar::new() }
+    }
+
+    // The lock is never held while calling user code, so it cannot be
+    // poisoned in any meaningful way.
+    fn lock(&self) -> crate::sync::MutexGuard<'_, u32> {
+        self.permits.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn available(&self) -> u32 {
+        *self.lock()
+    }
+
+    pub fn try_acquire(&self) -> bool {
+        let mut permits = self.lock();
+        if *permits == 0 {
+            return false;
+        }
+        *permits -= 1;
+        true
+    }
+
+    pub fn acquire(&self) {
+        let permits = self.lock();
+        let mut permits =
+            self.cvar.wait_while(permits, |p| *p == 0).unwrap_or_else(PoisonError::into_inner);
+        *permits -= 1;
+    }
+
+    pub fn acquire_timeout(&self, timeout: Duration) -> bool {
+        let permits = self.lock();
+        let (mut permits, _) = self
+            .cvar
+            .wait_timeout_while(permits, timeout, |p| *p == 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        if *permits == 0 {
+            return false;
+        }
+        *permits -= 1;
+        true
+    }
+
+    pub fn release(&self, n: u32) {
+        if n == 0 {
+            return;
+        }
+        let mut permits = self.lock();
+        *permits = permits.checked_add(n).expect("overflow in semaphore permit count");
+        drop(permits);
+        if n == 1 {
+            self.cvar.notify_one();
+        } else {
+            self.cvar.notify_all();
+        }
+    }
+}
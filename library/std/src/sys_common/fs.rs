@@ -26,6 +26,16 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
     Ok(ret)
 }
 
+/// Replaces `dst` with `src` on platforms without a dedicated primitive.
+pub fn replace(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::rename(src, dst)
+}
+
+/// Platforms that cannot flush a directory entry treat this as a no-op.
+pub fn sync_dir(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
 pub fn remove_dir_all(path: &Path) -> io::Result<()> {
     let filetype = fs::symlink_metadata(path)?.file_type();
     if filetype.is_symlink() { fs::remove_file(path) } else { remove_dir_all_recursive(path) }
@@ -5,7 +5,8 @@
 use crate::ffi::{OsStr, OsString};
 use crate::sys::pipe::read2;
 use crate::sys::process::{EnvKey, ExitStatus, Process, StdioPipes};
-use crate::{env, fmt, io};
+use crate::time::{Duration, Instant};
+use crate::{cmp, env, fmt, io, thread};
 
 // Stores a set of changes to an environment
 #[derive(Clone)]
@@ -157,3 +158,33 @@ pub fn wait_with_output(
     let status = process.wait()?;
     Ok((status, stdout, stderr))
 }
+
+/// Implements `Process::wait_timeout` on platforms that cannot block on a
+/// child with a timeout, by calling `try_wait` with exponential backoff.
+///
+/// This does not rely on `SIGCHLD`, so it is safe to use regardless of how
+/// the program has configured its signal handlers.
+pub fn wait_timeout_by_polling(
+    timeout: Duration,
+    mut try_wait: impl FnMut() -> io::Result<Option<ExitStatus>>,
+) -> io::Result<Option<ExitStatus>> {
+    const MAX_INTERVAL: Duration = Duration::from_millis(50);
+
+    // A deadline that cannot be represented is as good as waiting forever.
+    let deadline = Instant::now().checked_add(timeout);
+    let mut interval = Duration::from_millis(1);
+    loop {
+        if let Some(status) = try_wait()? {
+            return Ok(Some(status));
+        }
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => Duration::MAX,
+        };
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+        thread::sleep(cmp::min(interval, remaining));
+        interval = cmp::min(interval * 2, MAX_INTERVAL);
+    }
+}
@@ -180,6 +180,11 @@
 #[stable(feature = "scoped_threads", since = "1.63.0")]
 pub use scoped::{scope, Scope, ScopedJoinHandle};
 
+mod pool;
+
+#[unstable(feature = "thread_pool", issue = "none")]
+pub use pool::{Pool, PoolScope};
+
 ////////////////////////////////////////////////////////////////////////////////
 // Thread-local storage
 ////////////////////////////////////////////////////////////////////////////////
@@ -206,10 +211,12 @@ pub mod local_impl {
 ///
 /// Methods can be chained on it in order to configure it.
 ///
-/// The two configurations available are:
+/// The configurations available are:
 ///
 /// - [`name`]: specifies an [associated name for the thread][naming-threads]
 /// - [`stack_size`]: specifies the [desired stack size for the thread][stack-size]
+/// - [`affinity`]: restricts the thread to a set of CPUs
+/// - [`priority`]: specifies the scheduling priority of the thread
 ///
 /// The [`spawn`] method will take ownership of the builder and create an
 /// [`io::Result`] to the thread handle with the given configuration.
@@ -237,6 +244,8 @@ pub mod local_impl {
 ///
 /// [`stack_size`]: Builder::stack_size
 /// [`name`]: Builder::name
+/// [`affinity`]: Builder::affinity
+/// [`priority`]: Builder::priority
 /// [`spawn`]: Builder::spawn
 /// [`thread::spawn`]: spawn
 /// [`io::Result`]: crate::io::Result
@@ -251,6 +260,10 @@ pub struct Builder {
     name: Option<String>,
     // The size of the stack for the spawned thread in bytes
     stack_size: Option<usize>,
+    // The CPUs the spawned thread is allowed to run on
+    affinity: Option<Vec<usize>>,
+    // The scheduling priority of the spawned thread
+    priority: Option<ThreadPriority>,
 }
 
 impl Builder {
@@ -274,7 +287,7 @@ impl Builder {
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn new() -> Builder {
-        Builder { name: None, stack_size: None }
+        Builder { name: None, stack_size: None, affinity: None, priority: None }
     }
 
     /// Names the thread-to-be. Currently the name is used for identification
@@ -330,6 +343,101 @@ pub fn stack_size(mut self, size: usize) -> Builder {
         self
     }
 
+    /// Restricts the thread-to-be to run only on the given CPUs.
+    ///
+    /// CPUs are identified by the zero-based index the operating system
+    /// assigns to them. The affinity is applied by the new thread itself,
+    /// before the closure passed to [`spawn`] starts running, so the closure
+    /// never observes the thread on any other CPU.
+    ///
+    /// # Errors
+    ///
+    /// The affinity is only validated when the thread is spawned. Spawning
+    /// fails with [`io::ErrorKind::InvalidInput`] if `cpus` is empty or names
+    /// a CPU the platform cannot address, and with the error reported by the
+    /// operating system if it rejects the mask, for example because none of
+    /// the CPUs are online.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// This is currently supported on Linux, Android, FreeBSD and Windows.
+    /// On Windows only the processor group the thread starts in can be
+    /// addressed, which limits the indices to `0..usize::BITS`. On other
+    /// platforms spawning fails with [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_spawn_affinity)]
+    /// use std::thread;
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .affinity(&[0])
+    ///     .spawn(|| {
+    ///         // thread code, running on the first CPU only
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    ///
+    /// [`spawn`]: Builder::spawn
+    #[unstable(feature = "thread_spawn_affinity", issue = "none")]
+    pub fn affinity(mut self, cpus: &[usize]) -> Builder {
+        self.affinity = Some(cpus.to_vec());
+        self
+    }
+
+    /// Sets the scheduling priority of the thread-to-be.
+    ///
+    /// Like [`affinity`], the priority is applied by the new thread before
+    /// the closure passed to [`spawn`] starts running.
+    ///
+    /// # Errors
+    ///
+    /// Spawning fails with the error reported by the operating system if it
+    /// refuses the priority. Raising the priority above
+    /// [`ThreadPriority::Normal`] commonly requires elevated privileges.
+    ///
+    /// # Platform-specific behavior
+    ///
+    /// On Linux and Android the priority is mapped onto the niceness of the
+    /// thread, which ranges from 19 for [`ThreadPriority::Lowest`] to -20 for
+    /// [`ThreadPriority::Highest`]. On other Unix platforms it is mapped onto
+    /// the range of static priorities of the thread's scheduling policy, and
+    /// on Windows onto the `THREAD_PRIORITY_*` levels of `SetThreadPriority`.
+    /// Platforms without a notion of thread priority fail to spawn the thread
+    /// with [`io::ErrorKind::Unsupported`].
+    ///
+    /// [`ThreadPriority::Normal`] leaves the priority of the new thread as the
+    /// platform sets it. On Unix the new thread inherits the priority of the
+    /// spawning thread, while on Windows new threads always start at
+    /// `THREAD_PRIORITY_NORMAL`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(thread_spawn_priority)]
+    /// use std::thread::{self, ThreadPriority};
+    ///
+    /// let handler = thread::Builder::new()
+    ///     .priority(ThreadPriority::Lowest)
+    ///     .spawn(|| {
+    ///         // background work
+    ///     })
+    ///     .unwrap();
+    ///
+    /// handler.join().unwrap();
+    /// ```
+    ///
+    /// [`affinity`]: Builder::affinity
+    /// [`spawn`]: Builder::spawn
+    #[unstable(feature = "thread_spawn_priority", issue = "none")]
+    pub fn priority(mut self, priority: ThreadPriority) -> Builder {
+        self.priority = Some(priority);
+        self
+    }
+
     /// Spawns a new thread by taking ownership of the `Builder`, and returns an
     /// [`io::Result`] to its [`JoinHandle`].
     ///
@@ -452,7 +560,18 @@ unsafe fn spawn_unchecked_<'scope, F, T>(
         F: Send,
         T: Send,
     {
-        let Builder { name, stack_size } = self;
+        let Builder { name, stack_size, affinity, priority } = self;
+        // New threads already start at the default priority that `Normal`
+        // asks for. Setting it explicitly could fail on Unix, as a thread
+        // whose priority was lowered may not be allowed to raise it.
+        let priority = priority.filter(|&priority| priority != ThreadPriority::Normal);
+
+        if affinity.as_ref().is_some_and(|cpus| cpus.is_empty()) {
+            return Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "a thread affinity mask must contain at least one CPU",
+            ));
+        }
 
         let stack_size = stack_size.unwrap_or_else(|| {
             static MIN: AtomicUsize = AtomicUsize::new(0);
@@ -508,12 +627,36 @@ fn drop(&mut self) {
             }
         }
 
+        // Scheduling options can only be applied reliably from the new thread
+        // itself. It reports the outcome back before running `f`, so that a
+        // failure can be returned from the spawn rather than being lost.
+        let (sched_tx, sched_rx) = if affinity.is_some() || priority.is_some() {
+            let (tx, rx) = crate::sync::mpsc::sync_channel(1);
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+
         let f = MaybeDangling::new(f);
         let main = move || {
             if let Some(name) = their_thread.cname() {
                 imp::Thread::set_name(name);
             }
 
+            if let Some(sched_tx) = sched_tx {
+                let result = affinity
+                    .as_deref()
+                    .map_or(Ok(()), imp::set_affinity)
+                    .and_then(|()| priority.map_or(Ok(()), imp::set_priority));
+                let failed = result.is_err();
+                let _ = sched_tx.send(result);
+                if failed {
+                    // The spawner joins this thread and reports the error;
+                    // `f` is dropped without being run.
+                    return;
+                }
+            }
+
             crate::io::set_output_capture(output_capture);
 
             let f = f.into_inner();
@@ -544,27 +687,58 @@ fn drop(&mut self) {
         let main =
             unsafe { Box::from_raw(Box::into_raw(main) as *mut (dyn FnOnce() + Send + 'static)) };
 
-        Ok(JoinInner {
-            // SAFETY:
-            //
-            // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
-            // through FFI or otherwise used with low-level threading primitives that have no
-            // notion of or way to enforce lifetimes.
-            //
-            // As mentioned in the `Safety` section of this function's documentation, the caller of
-            // this function needs to guarantee that the passed-in lifetime is sufficiently long
-            // for the lifetime of the thread.
-            //
-            // Similarly, the `sys` implementation must guarantee that no references to the closure
-            // exist after the thread has terminated, which is signaled by `Thread::join`
-            // returning.
-            native: unsafe { imp::Thread::new(stack_size, main)? },
-            thread: my_thread,
-            packet: my_packet,
-        })
+        // SAFETY:
+        //
+        // `imp::Thread::new` takes a closure with a `'static` lifetime, since it's passed
+        // through FFI or otherwise used with low-level threading primitives that have no
+        // notion of or way to enforce lifetimes.
+        //
+        // As mentioned in the `Safety` section of this function's documentation, the caller of
+        // this function needs to guarantee that the passed-in lifetime is sufficiently long
+        // for the lifetime of the thread.
+        //
+        // Similarly, the `sys` implementation must guarantee that no references to the closure
+        // exist after the thread has terminated, which is signaled by `Thread::join`
+        // returning.
+        let native = unsafe { imp::Thread::new(stack_size, main)? };
+
+        if let Some(sched_rx) = sched_rx {
+            if let Ok(Err(e)) = sched_rx.recv() {
+                native.join();
+                return Err(e);
+            }
+        }
+
+        Ok(JoinInner { native, thread: my_thread, packet: my_packet })
     }
 }
 
+/// The scheduling priority of a thread, relative to the other threads of the
+/// process.
+///
+/// The levels are portable approximations; see [`Builder::priority`] for how
+/// they map onto each platform's scheduler.
+#[unstable(feature = "thread_spawn_priority", issue = "none")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ThreadPriority {
+    /// The lowest priority, for work that should only run when nothing else
+    /// wants the CPU.
+    Lowest,
+    /// A priority below that of ordinary threads.
+    BelowNormal,
+    /// The priority threads are spawned with by default. On Unix this is the
+    /// priority of the spawning thread, and on Windows it is
+    /// `THREAD_PRIORITY_NORMAL`.
+    #[default]
+    Normal,
+    /// A priority above that of ordinary threads.
+    AboveNormal,
+    /// The highest priority available without switching to a real-time
+    /// scheduling policy.
+    Highest,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Free functions
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,445 @@
+//! A work-stealing pool of worker threads for fork-join parallelism.
+//!
+//! Every worker owns a deque of jobs. Jobs spawned from a worker are pushed
+//! onto the back of its own deque and popped from there again, so nested
+//! fork-join work stays on the thread that created it. Idle workers steal
+//! from the front of the other deques, which holds the oldest and usually
+//! largest pieces of work. Jobs submitted from outside the pool go through a
+//! shared injector queue instead.
+//!
+//! Threads that wait for a job to finish keep running other jobs in the
+//! meantime if they are workers of the pool, and park otherwise.
+
+#[cfg(all(test, not(target_os = "emscripten")))]
+mod tests;
+
+use super::scoped::ScopeData;
+use super::{available_parallelism, current, park, Builder, JoinHandle, Thread};
+use crate::cell::Cell;
+use crate::collections::VecDeque;
+use crate::marker::PhantomData;
+use crate::num::NonZero;
+use crate::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use crate::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use crate::sync::{Arc, Condvar, Mutex};
+use crate::{fmt, io, ptr};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+thread_local! {
+    /// The pool the current thread is a worker of, and its index in that pool.
+    static WORKER: Cell<(*const Shared, usize)> = const { Cell::new((ptr::null(), 0)) };
+}
+
+struct Shared {
+    injector: Mutex<VecDeque<Job>>,
+    deques: Box<[Mutex<VecDeque<Job>>]>,
+    /// Incremented every time a job is pushed, so that a worker going to
+    /// sleep can tell whether it missed one.
+    epoch: AtomicU64,
+    /// The number of workers blocked on `wakeup`. Only modified while holding
+    /// the `terminate` lock.
+    sleepers: AtomicUsize,
+    terminate: Mutex<bool>,
+    wakeup: Condvar,
+}
+
+impl Shared {
+    fn worker_index(&self) -> Option<usize> {
+        let (pool, index) = WORKER.get();
+        ptr::eq(pool, self).then_some(index)
+    }
+
+    fn push(&self, job: Job) {
+        match self.worker_index() {
+            Some(index) => self.deques[index].lock().unwrap().push_back(job),
+            None => self.injector.lock().unwrap().push_back(job),
+        }
+
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+        if self.sleepers.load(Ordering::SeqCst) != 0 {
+            // Taking the lock makes sure a worker that saw the old epoch is
+            // blocked on the condition variable before we notify it.
+            let _guard = self.terminate.lock().unwrap();
+            self.wakeup.notify_one();
+        }
+    }
+
+    fn find_job(&self, index: usize) -> Option<Job> {
+        if let Some(job) = self.deques[index].lock().unwrap().pop_back() {
+            return Some(job);
+        }
+        if let Some(job) = self.injector.lock().unwrap().pop_front() {
+            return Some(job);
+        }
+        let n = self.deques.len();
+        (1..n).find_map(|offset| self.deques[(index + offset) % n].lock().unwrap().pop_front())
+    }
+
+    /// Removes a job pushed by a thread outside the pool from the injector
+    /// queue, if no worker has picked it up yet.
+    fn reclaim(&self, job: *const ()) -> Option<Job> {
+        let mut injector = self.injector.lock().unwrap();
+        let pos = injector.iter().position(|j| ptr::eq(job_addr(j), job))?;
+        injector.remove(pos)
+    }
+
+    /// Blocks until `done` returns true, running other jobs in the meantime
+    /// if the current thread is a worker of this pool.
+    ///
+    /// Whoever makes `done` return true must unpark the current thread.
+    fn wait_until(&self, done: impl Fn() -> bool) {
+        let index = self.worker_index();
+        while !done() {
+            match index.and_then(|index| self.find_job(index)) {
+                Some(job) => job(),
+                None => park(),
+            }
+        }
+    }
+
+    fn run_worker(&self, index: usize) {
+        WORKER.set((ptr::from_ref(self), index));
+        loop {
+            let epoch = self.epoch.load(Ordering::SeqCst);
+            if let Some(job) = self.find_job(index) {
+                job();
+                continue;
+            }
+
+            let mut terminate = self.terminate.lock().unwrap();
+            if *terminate {
+                break;
+            }
+            self.sleepers.fetch_add(1, Ordering::SeqCst);
+            if self.epoch.load(Ordering::SeqCst) == epoch {
+                terminate = self.wakeup.wait(terminate).unwrap();
+            }
+            self.sleepers.fetch_sub(1, Ordering::SeqCst);
+            drop(terminate);
+        }
+    }
+}
+
+fn job_addr(job: &Job) -> *const () {
+    (&**job as *const (dyn FnOnce() + Send)).cast()
+}
+
+/// Erases the lifetime of a job.
+///
+/// # Safety
+///
+/// The caller must make sure the job has run before `'a` ends.
+unsafe fn erase<'a>(job: Box<dyn FnOnce() + Send + 'a>) -> Job {
+    // SAFETY: dynamic size and alignment of the Box remain the same, and the
+    // caller guarantees the job does not outlive its borrows.
+    unsafe { Box::from_raw(Box::into_raw(job) as *mut (dyn FnOnce() + Send + 'static)) }
+}
+
+/// A pool of worker threads that runs fork-join work.
+///
+/// Unlike [`thread::scope`], which starts a new OS thread for every spawned
+/// closure, a `Pool` starts a fixed set of workers once and hands them small
+/// jobs: the closures given to [`PoolScope::spawn`], and the second closure
+/// of every [`join`]. Workers balance the load among themselves by stealing
+/// jobs from each other, so work can be split into many small pieces.
+///
+/// Like scoped threads, jobs can borrow non-`'static` data, because [`scope`]
+/// and [`join`] do not return before all jobs they started have finished.
+///
+/// Dropping a `Pool` shuts down its workers and waits for them to exit.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(thread_pool)]
+/// use std::thread::Pool;
+///
+/// fn sum(pool: &Pool, v: &[u64]) -> u64 {
+///     if v.len() <= 1024 {
+///         return v.iter().sum();
+///     }
+///     let (left, right) = v.split_at(v.len() / 2);
+///     let (a, b) = pool.join(|| sum(pool, left), || sum(pool, right));
+///     a + b
+/// }
+///
+/// let pool = Pool::new().unwrap();
+/// let v: Vec<u64> = (0..100_000).collect();
+/// assert_eq!(sum(&pool, &v), 4_999_950_000);
+/// ```
+///
+/// [`thread::scope`]: super::scope
+/// [`scope`]: Pool::scope
+/// [`join`]: Pool::join
+#[unstable(feature = "thread_pool", issue = "none")]
+pub struct Pool {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Pool {
+    /// Creates a pool with one worker per unit of [`available_parallelism`].
+    ///
+    /// If the available parallelism cannot be determined, the pool gets a
+    /// single worker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a worker thread could not be spawned.
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn new() -> io::Result<Pool> {
+        Pool::with_threads(available_parallelism().unwrap_or(NonZero::<usize>::MIN))
+    }
+
+    /// Creates a pool with exactly `threads` workers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a worker thread could not be spawned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(thread_pool)]
+    /// use std::num::NonZero;
+    /// use std::thread::Pool;
+    ///
+    /// let pool = Pool::with_threads(NonZero::new(2).unwrap()).unwrap();
+    /// assert_eq!(pool.num_threads(), 2);
+    /// ```
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn with_threads(threads: NonZero<usize>) -> io::Result<Pool> {
+        let threads = threads.get();
+        let shared = Arc::new(Shared {
+            injector: Mutex::new(VecDeque::new()),
+            deques: (0..threads).map(|_| Mutex::new(VecDeque::new())).collect(),
+            epoch: AtomicU64::new(0),
+            sleepers: AtomicUsize::new(0),
+            terminate: Mutex::new(false),
+            wakeup: Condvar::new(),
+        });
+        let mut pool = Pool { shared, workers: Vec::with_capacity(threads) };
+        for index in 0..threads {
+            let shared = pool.shared.clone();
+            // On failure, dropping `pool` shuts down the workers spawned so far.
+            let worker = Builder::new().spawn(move || shared.run_worker(index))?;
+            pool.workers.push(worker);
+        }
+        Ok(pool)
+    }
+
+    /// Returns the number of worker threads of this pool.
+    #[unstable(feature = "thread_pool", issue = "none")]
+    #[must_use]
+    pub fn num_threads(&self) -> usize {
+        self.shared.deques.len()
+    }
+
+    /// Creates a scope for spawning jobs on this pool.
+    ///
+    /// This works like [`thread::scope`], except that the closures passed to
+    /// [`PoolScope::spawn`] run on the workers of the pool instead of on new
+    /// threads. All jobs spawned within the scope are finished before this
+    /// function returns, so they may borrow anything that outlives the call.
+    ///
+    /// If the current thread is a worker of this pool, it runs pending jobs
+    /// while waiting for the spawned ones to finish.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated once all spawned jobs have
+    /// finished. Otherwise, if any of the spawned jobs panicked, this function
+    /// panics after all of them have finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(thread_pool)]
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::thread::Pool;
+    ///
+    /// let pool = Pool::new().unwrap();
+    /// let words = ["apple", "banana", "cherry"];
+    /// let letters = AtomicUsize::new(0);
+    ///
+    /// pool.scope(|s| {
+    ///     for word in &words {
+    ///         s.spawn(|| {
+    ///             letters.fetch_add(word.len(), Ordering::Relaxed);
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(letters.into_inner(), 17);
+    /// ```
+    ///
+    /// [`thread::scope`]: super::scope
+    #[track_caller]
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn scope<'env, F, T>(&self, f: F) -> T
+    where
+        F: for<'scope> FnOnce(&'scope PoolScope<'scope, 'env>) -> T,
+    {
+        let scope = PoolScope {
+            shared: self.shared.clone(),
+            data: Arc::new(ScopeData::new()),
+            env: PhantomData,
+            scope: PhantomData,
+        };
+
+        // Run `f`, but catch panics so we can make sure to wait for all the jobs.
+        let result = catch_unwind(AssertUnwindSafe(|| f(&scope)));
+
+        self.shared.wait_until(|| scope.data.is_finished());
+
+        match result {
+            Err(e) => resume_unwind(e),
+            Ok(_) if scope.data.a_thread_panicked() => panic!("a pool job panicked"),
+            Ok(result) => result,
+        }
+    }
+
+    /// Runs `a` and `b`, potentially in parallel, and returns both results.
+    ///
+    /// `a` runs on the current thread, while `b` is made available to the
+    /// workers of the pool. If no worker has picked `b` up by the time `a`
+    /// finishes, the current thread runs `b` itself. Splitting work
+    /// recursively with `join` therefore costs little more than a pair of
+    /// function calls when all workers are already busy.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` panics, the panic is propagated once both closures have
+    /// finished. If both panic, the panic of `a` is propagated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(thread_pool)]
+    /// use std::thread::Pool;
+    ///
+    /// let pool = Pool::new().unwrap();
+    /// let mut v = vec![5, 1, 4, 2, 3];
+    /// let (left, right) = v.split_at_mut(2);
+    /// pool.join(|| left.sort(), || right.sort());
+    /// assert_eq!(v, [1, 5, 2, 3, 4]);
+    /// ```
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn join<A, B, RA, RB>(&self, a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        let slot = Arc::new(JoinSlot {
+            result: Mutex::new(None),
+            done: AtomicBool::new(false),
+            waiter: current(),
+        });
+
+        let job = {
+            let slot = slot.clone();
+            Box::new(move || {
+                let result = catch_unwind(AssertUnwindSafe(b));
+                *slot.result.lock().unwrap() = Some(result);
+                let waiter = slot.waiter.clone();
+                slot.done.store(true, Ordering::Release);
+                // `join` may return as soon as `done` is set, so only the
+                // cloned handle may be used from here on.
+                waiter.unpark();
+            })
+        };
+        // SAFETY: `join` does not return before the job has run, which the
+        // loop below waits for even if `a` panics.
+        let job = unsafe { erase(job) };
+        let addr = job_addr(&job);
+        self.shared.push(job);
+
+        let result_a = catch_unwind(AssertUnwindSafe(a));
+
+        if self.shared.worker_index().is_none() {
+            if let Some(job) = self.shared.reclaim(addr) {
+                job();
+            }
+        }
+        self.shared.wait_until(|| slot.done.load(Ordering::Acquire));
+
+        let result_b = slot.result.lock().unwrap().take().unwrap();
+        match (result_a, result_b) {
+            (Ok(a), Ok(b)) => (a, b),
+            (Err(e), _) | (_, Err(e)) => resume_unwind(e),
+        }
+    }
+}
+
+struct JoinSlot<T> {
+    result: Mutex<Option<super::Result<T>>>,
+    done: AtomicBool,
+    waiter: Thread,
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl Drop for Pool {
+    fn drop(&mut self) {
+        *self.shared.terminate.lock().unwrap() = true;
+        self.shared.wakeup.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl fmt::Debug for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool").field("num_threads", &self.num_threads()).finish_non_exhaustive()
+    }
+}
+
+/// A scope to spawn jobs on a [`Pool`] in.
+///
+/// See [`Pool::scope`] for details.
+#[unstable(feature = "thread_pool", issue = "none")]
+pub struct PoolScope<'scope, 'env: 'scope> {
+    shared: Arc<Shared>,
+    data: Arc<ScopeData>,
+    /// Invariance over 'scope, for the same reason as in [`Scope`](super::Scope).
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> PoolScope<'scope, 'env> {
+    /// Spawns a job within the scope.
+    ///
+    /// The job runs on one of the workers of the pool, and may borrow
+    /// non-`'static` data from outside the scope. See [`Pool::scope`] for
+    /// details.
+    ///
+    /// If the job panics, [`Pool::scope`] panics after all jobs are finished.
+    #[unstable(feature = "thread_pool", issue = "none")]
+    pub fn spawn<F>(&'scope self, f: F)
+    where
+        F: FnOnce() + Send + 'scope,
+    {
+        self.data.increment_num_running_threads();
+        let data = self.data.clone();
+        let job = Box::new(move || {
+            let panicked = catch_unwind(AssertUnwindSafe(f)).is_err();
+            data.decrement_num_running_threads(panicked);
+        });
+        // SAFETY: `Pool::scope` does not return before the job has run.
+        self.shared.push(unsafe { erase(job) });
+    }
+}
+
+#[unstable(feature = "thread_pool", issue = "none")]
+impl fmt::Debug for PoolScope<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolScope")
+            .field("num_threads", &self.shared.deques.len())
+            .field("a_job_panicked", &self.data.a_thread_panicked())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,143 @@
+use super::Pool;
+use crate::num::NonZero;
+use crate::panic::{self, AssertUnwindSafe};
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::sync::Mutex;
+use crate::thread;
+
+fn pool(threads: usize) -> Pool {
+    Pool::with_threads(NonZero::new(threads).unwrap()).unwrap()
+}
+
+fn fib(pool: &Pool, n: u32) -> u64 {
+    if n < 2 {
+        return n as u64;
+    }
+    let (a, b) = pool.join(|| fib(pool, n - 1), || fib(pool, n - 2));
+    a + b
+}
+
+#[test]
+fn join_recursive() {
+    for threads in [1, 4] {
+        let pool = pool(threads);
+        assert_eq!(fib(&pool, 20), 6765);
+    }
+}
+
+#[test]
+fn join_from_worker() {
+    let pool = pool(2);
+    let (a, b) = pool.join(|| pool.join(|| 1, || 2), || pool.join(|| 3, || 4));
+    assert_eq!((a, b), ((1, 2), (3, 4)));
+}
+
+#[test]
+fn join_panic() {
+    let pool = pool(2);
+    let finished = AtomicUsize::new(0);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.join(|| finished.fetch_add(1, Ordering::Relaxed), || -> usize { panic!("b") })
+    }));
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"b"));
+
+    // `b` must still have finished before a panic of `a` propagates.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.join(|| -> usize { panic!("a") }, || finished.fetch_add(1, Ordering::Relaxed))
+    }));
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"a"));
+    assert_eq!(finished.load(Ordering::Relaxed), 2);
+
+    // The pool keeps working afterwards.
+    assert_eq!(pool.join(|| 1, || 2), (1, 2));
+}
+
+#[test]
+fn scope_borrows() {
+    let pool = pool(4);
+    let mut counts = vec![0; 100];
+    let total = AtomicUsize::new(0);
+
+    pool.scope(|s| {
+        for (i, count) in counts.iter_mut().enumerate() {
+            let total = &total;
+            s.spawn(move || {
+                *count = i;
+                total.fetch_add(i, Ordering::Relaxed);
+            });
+        }
+    });
+
+    assert_eq!(counts, (0..100).collect::<Vec<_>>());
+    assert_eq!(total.into_inner(), 4950);
+}
+
+#[test]
+fn scope_nested_spawn() {
+    let pool = pool(3);
+    let seen = Mutex::new(Vec::new());
+
+    pool.scope(|s| {
+        for i in 0..10 {
+            let seen = &seen;
+            s.spawn(move || {
+                s.spawn(move || seen.lock().unwrap().push(i));
+            });
+        }
+    });
+
+    let mut seen = seen.into_inner().unwrap();
+    seen.sort();
+    assert_eq!(seen, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn scope_panic() {
+    let pool = pool(2);
+    let finished = AtomicUsize::new(0);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.scope(|s| {
+            s.spawn(|| panic!("job"));
+            for _ in 0..10 {
+                s.spawn(|| {
+                    finished.fetch_add(1, Ordering::Relaxed);
+                });
+            }
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(finished.load(Ordering::Relaxed), 10);
+
+    // A panic in the scope closure itself is propagated as is.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        pool.scope(|s| {
+            s.spawn(|| {
+                finished.fetch_add(1, Ordering::Relaxed);
+            });
+            panic!("scope");
+        })
+    }));
+    assert_eq!(result.unwrap_err().downcast_ref::<&str>(), Some(&"scope"));
+    assert_eq!(finished.load(Ordering::Relaxed), 11);
+}
+
+#[test]
+fn jobs_run_on_workers() {
+    let pool = pool(2);
+    let main = thread::current().id();
+    let ids = Mutex::new(Vec::new());
+
+    pool.scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| ids.lock().unwrap().push(thread::current().id()));
+        }
+    });
+
+    let ids = ids.into_inner().unwrap();
+    assert_eq!(ids.len(), 8);
+    assert!(ids.iter().all(|&id| id != main));
+}
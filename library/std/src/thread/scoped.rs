@@ -41,6 +41,22 @@ pub(super) struct ScopeData {
 }
 
 impl ScopeData {
+    pub(super) fn new() -> ScopeData {
+        ScopeData {
+            num_running_threads: AtomicUsize::new(0),
+            main_thread: current(),
+            a_thread_panicked: AtomicBool::new(false),
+        }
+    }
+
+    pub(super) fn is_finished(&self) -> bool {
+        self.num_running_threads.load(Ordering::Acquire) == 0
+    }
+
+    pub(super) fn a_thread_panicked(&self) -> bool {
+        self.a_thread_panicked.load(Ordering::Relaxed)
+    }
+
     pub(super) fn increment_num_running_threads(&self) {
         // We check for 'overflow' with usize::MAX / 2, to make sure there's no
         // chance it overflows to 0, which would result in unsoundness.
@@ -137,28 +153,20 @@ pub fn scope<'env, F, T>(f: F) -> T
 {
     // We put the `ScopeData` into an `Arc` so that other threads can finish their
     // `decrement_num_running_threads` even after this function returns.
-    let scope = Scope {
-        data: Arc::new(ScopeData {
-            num_running_threads: AtomicUsize::new(0),
-            main_thread: current(),
-            a_thread_panicked: AtomicBool::new(false),
-        }),
-        env: PhantomData,
-        scope: PhantomData,
-    };
+    let scope = Scope { data: Arc::new(ScopeData::new()), env: PhantomData, scope: PhantomData };
 
     // Run `f`, but catch panics so we can make sure to wait for all the threads to join.
     let result = catch_unwind(AssertUnwindSafe(|| f(&scope)));
 
     // Wait until all the threads are finished.
-    while scope.data.num_running_threads.load(Ordering::Acquire) != 0 {
+    while !scope.data.is_finished() {
         park();
     }
 
     // Throw any panic from `f`, or the return value of `f` if no thread panicked.
     match result {
         Err(e) => resume_unwind(e),
-        Ok(_) if scope.data.a_thread_panicked.load(Ordering::Relaxed) => {
+        Ok(_) if scope.data.a_thread_panicked() => {
             panic!("a scoped thread panicked")
         }
         Ok(result) => result,
@@ -410,3 +410,64 @@ fn test_minimal_thread_stack() {
     assert_eq!(before, 0);
     assert_eq!(COUNT.load(Ordering::Relaxed), 1);
 }
+
+#[test]
+fn test_empty_affinity() {
+    let result = Builder::new().affinity(&[]).spawn(|| panic!("should not run"));
+    assert_eq!(result.unwrap_err().kind(), crate::io::ErrorKind::InvalidInput);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_affinity() {
+    // Pick a CPU we are allowed to run on, which need not be CPU 0.
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    let size = mem::size_of::<libc::cpu_set_t>();
+    assert_eq!(unsafe { libc::sched_getaffinity(0, size, &mut set) }, 0);
+    let cpu = (0..libc::CPU_SETSIZE as usize).find(|&i| unsafe { libc::CPU_ISSET(i, &set) });
+    let cpu = cpu.unwrap();
+
+    Builder::new()
+        .affinity(&[cpu])
+        .spawn(move || {
+            let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+            assert_eq!(unsafe { libc::sched_getaffinity(0, size, &mut set) }, 0);
+            assert_eq!(unsafe { libc::CPU_COUNT(&set) }, 1);
+            assert!(unsafe { libc::CPU_ISSET(cpu, &set) });
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let result = Builder::new().affinity(&[usize::MAX]).spawn(|| panic!("should not run"));
+    assert_eq!(result.unwrap_err().kind(), crate::io::ErrorKind::InvalidInput);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_priority() {
+    use crate::thread::ThreadPriority;
+
+    // Lowering the priority never requires any privileges.
+    Builder::new()
+        .priority(ThreadPriority::Lowest)
+        .spawn(|| {
+            let tid = unsafe { libc::syscall(libc::SYS_gettid) } as libc::id_t;
+            assert_eq!(unsafe { libc::getpriority(libc::PRIO_PROCESS, tid) }, 19);
+
+            // The default priority is inherited rather than reset, which would
+            // require privileges from here.
+            Builder::new()
+                .priority(ThreadPriority::Normal)
+                .spawn(|| {
+                    let tid = unsafe { libc::syscall(libc::SYS_gettid) } as libc::id_t;
+                    assert_eq!(unsafe { libc::getpriority(libc::PRIO_PROCESS, tid) }, 19);
+                })
+                .unwrap()
+                .join()
+                .unwrap();
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
@@ -36,6 +36,8 @@
 
 #[stable(feature = "time", since = "1.3.0")]
 pub use core::time::Duration;
+#[unstable(feature = "duration_human", issue = "none")]
+pub use core::time::{DisplayHuman, ParseDurationError};
 #[stable(feature = "duration_checked_float", since = "1.66.0")]
 pub use core::time::TryFromFloatSecsError;
 
@@ -36,7 +36,7 @@ class RustType(object):
     STD_PATHBUF = "StdPathBuf"
 
 
-STD_STRING_REGEX = re.compile(r"^(alloc::([a-z_]+::)+)String$")
+STD_STRING_REGEX = re.compile(r"^(alloc::([a-z_]+::)+)String(<.+>)?$")
 STD_STR_REGEX = re.compile(r"^&(mut )?str$")
 STD_SLICE_REGEX = re.compile(r"^&(mut )?\[.+\]$")
 STD_OS_STRING_REGEX = re.compile(r"^(std::ffi::([a-z_]+::)+)OsString$")
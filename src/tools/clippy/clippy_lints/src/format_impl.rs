@@ -192,6 +192,8 @@ fn check_self_in_format_args(&self) {
                         FormatTrait::Binary => sym!(Binary),
                         FormatTrait::LowerHex => sym!(LowerHex),
                         FormatTrait::UpperHex => sym!(UpperHex),
+                        FormatTrait::LowerHexFloat => sym!(LowerHexFloat),
+                        FormatTrait::UpperHexFloat => sym!(UpperHexFloat),
                     }
                     && trait_name == self.format_trait_impl.name
                     && let Ok(index) = placeholder.argument.index
@@ -1,6 +1,7 @@
 use rustc_ast::ast::{LitIntType, LitKind};
 use rustc_data_structures::packed::Pu128;
 use rustc_errors::Applicability;
+use rustc_hir::def::{CtorOf, DefKind, Res};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::LateContext;
 use rustc_span::sym;
@@ -8,7 +9,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::implements_trait;
-use clippy_utils::{match_def_path, paths};
 
 use super::SEEK_FROM_CURRENT;
 
@@ -36,8 +36,10 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'
 fn arg_is_seek_from_current<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> bool {
     if let ExprKind::Call(f, args) = expr.kind
         && let ExprKind::Path(ref path) = f.kind
-        && let Some(def_id) = cx.qpath_res(path, f.hir_id).opt_def_id()
-        && match_def_path(cx, def_id, &paths::STD_IO_SEEK_FROM_CURRENT)
+        && let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_id) = cx.qpath_res(path, f.hir_id)
+        && let variant_id = cx.tcx.parent(ctor_id)
+        && cx.tcx.is_diagnostic_item(sym::SeekFrom, cx.tcx.parent(variant_id))
+        && cx.tcx.item_name(variant_id).as_str() == "Current"
     {
         // check if argument of `SeekFrom::Current` is `0`
         if args.len() == 1
@@ -71,14 +71,17 @@
 //! index of that offset is utilized as the answer to whether we're in the set
 //! or not.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::ops::Range;
+use std::path::Path;
 
 use ucd_parse::Codepoints;
 
 mod cascading_map;
 mod case_mapping;
+mod normalization;
 mod raw_emitter;
+mod segmentation;
 mod skiplist;
 mod unicode_download;
 
@@ -94,12 +97,37 @@
     "White_Space",
     "Cc",
     "N",
+    "Extended_Pictographic",
 ];
 
+/// The quick check values from DerivedNormalizationProps.txt that are emitted as
+/// properties, with the name of the property they are emitted as.
+///
+/// The NFD and NFKD quick checks are not needed, they follow from the
+/// decomposition tables. The `Maybe` values of the NFKC quick check are the
+/// same as those of the NFC one.
+static NORMALIZATION_PROPERTIES: &[(&str, &str, &str)] =
+    &[("NFC_QC_M", "NFC_QC", "M"), ("NFC_QC_N", "NFC_QC", "N"), ("NFKC_QC_N", "NFKC_QC", "N")];
+
+/// The values of the Indic_Conjunct_Break property from DerivedCoreProperties.txt,
+/// with the name of the property they are emitted as.
+static INDIC_CONJUNCT_BREAK_PROPERTIES: &[(&str, &str)] =
+    &[("InCB_Consonant", "Consonant"), ("InCB_Extend", "Extend"), ("InCB_Linker", "Linker")];
+
+/// The general categories of the codepoints that take up no columns when
+/// displayed, which are emitted as the `Zero_Width` property.
+static ZERO_WIDTH_CATEGORIES: &[&str] = &["Cc", "Cf", "Mn", "Me"];
+
 struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    combining_classes: BTreeMap<u32, u8>,
+    canonical_decompositions: BTreeMap<u32, Vec<u32>>,
+    compatibility_decompositions: BTreeMap<u32, Vec<u32>>,
+    composition_exclusions: BTreeSet<u32>,
+    grapheme_cluster_break: BTreeMap<u32, String>,
+    word_break: BTreeMap<u32, String>,
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -143,8 +171,70 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    for row in ucd_parse::parse::<_, ucd_parse::EmojiProperty>(&UNICODE_DIRECTORY).unwrap() {
+        if let Some(name) = PROPERTIES.iter().find(|prop| **prop == row.property.as_str()) {
+            properties.entry(*name).or_insert_with(Vec::new).push(row.codepoints);
+        }
+    }
+
+    // ucd-parse does not know about properties with values in
+    // DerivedCoreProperties.txt, so those are parsed here.
+    parse_fields("DerivedCoreProperties.txt", |codepoints, fields| {
+        if let ["InCB", value] = fields {
+            if let Some(&(name, _)) =
+                INDIC_CONJUNCT_BREAK_PROPERTIES.iter().find(|&&(_, val)| val == *value)
+            {
+                properties.entry(name).or_insert_with(Vec::new).push(codepoints);
+            }
+        }
+    });
+
+    let mut composition_exclusions = BTreeSet::new();
+    let mut nfkc_maybe = Vec::new();
+    parse_fields("DerivedNormalizationProps.txt", |codepoints, fields| match *fields {
+        ["Full_Composition_Exclusion"] => {
+            composition_exclusions.extend(codepoint_values(codepoints));
+        }
+        ["NFKC_QC", "M"] => nfkc_maybe.extend(codepoint_values(codepoints)),
+        [property, value] => {
+            if let Some(&(name, _, _)) = NORMALIZATION_PROPERTIES
+                .iter()
+                .find(|&&(_, prop, val)| prop == property && val == value)
+            {
+                properties.entry(name).or_insert_with(Vec::new).push(codepoints);
+            }
+        }
+        _ => {}
+    });
+
+    // Codepoints with an East Asian Width of Wide or Fullwidth take up two
+    // columns when displayed.
+    let mut wide = BTreeSet::new();
+    parse_fields("EastAsianWidth.txt", |codepoints, fields| {
+        if let ["W" | "F"] = fields {
+            wide.extend(codepoint_values(codepoints));
+        }
+    });
+
+    let mut grapheme_cluster_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::GraphemeClusterBreak>(&UNICODE_DIRECTORY).unwrap() {
+        for codepoint in codepoint_values(row.codepoints) {
+            grapheme_cluster_break.insert(codepoint, row.value.clone());
+        }
+    }
+    let mut word_break = BTreeMap::new();
+    for row in ucd_parse::parse::<_, ucd_parse::WordBreak>(&UNICODE_DIRECTORY).unwrap() {
+        for codepoint in codepoint_values(row.codepoints) {
+            word_break.insert(codepoint, row.value.clone());
+        }
+    }
+
     let mut to_lower = BTreeMap::new();
     let mut to_upper = BTreeMap::new();
+    let mut combining_classes = BTreeMap::new();
+    let mut canonical_decompositions = BTreeMap::new();
+    let mut compatibility_decompositions = BTreeMap::new();
+    let mut zero_width = BTreeSet::new();
     for row in ucd_parse::UnicodeDataExpander::new(
         ucd_parse::parse::<_, ucd_parse::UnicodeData>(&UNICODE_DIRECTORY).unwrap(),
     ) {
@@ -160,6 +250,27 @@ fn load_data() -> UnicodeData {
                 .push(Codepoints::Single(row.codepoint));
         }
 
+        let codepoint = row.codepoint.value();
+        if ZERO_WIDTH_CATEGORIES.contains(&row.general_category.as_str()) {
+            zero_width.insert(codepoint);
+        }
+        if row.canonical_combining_class != 0 {
+            combining_classes.insert(codepoint, row.canonical_combining_class);
+        }
+        let decomposition = &row.decomposition;
+        let mapping = decomposition.mapping[..decomposition.len]
+            .iter()
+            .map(|c| c.value())
+            .collect::<Vec<_>>();
+        // Without a decomposition, the mapping holds just the codepoint itself.
+        if mapping != [codepoint] {
+            if decomposition.tag.is_none() {
+                canonical_decompositions.insert(codepoint, mapping);
+            } else {
+                compatibility_decompositions.insert(codepoint, mapping);
+            }
+        }
+
         if let Some(mapped) = row.simple_lowercase_mapping {
             if mapped != row.codepoint {
                 to_lower.insert(row.codepoint.value(), (mapped.value(), 0, 0));
@@ -209,13 +320,57 @@ fn load_data() -> UnicodeData {
         })
         .collect();
 
+    // The widths follow Markus Kuhn's `wcwidth()`: the soft hyphen is usually
+    // displayed, and the Hangul Jamo medial vowels and final consonants are
+    // displayed as part of the preceding initial consonant.
+    zero_width.remove(&0xAD);
+    zero_width.extend(0x1160..=0x11FF);
+    wide.retain(|c| !zero_width.contains(c));
+    properties.insert("Wide", wide.into_iter().map(|c| c..c + 1).collect());
+    properties.insert("Zero_Width", zero_width.into_iter().map(|c| c..c + 1).collect());
+
     for ranges in properties.values_mut() {
         merge_ranges(ranges);
     }
 
+    let mut nfkc_maybe = nfkc_maybe.into_iter().map(|c| c..c + 1).collect::<Vec<_>>();
+    merge_ranges(&mut nfkc_maybe);
+    assert_eq!(properties["NFC_QC_M"], nfkc_maybe, "the NFC and NFKC Maybe values differ");
+
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData {
+        ranges: properties,
+        to_lower,
+        to_upper,
+        combining_classes,
+        canonical_decompositions,
+        compatibility_decompositions,
+        composition_exclusions,
+        grapheme_cluster_break,
+        word_break,
+    }
+}
+
+/// Calls `f` with the codepoints and the remaining fields of each line of a UCD
+/// file, for the files and properties that ucd-parse does not support.
+fn parse_fields(file: &str, mut f: impl FnMut(Codepoints, &[&str])) {
+    let contents = std::fs::read_to_string(Path::new(UNICODE_DIRECTORY).join(file)).unwrap();
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        f(fields[0].parse().unwrap(), &fields[1..]);
+    }
+}
+
+fn codepoint_values(codepoints: Codepoints) -> Vec<u32> {
+    match codepoints {
+        Codepoints::Single(c) => vec![c.value()],
+        Codepoints::Range(c) => c.into_iter().map(|c| c.value()).collect(),
+    }
 }
 
 fn main() {
@@ -281,6 +436,12 @@ fn main() {
     table_file.push('\n');
 
     modules.push((String::from("conversions"), case_mapping::generate_case_mapping(&unicode_data)));
+    modules.push((
+        String::from("normalization"),
+        normalization::generate_normalization(&unicode_data),
+    ));
+    modules
+        .push((String::from("segmentation"), segmentation::generate_segmentation(&unicode_data)));
 
     for (name, contents) in modules {
         table_file.push_str("#[rustfmt::skip]\n");
@@ -0,0 +1,164 @@
+use std::char;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::{UnicodeData, fmt_list};
+
+/// The number of low bits of a packed decomposition entry that hold the length
+/// of the decomposition, the remaining high bits hold its start index.
+const LEN_BITS: u32 = 5;
+
+pub(crate) fn generate_normalization(data: &UnicodeData) -> String {
+    let mut canonical = BTreeMap::new();
+    let mut compatibility = BTreeMap::new();
+    for &c in data.canonical_decompositions.keys().chain(data.compatibility_decompositions.keys()) {
+        let mut full_canonical = Vec::new();
+        decompose(data, false, c, &mut full_canonical);
+        let mut full_compatibility = Vec::new();
+        decompose(data, true, c, &mut full_compatibility);
+
+        if full_canonical != [c] {
+            canonical.insert(c, full_canonical.clone());
+        }
+        // Only store the compatibility decompositions which differ from the
+        // canonical ones, lookups fall back to the canonical table.
+        if full_compatibility != full_canonical {
+            compatibility.insert(c, full_compatibility);
+        }
+    }
+
+    let mut file = String::new();
+
+    write!(file, "const LEN_BITS: u32 = {};", LEN_BITS).unwrap();
+    file.push_str("\n\n");
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_combining_classes(&data.combining_classes));
+    file.push_str("\n\n");
+    file.push_str(&generate_decompositions("CANONICAL", &canonical));
+    file.push_str("\n\n");
+    file.push_str(&generate_decompositions("COMPATIBILITY", &compatibility));
+    file.push_str("\n\n");
+    file.push_str(&generate_compositions(data));
+    file
+}
+
+/// Recursively applies the decomposition mappings to `c`, which yields its full
+/// decomposition.
+fn decompose(data: &UnicodeData, compatibility: bool, c: u32, out: &mut Vec<u32>) {
+    let mapping = data
+        .canonical_decompositions
+        .get(&c)
+        .or_else(|| if compatibility { data.compatibility_decompositions.get(&c) } else { None });
+    match mapping {
+        Some(mapping) => {
+            for &c in mapping {
+                decompose(data, compatibility, c, out);
+            }
+        }
+        None => out.push(c),
+    }
+}
+
+fn generate_combining_classes(data: &BTreeMap<u32, u8>) -> String {
+    // Each entry is packed as the codepoint in the high and the class in the
+    // low 8 bits, so that the table is sorted by codepoint.
+    let entries = data
+        .iter()
+        .filter(|&(_, &class)| class != 0)
+        .map(|(&c, &class)| c << 8 | u32::from(class))
+        .collect::<Vec<_>>();
+
+    format!("static COMBINING_CLASS_TABLE: &[u32] = &[{}];", fmt_list(entries))
+}
+
+fn generate_decompositions(kind: &str, data: &BTreeMap<u32, Vec<u32>>) -> String {
+    let mut entries = Vec::with_capacity(data.len());
+    let mut chars = Vec::new();
+
+    for (&key, mapping) in data {
+        let start = u32::try_from(chars.len()).unwrap();
+        let len = u32::try_from(mapping.len()).unwrap();
+        assert!(len < 1 << LEN_BITS, "decomposition of {key:#x} is too long");
+        assert!(start < 1 << (32 - LEN_BITS), "too many decomposed chars");
+
+        entries.push((CharEscape(char::from_u32(key).unwrap()), start << LEN_BITS | len));
+        chars.extend(mapping.iter().map(|&c| CharEscape(char::from_u32(c).unwrap())));
+    }
+
+    let mut tables = String::new();
+
+    write!(tables, "static {}_DECOMPOSED: &[(char, u32)] = &[{}];", kind, fmt_list(entries))
+        .unwrap();
+
+    tables.push_str("\n\n");
+
+    write!(tables, "static {}_DECOMPOSED_CHARS: &[char] = &[{}];", kind, fmt_list(chars)).unwrap();
+
+    tables
+}
+
+fn generate_compositions(data: &UnicodeData) -> String {
+    // The primary composites are the characters with a canonical decomposition
+    // into a pair, that are not excluded from composition.
+    let mut pairs = data
+        .canonical_decompositions
+        .iter()
+        .filter(|&(c, mapping)| mapping.len() == 2 && !data.composition_exclusions.contains(c))
+        .map(|(&c, mapping)| (mapping[0], mapping[1], c))
+        .collect::<Vec<_>>();
+    pairs.sort_unstable();
+
+    let pairs = pairs.into_iter().map(|(a, b, c)| {
+        (
+            CharEscape(char::from_u32(a).unwrap()),
+            CharEscape(char::from_u32(b).unwrap()),
+            CharEscape(char::from_u32(c).unwrap()),
+        )
+    });
+
+    format!("static COMPOSITION_TABLE: &[(char, char, char)] = &[{}];", fmt_list(pairs))
+}
+
+struct CharEscape(char);
+
+impl fmt::Debug for CharEscape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.0.escape_default())
+    }
+}
+
+static HEADER: &str = r"
+pub fn combining_class(c: char) -> u8 {
+    COMBINING_CLASS_TABLE
+        .binary_search_by(|&entry| (entry >> 8).cmp(&(c as u32)))
+        .map(|i| COMBINING_CLASS_TABLE[i] as u8)
+        .unwrap_or(0)
+}
+
+pub fn canonical_decomposition(c: char) -> Option<&'static [char]> {
+    decomposition(c, CANONICAL_DECOMPOSED, CANONICAL_DECOMPOSED_CHARS)
+}
+
+pub fn compatibility_decomposition(c: char) -> Option<&'static [char]> {
+    decomposition(c, COMPATIBILITY_DECOMPOSED, COMPATIBILITY_DECOMPOSED_CHARS)
+}
+
+fn decomposition(
+    c: char,
+    table: &[(char, u32)],
+    chars: &'static [char],
+) -> Option<&'static [char]> {
+    let i = table.binary_search_by(|&(key, _)| key.cmp(&c)).ok()?;
+    let start = (table[i].1 >> LEN_BITS) as usize;
+    let len = (table[i].1 & ((1 << LEN_BITS) - 1)) as usize;
+    chars.get(start..start + len)
+}
+
+pub fn compose_pair(a: char, b: char) -> Option<char> {
+    COMPOSITION_TABLE
+        .binary_search_by(|&(x, y, _)| (x, y).cmp(&(a, b)))
+        .map(|i| COMPOSITION_TABLE[i].2)
+        .ok()
+}
+";
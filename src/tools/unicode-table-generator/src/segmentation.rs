@@ -0,0 +1,82 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write};
+
+use crate::{fmt_list, UnicodeData};
+
+pub(crate) fn generate_segmentation(data: &UnicodeData) -> String {
+    let mut file = String::new();
+
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_property(
+        "GraphemeClusterBreak",
+        "GRAPHEME_CLUSTER_BREAK",
+        &data.grapheme_cluster_break,
+    ));
+    file.push_str("\n\n");
+    file.push_str(&generate_property("WordBreak", "WORD_BREAK", &data.word_break));
+    file
+}
+
+/// Emits an enum with the values of the property, a function looking up the
+/// value of a `char`, and the tables the lookup uses.
+fn generate_property(name: &str, table: &str, data: &BTreeMap<u32, String>) -> String {
+    // `Other` is the value of all codepoints that are not listed, and the
+    // first variant of the enum.
+    let values = std::iter::once("Other")
+        .chain(data.values().map(String::as_str).collect::<BTreeSet<_>>())
+        .collect::<Vec<_>>();
+    assert!(values.len() <= 1 << 8, "too many values of {name}");
+    let indices =
+        values.iter().enumerate().map(|(i, &v)| (v, i as u32)).collect::<BTreeMap<_, _>>();
+
+    // Each run of codepoints with the same value is packed as its first
+    // codepoint in the high and the index of the value in the low 8 bits, so
+    // that the table is sorted by codepoint.
+    let mut runs = Vec::new();
+    let mut last = None;
+    for c in 0..=(char::MAX as u32) {
+        let value = data.get(&c).map_or(0, |v| indices[v.as_str()]);
+        if last != Some(value) {
+            runs.push(c << 8 | value);
+            last = Some(value);
+        }
+    }
+
+    let variants = values.iter().map(|v| v.replace('_', "")).collect::<Vec<_>>();
+    let mut out = String::new();
+
+    out.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq)]\n");
+    writeln!(out, "pub enum {name} {{").unwrap();
+    for variant in &variants {
+        writeln!(out, "    {variant},").unwrap();
+    }
+    out.push_str("}\n\n");
+
+    writeln!(out, "pub fn {}(c: char) -> {name} {{", table.to_lowercase()).unwrap();
+    writeln!(out, "    {table}_VALUES[lookup(c, {table}_RUNS)]").unwrap();
+    out.push_str("}\n\n");
+
+    let variants = variants.iter().map(|v| Path(format!("{name}::{v}")));
+    writeln!(out, "static {table}_VALUES: &[{name}] = &[{}];", fmt_list(variants)).unwrap();
+    out.push('\n');
+    write!(out, "static {table}_RUNS: &[u32] = &[{}];", fmt_list(runs)).unwrap();
+    out
+}
+
+struct Path(String);
+
+impl fmt::Debug for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+static HEADER: &str = r"
+/// Returns the index of the value of the run that `c` is in.
+fn lookup(c: char, runs: &[u32]) -> usize {
+    // The first run starts at U+0000, so there always is one.
+    let i = runs.partition_point(|&run| run >> 8 <= c as u32) - 1;
+    (runs[i] & 0xff) as usize
+}
+";
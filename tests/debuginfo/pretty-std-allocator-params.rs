@@ -0,0 +1,141 @@
+// ignore-tidy-linelength
+//@ ignore-android: FIXME(#10381)
+//@ ignore-windows-gnu: #128981
+//@ compile-flags:-g
+//@ min-lldb-version: 1800
+//@ min-cdb-version: 10.0.18317.1001
+
+// Checks that `String`, `HashMap` and `HashSet` are still pretty-printed now that their type names
+// carry an allocator parameter, both for the default allocator and for a custom one.
+
+// === GDB TESTS ===================================================================================
+
+// gdb-command: run
+
+// gdb-command: print string
+// gdb-check:$1 = "IAMA string!"
+
+// gdb-command: print string_in
+// gdb-check:$2 = "IAMA string in an allocator!"
+
+// gdb-command: print hash_map
+// gdb-check:$3 = HashMap(size=2) = {[1] = 10, [2] = 20}
+
+// gdb-command: print hash_map_in
+// gdb-check:$4 = HashMap(size=2) = {[3] = 30, [4] = 40}
+
+// gdb-command: print hash_set
+// gdb-check:$5 = HashSet(size=2) = {1, 2}
+
+// gdb-command: print hash_set_in
+// gdb-check:$6 = HashSet(size=2) = {3, 4}
+
+// === LLDB TESTS ==================================================================================
+
+// lldb-command:run
+
+// lldb-command:v string
+// lldb-check:(alloc::string::String<alloc::alloc::Global>) string = "IAMA string!" [...]
+
+// lldb-command:v string_in
+// lldb-check:(alloc::string::String<pretty_std_allocator_params::ProxyAlloc>) string_in = "IAMA string in an allocator!" [...]
+
+// lldb-command:v hash_map
+// lldb-check:[...] size=2 { [0] = { 0 = 1 1 = 10 } [1] = { 0 = 2 1 = 20 } }
+
+// lldb-command:v hash_map_in
+// lldb-check:[...] size=2 { [0] = { 0 = 3 1 = 30 } [1] = { 0 = 4 1 = 40 } }
+
+// lldb-command:v hash_set
+// lldb-check:[...] size=2 { [0] = 1 [1] = 2 }
+
+// lldb-command:v hash_set_in
+// lldb-check:[...] size=2 { [0] = 3 [1] = 4 }
+
+// === CDB TESTS ==================================================================================
+
+// cdb-command: g
+
+// cdb-command: dx string
+// cdb-check:string           : "IAMA string!" [Type: alloc::string::String<alloc::alloc::Global>]
+
+// cdb-command: dx string_in
+// cdb-check:string_in        : "IAMA string in an allocator!" [Type: alloc::string::String<pretty_std_allocator_params::ProxyAlloc>]
+
+// cdb-command: dx hash_map
+// cdb-check:hash_map         : { len=2 } [Type: std::collections::hash::map::HashMap<u64,u64,[...],alloc::alloc::Global>]
+// cdb-check:    [len]            : 2 [Type: [...]]
+
+// cdb-command: dx hash_map_in
+// cdb-check:hash_map_in      : { len=2 } [Type: std::collections::hash::map::HashMap<u64,u64,[...],pretty_std_allocator_params::ProxyAlloc>]
+// cdb-check:    [len]            : 2 [Type: [...]]
+
+// cdb-command: dx hash_set
+// cdb-check:hash_set         : { len=2 } [Type: std::collections::hash::set::HashSet<u64,[...],alloc::alloc::Global>]
+// cdb-check:    [len]            : 2 [Type: [...]]
+
+// cdb-command: dx hash_set_in
+// cdb-check:hash_set_in      : { len=2 } [Type: std::collections::hash::set::HashSet<u64,[...],pretty_std_allocator_params::ProxyAlloc>]
+// cdb-check:    [len]            : 2 [Type: [...]]
+
+#![allow(unused_variables)]
+#![feature(allocator_api)]
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::ptr::NonNull;
+
+#[derive(Default)]
+struct SimpleHasher { hash: u64 }
+
+impl Hasher for SimpleHasher {
+    fn finish(&self) -> u64 { self.hash }
+    fn write(&mut self, bytes: &[u8]) {}
+    fn write_u64(&mut self, i: u64) { self.hash = i }
+}
+
+type SimpleState = BuildHasherDefault<SimpleHasher>;
+
+/// An allocator that forwards to `Global`, so that the allocator shows up in the type names.
+#[derive(Clone, Copy)]
+struct ProxyAlloc;
+
+unsafe impl Allocator for ProxyAlloc {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+fn main() {
+    // String
+    let string = String::from("IAMA string!");
+    let mut string_in = String::with_capacity_in(32, ProxyAlloc);
+    string_in.push_str("IAMA string in an allocator!");
+
+    // HashMap
+    let mut hash_map = HashMap::<u64, u64, SimpleState>::default();
+    hash_map.insert(1, 10);
+    hash_map.insert(2, 20);
+    let mut hash_map_in = HashMap::with_hasher_in(SimpleState::default(), ProxyAlloc);
+    hash_map_in.insert(3u64, 30u64);
+    hash_map_in.insert(4, 40);
+
+    // HashSet
+    let mut hash_set = HashSet::<u64, SimpleState>::default();
+    hash_set.insert(1);
+    hash_set.insert(2);
+    let mut hash_set_in = HashSet::with_hasher_in(SimpleState::default(), ProxyAlloc);
+    hash_set_in.insert(3u64);
+    hash_set_in.insert(4);
+
+    zzz(); // #break
+}
+
+fn zzz() {
+    ()
+}
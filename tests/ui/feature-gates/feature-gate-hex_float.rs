@@ -0,0 +1,4 @@
+fn main() {
+    println!("{:a}", 1.0f64); //~ ERROR formatting floats in hexadecimal is experimental
+    println!("{:A}", 1.0f32); //~ ERROR formatting floats in hexadecimal is experimental
+}